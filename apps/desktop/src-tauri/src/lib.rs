@@ -17,6 +17,15 @@ use tauri_plugin_windows::{AppWindow, WindowsPluginExt};
 pub async fn main() {
     tauri::async_runtime::set(tokio::runtime::Handle::current());
 
+    // Launched by an agent as its MCP server. Bridges stdio to the running app and exits.
+    if pico_args::Arguments::from_env().contains("--mcp-stdio") {
+        if let Err(e) = tauri_plugin_mcp::run_stdio().await {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+        return;
+    }
+
     let (root_supervisor_ctx, root_supervisor_handle) =
         match supervisor::spawn_root_supervisor().await {
             Some((ctx, handle)) => (Some(ctx), Some(handle)),
@@ -83,6 +92,7 @@ pub async fn main() {
         .plugin(tauri_plugin_importer::init())
         .plugin(tauri_plugin_apple_calendar::init())
         .plugin(tauri_plugin_apple_contact::init())
+        .plugin(tauri_plugin_caldav::init())
         .plugin(tauri_plugin_auth::init())
         .plugin(tauri_plugin_db2::init())
         .plugin(tauri_plugin_tracing::init())
@@ -198,6 +208,19 @@ pub async fn main() {
                 }
             }
 
+            {
+                let app_handle = app_handle.clone();
+                tokio::spawn(async move {
+                    use tauri_plugin_mcp::McpPluginExt;
+
+                    if app_handle.mcp().is_enabled()
+                        && let Err(e) = app_handle.mcp().start_server().await
+                    {
+                        tracing::error!("failed_to_start_mcp_server: {}", e);
+                    }
+                });
+            }
+
             tokio::spawn(async move {
                 use tauri_plugin_db2::Database2PluginExt;
 
@@ -1,7 +1,7 @@
 import { tool } from "ai";
 import { z } from "zod";
 
-import type { SessionContext } from "@hypr/plugin-template";
+import type { SessionContext, VaultPassage } from "@hypr/plugin-template";
 import { commands as templateCommands } from "@hypr/plugin-template";
 
 import { searchFiltersSchema } from "../contexts/search/engine/types";
@@ -59,8 +59,122 @@ const buildSearchSessionsTool = (deps: ToolDependencies) =>
     },
   });
 
+const VAULT_MAX_SESSIONS = 6;
+const VAULT_SPANS_PER_SESSION = 3;
+
+// Reciprocal rank fusion: sessions that rank well for several queries win.
+async function searchVaultSessions(
+  deps: ToolDependencies,
+  queries: string[],
+  filters: SearchFilters | null,
+): Promise<SearchHit[]> {
+  const fused = new Map<string, { hit: SearchHit; score: number }>();
+
+  for (const query of queries) {
+    const hits = await deps.search(query, filters);
+    hits
+      .filter((hit) => hit.document.type === "session")
+      .forEach((hit, rank) => {
+        const entry = fused.get(hit.document.id) ?? { hit, score: 0 };
+        entry.score += 1 / (60 + rank);
+        fused.set(hit.document.id, entry);
+      });
+  }
+
+  return [...fused.values()]
+    .sort((a, b) => b.score - a.score)
+    .slice(0, VAULT_MAX_SESSIONS)
+    .map(({ hit, score }) => ({ ...hit, score }));
+}
+
+async function toVaultPassages(
+  hit: SearchHit,
+  queries: string[],
+  context: SessionContext | null,
+): Promise<VaultPassage[]> {
+  const base = {
+    sessionId: hit.document.id,
+    title: context?.title ?? (hit.document.title || null),
+    date: context?.date ?? null,
+  };
+
+  if (context?.transcript) {
+    const spans = await templateCommands.selectTranscriptSpans(
+      context.transcript,
+      queries,
+      { maxSpans: VAULT_SPANS_PER_SESSION, contextSegments: 1 },
+    );
+
+    if (spans.status === "ok" && spans.data.length > 0) {
+      return spans.data.map((span) => ({
+        ...base,
+        startMs: span.startMs,
+        segments: span.segments,
+        excerpt: null,
+      }));
+    }
+  }
+
+  return [
+    {
+      ...base,
+      startMs: null,
+      segments: [],
+      excerpt: hit.document.content.slice(0, 280),
+    },
+  ];
+}
+
+const buildSearchVaultTool = (deps: ToolDependencies) =>
+  tool({
+    description: `
+  Search every meeting in the vault with one or more keyword queries.
+  Returns the matching parts of transcripts, each with a link to cite it.
+  `.trim(),
+    inputSchema: z.object({
+      queries: z
+        .array(z.string())
+        .min(1)
+        .max(4)
+        .describe(
+          "Short keyword queries, including other words the topic may have been said as",
+        ),
+      filters: searchFiltersSchema
+        .optional()
+        .describe("Optional filters for the search query"),
+    }),
+    execute: async (params: { queries: string[]; filters?: SearchFilters }) => {
+      const hits = await searchVaultSessions(
+        deps,
+        params.queries,
+        params.filters || null,
+      );
+
+      const passages = (
+        await Promise.all(
+          hits.map(async (hit) =>
+            toVaultPassages(
+              hit,
+              params.queries,
+              await deps.resolveSessionContext(hit.document.id),
+            ),
+          ),
+        )
+      ).flat();
+
+      const rendered = await templateCommands.render({
+        toolSearchVault: { queries: params.queries, passages },
+      });
+
+      const contextText = rendered.status === "ok" ? rendered.data : null;
+
+      return { passages, contextText };
+    },
+  });
+
 export const buildChatTools = (deps: ToolDependencies) => ({
   search_sessions: buildSearchSessionsTool(deps),
+  search_vault: buildSearchVaultTool(deps),
 });
 
 type LocalTools = {
@@ -78,6 +192,13 @@ type LocalTools = {
       contextText: string | null;
     };
   };
+  search_vault: {
+    input: { queries: string[]; filters?: SearchFilters };
+    output: {
+      passages: VaultPassage[];
+      contextText: string | null;
+    };
+  };
 };
 
 export type Tools = LocalTools & SupportMcpTools;
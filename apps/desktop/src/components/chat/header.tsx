@@ -1,5 +1,6 @@
 import {
   ChevronDown,
+  LibraryIcon,
   MessageCircle,
   PanelRightIcon,
   PictureInPicture2Icon,
@@ -23,11 +24,15 @@ export function ChatHeader({
   currentChatGroupId,
   onNewChat,
   onSelectChat,
+  vaultMode,
+  onToggleVaultMode,
   handleClose,
 }: {
   currentChatGroupId: string | undefined;
   onNewChat: () => void;
   onSelectChat: (chatGroupId: string) => void;
+  vaultMode: boolean;
+  onToggleVaultMode: () => void;
   handleClose: () => void;
 }) {
   const { chat } = useShell();
@@ -53,6 +58,17 @@ export function ChatHeader({
       </div>
 
       <div className="flex items-center">
+        <ChatActionButton
+          icon={
+            <LibraryIcon
+              className={cn(["w-4 h-4", vaultMode && "text-blue-600"])}
+            />
+          }
+          onClick={onToggleVaultMode}
+          title={
+            vaultMode ? "Chat about this note" : "Chat across all meetings"
+          }
+        />
         <ChatActionButton
           icon={
             chat.mode === "RightPanelOpen" ? (
@@ -3,6 +3,7 @@ import { useCallback, useEffect, useRef, useState } from "react";
 import { Streamdown } from "streamdown";
 
 import type { HyprUIMessage } from "../../../chat/types";
+import { useTabs } from "../../../store/zustand/tabs";
 import { hasRenderableContent } from "../shared";
 import { Disclosure, MessageBubble, MessageContainer } from "./shared";
 import { Tool } from "./tool";
@@ -170,8 +171,73 @@ const chatComponents = {
       <p className="mb-1.5 last:mb-0">{props.children as React.ReactNode}</p>
     );
   },
+  a: (props: React.AnchorHTMLAttributes<HTMLAnchorElement>) => {
+    const citation = props.href?.match(/^#session\/([^/]+)(?:\/(\d+))?$/);
+    if (citation) {
+      return (
+        <SessionCitation
+          sessionId={citation[1]}
+          startMs={citation[2] ? Number(citation[2]) : null}
+        >
+          {props.children as React.ReactNode}
+        </SessionCitation>
+      );
+    }
+
+    return (
+      <a
+        href={props.href}
+        target="_blank"
+        rel="noreferrer"
+        className="underline underline-offset-2"
+      >
+        {props.children as React.ReactNode}
+      </a>
+    );
+  },
 } as const;
 
+function SessionCitation({
+  sessionId,
+  startMs,
+  children,
+}: {
+  sessionId: string;
+  startMs: number | null;
+  children: React.ReactNode;
+}) {
+  const openNew = useTabs((state) => state.openNew);
+  const updateSessionTabState = useTabs((state) => state.updateSessionTabState);
+
+  const handleClick = useCallback(() => {
+    openNew({ type: "sessions", id: sessionId });
+    if (startMs === null) {
+      return;
+    }
+
+    const tab = useTabs
+      .getState()
+      .tabs.find((t) => t.type === "sessions" && t.id === sessionId);
+    if (tab?.type === "sessions") {
+      updateSessionTabState(tab, {
+        ...tab.state,
+        view: { type: "transcript" },
+        seekMs: startMs,
+      });
+    }
+  }, [openNew, updateSessionTabState, sessionId, startMs]);
+
+  return (
+    <button
+      type="button"
+      onClick={handleClick}
+      className="text-blue-600 hover:underline"
+    >
+      {children}
+    </button>
+  );
+}
+
 function Text({ part }: { part: Extract<Part, { type: "text" }> }) {
   const isAnimating = part.state !== "done";
 
@@ -4,7 +4,7 @@ import { ToolBillingPortal } from "./billing-portal";
 import { ToolCreateIssue } from "./create-issue";
 import { ToolGeneric } from "./generic";
 import { ToolListSubscriptions } from "./list-subscriptions";
-import { ToolSearchSessions } from "./search";
+import { ToolSearchSessions, ToolSearchVault } from "./search";
 import { ToolSearchIssues } from "./search-issues";
 
 const toolRegistry: Record<string, (props: { part: Part }) => React.ReactNode> =
@@ -12,6 +12,9 @@ const toolRegistry: Record<string, (props: { part: Part }) => React.ReactNode> =
     "tool-search_sessions": ToolSearchSessions as (props: {
       part: Part;
     }) => React.ReactNode,
+    "tool-search_vault": ToolSearchVault as (props: {
+      part: Part;
+    }) => React.ReactNode,
     "tool-create_issue": ToolCreateIssue as (props: {
       part: Part;
     }) => React.ReactNode,
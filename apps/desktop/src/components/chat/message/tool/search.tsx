@@ -18,6 +18,8 @@ import { useToolState } from "./shared";
 
 type Renderer = ToolRenderer<"tool-search_sessions">;
 type Part = Parameters<Renderer>[0]["part"];
+type VaultRenderer = ToolRenderer<"tool-search_vault">;
+type VaultPart = Parameters<VaultRenderer>[0]["part"];
 type SearchResult = {
   id: string;
 };
@@ -79,37 +81,53 @@ const getTitle = (part: Part) => {
 function RenderContent({ part }: { part: Part }) {
   if (part.state === "output-available") {
     const results = parseSearchResults(part.output);
+    return <SessionResults sessionIds={results.map((result) => result.id)} />;
+  }
 
-    if (!results || results.length === 0) {
-      return (
-        <div className="text-xs text-muted-foreground flex justify-center items-center py-2">
-          No results found
-        </div>
-      );
-    }
+  if (part.state === "output-error") {
+    return <div className="text-sm text-red-500">Error: {part.errorText}</div>;
+  }
 
-    return (
-      <div className="relative -mx-1">
-        <Carousel className="w-full" opts={{ align: "start" }}>
-          <CarouselContent className="-ml-2">
-            {results.map((result, index: number) => (
-              <CarouselItem
-                key={result.id || index}
-                className="pl-1 basis-full sm:basis-1/2 lg:basis-1/3"
-              >
-                <Card className="h-full bg-neutral-50">
-                  <CardContent className="px-2 py-0.5">
-                    <RenderSession sessionId={result.id} />
-                  </CardContent>
-                </Card>
-              </CarouselItem>
-            ))}
-          </CarouselContent>
-          <CarouselPrevious className="-left-4 h-6 w-6 bg-neutral-100 hover:bg-neutral-200" />
-          <CarouselNext className="-right-4 h-6 w-6 bg-neutral-100 hover:bg-neutral-200" />
-        </Carousel>
-      </div>
-    );
+  return null;
+}
+
+export const ToolSearchVault: VaultRenderer = ({ part }) => {
+  const { running: disabled } = useToolState(part);
+
+  return (
+    <Disclosure
+      icon={<SearchIcon className="w-3 h-3" />}
+      title={getVaultTitle(part)}
+      disabled={disabled}
+    >
+      <RenderVaultContent part={part} />
+    </Disclosure>
+  );
+};
+
+const getVaultTitle = (part: VaultPart) => {
+  const queries = part.input?.queries?.join(", ");
+  if (part.state === "input-streaming") {
+    return "Preparing search...";
+  }
+  if (part.state === "input-available") {
+    return `Searching all meetings for: ${queries}`;
+  }
+  if (part.state === "output-available") {
+    return `Searched all meetings for: ${queries}`;
+  }
+  if (part.state === "output-error") {
+    return queries ? `Search failed: ${queries}` : "Search failed";
+  }
+  return "Search";
+};
+
+function RenderVaultContent({ part }: { part: VaultPart }) {
+  if (part.state === "output-available") {
+    const sessionIds = [
+      ...new Set(part.output.passages.map((passage) => passage.sessionId)),
+    ];
+    return <SessionResults sessionIds={sessionIds} />;
   }
 
   if (part.state === "output-error") {
@@ -119,6 +137,39 @@ function RenderContent({ part }: { part: Part }) {
   return null;
 }
 
+function SessionResults({ sessionIds }: { sessionIds: string[] }) {
+  if (sessionIds.length === 0) {
+    return (
+      <div className="text-xs text-muted-foreground flex justify-center items-center py-2">
+        No results found
+      </div>
+    );
+  }
+
+  return (
+    <div className="relative -mx-1">
+      <Carousel className="w-full" opts={{ align: "start" }}>
+        <CarouselContent className="-ml-2">
+          {sessionIds.map((sessionId) => (
+            <CarouselItem
+              key={sessionId}
+              className="pl-1 basis-full sm:basis-1/2 lg:basis-1/3"
+            >
+              <Card className="h-full bg-neutral-50">
+                <CardContent className="px-2 py-0.5">
+                  <RenderSession sessionId={sessionId} />
+                </CardContent>
+              </Card>
+            </CarouselItem>
+          ))}
+        </CarouselContent>
+        <CarouselPrevious className="-left-4 h-6 w-6 bg-neutral-100 hover:bg-neutral-200" />
+        <CarouselNext className="-right-4 h-6 w-6 bg-neutral-100 hover:bg-neutral-200" />
+      </Carousel>
+    </div>
+  );
+}
+
 function RenderSession({ sessionId }: { sessionId: string }) {
   const session = main.UI.useRow("sessions", sessionId, main.STORE_ID);
   const enhancedNoteIds = main.UI.useSliceRowIds(
@@ -29,6 +29,9 @@ interface ChatSessionProps {
   modelOverride?: LanguageModel;
   extraTools?: ToolSet;
   systemPromptOverride?: string;
+  // History kept outside tinybase, e.g. vault chats in the local database.
+  // `null` while it is loading; leave unset to load from tinybase.
+  storedMessages?: HyprUIMessage[] | null;
   children: (props: {
     sessionId: string;
     messages: HyprUIMessage[];
@@ -50,6 +53,7 @@ export function ChatSession({
   modelOverride,
   extraTools,
   systemPromptOverride,
+  storedMessages,
   children,
 }: ChatSessionProps) {
   const sessionEntity = useSessionContextEntity(currentSessionId);
@@ -88,6 +92,10 @@ export function ChatSession({
   );
 
   const initialMessages = useMemo((): HyprUIMessage[] => {
+    if (storedMessages !== undefined) {
+      return storedMessages ?? [];
+    }
+
     if (!store || !chatGroupId) {
       return [];
     }
@@ -113,7 +121,7 @@ export function ChatSession({
       }
     }
     return loaded;
-  }, [store, messageIds, chatGroupId]);
+  }, [store, messageIds, chatGroupId, storedMessages]);
 
   const {
     messages,
@@ -122,6 +130,7 @@ export function ChatSession({
     stop,
     status,
     error,
+    setMessages,
   } = useChat({
     id: sessionId,
     messages: initialMessages,
@@ -130,6 +139,12 @@ export function ChatSession({
     onError: console.error,
   });
 
+  useEffect(() => {
+    if (storedMessages?.length && messages.length === 0) {
+      setMessages(storedMessages);
+    }
+  }, [storedMessages, messages.length, setMessages]);
+
   useEffect(() => {
     if (!chatGroupId || !store) {
       return;
@@ -0,0 +1,171 @@
+import { useQuery, useQueryClient } from "@tanstack/react-query";
+import type { ChatStatus } from "ai";
+import { useEffect, useRef, useState } from "react";
+
+import { commands as db2Commands } from "@hypr/plugin-db2";
+import { commands as templateCommands } from "@hypr/plugin-template";
+
+import type { HyprUIMessage } from "../../chat/types";
+import * as main from "../../store/tinybase/store/main";
+
+const VAULT_CONVERSATIONS_KEY = "vault-conversations";
+const VAULT_MESSAGES_KEY = "vault-messages";
+
+export function useVaultConversationIds() {
+  const { user_id } = main.UI.useValues(main.STORE_ID);
+
+  return useQuery({
+    enabled: !!user_id,
+    queryKey: [VAULT_CONVERSATIONS_KEY, user_id],
+    queryFn: async () => {
+      const result = await db2Commands.listVaultConversations(user_id!);
+      if (result.status === "error") {
+        throw new Error(result.error);
+      }
+      return new Set(result.data.map((conversation) => conversation.id));
+    },
+  });
+}
+
+export function useVaultChatMessages(groupId: string | undefined) {
+  return useQuery({
+    enabled: !!groupId,
+    queryKey: [VAULT_MESSAGES_KEY, groupId],
+    queryFn: async (): Promise<HyprUIMessage[]> => {
+      const result = await db2Commands.listMessagesV2(groupId!);
+      if (result.status === "error") {
+        throw new Error(result.error);
+      }
+      return result.data.map((message) => {
+        let parts: HyprUIMessage["parts"] = [];
+        let metadata: Record<string, unknown> = {};
+        try {
+          parts = JSON.parse(message.parts);
+        } catch {}
+        try {
+          metadata = JSON.parse(message.metadata ?? "{}");
+        } catch {}
+        return {
+          id: message.id,
+          role: message.role,
+          parts,
+          metadata,
+        } as HyprUIMessage;
+      });
+    },
+  });
+}
+
+export function useVaultSystemPrompt(enabled: boolean) {
+  const language = main.UI.useValue("ai_language", main.STORE_ID) ?? "en";
+  const [systemPrompt, setSystemPrompt] = useState<string | undefined>();
+
+  useEffect(() => {
+    if (!enabled) {
+      setSystemPrompt(undefined);
+      return;
+    }
+
+    let stale = false;
+
+    void templateCommands
+      .render({ chatVaultSystem: { language } })
+      .then((result) => {
+        if (!stale) {
+          setSystemPrompt(result.status === "ok" ? result.data : "");
+        }
+      });
+
+    return () => {
+      stale = true;
+    };
+  }, [enabled, language]);
+
+  return systemPrompt;
+}
+
+// Vault chats keep their history in the local database, next to the
+// tinybase chat group that lists them in the header. It is read back through
+// `useVaultChatMessages` when the conversation is reopened.
+export function VaultChatHistory({
+  groupId,
+  messages,
+  status,
+}: {
+  groupId: string | undefined;
+  messages: HyprUIMessage[];
+  status: ChatStatus;
+}) {
+  const { user_id } = main.UI.useValues(main.STORE_ID);
+  const title = main.UI.useCell(
+    "chat_groups",
+    groupId ?? "",
+    "title",
+    main.STORE_ID,
+  );
+  const { data: conversationIds } = useVaultConversationIds();
+  const queryClient = useQueryClient();
+  const pending = useRef<Promise<void>>(Promise.resolve());
+  const created = useRef(new Set<string>());
+
+  useEffect(() => {
+    if (!groupId || !user_id || !conversationIds || status !== "ready") {
+      return;
+    }
+
+    pending.current = pending.current
+      .then(async () => {
+        if (!conversationIds.has(groupId) && !created.current.has(groupId)) {
+          created.current.add(groupId);
+          const now = new Date().toISOString();
+          await db2Commands.createConversation({
+            id: groupId,
+            session_id: null,
+            user_id,
+            name: title ?? null,
+            created_at: now,
+            updated_at: now,
+          });
+          await queryClient.invalidateQueries({
+            queryKey: [VAULT_CONVERSATIONS_KEY],
+          });
+        }
+
+        const stored = await db2Commands.listMessagesV2(groupId);
+        const storedParts = new Map(
+          stored.status === "ok"
+            ? stored.data.map((message) => [message.id, message.parts])
+            : [],
+        );
+
+        for (const message of messages) {
+          const parts = JSON.stringify(message.parts);
+          const previous = storedParts.get(message.id);
+
+          if (previous === undefined) {
+            const now = new Date().toISOString();
+            await db2Commands.createMessageV2({
+              id: message.id,
+              conversation_id: groupId,
+              role: message.role,
+              parts,
+              metadata: message.metadata
+                ? JSON.stringify(message.metadata)
+                : null,
+              created_at: now,
+              updated_at: now,
+            });
+          } else if (previous !== parts) {
+            await db2Commands.updateMessageV2Parts(message.id, parts);
+          }
+        }
+
+        await queryClient.invalidateQueries({
+          queryKey: [VAULT_MESSAGES_KEY, groupId],
+        });
+      })
+      .catch(console.error);
+  }, [groupId, user_id, title, conversationIds, messages, status, queryClient]);
+
+  return null;
+}
@@ -1,4 +1,4 @@
-import { useCallback } from "react";
+import { useCallback, useState } from "react";
 
 import { useShell } from "../../contexts/shell";
 import { useLanguageModel } from "../../hooks/useLLMConnection";
@@ -8,6 +8,12 @@ import { ChatContent } from "./content";
 import { ChatHeader } from "./header";
 import { ChatSession } from "./session";
 import { useChatActions, useStableSessionId } from "./use-chat-actions";
+import {
+  useVaultChatMessages,
+  useVaultConversationIds,
+  useVaultSystemPrompt,
+  VaultChatHistory,
+} from "./vault";
 
 export function ChatView() {
   const { chat } = useShell();
@@ -20,6 +26,13 @@ export function ChatView() {
   const stableSessionId = useStableSessionId(groupId);
   const model = useLanguageModel();
 
+  const [vaultMode, setVaultMode] = useState(false);
+  const { data: vaultConversationIds } = useVaultConversationIds();
+  const vaultSystemPrompt = useVaultSystemPrompt(vaultMode);
+  const { data: vaultMessages } = useVaultChatMessages(
+    vaultMode ? groupId : undefined,
+  );
+
   const { handleSendMessage } = useChatActions({
     groupId,
     onGroupCreated: setGroupId,
@@ -31,42 +44,61 @@ export function ChatView() {
 
   const handleSelectChat = useCallback(
     (selectedGroupId: string) => {
+      setVaultMode(vaultConversationIds?.has(selectedGroupId) ?? false);
       setGroupId(selectedGroupId);
     },
-    [setGroupId],
+    [setGroupId, vaultConversationIds],
   );
 
+  const handleToggleVaultMode = useCallback(() => {
+    setVaultMode((prev) => !prev);
+    setGroupId(undefined);
+  }, [setGroupId]);
+
   return (
     <div className="flex flex-col h-full">
       <ChatHeader
         currentChatGroupId={groupId}
         onNewChat={handleNewChat}
         onSelectChat={handleSelectChat}
+        vaultMode={vaultMode}
+        onToggleVaultMode={handleToggleVaultMode}
         handleClose={() => chat.sendEvent({ type: "CLOSE" })}
       />
       <div className="bg-sky-100 text-neutral-900 text-[11px] px-3 py-1.5">
         Chat is Experimental and under active development
       </div>
       <ChatSession
-        key={stableSessionId}
+        key={`${stableSessionId}-${vaultMode}`}
         sessionId={stableSessionId}
         chatGroupId={groupId}
-        currentSessionId={currentSessionId}
+        currentSessionId={vaultMode ? undefined : currentSessionId}
+        systemPromptOverride={vaultMode ? vaultSystemPrompt : undefined}
+        storedMessages={vaultMode ? (vaultMessages ?? null) : undefined}
       >
         {(sessionProps) => (
-          <ChatContent
-            {...sessionProps}
-            model={model}
-            handleSendMessage={handleSendMessage}
-          >
-            <ChatBody
-              messages={sessionProps.messages}
-              status={sessionProps.status}
-              error={sessionProps.error}
-              onReload={sessionProps.regenerate}
-              isModelConfigured={!!model}
-            />
-          </ChatContent>
+          <>
+            {vaultMode && (
+              <VaultChatHistory
+                groupId={groupId}
+                messages={sessionProps.messages}
+                status={sessionProps.status}
+              />
+            )}
+            <ChatContent
+              {...sessionProps}
+              model={model}
+              handleSendMessage={handleSendMessage}
+            >
+              <ChatBody
+                messages={sessionProps.messages}
+                status={sessionProps.status}
+                error={sessionProps.error}
+                onReload={sessionProps.regenerate}
+                isModelConfigured={!!model}
+              />
+            </ChatContent>
+          </>
         )}
       </ChatSession>
     </div>
@@ -5,10 +5,12 @@ import { useHotkeys } from "react-hotkeys-hook";
 import { Kbd } from "@hypr/ui/components/ui/kbd";
 import { cn } from "@hypr/utils";
 
+import { useImportMedia } from "../../../../hooks/useImportMedia";
 import { type Tab, useTabs } from "../../../../store/zustand/tabs";
 import { useNewNote } from "../../shared";
 import { StandardTabWrapper } from "../index";
 import { type TabItem, TabItemBase } from "../shared";
+import { MediaTrackDialog, useTrackRequest } from "./media-track-dialog";
 import { OpenNoteDialog } from "./open-note-dialog";
 
 export const TabItemEmpty: TabItem<Extract<Tab, { type: "empty" }>> = ({
@@ -53,6 +55,8 @@ export function TabContentEmpty({
 
 function EmptyView() {
   const newNote = useNewNote({ behavior: "current" });
+  const trackRequest = useTrackRequest();
+  const { selectAndImport } = useImportMedia(trackRequest.chooseTrack);
   const openCurrent = useTabs((state) => state.openCurrent);
   const [openNoteDialogOpen, setOpenNoteDialogOpen] = useState(false);
 
@@ -93,6 +97,10 @@ function EmptyView() {
           shortcut={["⌘", "O"]}
           onClick={() => setOpenNoteDialogOpen(true)}
         />
+        <ActionItem
+          label="Import Recordings"
+          onClick={() => void selectAndImport()}
+        />
         <div className="h-px bg-neutral-200 my-1" />
         <ActionItem
           label="Contacts"
@@ -125,6 +133,10 @@ function EmptyView() {
         open={openNoteDialogOpen}
         onOpenChange={setOpenNoteDialogOpen}
       />
+      <MediaTrackDialog
+        request={trackRequest.request}
+        onResolve={trackRequest.resolve}
+      />
     </div>
   );
 }
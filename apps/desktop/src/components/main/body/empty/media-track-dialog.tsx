@@ -0,0 +1,173 @@
+import { useCallback, useState } from "react";
+
+import type {
+  MediaTrackInfo,
+  MediaTrackSelection,
+} from "@hypr/plugin-fs-sync";
+import { Button } from "@hypr/ui/components/ui/button";
+import {
+  Dialog,
+  DialogContent,
+  DialogDescription,
+  DialogFooter,
+  DialogHeader,
+  DialogTitle,
+} from "@hypr/ui/components/ui/dialog";
+import { cn } from "@hypr/utils";
+
+export type TrackRequest = {
+  path: string;
+  tracks: MediaTrackInfo[];
+  resolve: (selection: MediaTrackSelection | null) => void;
+};
+
+// Lets `useImportMedia` ask which track to import and wait for the answer.
+export function useTrackRequest() {
+  const [request, setRequest] = useState<TrackRequest | null>(null);
+
+  const chooseTrack = useCallback(
+    (path: string, tracks: MediaTrackInfo[]) =>
+      new Promise<MediaTrackSelection | null>((resolve) => {
+        setRequest({ path, tracks, resolve });
+      }),
+    [],
+  );
+
+  const resolve = useCallback(
+    (selection: MediaTrackSelection | null) => {
+      request?.resolve(selection);
+      setRequest(null);
+    },
+    [request],
+  );
+
+  return { request, chooseTrack, resolve };
+}
+
+export function MediaTrackDialog({
+  request,
+  onResolve,
+}: {
+  request: TrackRequest | null;
+  onResolve: (selection: MediaTrackSelection | null) => void;
+}) {
+  return (
+    <Dialog
+      open={!!request}
+      onOpenChange={(open) => {
+        if (!open) {
+          onResolve(null);
+        }
+      }}
+    >
+      <DialogContent>
+        {request && (
+          <TrackPicker
+            key={request.path}
+            request={request}
+            onResolve={onResolve}
+          />
+        )}
+      </DialogContent>
+    </Dialog>
+  );
+}
+
+function TrackPicker({
+  request,
+  onResolve,
+}: {
+  request: TrackRequest;
+  onResolve: (selection: MediaTrackSelection | null) => void;
+}) {
+  const defaultTrack = request.tracks.find((track) => track.isDefault);
+  const [selected, setSelected] = useState<MediaTrackSelection>(
+    defaultTrack ? { type: "track", id: defaultTrack.id } : { type: "mixAll" },
+  );
+
+  const fileName = request.path.split(/[\\/]/).pop() ?? request.path;
+
+  return (
+    <>
+      <DialogHeader>
+        <DialogTitle>Choose an audio track</DialogTitle>
+        <DialogDescription>
+          {fileName} has {request.tracks.length} audio tracks.
+        </DialogDescription>
+      </DialogHeader>
+      <div className="flex flex-col gap-1">
+        {request.tracks.map((track) => (
+          <TrackOption
+            key={track.id}
+            label={trackLabel(track)}
+            detail={trackDetail(track)}
+            checked={selected.type === "track" && selected.id === track.id}
+            onSelect={() => setSelected({ type: "track", id: track.id })}
+          />
+        ))}
+        <TrackOption
+          label="All tracks"
+          detail="Mix every track together"
+          checked={selected.type === "mixAll"}
+          onSelect={() => setSelected({ type: "mixAll" })}
+        />
+      </div>
+      <DialogFooter>
+        <Button variant="outline" onClick={() => onResolve(null)}>
+          Cancel
+        </Button>
+        <Button onClick={() => onResolve(selected)}>Import</Button>
+      </DialogFooter>
+    </>
+  );
+}
+
+function TrackOption({
+  label,
+  detail,
+  checked,
+  onSelect,
+}: {
+  label: string;
+  detail: string;
+  checked: boolean;
+  onSelect: () => void;
+}) {
+  return (
+    <button
+      type="button"
+      onClick={onSelect}
+      className={cn([
+        "flex flex-col items-start rounded-md border px-3 py-2 text-left",
+        checked
+          ? "border-neutral-900 bg-neutral-50"
+          : "border-neutral-200 hover:bg-neutral-50",
+      ])}
+    >
+      <span className="text-sm font-medium">{label}</span>
+      <span className="text-xs text-neutral-500">{detail}</span>
+    </button>
+  );
+}
+
+function trackLabel(track: MediaTrackInfo) {
+  const label = `Track ${track.id}`;
+  return track.isDefault ? `${label} (default)` : label;
+}
+
+function trackDetail(track: MediaTrackInfo) {
+  return [
+    track.codec,
+    track.language,
+    track.channels ? `${track.channels} ch` : null,
+    track.durationSecs ? formatDuration(track.durationSecs) : null,
+  ]
+    .filter(Boolean)
+    .join(" · ");
+}
+
+function formatDuration(secs: number) {
+  const minutes = Math.floor(secs / 60);
+  const seconds = Math.round(secs % 60);
+  return `${minutes}:${seconds.toString().padStart(2, "0")}`;
+}
@@ -25,7 +25,9 @@ import { useAITask } from "../../../../../contexts/ai-task";
 import { useListener } from "../../../../../contexts/listener";
 import { fromResult } from "../../../../../effect";
 import { getEligibility } from "../../../../../hooks/autoEnhance/eligibility";
+import { useActionItemExtraction } from "../../../../../hooks/useActionItemExtraction";
 import { useCreateEnhancedNote } from "../../../../../hooks/useEnhancedNotes";
+import { IMPORTABLE_MEDIA_EXTENSIONS } from "../../../../../hooks/useImportMedia";
 import { useLanguageModel } from "../../../../../hooks/useLLMConnection";
 import { useRunBatch } from "../../../../../hooks/useRunBatch";
 import * as main from "../../../../../store/tinybase/store/main";
@@ -63,6 +65,7 @@ export function OptionsMenu({
   const createEnhancedNote = useCreateEnhancedNote();
   const model = useLanguageModel();
   const generate = useAITask((state) => state.generate);
+  const extractActionItems = useActionItemExtraction();
   const sessionTab = useTabs((state) => {
     const found = state.tabs.find(
       (tab): tab is Extract<Tab, { type: "sessions" }> =>
@@ -128,6 +131,10 @@ export function OptionsMenu({
               },
             });
           }
+
+          if (model) {
+            extractActionItems(model, sessionId, enhancedNoteId);
+          }
         } catch (error) {
           console.error("Failed to convert markdown to JSON:", error);
         }
@@ -142,6 +149,7 @@ export function OptionsMenu({
     sessionTab,
     updateSessionTabState,
     generate,
+    extractActionItems,
   ]);
 
   const handleFilePath = useCallback(
@@ -217,12 +225,9 @@ export function OptionsMenu({
       }
 
       if (
-        !normalizedPath.endsWith(".wav") &&
-        !normalizedPath.endsWith(".mp3") &&
-        !normalizedPath.endsWith(".ogg") &&
-        !normalizedPath.endsWith(".mp4") &&
-        !normalizedPath.endsWith(".m4a") &&
-        !normalizedPath.endsWith(".flac")
+        !IMPORTABLE_MEDIA_EXTENSIONS.some((ext) =>
+          normalizedPath.endsWith(`.${ext}`),
+        )
       ) {
         return Effect.void;
       }
@@ -332,8 +337,8 @@ export function OptionsMenu({
         title: "Upload Audio",
         filters: [
           {
-            name: "Audio",
-            extensions: ["wav", "mp3", "ogg", "mp4", "m4a", "flac"],
+            name: "Audio & Video",
+            extensions: IMPORTABLE_MEDIA_EXTENSIONS,
           },
         ],
       },
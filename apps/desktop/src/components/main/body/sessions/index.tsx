@@ -9,6 +9,7 @@ import { commands as fsSyncCommands } from "@hypr/plugin-fs-sync";
 import { cn } from "@hypr/utils";
 
 import AudioPlayer from "../../../../contexts/audio-player";
+import { useAudioPlayer } from "../../../../contexts/audio-player/provider";
 import { useListener } from "../../../../contexts/listener";
 import { useShell } from "../../../../contexts/shell";
 import { useAutoEnhance } from "../../../../hooks/useAutoEnhance";
@@ -205,6 +206,7 @@ function TabContentNoteInner({
   const prevSessionMode = useRef<string | null>(sessionMode);
 
   useAutoFocusTitle({ sessionId, titleInputRef });
+  useSeekOnOpen(tab);
 
   useEffect(() => {
     const justStartedListening =
@@ -342,6 +344,22 @@ function StatusBanner({
   );
 }
 
+function useSeekOnOpen(tab: Extract<Tab, { type: "sessions" }>) {
+  const { seek, time } = useAudioPlayer();
+  const updateSessionTabState = useTabs((state) => state.updateSessionTabState);
+  const seekMs = tab.state.seekMs;
+  const loaded = time.total > 0;
+
+  useEffect(() => {
+    if (seekMs == null || !loaded) {
+      return;
+    }
+
+    seek(seekMs / 1000);
+    updateSessionTabState(tab, { ...tab.state, seekMs: null });
+  }, [seekMs, loaded, seek, tab, updateSessionTabState]);
+}
+
 function useAutoFocusTitle({
   sessionId,
   titleInputRef,
@@ -20,6 +20,8 @@ export function StreamingView({ enhancedNoteId }: { enhancedNoteId: string }) {
   if (isGenerating && !hasContent) {
     if (step?.type === "analyzing") {
       statusText = "Analyzing structure...";
+    } else if (step?.type === "extracting") {
+      statusText = `Reading transcript (part ${step.chunk} of ${step.total})...`;
     } else if (step?.type === "generating") {
       statusText = "Generating...";
     } else if (step?.type === "retrying") {
@@ -16,6 +16,7 @@ import {
   useScrollDetection,
 } from "./hooks";
 import { Operations } from "./operations";
+import { QualityWarnings } from "./quality-warnings";
 import { RenderTranscript } from "./render-transcript";
 import { SelectionMenu } from "./selection-menu";
 
@@ -133,7 +134,10 @@ export function TranscriptContainer({
       return <DegradedState error={degraded} />;
     }
     return (
-      <TranscriptEmptyState isBatching={sessionMode === "running_batch"} />
+      <div className="flex flex-col h-full">
+        {currentActive && <QualityWarnings />}
+        <TranscriptEmptyState isBatching={sessionMode === "running_batch"} />
+      </div>
     );
   }
 
@@ -154,6 +158,7 @@ export function TranscriptContainer({
         ])}
       >
         {currentActive && degraded && <DegradedState error={degraded} />}
+        {currentActive && <QualityWarnings />}
         {transcriptIds.map((transcriptId, index) => (
           <div key={transcriptId} className="flex flex-col gap-8">
             <RenderTranscript
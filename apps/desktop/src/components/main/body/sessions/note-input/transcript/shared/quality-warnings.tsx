@@ -0,0 +1,55 @@
+import { AudioLines } from "lucide-react";
+
+import type { AudioQualityIssue } from "@hypr/plugin-listener";
+import { cn } from "@hypr/utils";
+
+import { useListener } from "../../../../../../../contexts/listener";
+
+function issueMessage(issue: AudioQualityIssue): string {
+  const channel =
+    "channel" in issue
+      ? issue.channel === "mic"
+        ? "Microphone"
+        : "System audio"
+      : null;
+
+  switch (issue.kind) {
+    case "clipping":
+      return `${channel} is clipping. Try lowering the input volume.`;
+    case "silence":
+      return `${channel} is not picking up any sound.`;
+    case "dc_offset":
+      return `${channel} signal looks distorted.`;
+    case "echo_residual":
+      return "Speaker audio is leaking into the microphone. Headphones help.";
+    case "sample_rate_drift":
+      return "Microphone and system audio are drifting out of sync.";
+  }
+}
+
+export function QualityWarnings() {
+  const issues = useListener((state) => state.live.qualityIssues);
+
+  if (issues.length === 0) {
+    return null;
+  }
+
+  return (
+    <div
+      className={cn([
+        "flex flex-col gap-1 px-3 py-2 rounded-md",
+        "bg-amber-50 text-amber-800 text-xs",
+      ])}
+    >
+      {issues.map((issue) => (
+        <div
+          key={`${issue.kind}-${"channel" in issue ? issue.channel : ""}`}
+          className="flex items-center gap-2"
+        >
+          <AudioLines size={14} className="shrink-0 text-amber-500" />
+          <span>{issueMessage(issue)}</span>
+        </div>
+      ))}
+    </div>
+  );
+}
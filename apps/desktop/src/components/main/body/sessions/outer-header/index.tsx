@@ -1,6 +1,7 @@
 import type { EditorView } from "../../../../../store/zustand/tabs/schema";
 import { FolderChain } from "./folder";
 import { ListenButton } from "./listen";
+import { MeetingMatchPrompt } from "./meeting-match";
 import { MetadataButton } from "./metadata";
 import { OverflowButton } from "./overflow";
 
@@ -24,6 +25,7 @@ export function OuterHeader({
           <OverflowButton sessionId={sessionId} currentView={currentView} />
         </div>
       </div>
+      <MeetingMatchPrompt sessionId={sessionId} />
     </div>
   );
 }
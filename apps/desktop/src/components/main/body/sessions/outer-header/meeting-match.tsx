@@ -0,0 +1,48 @@
+import { CalendarIcon, VideoIcon, XIcon } from "lucide-react";
+
+import { Button } from "@hypr/ui/components/ui/button";
+
+import { useMeetingMatch } from "../../../../../hooks/useMeetingMatch";
+
+export function MeetingMatchPrompt({ sessionId }: { sessionId: string }) {
+  const { candidates, link, dismiss } = useMeetingMatch(sessionId);
+
+  if (candidates.length === 0) {
+    return null;
+  }
+
+  return (
+    <div className="mt-1 flex items-center gap-2 rounded-md border bg-neutral-50 px-2 py-1 text-xs text-neutral-600">
+      <span className="shrink-0">Link to event:</span>
+      <div className="flex min-w-0 flex-1 items-center gap-1 overflow-x-auto">
+        {candidates.map((candidate) => (
+          <Button
+            key={candidate.event_id}
+            variant="outline"
+            size="sm"
+            className="h-6 max-w-50 gap-1 bg-white px-2 text-xs"
+            onClick={() => link(candidate.event_id)}
+          >
+            {candidate.conference ? (
+              <VideoIcon size={12} className="shrink-0" />
+            ) : (
+              <CalendarIcon size={12} className="shrink-0" />
+            )}
+            <span className="truncate">
+              {candidate.title || "Untitled Event"}
+            </span>
+          </Button>
+        ))}
+      </div>
+      <Button
+        variant="ghost"
+        size="icon"
+        className="size-6 shrink-0"
+        onClick={dismiss}
+        aria-label="Dismiss"
+      >
+        <XIcon size={12} />
+      </Button>
+    </div>
+  );
+}
@@ -0,0 +1,160 @@
+import { useMutation } from "@tanstack/react-query";
+import { downloadDir, join } from "@tauri-apps/api/path";
+import { save as selectSavePath } from "@tauri-apps/plugin-dialog";
+import { DownloadIcon, Loader2Icon } from "lucide-react";
+
+import { commands as analyticsCommands } from "@hypr/plugin-analytics";
+import {
+  commands as fsSyncCommands,
+  type ExportFormat,
+} from "@hypr/plugin-fs-sync";
+import { commands as openerCommands } from "@hypr/plugin-opener2";
+import {
+  DropdownMenuItem,
+  DropdownMenuSub,
+  DropdownMenuSubContent,
+  DropdownMenuSubTrigger,
+} from "@hypr/ui/components/ui/dropdown-menu";
+
+import * as main from "../../../../../../store/tinybase/store/main";
+import { save } from "../../../../../../store/tinybase/store/save";
+
+const SUBTITLE_OPTIONS = {
+  speakers: true,
+  maxCharsPerCue: 84,
+  maxCueDurationMs: 7000,
+};
+
+const EXPORT_FORMATS: {
+  label: string;
+  extension: string;
+  format: ExportFormat;
+}[] = [
+  {
+    label: "Word (.docx)",
+    extension: "docx",
+    format: {
+      format: "docx",
+      options: { includeMemo: true, includeTranscript: true, timestamps: true },
+    },
+  },
+  {
+    label: "PDF",
+    extension: "pdf",
+    format: {
+      format: "pdf",
+      options: { includeCover: true, includeTranscript: true },
+    },
+  },
+  {
+    label: "Web page (.html)",
+    extension: "html",
+    format: {
+      format: "html",
+      options: { includeMemo: true, includeTranscript: true, timestamps: true },
+    },
+  },
+  {
+    label: "Subtitles (.srt)",
+    extension: "srt",
+    format: { format: "srt", options: SUBTITLE_OPTIONS },
+  },
+  {
+    label: "Subtitles (.vtt)",
+    extension: "vtt",
+    format: { format: "vtt", options: SUBTITLE_OPTIONS },
+  },
+  {
+    label: "Plain text transcript",
+    extension: "txt",
+    format: { format: "text", options: { timestamps: true, speakers: true } },
+  },
+  {
+    label: "JSON",
+    extension: "json",
+    format: { format: "json" },
+  },
+  {
+    label: "Bundle with audio (.zip)",
+    extension: "zip",
+    format: { format: "bundle", options: { includeAudio: true } },
+  },
+];
+
+export function ExportSession({ sessionId }: { sessionId: string }) {
+  const sessionTitle = main.UI.useCell(
+    "sessions",
+    sessionId,
+    "title",
+    main.STORE_ID,
+  ) as string | undefined;
+
+  const { mutate, isPending } = useMutation({
+    mutationFn: async (target: (typeof EXPORT_FORMATS)[number]) => {
+      const sanitizedTitle = (
+        (sessionTitle ?? "Untitled").trim() || "Untitled"
+      ).replace(/[<>:"/\\|?*]/g, "_");
+      const path = await selectSavePath({
+        title: "Export note",
+        defaultPath: await join(
+          await downloadDir(),
+          `${sanitizedTitle}.${target.extension}`,
+        ),
+        filters: [{ name: target.label, extensions: [target.extension] }],
+      });
+      if (!path) {
+        return null;
+      }
+
+      // The exporter reads the session folder, so flush pending edits first.
+      await save();
+
+      const result = await fsSyncCommands.exportSession(
+        sessionId,
+        target.format,
+        path,
+      );
+      if (result.status === "error") {
+        throw new Error(result.error);
+      }
+
+      return { path, format: target.format.format };
+    },
+    onSuccess: (exported) => {
+      if (!exported) {
+        return;
+      }
+
+      void analyticsCommands.event({
+        event: "session_exported",
+        format: exported.format,
+      });
+      void openerCommands.revealItemInDir(exported.path);
+    },
+    onError: console.error,
+  });
+
+  return (
+    <DropdownMenuSub>
+      <DropdownMenuSubTrigger className="cursor-pointer" disabled={isPending}>
+        {isPending ? (
+          <Loader2Icon className="animate-spin" />
+        ) : (
+          <DownloadIcon />
+        )}
+        <span>{isPending ? "Exporting..." : "Export as"}</span>
+      </DropdownMenuSubTrigger>
+      <DropdownMenuSubContent>
+        {EXPORT_FORMATS.map((target) => (
+          <DropdownMenuItem
+            key={target.extension}
+            onClick={() => mutate(target)}
+            className="cursor-pointer"
+          >
+            <span>{target.label}</span>
+          </DropdownMenuItem>
+        ))}
+      </DropdownMenuSubContent>
+    </DropdownMenuSub>
+  );
+}
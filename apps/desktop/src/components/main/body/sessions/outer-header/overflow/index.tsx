@@ -15,6 +15,7 @@ import type { EditorView } from "../../../../../../store/zustand/tabs/schema";
 import { useHasTranscript } from "../../shared";
 import { DeleteNote, DeleteRecording } from "./delete";
 import { ExportPDF } from "./export-pdf";
+import { ExportSession } from "./export-session";
 import { ExportTranscript } from "./export-transcript";
 import { Listening } from "./listening";
 import { Copy, Folder, ShowInFinder } from "./misc";
@@ -55,6 +56,7 @@ export function OverflowButton({
         <Folder sessionId={sessionId} setOpen={setOpen} />
         <ExportPDF sessionId={sessionId} currentView={currentView} />
         {hasTranscript && <ExportTranscript sessionId={sessionId} />}
+        <ExportSession sessionId={sessionId} />
         <DropdownMenuSeparator />
         <Listening sessionId={sessionId} />
         <DropdownMenuSeparator />
@@ -0,0 +1,86 @@
+import { useEffect, useMemo, useState } from "react";
+
+import type { UserTemplatePreview } from "@hypr/plugin-template";
+import { commands as templateCommands } from "@hypr/plugin-template";
+import { Textarea } from "@hypr/ui/components/ui/textarea";
+
+import * as main from "../../../../store/tinybase/store/main";
+
+export function TemplateBodyEditor({
+  value,
+  onChange,
+}: {
+  value: string;
+  onChange: (value: string) => void;
+}) {
+  const snippetRows = main.UI.useTable("template_snippets", main.STORE_ID);
+  const snippets = useMemo(() => {
+    const result: Record<string, string> = {};
+    for (const row of Object.values(snippetRows)) {
+      if (row.name?.trim() && typeof row.content === "string") {
+        result[row.name.trim()] = row.content;
+      }
+    }
+    return result;
+  }, [snippetRows]);
+
+  const [preview, setPreview] = useState<UserTemplatePreview | null>(null);
+
+  useEffect(() => {
+    if (!value.trim()) {
+      setPreview(null);
+      return;
+    }
+
+    let cancelled = false;
+    const timeout = setTimeout(() => {
+      void templateCommands
+        .previewUserTemplate(value, snippets, null)
+        .then((result) => {
+          if (!cancelled && result.status === "ok") {
+            setPreview(result.data);
+          }
+        });
+    }, 300);
+
+    return () => {
+      cancelled = true;
+      clearTimeout(timeout);
+    };
+  }, [value, snippets]);
+
+  return (
+    <div className="flex flex-col gap-3">
+      <Textarea
+        value={value}
+        onChange={(e) => onChange(e.target.value)}
+        placeholder={
+          "{% for p in participants %}\n## {{ p.name }}\n{% endfor %}"
+        }
+        className="font-mono text-xs min-h-[160px]"
+      />
+
+      {preview && preview.diagnostics.length > 0 && (
+        <ul className="flex flex-col gap-1">
+          {preview.diagnostics.map((d, index) => (
+            <li key={index} className="text-xs text-red-600 font-mono">
+              {d.snippet ? `${d.snippet}:` : ""}
+              {d.line}:{d.column}: {d.message}
+            </li>
+          ))}
+        </ul>
+      )}
+
+      {preview?.output != null && (
+        <div>
+          <h4 className="text-xs font-medium text-neutral-500 mb-1">
+            Preview with a sample meeting
+          </h4>
+          <pre className="text-xs whitespace-pre-wrap bg-neutral-50 border border-neutral-200 rounded-xs p-3">
+            {preview.output}
+          </pre>
+        </div>
+      )}
+    </div>
+  );
+}
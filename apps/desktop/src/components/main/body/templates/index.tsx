@@ -43,6 +43,7 @@ import { StandardTabWrapper } from "../index";
 import { useWebResources } from "../resource-list";
 import { type TabItem, TabItemBase } from "../shared";
 import { TemplateDetailsColumn } from "./details";
+import { TemplateSnippets } from "./snippets";
 
 export const TabItemTemplate: TabItem<Extract<Tab, { type: "templates" }>> = ({
   tab,
@@ -856,6 +857,7 @@ function TemplateListColumn({
           ))
         )}
       </div>
+      {!isWebMode && <TemplateSnippets />}
     </div>
   );
 }
@@ -0,0 +1,164 @@
+import { ChevronDown, ChevronRight, Plus, Trash2 } from "lucide-react";
+import { useCallback, useMemo, useState } from "react";
+
+import { Button } from "@hypr/ui/components/ui/button";
+import { Input } from "@hypr/ui/components/ui/input";
+import { Textarea } from "@hypr/ui/components/ui/textarea";
+import { cn } from "@hypr/utils";
+
+import * as main from "../../../../store/tinybase/store/main";
+
+type Snippet = { id: string; name: string; content: string };
+
+export function TemplateSnippets() {
+  const { user_id } = main.UI.useValues(main.STORE_ID);
+  const rows = main.UI.useTable("template_snippets", main.STORE_ID);
+  const [expandedId, setExpandedId] = useState<string | null>(null);
+
+  const snippets = useMemo(
+    () =>
+      Object.entries(rows)
+        .map(([id, row]) => ({
+          id,
+          name: typeof row.name === "string" ? row.name : "",
+          content: typeof row.content === "string" ? row.content : "",
+        }))
+        .sort((a, b) => a.name.localeCompare(b.name)),
+    [rows],
+  );
+
+  const addSnippet = main.UI.useSetRowCallback(
+    "template_snippets",
+    (p: { id: string; user_id: string; name: string }) => p.id,
+    (p: { id: string; user_id: string; name: string }) => ({
+      user_id: p.user_id,
+      name: p.name,
+      content: "",
+    }),
+    [],
+    main.STORE_ID,
+  );
+
+  const handleAdd = useCallback(() => {
+    if (!user_id) return;
+
+    const taken = new Set(snippets.map((s) => s.name));
+    let name = "snippet";
+    for (let i = 2; taken.has(name); i++) {
+      name = `snippet-${i}`;
+    }
+
+    const id = crypto.randomUUID();
+    addSnippet({ id, user_id, name });
+    setExpandedId(id);
+  }, [user_id, snippets, addSnippet]);
+
+  return (
+    <div className="border-t border-neutral-200 max-h-[50%] flex flex-col">
+      <div className="py-2 pl-3 pr-1 flex items-center justify-between h-10 shrink-0">
+        <span className="text-sm font-medium">Snippets</span>
+        <Button
+          size="icon"
+          variant="ghost"
+          onClick={handleAdd}
+          className="text-neutral-600 hover:text-black"
+        >
+          <Plus size={16} />
+        </Button>
+      </div>
+      <div className="overflow-y-auto px-2 pb-2">
+        {snippets.length === 0 ? (
+          <p className="px-3 py-2 text-xs text-neutral-500">
+            Reusable blocks you can pull into a template's structure with{" "}
+            <code>{'{% include "name" %}'}</code>.
+          </p>
+        ) : (
+          snippets.map((snippet) => (
+            <SnippetRow
+              key={snippet.id}
+              snippet={snippet}
+              expanded={expandedId === snippet.id}
+              onToggle={() =>
+                setExpandedId((current) =>
+                  current === snippet.id ? null : snippet.id,
+                )
+              }
+            />
+          ))
+        )}
+      </div>
+    </div>
+  );
+}
+
+function SnippetRow({
+  snippet,
+  expanded,
+  onToggle,
+}: {
+  snippet: Snippet;
+  expanded: boolean;
+  onToggle: () => void;
+}) {
+  const setSnippet = main.UI.useSetPartialRowCallback(
+    "template_snippets",
+    snippet.id,
+    (row: { name?: string; content?: string }) => row,
+    [snippet.id],
+    main.STORE_ID,
+  ) as (row: { name?: string; content?: string }) => void;
+
+  const deleteSnippet = main.UI.useDelRowCallback(
+    "template_snippets",
+    snippet.id,
+    main.STORE_ID,
+  );
+
+  return (
+    <div
+      className={cn([
+        "rounded-md border",
+        expanded ? "border-neutral-200 bg-neutral-50" : "border-transparent",
+      ])}
+    >
+      <button
+        onClick={onToggle}
+        className="w-full flex items-center gap-2 px-3 py-2 rounded-md text-left text-sm hover:bg-neutral-100"
+      >
+        {expanded ? (
+          <ChevronDown className="h-4 w-4 text-neutral-500 shrink-0" />
+        ) : (
+          <ChevronRight className="h-4 w-4 text-neutral-500 shrink-0" />
+        )}
+        <span className="font-mono text-xs truncate">
+          {snippet.name || "unnamed"}
+        </span>
+      </button>
+      {expanded && (
+        <div className="flex flex-col gap-2 px-3 pb-3">
+          <Input
+            value={snippet.name}
+            onChange={(e) => setSnippet({ name: e.target.value })}
+            placeholder="Name"
+            className="font-mono text-xs h-8"
+          />
+          <Textarea
+            value={snippet.content}
+            onChange={(e) => setSnippet({ content: e.target.value })}
+            placeholder={
+              "## Attendees\n{% for p in participants %}\n- {{ p.name }}\n{% endfor %}"
+            }
+            className="font-mono text-xs min-h-[120px]"
+          />
+          <button
+            onClick={deleteSnippet}
+            className="self-end flex items-center gap-1 text-xs text-red-600 hover:text-red-700"
+          >
+            <Trash2 className="h-3 w-3" />
+            Delete
+          </button>
+        </div>
+      )}
+    </div>
+  );
+}
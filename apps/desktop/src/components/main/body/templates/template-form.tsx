@@ -6,6 +6,7 @@ import { Textarea } from "@hypr/ui/components/ui/textarea";
 
 import * as main from "../../../../store/tinybase/store/main";
 import { DangerZone } from "../resource-list";
+import { TemplateBodyEditor } from "./body-editor";
 import { RelatedSessions } from "./related-sessions";
 import { SectionsList } from "./sections-editor";
 
@@ -49,6 +50,7 @@ function normalizeTemplatePayload(template: unknown): Template {
       typeof record.description === "string" ? record.description : "",
     sections,
     targets,
+    body: typeof record.body === "string" ? record.body : undefined,
   };
 }
 
@@ -80,6 +82,7 @@ export function TemplateForm({
       title: value?.title ?? "",
       description: value?.description ?? "",
       sections: value?.sections ?? [],
+      body: value?.body ?? "",
     },
     listeners: {
       onChange: ({ formApi }) => {
@@ -160,6 +163,24 @@ export function TemplateForm({
           </form.Field>
         </div>
 
+        <div className="p-6 border-b border-neutral-200">
+          <h3 className="text-sm font-medium text-neutral-600 mb-1">
+            Structure
+          </h3>
+          <p className="text-xs text-neutral-500 mb-3">
+            Optional. Lay out the note with variables, conditions, loops over
+            participants and shared snippets. Used instead of the sections.
+          </p>
+          <form.Field name="body">
+            {(field) => (
+              <TemplateBodyEditor
+                value={field.state.value}
+                onChange={(body) => field.handleChange(body)}
+              />
+            )}
+          </form.Field>
+        </div>
+
         <div className="p-6 border-b border-neutral-200">
           <h3 className="text-sm font-medium text-neutral-600 mb-4">
             Related Notes
@@ -0,0 +1,165 @@
+import { useForm } from "@tanstack/react-form";
+import { useState } from "react";
+
+import { commands as caldavCommands } from "@hypr/plugin-caldav";
+import { type CalendarAccount, calendarAccountSchema } from "@hypr/store";
+import { Button } from "@hypr/ui/components/ui/button";
+import { ButtonGroup } from "@hypr/ui/components/ui/button-group";
+import {
+  InputGroup,
+  InputGroupInput,
+} from "@hypr/ui/components/ui/input-group";
+
+import * as settings from "../../../../../store/tinybase/store/settings";
+import { toCalDavAccount } from "../../../../../utils/calendar";
+
+export function AddAccountForm() {
+  const settingsStore = settings.UI.useStore(settings.STORE_ID);
+  const [error, setError] = useState<string | null>(null);
+
+  const form = useForm({
+    defaultValues: {
+      kind: "caldav",
+      name: "",
+      url: "",
+      username: "",
+      password: "",
+    } as CalendarAccount,
+    validators: { onSubmit: calendarAccountSchema },
+    onSubmit: async ({ value, formApi }) => {
+      const account = toCalDavAccount(value);
+      if (!account || !settingsStore) {
+        return;
+      }
+
+      // Listing the calendars checks the URL and credentials before saving.
+      const result = await caldavCommands.listCalendars(account);
+      if (result.status === "error") {
+        setError(result.error);
+        return;
+      }
+
+      const host = new URL(value.url).host;
+      settingsStore.setRow("calendar_accounts", crypto.randomUUID(), {
+        ...value,
+        name: value.kind === "ics" ? (result.data[0]?.name ?? host) : host,
+      });
+      setError(null);
+      formApi.reset();
+    },
+  });
+
+  return (
+    <form
+      className="flex flex-col gap-3"
+      onSubmit={(e) => {
+        e.preventDefault();
+        e.stopPropagation();
+        void form.handleSubmit();
+      }}
+    >
+      <form.Field name="kind">
+        {(field) => (
+          <ButtonGroup>
+            {(["caldav", "ics"] as const).map((kind) => (
+              <Button
+                key={kind}
+                type="button"
+                size="sm"
+                variant={field.state.value === kind ? "default" : "outline"}
+                onClick={() => field.handleChange(kind)}
+                className="shadow-none"
+              >
+                {kind === "caldav" ? "CalDAV" : "ICS link"}
+              </Button>
+            ))}
+          </ButtonGroup>
+        )}
+      </form.Field>
+
+      <form.Subscribe selector={(state) => state.values.kind}>
+        {(kind) => (
+          <>
+            <form.Field name="url">
+              {(field) => (
+                <TextField
+                  value={field.state.value}
+                  onChange={field.handleChange}
+                  placeholder={
+                    kind === "caldav"
+                      ? "https://caldav.example.com/"
+                      : "webcal://example.com/calendar.ics"
+                  }
+                />
+              )}
+            </form.Field>
+            {kind === "caldav" && (
+              <div className="flex gap-2">
+                <form.Field name="username">
+                  {(field) => (
+                    <TextField
+                      value={field.state.value}
+                      onChange={field.handleChange}
+                      placeholder="Username"
+                    />
+                  )}
+                </form.Field>
+                <form.Field name="password">
+                  {(field) => (
+                    <TextField
+                      value={field.state.value}
+                      onChange={field.handleChange}
+                      placeholder="App password"
+                      type="password"
+                    />
+                  )}
+                </form.Field>
+              </div>
+            )}
+          </>
+        )}
+      </form.Subscribe>
+
+      <form.Subscribe
+        selector={(state) => [state.isSubmitting, state.errors] as const}
+      >
+        {([isSubmitting, errors]) => {
+          const message =
+            error ?? (errors.length > 0 ? "Enter a valid URL" : null);
+
+          return (
+            <div className="flex items-center justify-between gap-2">
+              <p className="text-destructive text-xs truncate">{message}</p>
+              <Button type="submit" size="sm" disabled={isSubmitting}>
+                {isSubmitting ? "Connecting..." : "Add"}
+              </Button>
+            </div>
+          );
+        }}
+      </form.Subscribe>
+    </form>
+  );
+}
+
+function TextField({
+  value,
+  onChange,
+  placeholder,
+  type,
+}: {
+  value: string;
+  onChange: (value: string) => void;
+  placeholder: string;
+  type?: string;
+}) {
+  return (
+    <InputGroup className="bg-white">
+      <InputGroupInput
+        type={type}
+        value={value}
+        onChange={(e) => onChange(e.target.value)}
+        placeholder={placeholder}
+      />
+    </InputGroup>
+  );
+}
@@ -0,0 +1,209 @@
+import { useQuery } from "@tanstack/react-query";
+import { RefreshCwIcon, TrashIcon } from "lucide-react";
+import { useCallback, useEffect, useMemo } from "react";
+
+import { commands as caldavCommands } from "@hypr/plugin-caldav";
+import { Button } from "@hypr/ui/components/ui/button";
+import { cn } from "@hypr/utils";
+
+import * as main from "../../../../../store/tinybase/store/main";
+import * as settings from "../../../../../store/tinybase/store/settings";
+import {
+  findCalendarByTrackingId,
+  toCalDavAccount,
+} from "../../../../../utils/calendar";
+import { Section } from "../apple";
+import { useSync } from "../apple/context";
+import { SyncIndicator } from "../apple/sync";
+import {
+  type CalendarGroup,
+  type CalendarItem,
+  CalendarSelection,
+} from "../shared";
+
+export function CalDavCalendarSelection({
+  calendarClassName,
+}: { calendarClassName?: string } = {}) {
+  const { groups, handleToggle, handleRefresh, handleRemove, isLoading } =
+    useCalDavCalendarSelection();
+
+  if (groups.length === 0) {
+    return null;
+  }
+
+  return (
+    <Section
+      title="Calendars"
+      action={
+        <div className="flex items-center gap-2">
+          <SyncIndicator />
+
+          <Button
+            variant="ghost"
+            size="icon"
+            onClick={handleRefresh}
+            className="size-6"
+            disabled={isLoading}
+          >
+            <RefreshCwIcon
+              className={cn(["size-3.5", isLoading && "animate-spin"])}
+            />
+          </Button>
+        </div>
+      }
+    >
+      <div className="pt-0.5"></div>
+      {groups.map(({ accountId, error, ...group }) => (
+        <div key={accountId} className="flex flex-col gap-1">
+          <div className="flex items-center justify-between">
+            <span className="text-sm font-medium truncate">
+              {group.sourceName}
+            </span>
+            <Button
+              variant="ghost"
+              size="icon"
+              onClick={() => handleRemove(accountId)}
+              className="size-6"
+            >
+              <TrashIcon className="size-3.5" />
+            </Button>
+          </div>
+          {error && <p className="text-destructive text-xs">{error}</p>}
+          <CalendarSelection
+            groups={[group]}
+            onToggle={handleToggle}
+            className={calendarClassName}
+          />
+        </div>
+      ))}
+    </Section>
+  );
+}
+
+export function useCalDavCalendarSelection() {
+  const { scheduleSync, scheduleDebouncedSync, cancelDebouncedSync } =
+    useSync();
+
+  const store = main.UI.useStore(main.STORE_ID);
+  const calendars = main.UI.useTable("calendars", main.STORE_ID);
+  const { user_id } = main.UI.useValues(main.STORE_ID);
+
+  const settingsStore = settings.UI.useStore(settings.STORE_ID);
+  const accounts = settings.UI.useTable("calendar_accounts", settings.STORE_ID);
+
+  const {
+    data: incomingCalendars,
+    refetch,
+    isFetching,
+  } = useQuery({
+    queryKey: ["caldavCalendars", accounts],
+    queryFn: () =>
+      Promise.all(
+        Object.entries(accounts).map(async ([accountId, row]) => {
+          const account = toCalDavAccount(row);
+          if (!account) {
+            return { accountId, calendars: [], error: "Invalid account" };
+          }
+
+          const result = await caldavCommands.listCalendars(account);
+          return result.status === "error"
+            ? { accountId, calendars: [], error: result.error }
+            : { accountId, calendars: result.data, error: null };
+        }),
+      ),
+  });
+
+  useEffect(() => {
+    if (!incomingCalendars || !store || !user_id) return;
+
+    store.transaction(() => {
+      for (const { accountId, calendars } of incomingCalendars) {
+        const kind = accounts[accountId]?.kind;
+        if (kind !== "caldav" && kind !== "ics") continue;
+
+        for (const cal of calendars) {
+          const existingRowId = findCalendarByTrackingId(store, cal.id);
+          const rowId = existingRowId ?? crypto.randomUUID();
+          const existing = existingRowId
+            ? store.getRow("calendars", existingRowId)
+            : null;
+
+          store.setRow("calendars", rowId, {
+            user_id,
+            created_at: existing?.created_at || new Date().toISOString(),
+            tracking_id_calendar: cal.id,
+            name: cal.name,
+            enabled: existing?.enabled ?? false,
+            provider: kind,
+            source: accountId,
+          });
+        }
+      }
+    });
+  }, [incomingCalendars, accounts, store, user_id]);
+
+  const groups = useMemo(() => {
+    const errors = new Map(
+      (incomingCalendars ?? []).map(({ accountId, error }) => [
+        accountId,
+        error,
+      ]),
+    );
+
+    return Object.entries(accounts).map(
+      ([accountId, account]): CalendarGroup & {
+        accountId: string;
+        error: string | null;
+      } => ({
+        accountId,
+        error: errors.get(accountId) ?? null,
+        sourceName: account.name || account.url || "Untitled",
+        calendars: Object.entries(calendars)
+          .filter(([_, cal]) => cal.source === accountId)
+          .map(([id, cal]) => ({
+            id,
+            title: cal.name || "Untitled",
+            color: cal.color ?? "#888",
+            enabled: cal.enabled ?? false,
+          })),
+      }),
+    );
+  }, [accounts, calendars, incomingCalendars]);
+
+  const handleToggle = useCallback(
+    (calendar: CalendarItem, enabled: boolean) => {
+      store?.setPartialRow("calendars", calendar.id, { enabled });
+      scheduleDebouncedSync();
+    },
+    [store, scheduleDebouncedSync],
+  );
+
+  const handleRefresh = useCallback(async () => {
+    cancelDebouncedSync();
+    await refetch();
+    scheduleSync();
+  }, [refetch, scheduleSync, cancelDebouncedSync]);
+
+  const handleRemove = useCallback(
+    (accountId: string) => {
+      store?.transaction(() => {
+        for (const rowId of store.getRowIds("calendars")) {
+          if (store.getCell("calendars", rowId, "source") === accountId) {
+            store.delRow("calendars", rowId);
+          }
+        }
+      });
+      settingsStore?.delRow("calendar_accounts", accountId);
+      scheduleSync();
+    },
+    [store, settingsStore, scheduleSync],
+  );
+
+  return {
+    groups,
+    handleToggle,
+    handleRefresh,
+    handleRemove,
+    isLoading: isFetching,
+  };
+}
@@ -0,0 +1,53 @@
+import { commands as openerCommands } from "@hypr/plugin-opener2";
+import {
+  AccordionContent,
+  AccordionItem,
+  AccordionTrigger,
+} from "@hypr/ui/components/ui/accordion";
+
+import { StyledStreamdown } from "../../../ai/shared";
+import { PROVIDERS } from "../../shared";
+import { Section } from "../apple";
+import { SyncProvider } from "../apple/context";
+import { AddAccountForm } from "./add-account";
+import { CalDavCalendarSelection } from "./calendar-selection";
+
+export function CalDavProviderCard() {
+  const config = PROVIDERS.find((p) => p.id === "caldav")!;
+
+  return (
+    <AccordionItem
+      value={config.id}
+      className="rounded-xl border-2 border-dashed bg-neutral-50"
+    >
+      <AccordionTrigger className="gap-2 px-4">
+        <div className="flex items-center gap-2">
+          {config.icon}
+          <span>{config.displayName}</span>
+        </div>
+      </AccordionTrigger>
+      <AccordionContent className="px-4 flex flex-col gap-5">
+        <div className="flex items-center justify-between">
+          <StyledStreamdown>
+            Sync a **CalDAV** account such as Fastmail, Nextcloud or iCloud, or
+            subscribe to an **ICS** link.
+          </StyledStreamdown>
+          <button
+            onClick={() => openerCommands.openUrl(config.docsPath, null)}
+            className="text-xs text-neutral-400 hover:text-neutral-600 transition-colors"
+          >
+            Docs ↗
+          </button>
+        </div>
+
+        <SyncProvider>
+          <Section title="Add account">
+            <AddAccountForm />
+          </Section>
+
+          <CalDavCalendarSelection calendarClassName="border rounded-lg" />
+        </SyncProvider>
+      </AccordionContent>
+    </AccordionItem>
+  );
+}
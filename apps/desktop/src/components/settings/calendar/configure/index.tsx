@@ -4,6 +4,7 @@ import { Accordion } from "@hypr/ui/components/ui/accordion";
 
 import { PROVIDERS } from "../shared";
 import { AppleCalendarProviderCard } from "./apple";
+import { CalDavProviderCard } from "./caldav";
 import { DisabledProviderCard } from "./cloud";
 
 export function ConfigureProviders() {
@@ -20,6 +21,8 @@ export function ConfigureProviders() {
           <DisabledProviderCard key={provider.id} config={provider} />
         ) : provider.id === "apple" ? (
           <AppleCalendarProviderCard key={provider.id} />
+        ) : provider.id === "caldav" ? (
+          <CalDavProviderCard key={provider.id} />
         ) : null,
       )}
     </Accordion>
@@ -25,6 +25,14 @@ const _PROVIDERS = [
     platform: "macos",
     docsPath: "https://hyprnote.com/docs/calendar/apple",
   },
+  {
+    disabled: false,
+    id: "caldav",
+    displayName: "CalDAV / ICS",
+    icon: <Icon icon="mdi:calendar-sync-outline" width={20} height={20} />,
+    platform: "all",
+    docsPath: "https://hyprnote.com/docs/calendar/caldav",
+  },
   {
     disabled: true,
     id: "google",
@@ -0,0 +1,97 @@
+import { open as selectFile } from "@tauri-apps/plugin-dialog";
+import { CheckIcon, Loader2Icon } from "lucide-react";
+
+import { type TransformKind } from "@hypr/plugin-importer";
+import { Button } from "@hypr/ui/components/ui/button";
+
+export type FileSource = {
+  transform: TransformKind;
+  name: string;
+  description: string;
+  extensions: string[];
+};
+
+// Transcripts exported from other tools, picked by the user instead of
+// detected on disk.
+export const FILE_SOURCES: FileSource[] = [
+  {
+    transform: "otter",
+    name: "Otter.ai",
+    description: "TXT, DOCX or SRT export",
+    extensions: ["txt", "docx", "srt"],
+  },
+  {
+    transform: "fireflies",
+    name: "Fireflies.ai",
+    description: "JSON transcript",
+    extensions: ["json"],
+  },
+  {
+    transform: "zoom",
+    name: "Zoom",
+    description: "Cloud recording transcript (.vtt)",
+    extensions: ["vtt"],
+  },
+  {
+    transform: "subtitles",
+    name: "Subtitles",
+    description: "Any WebVTT or SRT file",
+    extensions: ["vtt", "srt"],
+  },
+];
+
+export function FileSourceItem({
+  source,
+  onSelect,
+  disabled,
+  isScanning,
+  isSuccess,
+}: {
+  source: FileSource;
+  onSelect: (path: string) => void;
+  disabled: boolean;
+  isScanning: boolean;
+  isSuccess?: boolean;
+}) {
+  const handleChoose = async () => {
+    const selected = await selectFile({
+      title: `Import from ${source.name}`,
+      multiple: false,
+      directory: false,
+      filters: [{ name: source.name, extensions: source.extensions }],
+    });
+    if (typeof selected === "string") {
+      onSelect(selected);
+    }
+  };
+
+  return (
+    <div className="flex items-center justify-between gap-4">
+      <div className="flex-1 min-w-0">
+        <h3 className="text-sm font-medium mb-1">{source.name}</h3>
+        <p className="text-xs text-neutral-600">{source.description}</p>
+      </div>
+      <div className="flex items-center gap-2 shrink-0">
+        <Button
+          size="sm"
+          variant="outline"
+          onClick={() => void handleChoose()}
+          disabled={disabled || isSuccess}
+        >
+          {isScanning ? (
+            <>
+              <Loader2Icon size={14} className="animate-spin mr-1" />
+              Scanning...
+            </>
+          ) : isSuccess ? (
+            <>
+              <CheckIcon size={14} className="text-green-600 mr-1" />
+            </>
+          ) : (
+            "Choose file"
+          )}
+        </Button>
+      </div>
+    </div>
+  );
+}
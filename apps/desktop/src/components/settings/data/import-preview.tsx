@@ -8,8 +8,15 @@ import {
   UsersIcon,
 } from "lucide-react";
 
-import { type ImportStats } from "@hypr/plugin-importer";
+import { type ConflictPolicy, type ImportPlan } from "@hypr/plugin-importer";
 import { Button } from "@hypr/ui/components/ui/button";
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from "@hypr/ui/components/ui/select";
 import {
   Tooltip,
   TooltipContent,
@@ -18,18 +25,23 @@ import {
 } from "@hypr/ui/components/ui/tooltip";
 
 export function ImportPreview({
-  stats,
+  plan,
+  policy,
+  onPolicyChange,
   sourceName,
   onConfirm,
   onCancel,
   isPending,
 }: {
-  stats: ImportStats;
+  plan: ImportPlan;
+  policy: ConflictPolicy;
+  onPolicyChange: (policy: ConflictPolicy) => void;
   sourceName: string;
   onConfirm: () => void;
   onCancel: () => void;
   isPending: boolean;
 }) {
+  const { stats } = plan;
   const totalItems =
     stats.sessionsCount +
     stats.transcriptsCount +
@@ -38,6 +50,10 @@ export function ImportPreview({
     stats.templatesCount;
 
   const hasData = totalItems > 0;
+  const hasChanges =
+    plan.newCount > 0 ||
+    (plan.conflictCount > 0 && policy !== "skip") ||
+    stats.templatesCount > 0;
 
   const statItems = [
     { icon: FileTextIcon, label: "Sessions", count: stats.sessionsCount },
@@ -81,6 +97,12 @@ export function ImportPreview({
         ) : (
           <p className="text-xs text-neutral-500">No data found to import.</p>
         )}
+        {hasData && (
+          <p className="mt-1 text-xs text-neutral-500">
+            {plan.newCount} new, {plan.duplicateCount} already imported,{" "}
+            {plan.conflictCount} changed since last import
+          </p>
+        )}
       </div>
       <div className="flex items-center gap-2 shrink-0">
         <Button
@@ -91,8 +113,28 @@ export function ImportPreview({
         >
           Cancel
         </Button>
+        {plan.conflictCount > 0 && (
+          <Select
+            value={policy}
+            onValueChange={(v) => onPolicyChange(v as ConflictPolicy)}
+            disabled={isPending}
+          >
+            <SelectTrigger className="w-[120px] h-8 text-xs">
+              <SelectValue />
+            </SelectTrigger>
+            <SelectContent>
+              <SelectItem value="skip">Skip changed</SelectItem>
+              <SelectItem value="merge">Merge changed</SelectItem>
+              <SelectItem value="overwrite">Overwrite changed</SelectItem>
+            </SelectContent>
+          </Select>
+        )}
         {hasData && (
-          <Button size="sm" onClick={onConfirm} disabled={isPending}>
+          <Button
+            size="sm"
+            onClick={onConfirm}
+            disabled={isPending || !hasChanges}
+          >
             {isPending ? (
               <>
                 <Loader2Icon size={14} className="animate-spin mr-1" />
@@ -5,27 +5,55 @@ import { useState } from "react";
 import { commands as analyticsCommands } from "@hypr/plugin-analytics";
 import {
   commands,
+  type ConflictPolicy,
+  type ImportPlan,
   type ImportSourceInfo,
   type ImportSourceKind,
-  type ImportStats,
+  type TransformKind,
 } from "@hypr/plugin-importer";
 
 import { importData } from "../../../store/tinybase/store/importer";
 import * as main from "../../../store/tinybase/store/main";
 import { save } from "../../../store/tinybase/store/save";
 import { StyledStreamdown } from "../ai/shared";
+import { FILE_SOURCES, FileSourceItem } from "./file-source-item";
 import { ImportPreview } from "./import-preview";
 import { SourceItem } from "./source-item";
+import { VAULT_TARGETS, VaultExportItem } from "./vault-export-item";
+
+// A detected app database, or an export file the user picked.
+type ImportTarget =
+  | { type: "source"; kind: ImportSourceKind }
+  | { type: "file"; transform: TransformKind; path: string };
 
 type DryRunResult = {
-  source: ImportSourceKind;
-  stats: ImportStats;
+  target: ImportTarget;
+  plan: ImportPlan;
 };
 
+const targetKey = (target: ImportTarget): string =>
+  target.type === "source" ? target.kind : target.transform;
+
+const runImport = (
+  target: ImportTarget,
+  userId: string,
+  policy: ConflictPolicy,
+) =>
+  target.type === "source"
+    ? commands.runImport(target.kind, userId, policy)
+    : commands.runImportFromPath(target.path, target.transform, userId, policy);
+
+const runImportDry = (target: ImportTarget) =>
+  target.type === "source"
+    ? commands.runImportDry(target.kind)
+    : commands.runImportFromPathDry(target.path, target.transform);
+
 export function Data() {
   const [dryRunResult, setDryRunResult] = useState<DryRunResult | null>(null);
-  const [successfulSource, setSuccessfulSource] =
-    useState<ImportSourceKind | null>(null);
+  const [policy, setPolicy] = useState<ConflictPolicy>("skip");
+  const [successfulSource, setSuccessfulSource] = useState<string | null>(
+    null,
+  );
   const store = main.UI.useStore(main.STORE_ID);
   const { user_id } = main.UI.useValues(main.STORE_ID);
 
@@ -41,8 +69,8 @@ export function Data() {
   });
 
   const importMutation = useMutation({
-    mutationFn: async (source: ImportSourceKind) => {
-      const result = await commands.runImport(source, user_id ?? "");
+    mutationFn: async (target: ImportTarget) => {
+      const result = await runImport(target, user_id ?? "", policy);
       if (result.status === "error") {
         throw new Error(result.error);
       }
@@ -63,7 +91,7 @@ export function Data() {
       return result.data.stats;
     },
     onSuccess: () => {
-      const source = dryRunResult?.source;
+      const source = dryRunResult ? targetKey(dryRunResult.target) : undefined;
       void analyticsCommands.event({
         event: "data_imported",
         source,
@@ -76,14 +104,15 @@ export function Data() {
   });
 
   const dryImportMutation = useMutation({
-    mutationFn: async (source: ImportSourceKind) => {
-      const result = await commands.runImportDry(source);
+    mutationFn: async (target: ImportTarget): Promise<DryRunResult> => {
+      const result = await runImportDry(target);
       if (result.status === "error") {
         throw new Error(result.error);
       }
-      return { source, stats: result.data };
+      return { target, plan: result.data };
     },
     onSuccess: (result) => {
+      setPolicy("skip");
       setDryRunResult(result);
     },
   });
@@ -96,6 +125,24 @@ export function Data() {
   };
 
   const isPending = importMutation.isPending || dryImportMutation.isPending;
+  const scanningKey =
+    dryImportMutation.isPending && dryImportMutation.variables
+      ? targetKey(dryImportMutation.variables)
+      : null;
+
+  const scan = (target: ImportTarget) => {
+    setSuccessfulSource(null);
+    dryImportMutation.mutate(target);
+  };
+
+  const targetName = (target: ImportTarget) => {
+    if (target.type === "source") {
+      return sources?.find((s) => s.kind === target.kind)?.name ?? "Unknown";
+    }
+    const source = FILE_SOURCES.find((s) => s.transform === target.transform);
+    const fileName = target.path.split(/[\\/]/).pop() ?? target.path;
+    return source ? `${source.name} (${fileName})` : fileName;
+  };
 
   return (
     <div>
@@ -108,39 +155,57 @@ export function Data() {
       <div className="mt-4 flex flex-col gap-3">
         {dryRunResult ? (
           <ImportPreview
-            stats={dryRunResult.stats}
-            sourceName={
-              sources?.find((s) => s.kind === dryRunResult.source)?.name ??
-              "Unknown"
-            }
-            onConfirm={() => importMutation.mutate(dryRunResult.source)}
+            plan={dryRunResult.plan}
+            policy={policy}
+            onPolicyChange={setPolicy}
+            sourceName={targetName(dryRunResult.target)}
+            onConfirm={() => importMutation.mutate(dryRunResult.target)}
             onCancel={handleCancel}
             isPending={importMutation.isPending}
           />
         ) : (
-          sources
-            ?.filter(
-              (
-                source,
-              ): source is ImportSourceInfo & { kind: ImportSourceKind } =>
-                source.kind !== null,
-            )
-            .map((source) => (
-              <SourceItem
-                key={source.kind}
+          <>
+            {sources
+              ?.filter(
+                (
+                  source,
+                ): source is ImportSourceInfo & { kind: ImportSourceKind } =>
+                  source.kind !== null,
+              )
+              .map((source) => (
+                <SourceItem
+                  key={source.kind}
+                  source={source}
+                  onScan={() => scan({ type: "source", kind: source.kind })}
+                  disabled={isPending}
+                  isScanning={scanningKey === source.kind}
+                  isSuccess={successfulSource === source.kind}
+                />
+              ))}
+
+            <h3 className="mt-2 text-xs font-medium uppercase text-neutral-500">
+              From an export file
+            </h3>
+            {FILE_SOURCES.map((source) => (
+              <FileSourceItem
+                key={source.transform}
                 source={source}
-                onScan={() => {
-                  setSuccessfulSource(null);
-                  dryImportMutation.mutate(source.kind);
-                }}
-                disabled={isPending}
-                isScanning={
-                  dryImportMutation.isPending &&
-                  dryImportMutation.variables === source.kind
+                onSelect={(path) =>
+                  scan({ type: "file", transform: source.transform, path })
                 }
-                isSuccess={successfulSource === source.kind}
+                disabled={isPending}
+                isScanning={scanningKey === source.transform}
+                isSuccess={successfulSource === source.transform}
               />
-            ))
+            ))}
+
+            <h3 className="mt-2 text-xs font-medium uppercase text-neutral-500">
+              Export vault
+            </h3>
+            {VAULT_TARGETS.map((target) => (
+              <VaultExportItem key={target.flavor} target={target} />
+            ))}
+          </>
         )}
 
         {(importMutation.isError || dryImportMutation.isError) && (
@@ -0,0 +1,121 @@
+import { useMutation } from "@tanstack/react-query";
+import { open as selectFolder } from "@tauri-apps/plugin-dialog";
+import { CheckIcon, Loader2Icon, XCircleIcon } from "lucide-react";
+
+import { commands as analyticsCommands } from "@hypr/plugin-analytics";
+import {
+  commands as fsSyncCommands,
+  type VaultFlavor,
+} from "@hypr/plugin-fs-sync";
+import { commands as openerCommands } from "@hypr/plugin-opener2";
+import { Button } from "@hypr/ui/components/ui/button";
+
+import { save } from "../../../store/tinybase/store/save";
+
+export const VAULT_TARGETS: {
+  flavor: VaultFlavor;
+  name: string;
+  description: string;
+}[] = [
+  {
+    flavor: "obsidian",
+    name: "Obsidian",
+    description: "Markdown pages with properties and [[wiki links]]",
+  },
+  {
+    flavor: "logseq",
+    name: "Logseq",
+    description: "Outline pages and journals linked by [[page refs]]",
+  },
+];
+
+export function VaultExportItem({
+  target,
+}: {
+  target: (typeof VAULT_TARGETS)[number];
+}) {
+  const exportMutation = useMutation({
+    mutationFn: async () => {
+      const outputDir = await selectFolder({
+        title: `Export to ${target.name}`,
+        directory: true,
+        multiple: false,
+      });
+      if (typeof outputDir !== "string") {
+        return null;
+      }
+
+      // The exporter reads the vault on disk, so flush pending edits first.
+      await save();
+
+      const result = await fsSyncCommands.exportVault(
+        {
+          flavor: target.flavor,
+          includeMemo: true,
+          includeTranscript: true,
+          dailyNotes: true,
+        },
+        outputDir,
+      );
+      if (result.status === "error") {
+        throw new Error(result.error);
+      }
+      return { outputDir, stats: result.data };
+    },
+    onSuccess: (exported) => {
+      if (!exported) {
+        return;
+      }
+
+      void analyticsCommands.event({
+        event: "vault_exported",
+        flavor: target.flavor,
+        meetings: exported.stats.meetings,
+      });
+      void openerCommands.revealItemInDir(exported.outputDir);
+    },
+  });
+
+  const stats = exportMutation.data?.stats;
+
+  return (
+    <div className="flex flex-col gap-1">
+      <div className="flex items-center justify-between gap-4">
+        <div className="flex-1 min-w-0">
+          <h3 className="text-sm font-medium mb-1">{target.name}</h3>
+          <p className="text-xs text-neutral-600">
+            {stats
+              ? `Exported ${stats.meetings} meetings, ${stats.people} people and ${stats.organizations} organizations`
+              : target.description}
+          </p>
+        </div>
+        <Button
+          size="sm"
+          variant="outline"
+          onClick={() => exportMutation.mutate()}
+          disabled={exportMutation.isPending}
+        >
+          {exportMutation.isPending ? (
+            <>
+              <Loader2Icon size={14} className="animate-spin mr-1" />
+              Exporting...
+            </>
+          ) : stats ? (
+            <>
+              <CheckIcon size={14} className="text-green-600 mr-1" />
+              Export again
+            </>
+          ) : (
+            "Choose folder"
+          )}
+        </Button>
+      </div>
+      {exportMutation.isError && (
+        <div className="flex items-center gap-2 text-xs text-red-600">
+          <XCircleIcon size={14} />
+          <span>Export failed: {exportMutation.error.message}</span>
+        </div>
+      )}
+    </div>
+  );
+}
@@ -15,6 +15,7 @@ import { Audio } from "./audio";
 import { MainLanguageView } from "./main-language";
 import { NotificationSettingsView } from "./notification";
 import { Permissions } from "./permissions";
+import { RecordingSettingsView } from "./recording";
 import { SpokenLanguagesView } from "./spoken-languages";
 import { StorageSettingsView } from "./storage";
 import { TimezoneSelector } from "./timezone";
@@ -211,6 +212,8 @@ export function SettingsApp() {
         </div>
       </div>
 
+      <RecordingSettingsView />
+
       <StorageSettingsView />
 
       <div>
@@ -0,0 +1,101 @@
+import { Switch } from "@hypr/ui/components/ui/switch";
+
+import { useConfigValue } from "../../../config/use-config";
+import * as settings from "../../../store/tinybase/store/settings";
+import {
+  SearchableSelect,
+  type SearchableSelectOption,
+} from "./searchable-select";
+
+const FORMAT_OPTIONS: SearchableSelectOption[] = [
+  { value: "wav", label: "WAV (uncompressed)" },
+  { value: "flac", label: "FLAC (lossless)" },
+  { value: "opus", label: "Opus (smallest)" },
+];
+
+const STEM_OPTIONS: SearchableSelectOption[] = [
+  { value: "discard", label: "Don't keep" },
+  { value: "7", label: "Keep for 7 days" },
+  { value: "30", label: "Keep for 30 days" },
+  { value: "keep", label: "Keep forever" },
+];
+
+export function RecordingSettingsView() {
+  const format = useConfigValue("recording_format");
+  const stems = useConfigValue("recording_stems");
+  const speakerSeparation = useConfigValue("speaker_separation");
+
+  const setFormat = settings.UI.useSetValueCallback(
+    "recording_format",
+    (val: string) => val,
+    [],
+    settings.STORE_ID,
+  );
+  const setStems = settings.UI.useSetValueCallback(
+    "recording_stems",
+    (val: string) => val,
+    [],
+    settings.STORE_ID,
+  );
+  const setSpeakerSeparation = settings.UI.useSetValueCallback(
+    "speaker_separation",
+    (val: boolean) => val,
+    [],
+    settings.STORE_ID,
+  );
+
+  return (
+    <div>
+      <h2 className="text-lg font-semibold font-serif mb-4">Recordings</h2>
+      <div className="flex flex-col gap-6">
+        <div className="flex flex-row items-center justify-between">
+          <div>
+            <h3 className="text-sm font-medium mb-1">Format</h3>
+            <p className="text-xs text-neutral-600">
+              Applies to new recordings
+            </p>
+          </div>
+          <SearchableSelect
+            value={format}
+            onChange={setFormat}
+            options={FORMAT_OPTIONS}
+            placeholder="Select format"
+            className="w-48"
+          />
+        </div>
+        <div className="flex flex-row items-center justify-between">
+          <div>
+            <h3 className="text-sm font-medium mb-1">
+              Separate mic and system audio
+            </h3>
+            <p className="text-xs text-neutral-600">
+              Keep each side of the conversation as its own file
+            </p>
+          </div>
+          <SearchableSelect
+            value={stems}
+            onChange={setStems}
+            options={STEM_OPTIONS}
+            placeholder="Select retention"
+            className="w-48"
+          />
+        </div>
+        <div className="flex flex-row items-center justify-between gap-4">
+          <div className="flex-1">
+            <h3 className="text-sm font-medium mb-1">
+              Tell in-person speakers apart
+            </h3>
+            <p className="text-xs text-neutral-600">
+              Split the microphone into one speaker per voice when several
+              people share it. Runs on this device.
+            </p>
+          </div>
+          <Switch
+            checked={speakerSeparation}
+            onCheckedChange={setSpeakerSeparation}
+          />
+        </div>
+      </div>
+    </div>
+  );
+}
@@ -7,6 +7,8 @@ import { commands as windowsCommands } from "@hypr/plugin-windows";
 import { Button } from "@hypr/ui/components/ui/button";
 import { cn } from "@hypr/utils";
 
+import { McpServerSettings } from "./mcp-server";
+
 export function SettingsLab() {
   const handleOpenControlWindow = async () => {
     await windowsCommands.windowShow({ type: "control" });
@@ -26,6 +28,8 @@ export function SettingsLab() {
         </Button>
       </div>
 
+      <McpServerSettings />
+
       <DownloadButtons />
     </div>
   );
@@ -0,0 +1,233 @@
+import { useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
+import { CheckIcon, CopyIcon, RefreshCwIcon } from "lucide-react";
+import { useState } from "react";
+
+import {
+  commands as mcpCommands,
+  type McpConnection,
+  type ToolPermissions,
+} from "@hypr/plugin-mcp";
+import { commands as openerCommands } from "@hypr/plugin-opener2";
+import { Button } from "@hypr/ui/components/ui/button";
+import { Switch } from "@hypr/ui/components/ui/switch";
+import { cn } from "@hypr/utils";
+
+const TOOLS: { key: keyof ToolPermissions; label: string }[] = [
+  { key: "search_sessions", label: "Search notes" },
+  { key: "get_session", label: "Read a note and its transcript" },
+  { key: "list_upcoming_events", label: "List upcoming events" },
+  { key: "list_action_items", label: "List action items" },
+  { key: "append_to_note", label: "Append to notes" },
+];
+
+export function McpServerSettings() {
+  const queryClient = useQueryClient();
+
+  const running = useQuery({
+    queryKey: ["mcp", "running"],
+    queryFn: () => mcpCommands.isServerRunning(),
+  });
+  const connection = useQuery({
+    queryKey: ["mcp", "connection"],
+    queryFn: async () => unwrap(await mcpCommands.getConnection()),
+    enabled: running.data === true,
+  });
+
+  const toggle = useMutation({
+    mutationFn: async (enabled: boolean) => {
+      if (enabled) {
+        return unwrap(await mcpCommands.startServer());
+      }
+      unwrap(await mcpCommands.stopServer());
+      return null;
+    },
+    onSuccess: (data) => {
+      queryClient.setQueryData(["mcp", "running"], data !== null);
+      if (data) {
+        queryClient.setQueryData(["mcp", "connection"], data);
+      }
+    },
+  });
+
+  const rotate = useMutation({
+    mutationFn: async () => unwrap(await mcpCommands.rotateToken()),
+    onSuccess: (data) => {
+      queryClient.setQueryData(["mcp", "connection"], data);
+    },
+  });
+
+  const error = toggle.error ?? rotate.error ?? connection.error;
+
+  return (
+    <div className="flex flex-col gap-3">
+      <div className="flex items-center justify-between gap-4">
+        <div className="flex-1">
+          <h3 className="text-sm font-medium mb-1">MCP Server</h3>
+          <p className="text-xs text-neutral-600">
+            Let coding agents on this computer search and read your notes.{" "}
+            <button
+              onClick={() =>
+                openerCommands.openUrl(
+                  "https://hyprnote.com/docs/guides/mcp",
+                  null,
+                )
+              }
+              className="text-neutral-400 hover:text-neutral-600 transition-colors"
+            >
+              Docs ↗
+            </button>
+          </p>
+        </div>
+        <Switch
+          checked={running.data ?? false}
+          disabled={running.isLoading || toggle.isPending}
+          onCheckedChange={(enabled) => toggle.mutate(enabled)}
+        />
+      </div>
+
+      {error && <p className="text-xs text-destructive">{error.message}</p>}
+
+      {running.data && connection.data && (
+        <div className="flex flex-col gap-3 rounded-lg border bg-neutral-50 p-3">
+          <CopyRow label="URL" value={connection.data.url} />
+          <CopyRow
+            label="Token"
+            value={connection.data.token}
+            secret
+            action={
+              <Button
+                variant="ghost"
+                size="icon"
+                className="size-6"
+                disabled={rotate.isPending}
+                onClick={() => rotate.mutate()}
+                title="Rotate token"
+              >
+                <RefreshCwIcon
+                  className={cn([
+                    "size-3.5",
+                    rotate.isPending && "animate-spin",
+                  ])}
+                />
+              </Button>
+            }
+          />
+          <CopyRow label="Config" value={agentConfig(connection.data)} />
+          <ToolPermissionList />
+        </div>
+      )}
+    </div>
+  );
+}
+
+function ToolPermissionList() {
+  const queryClient = useQueryClient();
+
+  const permissions = useQuery({
+    queryKey: ["mcp", "permissions"],
+    queryFn: async () => unwrap(await mcpCommands.getPermissions()),
+  });
+
+  const update = useMutation({
+    mutationFn: async (next: ToolPermissions) => {
+      unwrap(await mcpCommands.setPermissions(next));
+      return next;
+    },
+    onSuccess: (next) => {
+      queryClient.setQueryData(["mcp", "permissions"], next);
+    },
+  });
+
+  if (!permissions.data) {
+    return null;
+  }
+
+  return (
+    <div className="flex flex-col gap-2 border-t border-neutral-200 pt-3">
+      <h4 className="text-xs font-medium text-neutral-400 uppercase tracking-wide">
+        Allowed tools
+      </h4>
+      {TOOLS.map(({ key, label }) => (
+        <div key={key} className="flex items-center justify-between gap-4">
+          <span className="text-xs text-neutral-700">{label}</span>
+          <Switch
+            checked={permissions.data[key]}
+            disabled={update.isPending}
+            onCheckedChange={(checked) =>
+              update.mutate({ ...permissions.data, [key]: checked })
+            }
+          />
+        </div>
+      ))}
+    </div>
+  );
+}
+
+function CopyRow({
+  label,
+  value,
+  secret,
+  action,
+}: {
+  label: string;
+  value: string;
+  secret?: boolean;
+  action?: React.ReactNode;
+}) {
+  const [copied, setCopied] = useState(false);
+
+  const handleCopy = async () => {
+    await navigator.clipboard.writeText(value);
+    setCopied(true);
+    setTimeout(() => setCopied(false), 1500);
+  };
+
+  return (
+    <div className="flex items-center gap-2">
+      <span className="w-12 shrink-0 text-xs text-neutral-500">{label}</span>
+      <code className="flex-1 truncate text-xs">
+        {secret ? "•".repeat(16) : value.replace(/\s+/g, " ")}
+      </code>
+      {action}
+      <Button
+        variant="ghost"
+        size="icon"
+        className="size-6"
+        onClick={handleCopy}
+        title={`Copy ${label.toLowerCase()}`}
+      >
+        {copied ? (
+          <CheckIcon className="size-3.5" />
+        ) : (
+          <CopyIcon className="size-3.5" />
+        )}
+      </Button>
+    </div>
+  );
+}
+
+// The `mcpServers` shape read by Claude Code, Cursor and most other agents.
+function agentConfig({ url, token }: McpConnection) {
+  return JSON.stringify(
+    {
+      mcpServers: {
+        char: {
+          type: "http",
+          url,
+          headers: { Authorization: `Bearer ${token}` },
+        },
+      },
+    },
+    null,
+    2,
+  );
+}
+
+function unwrap<T>(
+  result: { status: "ok"; data: T } | { status: "error"; error: string },
+): T {
+  if (result.status === "error") {
+    throw new Error(result.error);
+  }
+  return result.data;
+}
@@ -1,6 +1,7 @@
 import { disable, enable } from "@tauri-apps/plugin-autostart";
 
 import { commands as detectCommands } from "@hypr/plugin-detect";
+import type { RecordingFormat } from "@hypr/plugin-listener";
 import {
   commands as localSttCommands,
   type SupportedSttModel,
@@ -18,6 +19,9 @@ export type ConfigKey =
   | "ai_language"
   | "spoken_languages"
   | "save_recordings"
+  | "recording_format"
+  | "recording_stems"
+  | "speaker_separation"
   | "telemetry_consent"
   | "current_llm_provider"
   | "current_llm_model"
@@ -136,6 +140,21 @@ export const CONFIG_REGISTRY = {
     default: true,
   },
 
+  recording_format: {
+    key: "recording_format",
+    default: "wav" as RecordingFormat,
+  },
+
+  recording_stems: {
+    key: "recording_stems",
+    default: "discard",
+  },
+
+  speaker_separation: {
+    key: "speaker_separation",
+    default: false,
+  },
+
   telemetry_consent: {
     key: "telemetry_consent",
     default: true,
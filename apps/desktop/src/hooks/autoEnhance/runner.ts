@@ -10,6 +10,7 @@ import { createTaskId } from "../../store/zustand/ai-task/task-configs";
 import { getTaskState } from "../../store/zustand/ai-task/tasks";
 import { useTabs } from "../../store/zustand/tabs";
 import type { Tab } from "../../store/zustand/tabs/schema";
+import { useActionItemExtraction } from "../useActionItemExtraction";
 import { useAITaskTask } from "../useAITaskTask";
 import { useCreateEnhancedNote } from "../useEnhancedNotes";
 import { useLanguageModel, useLLMConnection } from "../useLLMConnection";
@@ -79,6 +80,8 @@ export function useAutoEnhanceRunner(
     onSuccess: handleTitleSuccess,
   });
 
+  const extractActionItems = useActionItemExtraction();
+
   const handleEnhanceSuccess = useCallback(
     (text: string) => {
       const noteId = currentNoteIdRef.current;
@@ -97,11 +100,15 @@ export function useAutoEnhanceRunner(
         if (!trimmedTitle && model) {
           void titleTask.start({ model, args: { sessionId } });
         }
+
+        if (model) {
+          extractActionItems(model, sessionId, noteId);
+        }
       } catch (error) {
         console.error("Failed to convert markdown to JSON:", error);
       }
     },
-    [store, sessionId, model, titleTask.start],
+    [store, sessionId, model, titleTask.start, extractActionItems],
   );
 
   const prevEnhanceStatusRef = useRef<string>("idle");
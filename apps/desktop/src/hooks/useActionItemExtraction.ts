@@ -0,0 +1,37 @@
+import type { LanguageModel } from "ai";
+import { useCallback } from "react";
+
+import { useAITask } from "../contexts/ai-task";
+import { saveExtractedActionItems } from "../store/tinybase/store/action-items";
+import * as main from "../store/tinybase/store/main";
+import { createTaskId } from "../store/zustand/ai-task/task-configs";
+import { parseActionItems } from "../store/zustand/ai-task/task-configs/action-items-workflow";
+
+export function useActionItemExtraction() {
+  const store = main.UI.useStore(main.STORE_ID);
+  const generate = useAITask((state) => state.generate);
+
+  return useCallback(
+    (model: LanguageModel, sessionId: string, enhancedNoteId: string) => {
+      const taskId = createTaskId(enhancedNoteId, "actionItems");
+      void generate(taskId, {
+        model,
+        taskType: "actionItems",
+        args: { sessionId, enhancedNoteId },
+        onComplete: (text) => {
+          if (!text || !store) return;
+          try {
+            saveExtractedActionItems(store, {
+              sessionId,
+              enhancedNoteId,
+              extracted: parseActionItems(text),
+            });
+          } catch (error) {
+            console.error("Failed to save action items:", error);
+          }
+        },
+      });
+    },
+    [store, generate],
+  );
+}
@@ -0,0 +1,150 @@
+import { downloadDir } from "@tauri-apps/api/path";
+import { open as selectFile } from "@tauri-apps/plugin-dialog";
+import { useCallback, useRef } from "react";
+
+import { commands as analyticsCommands } from "@hypr/plugin-analytics";
+import {
+  commands as fsSyncCommands,
+  type MediaTrackInfo,
+  type MediaTrackSelection,
+} from "@hypr/plugin-fs-sync";
+
+import { useListener } from "../contexts/listener";
+import * as main from "../store/tinybase/store/main";
+import { useTabs } from "../store/zustand/tabs";
+import { useRunBatchForSession } from "./useRunBatch";
+
+export const IMPORTABLE_MEDIA_EXTENSIONS = [
+  "wav",
+  "mp3",
+  "m4a",
+  "aac",
+  "ogg",
+  "opus",
+  "flac",
+  "mp4",
+  "mov",
+  "webm",
+  "mkv",
+];
+
+export type ChooseTrack = (
+  path: string,
+  tracks: MediaTrackInfo[],
+) => Promise<MediaTrackSelection | null>;
+
+// Imports each file into its own new session, one at a time, and transcribes
+// it once the normalized audio is in the vault. Files with several audio
+// tracks go through `chooseTrack` first; resolving it with null skips the file.
+export function useImportMedia(chooseTrack: ChooseTrack) {
+  const store = main.UI.useStore(main.STORE_ID);
+  const { user_id } = main.UI.useValues(main.STORE_ID);
+  const openNew = useTabs((state) => state.openNew);
+  const runBatchForSession = useRunBatchForSession();
+  const handleBatchStarted = useListener((state) => state.handleBatchStarted);
+  const handleBatchFailed = useListener((state) => state.handleBatchFailed);
+  const clearBatchSession = useListener((state) => state.clearBatchSession);
+
+  const queue = useRef<Promise<void>>(Promise.resolve());
+
+  const importOne = useCallback(
+    async (path: string) => {
+      if (!store) {
+        return;
+      }
+
+      const trackSelection = await selectTrack(path, chooseTrack);
+      if (!trackSelection) {
+        return;
+      }
+
+      const result = await fsSyncCommands.mediaImport(path, trackSelection);
+      if (result.status === "error") {
+        console.error("[import] failed to import", path, result.error);
+        return;
+      }
+
+      const { sessionId, audioPath, title, createdAt } = result.data;
+      store.setRow("sessions", sessionId, {
+        user_id,
+        created_at: createdAt,
+        title,
+        raw_md: "",
+      });
+
+      void analyticsCommands.event({
+        event: "file_uploaded",
+        file_type: "audio",
+      });
+
+      openNew({ type: "sessions", id: sessionId });
+      handleBatchStarted(sessionId);
+
+      try {
+        clearBatchSession(sessionId);
+        await runBatchForSession(sessionId, audioPath);
+      } catch (error) {
+        const msg = error instanceof Error ? error.message : String(error);
+        handleBatchFailed(sessionId, msg);
+      }
+    },
+    [
+      chooseTrack,
+      clearBatchSession,
+      handleBatchFailed,
+      handleBatchStarted,
+      openNew,
+      runBatchForSession,
+      store,
+      user_id,
+    ],
+  );
+
+  const importFiles = useCallback(
+    (paths: string[]) => {
+      for (const path of paths) {
+        queue.current = queue.current.then(() => importOne(path));
+      }
+      return queue.current;
+    },
+    [importOne],
+  );
+
+  const selectAndImport = useCallback(async () => {
+    const selection = await selectFile({
+      title: "Import recordings",
+      multiple: true,
+      directory: false,
+      defaultPath: await downloadDir(),
+      filters: [
+        { name: "Audio & Video", extensions: IMPORTABLE_MEDIA_EXTENSIONS },
+      ],
+    });
+
+    if (!selection) {
+      return;
+    }
+
+    await importFiles(Array.isArray(selection) ? selection : [selection]);
+  }, [importFiles]);
+
+  return { importFiles, selectAndImport };
+}
+
+async function selectTrack(
+  path: string,
+  chooseTrack: ChooseTrack,
+): Promise<MediaTrackSelection | null> {
+  const probe = await fsSyncCommands.mediaProbe(path);
+  if (probe.status === "error") {
+    // Let the import itself report why the file can't be read.
+    return { type: "default" };
+  }
+
+  const { tracks } = probe.data;
+  if (tracks.length <= 1) {
+    return { type: "default" };
+  }
+
+  return chooseTrack(path, tracks);
+}
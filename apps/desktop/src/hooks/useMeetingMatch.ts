@@ -0,0 +1,52 @@
+import { useCallback } from "react";
+
+import { useConfigValue } from "../config/use-config";
+import { matchSessionToMeeting } from "../services/meeting-match";
+import * as main from "../store/tinybase/store/main";
+import { linkSessionToEvent } from "../store/tinybase/store/sessions";
+import { useMeetingMatchCandidates } from "../store/zustand/meeting-match";
+
+export function useMeetingMatch(sessionId: string) {
+  const store = main.UI.useStore(main.STORE_ID);
+  const timezone = useConfigValue("timezone") || undefined;
+
+  const candidates = useMeetingMatchCandidates(
+    (state) => state.candidates[sessionId],
+  );
+  const setCandidates = useMeetingMatchCandidates(
+    (state) => state.setCandidates,
+  );
+  const clear = useMeetingMatchCandidates((state) => state.clear);
+
+  const link = useCallback(
+    (eventId: string) => {
+      clear(sessionId);
+      if (store) {
+        linkSessionToEvent(store as main.Store, sessionId, eventId);
+      }
+    },
+    [store, sessionId, clear],
+  );
+
+  const match = useCallback(() => {
+    if (!store) {
+      return;
+    }
+
+    matchSessionToMeeting(store as main.Store, sessionId, timezone)
+      .then((outcome) => {
+        if (outcome.type === "linked") {
+          link(outcome.meeting.event_id);
+        } else if (outcome.type === "prompt") {
+          setCandidates(sessionId, outcome.candidates);
+        }
+      })
+      .catch((error) => {
+        console.error("[meeting-match] failed:", error);
+      });
+  }, [store, sessionId, timezone, link, setCandidates]);
+
+  const dismiss = useCallback(() => clear(sessionId), [clear, sessionId]);
+
+  return { candidates: candidates ?? [], match, link, dismiss };
+}
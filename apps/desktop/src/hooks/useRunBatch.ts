@@ -1,4 +1,4 @@
-import { useCallback, useRef } from "react";
+import { useCallback } from "react";
 
 import type { BatchParams } from "@hypr/plugin-listener2";
 
@@ -44,28 +44,33 @@ function getBatchProvider(
 }
 
 export const useRunBatch = (sessionId: string) => {
+  const keywords = useKeywords(sessionId);
+  const runBatchForSession = useRunBatchForSession();
+
+  return useCallback(
+    (filePath: string, options?: RunOptions) =>
+      runBatchForSession(sessionId, filePath, {
+        ...options,
+        keywords: options?.keywords ?? keywords,
+      }),
+    [keywords, runBatchForSession, sessionId],
+  );
+};
+
+// Same as `useRunBatch`, but for callers that only learn the session id at
+// call time (e.g. importing files into freshly created sessions).
+export const useRunBatchForSession = () => {
   const store = main.UI.useStore(main.STORE_ID);
   const { user_id } = main.UI.useValues(main.STORE_ID);
 
   const runBatch = useListener((state) => state.runBatch);
-  const sessionTab = useTabs((state) => {
-    const found = state.tabs.find(
-      (tab): tab is Extract<Tab, { type: "sessions" }> =>
-        tab.type === "sessions" && tab.id === sessionId,
-    );
-    return found ?? null;
-  });
   const updateSessionTabState = useTabs((state) => state.updateSessionTabState);
 
-  const sessionTabRef = useRef(sessionTab);
-  sessionTabRef.current = sessionTab;
-
   const { conn } = useSTTConnection();
-  const keywords = useKeywords(sessionId);
   const languages = useConfigValue("spoken_languages");
 
   return useCallback(
-    async (filePath: string, options?: RunOptions) => {
+    async (sessionId: string, filePath: string, options?: RunOptions) => {
       if (!store || !conn || !runBatch) {
         throw new Error(
           "STT connection is not available. Please configure your speech-to-text provider.",
@@ -80,9 +85,15 @@ export const useRunBatch = (sessionId: string) => {
         );
       }
 
-      if (sessionTabRef.current) {
-        updateSessionTabState(sessionTabRef.current, {
-          ...sessionTabRef.current.state,
+      const sessionTab = useTabs
+        .getState()
+        .tabs.find(
+          (tab): tab is Extract<Tab, { type: "sessions" }> =>
+            tab.type === "sessions" && tab.id === sessionId,
+        );
+      if (sessionTab) {
+        updateSessionTabState(sessionTab, {
+          ...sessionTab.state,
           view: { type: "transcript" },
         });
       }
@@ -172,21 +183,12 @@ export const useRunBatch = (sessionId: string) => {
         model: options?.model ?? conn.model,
         base_url: options?.baseUrl ?? conn.baseUrl,
         api_key: options?.apiKey ?? conn.apiKey,
-        keywords: options?.keywords ?? keywords ?? [],
+        keywords: options?.keywords ?? [],
         languages: options?.languages ?? languages ?? [],
       };
 
       await runBatch(params, { handlePersist: persist, sessionId });
     },
-    [
-      conn,
-      keywords,
-      languages,
-      runBatch,
-      sessionId,
-      store,
-      updateSessionTabState,
-      user_id,
-    ],
+    [conn, languages, runBatch, store, updateSessionTabState, user_id],
   );
 };
@@ -1,6 +1,7 @@
 import { useCallback } from "react";
 
 import { commands as analyticsCommands } from "@hypr/plugin-analytics";
+import type { StemRetention } from "@hypr/plugin-listener";
 
 import { useConfigValue } from "../config/use-config";
 import { useListener } from "../contexts/listener";
@@ -16,6 +17,7 @@ import type { HandlePersistCallback } from "../store/zustand/listener/transcript
 import { id } from "../utils";
 import { getSessionEventById } from "../utils/session-event";
 import { useKeywords } from "./useKeywords";
+import { useMeetingMatch } from "./useMeetingMatch";
 import { useSTTConnection } from "./useSTTConnection";
 
 export function useStartListening(sessionId: string) {
@@ -24,11 +26,15 @@ export function useStartListening(sessionId: string) {
 
   const record_enabled = useConfigValue("save_recordings");
   const languages = useConfigValue("spoken_languages");
+  const recording_format = useConfigValue("recording_format");
+  const recording_stems = useConfigValue("recording_stems");
+  const speaker_separation = useConfigValue("speaker_separation");
 
   const start = useListener((state) => state.start);
   const { conn } = useSTTConnection();
 
   const keywords = useKeywords(sessionId);
+  const { match: matchMeeting } = useMeetingMatch(sessionId);
 
   const startListening = useCallback(() => {
     if (!conn || !store) {
@@ -129,21 +135,46 @@ export function useStartListening(sessionId: string) {
         base_url: conn.baseUrl,
         api_key: conn.apiKey,
         keywords,
+        recording: {
+          format: recording_format,
+          stems: toStemRetention(recording_stems),
+        },
+        speaker_separation,
       },
       {
         handlePersist,
       },
     );
+
+    // Runs alongside the listener start: mic-using apps are known by now and
+    // linking only fills in metadata, so it must not delay recording.
+    matchMeeting();
   }, [
     conn,
     store,
     sessionId,
     start,
     keywords,
+    matchMeeting,
     user_id,
     record_enabled,
     languages,
+    recording_format,
+    recording_stems,
+    speaker_separation,
   ]);
 
   return startListening;
 }
+
+// Stored as "discard", "keep" or a number of days.
+function toStemRetention(value: string): StemRetention {
+  if (value === "keep") {
+    return { type: "keep" };
+  }
+
+  const days = Number(value);
+  return Number.isInteger(days) && days > 0
+    ? { type: "keep_for_days", days }
+    : { type: "discard" };
+}
@@ -1,10 +1,14 @@
 import type { Queries } from "tinybase/with-schemas";
 
+import type { CalendarAccount } from "@hypr/plugin-caldav";
+
 import {
   QUERIES,
   type Schemas,
   type Store,
 } from "../../store/tinybase/store/main";
+import type * as settings from "../../store/tinybase/store/settings";
+import { toCalDavAccount } from "../../utils/calendar";
 
 // ---
 
@@ -15,22 +19,44 @@ export interface Ctx {
   to: Date;
   calendarIds: Set<string>;
   calendarTrackingIdToId: Map<string, string>;
+  // CalDAV and ICS calendars, keyed by tracking id. The rest are Apple's.
+  calendarAccounts: Map<string, CalendarAccount>;
+  timezone?: string;
 }
 
 // ---
 
-export function createCtx(store: Store, queries: Queries<Schemas>): Ctx | null {
-  const resultTable = queries.getResultTable(QUERIES.enabledAppleCalendars);
+export function createCtx(
+  store: Store,
+  queries: Queries<Schemas>,
+  settingsStore?: settings.Store,
+): Ctx | null {
+  const resultTable = queries.getResultTable(QUERIES.enabledCalendars);
 
-  const calendarIds = new Set(Object.keys(resultTable));
+  const calendarIds = new Set<string>();
   const calendarTrackingIdToId = new Map<string, string>();
+  const calendarAccounts = new Map<string, CalendarAccount>();
 
-  for (const calendarId of calendarIds) {
+  for (const calendarId of Object.keys(resultTable)) {
     const calendar = store.getRow("calendars", calendarId);
     const trackingId = calendar?.tracking_id_calendar as string | undefined;
-    if (trackingId) {
-      calendarTrackingIdToId.set(trackingId, calendarId);
+    if (!trackingId) {
+      continue;
+    }
+
+    if (calendar.provider !== "apple") {
+      // Calendars of a removed account are left out, so their events go too.
+      const account = toCalDavAccount(
+        settingsStore?.getRow("calendar_accounts", calendar.source ?? ""),
+      );
+      if (!account) {
+        continue;
+      }
+      calendarAccounts.set(trackingId, account);
     }
+
+    calendarIds.add(calendarId);
+    calendarTrackingIdToId.set(trackingId, calendarId);
   }
 
   if (calendarTrackingIdToId.size === 0) {
@@ -51,6 +77,8 @@ export function createCtx(store: Store, queries: Queries<Schemas>): Ctx | null {
     to,
     calendarIds,
     calendarTrackingIdToId,
+    calendarAccounts,
+    timezone: settingsStore?.getValue("timezone") as string | undefined,
   };
 }
 
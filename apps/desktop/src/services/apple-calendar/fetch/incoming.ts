@@ -1,5 +1,10 @@
 import type { AppleEvent, Participant } from "@hypr/plugin-apple-calendar";
 import { commands as appleCalendarCommands } from "@hypr/plugin-apple-calendar";
+import {
+  type CalendarAccount,
+  commands as caldavCommands,
+  type RemoteEvent,
+} from "@hypr/plugin-caldav";
 import { commands as miscCommands } from "@hypr/plugin-misc";
 
 import type { Ctx } from "../ctx";
@@ -29,27 +34,18 @@ export async function fetchIncomingEvents(ctx: Ctx): Promise<{
   const trackingIds = Array.from(ctx.calendarTrackingIdToId.keys());
 
   const results = await Promise.all(
-    trackingIds.map(async (trackingId) => {
-      const result = await appleCalendarCommands.listEvents({
-        calendar_tracking_id: trackingId,
-        from: ctx.from.toISOString(),
-        to: ctx.to.toISOString(),
-      });
-
-      if (result.status === "error") {
-        throw new CalendarFetchError(trackingId, result.error);
-      }
-
-      return result.data;
+    trackingIds.map((trackingId) => {
+      const account = ctx.calendarAccounts.get(trackingId);
+      return account
+        ? fetchCalDavEvents(ctx, trackingId, account)
+        : fetchAppleEvents(ctx, trackingId);
     }),
   );
 
-  const appleEvents = results.flat();
   const events: IncomingEvent[] = [];
   const participants: IncomingParticipants = new Map();
 
-  for (const appleEvent of appleEvents) {
-    const { event, eventParticipants } = await normalizeAppleEvent(appleEvent);
+  for (const { event, eventParticipants } of results.flat()) {
     events.push(event);
     if (eventParticipants.length > 0) {
       const key = getEventKey(
@@ -64,6 +60,81 @@ export async function fetchIncomingEvents(ctx: Ctx): Promise<{
   return { events, participants };
 }
 
+async function fetchAppleEvents(ctx: Ctx, trackingId: string) {
+  const result = await appleCalendarCommands.listEvents({
+    calendar_tracking_id: trackingId,
+    from: ctx.from.toISOString(),
+    to: ctx.to.toISOString(),
+  });
+
+  if (result.status === "error") {
+    throw new CalendarFetchError(trackingId, result.error);
+  }
+
+  return Promise.all(result.data.map(normalizeAppleEvent));
+}
+
+async function fetchCalDavEvents(
+  ctx: Ctx,
+  trackingId: string,
+  account: CalendarAccount,
+) {
+  const result = await caldavCommands.listEvents(account, {
+    calendar_id: trackingId,
+    from: ctx.from.toISOString(),
+    to: ctx.to.toISOString(),
+    timezone: ctx.timezone ?? null,
+  });
+
+  if (result.status === "error") {
+    throw new CalendarFetchError(trackingId, result.error);
+  }
+
+  const username = account.type === "caldav" ? account.username : null;
+  return Promise.all(
+    result.data.map((event) => normalizeRemoteEvent(event, username)),
+  );
+}
+
+async function normalizeRemoteEvent(
+  remoteEvent: RemoteEvent,
+  username: string | null,
+): Promise<{
+  event: IncomingEvent;
+  eventParticipants: EventParticipant[];
+}> {
+  const meetingLink =
+    remoteEvent.url ??
+    (await extractMeetingLink(remoteEvent.description, remoteEvent.location));
+  const isRecurring = remoteEvent.original_start !== null;
+  const eventParticipants: EventParticipant[] = remoteEvent.attendees.map(
+    (attendee) => ({
+      name: attendee.name ?? undefined,
+      email: attendee.email ?? undefined,
+      is_organizer: false,
+      is_current_user: !!attendee.email && attendee.email === username,
+    }),
+  );
+
+  return {
+    event: {
+      tracking_id_event: remoteEvent.uid,
+      tracking_id_calendar: remoteEvent.calendar_id,
+      title: remoteEvent.title ?? undefined,
+      started_at: remoteEvent.start_date,
+      ended_at: remoteEvent.end_date,
+      location: remoteEvent.location ?? undefined,
+      meeting_link: meetingLink ?? undefined,
+      description: remoteEvent.description ?? undefined,
+      recurrence_series_id: isRecurring ? remoteEvent.uid : undefined,
+      has_recurrence_rules: isRecurring,
+      is_all_day: remoteEvent.is_all_day,
+      participants_json: JSON.stringify(eventParticipants),
+    },
+    eventParticipants,
+  };
+}
+
 async function normalizeAppleEvent(appleEvent: AppleEvent): Promise<{
   event: IncomingEvent;
   eventParticipants: EventParticipant[];
@@ -96,6 +167,7 @@ async function normalizeAppleEvent(appleEvent: AppleEvent): Promise<{
         appleEvent.recurrence?.series_identifier ?? undefined,
       has_recurrence_rules: appleEvent.has_recurrence_rules,
       is_all_day: appleEvent.is_all_day,
+      participants_json: JSON.stringify(eventParticipants),
     },
     eventParticipants,
   };
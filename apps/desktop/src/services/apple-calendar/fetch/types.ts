@@ -1,11 +1,6 @@
-import { EventStorage } from "@hypr/store";
+import { EventParticipant, EventStorage } from "@hypr/store";
 
-export type EventParticipant = {
-  name?: string;
-  email?: string;
-  is_organizer?: boolean;
-  is_current_user?: boolean;
-};
+export type { EventParticipant };
 
 export type IncomingEvent = {
   tracking_id_event: string;
@@ -19,6 +14,7 @@ export type IncomingEvent = {
   recurrence_series_id?: string;
   has_recurrence_rules: boolean;
   is_all_day: boolean;
+  participants_json?: string;
 };
 
 export type IncomingParticipants = Map<string, EventParticipant[]>;
@@ -34,12 +34,12 @@ async function run(
   queries: Queries<Schemas>,
   settingsStore?: settings.Store,
 ) {
-  const ctx = createCtx(store, queries);
+  const ctx = createCtx(store, queries, settingsStore);
   if (!ctx) {
     return null;
   }
 
-  const timezone = settingsStore?.getValue("timezone") as string | undefined;
+  const { timezone } = ctx;
 
   let incoming;
   let incomingParticipants;
@@ -45,6 +45,7 @@ export function executeForEventsSync(
         recurrence_series_id: event.recurrence_series_id,
         has_recurrence_rules: event.has_recurrence_rules,
         is_all_day: event.is_all_day,
+        participants_json: event.participants_json,
       });
       const key = getEventKey(
         event.tracking_id_event!,
@@ -84,6 +85,7 @@ export function executeForEventsSync(
         recurrence_series_id: incomingEvent.recurrence_series_id,
         has_recurrence_rules: incomingEvent.has_recurrence_rules,
         is_all_day: incomingEvent.is_all_day,
+        participants_json: incomingEvent.participants_json,
       } satisfies EventStorage);
     }
   });
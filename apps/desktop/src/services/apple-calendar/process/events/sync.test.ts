@@ -58,6 +58,7 @@ function createMockCtx(
     calendarTrackingIdToId:
       overrides.calendarTrackingIdToId ??
       new Map([["tracking-cal-1", "cal-1"]]),
+    calendarAccounts: new Map(),
     store,
     ...overrides,
   };
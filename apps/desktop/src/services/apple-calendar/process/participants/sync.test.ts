@@ -37,6 +37,7 @@ function createMockCtx(store: Ctx["store"]): Ctx {
     to: new Date("2024-02-01"),
     calendarIds: new Set(["cal-1"]),
     calendarTrackingIdToId: new Map([["tracking-cal-1", "cal-1"]]),
+    calendarAccounts: new Map(),
   };
 }
 
@@ -0,0 +1,104 @@
+import {
+  commands as detectCommands,
+  type Event as MatchEvent,
+  type MatchOutcome,
+} from "@hypr/plugin-detect";
+import type { Event } from "@hypr/store";
+
+import type * as main from "../../store/tinybase/store/main";
+import {
+  findSessionByEventId,
+  getEventParticipants,
+  getSessionEventById,
+} from "../../utils/session-event";
+
+// Mirrors the matcher's own window: running events, and ones starting soon.
+const LOOKBEHIND_MS = 12 * 60 * 60 * 1000;
+const LOOKAHEAD_MS = 15 * 60 * 1000;
+
+export async function matchSessionToMeeting(
+  store: main.Store,
+  sessionId: string,
+  timezone?: string,
+): Promise<MatchOutcome> {
+  if (getSessionEventById(store, sessionId)) {
+    return { type: "no_match" };
+  }
+
+  const events = collectCandidateEvents(store, timezone);
+  if (events.length === 0) {
+    return { type: "no_match" };
+  }
+
+  const result = await detectCommands.matchMeeting(
+    events,
+    getParticipantEmails(store, sessionId),
+  );
+  if (result.status === "error") {
+    throw new Error(result.error);
+  }
+  return result.data;
+}
+
+function collectCandidateEvents(
+  store: main.Store,
+  timezone?: string,
+): MatchEvent[] {
+  const now = Date.now();
+  const events: MatchEvent[] = [];
+
+  store.forEachRow("events", (eventId, _forEachCell) => {
+    const event = store.getRow("events", eventId) as Event;
+    const startedAt = Date.parse(event.started_at);
+    const endedAt = Date.parse(event.ended_at) || startedAt;
+    if (
+      Number.isNaN(startedAt) ||
+      startedAt > now + LOOKAHEAD_MS ||
+      endedAt < now - LOOKBEHIND_MS
+    ) {
+      return;
+    }
+
+    // An event that already has a note is not the one being started.
+    if (findSessionByEventId(store, eventId, timezone)) {
+      return;
+    }
+
+    events.push({
+      id: eventId,
+      title: event.title,
+      started_at: event.started_at,
+      ended_at: event.ended_at,
+      is_all_day: !!event.is_all_day,
+      location: event.location ?? null,
+      meeting_link: event.meeting_link ?? null,
+      description: event.description ?? null,
+      attendees: getEventParticipants(event)
+        .filter((participant) => participant.email)
+        .map((participant) => ({
+          email: participant.email!,
+          is_self: !!participant.is_current_user,
+        })),
+    });
+  });
+
+  return events;
+}
+
+function getParticipantEmails(store: main.Store, sessionId: string): string[] {
+  const emails: string[] = [];
+
+  store.forEachRow("mapping_session_participant", (mappingId, _forEachCell) => {
+    const mapping = store.getRow("mapping_session_participant", mappingId);
+    if (mapping?.session_id !== sessionId || mapping.source === "excluded") {
+      return;
+    }
+
+    const email = store.getCell("humans", String(mapping.human_id), "email");
+    if (email) {
+      emails.push(email);
+    }
+  });
+
+  return emails;
+}
@@ -0,0 +1,25 @@
+import * as _UI from "tinybase/ui-react/with-schemas";
+
+import { getCurrentWebviewWindowLabel } from "@hypr/plugin-windows";
+import { type Schemas } from "@hypr/store";
+
+import type { Store } from "../../store/main";
+import { createActionItemPersister } from "./persister";
+
+const { useCreatePersister } = _UI as _UI.WithSchemas<Schemas>;
+
+export function useActionItemPersister(store: Store) {
+  return useCreatePersister(
+    store,
+    async (store) => {
+      const persister = createActionItemPersister(store as Store);
+      if (getCurrentWebviewWindowLabel() === "main") {
+        await persister.startAutoPersisting();
+      } else {
+        await persister.startAutoLoad();
+      }
+      return persister;
+    },
+    [],
+  );
+}
@@ -0,0 +1,10 @@
+import type { Store } from "../../store/main";
+import { createJsonFilePersister } from "../factories";
+
+export function createActionItemPersister(store: Store) {
+  return createJsonFilePersister(store, {
+    tableName: "action_items",
+    filename: "action_items.json",
+    label: "ActionItemPersister",
+  });
+}
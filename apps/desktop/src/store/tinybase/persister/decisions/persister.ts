@@ -0,0 +1,10 @@
+import type { Store } from "../../store/main";
+import { createJsonFilePersister } from "../factories";
+
+export function createDecisionPersister(store: Store) {
+  return createJsonFilePersister(store, {
+    tableName: "decisions",
+    filename: "decisions.json",
+    label: "DecisionPersister",
+  });
+}
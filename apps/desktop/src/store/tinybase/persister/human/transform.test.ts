@@ -167,6 +167,7 @@ describe("humanToFrontmatter", () => {
         job_title: "Engineer",
         linkedin_username: "johndoe",
         pinned: false,
+        schema_version: 2,
       },
       body: "Notes",
     });
@@ -1,6 +1,8 @@
 import type { JsonValue } from "@hypr/plugin-fs-sync";
 import type { HumanStorage } from "@hypr/store";
 
+import { SCHEMA_VERSION, SCHEMA_VERSION_KEY } from "../shared";
+
 type HumanFrontmatter = Omit<HumanStorage, "memo">;
 
 function emailsToStore(frontmatter: Record<string, unknown>): string {
@@ -47,6 +49,7 @@ function storeToFrontmatter(
     job_title: store.job_title ?? "",
     linkedin_username: store.linkedin_username ?? "",
     pinned: store.pinned ?? false,
+    [SCHEMA_VERSION_KEY]: SCHEMA_VERSION.human,
   };
 }
 
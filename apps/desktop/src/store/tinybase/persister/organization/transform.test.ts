@@ -39,6 +39,7 @@ describe("organizationToFrontmatter", () => {
       frontmatter: {
         user_id: "user-1",
         name: "Acme Corp",
+        schema_version: 1,
       },
       body: "",
     });
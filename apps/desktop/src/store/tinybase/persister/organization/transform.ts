@@ -1,6 +1,8 @@
 import type { JsonValue } from "@hypr/plugin-fs-sync";
 import type { OrganizationStorage } from "@hypr/store";
 
+import { SCHEMA_VERSION, SCHEMA_VERSION_KEY } from "../shared";
+
 export function frontmatterToOrganization(
   frontmatter: Record<string, unknown>,
   _body: string,
@@ -19,6 +21,7 @@ export function organizationToFrontmatter(org: OrganizationStorage): {
     frontmatter: {
       name: org.name ?? "",
       user_id: org.user_id ?? "",
+      [SCHEMA_VERSION_KEY]: SCHEMA_VERSION.organization,
     },
     body: "",
   };
@@ -8,6 +8,7 @@ import {
   buildSessionPath,
   iterateTableRows,
   sanitizeFilename,
+  SCHEMA_VERSION,
   SESSION_MEMO_FILE,
   type TablesContent,
   type WriteOperation,
@@ -65,6 +66,7 @@ function collectEnhancedNotes(ctx: BuildContext): DocumentItem[] {
         template_id: note.template_id || undefined,
         position: note.position,
         title: note.title || undefined,
+        schema_version: SCHEMA_VERSION.enhancedNote,
       };
 
       return [{ frontmatter, content: markdown }, path] as DocumentItem;
@@ -101,6 +103,7 @@ function collectMemos(ctx: BuildContext): {
     const frontmatter: NoteFrontmatter = {
       id: session.id,
       session_id: session.id,
+      schema_version: SCHEMA_VERSION.memo,
     };
 
     items.push([{ frontmatter, content: markdown }, memoPath]);
@@ -36,6 +36,7 @@ export type TranscriptJson = {
 export type NoteFrontmatter = {
   id: string;
   session_id: string;
+  schema_version?: number;
   template_id?: string;
   position?: number;
   title?: string;
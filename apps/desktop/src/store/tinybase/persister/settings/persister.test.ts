@@ -186,6 +186,47 @@ describe("settingsPersister roundtrip", () => {
     });
   });
 
+  test("roundtrips calendar accounts", () => {
+    const original = {
+      ai: { llm: {}, stt: {} },
+      notification: {},
+      general: {},
+      language: {},
+      calendar: {
+        accounts: {
+          "account-1": {
+            kind: "caldav",
+            name: "Fastmail",
+            url: "https://caldav.fastmail.com/",
+            username: "me@fastmail.com",
+            password: "app-password",
+          },
+          "account-2": {
+            kind: "ics",
+            name: "Team",
+            url: "webcal://example.com/team.ics",
+            username: "",
+            password: "",
+          },
+        },
+      },
+    };
+
+    const [tables, values] = settingsToContent(original);
+    expect(Object.keys(tables.calendar_accounts ?? {})).toEqual([
+      "account-1",
+      "account-2",
+    ]);
+
+    const store = createMergeableStore()
+      .setTablesSchema(SCHEMA.table)
+      .setValuesSchema(SCHEMA.value);
+    store.setTables(tables);
+    store.setValues(values);
+
+    expect(storeToSettings(store)).toEqual(original);
+  });
+
   test("handles partial data - only ai settings", () => {
     const original = {
       ai: {
@@ -5,6 +5,13 @@ import { SETTINGS_MAPPING } from "../../store/settings";
 
 type ProviderData = { base_url: string; api_key: string };
 type ProviderRow = { type: "llm" | "stt"; base_url: string; api_key: string };
+type CalendarAccountRow = {
+  kind: string;
+  name: string;
+  url: string;
+  username: string;
+  password: string;
+};
 
 const JSON_ARRAY_FIELDS = new Set([
   "spoken_languages",
@@ -113,6 +120,28 @@ function settingsToProviderRows(
   return rows;
 }
 
+function settingsToCalendarAccountRows(
+  settings: unknown,
+): Record<string, CalendarAccountRow> {
+  const accounts = getByPath(settings, ["calendar", "accounts"]) as
+    | Record<string, Partial<CalendarAccountRow>>
+    | undefined;
+
+  const rows: Record<string, CalendarAccountRow> = {};
+  for (const [id, data] of Object.entries(accounts ?? {})) {
+    if (data) {
+      rows[id] = {
+        kind: data.kind ?? "",
+        name: data.name ?? "",
+        url: data.url ?? "",
+        username: data.username ?? "",
+        password: data.password ?? "",
+      };
+    }
+  }
+  return rows;
+}
+
 export function storeValuesToSettings(
   values: Record<string, unknown>,
 ): Record<string, unknown> {
@@ -157,8 +186,14 @@ function providerRowsToSettings(rows: Record<string, ProviderRow>): {
 
 export function settingsToContent(data: unknown): Content<Schemas> {
   const aiProviders = settingsToProviderRows(data);
+  const calendarAccounts = settingsToCalendarAccountRows(data);
   const values = settingsToStoreValues(data);
-  return [{ ai_providers: aiProviders }, values] as Content<Schemas>;
+
+  const tables: Record<string, unknown> = { ai_providers: aiProviders };
+  if (Object.keys(calendarAccounts).length > 0) {
+    tables.calendar_accounts = calendarAccounts;
+  }
+  return [tables, values] as Content<Schemas>;
 }
 
 export function storeToSettings(store: Store): Record<string, unknown> {
@@ -175,5 +210,12 @@ export function storeToSettings(store: Store): Record<string, unknown> {
     ...providers,
   };
 
+  const accounts = store.getTable("calendar_accounts") ?? {};
+  if (Object.keys(accounts).length > 0) {
+    (settings as Record<string, Record<string, unknown>>).calendar = {
+      accounts,
+    };
+  }
+
   return settings;
 }
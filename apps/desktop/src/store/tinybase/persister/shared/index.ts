@@ -6,3 +6,4 @@ export * from "./utils";
 export * from "./deletion-marker";
 export * from "./listener";
 export * from "./load-result";
+export * from "./schema-version";
@@ -0,0 +1,11 @@
+// Mirrors `Schema::VERSION` of each frontmatter type in plugins/fs-db/src/types.rs.
+// Files written without `schema_version` are treated as version 1 and get
+// rewritten by the next vault migration, so every writer must stamp it.
+export const SCHEMA_VERSION_KEY = "schema_version";
+
+export const SCHEMA_VERSION = {
+  memo: 1,
+  enhancedNote: 1,
+  human: 2,
+  organization: 1,
+} as const;
@@ -0,0 +1,25 @@
+import * as _UI from "tinybase/ui-react/with-schemas";
+
+import { getCurrentWebviewWindowLabel } from "@hypr/plugin-windows";
+import { type Schemas } from "@hypr/store";
+
+import type { Store } from "../../store/main";
+import { createTemplateSnippetPersister } from "./persister";
+
+const { useCreatePersister } = _UI as _UI.WithSchemas<Schemas>;
+
+export function useTemplateSnippetPersister(store: Store) {
+  return useCreatePersister(
+    store,
+    async (store) => {
+      const persister = createTemplateSnippetPersister(store as Store);
+      if (getCurrentWebviewWindowLabel() === "main") {
+        await persister.startAutoPersisting();
+      } else {
+        await persister.startAutoLoad();
+      }
+      return persister;
+    },
+    [],
+  );
+}
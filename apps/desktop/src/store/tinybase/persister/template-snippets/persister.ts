@@ -0,0 +1,10 @@
+import type { Store } from "../../store/main";
+import { createJsonFilePersister } from "../factories";
+
+export function createTemplateSnippetPersister(store: Store) {
+  return createJsonFilePersister(store, {
+    tableName: "template_snippets",
+    filename: "template_snippets.json",
+    label: "TemplateSnippetPersister",
+  });
+}
@@ -0,0 +1,145 @@
+import type {
+  ActionItemStatus,
+  ActionItemStorage,
+  DecisionStorage,
+} from "@hypr/store";
+
+import type { ExtractedActionItems } from "../../zustand/ai-task/task-configs/action-items-workflow";
+import type { Store } from "./main";
+
+// Replaces whatever was extracted from this note before. Items that were
+// already marked done keep their status when they are extracted again.
+export function saveExtractedActionItems(
+  store: Store,
+  {
+    sessionId,
+    enhancedNoteId,
+    extracted,
+  }: {
+    sessionId: string;
+    enhancedNoteId: string;
+    extracted: ExtractedActionItems;
+  },
+): void {
+  const userId = store.getValue("user_id") || "";
+  const createdAt = new Date().toISOString();
+  const owners = getOwnerLookup(store, sessionId, userId);
+  const previousStatus = new Map<string, ActionItemStatus>();
+
+  store.transaction(() => {
+    store.forEachRow("action_items", (rowId, _forEachCell) => {
+      if (
+        store.getCell("action_items", rowId, "enhanced_note_id") !==
+        enhancedNoteId
+      ) {
+        return;
+      }
+
+      const description = store.getCell("action_items", rowId, "description");
+      const status = store.getCell("action_items", rowId, "status");
+      if (typeof description === "string" && status === "done") {
+        previousStatus.set(normalize(description), "done");
+      }
+      store.delRow("action_items", rowId);
+    });
+
+    store.forEachRow("decisions", (rowId, _forEachCell) => {
+      if (
+        store.getCell("decisions", rowId, "enhanced_note_id") ===
+        enhancedNoteId
+      ) {
+        store.delRow("decisions", rowId);
+      }
+    });
+
+    for (const item of extracted.action_items) {
+      const description = item.description.trim();
+      if (!description) continue;
+
+      store.setRow("action_items", crypto.randomUUID(), {
+        user_id: userId,
+        created_at: createdAt,
+        session_id: sessionId,
+        enhanced_note_id: enhancedNoteId,
+        description,
+        owner_human_id: item.owner
+          ? owners.get(normalize(item.owner))
+          : undefined,
+        due_date: item.due_date ?? undefined,
+        status: previousStatus.get(normalize(description)) ?? "open",
+      } satisfies ActionItemStorage);
+    }
+
+    for (const decision of extracted.decisions) {
+      const description = decision.description.trim();
+      if (!description) continue;
+
+      store.setRow("decisions", crypto.randomUUID(), {
+        user_id: userId,
+        created_at: createdAt,
+        session_id: sessionId,
+        enhanced_note_id: enhancedNoteId,
+        description,
+      } satisfies DecisionStorage);
+    }
+  });
+}
+
+export function setActionItemStatus(
+  store: Store,
+  actionItemId: string,
+  status: ActionItemStatus,
+): void {
+  store.setCell("action_items", actionItemId, "status", status);
+}
+
+// Maps participant names, lowercased, to their human ids. The user's own
+// human also answers to "me" and "I", which models sometimes use as owner.
+function getOwnerLookup(
+  store: Store,
+  sessionId: string,
+  userId: string,
+): Map<string, string> {
+  const owners = new Map<string, string>();
+
+  const add = (humanId: string) => {
+    const name = store.getCell("humans", humanId, "name");
+    if (typeof name === "string" && name.trim()) {
+      owners.set(normalize(name), humanId);
+      const firstName = normalize(name).split(" ")[0];
+      if (!owners.has(firstName)) {
+        owners.set(firstName, humanId);
+      }
+    }
+  };
+
+  store.forEachRow("mapping_session_participant", (mappingId, _forEachCell) => {
+    if (
+      store.getCell("mapping_session_participant", mappingId, "session_id") !==
+      sessionId
+    ) {
+      return;
+    }
+
+    const humanId = store.getCell(
+      "mapping_session_participant",
+      mappingId,
+      "human_id",
+    );
+    if (typeof humanId === "string" && humanId) {
+      add(humanId);
+    }
+  });
+
+  if (userId && store.hasRow("humans", userId)) {
+    add(userId);
+    owners.set("me", userId);
+    owners.set("i", userId);
+  }
+
+  return owners;
+}
+
+function normalize(value: string): string {
+  return value.trim().toLowerCase();
+}
@@ -227,6 +227,20 @@ export function deleteSessionCascade(
         sessionId,
         "enhanced_notes",
       );
+      deleteByIndex(
+        store,
+        indexes,
+        main.INDEXES.actionItemsBySession,
+        sessionId,
+        "action_items",
+      );
+      deleteByIndex(
+        store,
+        indexes,
+        main.INDEXES.decisionsBySession,
+        sessionId,
+        "decisions",
+      );
 
       store.delRow("sessions", sessionId);
     });
@@ -22,6 +22,8 @@ export const TABLES = Object.keys(
   SCHEMA.table,
 ) as (keyof typeof SCHEMA.table)[];
 
+const SYNCED_CALENDAR_PROVIDERS = ["apple", "caldav", "ics"];
+
 const {
   useCreateMergeableStore,
   useCreateSynchronizer,
@@ -176,16 +178,37 @@ export const StoreComponent = () => {
           },
         )
         .setQueryDefinition(
-          QUERIES.enabledAppleCalendars,
+          QUERIES.enabledCalendars,
           "calendars",
           ({ select, where }) => {
             select("provider");
             where(
               (getCell) =>
-                getCell("enabled") === true && getCell("provider") === "apple",
+                getCell("enabled") === true &&
+                SYNCED_CALENDAR_PROVIDERS.includes(
+                  getCell("provider") as string,
+                ),
             );
           },
         )
+        .setQueryDefinition(
+          QUERIES.openActionItems,
+          "action_items",
+          ({ select, join, where }) => {
+            select("session_id");
+            select("description");
+            select("owner_human_id");
+            select("due_date");
+            where("status", "open");
+
+            join("sessions", "session_id").as("session");
+            select("session", "title").as("session_title");
+            select("session", "created_at").as("session_created_at");
+
+            join("humans", "owner_human_id").as("owner");
+            select("owner", "name").as("owner_name");
+          },
+        )
         .setQueryDefinition(
           QUERIES.userTemplates,
           "templates",
@@ -312,6 +335,24 @@ export const StoreComponent = () => {
         INDEXES.mentionsByTarget,
         "mapping_mention",
         "target_id",
+      )
+      .setIndexDefinition(
+        INDEXES.actionItemsBySession,
+        "action_items",
+        "session_id",
+        "created_at",
+      )
+      .setIndexDefinition(
+        INDEXES.actionItemsByOwner,
+        "action_items",
+        "owner_human_id",
+        "due_date",
+      )
+      .setIndexDefinition(
+        INDEXES.decisionsBySession,
+        "decisions",
+        "session_id",
+        "created_at",
       ),
   );
 
@@ -352,8 +393,9 @@ export const QUERIES = {
   visibleChatShortcuts: "visibleChatShortcuts",
   sessionParticipantsWithDetails: "sessionParticipantsWithDetails",
   sessionRecordingTimes: "sessionRecordingTimes",
-  enabledAppleCalendars: "enabledAppleCalendars",
+  enabledCalendars: "enabledCalendars",
   userTemplates: "userTemplates",
+  openActionItems: "openActionItems",
 } as const;
 
 export const METRICS = {
@@ -377,6 +419,9 @@ export const INDEXES = {
   enhancedNotesByTemplate: "enhancedNotesByTemplate",
   mentionsBySource: "mentionsBySource",
   mentionsByTarget: "mentionsByTarget",
+  actionItemsBySession: "actionItemsBySession",
+  actionItemsByOwner: "actionItemsByOwner",
+  decisionsBySession: "decisionsBySession",
 } as const;
 
 export const RELATIONSHIPS = {
@@ -439,7 +484,7 @@ interface _QueryResultRows {
     min_started_at: number;
     max_ended_at: number;
   };
-  enabledAppleCalendars: {
+  enabledCalendars: {
     provider: string;
   };
   userTemplates: {
@@ -448,6 +493,15 @@ interface _QueryResultRows {
     sections: string;
     user_id: string;
   };
+  openActionItems: {
+    session_id: string;
+    description: string;
+    owner_human_id?: string;
+    due_date?: string;
+    session_title?: string;
+    session_created_at?: string;
+    owner_name?: string;
+  };
 }
 
 export type QueryResultRowMap = { [K in QueryId]: _QueryResultRows[K] };
@@ -2,14 +2,17 @@ import { useEffect } from "react";
 
 import { getCurrentWebviewWindowLabel } from "@hypr/plugin-windows";
 
+import { useActionItemPersister } from "../persister/action-items";
 import { useCalendarPersister } from "../persister/calendar";
 import { useChatPersister } from "../persister/chat";
 import { useChatShortcutPersister } from "../persister/chat-shortcuts";
+import { useDecisionPersister } from "../persister/decisions";
 import { useEventsPersister } from "../persister/events";
 import { useHumanPersister } from "../persister/human";
 import { useOrganizationPersister } from "../persister/organization";
 import { usePromptPersister } from "../persister/prompts";
 import { useSessionPersister } from "../persister/session";
+import { useTemplateSnippetPersister } from "../persister/template-snippets";
 import { useTemplatePersister } from "../persister/templates";
 import { useValuesPersister } from "../persister/values";
 import { useInitializeStore } from "./initialize";
@@ -26,7 +29,10 @@ export function useMainPersisters(store: Store) {
   const chatShortcutPersister = useChatShortcutPersister(store);
   const promptPersister = usePromptPersister(store);
   const templatePersister = useTemplatePersister(store);
+  const templateSnippetPersister = useTemplateSnippetPersister(store);
   const calendarPersister = useCalendarPersister(store);
+  const actionItemPersister = useActionItemPersister(store);
+  const decisionPersister = useDecisionPersister(store);
 
   useEffect(() => {
     if (getCurrentWebviewWindowLabel() !== "main") {
@@ -43,7 +49,10 @@ export function useMainPersisters(store: Store) {
       { id: "chatShortcut", persister: chatShortcutPersister },
       { id: "prompt", persister: promptPersister },
       { id: "template", persister: templatePersister },
+      { id: "templateSnippet", persister: templateSnippetPersister },
       { id: "calendar", persister: calendarPersister },
+      { id: "actionItem", persister: actionItemPersister },
+      { id: "decision", persister: decisionPersister },
     ];
 
     const unsubscribes = persisters
@@ -67,7 +76,10 @@ export function useMainPersisters(store: Store) {
     chatShortcutPersister,
     promptPersister,
     templatePersister,
+    templateSnippetPersister,
     calendarPersister,
+    actionItemPersister,
+    decisionPersister,
   ]);
 
   useInitializeStore(store, {
@@ -86,6 +98,9 @@ export function useMainPersisters(store: Store) {
     chatShortcutPersister,
     promptPersister,
     templatePersister,
+    templateSnippetPersister,
     calendarPersister,
+    actionItemPersister,
+    decisionPersister,
   };
 }
@@ -1,10 +1,19 @@
 import { commands as analyticsCommands } from "@hypr/plugin-analytics";
-import type { Event, SessionEvent } from "@hypr/store";
+import type {
+  Event,
+  EventParticipant,
+  HumanStorage,
+  MappingSessionParticipantStorage,
+  SessionEvent,
+} from "@hypr/store";
 import { json2md } from "@hypr/tiptap/shared";
 
 import { DEFAULT_USER_ID } from "../../../utils";
 import { id } from "../../../utils";
-import { findSessionByEventId } from "../../../utils/session-event";
+import {
+  findSessionByEventId,
+  getEventParticipants,
+} from "../../../utils/session-event";
 import * as main from "./main";
 
 type Store = NonNullable<ReturnType<typeof main.UI.useStore>>;
@@ -42,9 +51,163 @@ export function getOrCreateSessionForEventId(
     return existingSessionId;
   }
 
+  const sessionEvent = toSessionEvent(
+    store.getRow("events", eventId) as Event,
+  );
+
+  const sessionId = id();
+  store.setRow("sessions", sessionId, {
+    event_json: JSON.stringify(sessionEvent),
+    title: title ?? "",
+    created_at: new Date().toISOString(),
+    raw_md: "",
+    user_id: DEFAULT_USER_ID,
+  });
+  void analyticsCommands.event({
+    event: "note_created",
+    has_event_id: true,
+  });
+  return sessionId;
+}
+
+// Links an existing session to an event, keeping a title the user already
+// typed, and adds the event's attendees as participants.
+export function linkSessionToEvent(
+  store: Store,
+  sessionId: string,
+  eventId: string,
+): boolean {
+  if (
+    !store.hasRow("sessions", sessionId) ||
+    !store.hasRow("events", eventId)
+  ) {
+    return false;
+  }
+
   const event = store.getRow("events", eventId) as Event;
+  const title = store.getCell("sessions", sessionId, "title");
+
+  store.transaction(() => {
+    store.setCell(
+      "sessions",
+      sessionId,
+      "event_json",
+      JSON.stringify(toSessionEvent(event)),
+    );
+    if (!title?.trim()) {
+      store.setCell("sessions", sessionId, "title", event.title);
+    }
+    addEventParticipants(store, sessionId, getEventParticipants(event));
+  });
+  return true;
+}
+
+// Same "auto" mappings the calendar sync keeps up to date, so its next pass
+// reconciles them instead of duplicating them. Attendees without an
+// organization get the one most contacts on their email domain belong to.
+function addEventParticipants(
+  store: Store,
+  sessionId: string,
+  participants: EventParticipant[],
+) {
+  const userId = store.getValue("user_id");
+  if (!userId || participants.length === 0) {
+    return;
+  }
+
+  const humansByEmail = new Map<string, string>();
+  const humansByDomain = new Map<string, string[]>();
+  store.forEachRow("humans", (humanId, _forEachCell) => {
+    const email = store.getCell("humans", humanId, "email");
+    if (!email?.trim()) return;
+
+    const key = email.trim().toLowerCase();
+    humansByEmail.set(key, humanId);
+    const domain = emailDomain(key);
+    humansByDomain.set(domain, [
+      ...(humansByDomain.get(domain) ?? []),
+      humanId,
+    ]);
+  });
+
+  const mapped = new Set<string>();
+  store.forEachRow("mapping_session_participant", (mappingId, _forEachCell) => {
+    const mapping = store.getRow("mapping_session_participant", mappingId);
+    if (mapping?.session_id === sessionId && mapping.human_id) {
+      mapped.add(String(mapping.human_id));
+    }
+  });
+
+  for (const participant of participants) {
+    const email = participant.email?.trim();
+    if (!email) continue;
+
+    const key = email.toLowerCase();
+    let humanId = humansByEmail.get(key);
+    if (!humanId) {
+      humanId = id();
+      store.setRow("humans", humanId, {
+        user_id: String(userId),
+        name: participant.name || email,
+        email,
+        org_id: "",
+        job_title: "",
+        linkedin_username: "",
+        memo: "",
+        pinned: false,
+      } satisfies HumanStorage);
+      humansByEmail.set(key, humanId);
+    }
+
+    if (!store.getCell("humans", humanId, "org_id")) {
+      const orgId = domainOrganization(
+        store,
+        humansByDomain.get(emailDomain(key)) ?? [],
+      );
+      if (orgId) {
+        store.setCell("humans", humanId, "org_id", orgId);
+      }
+    }
+
+    if (mapped.has(humanId)) continue;
+    mapped.add(humanId);
+    store.setRow("mapping_session_participant", id(), {
+      user_id: String(userId),
+      session_id: sessionId,
+      human_id: humanId,
+      source: "auto",
+    } satisfies MappingSessionParticipantStorage);
+  }
+}
+
+function emailDomain(email: string): string {
+  return email.slice(email.lastIndexOf("@") + 1);
+}
+
+// Only a clear majority counts, so shared domains like gmail.com don't lump
+// unrelated people into one organization.
+function domainOrganization(
+  store: Store,
+  humanIds: string[],
+): string | undefined {
+  const votes = new Map<string, number>();
+  for (const humanId of humanIds) {
+    const orgId = store.getCell("humans", humanId, "org_id");
+    if (orgId && store.hasRow("organizations", orgId)) {
+      votes.set(orgId, (votes.get(orgId) ?? 0) + 1);
+    }
+  }
+
+  for (const [orgId, count] of votes) {
+    if (count * 2 > humanIds.length) {
+      return orgId;
+    }
+  }
+  return undefined;
+}
 
-  let sessionEvent: SessionEvent = {
+function toSessionEvent(event: Event): SessionEvent {
+  return {
     tracking_id: event.tracking_id_event,
     calendar_id: event.calendar_id,
     title: event.title,
@@ -58,20 +221,6 @@ export function getOrCreateSessionForEventId(
     description: event.description,
     recurrence_series_id: event.recurrence_series_id,
   };
-
-  const sessionId = id();
-  store.setRow("sessions", sessionId, {
-    event_json: JSON.stringify(sessionEvent),
-    title: title ?? "",
-    created_at: new Date().toISOString(),
-    raw_md: "",
-    user_id: DEFAULT_USER_ID,
-  });
-  void analyticsCommands.event({
-    event: "note_created",
-    has_event_id: true,
-  });
-  return sessionId;
 }
 
 export function isSessionEmpty(store: Store, sessionId: string): boolean {
@@ -23,6 +23,18 @@ export const SETTINGS_MAPPING = {
       type: "boolean",
       path: ["general", "save_recordings"],
     },
+    recording_format: {
+      type: "string",
+      path: ["general", "recording_format"],
+    },
+    recording_stems: {
+      type: "string",
+      path: ["general", "recording_stems"],
+    },
+    speaker_separation: {
+      type: "boolean",
+      path: ["general", "speaker_separation"],
+    },
     notification_event: {
       type: "boolean",
       path: ["notification", "event"],
@@ -82,6 +94,15 @@ export const SETTINGS_MAPPING = {
         api_key: { type: "string" },
       },
     },
+    calendar_accounts: {
+      schema: {
+        kind: { type: "string" },
+        name: { type: "string" },
+        url: { type: "string" },
+        username: { type: "string" },
+        password: { type: "string" },
+      },
+    },
   },
 } as const;
 
@@ -108,6 +129,7 @@ export const SCHEMA = {
     ]),
   ) as {
     ai_providers: typeof SETTINGS_MAPPING.tables.ai_providers.schema;
+    calendar_accounts: typeof SETTINGS_MAPPING.tables.calendar_accounts.schema;
   } satisfies TablesSchema,
 } as const;
 
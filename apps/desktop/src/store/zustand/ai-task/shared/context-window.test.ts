@@ -0,0 +1,46 @@
+import type { LanguageModel } from "ai";
+import { describe, expect, it } from "vitest";
+
+import { chunkBudget, contextWindow } from "./context-window";
+
+function qwen3(provider: string): LanguageModel {
+  return { provider, modelId: "qwen3:8b" } as unknown as LanguageModel;
+}
+
+describe("contextWindow", () => {
+  it.each([
+    ["anthropic/claude-sonnet-4.5", 200_000],
+    ["gemini-2.5-flash", 1_000_000],
+    ["openai/gpt-4.1-nano", 1_000_000],
+    ["gpt-4o-mini", 128_000],
+    ["o3-mini", 128_000],
+    ["llama3.2:3b", 128_000],
+    ["gemma3:4b", 8_192],
+  ])("%s -> %d", (modelId, expected) => {
+    expect(contextWindow(modelId)).toBe(expected);
+  });
+
+  it.each(["ollama.chat", "lmstudio.chat"])(
+    "caps %s models at the default",
+    (provider) => {
+      expect(contextWindow(qwen3(provider))).toBe(8_192);
+    },
+  );
+
+  it("uses the model's window for hosted providers", () => {
+    expect(contextWindow(qwen3("openrouter.chat"))).toBe(128_000);
+  });
+});
+
+describe("chunkBudget", () => {
+  it("leaves room for the prompt and output", () => {
+    expect(chunkBudget("claude-haiku-4-5")).toEqual({
+      maxTokens: 200_000 - 4_096,
+      maxDurationMs: 20 * 60 * 1000,
+    });
+  });
+
+  it("assumes a small context for unknown models", () => {
+    expect(chunkBudget("phi-2").maxTokens).toBe(8_192 - 4_096);
+  });
+});
@@ -0,0 +1,58 @@
+import type { LanguageModel } from "ai";
+
+import type { ChunkBudget } from "@hypr/plugin-template";
+
+// Local and unrecognized models are often run with small contexts.
+const DEFAULT_CONTEXT_WINDOW = 8_192;
+
+// Room for the system prompt, template and the generated note.
+const RESERVED_TOKENS = 4_096;
+
+const MIN_CHUNK_TOKENS = 2_000;
+
+// Even a large context loses detail over hours of conversation.
+const MAX_CHUNK_DURATION_MS = 20 * 60 * 1000;
+
+// Local servers load models with far less context than they support (Ollama
+// defaults to a few thousand tokens), so the model's own window is no guide.
+const LOCAL_PROVIDERS = ["ollama", "lmstudio"];
+
+const KNOWN_CONTEXT_WINDOWS: [RegExp, number][] = [
+  [/gemini/, 1_000_000],
+  [/gpt-4\.1/, 1_000_000],
+  [/claude/, 200_000],
+  [/gpt-5|gpt-4o|(^|\/)o[134](-|$)/, 128_000],
+  [/mistral-(large|medium|small)|ministral/, 128_000],
+  [/llama-?3\.[123]|qwen-?3|deepseek/, 128_000],
+];
+
+export function contextWindow(model: LanguageModel): number {
+  // OpenAI-compatible models report their provider as `<name>.chat`.
+  if (
+    typeof model !== "string" &&
+    LOCAL_PROVIDERS.includes(model.provider.split(".")[0])
+  ) {
+    return DEFAULT_CONTEXT_WINDOW;
+  }
+
+  const modelId = (
+    typeof model === "string" ? model : model.modelId
+  ).toLowerCase();
+
+  const known = KNOWN_CONTEXT_WINDOWS.find(([pattern]) =>
+    pattern.test(modelId),
+  );
+  return known?.[1] ?? DEFAULT_CONTEXT_WINDOW;
+}
+
+// Transcripts that fit the model's context and run under the duration limit go
+// in one prompt; anything longer is split and summarized part by part.
+export function chunkBudget(model: LanguageModel): ChunkBudget {
+  return {
+    maxTokens: Math.max(
+      MIN_CHUNK_TOKENS,
+      contextWindow(model) - RESERVED_TOKENS,
+    ),
+    maxDurationMs: MAX_CHUNK_DURATION_MS,
+  };
+}
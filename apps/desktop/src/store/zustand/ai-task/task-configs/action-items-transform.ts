@@ -0,0 +1,50 @@
+import { isValidTiptapContent, json2md } from "@hypr/tiptap/shared";
+
+import type { TaskArgsMap, TaskArgsMapTransformed, TaskConfig } from ".";
+import type { Store as MainStore } from "../../../tinybase/store/main";
+import type { Store as SettingsStore } from "../../../tinybase/store/settings";
+import { getParticipants, getSessionData } from "./enhance-transform";
+
+export const actionItemsTransform: Pick<
+  TaskConfig<"actionItems">,
+  "transformArgs"
+> = {
+  transformArgs,
+};
+
+async function transformArgs(
+  args: TaskArgsMap["actionItems"],
+  store: MainStore,
+  settingsStore: SettingsStore,
+): Promise<TaskArgsMapTransformed["actionItems"]> {
+  const { sessionId, enhancedNoteId } = args;
+
+  return {
+    language: getLanguage(settingsStore),
+    session: getSessionData(sessionId, store),
+    participants: getParticipants(sessionId, store),
+    enhancedNote: getEnhancedNoteMarkdown(enhancedNoteId, store),
+  };
+}
+
+function getEnhancedNoteMarkdown(
+  enhancedNoteId: string,
+  store: MainStore,
+): string {
+  const content = store.getCell("enhanced_notes", enhancedNoteId, "content");
+  if (typeof content !== "string" || !content.trim()) {
+    return "";
+  }
+
+  try {
+    const parsed = JSON.parse(content);
+    return isValidTiptapContent(parsed) ? json2md(parsed) : content;
+  } catch {
+    return content;
+  }
+}
+
+function getLanguage(settingsStore: SettingsStore): string | null {
+  const value = settingsStore.getValue("ai_language");
+  return typeof value === "string" && value.length > 0 ? value : null;
+}
@@ -0,0 +1,146 @@
+import { generateId, generateText, type LanguageModel, Output } from "ai";
+import { z } from "zod";
+
+import { commands as templateCommands } from "@hypr/plugin-template";
+
+import type { TaskArgsMapTransformed, TaskConfig } from ".";
+import type { Store } from "../../../tinybase/store/main";
+
+export const extractedActionItemsSchema = z.object({
+  action_items: z.array(
+    z.object({
+      description: z.string(),
+      owner: z.string().nullable(),
+      due_date: z.string().nullable(),
+    }),
+  ),
+  decisions: z.array(z.object({ description: z.string() })),
+});
+
+export type ExtractedActionItems = z.infer<typeof extractedActionItemsSchema>;
+
+// Only the bundled local-llm server reads `metadata.grammar`, to constrain
+// decoding to the same shape. Provider options are forwarded by provider name,
+// so other servers never receive it.
+const LOCAL_LLM_PROVIDER_OPTIONS = {
+  "local-llm": { metadata: { grammar: { task: "action-items" } } },
+};
+
+export const actionItemsWorkflow: Pick<
+  TaskConfig<"actionItems">,
+  "executeWorkflow" | "transforms"
+> = {
+  executeWorkflow,
+  transforms: [],
+};
+
+async function* executeWorkflow(params: {
+  model: LanguageModel;
+  args: TaskArgsMapTransformed["actionItems"];
+  onProgress: (step: any) => void;
+  signal: AbortSignal;
+  store: Store;
+}) {
+  const { model, args, onProgress, signal } = params;
+
+  if (!args.enhancedNote.trim()) {
+    return;
+  }
+
+  const system = await getSystemPrompt(args);
+  const prompt = await getUserPrompt(args);
+
+  onProgress({ type: "generating" });
+
+  const extracted = await generateActionItems({
+    model,
+    system,
+    prompt,
+    signal,
+  });
+
+  yield {
+    type: "text-delta" as const,
+    id: generateId(),
+    text: JSON.stringify(extracted),
+  };
+}
+
+async function generateActionItems(params: {
+  model: LanguageModel;
+  system: string;
+  prompt: string;
+  signal: AbortSignal;
+}): Promise<ExtractedActionItems> {
+  const { model, system, prompt, signal } = params;
+
+  try {
+    const result = await generateText({
+      model,
+      temperature: 0,
+      system,
+      prompt,
+      output: Output.object({ schema: extractedActionItemsSchema }),
+      providerOptions: LOCAL_LLM_PROVIDER_OPTIONS,
+      abortSignal: signal,
+    });
+
+    if (result.output) {
+      return result.output;
+    }
+  } catch (error) {
+    if (signal.aborted) {
+      throw error;
+    }
+  }
+
+  const fallbackResult = await generateText({
+    model,
+    temperature: 0,
+    system,
+    prompt,
+    providerOptions: LOCAL_LLM_PROVIDER_OPTIONS,
+    abortSignal: signal,
+  });
+
+  return parseActionItems(fallbackResult.text);
+}
+
+export function parseActionItems(text: string): ExtractedActionItems {
+  const jsonMatch = text.match(/\{[\s\S]*\}/);
+  if (!jsonMatch) {
+    throw new Error("Model did not return action items as JSON");
+  }
+
+  return extractedActionItemsSchema.parse(JSON.parse(jsonMatch[0]));
+}
+
+async function getSystemPrompt(args: TaskArgsMapTransformed["actionItems"]) {
+  const result = await templateCommands.render({
+    actionItemsSystem: {
+      language: args.language,
+    },
+  });
+
+  if (result.status === "error") {
+    throw new Error(result.error);
+  }
+
+  return result.data;
+}
+
+async function getUserPrompt(args: TaskArgsMapTransformed["actionItems"]) {
+  const result = await templateCommands.render({
+    actionItemsUser: {
+      session: args.session,
+      participants: args.participants,
+      enhancedNote: args.enhancedNote,
+    },
+  });
+
+  if (result.status === "error") {
+    throw new Error(result.error);
+  }
+
+  return result.data;
+}
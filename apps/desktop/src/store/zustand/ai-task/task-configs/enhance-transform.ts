@@ -5,7 +5,9 @@ import type {
   Session,
   TemplateSection,
   Transcript,
+  UserTemplateContext,
 } from "@hypr/plugin-template";
+import { commands as templateCommands } from "@hypr/plugin-template";
 
 import type { TaskArgsMap, TaskArgsMapTransformed, TaskConfig } from ".";
 import {
@@ -66,8 +68,14 @@ async function transformArgs(
   const { sessionId, templateId } = args;
 
   const sessionContext = getSessionContext(sessionId, store);
-  const template = templateId ? getTemplateData(templateId, store) : null;
   const language = getLanguage(settingsStore);
+  const template = templateId
+    ? await getTemplateData(templateId, store, {
+        session: sessionContext.session,
+        participants: sessionContext.participants,
+        language,
+      })
+    : null;
 
   return {
     language,
@@ -103,6 +111,8 @@ function formatTranscripts(
           (s): Segment => ({
             speaker: s.speaker_label,
             text: s.text,
+            startMs: s.start_ms,
+            endMs: s.end_ms,
           }),
         ),
         startedAt: Number.isFinite(startedAt) ? startedAt : null,
@@ -140,7 +150,10 @@ function getSessionContext(sessionId: string, store: MainStore) {
   };
 }
 
-function getSessionData(sessionId: string, store: MainStore): Session {
+export function getSessionData(
+  sessionId: string,
+  store: MainStore,
+): Session {
   const rawTitle = getStringCell(store, "sessions", sessionId, "title");
   const parsed = getSessionEventById(store, sessionId);
 
@@ -164,7 +177,10 @@ function getSessionData(sessionId: string, store: MainStore): Session {
   };
 }
 
-function getParticipants(sessionId: string, store: MainStore): Participant[] {
+export function getParticipants(
+  sessionId: string,
+  store: MainStore,
+): Participant[] {
   const participants: Participant[] = [];
 
   store.forEachRow("mapping_session_participant", (mappingId, _forEachCell) => {
@@ -203,11 +219,12 @@ function getParticipants(sessionId: string, store: MainStore): Participant[] {
   return participants;
 }
 
-function getTemplateData(
+async function getTemplateData(
   templateId: string,
   store: MainStore,
-): EnhanceTemplate {
-  return {
+  ctx: UserTemplateContext,
+): Promise<EnhanceTemplate> {
+  const template: EnhanceTemplate = {
     title: getStringCell(store, "templates", templateId, "title"),
     description:
       getOptionalStringCell(store, "templates", templateId, "description") ??
@@ -216,6 +233,41 @@ function getTemplateData(
       store.getCell("templates", templateId, "sections"),
     ),
   };
+
+  const source = getOptionalStringCell(store, "templates", templateId, "body");
+  if (!source?.trim()) {
+    return template;
+  }
+
+  const result = await templateCommands.renderUserTemplate(
+    source,
+    getTemplateSnippets(store),
+    ctx,
+  );
+  if (result.status === "error") {
+    throw new Error(result.error);
+  }
+
+  return { ...template, body: result.data };
+}
+
+export function getTemplateSnippets(store: MainStore): Record<string, string> {
+  const snippets: Record<string, string> = {};
+
+  store.forEachRow("template_snippets", (snippetId, _forEachCell) => {
+    const name = getOptionalStringCell(
+      store,
+      "template_snippets",
+      snippetId,
+      "name",
+    );
+    const content = store.getCell("template_snippets", snippetId, "content");
+    if (name?.trim() && typeof content === "string") {
+      snippets[name.trim()] = content;
+    }
+  });
+
+  return snippets;
 }
 
 function parseTemplateSections(raw: unknown): TemplateSection[] {
@@ -0,0 +1,163 @@
+import type { TextStreamPart, ToolSet } from "ai";
+import { beforeEach, describe, expect, it, vi } from "vitest";
+
+import type { Transcript } from "@hypr/plugin-template";
+
+import type { TaskArgsMapTransformed } from ".";
+import type { Store } from "../../../tinybase/store/main";
+import { enhanceWorkflow } from "./enhance-workflow";
+
+const aiMocks = vi.hoisted(() => ({
+  generateText: vi.fn(),
+  streamText: vi.fn(),
+}));
+
+// Renders each template as its JSON input, so prompts can be inspected.
+const templateMocks = vi.hoisted(() => ({
+  render: vi.fn(async (template: unknown) => ({
+    status: "ok",
+    data: JSON.stringify(template),
+  })),
+  renderCustom: vi.fn(),
+  chunkTranscripts: vi.fn(),
+}));
+
+vi.mock("ai", async (importOriginal) => ({
+  ...(await importOriginal<typeof import("ai")>()),
+  ...aiMocks,
+}));
+vi.mock("@hypr/plugin-template", () => ({ commands: templateMocks }));
+vi.mock("../../../tinybase/store/prompts", () => ({
+  getCustomPrompt: () => null,
+}));
+
+function transcript(...lines: string[]): Transcript {
+  return {
+    segments: lines.map((text) => ({ text, speaker: "Alice" })),
+    startedAt: null,
+    endedAt: null,
+  };
+}
+
+const args: TaskArgsMapTransformed["enhance"] = {
+  language: null,
+  session: { title: "Planning", startedAt: null, endedAt: null, event: null },
+  participants: [],
+  template: {
+    title: "",
+    description: null,
+    sections: [{ title: "Summary", description: null }],
+  },
+  transcripts: [transcript("ship it friday", "need more tests")],
+};
+
+async function* textStream(
+  text: string,
+): AsyncIterable<TextStreamPart<ToolSet>> {
+  yield { type: "text-delta", text, id: "1" };
+}
+
+const progress: unknown[] = [];
+
+async function run() {
+  const parts: TextStreamPart<ToolSet>[] = [];
+
+  const stream = enhanceWorkflow.executeWorkflow({
+    model: "gpt-4o-mini",
+    args,
+    onProgress: (step) => progress.push(step),
+    signal: new AbortController().signal,
+    store: {} as Store,
+  });
+  for await (const part of stream) {
+    parts.push(part);
+  }
+
+  return parts;
+}
+
+function finalPrompt() {
+  return JSON.parse(aiMocks.streamText.mock.calls[0][0].prompt);
+}
+
+describe("enhanceWorkflow", () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+    progress.length = 0;
+    aiMocks.streamText.mockImplementation(() => ({
+      fullStream: textStream("# Summary\n\n- Ship it on Friday"),
+    }));
+  });
+
+  it("summarizes a transcript that fits in one pass", async () => {
+    templateMocks.chunkTranscripts.mockResolvedValue({
+      status: "ok",
+      data: [args.transcripts],
+    });
+
+    const parts = await run();
+
+    expect(templateMocks.chunkTranscripts).toHaveBeenCalledWith(
+      args.transcripts,
+      { maxTokens: 128_000 - 4_096, maxDurationMs: 20 * 60 * 1000 },
+    );
+    expect(aiMocks.generateText).not.toHaveBeenCalled();
+    expect(progress).toEqual([{ type: "generating" }]);
+    expect(finalPrompt().enhanceUser.transcripts).toEqual(args.transcripts);
+    expect(parts).toHaveLength(1);
+  });
+
+  it("extracts notes per chunk and reduces them into the note", async () => {
+    const chunks = [
+      [transcript("ship it friday")],
+      [transcript("need more tests")],
+      [transcript("tests by thursday")],
+    ];
+    templateMocks.chunkTranscripts.mockResolvedValue({
+      status: "ok",
+      data: chunks,
+    });
+    aiMocks.generateText.mockImplementation(async () => ({
+      text: `notes ${aiMocks.generateText.mock.calls.length}`,
+    }));
+
+    await run();
+
+    expect(progress).toEqual([
+      { type: "extracting", chunk: 1, total: 3 },
+      { type: "extracting", chunk: 2, total: 3 },
+      { type: "extracting", chunk: 3, total: 3 },
+      { type: "generating" },
+    ]);
+
+    const chunkPrompts = aiMocks.generateText.mock.calls.map(
+      ([call]) => JSON.parse(call.prompt).enhanceChunkUser,
+    );
+    expect(chunkPrompts.map((p) => [p.chunk, p.totalChunks])).toEqual([
+      [1, 3],
+      [2, 3],
+      [3, 3],
+    ]);
+    expect(chunkPrompts.map((p) => p.transcripts)).toEqual(chunks);
+
+    const { enhanceReduceUser } = finalPrompt();
+    expect(enhanceReduceUser.chunkNotes).toEqual([
+      "notes 1",
+      "notes 2",
+      "notes 3",
+    ]);
+    expect(enhanceReduceUser.template).toEqual(args.template);
+  });
+
+  it("stops when a chunk fails to summarize", async () => {
+    templateMocks.chunkTranscripts.mockResolvedValue({
+      status: "ok",
+      data: [[transcript("ship it friday")], [transcript("need more tests")]],
+    });
+    aiMocks.generateText.mockRejectedValue(new Error("context overflow"));
+
+    await expect(run()).rejects.toThrow("context overflow");
+    expect(progress).toEqual([{ type: "extracting", chunk: 1, total: 2 }]);
+    expect(aiMocks.streamText).not.toHaveBeenCalled();
+  });
+});
@@ -17,6 +17,7 @@ import { templateSectionSchema } from "@hypr/store";
 import type { TaskArgsMapTransformed, TaskConfig } from ".";
 import type { Store } from "../../../tinybase/store/main";
 import { getCustomPrompt } from "../../../tinybase/store/prompts";
+import { chunkBudget } from "../shared/context-window";
 import {
   normalizeBulletPoints,
   trimBeforeMarker,
@@ -47,9 +48,17 @@ async function* executeWorkflow(params: {
 }) {
   const { model, args, onProgress, signal, store } = params;
 
+  const chunkNotes = await extractChunkNotesIfNeeded({
+    model,
+    args,
+    onProgress,
+    signal,
+  });
+
   const sections = await generateTemplateIfNeeded({
     model,
     args,
+    chunkNotes,
     onProgress,
     signal,
     store,
@@ -60,7 +69,7 @@ async function* executeWorkflow(params: {
   };
 
   const system = await getSystemPrompt(argsWithTemplate);
-  const prompt = await getUserPrompt(argsWithTemplate, store);
+  const prompt = await getUserPrompt(argsWithTemplate, store, chunkNotes);
 
   yield* generateSummary({
     model,
@@ -89,9 +98,27 @@ async function getSystemPrompt(args: TaskArgsMapTransformed["enhance"]) {
 async function getUserPrompt(
   args: TaskArgsMapTransformed["enhance"],
   store: Store,
+  chunkNotes: string[] | null,
 ) {
   const { session, participants, template, transcripts } = args;
 
+  if (chunkNotes) {
+    const result = await templateCommands.render({
+      enhanceReduceUser: {
+        session,
+        participants,
+        template,
+        chunkNotes,
+      },
+    });
+
+    if (result.status === "error") {
+      throw new Error(result.error);
+    }
+
+    return result.data;
+  }
+
   const ctx = {
     content: transcripts,
     session,
@@ -124,20 +151,74 @@ async function getUserPrompt(
   return result.data;
 }
 
+async function extractChunkNotesIfNeeded(params: {
+  model: LanguageModel;
+  args: TaskArgsMapTransformed["enhance"];
+  onProgress: (step: any) => void;
+  signal: AbortSignal;
+}): Promise<string[] | null> {
+  const { model, args, onProgress, signal } = params;
+
+  const chunked = await templateCommands.chunkTranscripts(
+    args.transcripts,
+    chunkBudget(model),
+  );
+  if (chunked.status === "error") {
+    throw new Error(chunked.error);
+  }
+
+  const chunks = chunked.data;
+  if (chunks.length <= 1) {
+    return null;
+  }
+
+  const system = await getSystemPrompt(args);
+  const notes: string[] = [];
+
+  for (const [i, transcripts] of chunks.entries()) {
+    onProgress({ type: "extracting", chunk: i + 1, total: chunks.length });
+
+    const result = await templateCommands.render({
+      enhanceChunkUser: {
+        session: args.session,
+        participants: args.participants,
+        template: args.template,
+        transcripts,
+        chunk: i + 1,
+        totalChunks: chunks.length,
+      },
+    });
+    if (result.status === "error") {
+      throw new Error(result.error);
+    }
+
+    const { text } = await generateText({
+      model,
+      system,
+      prompt: result.data,
+      abortSignal: signal,
+    });
+    notes.push(text);
+  }
+
+  return notes;
+}
+
 async function generateTemplateIfNeeded(params: {
   model: LanguageModel;
   args: TaskArgsMapTransformed["enhance"];
+  chunkNotes: string[] | null;
   onProgress: (step: any) => void;
   signal: AbortSignal;
   store: Store;
 }): Promise<TemplateSection[] | null> {
-  const { model, args, onProgress, signal, store } = params;
+  const { model, args, chunkNotes, onProgress, signal, store } = params;
 
   if (!args.template) {
     onProgress({ type: "analyzing" });
 
     const schema = z.object({ sections: z.array(templateSectionSchema) });
-    const userPrompt = await getUserPrompt(args, store);
+    const userPrompt = await getUserPrompt(args, store, chunkNotes);
 
     const result = await generateStructuredOutput({
       model,
@@ -1,6 +1,8 @@
 import type { LanguageModel, TextStreamPart } from "ai";
 
 import type {
+  ActionItemsSystem,
+  ActionItemsUser,
   EnhanceSystem,
   EnhanceUser,
   TitleSystem,
@@ -11,21 +13,25 @@ import type { Store as MainStore } from "../../../tinybase/store/main";
 import type { Store as SettingsStore } from "../../../tinybase/store/settings";
 import { StreamTransform } from "../shared/transform_infra";
 import type { TaskStepInfo } from "../tasks";
+import { actionItemsTransform } from "./action-items-transform";
+import { actionItemsWorkflow } from "./action-items-workflow";
 import { enhanceTransform } from "./enhance-transform";
 import { enhanceWorkflow } from "./enhance-workflow";
 import { titleTransform } from "./title-transform";
 import { titleWorkflow } from "./title-workflow";
 
-export type TaskType = "enhance" | "title";
+export type TaskType = "enhance" | "title" | "actionItems";
 
 export interface TaskArgsMap {
   enhance: { sessionId: string; enhancedNoteId: string; templateId?: string };
   title: { sessionId: string };
+  actionItems: { sessionId: string; enhancedNoteId: string };
 }
 
 export interface TaskArgsMapTransformed {
   enhance: EnhanceSystem & EnhanceUser;
   title: TitleSystem & TitleUser;
+  actionItems: ActionItemsSystem & ActionItemsUser;
 }
 
 export type TaskId<T extends TaskType = TaskType> = `${string}-${T}`;
@@ -66,4 +72,8 @@ export const TASK_CONFIGS: TaskConfigMap = {
     ...titleWorkflow,
     ...titleTransform,
   },
+  actionItems: {
+    ...actionItemsWorkflow,
+    ...actionItemsTransform,
+  },
 };
@@ -34,6 +34,7 @@ export type TasksActions = {
 export type TaskStepInfo<T extends TaskType = TaskType> = T extends "enhance"
   ?
       | { type: "analyzing" }
+      | { type: "extracting"; chunk: number; total: number }
       | { type: "generating" }
       | { type: "retrying"; attempt: number; reason: string }
   : T extends "title"
@@ -7,6 +7,7 @@ import { commands as detectCommands } from "@hypr/plugin-detect";
 import { commands as hooksCommands } from "@hypr/plugin-hooks";
 import { commands as iconCommands } from "@hypr/plugin-icon";
 import {
+  type AudioQualityIssue,
   type DegradedError,
   commands as listenerCommands,
   events as listenerEvents,
@@ -53,6 +54,7 @@ export type GeneralState = {
     lastError: string | null;
     device: string | null;
     degraded: DegradedError | null;
+    qualityIssues: AudioQualityIssue[];
   };
 };
 
@@ -82,6 +84,7 @@ const initialState: GeneralState = {
     lastError: null,
     device: null,
     degraded: null,
+    qualityIssues: [],
   },
 };
 
@@ -232,6 +235,7 @@ export const createGeneralSlice = <
             draft.live.lastError = payload.error ?? null;
             draft.live.device = null;
             draft.live.degraded = null;
+            draft.live.qualityIssues = [];
             draft.live.muted = initialState.live.muted;
           }),
         );
@@ -320,6 +324,25 @@ export const createGeneralSlice = <
             draft.live.muted = payload.value;
           }),
         );
+      } else if (payload.type === "audio_quality_warning") {
+        set((state) =>
+          mutate(state, (draft) => {
+            draft.live.qualityIssues = [
+              ...draft.live.qualityIssues.filter(
+                (issue) => !isSameQualityIssue(issue, payload.issue),
+              ),
+              payload.issue,
+            ];
+          }),
+        );
+      } else if (payload.type === "audio_quality_recovered") {
+        set((state) =>
+          mutate(state, (draft) => {
+            draft.live.qualityIssues = draft.live.qualityIssues.filter(
+              (issue) => !isSameQualityIssue(issue, payload.issue),
+            );
+          }),
+        );
       }
     };
 
@@ -409,6 +432,7 @@ export const createGeneralSlice = <
               draft.live.lastError = null;
               draft.live.device = null;
               draft.live.degraded = null;
+              draft.live.qualityIssues = [];
             }),
           );
         },
@@ -615,3 +639,7 @@ export const createGeneralSlice = <
     return "inactive";
   },
 });
+
+const isSameQualityIssue = (a: AudioQualityIssue, b: AudioQualityIssue) =>
+  a.kind === b.kind &&
+  ("channel" in a ? a.channel : null) === ("channel" in b ? b.channel : null);
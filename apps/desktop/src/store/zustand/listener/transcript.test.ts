@@ -274,4 +274,32 @@ describe("transcript slice", () => {
     expect(hintedWord).toBeDefined();
     expect(hintedWord?.text).toBe(" test");
   });
+
+  test("separated finals clear the mic partials they replace", () => {
+    const persist = vi.fn();
+    store.getState().setTranscriptPersist(persist);
+
+    store.getState().handleTranscriptResponse(
+      createResponse({
+        words: defaultWords,
+        transcript: "Another problem",
+        isFinal: false,
+      }),
+    );
+    expect(store.getState().partialWordsByChannel[0]).toHaveLength(2);
+
+    store.getState().handleTranscriptResponse(
+      createResponse({
+        words: defaultWords,
+        transcript: "Another problem",
+        isFinal: true,
+        channelIndex: 3,
+      }),
+    );
+
+    expect(store.getState().partialWordsByChannel[0]).toHaveLength(0);
+    expect(store.getState().partialWordsByChannel[3]).toBeUndefined();
+    const [persistedWords] = persist.mock.calls[0] as [WordLike[]];
+    expect(persistedWords.map((word) => word.channel)).toEqual([3, 3]);
+  });
 });
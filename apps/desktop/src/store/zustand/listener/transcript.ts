@@ -3,7 +3,12 @@ import type { StoreApi } from "zustand";
 
 import type { StreamResponse } from "@hypr/plugin-listener";
 
-import type { RuntimeSpeakerHint, WordLike } from "../../../utils/segment";
+import {
+  ChannelProfile,
+  isSeparatedChannel,
+  type RuntimeSpeakerHint,
+  type WordLike,
+} from "../../../utils/segment";
 import { transformWordEntries } from "./utils";
 
 type WordsByChannel = Record<number, WordLike[]>;
@@ -69,7 +74,13 @@ export const createTranscriptSlice = <
         wordIndex: hint.wordIndex - firstNewWordIndex,
       }));
 
-    const existingPartialWords = partialWordsByChannel[channelIndex] ?? [];
+    // Speaker separation only relabels finals, so their partials were
+    // streamed on the mic channel.
+    const partialChannel = isSeparatedChannel(channelIndex)
+      ? ChannelProfile.DirectMic
+      : channelIndex;
+
+    const existingPartialWords = partialWordsByChannel[partialChannel] ?? [];
     const remainingPartialWords = existingPartialWords.filter(
       (word) => word.start_ms > lastEndMs,
     );
@@ -83,7 +94,7 @@ export const createTranscriptSlice = <
       }
     }
 
-    const existingPartialHints = partialHintsByChannel[channelIndex] ?? [];
+    const existingPartialHints = partialHintsByChannel[partialChannel] ?? [];
     const remainingPartialHints = existingPartialHints
       .filter((hint) => oldToNewIndex.has(hint.wordIndex))
       .map((hint) => ({
@@ -93,8 +104,8 @@ export const createTranscriptSlice = <
 
     set((state) =>
       mutate(state, (draft) => {
-        draft.partialWordsByChannel[channelIndex] = remainingPartialWords;
-        draft.partialHintsByChannel[channelIndex] = remainingPartialHints;
+        draft.partialWordsByChannel[partialChannel] = remainingPartialWords;
+        draft.partialHintsByChannel[partialChannel] = remainingPartialHints;
         draft.finalWordsMaxEndMsByChannel[channelIndex] = lastEndMs;
       }),
     );
@@ -0,0 +1,23 @@
+import { create } from "zustand";
+
+import type { MeetingMatch } from "@hypr/plugin-detect";
+
+interface MeetingMatchState {
+  // Events offered for a session whose match wasn't confident enough to link.
+  candidates: Record<string, MeetingMatch[]>;
+  setCandidates: (sessionId: string, candidates: MeetingMatch[]) => void;
+  clear: (sessionId: string) => void;
+}
+
+export const useMeetingMatchCandidates = create<MeetingMatchState>((set) => ({
+  candidates: {},
+  setCandidates: (sessionId, candidates) =>
+    set((state) => ({
+      candidates: { ...state.candidates, [sessionId]: candidates },
+    })),
+  clear: (sessionId) =>
+    set((state) => {
+      const { [sessionId]: _, ...rest } = state.candidates;
+      return { candidates: rest };
+    }),
+}));
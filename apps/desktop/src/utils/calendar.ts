@@ -1,5 +1,8 @@
 import type { Store } from "tinybase/with-schemas";
 
+import type { CalendarAccount as CalDavAccount } from "@hypr/plugin-caldav";
+import { type CalendarAccount, calendarAccountSchema } from "@hypr/store";
+
 import type { Schemas } from "../store/tinybase/store/main";
 
 export function findCalendarByTrackingId(
@@ -18,3 +21,22 @@ export function findCalendarByTrackingId(
 
   return foundRowId;
 }
+
+export function toCalDavAccount(
+  account: Partial<CalendarAccount> | undefined,
+): CalDavAccount | null {
+  const { data } = calendarAccountSchema.safeParse(account);
+  if (!data) {
+    return null;
+  }
+
+  if (data.kind === "ics") {
+    return { type: "ics", url: data.url };
+  }
+  return {
+    type: "caldav",
+    url: data.url,
+    username: data.username || null,
+    password: data.password || null,
+  };
+}
@@ -17,10 +17,12 @@ import type {
   StageId,
   WordLike,
 } from "./shared";
+import { isSeparatedChannel } from "./shared";
 
 export {
   ChannelProfile,
   ChannelProfileSchema,
+  isSeparatedChannel,
   SegmentKey,
   type PartialWord,
   type RuntimeSpeakerHint,
@@ -43,7 +45,10 @@ export function buildSegments<
     return [];
   }
 
-  const context = createSegmentPassContext(speakerHints, options);
+  const context = createSegmentPassContext(speakerHints, options, [
+    ...finalWords,
+    ...partialWords,
+  ]);
   const initialGraph: SegmentGraph = { finalWords, partialWords };
   const graph = runSegmentPipeline(initialGraph, context);
   const segmentsGraph = ensureGraphKey(
@@ -112,7 +117,8 @@ function runSegmentPipeline(
 
 function createSpeakerState(
   speakerHints: readonly RuntimeSpeakerHint[],
-  options?: SegmentBuilderOptions,
+  options: SegmentBuilderOptions | undefined,
+  words: readonly WordLike[],
 ): SpeakerState {
   const assignmentByWordIndex = new Map<number, SpeakerIdentity>();
   const humanIdBySpeakerIndex = new Map<number, string>();
@@ -125,6 +131,13 @@ function createSpeakerState(
     completeChannels.add(1);
   }
 
+  // Speaker separation gives each in-person speaker a channel of their own.
+  for (const word of words) {
+    if (isSeparatedChannel(word.channel)) {
+      completeChannels.add(word.channel);
+    }
+  }
+
   for (const hint of speakerHints) {
     const current = assignmentByWordIndex.get(hint.wordIndex) ?? {};
     if (hint.data.type === "provider_speaker_index") {
@@ -150,13 +163,14 @@ function createSpeakerState(
 
 function createSegmentPassContext(
   speakerHints: readonly RuntimeSpeakerHint[],
-  options?: SegmentBuilderOptions,
+  options: SegmentBuilderOptions | undefined,
+  words: readonly WordLike[],
 ): SegmentPassContext {
   const resolvedOptions: SegmentBuilderOptions = options ? { ...options } : {};
   return {
     speakerHints,
     options: resolvedOptions,
-    speakerState: createSpeakerState(speakerHints, resolvedOptions),
+    speakerState: createSpeakerState(speakerHints, resolvedOptions, words),
   };
 }
 
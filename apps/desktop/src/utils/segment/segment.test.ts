@@ -976,6 +976,35 @@ describe("buildSegments", () => {
       console.error(visualizeSegments(finalWords, partialWords));
     },
   );
+
+  test("treats each separated mic channel as a single speaker", () => {
+    const segments = buildSegments(
+      [
+        { text: "0", start_ms: 0, end_ms: 100, channel: 3 },
+        { text: "1", start_ms: 200, end_ms: 300, channel: 4 },
+        { text: "2", start_ms: 5000, end_ms: 5100, channel: 3 },
+      ],
+      [],
+      [
+        {
+          wordIndex: 0,
+          data: { type: "user_speaker_assignment", human_id: "alice" },
+        },
+      ],
+    );
+
+    expect(
+      segments
+        .filter((segment) => segment.key.channel === 3)
+        .map((segment) => segment.key.speaker_human_id),
+    ).toEqual(["alice", "alice"]);
+    expect(
+      segments.find((segment) => segment.key.channel === 4)?.key,
+    ).toEqual(SegmentKey.make({ channel: 4 }));
+    expect(SegmentKey.renderLabel(SegmentKey.make({ channel: 4 }))).toBe(
+      "Speaker E",
+    );
+  });
 });
 
 function visualizeSegments(
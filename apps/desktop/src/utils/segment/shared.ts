@@ -6,9 +6,18 @@ export enum ChannelProfile {
   DirectMic = 0,
   RemoteParty = 1,
   MixedCapture = 2,
+  // Speaker separation splits the mic into one channel per in-person
+  // speaker, numbered from here up.
+  SeparatedMic = 3,
 }
 
-export const ChannelProfileSchema = Schema.Enums(ChannelProfile);
+export const isSeparatedChannel = (channel: number): boolean =>
+  channel >= ChannelProfile.SeparatedMic;
+
+export const ChannelProfileSchema = Schema.Union(
+  Schema.Enums(ChannelProfile),
+  Schema.Int.pipe(Schema.greaterThan(ChannelProfile.SeparatedMic)),
+);
 
 export type WordLike = {
   text: string;
@@ -167,12 +176,11 @@ export const SegmentKey = {
       return `Speaker ${speakerNumber}`;
     }
 
-    const channelLabel =
-      key.channel === ChannelProfile.DirectMic
-        ? "A"
-        : key.channel === ChannelProfile.RemoteParty
-          ? "B"
-          : "C";
+    // A, B and C for the physical channels, then D onward for separated
+    // in-person speakers.
+    const channelLabel = String.fromCharCode(
+      "A".charCodeAt(0) + Math.max(0, key.channel),
+    );
 
     return key.speaker_index !== undefined
       ? `Speaker ${key.speaker_index + 1}`
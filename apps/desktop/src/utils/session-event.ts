@@ -1,4 +1,4 @@
-import type { SessionEvent } from "@hypr/store";
+import type { EventParticipant, SessionEvent } from "@hypr/store";
 import { format, safeParseDate, TZDate } from "@hypr/utils";
 
 import type * as main from "../store/tinybase/store/main";
@@ -26,6 +26,19 @@ export function getSessionEventById(
   return getSessionEvent(row);
 }
 
+export function getEventParticipants(event: {
+  participants_json?: string | null;
+}): EventParticipant[] {
+  const participantsJson = event.participants_json;
+  if (!participantsJson) return [];
+  try {
+    const participants = JSON.parse(participantsJson);
+    return Array.isArray(participants) ? participants : [];
+  } catch {
+    return [];
+  }
+}
+
 function dayFromDate(
   dateStr: string | null | undefined,
   timezone?: string,
@@ -8,7 +8,8 @@ mod report;
 mod submissions;
 
 use hypr_eval::{
-    DEFAULT_MODELS, EvalResult, Executor, ExecutorProgress, OpenRouterClient, parse_config,
+    ChatCompleter, DEFAULT_MODELS, EvalResult, Executor, ExecutorProgress, FixtureClient,
+    OpenAICompatibleClient, OpenRouterClient, parse_config,
 };
 use report::{render_json, render_results};
 use submissions::{all_cases, filter_cases};
@@ -38,6 +39,15 @@ enum Commands {
 
         #[arg(long)]
         cache_dir: Option<String>,
+
+        #[arg(long, env = "EVALS_BASE_URL")]
+        base_url: Option<String>,
+
+        #[arg(long)]
+        fixtures: Option<String>,
+
+        #[arg(long, requires = "fixtures")]
+        record: bool,
     },
     List,
     Completion {
@@ -65,8 +75,17 @@ fn main() -> ExitCode {
             models,
             no_cache,
             cache_dir,
+            base_url,
+            fixtures,
+            record,
         } => {
-            if let Err(e) = run_evals(tasks, output, models, no_cache, cache_dir) {
+            let client_options = ClientOptions {
+                cache_dir: if no_cache { None } else { cache_dir },
+                base_url,
+                fixtures,
+                record,
+            };
+            if let Err(e) = run_evals(tasks, output, models, client_options) {
                 eprintln!("Error: {}", e);
                 return ExitCode::FAILURE;
             }
@@ -82,19 +101,55 @@ fn main() -> ExitCode {
     ExitCode::SUCCESS
 }
 
+struct ClientOptions {
+    cache_dir: Option<String>,
+    base_url: Option<String>,
+    fixtures: Option<String>,
+    record: bool,
+}
+
+type Clients = (Arc<dyn ChatCompleter>, Option<Arc<OpenRouterClient>>);
+
+/// Picks the completer to run against. Usage is only resolvable through OpenRouter,
+/// so that client is returned separately when it is the one talking to the network.
+fn build_client(options: ClientOptions) -> Result<Clients, String> {
+    if let (Some(dir), false) = (&options.fixtures, options.record) {
+        return Ok((Arc::new(FixtureClient::replay(dir)), None));
+    }
+
+    let (client, openrouter): (Arc<dyn ChatCompleter>, _) = match options.base_url {
+        Some(base_url) => (
+            Arc::new(OpenAICompatibleClient::with_cache_dir(
+                base_url,
+                options.cache_dir,
+            )),
+            None,
+        ),
+        None => {
+            let cfg = parse_config();
+            if cfg.openrouter_api_key.is_empty() {
+                return Err("OPENROUTER_API_KEY environment variable is not set".to_string());
+            }
+            let client = Arc::new(OpenRouterClient::with_cache_dir(
+                cfg.openrouter_api_key,
+                options.cache_dir,
+            ));
+            (client.clone(), Some(client))
+        }
+    };
+
+    match options.fixtures {
+        Some(dir) => Ok((Arc::new(FixtureClient::record(dir, client)), None)),
+        None => Ok((client, openrouter)),
+    }
+}
+
 fn run_evals(
     task_filter: Option<Vec<String>>,
     output_format: String,
     model_override: Option<Vec<String>>,
-    no_cache: bool,
-    cache_dir: Option<String>,
+    client_options: ClientOptions,
 ) -> Result<(), String> {
-    let cfg = parse_config();
-
-    if cfg.openrouter_api_key.is_empty() {
-        return Err("OPENROUTER_API_KEY environment variable is not set".to_string());
-    }
-
     let all = all_cases();
     let selected_cases = filter_cases(&all, task_filter.as_deref());
 
@@ -102,20 +157,18 @@ fn run_evals(
         return Err("no cases matched the filter".to_string());
     }
 
-    let cache_dir_opt = if no_cache { None } else { cache_dir };
-    let client = Arc::new(OpenRouterClient::with_cache_dir(
-        cfg.openrouter_api_key.clone(),
-        cache_dir_opt,
-    ));
+    let (client, openrouter) = build_client(client_options)?;
 
     let models =
         model_override.unwrap_or_else(|| DEFAULT_MODELS.iter().map(|s| s.to_string()).collect());
 
-    let executor = Executor::new(client.clone());
+    let executor = Executor::new(client);
 
     if output_format == "json" {
         let mut results = executor.execute(&selected_cases, &models);
-        resolve_usage(&client, &mut results);
+        if let Some(client) = &openrouter {
+            resolve_usage(client, &mut results);
+        }
         return render_json(&results).map_err(|e| e.to_string());
     }
 
@@ -149,7 +202,9 @@ fn run_evals(
     gen_bar.finish();
     eval_bar.finish();
 
-    resolve_usage(&client, &mut results);
+    if let Some(client) = &openrouter {
+        resolve_usage(client, &mut results);
+    }
 
     render_results(&results).map_err(|e| e.to_string())
 }
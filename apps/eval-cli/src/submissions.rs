@@ -1,11 +1,17 @@
 use hypr_eval::{
     ChatMessage, CheckResult, EvalCase, GraderSpec, RubricSpec, find_headings, find_lists, grade,
-    is_non_empty,
+    is_non_empty, meeting_samples, reference_rubrics,
 };
+use hypr_template_app::{EnhanceSystem, EnhanceUser, Segment, Session, Transcript};
 use hypr_template_eval::{MdgenSystem, Template};
 
+/// Pins `current_date` in the enhance system prompt, so recorded fixtures keep matching.
+const ENHANCE_DATE: &str = "2025-01-15";
+
 pub fn all_cases() -> Vec<EvalCase> {
-    vec![mdbench_case()]
+    let mut cases = vec![mdbench_case()];
+    cases.extend(meeting_note_cases());
+    cases
 }
 
 fn mdbench_format_validator(output: &str) -> (bool, String) {
@@ -106,6 +112,71 @@ pub fn mdbench_case() -> EvalCase {
     }
 }
 
+pub fn meeting_note_cases() -> Vec<EvalCase> {
+    hypr_askama_utils::set_current_date_override(Some(ENHANCE_DATE.to_string()));
+
+    let system =
+        Template::render(&EnhanceSystem { language: None }).expect("Failed to render template");
+
+    let cases = meeting_samples()
+        .into_iter()
+        .map(|sample| {
+            let user = Template::render(&EnhanceUser {
+                session: Session {
+                    title: None,
+                    started_at: None,
+                    ended_at: None,
+                    event: None,
+                },
+                participants: vec![],
+                template: None,
+                transcripts: vec![Transcript {
+                    segments: sample
+                        .turns
+                        .iter()
+                        .map(|turn| Segment {
+                            text: turn.text.clone(),
+                            speaker: turn.speaker.clone(),
+                            start_ms: None,
+                            end_ms: None,
+                        })
+                        .collect(),
+                    started_at: None,
+                    ended_at: None,
+                }],
+            })
+            .expect("Failed to render template");
+
+            let mut rubrics = vec![RubricSpec {
+                name: "non_empty".to_string(),
+                description: "Output is non-empty".to_string(),
+                grader: GraderSpec::Func(is_non_empty),
+            }];
+            rubrics.extend(reference_rubrics());
+
+            EvalCase {
+                case_id: format!("meeting_note_{}", sample.id),
+                messages: vec![
+                    ChatMessage {
+                        role: "system".to_string(),
+                        content: system.clone(),
+                    },
+                    ChatMessage {
+                        role: "user".to_string(),
+                        content: user,
+                    },
+                ],
+                rubrics,
+                samples: 1,
+                meta: Some(sample.meta()),
+            }
+        })
+        .collect();
+
+    hypr_askama_utils::set_current_date_override(None);
+    cases
+}
+
 pub fn filter_cases(all_cases: &[EvalCase], filter: Option<&[String]>) -> Vec<EvalCase> {
     match filter {
         None => all_cases.to_vec(),
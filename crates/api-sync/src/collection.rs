@@ -0,0 +1,83 @@
+use hypr_importer_core::ir::Collection;
+use serde::Serialize;
+
+use crate::types::{EntityKind, Fields, PushChange};
+
+/// Turns the syncable parts of an imported [`Collection`] into a push batch,
+/// e.g. to seed the server from a local vault.
+pub fn changes_from_collection(collection: &Collection, updated_at: i64) -> Vec<PushChange> {
+    let sessions = collection
+        .sessions
+        .iter()
+        .map(|s| change(EntityKind::Session, &s.id, s, updated_at));
+    let notes = collection
+        .enhanced_notes
+        .iter()
+        .map(|n| change(EntityKind::Note, &n.id, n, updated_at));
+    let transcripts = collection
+        .transcripts
+        .iter()
+        .map(|t| change(EntityKind::Transcript, &t.id, t, updated_at));
+
+    sessions.chain(notes).chain(transcripts).collect()
+}
+
+fn change(kind: EntityKind, id: &str, entity: &impl Serialize, updated_at: i64) -> PushChange {
+    let mut data = match serde_json::to_value(entity) {
+        Ok(serde_json::Value::Object(map)) => map,
+        _ => Fields::new(),
+    };
+    // Both are implied by the request: the id by the change, the user by auth.
+    data.remove("id");
+    data.remove("user_id");
+
+    PushChange {
+        kind,
+        id: id.to_string(),
+        base_version: None,
+        updated_at,
+        deleted: false,
+        data,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hypr_importer_core::ir::{EnhancedNote, Session};
+
+    #[test]
+    fn test_changes_from_collection() {
+        let collection = Collection {
+            sessions: vec![Session {
+                id: "s1".to_string(),
+                user_id: "u1".to_string(),
+                created_at: "2025-01-01T00:00:00Z".to_string(),
+                title: "Standup".to_string(),
+                raw_md: None,
+                enhanced_content: None,
+                folder_id: None,
+                event_id: None,
+            }],
+            enhanced_notes: vec![EnhancedNote {
+                id: "n1".to_string(),
+                user_id: "u1".to_string(),
+                session_id: "s1".to_string(),
+                content: "# Summary".to_string(),
+                template_id: None,
+                position: 0,
+                title: "Summary".to_string(),
+            }],
+            ..Default::default()
+        };
+
+        let changes = changes_from_collection(&collection, 42);
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].kind, EntityKind::Session);
+        assert_eq!(changes[0].data["title"], "Standup");
+        assert!(!changes[0].data.contains_key("id"));
+        assert!(!changes[0].data.contains_key("user_id"));
+        assert_eq!(changes[1].kind, EntityKind::Note);
+        assert_eq!(changes[1].data["session_id"], "s1");
+    }
+}
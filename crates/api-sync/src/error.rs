@@ -31,6 +31,24 @@ impl From<hypr_supabase_auth::Error> for SyncError {
     }
 }
 
+impl From<hypr_db_core::Error> for SyncError {
+    fn from(err: hypr_db_core::Error) -> Self {
+        Self::Internal(err.to_string())
+    }
+}
+
+impl From<hypr_db_core::libsql::Error> for SyncError {
+    fn from(err: hypr_db_core::libsql::Error) -> Self {
+        Self::Internal(err.to_string())
+    }
+}
+
+impl From<serde_json::Error> for SyncError {
+    fn from(err: serde_json::Error) -> Self {
+        Self::Internal(err.to_string())
+    }
+}
+
 impl IntoResponse for SyncError {
     fn into_response(self) -> Response {
         let (status, error_code) = match &self {
@@ -1,9 +1,16 @@
+mod collection;
 mod config;
 mod error;
+pub mod merge;
 mod routes;
 mod state;
+mod store;
+mod types;
 
+pub use collection::changes_from_collection;
 pub use config::SyncConfig;
 pub use error::{Result, SyncError};
 pub use routes::{openapi, router};
 pub use state::AppState;
+pub use store::{LibsqlSyncStore, StoreFuture, SyncStore};
+pub use types::*;
@@ -0,0 +1,420 @@
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::{
+    ConflictResolution, EntityRecord, FieldConflict, Fields, PushChange, PushResult, PushStatus,
+};
+
+/// When and by whom a field was last written.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldClock {
+    pub version: i64,
+    pub updated_at: i64,
+    pub device_id: String,
+}
+
+impl FieldClock {
+    // Ties on the client timestamp are broken by device id, so every replica
+    // resolves the same pair of writes the same way.
+    fn cmp_write(&self, updated_at: i64, device_id: &str) -> Ordering {
+        (self.updated_at, self.device_id.as_str()).cmp(&(updated_at, device_id))
+    }
+}
+
+/// An entity as persisted by a [`crate::SyncStore`]: the public record plus
+/// per-field clocks used for last-writer-wins.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StoredEntity {
+    pub record: EntityRecord,
+    pub fields: BTreeMap<String, FieldClock>,
+}
+
+impl StoredEntity {
+    fn clock(&self) -> FieldClock {
+        FieldClock {
+            version: self.record.version,
+            updated_at: self.record.updated_at,
+            device_id: self.record.updated_by.clone(),
+        }
+    }
+
+    fn from_change(change: PushChange, device_id: &str, version: i64) -> Self {
+        let clock = FieldClock {
+            version,
+            updated_at: change.updated_at,
+            device_id: device_id.to_string(),
+        };
+
+        let (data, fields) = if change.deleted {
+            (Fields::new(), BTreeMap::new())
+        } else {
+            let fields = change
+                .data
+                .keys()
+                .map(|field| (field.clone(), clock.clone()))
+                .collect();
+            (change.data, fields)
+        };
+
+        Self {
+            record: EntityRecord {
+                kind: change.kind,
+                id: change.id,
+                version,
+                updated_at: change.updated_at,
+                updated_by: device_id.to_string(),
+                deleted: change.deleted,
+                data,
+            },
+            fields,
+        }
+    }
+}
+
+pub struct Merge {
+    /// The new state to persist, if anything was written.
+    pub write: Option<StoredEntity>,
+    pub result: PushResult,
+}
+
+/// Merges one pushed change into the current server state. `version` is the
+/// sequence number to stamp on the entity if the change writes anything.
+pub fn apply(
+    current: Option<StoredEntity>,
+    change: PushChange,
+    device_id: &str,
+    version: i64,
+) -> Merge {
+    match current {
+        None => {
+            let stored = StoredEntity::from_change(change, device_id, version);
+            Merge {
+                result: result(&stored, PushStatus::Applied, Vec::new()),
+                write: Some(stored),
+            }
+        }
+        Some(current) if current.record.deleted => revive(current, change, device_id, version),
+        Some(current) if change.deleted => delete(current, change, device_id, version),
+        Some(current) => update(current, change, device_id, version),
+    }
+}
+
+fn revive(current: StoredEntity, change: PushChange, device_id: &str, version: i64) -> Merge {
+    if change.deleted {
+        return Merge {
+            result: result(&current, PushStatus::Applied, Vec::new()),
+            write: None,
+        };
+    }
+
+    if current.clock().cmp_write(change.updated_at, device_id) != Ordering::Less {
+        return Merge {
+            result: result(&current, PushStatus::Rejected, Vec::new()),
+            write: None,
+        };
+    }
+
+    let stored = StoredEntity::from_change(change, device_id, version);
+    Merge {
+        result: result(&stored, PushStatus::Applied, Vec::new()),
+        write: Some(stored),
+    }
+}
+
+fn delete(current: StoredEntity, change: PushChange, device_id: &str, version: i64) -> Merge {
+    if current.clock().cmp_write(change.updated_at, device_id) == Ordering::Greater {
+        return Merge {
+            result: result(&current, PushStatus::Rejected, Vec::new()),
+            write: None,
+        };
+    }
+
+    let conflicts = current
+        .fields
+        .iter()
+        .filter(|(_, clock)| is_concurrent(clock, change.base_version, device_id))
+        .filter_map(|(field, _)| {
+            let server_value = current.record.data.get(field).cloned().unwrap_or_default();
+            (!server_value.is_null()).then(|| FieldConflict {
+                field: field.clone(),
+                resolution: ConflictResolution::ClientWins,
+                server_value,
+                client_value: serde_json::Value::Null,
+            })
+        })
+        .collect();
+
+    let stored = StoredEntity::from_change(change, device_id, version);
+    Merge {
+        result: result(&stored, PushStatus::Applied, conflicts),
+        write: Some(stored),
+    }
+}
+
+fn update(current: StoredEntity, change: PushChange, device_id: &str, version: i64) -> Merge {
+    let mut next = current.clone();
+    let mut conflicts = Vec::new();
+    let (mut accepted, mut rejected) = (0, 0);
+
+    for (field, client_value) in change.data {
+        let server_value = current.record.data.get(&field).cloned().unwrap_or_default();
+        let clock = current.fields.get(&field);
+
+        if clock.is_some_and(|c| c.cmp_write(change.updated_at, device_id) == Ordering::Greater) {
+            rejected += 1;
+            if server_value != client_value {
+                conflicts.push(FieldConflict {
+                    field,
+                    resolution: ConflictResolution::ServerWins,
+                    server_value,
+                    client_value,
+                });
+            }
+            continue;
+        }
+
+        let concurrent = clock.is_some_and(|c| is_concurrent(c, change.base_version, device_id));
+        if concurrent && server_value != client_value {
+            conflicts.push(FieldConflict {
+                field: field.clone(),
+                resolution: ConflictResolution::ClientWins,
+                server_value,
+                client_value: client_value.clone(),
+            });
+        }
+
+        next.fields.insert(
+            field.clone(),
+            FieldClock {
+                version,
+                updated_at: change.updated_at,
+                device_id: device_id.to_string(),
+            },
+        );
+        next.record.data.insert(field, client_value);
+        accepted += 1;
+    }
+
+    if accepted == 0 {
+        let status = if rejected == 0 {
+            PushStatus::Applied
+        } else {
+            PushStatus::Rejected
+        };
+        return Merge {
+            result: result(&current, status, conflicts),
+            write: None,
+        };
+    }
+
+    next.record.version = version;
+    if current.clock().cmp_write(change.updated_at, device_id) == Ordering::Less {
+        next.record.updated_at = change.updated_at;
+        next.record.updated_by = device_id.to_string();
+    }
+
+    let status = if rejected == 0 {
+        PushStatus::Applied
+    } else {
+        PushStatus::Partial
+    };
+    Merge {
+        result: result(&next, status, conflicts),
+        write: Some(next),
+    }
+}
+
+// Whether a field was written without the pushing device having seen it.
+// Without a base version the device has never seen the entity, so every
+// existing field counts and callers only report the ones whose value differs.
+fn is_concurrent(clock: &FieldClock, base_version: Option<i64>, device_id: &str) -> bool {
+    match base_version {
+        Some(base) => clock.version > base && clock.device_id != device_id,
+        None => true,
+    }
+}
+
+fn result(stored: &StoredEntity, status: PushStatus, conflicts: Vec<FieldConflict>) -> PushResult {
+    PushResult {
+        kind: stored.record.kind,
+        id: stored.record.id.clone(),
+        status,
+        version: stored.record.version,
+        record: (status != PushStatus::Applied || !conflicts.is_empty())
+            .then(|| stored.record.clone()),
+        conflicts,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::EntityKind;
+    use serde_json::json;
+
+    fn change(updated_at: i64, base_version: Option<i64>, data: serde_json::Value) -> PushChange {
+        PushChange {
+            kind: EntityKind::Session,
+            id: "s1".to_string(),
+            base_version,
+            updated_at,
+            deleted: false,
+            data: data.as_object().unwrap().clone(),
+        }
+    }
+
+    fn tombstone(updated_at: i64, base_version: Option<i64>) -> PushChange {
+        PushChange {
+            deleted: true,
+            ..change(updated_at, base_version, json!({}))
+        }
+    }
+
+    fn seed() -> StoredEntity {
+        apply(
+            None,
+            change(100, None, json!({ "title": "Standup", "raw_md": "notes" })),
+            "a",
+            1,
+        )
+        .write
+        .unwrap()
+    }
+
+    #[test]
+    fn concurrent_edits_merge_per_field() {
+        let merged = apply(
+            Some(seed()),
+            change(200, Some(1), json!({ "title": "Daily standup" })),
+            "b",
+            2,
+        );
+        assert_eq!(merged.result.status, PushStatus::Applied);
+        let stored = merged.write.unwrap();
+
+        // Device "a" edits the body without having seen "b"'s title change.
+        let merged = apply(
+            Some(stored),
+            change(
+                150,
+                Some(1),
+                json!({ "title": "Sync", "raw_md": "more notes" }),
+            ),
+            "a",
+            3,
+        );
+
+        assert_eq!(merged.result.status, PushStatus::Partial);
+        assert_eq!(
+            merged.result.conflicts,
+            vec![FieldConflict {
+                field: "title".to_string(),
+                resolution: ConflictResolution::ServerWins,
+                server_value: json!("Daily standup"),
+                client_value: json!("Sync"),
+            }]
+        );
+
+        let stored = merged.write.unwrap();
+        assert_eq!(stored.record.version, 3);
+        assert_eq!(stored.record.updated_at, 200);
+        assert_eq!(stored.record.data["title"], json!("Daily standup"));
+        assert_eq!(stored.record.data["raw_md"], json!("more notes"));
+    }
+
+    #[test]
+    fn newer_write_reports_overwritten_concurrent_field() {
+        let stored = apply(
+            Some(seed()),
+            change(200, Some(1), json!({ "title": "B" })),
+            "b",
+            2,
+        )
+        .write
+        .unwrap();
+
+        let merged = apply(
+            Some(stored),
+            change(300, Some(1), json!({ "title": "A" })),
+            "a",
+            3,
+        );
+        assert_eq!(merged.result.status, PushStatus::Applied);
+        assert_eq!(
+            merged.result.conflicts[0].resolution,
+            ConflictResolution::ClientWins
+        );
+        assert_eq!(merged.write.unwrap().record.data["title"], json!("A"));
+    }
+
+    #[test]
+    fn unseen_entity_only_conflicts_on_differing_values() {
+        let merged = apply(
+            Some(seed()),
+            change(200, None, json!({ "title": "Standup" })),
+            "b",
+            2,
+        );
+        assert_eq!(merged.result.status, PushStatus::Applied);
+        assert!(merged.result.conflicts.is_empty());
+        assert!(merged.result.record.is_none());
+
+        let merged = apply(
+            Some(seed()),
+            change(200, None, json!({ "title": "Standup", "raw_md": "mine" })),
+            "a",
+            2,
+        );
+        assert_eq!(merged.result.status, PushStatus::Applied);
+        assert_eq!(merged.result.conflicts.len(), 1);
+        assert_eq!(merged.result.conflicts[0].field, "raw_md");
+    }
+
+    #[test]
+    fn stale_update_is_rejected_without_write() {
+        let merged = apply(
+            Some(seed()),
+            change(50, Some(1), json!({ "title": "Old" })),
+            "b",
+            2,
+        );
+        assert_eq!(merged.result.status, PushStatus::Rejected);
+        assert!(merged.write.is_none());
+        assert_eq!(merged.result.version, 1);
+        assert!(merged.result.record.is_some());
+    }
+
+    #[test]
+    fn tombstones_win_by_timestamp() {
+        let stale = apply(Some(seed()), tombstone(50, Some(1)), "b", 2);
+        assert_eq!(stale.result.status, PushStatus::Rejected);
+
+        let deleted = apply(Some(seed()), tombstone(200, None), "b", 2);
+        assert_eq!(deleted.result.status, PushStatus::Applied);
+        assert_eq!(deleted.result.conflicts.len(), 2);
+        let stored = deleted.write.unwrap();
+        assert!(stored.record.deleted);
+        assert!(stored.record.data.is_empty());
+
+        let late_edit = apply(
+            Some(stored.clone()),
+            change(150, Some(1), json!({ "title": "Edited offline" })),
+            "a",
+            3,
+        );
+        assert_eq!(late_edit.result.status, PushStatus::Rejected);
+
+        let recreated = apply(
+            Some(stored),
+            change(250, Some(2), json!({ "title": "Back" })),
+            "a",
+            3,
+        );
+        assert_eq!(recreated.result.status, PushStatus::Applied);
+        let stored = recreated.write.unwrap();
+        assert!(!stored.record.deleted);
+        assert_eq!(stored.record.data.len(), 1);
+    }
+}
@@ -1,10 +1,37 @@
-use axum::Router;
+pub(crate) mod sync;
+
+use axum::{
+    Router,
+    routing::{get, post},
+};
 use utoipa::OpenApi;
 
 use crate::state::AppState;
 
 #[derive(OpenApi)]
 #[openapi(
+    paths(
+        sync::push,
+        sync::pull,
+        sync::list_devices,
+    ),
+    components(
+        schemas(
+            crate::types::EntityKind,
+            crate::types::EntityRecord,
+            crate::types::PushChange,
+            crate::types::PushStatus,
+            crate::types::ConflictResolution,
+            crate::types::FieldConflict,
+            crate::types::PushResult,
+            crate::types::PushRequest,
+            crate::types::PushResponse,
+            crate::types::PullRequest,
+            crate::types::PullResponse,
+            crate::types::DeviceCursor,
+            crate::types::ListDevicesResponse,
+        )
+    ),
     tags(
         (name = "sync", description = "Sync management")
     )
@@ -16,5 +43,9 @@ pub fn openapi() -> utoipa::openapi::OpenApi {
 }
 
 pub fn router(state: AppState) -> Router {
-    Router::new().with_state(state)
+    Router::new()
+        .route("/push", post(sync::push))
+        .route("/pull", post(sync::pull))
+        .route("/devices", get(sync::list_devices))
+        .with_state(state)
 }
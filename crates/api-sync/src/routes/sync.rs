@@ -0,0 +1,133 @@
+use axum::{Extension, Json, extract::State};
+
+use hypr_api_auth::AuthContext;
+
+use crate::error::{Result, SyncError};
+use crate::state::AppState;
+use crate::types::{ListDevicesResponse, PullRequest, PullResponse, PushRequest, PushResponse};
+
+const MAX_PUSH_BATCH: usize = 500;
+const DEFAULT_PULL_LIMIT: u32 = 500;
+const MAX_PULL_LIMIT: u32 = 1000;
+
+#[utoipa::path(
+    post,
+    path = "/push",
+    request_body = PushRequest,
+    responses(
+        (status = 200, description = "Changes merged", body = PushResponse),
+        (status = 400, description = "Invalid batch"),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Internal server error"),
+    ),
+    tag = "sync",
+)]
+pub async fn push(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
+    Json(payload): Json<PushRequest>,
+) -> Result<Json<PushResponse>> {
+    validate_device_id(&payload.device_id)?;
+
+    if payload.changes.len() > MAX_PUSH_BATCH {
+        return Err(SyncError::BadRequest(format!(
+            "at most {MAX_PUSH_BATCH} changes per push"
+        )));
+    }
+    if payload.changes.iter().any(|c| c.id.is_empty()) {
+        return Err(SyncError::BadRequest("entity id is required".to_string()));
+    }
+
+    let response = state
+        .store
+        .push(&auth.claims.sub, &payload.device_id, payload.changes)
+        .await?;
+
+    Ok(Json(response))
+}
+
+#[utoipa::path(
+    post,
+    path = "/pull",
+    request_body = PullRequest,
+    responses(
+        (status = 200, description = "Changes after the cursor", body = PullResponse),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Internal server error"),
+    ),
+    tag = "sync",
+)]
+pub async fn pull(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
+    Json(payload): Json<PullRequest>,
+) -> Result<Json<PullResponse>> {
+    validate_device_id(&payload.device_id)?;
+    let user_id = auth.claims.sub.as_str();
+
+    let after = match payload.cursor {
+        Some(cursor) => cursor,
+        None => state
+            .store
+            .device_cursor(user_id, &payload.device_id)
+            .await?
+            .unwrap_or(0),
+    };
+    let limit = payload
+        .limit
+        .unwrap_or(DEFAULT_PULL_LIMIT)
+        .clamp(1, MAX_PULL_LIMIT);
+
+    let mut changes = state.store.pull(user_id, after, limit + 1).await?;
+    let has_more = changes.len() > limit as usize;
+    changes.truncate(limit as usize);
+
+    // The cursor the device sent back is its acknowledgement of everything up
+    // to it. The one returned here is only recorded once the device sends it
+    // back, so a pull whose response is lost is served again.
+    state
+        .store
+        .save_device_cursor(user_id, &payload.device_id, after, now_ms())
+        .await?;
+
+    let cursor = changes.last().map(|r| r.version).unwrap_or(after);
+
+    Ok(Json(PullResponse {
+        changes,
+        cursor,
+        has_more,
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/devices",
+    responses(
+        (status = 200, description = "Devices and their feed cursors", body = ListDevicesResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Internal server error"),
+    ),
+    tag = "sync",
+)]
+pub async fn list_devices(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
+) -> Result<Json<ListDevicesResponse>> {
+    let devices = state.store.list_devices(&auth.claims.sub).await?;
+    Ok(Json(ListDevicesResponse { devices }))
+}
+
+fn validate_device_id(device_id: &str) -> Result<()> {
+    if device_id.is_empty() {
+        return Err(SyncError::BadRequest("device_id is required".to_string()));
+    }
+    Ok(())
+}
+
+fn now_ms() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis() as i64)
+        .unwrap_or_default()
+}
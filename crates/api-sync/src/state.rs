@@ -1,12 +1,19 @@
+use std::sync::Arc;
+
 use crate::config::SyncConfig;
+use crate::store::SyncStore;
 
 #[derive(Clone)]
 pub struct AppState {
     pub config: SyncConfig,
+    pub store: Arc<dyn SyncStore>,
 }
 
 impl AppState {
-    pub fn new(config: SyncConfig) -> Self {
-        Self { config }
+    pub fn new(config: SyncConfig, store: impl SyncStore) -> Self {
+        Self {
+            config,
+            store: Arc::new(store),
+        }
     }
 }
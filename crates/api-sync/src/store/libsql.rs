@@ -0,0 +1,384 @@
+use std::collections::BTreeMap;
+
+use hypr_db_core::Database;
+
+use super::{StoreFuture, SyncStore};
+use crate::error::Result;
+use crate::merge::{self, FieldClock, StoredEntity};
+use crate::types::{DeviceCursor, EntityKind, EntityRecord, PushChange, PushResponse};
+
+const MIGRATIONS: [&str; 3] = [
+    "CREATE TABLE IF NOT EXISTS sync_entities (
+        user_id TEXT NOT NULL,
+        kind TEXT NOT NULL,
+        id TEXT NOT NULL,
+        version INTEGER NOT NULL,
+        updated_at INTEGER NOT NULL,
+        updated_by TEXT NOT NULL,
+        deleted INTEGER NOT NULL DEFAULT 0,
+        data TEXT NOT NULL,
+        fields TEXT NOT NULL,
+        PRIMARY KEY (user_id, kind, id)
+    )",
+    "CREATE INDEX IF NOT EXISTS sync_entities_feed ON sync_entities (user_id, version)",
+    "CREATE TABLE IF NOT EXISTS sync_devices (
+        user_id TEXT NOT NULL,
+        device_id TEXT NOT NULL,
+        cursor INTEGER NOT NULL DEFAULT 0,
+        last_seen_at INTEGER NOT NULL,
+        PRIMARY KEY (user_id, device_id)
+    )",
+];
+
+const ENTITY_COLUMNS: &str = "kind, id, version, updated_at, updated_by, deleted, data, fields";
+
+/// [`SyncStore`] on SQLite via libsql. Works against a local file or
+/// in-memory database as well as a remote Turso database.
+pub struct LibsqlSyncStore {
+    db: Database,
+    // Versions are allocated per push, so pushes are serialized.
+    write_lock: tokio::sync::Mutex<()>,
+}
+
+impl LibsqlSyncStore {
+    pub async fn new(db: Database) -> Result<Self> {
+        let conn = db.conn()?;
+        hypr_db_core::migrate(&conn, MIGRATIONS.to_vec()).await?;
+
+        Ok(Self {
+            db,
+            write_lock: tokio::sync::Mutex::new(()),
+        })
+    }
+}
+
+impl SyncStore for LibsqlSyncStore {
+    fn push<'a>(
+        &'a self,
+        user_id: &'a str,
+        device_id: &'a str,
+        changes: Vec<PushChange>,
+    ) -> StoreFuture<'a, PushResponse> {
+        Box::pin(async move {
+            let _guard = self.write_lock.lock().await;
+
+            let conn = self.db.conn()?;
+            let tx = conn.transaction().await?;
+
+            let mut version = latest_version(&tx, user_id).await?;
+            let mut results = Vec::with_capacity(changes.len());
+
+            for change in changes {
+                let current = get_entity(&tx, user_id, change.kind, &change.id).await?;
+                let merged = merge::apply(current, change, device_id, version + 1);
+
+                if let Some(stored) = &merged.write {
+                    put_entity(&tx, user_id, stored).await?;
+                    version += 1;
+                }
+                results.push(merged.result);
+            }
+
+            tx.commit().await?;
+
+            Ok(PushResponse {
+                results,
+                cursor: version,
+            })
+        })
+    }
+
+    fn pull<'a>(
+        &'a self,
+        user_id: &'a str,
+        after: i64,
+        limit: u32,
+    ) -> StoreFuture<'a, Vec<EntityRecord>> {
+        Box::pin(async move {
+            let conn = self.db.conn()?;
+            let mut rows = conn
+                .query(
+                    &format!(
+                        "SELECT {ENTITY_COLUMNS} FROM sync_entities
+                        WHERE user_id = ? AND version > ?
+                        ORDER BY version ASC LIMIT ?"
+                    ),
+                    libsql::params![user_id, after, limit as i64],
+                )
+                .await?;
+
+            let mut records = Vec::new();
+            while let Some(row) = rows.next().await? {
+                records.push(entity_from_row(&row)?.record);
+            }
+            Ok(records)
+        })
+    }
+
+    fn device_cursor<'a>(
+        &'a self,
+        user_id: &'a str,
+        device_id: &'a str,
+    ) -> StoreFuture<'a, Option<i64>> {
+        Box::pin(async move {
+            let conn = self.db.conn()?;
+            let mut rows = conn
+                .query(
+                    "SELECT cursor FROM sync_devices WHERE user_id = ? AND device_id = ?",
+                    libsql::params![user_id, device_id],
+                )
+                .await?;
+
+            match rows.next().await? {
+                Some(row) => Ok(Some(row.get::<i64>(0)?)),
+                None => Ok(None),
+            }
+        })
+    }
+
+    fn save_device_cursor<'a>(
+        &'a self,
+        user_id: &'a str,
+        device_id: &'a str,
+        cursor: i64,
+        seen_at: i64,
+    ) -> StoreFuture<'a, ()> {
+        Box::pin(async move {
+            let conn = self.db.conn()?;
+            // A retried or reordered pull can carry an older cursor; never move back.
+            conn.execute(
+                "INSERT INTO sync_devices (user_id, device_id, cursor, last_seen_at)
+                VALUES (?, ?, ?, ?)
+                ON CONFLICT (user_id, device_id)
+                DO UPDATE SET cursor = MAX(sync_devices.cursor, excluded.cursor),
+                    last_seen_at = excluded.last_seen_at",
+                libsql::params![user_id, device_id, cursor, seen_at],
+            )
+            .await?;
+            Ok(())
+        })
+    }
+
+    fn list_devices<'a>(&'a self, user_id: &'a str) -> StoreFuture<'a, Vec<DeviceCursor>> {
+        Box::pin(async move {
+            let conn = self.db.conn()?;
+            let mut rows = conn
+                .query(
+                    "SELECT device_id, cursor, last_seen_at FROM sync_devices
+                    WHERE user_id = ? ORDER BY last_seen_at DESC",
+                    libsql::params![user_id],
+                )
+                .await?;
+
+            let mut devices = Vec::new();
+            while let Some(row) = rows.next().await? {
+                devices.push(DeviceCursor {
+                    device_id: row.get(0)?,
+                    cursor: row.get(1)?,
+                    last_seen_at: row.get(2)?,
+                });
+            }
+            Ok(devices)
+        })
+    }
+}
+
+async fn latest_version(conn: &libsql::Connection, user_id: &str) -> Result<i64> {
+    let mut rows = conn
+        .query(
+            "SELECT COALESCE(MAX(version), 0) FROM sync_entities WHERE user_id = ?",
+            libsql::params![user_id],
+        )
+        .await?;
+
+    match rows.next().await? {
+        Some(row) => Ok(row.get::<i64>(0)?),
+        None => Ok(0),
+    }
+}
+
+async fn get_entity(
+    conn: &libsql::Connection,
+    user_id: &str,
+    kind: EntityKind,
+    id: &str,
+) -> Result<Option<StoredEntity>> {
+    let mut rows = conn
+        .query(
+            &format!(
+                "SELECT {ENTITY_COLUMNS} FROM sync_entities
+                WHERE user_id = ? AND kind = ? AND id = ?"
+            ),
+            libsql::params![user_id, kind.as_str(), id],
+        )
+        .await?;
+
+    match rows.next().await? {
+        Some(row) => Ok(Some(entity_from_row(&row)?)),
+        None => Ok(None),
+    }
+}
+
+async fn put_entity(conn: &libsql::Connection, user_id: &str, stored: &StoredEntity) -> Result<()> {
+    let record = &stored.record;
+    conn.execute(
+        &format!(
+            "INSERT OR REPLACE INTO sync_entities (user_id, {ENTITY_COLUMNS})
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)"
+        ),
+        libsql::params![
+            user_id,
+            record.kind.as_str(),
+            record.id.as_str(),
+            record.version,
+            record.updated_at,
+            record.updated_by.as_str(),
+            record.deleted as i64,
+            serde_json::to_string(&record.data)?,
+            serde_json::to_string(&stored.fields)?
+        ],
+    )
+    .await?;
+    Ok(())
+}
+
+fn entity_from_row(row: &libsql::Row) -> Result<StoredEntity> {
+    let kind: String = row.get(0)?;
+    let data: String = row.get(6)?;
+    let fields: String = row.get(7)?;
+
+    Ok(StoredEntity {
+        record: EntityRecord {
+            kind: kind.parse()?,
+            id: row.get(1)?,
+            version: row.get(2)?,
+            updated_at: row.get(3)?,
+            updated_by: row.get(4)?,
+            deleted: row.get::<i64>(5)? != 0,
+            data: serde_json::from_str(&data)?,
+        },
+        fields: serde_json::from_str::<BTreeMap<String, FieldClock>>(&fields)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::PushStatus;
+    use hypr_db_core::DatabaseBuilder;
+    use serde_json::json;
+
+    async fn setup_store() -> LibsqlSyncStore {
+        let db = DatabaseBuilder::default().memory().build().await.unwrap();
+        LibsqlSyncStore::new(db).await.unwrap()
+    }
+
+    fn change(kind: EntityKind, id: &str, updated_at: i64, data: serde_json::Value) -> PushChange {
+        PushChange {
+            kind,
+            id: id.to_string(),
+            base_version: None,
+            updated_at,
+            deleted: false,
+            data: data.as_object().unwrap().clone(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_push_and_pull_feed() {
+        let store = setup_store().await;
+
+        let pushed = store
+            .push(
+                "user",
+                "laptop",
+                vec![
+                    change(EntityKind::Session, "s1", 1, json!({ "title": "Standup" })),
+                    change(EntityKind::Note, "n1", 1, json!({ "content": "# Summary" })),
+                    change(EntityKind::Transcript, "t1", 1, json!({ "words": [] })),
+                ],
+            )
+            .await
+            .unwrap();
+        assert_eq!(pushed.cursor, 3);
+        assert!(
+            pushed
+                .results
+                .iter()
+                .all(|r| r.status == PushStatus::Applied)
+        );
+
+        let deleted = store
+            .push(
+                "user",
+                "phone",
+                vec![PushChange {
+                    deleted: true,
+                    ..change(EntityKind::Session, "s1", 2, json!({}))
+                }],
+            )
+            .await
+            .unwrap();
+        assert_eq!(deleted.cursor, 4);
+
+        let feed = store.pull("user", 0, 100).await.unwrap();
+        let ids: Vec<_> = feed.iter().map(|r| (r.id.as_str(), r.version)).collect();
+        assert_eq!(ids, vec![("n1", 2), ("t1", 3), ("s1", 4)]);
+        assert!(feed[2].deleted);
+        assert!(feed[2].data.is_empty());
+
+        assert_eq!(store.pull("user", 3, 100).await.unwrap().len(), 1);
+        assert!(store.pull("someone-else", 0, 100).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_device_cursors() {
+        let store = setup_store().await;
+
+        assert_eq!(store.device_cursor("user", "laptop").await.unwrap(), None);
+
+        store
+            .save_device_cursor("user", "laptop", 5, 1000)
+            .await
+            .unwrap();
+        store
+            .save_device_cursor("user", "laptop", 8, 2000)
+            .await
+            .unwrap();
+        store
+            .save_device_cursor("user", "phone", 2, 1500)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            store.device_cursor("user", "laptop").await.unwrap(),
+            Some(8)
+        );
+
+        let devices = store.list_devices("user").await.unwrap();
+        assert_eq!(devices.len(), 2);
+        assert_eq!(devices[0].device_id, "laptop");
+        assert_eq!(devices[0].cursor, 8);
+    }
+
+    #[tokio::test]
+    async fn test_device_cursor_never_moves_back() {
+        let store = setup_store().await;
+
+        store
+            .save_device_cursor("user", "laptop", 8, 1000)
+            .await
+            .unwrap();
+        store
+            .save_device_cursor("user", "laptop", 5, 2000)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            store.device_cursor("user", "laptop").await.unwrap(),
+            Some(8)
+        );
+        let devices = store.list_devices("user").await.unwrap();
+        assert_eq!(devices[0].last_seen_at, 2000);
+    }
+}
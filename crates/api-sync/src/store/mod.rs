@@ -0,0 +1,47 @@
+mod libsql;
+
+use std::future::Future;
+use std::pin::Pin;
+
+pub use self::libsql::LibsqlSyncStore;
+
+use crate::error::Result;
+use crate::types::{DeviceCursor, EntityRecord, PushChange, PushResponse};
+
+pub type StoreFuture<'a, T> = Pin<Box<dyn Future<Output = Result<T>> + Send + 'a>>;
+
+/// Persistence for the sync protocol. Every method is scoped to one user.
+pub trait SyncStore: Send + Sync + 'static {
+    /// Merges a batch with [`crate::merge::apply`] and persists it atomically,
+    /// stamping each written entity with the next version for the user.
+    fn push<'a>(
+        &'a self,
+        user_id: &'a str,
+        device_id: &'a str,
+        changes: Vec<PushChange>,
+    ) -> StoreFuture<'a, PushResponse>;
+
+    /// Entities written after version `after`, oldest first.
+    fn pull<'a>(
+        &'a self,
+        user_id: &'a str,
+        after: i64,
+        limit: u32,
+    ) -> StoreFuture<'a, Vec<EntityRecord>>;
+
+    fn device_cursor<'a>(
+        &'a self,
+        user_id: &'a str,
+        device_id: &'a str,
+    ) -> StoreFuture<'a, Option<i64>>;
+
+    fn save_device_cursor<'a>(
+        &'a self,
+        user_id: &'a str,
+        device_id: &'a str,
+        cursor: i64,
+        seen_at: i64,
+    ) -> StoreFuture<'a, ()>;
+
+    fn list_devices<'a>(&'a self, user_id: &'a str) -> StoreFuture<'a, Vec<DeviceCursor>>;
+}
@@ -0,0 +1,162 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+pub type Fields = serde_json::Map<String, serde_json::Value>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum EntityKind {
+    Session,
+    Note,
+    Transcript,
+}
+
+impl EntityKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Session => "session",
+            Self::Note => "note",
+            Self::Transcript => "transcript",
+        }
+    }
+}
+
+impl std::str::FromStr for EntityKind {
+    type Err = crate::SyncError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "session" => Ok(Self::Session),
+            "note" => Ok(Self::Note),
+            "transcript" => Ok(Self::Transcript),
+            other => Err(crate::SyncError::Internal(format!(
+                "unknown entity kind: {other}"
+            ))),
+        }
+    }
+}
+
+/// Current server state of an entity, as returned by the change feed.
+///
+/// Deleted entities are kept as tombstones with empty `data`, so devices that
+/// were offline during the delete still learn about it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct EntityRecord {
+    pub kind: EntityKind,
+    pub id: String,
+    /// Server sequence number of the last write. Feed cursors are versions.
+    pub version: i64,
+    /// Client timestamp (unix ms) of the last accepted write.
+    pub updated_at: i64,
+    pub updated_by: String,
+    pub deleted: bool,
+    #[schema(value_type = Object)]
+    pub data: Fields,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PushChange {
+    pub kind: EntityKind,
+    pub id: String,
+    /// The entity version this change was made against, if the device has
+    /// seen the entity before. Used to tell concurrent edits apart from
+    /// edits the device already knew about.
+    #[serde(default)]
+    pub base_version: Option<i64>,
+    /// Client timestamp (unix ms) of the change; the last writer wins.
+    pub updated_at: i64,
+    #[serde(default)]
+    pub deleted: bool,
+    /// Changed fields only. Omitted fields keep their server value.
+    #[serde(default)]
+    #[schema(value_type = Object)]
+    pub data: Fields,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PushStatus {
+    /// Every field in the change was written.
+    Applied,
+    /// Some fields were written; newer server values were kept for the rest.
+    Partial,
+    /// Nothing was written; the server state is newer.
+    Rejected,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictResolution {
+    ClientWins,
+    ServerWins,
+}
+
+/// A field that another device changed since the pushing device's
+/// `base_version`, and how last-writer-wins resolved it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct FieldConflict {
+    pub field: String,
+    pub resolution: ConflictResolution,
+    #[schema(value_type = Object)]
+    pub server_value: serde_json::Value,
+    #[schema(value_type = Object)]
+    pub client_value: serde_json::Value,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct PushResult {
+    pub kind: EntityKind,
+    pub id: String,
+    pub status: PushStatus,
+    pub version: i64,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub conflicts: Vec<FieldConflict>,
+    /// The merged server state, when it differs from what the device sent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub record: Option<EntityRecord>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct PushRequest {
+    pub device_id: String,
+    pub changes: Vec<PushChange>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PushResponse {
+    pub results: Vec<PushResult>,
+    /// Latest version after the push.
+    pub cursor: i64,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct PullRequest {
+    pub device_id: String,
+    /// Last version the device has applied, acknowledging every change up to
+    /// it. Falls back to the last cursor the device acknowledged.
+    #[serde(default)]
+    pub cursor: Option<i64>,
+    #[serde(default)]
+    pub limit: Option<u32>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PullResponse {
+    pub changes: Vec<EntityRecord>,
+    /// Send back as the next pull's `cursor` once `changes` are applied.
+    pub cursor: i64,
+    pub has_more: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct DeviceCursor {
+    pub device_id: String,
+    pub cursor: i64,
+    /// Unix ms of the device's last pull.
+    pub last_seen_at: i64,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ListDevicesResponse {
+    pub devices: Vec<DeviceCursor>,
+}
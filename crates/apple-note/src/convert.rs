@@ -31,6 +31,11 @@ pub fn note_to_markdown(note: &Note) -> String {
                 .and_then(|ps| ps.indent_amount)
                 .unwrap_or(0) as usize;
 
+            // Paragraph styles cover the trailing newline and may be split across
+            // several runs, so prefixes only go at the start of a non-empty line.
+            let trailing = line_idx > 0 && line_idx == lines.len() - 1 && line.is_empty();
+            let line_start = (markdown.is_empty() || markdown.ends_with('\n')) && !trailing;
+
             let mut prefix = String::new();
 
             if is_block_quote {
@@ -44,19 +49,19 @@ pub fn note_to_markdown(note: &Note) -> String {
             if let Some(style_type) = span.style_type {
                 match style_type {
                     STYLE_TYPE_TITLE => {
-                        if !in_code_block {
+                        if line_start && !in_code_block {
                             markdown.push_str(&prefix);
                             markdown.push_str("# ");
                         }
                     }
                     STYLE_TYPE_HEADING => {
-                        if !in_code_block {
+                        if line_start && !in_code_block {
                             markdown.push_str(&prefix);
                             markdown.push_str("## ");
                         }
                     }
                     STYLE_TYPE_SUBHEADING => {
-                        if !in_code_block {
+                        if line_start && !in_code_block {
                             markdown.push_str(&prefix);
                             markdown.push_str("### ");
                         }
@@ -67,15 +72,11 @@ pub fn note_to_markdown(note: &Note) -> String {
                             in_code_block = true;
                         }
                     }
-                    STYLE_TYPE_DOTTED_LIST => {
-                        markdown.push_str(&prefix);
-                        markdown.push_str("- ");
-                    }
-                    STYLE_TYPE_DASHED_LIST => {
+                    STYLE_TYPE_DOTTED_LIST | STYLE_TYPE_DASHED_LIST if line_start => {
                         markdown.push_str(&prefix);
                         markdown.push_str("- ");
                     }
-                    STYLE_TYPE_NUMBERED_LIST => {
+                    STYLE_TYPE_NUMBERED_LIST if line_start => {
                         while list_counters.len() <= indent_amount {
                             list_counters.push(1);
                         }
@@ -83,7 +84,7 @@ pub fn note_to_markdown(note: &Note) -> String {
                         markdown.push_str(&format!("{}. ", list_counters[indent_amount]));
                         list_counters[indent_amount] += 1;
                     }
-                    STYLE_TYPE_CHECKBOX => {
+                    STYLE_TYPE_CHECKBOX if line_start => {
                         let is_checked = span
                             .paragraph_style
                             .as_ref()
@@ -98,12 +99,12 @@ pub fn note_to_markdown(note: &Note) -> String {
                         }
                     }
                     _ => {
-                        if !in_code_block {
+                        if line_start && !in_code_block {
                             markdown.push_str(&prefix);
                         }
                     }
                 }
-            } else if !in_code_block {
+            } else if line_start && !in_code_block {
                 markdown.push_str(&prefix);
             }
 
@@ -111,7 +112,7 @@ pub fn note_to_markdown(note: &Note) -> String {
 
             if in_code_block {
                 formatted_text.push_str(line);
-            } else {
+            } else if !line.is_empty() {
                 if span.bold && span.italic {
                     formatted_text.push_str("***");
                 } else if span.bold {
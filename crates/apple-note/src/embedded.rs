@@ -24,6 +24,7 @@ impl EmbeddedObjectType {
             "com.apple.notes.table" => Self::Table,
             "com.apple.notes.ICTable" => Self::Table,
             "public.image" => Self::Image,
+            "public.jpeg" | "public.png" | "public.heic" | "public.tiff" => Self::Image,
             "com.apple.drawing.2" => Self::Drawing,
             "public.url" => Self::URL,
             "public.audio" => Self::Audio,
@@ -31,6 +32,9 @@ impl EmbeddedObjectType {
             "com.apple.paper" => Self::Document,
             "com.apple.notes.gallery" => Self::Gallery,
             "com.adobe.pdf" => Self::PDF,
+            "com.apple.notes.inlinetextattachment.hashtag" => Self::Hashtag,
+            "com.apple.notes.inlinetextattachment.mention" => Self::Mention,
+            "com.apple.notes.inlinetextattachment.link" => Self::Link,
             _ if uti.contains("image") => Self::Image,
             _ if uti.contains("video") => Self::Video,
             _ if uti.contains("audio") => Self::Audio,
@@ -0,0 +1,424 @@
+mod store;
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use hypr_importer_core::ir::{Collection, Session, Tag, TagMapping};
+
+use crate::proto::{AttributeRun, Note};
+use crate::{
+    EmbeddedObjectType, Error, Result, note_to_markdown, parse_mergable_data_proto,
+    parse_note_store_proto, table::Table, table::parse_table, utils::core_time_to_unix,
+};
+use store::{AttachmentRow, FolderRow, NoteStore};
+
+const FOLDER_TYPE_TRASH: i64 = 1;
+const SQLITE_SUFFIXES: &[&str] = &["", "-wal", "-shm"];
+// Media sits at `Media/<id>/<file>`, or one generation folder deeper on newer macOS.
+const MEDIA_SEARCH_DEPTH: usize = 2;
+
+pub fn default_note_store_path() -> PathBuf {
+    dirs::home_dir()
+        .map(|home| home.join("Library/Group Containers/group.com.apple.notes/NoteStore.sqlite"))
+        .unwrap_or_else(|| PathBuf::from("NoteStore.sqlite"))
+}
+
+/// Imports every note in a `NoteStore.sqlite` as a session.
+///
+/// The database (and its WAL) is copied to a temporary directory first, so
+/// this is safe to run while Notes is open. Attachments are linked to their
+/// files in the Notes container next to the database.
+pub async fn import_all_from_path(path: &Path) -> Result<Collection> {
+    if !path.exists() {
+        return Err(Error::InvalidNoteStore(format!(
+            "{} not found",
+            path.display()
+        )));
+    }
+
+    let dir = tempfile::tempdir().map_err(Error::Copy)?;
+    let copied = dir.path().join("NoteStore.sqlite");
+    for suffix in SQLITE_SUFFIXES {
+        let mut from = path.as_os_str().to_owned();
+        from.push(suffix);
+        let from = PathBuf::from(from);
+        if from.exists() {
+            std::fs::copy(&from, format!("{}{suffix}", copied.display())).map_err(Error::Copy)?;
+        }
+    }
+
+    let container = path.parent().unwrap_or(Path::new("."));
+    let store = NoteStore::open(&copied).await?;
+    let folders = Folders::new(store.folders().await?);
+
+    let mut sessions = Vec::new();
+    let mut tags = TagIndex::default();
+
+    for row in store.notes().await? {
+        if row.folder.is_some_and(|pk| folders.is_trashed(pk)) {
+            continue;
+        }
+
+        let proto = parse_note_store_proto(&row.data)?;
+        let note = proto.document.note;
+
+        let mut hashtags = Vec::new();
+        let mut attachments = Vec::new();
+        for run in &note.attribute_run {
+            let Some(info) = &run.attachment_info else {
+                continue;
+            };
+            let uuid = info.attachment_identifier.clone().unwrap_or_default();
+            let kind = EmbeddedObjectType::from_uti(info.type_uti.as_deref().unwrap_or_default());
+            let attachment = store.attachment(&uuid).await?;
+
+            if kind == EmbeddedObjectType::Hashtag
+                && let Some(text) = attachment.as_ref().and_then(|a| a.alt_text.as_deref())
+            {
+                hashtags.push(text.trim_start_matches('#').to_string());
+            }
+            let media = attachment.as_ref().and_then(|a| media_file(container, a));
+            attachments.push(render_attachment(
+                &kind,
+                attachment.as_ref(),
+                media.as_deref(),
+            ));
+        }
+
+        let title = row.title.clone().unwrap_or_default();
+        let session = Session {
+            id: row.identifier.clone(),
+            user_id: String::new(),
+            created_at: row.created_at.map(format_core_time).unwrap_or_default(),
+            raw_md: Some(note_body(&inline_attachments(&note, attachments), &title)),
+            title,
+            enhanced_content: None,
+            folder_id: row.folder.and_then(|pk| folders.folder_id(pk)),
+            event_id: None,
+        };
+
+        for name in hashtags {
+            tags.map(&name, &session.id);
+        }
+        sessions.push(session);
+    }
+
+    Ok(Collection {
+        sessions,
+        tags: tags.tags,
+        tag_mappings: tags.mappings,
+        ..Default::default()
+    })
+}
+
+struct Folders(HashMap<i64, FolderRow>);
+
+impl Folders {
+    fn new(rows: Vec<FolderRow>) -> Self {
+        Self(rows.into_iter().map(|f| (f.pk, f)).collect())
+    }
+
+    fn ancestors(&self, pk: i64) -> impl Iterator<Item = &FolderRow> {
+        let mut next = self.0.get(&pk);
+        let mut depth = 0;
+        std::iter::from_fn(move || {
+            // Guards against parent cycles in a damaged store.
+            depth += 1;
+            let folder = next.filter(|_| depth <= 32)?;
+            next = folder.parent.and_then(|p| self.0.get(&p));
+            Some(folder)
+        })
+    }
+
+    fn is_trashed(&self, pk: i64) -> bool {
+        self.ancestors(pk)
+            .any(|f| f.folder_type == FOLDER_TYPE_TRASH)
+    }
+
+    /// Vault folder id of a folder: its `Parent/Child` path under `sessions/`,
+    /// with each title made safe to use as a directory name.
+    fn folder_id(&self, pk: i64) -> Option<String> {
+        let mut segments: Vec<String> = self
+            .ancestors(pk)
+            .map(|f| folder_segment(&f.title))
+            .filter(|s| !s.is_empty())
+            .collect();
+        if segments.is_empty() {
+            return None;
+        }
+        segments.reverse();
+        Some(segments.join("/"))
+    }
+}
+
+fn folder_segment(title: &str) -> String {
+    let segment: String = title
+        .chars()
+        .map(|c| match c {
+            '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' => '-',
+            c if c.is_control() => '-',
+            c => c,
+        })
+        .collect();
+    segment.trim().trim_start_matches('.').trim().to_string()
+}
+
+/// Finds an attachment's file under `Accounts/<account>/Media/<media id>/`
+/// in the Notes container.
+fn media_file(container: &Path, attachment: &AttachmentRow) -> Option<PathBuf> {
+    let media_id = attachment.media_identifier.as_deref()?;
+    let filename = attachment.filename.as_deref()?;
+    if media_id.contains(['/', '\\']) || filename.contains(['/', '\\']) {
+        return None;
+    }
+
+    std::fs::read_dir(container.join("Accounts"))
+        .ok()?
+        .flatten()
+        .find_map(|account| {
+            let dir = account.path().join("Media").join(media_id);
+            find_file(&dir, filename, MEDIA_SEARCH_DEPTH)
+        })
+        .and_then(|file| std::fs::canonicalize(file).ok())
+}
+
+fn find_file(dir: &Path, filename: &str, depth: usize) -> Option<PathBuf> {
+    let candidate = dir.join(filename);
+    if candidate.is_file() {
+        return Some(candidate);
+    }
+    if depth == 0 {
+        return None;
+    }
+    std::fs::read_dir(dir)
+        .ok()?
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_dir())
+        .find_map(|path| find_file(&path, filename, depth - 1))
+}
+
+#[derive(Default)]
+struct TagIndex {
+    tags: Vec<Tag>,
+    mappings: Vec<TagMapping>,
+    ids: HashMap<String, String>,
+}
+
+impl TagIndex {
+    fn map(&mut self, name: &str, session_id: &str) {
+        let tag_id = self
+            .ids
+            .entry(name.to_string())
+            .or_insert_with(|| {
+                let id =
+                    uuid::Uuid::new_v5(&uuid::Uuid::NAMESPACE_OID, name.as_bytes()).to_string();
+                self.tags.push(Tag {
+                    id: id.clone(),
+                    user_id: String::new(),
+                    name: name.to_string(),
+                });
+                id
+            })
+            .clone();
+
+        let id = format!("{}_{}", tag_id, session_id);
+        if self.mappings.iter().any(|m| m.id == id) {
+            return;
+        }
+        self.mappings.push(TagMapping {
+            id,
+            user_id: String::new(),
+            tag_id,
+            session_id: session_id.to_string(),
+        });
+    }
+}
+
+fn format_core_time(core_time: f64) -> String {
+    chrono::DateTime::from_timestamp(core_time_to_unix(core_time as i64), 0)
+        .map(|dt| dt.to_rfc3339())
+        .unwrap_or_default()
+}
+
+/// Replaces each attachment run (an U+FFFC in the note text) with a plain
+/// text run holding its rendered markdown, so the regular converter keeps
+/// paragraph styles around it.
+fn inline_attachments(note: &Note, rendered: Vec<String>) -> Note {
+    let chars: Vec<char> = note.note_text.chars().collect();
+    let mut rendered = rendered.into_iter();
+    let mut text = String::new();
+    let mut runs = Vec::with_capacity(note.attribute_run.len());
+    let mut index = 0;
+
+    for run in &note.attribute_run {
+        let end = (index + run.length as usize).min(chars.len());
+
+        if run.attachment_info.is_some() {
+            let replacement = rendered.next().unwrap_or_default();
+            text.push_str(&replacement);
+            runs.push(AttributeRun {
+                length: replacement.chars().count() as i32,
+                attachment_info: None,
+                ..run.clone()
+            });
+        } else {
+            text.extend(&chars[index..end]);
+            runs.push(run.clone());
+        }
+        index = end;
+    }
+
+    Note {
+        note_text: text,
+        attribute_run: runs,
+    }
+}
+
+fn render_attachment(
+    kind: &EmbeddedObjectType,
+    attachment: Option<&AttachmentRow>,
+    media: Option<&Path>,
+) -> String {
+    let Some(attachment) = attachment else {
+        return String::new();
+    };
+    let name = attachment
+        .filename
+        .as_deref()
+        .or(attachment.title.as_deref())
+        .unwrap_or_default();
+
+    if let Some(media) = media {
+        let target = media.display();
+        return match kind {
+            EmbeddedObjectType::Image => format!("![{name}](<{target}>)"),
+            _ => format!("[{name}](<{target}>)"),
+        };
+    }
+
+    match kind {
+        EmbeddedObjectType::Table => attachment
+            .mergeable_data
+            .as_deref()
+            .and_then(|data| parse_mergable_data_proto(data).ok())
+            .and_then(|proto| parse_table(&proto))
+            .map(|table| table_to_markdown(&table))
+            .unwrap_or_default(),
+        EmbeddedObjectType::URL => match &attachment.url {
+            Some(url) => format!("[{}]({})", attachment.title.as_deref().unwrap_or(url), url),
+            None => String::new(),
+        },
+        EmbeddedObjectType::Hashtag | EmbeddedObjectType::Mention | EmbeddedObjectType::Link => {
+            attachment.alt_text.clone().unwrap_or_default()
+        }
+        EmbeddedObjectType::Image => format!("[Image: {name}]"),
+        EmbeddedObjectType::Drawing => format!("[Drawing: {name}]"),
+        EmbeddedObjectType::PDF => format!("[PDF: {name}]"),
+        EmbeddedObjectType::Audio => format!("[Audio: {name}]"),
+        EmbeddedObjectType::Video => format!("[Video: {name}]"),
+        EmbeddedObjectType::Document => format!("[Document: {name}]"),
+        EmbeddedObjectType::Gallery => format!("[Gallery: {name}]"),
+        EmbeddedObjectType::Unknown if !name.is_empty() => format!("[Attachment: {name}]"),
+        EmbeddedObjectType::Unknown => String::new(),
+    }
+}
+
+fn table_to_markdown(table: &Table) -> String {
+    let columns = table.column_count();
+    if columns == 0 {
+        return String::new();
+    }
+
+    let row = |cells: &[String]| {
+        let cells: Vec<String> = (0..columns)
+            .map(|i| {
+                cells
+                    .get(i)
+                    .map(|c| c.replace('|', "\\|").replace('\n', " "))
+                    .unwrap_or_default()
+            })
+            .collect();
+        format!("| {} |", cells.join(" | "))
+    };
+
+    let mut lines = vec![
+        row(&table.rows[0]),
+        format!("|{}", " --- |".repeat(columns)),
+    ];
+    lines.extend(table.rows[1..].iter().map(|cells| row(cells)));
+    lines.join("\n")
+}
+
+/// Markdown body without the leading title line, which becomes the session title.
+fn note_body(note: &Note, title: &str) -> String {
+    let markdown = note_to_markdown(note);
+    let (first, rest) = markdown.split_once('\n').unwrap_or((&markdown, ""));
+
+    let body = if !title.is_empty() && first.trim_start_matches("# ").trim() == title.trim() {
+        rest
+    } else {
+        &markdown
+    };
+    body.trim().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn folder(pk: i64, title: &str, parent: Option<i64>, folder_type: i64) -> FolderRow {
+        FolderRow {
+            pk,
+            title: title.to_string(),
+            parent,
+            folder_type,
+        }
+    }
+
+    #[test]
+    fn test_folder_paths() {
+        let folders = Folders::new(vec![
+            folder(1, "Work", None, 0),
+            folder(2, "Meetings", Some(1), 0),
+            folder(3, "Recently Deleted", None, FOLDER_TYPE_TRASH),
+            folder(4, "Old", Some(3), 0),
+        ]);
+
+        assert_eq!(folders.folder_id(2).as_deref(), Some("Work/Meetings"));
+        assert_eq!(folders.folder_id(9), None);
+        assert!(!folders.is_trashed(2));
+        assert!(folders.is_trashed(4));
+    }
+
+    #[test]
+    fn test_folder_ids_are_safe_directory_names() {
+        let folders = Folders::new(vec![
+            folder(1, "Q1/Q2 plans", None, 0),
+            folder(2, "..hidden: notes", Some(1), 0),
+            folder(3, "///", None, 0),
+        ]);
+
+        assert_eq!(
+            folders.folder_id(2).as_deref(),
+            Some("Q1-Q2 plans/hidden- notes")
+        );
+        assert_eq!(folders.folder_id(3).as_deref(), Some("---"));
+    }
+
+    #[test]
+    fn test_table_to_markdown() {
+        let table = Table {
+            rows: vec![
+                vec!["Owner".to_string(), "Task".to_string()],
+                vec!["Ana".to_string(), "a | b".to_string()],
+            ],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            table_to_markdown(&table),
+            "| Owner | Task |\n| --- | --- |\n| Ana | a \\| b |"
+        );
+    }
+}
@@ -0,0 +1,181 @@
+use std::collections::HashSet;
+use std::path::Path;
+
+use hypr_db_core::libsql;
+
+use crate::{Error, Result};
+
+const OBJECTS_TABLE: &str = "ZICCLOUDSYNCINGOBJECT";
+
+pub(super) struct FolderRow {
+    pub pk: i64,
+    pub title: String,
+    pub parent: Option<i64>,
+    pub folder_type: i64,
+}
+
+pub(super) struct NoteRow {
+    pub identifier: String,
+    pub title: Option<String>,
+    pub folder: Option<i64>,
+    /// Core Data timestamp (seconds since 2001-01-01).
+    pub created_at: Option<f64>,
+    pub data: Vec<u8>,
+}
+
+pub(super) struct AttachmentRow {
+    pub title: Option<String>,
+    pub url: Option<String>,
+    pub alt_text: Option<String>,
+    pub filename: Option<String>,
+    /// Identifier of the media object, which names its folder on disk.
+    pub media_identifier: Option<String>,
+    pub mergeable_data: Option<Vec<u8>>,
+}
+
+/// Read-only view over a `NoteStore.sqlite`.
+///
+/// Core Data numbers its columns per entity (`ZTITLE1`, `ZCREATIONDATE3`, ...)
+/// and the numbering shifts between macOS releases, so columns are resolved
+/// against the actual schema instead of being hard-coded.
+pub(super) struct NoteStore {
+    conn: libsql::Connection,
+    columns: HashSet<String>,
+}
+
+impl NoteStore {
+    pub async fn open(path: &Path) -> Result<Self> {
+        let db = libsql::Builder::new_local(path).build().await?;
+        let conn = db.connect()?;
+
+        let mut rows = conn
+            .query(&format!("PRAGMA table_info({OBJECTS_TABLE})"), ())
+            .await?;
+        let mut columns = HashSet::new();
+        while let Some(row) = rows.next().await? {
+            columns.insert(row.get::<String>(1)?);
+        }
+
+        if columns.is_empty() {
+            return Err(Error::InvalidNoteStore(format!(
+                "missing {OBJECTS_TABLE} table"
+            )));
+        }
+
+        Ok(Self { conn, columns })
+    }
+
+    /// First of `candidates` present in the schema, or `fallback` as a literal.
+    fn column(&self, alias: &str, candidates: &[&str], fallback: &str) -> String {
+        let column = candidates
+            .iter()
+            .find(|c| self.columns.contains(**c))
+            .map(|c| format!("{alias}.{c}"));
+        column.unwrap_or_else(|| fallback.to_string())
+    }
+
+    pub async fn folders(&self) -> Result<Vec<FolderRow>> {
+        let title = self.column("f", &["ZTITLE2"], "NULL");
+        let parent = self.column("f", &["ZPARENT"], "NULL");
+        let folder_type = self.column("f", &["ZFOLDERTYPE"], "0");
+
+        let mut rows = self
+            .conn
+            .query(
+                &format!(
+                    "SELECT f.Z_PK, {title}, {parent}, COALESCE({folder_type}, 0)
+                    FROM {OBJECTS_TABLE} f WHERE {title} IS NOT NULL"
+                ),
+                (),
+            )
+            .await?;
+
+        let mut folders = Vec::new();
+        while let Some(row) = rows.next().await? {
+            folders.push(FolderRow {
+                pk: row.get(0)?,
+                title: row.get(1)?,
+                parent: row.get(2)?,
+                folder_type: row.get(3)?,
+            });
+        }
+        Ok(folders)
+    }
+
+    pub async fn notes(&self) -> Result<Vec<NoteRow>> {
+        let title = self.column("n", &["ZTITLE1", "ZTITLE"], "NULL");
+        let folder = self.column("n", &["ZFOLDER"], "NULL");
+        let created_at = self.column(
+            "n",
+            &["ZCREATIONDATE3", "ZCREATIONDATE1", "ZCREATIONDATE"],
+            "NULL",
+        );
+        let deleted = self.column("n", &["ZMARKEDFORDELETION"], "0");
+        let locked = self.column("n", &["ZISPASSWORDPROTECTED"], "0");
+
+        let mut rows = self
+            .conn
+            .query(
+                &format!(
+                    "SELECT n.ZIDENTIFIER, {title}, {folder}, CAST({created_at} AS REAL), d.ZDATA
+                    FROM {OBJECTS_TABLE} n
+                    JOIN ZICNOTEDATA d ON d.ZNOTE = n.Z_PK
+                    WHERE d.ZDATA IS NOT NULL
+                    AND n.ZIDENTIFIER IS NOT NULL
+                    AND COALESCE({deleted}, 0) = 0
+                    AND COALESCE({locked}, 0) = 0
+                    ORDER BY {created_at} ASC"
+                ),
+                (),
+            )
+            .await?;
+
+        let mut notes = Vec::new();
+        while let Some(row) = rows.next().await? {
+            notes.push(NoteRow {
+                identifier: row.get(0)?,
+                title: row.get(1)?,
+                folder: row.get(2)?,
+                created_at: row.get(3)?,
+                data: row.get(4)?,
+            });
+        }
+        Ok(notes)
+    }
+
+    pub async fn attachment(&self, identifier: &str) -> Result<Option<AttachmentRow>> {
+        let title = self.column("a", &["ZTITLE"], "NULL");
+        let url = self.column("a", &["ZURLSTRING"], "NULL");
+        let alt_text = self.column("a", &["ZALTTEXT"], "NULL");
+        let media = self.column("a", &["ZMEDIA"], "NULL");
+        let filename = self.column("m", &["ZFILENAME"], "NULL");
+        let media_identifier = self.column("m", &["ZIDENTIFIER"], "NULL");
+        let mergeable_data = self.column("a", &["ZMERGEABLEDATA1", "ZMERGEABLEDATA"], "NULL");
+
+        let mut rows = self
+            .conn
+            .query(
+                &format!(
+                    "SELECT {title}, {url}, {alt_text}, {filename}, {media_identifier}, {mergeable_data}
+                    FROM {OBJECTS_TABLE} a
+                    LEFT JOIN {OBJECTS_TABLE} m ON m.Z_PK = {media}
+                    WHERE a.ZIDENTIFIER = ?
+                    LIMIT 1"
+                ),
+                libsql::params![identifier],
+            )
+            .await?;
+
+        match rows.next().await? {
+            Some(row) => Ok(Some(AttachmentRow {
+                title: row.get(0)?,
+                url: row.get(1)?,
+                alt_text: row.get(2)?,
+                filename: row.get(3)?,
+                media_identifier: row.get(4)?,
+                mergeable_data: row.get(5)?,
+            })),
+            None => Ok(None),
+        }
+    }
+}
@@ -1,6 +1,7 @@
 pub mod convert;
 pub mod embedded;
 pub mod extract;
+pub mod importer;
 pub mod parser;
 pub mod proto;
 pub mod table;
@@ -43,6 +44,15 @@ pub enum Error {
 
     #[error("Failed to decompress data: {0}")]
     Decompression(#[from] std::io::Error),
+
+    #[error("Failed to copy note store: {0}")]
+    Copy(#[source] std::io::Error),
+
+    #[error("Failed to read note store: {0}")]
+    Sqlite(#[from] hypr_db_core::libsql::Error),
+
+    #[error("Invalid note store: {0}")]
+    InvalidNoteStore(String),
 }
 
 pub type Result<T> = std::result::Result<T, Error>;
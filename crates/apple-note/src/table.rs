@@ -43,6 +43,22 @@ fn get_target_uuid_from_object_entry(object_entry: &MergeableDataObjectEntry) ->
         .map(|entry| entry.value.unsigned_integer_value)
 }
 
+// The direction register points at a string object holding the direction.
+fn get_direction(
+    object_entry: &MergeableDataObjectEntry,
+    table_objects: &[MergeableDataObjectEntry],
+) -> Option<String> {
+    let target = table_objects.get(object_entry.register_latest.contents.object_index as usize)?;
+    let value = &target
+        .custom_map
+        .as_ref()?
+        .map_entry
+        .first()?
+        .value
+        .string_value;
+    (!value.is_empty()).then(|| value.clone())
+}
+
 pub fn parse_table(proto: &MergableDataProto) -> Option<Table> {
     let data = &proto.mergable_data_object.mergeable_data_object_data;
 
@@ -51,30 +67,12 @@ pub fn parse_table(proto: &MergableDataProto) -> Option<Table> {
     let uuid_items = &data.mergeable_data_object_uuid_item;
     let table_objects = &data.mergeable_data_object_entry;
 
-    let mut table_direction = LEFT_TO_RIGHT_DIRECTION.to_string();
-
-    for entry in table_objects {
-        if let Some(ref custom_map) = entry.custom_map
-            && let Some(first_entry) = custom_map.map_entry.first()
-            && (first_entry.key as usize) == key_items.len()
-            && let Some(dir) = &first_entry.value.string_value.as_str().into()
-        {
-            table_direction = (*dir).to_string();
-        }
-    }
-
     for entry in table_objects {
         if let Some(ref custom_map) = entry.custom_map {
             let type_index = custom_map.r#type as usize;
             if type_index < type_items.len() && type_items[type_index] == "com.apple.notes.ICTable"
             {
-                return parse_table_entry(
-                    entry,
-                    key_items,
-                    uuid_items,
-                    table_objects,
-                    &table_direction,
-                );
+                return parse_table_entry(entry, key_items, uuid_items, table_objects);
             }
         }
     }
@@ -87,7 +85,6 @@ fn parse_table_entry(
     key_items: &[String],
     uuid_items: &[Vec<u8>],
     table_objects: &[MergeableDataObjectEntry],
-    table_direction: &str,
 ) -> Option<Table> {
     let custom_map = table_entry.custom_map.as_ref()?;
 
@@ -96,14 +93,12 @@ fn parse_table_entry(
     let mut total_rows = 0;
     let mut total_columns = 0;
     let mut cell_columns_entry: Option<&MergeableDataObjectEntry> = None;
+    let mut table_direction = LEFT_TO_RIGHT_DIRECTION.to_string();
 
     for map_entry in &custom_map.map_entry {
-        let key_index = map_entry.key as usize;
-        if key_index == 0 || key_index > key_items.len() {
+        let Some(key_name) = key_items.get(map_entry.key as usize) else {
             continue;
-        }
-
-        let key_name = &key_items[key_index - 1];
+        };
         let object_index = map_entry.value.object_index as usize;
 
         if object_index >= table_objects.len() {
@@ -113,6 +108,11 @@ fn parse_table_entry(
         let target_object = &table_objects[object_index];
 
         match key_name.as_str() {
+            "crTableColumnDirection" => {
+                if let Some(direction) = get_direction(target_object, table_objects) {
+                    table_direction = direction;
+                }
+            }
             "crRows" => {
                 total_rows = parse_rows(target_object, uuid_items, table_objects, &mut row_indices);
             }
@@ -155,7 +155,7 @@ fn parse_table_entry(
 
     Some(Table {
         rows: reconstructed_table,
-        direction: table_direction.to_string(),
+        direction: table_direction,
     })
 }
 
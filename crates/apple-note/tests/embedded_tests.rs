@@ -43,6 +43,10 @@ fn test_embedded_object_type_detection() {
         EmbeddedObjectType::from_uti("com.adobe.pdf"),
         EmbeddedObjectType::PDF
     );
+    assert_eq!(
+        EmbeddedObjectType::from_uti("com.apple.notes.inlinetextattachment.hashtag"),
+        EmbeddedObjectType::Hashtag
+    );
     assert_eq!(
         EmbeddedObjectType::from_uti("unknown.type"),
         EmbeddedObjectType::Unknown
@@ -0,0 +1,74 @@
+//! Importer tests
+//!
+//! `tests/data/NoteStore.sqlite` is a trimmed NoteStore with two live notes,
+//! one note in Recently Deleted and one marked for deletion. `tests/data/Accounts`
+//! holds the media file for its one image attachment.
+
+use apple_note::importer::import_all_from_path;
+use std::path::Path;
+
+const NOTE_STORE: &str = "tests/data/NoteStore.sqlite";
+
+#[tokio::test]
+async fn test_import_skips_trashed_and_deleted_notes() {
+    let collection = import_all_from_path(Path::new(NOTE_STORE)).await.unwrap();
+
+    let ids: Vec<_> = collection.sessions.iter().map(|s| s.id.as_str()).collect();
+    assert_eq!(ids, vec!["NOTE-WEEKLY", "NOTE-GROCERIES"]);
+}
+
+#[tokio::test]
+async fn test_import_maps_note_metadata() {
+    let collection = import_all_from_path(Path::new(NOTE_STORE)).await.unwrap();
+    let session = &collection.sessions[0];
+
+    assert_eq!(session.title, "Weekly sync");
+    assert_eq!(session.created_at, "2024-03-01T10:00:00+00:00");
+    assert_eq!(session.folder_id.as_deref(), Some("Work/Meetings"));
+    assert_eq!(collection.sessions[1].folder_id.as_deref(), Some("Notes"));
+}
+
+#[tokio::test]
+async fn test_import_renders_checklists_and_attachments() {
+    let collection = import_all_from_path(Path::new(NOTE_STORE)).await.unwrap();
+
+    let weekly = collection.sessions[0].raw_md.as_deref().unwrap();
+    assert!(!weekly.contains("Weekly sync"));
+    assert!(weekly.contains("Agenda for #work"));
+    assert!(weekly.contains("- [x] Ship importer"));
+    assert!(weekly.contains("- [ ] Write docs"));
+    let image = std::fs::canonicalize(
+        "tests/data/Accounts/LOCAL-ACCOUNT/Media/MEDIA-1/1_GENERATION/whiteboard.jpeg",
+    )
+    .unwrap();
+    assert!(weekly.contains(&format!("![whiteboard.jpeg](<{}>)", image.display())));
+    assert!(weekly.contains("| Row 1 Column 1 | Row 1 Column 2 |"));
+    assert!(!weekly.contains('\u{FFFC}'));
+
+    let groceries = collection.sessions[1].raw_md.as_deref().unwrap();
+    assert!(groceries.contains("[Oatly](https://www.oatly.com)"));
+}
+
+#[tokio::test]
+async fn test_import_maps_hashtags_to_tags() {
+    let collection = import_all_from_path(Path::new(NOTE_STORE)).await.unwrap();
+
+    let mut names: Vec<_> = collection.tags.iter().map(|t| t.name.as_str()).collect();
+    names.sort();
+    assert_eq!(names, vec!["home", "work"]);
+
+    assert_eq!(collection.tag_mappings.len(), 2);
+    let work = collection.tags.iter().find(|t| t.name == "work").unwrap();
+    assert!(
+        collection
+            .tag_mappings
+            .iter()
+            .any(|m| m.tag_id == work.id && m.session_id == "NOTE-WEEKLY")
+    );
+}
+
+#[tokio::test]
+async fn test_import_missing_store() {
+    let result = import_all_from_path(Path::new("tests/data/missing.sqlite")).await;
+    assert!(result.is_err());
+}
@@ -587,3 +587,49 @@ fn test_note_with_mixed_formatting() {
         "Should contain bold and/or italic markers"
     );
 }
+
+#[test]
+fn test_checklist_runs_with_trailing_newlines() {
+    use apple_note::proto::{AttributeRun, Checklist, Note, ParagraphStyle};
+    use apple_note::{FONT_TYPE_BOLD, STYLE_TYPE_CHECKBOX, note_to_markdown};
+
+    let run = |length: i32, done: i32, font_weight: Option<i32>| AttributeRun {
+        length,
+        paragraph_style: Some(ParagraphStyle {
+            style_type: Some(STYLE_TYPE_CHECKBOX),
+            alignment: None,
+            indent_amount: None,
+            checklist: Some(Checklist {
+                uuid: vec![0; 16],
+                done,
+            }),
+            block_quote: None,
+        }),
+        font: None,
+        font_weight,
+        underlined: None,
+        strikethrough: None,
+        superscript: None,
+        link: None,
+        color: None,
+        attachment_info: None,
+        unknown_identifier: None,
+        emphasis_style: None,
+    };
+
+    // Apple Notes ends each paragraph run with its newline and splits runs
+    // wherever the formatting changes.
+    let note = Note {
+        note_text: "Ship importer\nWrite docs\n".to_string(),
+        attribute_run: vec![
+            run(5, 1, None),
+            run(9, 1, Some(FONT_TYPE_BOLD)),
+            run(11, 0, None),
+        ],
+    };
+
+    assert_eq!(
+        note_to_markdown(&note),
+        "- [x] Ship **importer**\n- [ ] Write docs\n"
+    );
+}
@@ -48,6 +48,19 @@ pub fn is_korean(value: &Option<String>, _env: &dyn askama::Values) -> askama::R
     Ok(matches!(lang, Some(Language::Kor)))
 }
 
+/// Formats milliseconds from the start of a recording as `m:ss`, or `h:mm:ss`
+/// past the first hour.
+#[askama::filter_fn]
+pub fn timestamp(value: &u64, _env: &dyn askama::Values) -> askama::Result<String> {
+    let total_secs = *value / 1000;
+    let (hours, minutes, seconds) = (total_secs / 3600, (total_secs / 60) % 60, total_secs % 60);
+    Ok(if hours > 0 {
+        format!("{hours}:{minutes:02}:{seconds:02}")
+    } else {
+        format!("{minutes}:{seconds:02}")
+    })
+}
+
 pub const TEMPLATE_FILTERS: &[&str] = &["current_date", "language", "is_english", "is_korean"];
 
 #[cfg(test)]
@@ -206,4 +219,22 @@ mod tests {
         },
         |v| v == "yes"
     );
+
+    #[derive(Template)]
+    #[template(source = "{{ ms|timestamp }}", ext = "txt")]
+    struct TimestampFilterTest {
+        ms: u64,
+    }
+
+    tpl_assert!(
+        test_timestamp_filter_minutes,
+        TimestampFilterTest { ms: 754_900 },
+        |v| v == "12:34"
+    );
+
+    tpl_assert!(
+        test_timestamp_filter_hours,
+        TimestampFilterTest { ms: 3_725_000 },
+        |v| v == "1:02:05"
+    );
 }
@@ -12,6 +12,12 @@ pub enum Error {
     Hound(#[from] hound::Error),
     #[error(transparent)]
     Vorbis(#[from] vorbis_rs::VorbisError),
+    #[error(transparent)]
+    Opus(#[from] audiopus::Error),
+    #[error(transparent)]
+    Ogg(#[from] ogg::OggReadError),
+    #[error(transparent)]
+    Symphonia(#[from] symphonia::core::errors::Error),
     #[error("vorbis channel data length mismatch for channel {channel}")]
     ChannelDataLengthMismatch { channel: usize },
     #[error("unsupported channel count {count}")]
@@ -22,4 +28,10 @@ pub enum Error {
     EmptyChannelSet,
     #[error("too many channels: {count}")]
     TooManyChannels { count: usize },
+    #[error("no audio track found")]
+    NoAudioTrack,
+    #[error("audio track {0} not found")]
+    TrackNotFound(u32),
+    #[error("unsupported codec {0}")]
+    UnsupportedCodec(String),
 }
@@ -0,0 +1,519 @@
+use std::io::{Seek, SeekFrom, Write};
+use std::num::{NonZeroU8, NonZeroU32};
+
+use crate::{Error, I16_SCALE};
+
+pub const DEFAULT_FLAC_BLOCK_SIZE: usize = 4096;
+
+const BITS_PER_SAMPLE: u32 = 16;
+const STREAMINFO_LEN: u32 = 34;
+const MAX_FIXED_ORDER: usize = 4;
+const MAX_RICE_PARAM: u32 = 14;
+const VENDOR: &str = "hyprnote";
+
+/// Streaming FLAC encoder for 16-bit PCM.
+///
+/// Frames are written to the sink as soon as a block is full, so an unclean
+/// shutdown loses at most the pending block plus whatever the sink buffers.
+/// The STREAMINFO totals are left as "unknown" until [`FlacWriter::finish`],
+/// which keeps truncated files decodable.
+pub struct FlacWriter<W: Write + Seek> {
+    sink: W,
+    channels: usize,
+    sample_rate: u32,
+    block_size: usize,
+    pending: Vec<Vec<i32>>,
+    frame_number: u64,
+    total_samples: u64,
+    min_frame_size: u32,
+    max_frame_size: u32,
+    streaminfo_offset: u64,
+}
+
+impl<W: Write + Seek> FlacWriter<W> {
+    pub fn new(
+        sink: W,
+        channels: NonZeroU8,
+        sample_rate: NonZeroU32,
+        tags: &[(&str, &str)],
+    ) -> Result<Self, Error> {
+        Self::with_block_size(sink, channels, sample_rate, tags, DEFAULT_FLAC_BLOCK_SIZE)
+    }
+
+    pub fn with_block_size(
+        mut sink: W,
+        channels: NonZeroU8,
+        sample_rate: NonZeroU32,
+        tags: &[(&str, &str)],
+        block_size: usize,
+    ) -> Result<Self, Error> {
+        if channels.get() > 8 {
+            return Err(Error::TooManyChannels {
+                count: channels.get() as usize,
+            });
+        }
+        if sample_rate.get() >= 1 << 20 {
+            return Err(Error::InvalidSampleRate(sample_rate.get()));
+        }
+        let block_size = block_size.clamp(16, u16::MAX as usize);
+
+        let start = sink.stream_position()?;
+        sink.write_all(b"fLaC")?;
+
+        let mut writer = Self {
+            sink,
+            channels: channels.get() as usize,
+            sample_rate: sample_rate.get(),
+            block_size,
+            pending: vec![Vec::with_capacity(block_size); channels.get() as usize],
+            frame_number: 0,
+            total_samples: 0,
+            min_frame_size: 0,
+            max_frame_size: 0,
+            streaminfo_offset: start + 4,
+        };
+
+        writer.write_streaminfo(false)?;
+        writer.write_vorbis_comment(tags)?;
+        writer.sink.flush()?;
+
+        Ok(writer)
+    }
+
+    pub fn channels(&self) -> usize {
+        self.channels
+    }
+
+    /// Appends interleaved samples. A trailing partial frame is ignored.
+    pub fn write_interleaved(&mut self, samples: &[f32]) -> Result<(), Error> {
+        for frame in samples.chunks_exact(self.channels) {
+            for (channel, &sample) in self.pending.iter_mut().zip(frame) {
+                channel.push(to_i16(sample));
+            }
+            if self.pending[0].len() == self.block_size {
+                self.write_frame()?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn flush(&mut self) -> Result<(), Error> {
+        self.sink.flush()?;
+        Ok(())
+    }
+
+    pub fn finish(mut self) -> Result<W, Error> {
+        if !self.pending[0].is_empty() {
+            self.write_frame()?;
+        }
+
+        let end = self.sink.stream_position()?;
+        self.sink.seek(SeekFrom::Start(self.streaminfo_offset))?;
+        self.write_streaminfo(true)?;
+        self.sink.seek(SeekFrom::Start(end))?;
+        self.sink.flush()?;
+
+        Ok(self.sink)
+    }
+
+    fn write_streaminfo(&mut self, complete: bool) -> Result<(), Error> {
+        let mut bits = BitWriter::default();
+        bits.write(0, 1);
+        bits.write(0, 7);
+        bits.write(STREAMINFO_LEN as u64, 24);
+
+        // The last block may be shorter, which FLAC allows without lowering the minimum.
+        bits.write(self.block_size as u64, 16);
+        bits.write(self.block_size as u64, 16);
+        let (min_frame, max_frame, total) = if complete {
+            (self.min_frame_size, self.max_frame_size, self.total_samples)
+        } else {
+            (0, 0, 0)
+        };
+        bits.write(min_frame as u64, 24);
+        bits.write(max_frame as u64, 24);
+        bits.write(self.sample_rate as u64, 20);
+        bits.write(self.channels as u64 - 1, 3);
+        bits.write(BITS_PER_SAMPLE as u64 - 1, 5);
+        bits.write(total.min((1 << 36) - 1), 36);
+        // MD5 of the unencoded audio; all zeros means "not computed".
+        for _ in 0..16 {
+            bits.write(0, 8);
+        }
+
+        self.sink.write_all(&bits.into_bytes())?;
+        Ok(())
+    }
+
+    fn write_vorbis_comment(&mut self, tags: &[(&str, &str)]) -> Result<(), Error> {
+        let mut body = Vec::new();
+        body.extend_from_slice(&(VENDOR.len() as u32).to_le_bytes());
+        body.extend_from_slice(VENDOR.as_bytes());
+        body.extend_from_slice(&(tags.len() as u32).to_le_bytes());
+        for (key, value) in tags {
+            let comment = format!("{}={}", key, value);
+            body.extend_from_slice(&(comment.len() as u32).to_le_bytes());
+            body.extend_from_slice(comment.as_bytes());
+        }
+
+        let mut header = BitWriter::default();
+        header.write(1, 1);
+        header.write(4, 7);
+        header.write(body.len() as u64, 24);
+
+        self.sink.write_all(&header.into_bytes())?;
+        self.sink.write_all(&body)?;
+        Ok(())
+    }
+
+    fn write_frame(&mut self) -> Result<(), Error> {
+        let block_len = self.pending[0].len();
+        let mut bits = BitWriter::default();
+
+        bits.write(0b11111111111110, 14);
+        bits.write(0, 1);
+        bits.write(0, 1);
+        bits.write(0b0111, 4);
+        bits.write(sample_rate_code(self.sample_rate), 4);
+        bits.write(self.channels as u64 - 1, 4);
+        bits.write(0b100, 3);
+        bits.write(0, 1);
+        write_utf8_number(&mut bits, self.frame_number);
+        bits.write(block_len as u64 - 1, 16);
+        let crc8 = crc8(bits.bytes());
+        bits.write(crc8 as u64, 8);
+
+        for channel in &self.pending {
+            write_subframe(&mut bits, channel);
+        }
+        bits.align();
+        let crc16 = crc16(bits.bytes());
+        bits.write(crc16 as u64, 16);
+
+        let frame = bits.into_bytes();
+        let frame_len = frame.len() as u32;
+        self.min_frame_size = if self.frame_number == 0 {
+            frame_len
+        } else {
+            self.min_frame_size.min(frame_len)
+        };
+        self.max_frame_size = self.max_frame_size.max(frame_len);
+
+        self.sink.write_all(&frame)?;
+        self.frame_number += 1;
+        self.total_samples += block_len as u64;
+        for channel in &mut self.pending {
+            channel.clear();
+        }
+        Ok(())
+    }
+}
+
+fn to_i16(sample: f32) -> i32 {
+    (sample * I16_SCALE).clamp(-I16_SCALE, I16_SCALE - 1.0) as i32
+}
+
+fn sample_rate_code(sample_rate: u32) -> u64 {
+    match sample_rate {
+        88_200 => 0b0001,
+        176_400 => 0b0010,
+        192_000 => 0b0011,
+        8_000 => 0b0100,
+        16_000 => 0b0101,
+        22_050 => 0b0110,
+        24_000 => 0b0111,
+        32_000 => 0b1000,
+        44_100 => 0b1001,
+        48_000 => 0b1010,
+        96_000 => 0b1011,
+        _ => 0b0000,
+    }
+}
+
+fn write_utf8_number(bits: &mut BitWriter, value: u64) {
+    if value < 0x80 {
+        bits.write(value, 8);
+        return;
+    }
+
+    let mut continuation = 1;
+    while value >= 1 << (5 * continuation + 6) {
+        continuation += 1;
+    }
+
+    let lead_bits = 6 - continuation;
+    let lead_marker = (0xFF00u64 >> (continuation + 1)) & 0xFF;
+    bits.write(
+        lead_marker | ((value >> (6 * continuation)) & ((1 << lead_bits) - 1)),
+        8,
+    );
+    for i in (0..continuation).rev() {
+        bits.write(0x80 | ((value >> (6 * i)) & 0x3F), 8);
+    }
+}
+
+fn write_subframe(bits: &mut BitWriter, samples: &[i32]) {
+    if samples.iter().all(|&s| s == samples[0]) {
+        bits.write(0, 1);
+        bits.write(0b000000, 6);
+        bits.write(0, 1);
+        bits.write_signed(samples[0], BITS_PER_SAMPLE);
+        return;
+    }
+
+    let verbatim_bits = samples.len() as u64 * BITS_PER_SAMPLE as u64;
+    let best = (0..=MAX_FIXED_ORDER.min(samples.len() - 1))
+        .map(|order| {
+            let residual = fixed_residual(samples, order);
+            let param = rice_param(&residual);
+            let cost = order as u64 * BITS_PER_SAMPLE as u64 + 6 + rice_cost(&residual, param);
+            (order, residual, param, cost)
+        })
+        .min_by_key(|(_, _, _, cost)| *cost);
+
+    match best {
+        Some((order, residual, param, cost)) if cost < verbatim_bits => {
+            bits.write(0, 1);
+            bits.write(0b001000 | order as u64, 6);
+            bits.write(0, 1);
+            for &warmup in &samples[..order] {
+                bits.write_signed(warmup, BITS_PER_SAMPLE);
+            }
+            // Rice coding with 4-bit parameters and a single partition.
+            bits.write(0b00, 2);
+            bits.write(0, 4);
+            bits.write(param as u64, 4);
+            for &r in &residual {
+                bits.write_rice(r, param);
+            }
+        }
+        _ => {
+            bits.write(0, 1);
+            bits.write(0b000001, 6);
+            bits.write(0, 1);
+            for &s in samples {
+                bits.write_signed(s, BITS_PER_SAMPLE);
+            }
+        }
+    }
+}
+
+fn fixed_residual(samples: &[i32], order: usize) -> Vec<i32> {
+    (order..samples.len())
+        .map(|i| {
+            let s = |k: usize| samples[i - k];
+            match order {
+                0 => s(0),
+                1 => s(0) - s(1),
+                2 => s(0) - 2 * s(1) + s(2),
+                3 => s(0) - 3 * s(1) + 3 * s(2) - s(3),
+                _ => s(0) - 4 * s(1) + 6 * s(2) - 4 * s(3) + s(4),
+            }
+        })
+        .collect()
+}
+
+fn zigzag(value: i32) -> u64 {
+    ((value << 1) ^ (value >> 31)) as u32 as u64
+}
+
+fn rice_param(residual: &[i32]) -> u32 {
+    if residual.is_empty() {
+        return 0;
+    }
+    let mean = residual.iter().map(|&r| zigzag(r)).sum::<u64>() / residual.len() as u64;
+    let mut param = 0;
+    while param < MAX_RICE_PARAM && (1u64 << (param + 1)) <= mean {
+        param += 1;
+    }
+    param
+}
+
+fn rice_cost(residual: &[i32], param: u32) -> u64 {
+    residual
+        .iter()
+        .map(|&r| (zigzag(r) >> param) + 1 + param as u64)
+        .sum()
+}
+
+#[derive(Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    acc: u64,
+    len: u32,
+}
+
+impl BitWriter {
+    fn write(&mut self, value: u64, bits: u32) {
+        for shift in (0..bits).rev() {
+            self.acc = (self.acc << 1) | ((value >> shift) & 1);
+            self.len += 1;
+            if self.len == 8 {
+                self.bytes.push(self.acc as u8);
+                self.acc = 0;
+                self.len = 0;
+            }
+        }
+    }
+
+    fn write_signed(&mut self, value: i32, bits: u32) {
+        self.write(value as u32 as u64 & ((1 << bits) - 1), bits);
+    }
+
+    fn write_rice(&mut self, value: i32, param: u32) {
+        let folded = zigzag(value);
+        for _ in 0..(folded >> param) {
+            self.write(0, 1);
+        }
+        self.write(1, 1);
+        self.write(folded, param);
+    }
+
+    fn align(&mut self) {
+        if self.len > 0 {
+            self.write(0, 8 - self.len);
+        }
+    }
+
+    /// Completed bytes so far; callers only use this on byte boundaries.
+    fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    fn into_bytes(mut self) -> Vec<u8> {
+        self.align();
+        self.bytes
+    }
+}
+
+fn crc8(data: &[u8]) -> u8 {
+    data.iter().fold(0u8, |mut crc, &byte| {
+        crc ^= byte;
+        for _ in 0..8 {
+            crc = if crc & 0x80 != 0 {
+                (crc << 1) ^ 0x07
+            } else {
+                crc << 1
+            };
+        }
+        crc
+    })
+}
+
+fn crc16(data: &[u8]) -> u16 {
+    data.iter().fold(0u16, |mut crc, &byte| {
+        crc ^= (byte as u16) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x8005
+            } else {
+                crc << 1
+            };
+        }
+        crc
+    })
+}
+
+/// Re-encodes whatever is decodable from an existing (possibly truncated)
+/// FLAC file into `writer`, so a restarted recording can continue it.
+pub fn copy_flac_into<W: Write + Seek>(
+    path: impl AsRef<std::path::Path>,
+    writer: &mut FlacWriter<W>,
+) -> Result<(), Error> {
+    let source = crate::source_from_path(path)?;
+    let channels = rodio::Source::channels(&source) as usize;
+    if channels != writer.channels() {
+        return Err(Error::UnsupportedChannelCount {
+            count: channels as u16,
+        });
+    }
+
+    let mut buffer = Vec::with_capacity(DEFAULT_FLAC_BLOCK_SIZE * channels);
+    for sample in source {
+        buffer.push(sample);
+        if buffer.len() == buffer.capacity() {
+            writer.write_interleaved(&buffer)?;
+            buffer.clear();
+        }
+    }
+    writer.write_interleaved(&buffer)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encode(channels: u8, samples: &[f32], block_size: usize) -> Vec<u8> {
+        let mut writer = FlacWriter::with_block_size(
+            std::io::Cursor::new(Vec::new()),
+            NonZeroU8::new(channels).unwrap(),
+            NonZeroU32::new(16_000).unwrap(),
+            &[("SESSION_ID", "abc")],
+            block_size,
+        )
+        .unwrap();
+        for chunk in samples.chunks(1000) {
+            writer.write_interleaved(chunk).unwrap();
+        }
+        writer.finish().unwrap().into_inner()
+    }
+
+    #[test]
+    fn roundtrip_is_lossless_for_16_bit_input() {
+        let samples: Vec<f32> = (0..40_000)
+            .map(|i| {
+                let t = i as f32 / 16_000.0;
+                let value = 0.4 * (t * 440.0 * std::f32::consts::TAU).sin()
+                    + if i % 2 == 0 { 0.0 } else { 0.1 };
+                (value * I16_SCALE).round() / I16_SCALE
+            })
+            .collect();
+
+        let bytes = encode(2, &samples, 1024);
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("audio.flac");
+        std::fs::write(&path, &bytes).unwrap();
+
+        let decoded: Vec<f32> = crate::source_from_path(&path).unwrap().collect();
+        assert_eq!(decoded, samples);
+    }
+
+    #[test]
+    fn truncated_stream_is_still_decodable() {
+        let samples: Vec<f32> = (0..20_000)
+            .map(|i| ((i % 200) as f32 - 100.0) / 256.0)
+            .collect();
+        let mut writer = FlacWriter::with_block_size(
+            std::io::Cursor::new(Vec::new()),
+            NonZeroU8::new(1).unwrap(),
+            NonZeroU32::new(16_000).unwrap(),
+            &[],
+            4096,
+        )
+        .unwrap();
+        writer.write_interleaved(&samples).unwrap();
+        writer.flush().unwrap();
+        let bytes = writer.sink.get_ref().clone();
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("audio.flac");
+        std::fs::write(&path, &bytes).unwrap();
+
+        let decoded: Vec<f32> = crate::source_from_path(&path).unwrap().collect();
+        assert_eq!(decoded.len(), 4 * 4096);
+        assert_eq!(decoded[..], samples[..decoded.len()]);
+    }
+
+    #[test]
+    fn utf8_frame_numbers() {
+        let encode = |value| {
+            let mut bits = BitWriter::default();
+            write_utf8_number(&mut bits, value);
+            bits.into_bytes()
+        };
+        assert_eq!(encode(0x7F), vec![0x7F]);
+        assert_eq!(encode(0x80), vec![0xC2, 0x80]);
+        assert_eq!(encode(0x800), vec![0xE0, 0xA0, 0x80]);
+    }
+}
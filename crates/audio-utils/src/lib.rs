@@ -5,11 +5,17 @@ use futures_util::{Stream, StreamExt};
 use hypr_audio_interface::AsyncSource;
 
 mod error;
+mod flac;
+mod media;
+mod opus;
 mod pcm;
 mod resampler;
 mod vorbis;
 
 pub use error::*;
+pub use flac::*;
+pub use media::*;
+pub use opus::*;
 pub use pcm::*;
 pub use resampler::*;
 pub use vorbis::*;
@@ -130,12 +136,37 @@ pub fn mix_audio_pcm16le(mic: &[u8], speaker: &[u8]) -> Vec<u8> {
     mixed
 }
 
+/// Opens an audio file for decoding.
+///
+/// rodio has no Opus decoder, so Ogg/Opus files (including `.opus` recordings)
+/// are decoded up front and served from memory.
 pub fn source_from_path(
     path: impl AsRef<std::path::Path>,
-) -> Result<rodio::Decoder<std::io::BufReader<std::fs::File>>, crate::Error> {
-    let file = std::fs::File::open(path.as_ref())?;
+) -> Result<Box<dyn Source + Send>, crate::Error> {
+    let path = path.as_ref();
+    if is_ogg_opus(path)? {
+        let (samples, metadata) = decode_opus_file(path)?;
+        return Ok(Box::new(rodio::buffer::SamplesBuffer::new(
+            metadata.channels as u16,
+            metadata.sample_rate,
+            samples,
+        )));
+    }
+
+    let file = std::fs::File::open(path)?;
     let decoder = rodio::Decoder::try_from(file)?;
-    Ok(decoder)
+    Ok(Box::new(decoder))
+}
+
+fn is_ogg_opus(path: &std::path::Path) -> Result<bool, crate::Error> {
+    use std::io::Read;
+
+    // The identification header is the first packet of the first page.
+    let mut head = [0u8; 64];
+    let mut file = std::fs::File::open(path)?;
+    let len = file.read(&mut head)?;
+    let head = &head[..len];
+    Ok(head.starts_with(b"OggS") && head.windows(8).any(|w| w == b"OpusHead"))
 }
 
 fn metadata_from_source<S>(source: &S) -> Result<AudioMetadata, crate::Error>
@@ -175,9 +206,7 @@ pub fn resample_audio<S>(source: S, to_rate: u32) -> Result<Vec<f32>, crate::Err
 where
     S: rodio::Source,
 {
-    use rubato::{
-        Resampler, SincFixedIn, SincInterpolationParameters, SincInterpolationType, WindowFunction,
-    };
+    use rubato::{Resampler, SincFixedIn};
 
     let from_rate = source.sample_rate() as f64;
     let channels = source.channels() as usize;
@@ -189,16 +218,13 @@ where
         return Ok(samples);
     }
 
-    let params = SincInterpolationParameters {
-        sinc_len: 256,
-        f_cutoff: 0.95,
-        interpolation: SincInterpolationType::Linear,
-        oversampling_factor: 256,
-        window: WindowFunction::BlackmanHarris2,
-    };
-
-    let mut resampler =
-        SincFixedIn::<f32>::new(to_rate_f64 / from_rate, 2.0, params, 1024, channels)?;
+    let mut resampler = SincFixedIn::<f32>::new(
+        to_rate_f64 / from_rate,
+        2.0,
+        sinc_parameters(),
+        1024,
+        channels,
+    )?;
 
     let frames_per_channel = samples.len() / channels;
     let mut input_channels: Vec<Vec<f32>> = vec![Vec::with_capacity(frames_per_channel); channels];
@@ -221,6 +247,18 @@ where
     Ok(output)
 }
 
+/// Interpolation settings for offline resampling, where quality matters more
+/// than latency.
+pub(crate) fn sinc_parameters() -> rubato::SincInterpolationParameters {
+    rubato::SincInterpolationParameters {
+        sinc_len: 256,
+        f_cutoff: 0.95,
+        interpolation: rubato::SincInterpolationType::Linear,
+        oversampling_factor: 256,
+        window: rubato::WindowFunction::BlackmanHarris2,
+    }
+}
+
 #[derive(Debug)]
 pub struct ChunkedAudio {
     pub chunks: Vec<Bytes>,
@@ -306,5 +344,6 @@ mod tests {
         test_audio_file_metadata_flac: hypr_data::english_1::AUDIO_FLAC_PATH,
         test_audio_file_metadata_aac: hypr_data::english_1::AUDIO_AAC_PATH,
         test_audio_file_metadata_aiff: hypr_data::english_1::AUDIO_AIFF_PATH,
+        test_audio_file_metadata_opus: hypr_data::english_1::AUDIO_OPUS_PATH,
     }
 }
@@ -0,0 +1,504 @@
+use std::fs::File;
+use std::io::ErrorKind;
+use std::path::Path;
+
+use rubato::SincFixedIn;
+use symphonia::core::audio::SampleBuffer;
+use symphonia::core::codecs::{CODEC_TYPE_NULL, CODEC_TYPE_OPUS, CodecParameters, DecoderOptions};
+use symphonia::core::errors::Error as SymphoniaError;
+use symphonia::core::formats::{FormatOptions, FormatReader, Packet, Track};
+use symphonia::core::io::MediaSourceStream;
+use symphonia::core::meta::MetadataOptions;
+use symphonia::core::probe::Hint;
+
+use crate::Error;
+use crate::resampler::RubatoChunkResampler;
+
+const RESAMPLE_BLOCK_SIZE: usize = 1024;
+/// Samples handed to the sink at a time by [`stream_media_mono`].
+pub const MEDIA_BLOCK_SIZE: usize = 4096;
+const OPUS_DECODE_RATE: u32 = 48_000;
+/// 120ms at 48kHz, the longest Opus packet.
+const OPUS_MAX_FRAMES: usize = 5760;
+
+#[derive(Debug, Clone)]
+pub struct MediaTrack {
+    pub id: u32,
+    pub codec: String,
+    pub language: Option<String>,
+    pub sample_rate: Option<u32>,
+    pub channels: Option<u16>,
+    pub duration_secs: Option<f64>,
+    pub is_default: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct MediaInfo {
+    pub tracks: Vec<MediaTrack>,
+    pub duration_secs: Option<f64>,
+}
+
+/// Which audio tracks of a multi-track file end up in the decoded signal.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum TrackSelection {
+    /// The container's default audio track.
+    #[default]
+    Default,
+    Track(u32),
+    /// Every decodable audio track, summed. Useful for recordings that put each
+    /// participant on a separate track.
+    MixAll,
+}
+
+/// Lists the audio tracks of an audio or video file without decoding it.
+pub fn probe_media(path: impl AsRef<Path>) -> Result<MediaInfo, Error> {
+    let format = open_format(path.as_ref())?;
+    let default_id = default_audio_track(format.as_ref()).map(|t| t.id);
+
+    let tracks: Vec<MediaTrack> = audio_tracks(format.as_ref())
+        .map(|track| MediaTrack {
+            id: track.id,
+            codec: codec_name(&track.codec_params),
+            language: track.language.clone(),
+            sample_rate: track.codec_params.sample_rate,
+            channels: track.codec_params.channels.map(|c| c.count() as u16),
+            duration_secs: track_duration_secs(&track.codec_params),
+            is_default: Some(track.id) == default_id,
+        })
+        .collect();
+
+    let duration_secs = tracks
+        .iter()
+        .filter_map(|t| t.duration_secs)
+        .fold(None, |acc: Option<f64>, d| {
+            Some(acc.map_or(d, |a| a.max(d)))
+        });
+
+    Ok(MediaInfo {
+        tracks,
+        duration_secs,
+    })
+}
+
+/// Decodes the selected audio tracks of `path` into memory. See
+/// [`stream_media_mono`] for long files.
+pub fn decode_media_mono(
+    path: impl AsRef<Path>,
+    selection: &TrackSelection,
+    target_rate: u32,
+) -> Result<Vec<f32>, Error> {
+    let mut samples = Vec::new();
+    stream_media_mono(path, selection, target_rate, |block| {
+        samples.extend_from_slice(block);
+        Ok(())
+    })?;
+    Ok(samples)
+}
+
+/// Decodes the selected audio tracks of `path`, downmixing each to mono and
+/// resampling to `target_rate` as packets arrive, and hands the mix to `sink`
+/// in blocks of [`MEDIA_BLOCK_SIZE`] samples (the last one may be shorter).
+pub fn stream_media_mono(
+    path: impl AsRef<Path>,
+    selection: &TrackSelection,
+    target_rate: u32,
+    mut sink: impl FnMut(&[f32]) -> Result<(), Error>,
+) -> Result<(), Error> {
+    if target_rate == 0 {
+        return Err(Error::InvalidSampleRate(target_rate));
+    }
+
+    let mut format = open_format(path.as_ref())?;
+
+    let selected: Vec<Track> = match selection {
+        TrackSelection::Default => default_audio_track(format.as_ref())
+            .cloned()
+            .into_iter()
+            .collect(),
+        TrackSelection::Track(id) => vec![
+            audio_tracks(format.as_ref())
+                .find(|t| t.id == *id)
+                .cloned()
+                .ok_or(Error::TrackNotFound(*id))?,
+        ],
+        TrackSelection::MixAll => audio_tracks(format.as_ref()).cloned().collect(),
+    };
+    if selected.is_empty() {
+        return Err(Error::NoAudioTrack);
+    }
+
+    let mut decoders = selected
+        .iter()
+        .map(|track| TrackDecoder::new(track, target_rate))
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut block = Vec::with_capacity(MEDIA_BLOCK_SIZE);
+
+    loop {
+        let packet = match format.next_packet() {
+            Ok(packet) => packet,
+            Err(SymphoniaError::IoError(e)) if e.kind() == ErrorKind::UnexpectedEof => break,
+            Err(SymphoniaError::ResetRequired) => break,
+            Err(e) => return Err(e.into()),
+        };
+
+        let Some(decoder) = decoders
+            .iter_mut()
+            .find(|d| d.track_id == packet.track_id())
+        else {
+            continue;
+        };
+
+        match decoder.decode(&packet) {
+            Ok(()) => {}
+            Err(Error::Symphonia(SymphoniaError::DecodeError(error))) => {
+                tracing::warn!(track_id = decoder.track_id, error, "media_packet_skipped");
+            }
+            Err(e) => return Err(e),
+        }
+
+        drain_mixed(&mut decoders, false, &mut block, &mut sink)?;
+    }
+
+    for decoder in &mut decoders {
+        decoder.flush_resampler()?;
+    }
+    drain_mixed(&mut decoders, true, &mut block, &mut sink)
+}
+
+/// Mixes the samples every track has decoded so far into full blocks. A track
+/// that ends early holds the others back until `finished`, when the rest is
+/// mixed against silence.
+fn drain_mixed(
+    decoders: &mut [TrackDecoder],
+    finished: bool,
+    block: &mut Vec<f32>,
+    sink: &mut impl FnMut(&[f32]) -> Result<(), Error>,
+) -> Result<(), Error> {
+    loop {
+        let pending = decoders.iter().map(|d| d.pending.len());
+        let ready = if finished {
+            pending.max().unwrap_or(0)
+        } else {
+            pending.min().unwrap_or(0)
+        };
+        let len = ready.min(MEDIA_BLOCK_SIZE);
+        if len == 0 || (!finished && len < MEDIA_BLOCK_SIZE) {
+            return Ok(());
+        }
+
+        block.clear();
+        block.resize(len, 0.0);
+        for (index, decoder) in decoders.iter_mut().enumerate() {
+            let take = decoder.pending.len().min(len);
+            let samples = decoder.pending.drain(..take);
+            if index == 0 {
+                block[..take].copy_from_slice(samples.as_slice());
+            } else {
+                for (out, sample) in block.iter_mut().zip(samples) {
+                    *out = crate::mix_sample_f32(*out, sample);
+                }
+            }
+        }
+        sink(block)?;
+    }
+}
+
+fn open_format(path: &Path) -> Result<Box<dyn FormatReader>, Error> {
+    let file = File::open(path)?;
+    let stream = MediaSourceStream::new(Box::new(file), Default::default());
+
+    let mut hint = Hint::new();
+    if let Some(ext) = path.extension().and_then(|e| e.to_str()) {
+        hint.with_extension(ext);
+    }
+
+    let probed = symphonia::default::get_probe().format(
+        &hint,
+        stream,
+        &FormatOptions::default(),
+        &MetadataOptions::default(),
+    )?;
+    Ok(probed.format)
+}
+
+fn audio_tracks(format: &dyn FormatReader) -> impl Iterator<Item = &Track> {
+    // Video tracks come through with a null codec.
+    format
+        .tracks()
+        .iter()
+        .filter(|t| t.codec_params.codec != CODEC_TYPE_NULL)
+}
+
+fn default_audio_track(format: &dyn FormatReader) -> Option<&Track> {
+    format
+        .default_track()
+        .filter(|t| t.codec_params.codec != CODEC_TYPE_NULL)
+        .or_else(|| audio_tracks(format).next())
+}
+
+fn codec_name(params: &CodecParameters) -> String {
+    if params.codec == CODEC_TYPE_OPUS {
+        return "opus".to_string();
+    }
+
+    symphonia::default::get_codecs()
+        .get_codec(params.codec)
+        .map(|d| d.short_name.to_string())
+        .unwrap_or_else(|| params.codec.to_string())
+}
+
+fn track_duration_secs(params: &CodecParameters) -> Option<f64> {
+    let frames = params.n_frames?;
+    if let Some(tb) = params.time_base {
+        let time = tb.calc_time(frames);
+        return Some(time.seconds as f64 + time.frac);
+    }
+    params.sample_rate.map(|rate| frames as f64 / rate as f64)
+}
+
+enum Codec {
+    Symphonia(Box<dyn symphonia::core::codecs::Decoder>),
+    // Symphonia demuxes Opus (WebM/MKV, Ogg) but has no decoder for it.
+    Opus {
+        decoder: audiopus::coder::Decoder,
+        channels: usize,
+        pre_skip: usize,
+        output: Vec<f32>,
+    },
+}
+
+struct TrackDecoder {
+    track_id: u32,
+    codec: Codec,
+    target_rate: u32,
+    resampler: Option<RubatoChunkResampler<SincFixedIn<f32>, 1>>,
+    source_rate: Option<u32>,
+    /// Resampled samples not yet mixed.
+    pending: Vec<f32>,
+}
+
+impl TrackDecoder {
+    fn new(track: &Track, target_rate: u32) -> Result<Self, Error> {
+        let params = &track.codec_params;
+
+        let codec = if params.codec == CODEC_TYPE_OPUS {
+            let head = params.extra_data.as_deref().unwrap_or_default();
+            if head.len() < 19 || !head.starts_with(b"OpusHead") {
+                return Err(Error::UnsupportedCodec("opus".to_string()));
+            }
+            let channels = head[9] as usize;
+            let opus_channels = match channels {
+                1 => audiopus::Channels::Mono,
+                2 => audiopus::Channels::Stereo,
+                count => {
+                    return Err(Error::UnsupportedChannelCount {
+                        count: count as u16,
+                    });
+                }
+            };
+
+            Codec::Opus {
+                decoder: audiopus::coder::Decoder::new(
+                    audiopus::SampleRate::Hz48000,
+                    opus_channels,
+                )?,
+                channels,
+                pre_skip: u16::from_le_bytes([head[10], head[11]]) as usize,
+                output: vec![0.0; OPUS_MAX_FRAMES * channels],
+            }
+        } else {
+            let decoder = symphonia::default::get_codecs()
+                .make(params, &DecoderOptions::default())
+                .map_err(|_| Error::UnsupportedCodec(codec_name(params)))?;
+            Codec::Symphonia(decoder)
+        };
+
+        Ok(Self {
+            track_id: track.id,
+            codec,
+            target_rate,
+            resampler: None,
+            source_rate: None,
+            pending: Vec::new(),
+        })
+    }
+
+    fn decode(&mut self, packet: &Packet) -> Result<(), Error> {
+        let (mono, rate) = match &mut self.codec {
+            Codec::Symphonia(decoder) => {
+                let decoded = decoder.decode(packet)?;
+                let spec = *decoded.spec();
+                if decoded.frames() == 0 {
+                    return Ok(());
+                }
+
+                let mut buffer = SampleBuffer::<f32>::new(decoded.capacity() as u64, spec);
+                buffer.copy_interleaved_ref(decoded);
+                (downmix(buffer.samples(), spec.channels.count()), spec.rate)
+            }
+            Codec::Opus {
+                decoder,
+                channels,
+                pre_skip,
+                output,
+            } => {
+                let frames = decoder.decode_float(
+                    Some(audiopus::packet::Packet::try_from(packet.buf())?),
+                    audiopus::MutSignals::try_from(&mut output[..])?,
+                    false,
+                )?;
+
+                let skipped = (*pre_skip).min(frames);
+                *pre_skip -= skipped;
+                let decoded = &output[skipped * *channels..frames * *channels];
+                (downmix(decoded, *channels), OPUS_DECODE_RATE)
+            }
+        };
+
+        self.push(&mono, rate)
+    }
+
+    fn push(&mut self, mono: &[f32], rate: u32) -> Result<(), Error> {
+        if self.source_rate != Some(rate) {
+            self.flush_resampler()?;
+            self.source_rate = Some(rate);
+            self.resampler = if rate == self.target_rate {
+                None
+            } else {
+                // Same filter as `resample_audio`, which imports used before
+                // decoding moved here.
+                let resampler = SincFixedIn::<f32>::new(
+                    self.target_rate as f64 / rate as f64,
+                    1.0,
+                    crate::sinc_parameters(),
+                    RESAMPLE_BLOCK_SIZE,
+                    1,
+                )?;
+                Some(RubatoChunkResampler::new(
+                    resampler,
+                    RESAMPLE_BLOCK_SIZE,
+                    RESAMPLE_BLOCK_SIZE,
+                ))
+            };
+        }
+
+        match self.resampler.as_mut() {
+            None => self.pending.extend_from_slice(mono),
+            Some(resampler) => {
+                for &sample in mono {
+                    resampler.push_sample(sample);
+                }
+                resampler.process_all_ready_blocks()?;
+                if let Some(out) = resampler.take_all_output() {
+                    self.pending.extend(out);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn flush_resampler(&mut self) -> Result<(), Error> {
+        if let Some(resampler) = self.resampler.as_mut() {
+            resampler.process_all_ready_blocks()?;
+            resampler.process_partial_block(true)?;
+            if let Some(out) = resampler.take_all_output() {
+                self.pending.extend(out);
+            }
+        }
+        Ok(())
+    }
+}
+
+fn downmix(samples: &[f32], channels: usize) -> Vec<f32> {
+    if channels <= 1 {
+        return samples.to_vec();
+    }
+    samples
+        .chunks(channels)
+        .map(|frame| frame.iter().sum::<f32>() / frame.len() as f32)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn probe_lists_audio_tracks_of_video() {
+        let info = probe_media(hypr_data::english_1::AUDIO_MP4_PATH).unwrap();
+        assert_eq!(info.tracks.len(), 1);
+        assert!(info.tracks[0].is_default);
+        assert_eq!(info.tracks[0].codec, "aac");
+        assert!(info.duration_secs.unwrap() > 1.0);
+    }
+
+    #[test]
+    fn decodes_to_target_rate() {
+        let info = probe_media(hypr_data::english_1::AUDIO_MP3_PATH).unwrap();
+        let samples = decode_media_mono(
+            hypr_data::english_1::AUDIO_MP3_PATH,
+            &TrackSelection::Default,
+            16_000,
+        )
+        .unwrap();
+
+        let expected = info.duration_secs.unwrap() * 16_000.0;
+        let actual = samples.len() as f64;
+        assert!(
+            (actual - expected).abs() / expected < 0.02,
+            "{actual} vs {expected}"
+        );
+    }
+
+    #[test]
+    fn decodes_opus_from_webm() {
+        let info = probe_media(hypr_data::english_1::AUDIO_WEBM_PATH).unwrap();
+        assert_eq!(info.tracks[0].codec, "opus");
+
+        let samples = decode_media_mono(
+            hypr_data::english_1::AUDIO_WEBM_PATH,
+            &TrackSelection::MixAll,
+            16_000,
+        )
+        .unwrap();
+        let expected = info.duration_secs.unwrap() * 16_000.0;
+        assert!((samples.len() as f64 - expected).abs() / expected < 0.02);
+    }
+
+    #[test]
+    fn streams_fixed_size_blocks() {
+        let mut blocks = vec![];
+        stream_media_mono(
+            hypr_data::english_1::AUDIO_MP3_PATH,
+            &TrackSelection::Default,
+            16_000,
+            |block| {
+                blocks.push(block.to_vec());
+                Ok(())
+            },
+        )
+        .unwrap();
+
+        let (last, full) = blocks.split_last().unwrap();
+        assert!(full.iter().all(|b| b.len() == MEDIA_BLOCK_SIZE));
+        assert!(!last.is_empty() && last.len() <= MEDIA_BLOCK_SIZE);
+
+        let decoded = decode_media_mono(
+            hypr_data::english_1::AUDIO_MP3_PATH,
+            &TrackSelection::Default,
+            16_000,
+        )
+        .unwrap();
+        assert_eq!(blocks.concat(), decoded);
+    }
+
+    #[test]
+    fn unknown_track_is_rejected() {
+        let result = decode_media_mono(
+            hypr_data::english_1::AUDIO_M4A_PATH,
+            &TrackSelection::Track(99),
+            16_000,
+        );
+        assert!(matches!(result, Err(Error::TrackNotFound(99))));
+    }
+}
@@ -0,0 +1,403 @@
+use std::io::{BufReader, Write};
+use std::path::Path;
+
+use audiopus::coder::{Decoder, Encoder};
+use audiopus::{Application, Bitrate, Channels, MutSignals, SampleRate, packet::Packet};
+use ogg::{PacketReader, PacketWriteEndInfo, PacketWriter};
+
+use crate::{AudioMetadata, Error};
+
+pub const DEFAULT_OPUS_BITRATE: i32 = 32_000;
+
+const OPUS_GRANULE_RATE: u64 = 48_000;
+const FRAME_MS: usize = 20;
+const MAX_PACKET_SIZE: usize = 4000;
+/// Ogg pages are closed this often, bounding what a crash can lose.
+const PACKETS_PER_PAGE: usize = 1000 / FRAME_MS;
+const VENDOR: &str = "hyprnote";
+
+#[derive(Clone, Copy, Debug)]
+pub struct OpusEncodeSettings {
+    pub bitrate: i32,
+}
+
+impl Default for OpusEncodeSettings {
+    fn default() -> Self {
+        Self {
+            bitrate: DEFAULT_OPUS_BITRATE,
+        }
+    }
+}
+
+/// Streaming Ogg/Opus encoder (RFC 7845, mapping family 0).
+///
+/// Every page is closed after one second of audio, so readers of a file that
+/// was never finished still see everything up to the last full second.
+pub struct OggOpusWriter<W: Write> {
+    writer: PacketWriter<W>,
+    encoder: Encoder,
+    serial: u32,
+    channels: usize,
+    sample_rate: u32,
+    frame_len: usize,
+    granule_factor: u64,
+    pre_skip: u64,
+    pending: Vec<f32>,
+    /// Samples per channel written so far, at the input rate.
+    written: u64,
+    packets_in_page: usize,
+    packet: Vec<u8>,
+}
+
+impl<W: Write> OggOpusWriter<W> {
+    pub fn new(
+        sink: W,
+        channels: u8,
+        sample_rate: u32,
+        tags: &[(&str, &str)],
+        settings: OpusEncodeSettings,
+    ) -> Result<Self, Error> {
+        let opus_channels = match channels {
+            1 => Channels::Mono,
+            2 => Channels::Stereo,
+            count => {
+                return Err(Error::UnsupportedChannelCount {
+                    count: count as u16,
+                });
+            }
+        };
+        let opus_rate = opus_sample_rate(sample_rate)?;
+
+        let mut encoder = Encoder::new(opus_rate, opus_channels, Application::Voip)?;
+        encoder.set_bitrate(Bitrate::BitsPerSecond(settings.bitrate))?;
+
+        let granule_factor = OPUS_GRANULE_RATE / sample_rate as u64;
+        let pre_skip = encoder.lookahead()? as u64 * granule_factor;
+
+        let mut writer = Self {
+            writer: PacketWriter::new(sink),
+            encoder,
+            serial: stream_serial(),
+            channels: channels as usize,
+            sample_rate,
+            frame_len: sample_rate as usize * FRAME_MS / 1000,
+            granule_factor,
+            pre_skip,
+            pending: Vec::new(),
+            written: 0,
+            packets_in_page: 0,
+            packet: vec![0; MAX_PACKET_SIZE],
+        };
+
+        writer.write_headers(sample_rate, tags)?;
+        Ok(writer)
+    }
+
+    pub fn channels(&self) -> usize {
+        self.channels
+    }
+
+    fn write_headers(&mut self, sample_rate: u32, tags: &[(&str, &str)]) -> Result<(), Error> {
+        let mut head = Vec::with_capacity(19);
+        head.extend_from_slice(b"OpusHead");
+        head.push(1);
+        head.push(self.channels as u8);
+        head.extend_from_slice(&(self.pre_skip.min(u16::MAX as u64) as u16).to_le_bytes());
+        head.extend_from_slice(&sample_rate.to_le_bytes());
+        head.extend_from_slice(&0i16.to_le_bytes());
+        head.push(0);
+
+        let mut comments = Vec::new();
+        comments.extend_from_slice(b"OpusTags");
+        comments.extend_from_slice(&(VENDOR.len() as u32).to_le_bytes());
+        comments.extend_from_slice(VENDOR.as_bytes());
+        comments.extend_from_slice(&(tags.len() as u32).to_le_bytes());
+        for (key, value) in tags {
+            let comment = format!("{}={}", key, value);
+            comments.extend_from_slice(&(comment.len() as u32).to_le_bytes());
+            comments.extend_from_slice(comment.as_bytes());
+        }
+
+        self.writer
+            .write_packet(head.into(), self.serial, PacketWriteEndInfo::EndPage, 0)?;
+        self.writer
+            .write_packet(comments.into(), self.serial, PacketWriteEndInfo::EndPage, 0)?;
+        self.writer.inner_mut().flush()?;
+        Ok(())
+    }
+
+    /// Appends interleaved samples, encoding every complete 20ms frame.
+    pub fn write_interleaved(&mut self, samples: &[f32]) -> Result<(), Error> {
+        self.pending.extend_from_slice(samples);
+
+        let frame_samples = self.frame_len * self.channels;
+        while self.pending.len() >= frame_samples {
+            let frame: Vec<f32> = self.pending.drain(..frame_samples).collect();
+            self.encode_frame(&frame)?;
+        }
+        Ok(())
+    }
+
+    pub fn flush(&mut self) -> Result<(), Error> {
+        self.writer.inner_mut().flush()?;
+        Ok(())
+    }
+
+    /// Encodes the remaining samples and marks the end of the stream.
+    pub fn finish(mut self) -> Result<W, Error> {
+        let pending = self.pending.len() / self.channels;
+        let mut frame = std::mem::take(&mut self.pending);
+        frame.resize(self.frame_len * self.channels, 0.0);
+
+        // The final granule position trims the zero padding on decode.
+        let total = self.pre_skip + (self.written + pending as u64) * self.granule_factor;
+        let len = self.encoder.encode_float(&frame, &mut self.packet)?;
+        self.writer.write_packet(
+            self.packet[..len].to_vec().into(),
+            self.serial,
+            PacketWriteEndInfo::EndStream,
+            total,
+        )?;
+        self.writer.inner_mut().flush()?;
+
+        Ok(self.writer.into_inner())
+    }
+
+    fn encode_frame(&mut self, frame: &[f32]) -> Result<(), Error> {
+        let len = self.encoder.encode_float(frame, &mut self.packet)?;
+        let packet = self.packet[..len].to_vec();
+        self.write_audio_packet(packet, self.frame_len)
+    }
+
+    fn write_audio_packet(&mut self, packet: Vec<u8>, frames: usize) -> Result<(), Error> {
+        self.written += frames as u64;
+        self.packets_in_page += 1;
+
+        let info = if self.packets_in_page >= PACKETS_PER_PAGE {
+            self.packets_in_page = 0;
+            PacketWriteEndInfo::EndPage
+        } else {
+            PacketWriteEndInfo::NormalPacket
+        };
+
+        let granule = self.pre_skip + self.written * self.granule_factor;
+        self.writer
+            .write_packet(packet.into(), self.serial, info, granule)?;
+        Ok(())
+    }
+}
+
+fn opus_sample_rate(sample_rate: u32) -> Result<SampleRate, Error> {
+    match sample_rate {
+        8_000 => Ok(SampleRate::Hz8000),
+        12_000 => Ok(SampleRate::Hz12000),
+        16_000 => Ok(SampleRate::Hz16000),
+        24_000 => Ok(SampleRate::Hz24000),
+        48_000 => Ok(SampleRate::Hz48000),
+        other => Err(Error::InvalidSampleRate(other)),
+    }
+}
+
+fn stream_serial() -> u32 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.subsec_nanos() ^ d.as_secs() as u32)
+        .unwrap_or(0x6879_7072)
+}
+
+/// Copies the audio packets of an existing (possibly truncated) Ogg/Opus file
+/// into `writer` as-is, so a restarted recording continues it without another
+/// generation of lossy encoding.
+///
+/// The file must have the writer's channel count and input rate, which holds
+/// for anything a previous [`OggOpusWriter`] session produced. A finished
+/// file's last packet keeps its zero padding, so the seam gets at most one
+/// frame of silence.
+pub fn copy_opus_into<W: Write>(
+    path: impl AsRef<Path>,
+    writer: &mut OggOpusWriter<W>,
+) -> Result<(), Error> {
+    let file = std::fs::File::open(path)?;
+    let mut reader = PacketReader::new(BufReader::new(file));
+
+    let Some(head) = read_opus_head(&mut reader)? else {
+        return Ok(());
+    };
+    if head.channels != writer.channels {
+        return Err(Error::UnsupportedChannelCount {
+            count: head.channels as u16,
+        });
+    }
+    if head.input_rate != writer.sample_rate {
+        return Err(Error::InvalidSampleRate(head.input_rate));
+    }
+    let opus_rate = opus_sample_rate(writer.sample_rate)?;
+
+    while let Some(packet) = read_audio_packet(&mut reader) {
+        let frames = audiopus::packet::nb_samples(Packet::try_from(&packet.data[..])?, opus_rate)?;
+        let last = packet.last_in_stream();
+        writer.write_audio_packet(packet.data, frames)?;
+
+        if last {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+/// Decodes an Ogg/Opus file to interleaved samples at 48kHz.
+pub fn decode_opus_file(path: impl AsRef<Path>) -> Result<(Vec<f32>, AudioMetadata), Error> {
+    let file = std::fs::File::open(path)?;
+    let mut reader = PacketReader::new(BufReader::new(file));
+
+    let head = read_opus_head(&mut reader)?.ok_or(Error::NoAudioTrack)?;
+    let opus_channels = match head.channels {
+        1 => Channels::Mono,
+        2 => Channels::Stereo,
+        count => {
+            return Err(Error::UnsupportedChannelCount {
+                count: count as u16,
+            });
+        }
+    };
+    let mut decoder = Decoder::new(SampleRate::Hz48000, opus_channels)?;
+    // 120ms is the longest Opus packet.
+    let mut output = vec![0.0f32; OPUS_GRANULE_RATE as usize * 120 / 1000 * head.channels];
+
+    let mut samples = Vec::new();
+    let mut end_granule = None;
+    while let Some(packet) = read_audio_packet(&mut reader) {
+        let frames = decoder.decode_float(
+            Some(Packet::try_from(&packet.data[..])?),
+            MutSignals::try_from(&mut output[..])?,
+            false,
+        )?;
+        samples.extend_from_slice(&output[..frames * head.channels]);
+
+        if packet.last_in_stream() {
+            end_granule = Some(packet.absgp_page());
+            break;
+        }
+    }
+
+    // Granule positions count 48kHz frames including the pre-skip, so the
+    // final one also trims the encoder's zero padding.
+    let mut frames = samples.len() / head.channels;
+    if let Some(end) = end_granule {
+        frames = frames.min(end as usize);
+    }
+    let skip = head.pre_skip.min(frames);
+    let samples = samples[skip * head.channels..frames * head.channels].to_vec();
+
+    Ok((
+        samples,
+        AudioMetadata {
+            sample_rate: OPUS_GRANULE_RATE as u32,
+            channels: head.channels as u8,
+        },
+    ))
+}
+
+struct OpusHead {
+    channels: usize,
+    pre_skip: usize,
+    input_rate: u32,
+}
+
+/// Reads the identification and comment headers.
+fn read_opus_head<R: std::io::Read + std::io::Seek>(
+    reader: &mut PacketReader<R>,
+) -> Result<Option<OpusHead>, Error> {
+    let head = match reader.read_packet()? {
+        Some(packet) if packet.data.starts_with(b"OpusHead") && packet.data.len() >= 19 => packet,
+        _ => return Ok(None),
+    };
+
+    // OpusTags
+    if reader.read_packet()?.is_none() {
+        return Ok(None);
+    }
+
+    let data = &head.data;
+    Ok(Some(OpusHead {
+        channels: data[9] as usize,
+        pre_skip: u16::from_le_bytes([data[10], data[11]]) as usize,
+        input_rate: u32::from_le_bytes([data[12], data[13], data[14], data[15]]),
+    }))
+}
+
+/// Returns the next packet, stopping quietly at a page cut off by a crash.
+fn read_audio_packet<R: std::io::Read + std::io::Seek>(
+    reader: &mut PacketReader<R>,
+) -> Option<ogg::Packet> {
+    match reader.read_packet() {
+        Ok(packet) => packet,
+        Err(error) => {
+            tracing::warn!(?error, "opus_read_stopped_at_truncated_page");
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn writes_ogg_opus_stream() {
+        let samples: Vec<f32> = (0..16_000 * 3)
+            .map(|i| 0.3 * (i as f32 * 440.0 / 16_000.0 * std::f32::consts::TAU).sin())
+            .collect();
+
+        let mut writer = OggOpusWriter::new(
+            std::io::Cursor::new(Vec::new()),
+            1,
+            16_000,
+            &[("SESSION_ID", "abc")],
+            OpusEncodeSettings::default(),
+        )
+        .unwrap();
+        for chunk in samples.chunks(1920) {
+            writer.write_interleaved(chunk).unwrap();
+        }
+        let bytes = writer.finish().unwrap().into_inner();
+
+        assert!(bytes.starts_with(b"OggS"));
+        assert!(bytes.windows(8).any(|w| w == b"OpusHead"));
+        assert!(bytes.windows(14).any(|w| w == b"SESSION_ID=abc"));
+        // 3s of 16kHz f32 would be 192kB as WAV.
+        assert!(bytes.len() < 20_000, "{}", bytes.len());
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("audio.opus");
+        std::fs::write(&path, &bytes).unwrap();
+
+        let mut copy = OggOpusWriter::new(
+            std::io::Cursor::new(Vec::new()),
+            1,
+            16_000,
+            &[],
+            OpusEncodeSettings::default(),
+        )
+        .unwrap();
+        copy_opus_into(&path, &mut copy).unwrap();
+        // Three seconds of frames plus the padded final packet.
+        assert_eq!(copy.written, (3 * 1000 / FRAME_MS as u64 + 1) * 320);
+
+        let copied = copy.finish().unwrap().into_inner();
+        let packets = |bytes: &[u8]| {
+            let mut reader = PacketReader::new(std::io::Cursor::new(bytes.to_vec()));
+            std::iter::from_fn(move || reader.read_packet().unwrap())
+                .skip(2)
+                .map(|p| p.data)
+                .collect::<Vec<_>>()
+        };
+        let original = packets(&bytes);
+        assert_eq!(packets(&copied)[..original.len()], original[..]);
+
+        let (decoded, metadata) = decode_opus_file(&path).unwrap();
+        assert_eq!(metadata.sample_rate, 48_000);
+        assert_eq!(decoded.len(), 3 * 48_000);
+    }
+}
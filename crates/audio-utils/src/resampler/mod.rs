@@ -3,6 +3,7 @@ mod dynamic_new;
 mod dynamic_old;
 mod static_new;
 
+pub(crate) use driver::RubatoChunkResampler;
 pub use dynamic_new::*;
 pub use dynamic_old::*;
 pub use static_new::*;
@@ -1,10 +1,12 @@
 use std::fs::File;
-use std::io::BufReader;
+use std::io::{BufReader, Write};
 use std::num::{NonZeroU8, NonZeroU32};
 use std::path::Path;
 
 use hound::{SampleFormat, WavReader, WavSpec, WavWriter};
-use vorbis_rs::{VorbisBitrateManagementStrategy, VorbisDecoder, VorbisEncoderBuilder};
+use vorbis_rs::{
+    VorbisBitrateManagementStrategy, VorbisDecoder, VorbisEncoder, VorbisEncoderBuilder,
+};
 
 use crate::Error;
 
@@ -108,6 +110,43 @@ pub fn encode_vorbis_mono(
     encode_vorbis_from_channels(&[samples], sample_rate, settings)
 }
 
+/// Encodes mono audio to Ogg Vorbis as it is written, for input too long to
+/// collect first.
+pub struct VorbisMonoWriter<W: Write> {
+    encoder: VorbisEncoder<W>,
+    block_size: usize,
+}
+
+impl<W: Write> VorbisMonoWriter<W> {
+    pub fn new(
+        sink: W,
+        sample_rate: NonZeroU32,
+        settings: VorbisEncodeSettings,
+    ) -> Result<Self, Error> {
+        let encoder = VorbisEncoderBuilder::new(sample_rate, NonZeroU8::MIN, sink)?
+            .bitrate_management_strategy(VorbisBitrateManagementStrategy::QualityVbr {
+                target_quality: settings.quality,
+            })
+            .build()?;
+
+        Ok(Self {
+            encoder,
+            block_size: settings.block_size.max(1),
+        })
+    }
+
+    pub fn write(&mut self, samples: &[f32]) -> Result<(), Error> {
+        for block in samples.chunks(self.block_size) {
+            self.encoder.encode_audio_block([block])?;
+        }
+        Ok(())
+    }
+
+    pub fn finish(self) -> Result<W, Error> {
+        Ok(self.encoder.finish()?)
+    }
+}
+
 #[derive(Clone, Copy)]
 enum DecodeMode {
     Source,
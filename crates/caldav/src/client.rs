@@ -0,0 +1,388 @@
+use chrono::{DateTime, Utc};
+use reqwest::{Method, StatusCode, header};
+use url::Url;
+
+use crate::{Error, Result};
+
+const DAV: &str = "DAV:";
+const CALDAV: &str = "urn:ietf:params:xml:ns:caldav";
+
+const DISCOVERY_PROPS: &str = "<d:resourcetype/><d:displayname/><d:sync-token/><d:current-user-principal/><c:calendar-home-set/><c:supported-calendar-component-set/>";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Collection {
+    pub url: Url,
+    pub name: Option<String>,
+    /// `None` when the server doesn't support WebDAV sync.
+    pub sync_token: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Resource {
+    pub href: String,
+    pub etag: Option<String>,
+    pub data: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Changes {
+    pub changed: Vec<String>,
+    pub deleted: Vec<String>,
+    pub sync_token: Option<String>,
+}
+
+#[derive(Clone)]
+pub struct CalDavClient {
+    http: reqwest::Client,
+    base: Url,
+    credentials: Option<(String, String)>,
+}
+
+impl CalDavClient {
+    pub fn new(base: Url) -> Self {
+        Self {
+            http: reqwest::Client::new(),
+            base,
+            credentials: None,
+        }
+    }
+
+    pub fn with_basic_auth(
+        mut self,
+        username: impl Into<String>,
+        password: impl Into<String>,
+    ) -> Self {
+        self.credentials = Some((username.into(), password.into()));
+        self
+    }
+
+    pub fn base(&self) -> &Url {
+        &self.base
+    }
+
+    /// Event calendars reachable from the base URL, which may be a server root,
+    /// a principal, a calendar home or a single calendar.
+    pub async fn discover(&self) -> Result<Vec<Collection>> {
+        let root = self
+            .propfind(&self.base, 0)
+            .await?
+            .entries
+            .into_iter()
+            .next();
+        let Some(root) = root else {
+            return Ok(vec![]);
+        };
+        if root.is_calendar {
+            return Ok(root.into_collection(&self.base).into_iter().collect());
+        }
+
+        let home = match root.calendar_home_set {
+            Some(home) => self.base.join(&home)?,
+            None => {
+                let principal = match root.current_user_principal {
+                    Some(principal) => self.base.join(&principal)?,
+                    None => self.base.clone(),
+                };
+                let home = self
+                    .propfind(&principal, 0)
+                    .await?
+                    .entries
+                    .into_iter()
+                    .find_map(|e| e.calendar_home_set);
+                match home {
+                    Some(home) => self.base.join(&home)?,
+                    None => principal,
+                }
+            }
+        };
+
+        Ok(self
+            .propfind(&home, 1)
+            .await?
+            .entries
+            .into_iter()
+            .filter_map(|e| e.into_collection(&self.base))
+            .collect())
+    }
+
+    /// Event resources with at least one instance in `[from, to)`.
+    pub async fn query(
+        &self,
+        collection: &Url,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<Resource>> {
+        let body = format!(
+            r#"<?xml version="1.0" encoding="utf-8"?>
+<c:calendar-query xmlns:d="DAV:" xmlns:c="{CALDAV}">
+  <d:prop><d:getetag/><c:calendar-data/></d:prop>
+  <c:filter>
+    <c:comp-filter name="VCALENDAR">
+      <c:comp-filter name="VEVENT">
+        <c:time-range start="{}" end="{}"/>
+      </c:comp-filter>
+    </c:comp-filter>
+  </c:filter>
+</c:calendar-query>"#,
+            from.format("%Y%m%dT%H%M%SZ"),
+            to.format("%Y%m%dT%H%M%SZ"),
+        );
+
+        let multistatus = self.request("REPORT", collection, 1, body).await?;
+        Ok(multistatus
+            .entries
+            .into_iter()
+            .filter_map(Entry::into_resource)
+            .collect())
+    }
+
+    /// Hrefs changed or deleted since `token`, per RFC 6578.
+    pub async fn sync_collection(&self, collection: &Url, token: &str) -> Result<Changes> {
+        let body = format!(
+            r#"<?xml version="1.0" encoding="utf-8"?>
+<d:sync-collection xmlns:d="DAV:">
+  <d:sync-token>{}</d:sync-token>
+  <d:sync-level>1</d:sync-level>
+  <d:prop><d:getetag/></d:prop>
+</d:sync-collection>"#,
+            escape(token)
+        );
+
+        let multistatus = match self.request("REPORT", collection, 1, body).await {
+            Err(Error::Status {
+                status: 403 | 409, ..
+            }) => return Err(Error::InvalidSyncToken),
+            result => result?,
+        };
+
+        let mut changes = Changes {
+            sync_token: multistatus.sync_token,
+            ..Default::default()
+        };
+        for entry in multistatus.entries {
+            if self.base.join(&entry.href).ok().as_ref() == Some(collection) {
+                continue;
+            }
+            if entry.status == Some(404) {
+                changes.deleted.push(entry.href);
+            } else {
+                changes.changed.push(entry.href);
+            }
+        }
+
+        Ok(changes)
+    }
+
+    pub async fn multiget(&self, collection: &Url, hrefs: &[String]) -> Result<Vec<Resource>> {
+        if hrefs.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let hrefs: String = hrefs
+            .iter()
+            .map(|href| format!("<d:href>{}</d:href>", escape(href)))
+            .collect();
+        let body = format!(
+            r#"<?xml version="1.0" encoding="utf-8"?>
+<c:calendar-multiget xmlns:d="DAV:" xmlns:c="{CALDAV}">
+  <d:prop><d:getetag/><c:calendar-data/></d:prop>
+  {hrefs}
+</c:calendar-multiget>"#
+        );
+
+        let multistatus = self.request("REPORT", collection, 1, body).await?;
+        Ok(multistatus
+            .entries
+            .into_iter()
+            .filter_map(Entry::into_resource)
+            .collect())
+    }
+
+    async fn propfind(&self, url: &Url, depth: u8) -> Result<Multistatus> {
+        let body = format!(
+            r#"<?xml version="1.0" encoding="utf-8"?>
+<d:propfind xmlns:d="DAV:" xmlns:c="{CALDAV}"><d:prop>{DISCOVERY_PROPS}</d:prop></d:propfind>"#
+        );
+        self.request("PROPFIND", url, depth, body).await
+    }
+
+    async fn request(
+        &self,
+        method: &str,
+        url: &Url,
+        depth: u8,
+        body: String,
+    ) -> Result<Multistatus> {
+        let method = Method::from_bytes(method.as_bytes()).expect("valid method");
+
+        let mut request = self
+            .http
+            .request(method, url.clone())
+            .header("Depth", depth.to_string())
+            .header(header::CONTENT_TYPE, "application/xml; charset=utf-8")
+            .body(body);
+        if let Some((username, password)) = &self.credentials {
+            request = request.basic_auth(username, Some(password));
+        }
+
+        let response = request.send().await?;
+        if response.status() != StatusCode::MULTI_STATUS {
+            return Err(Error::Status {
+                status: response.status().as_u16(),
+                url: url.to_string(),
+            });
+        }
+
+        Multistatus::parse(&response.text().await?)
+    }
+}
+
+#[derive(Debug, Default)]
+struct Multistatus {
+    entries: Vec<Entry>,
+    sync_token: Option<String>,
+}
+
+/// The properties of one `<d:response>` that were returned with a 2xx status.
+#[derive(Debug, Default)]
+struct Entry {
+    href: String,
+    status: Option<u16>,
+    is_calendar: bool,
+    components: Option<Vec<String>>,
+    display_name: Option<String>,
+    sync_token: Option<String>,
+    current_user_principal: Option<String>,
+    calendar_home_set: Option<String>,
+    etag: Option<String>,
+    calendar_data: Option<String>,
+}
+
+impl Multistatus {
+    fn parse(body: &str) -> Result<Self> {
+        let doc = roxmltree::Document::parse(body)?;
+        let root = doc.root_element();
+
+        let mut multistatus = Self {
+            sync_token: child(root, DAV, "sync-token").and_then(text),
+            ..Default::default()
+        };
+
+        for response in children(root, DAV, "response") {
+            let Some(href) = child(response, DAV, "href").and_then(text) else {
+                continue;
+            };
+            let mut entry = Entry {
+                href,
+                status: child(response, DAV, "status").and_then(status),
+                ..Default::default()
+            };
+
+            for propstat in children(response, DAV, "propstat") {
+                if !child(propstat, DAV, "status")
+                    .and_then(status)
+                    .is_some_and(|s| (200..300).contains(&s))
+                {
+                    continue;
+                }
+                for prop in children(propstat, DAV, "prop").flat_map(|p| p.children()) {
+                    entry.apply(prop);
+                }
+            }
+
+            multistatus.entries.push(entry);
+        }
+
+        Ok(multistatus)
+    }
+}
+
+impl Entry {
+    fn apply(&mut self, prop: roxmltree::Node) {
+        let href = || child(prop, DAV, "href").and_then(text);
+
+        match (prop.tag_name().namespace(), prop.tag_name().name()) {
+            (Some(DAV), "resourcetype") => {
+                self.is_calendar = child(prop, CALDAV, "calendar").is_some();
+            }
+            (Some(DAV), "displayname") => self.display_name = text(prop),
+            (Some(DAV), "sync-token") => self.sync_token = text(prop),
+            (Some(DAV), "getetag") => self.etag = text(prop),
+            (Some(DAV), "current-user-principal") => self.current_user_principal = href(),
+            (Some(CALDAV), "calendar-home-set") => self.calendar_home_set = href(),
+            (Some(CALDAV), "calendar-data") => self.calendar_data = text(prop),
+            (Some(CALDAV), "supported-calendar-component-set") => {
+                self.components = Some(
+                    children(prop, CALDAV, "comp")
+                        .filter_map(|c| c.attribute("name"))
+                        .map(str::to_ascii_uppercase)
+                        .collect(),
+                );
+            }
+            _ => {}
+        }
+    }
+
+    fn into_collection(self, base: &Url) -> Option<Collection> {
+        // Servers that omit the component set accept every component.
+        let has_events = self
+            .components
+            .is_none_or(|c| c.iter().any(|c| c == "VEVENT"));
+        if !self.is_calendar || !has_events {
+            return None;
+        }
+
+        Some(Collection {
+            url: base.join(&self.href).ok()?,
+            name: self.display_name,
+            sync_token: self.sync_token,
+        })
+    }
+
+    fn into_resource(self) -> Option<Resource> {
+        Some(Resource {
+            href: self.href,
+            etag: self.etag,
+            data: self.calendar_data?,
+        })
+    }
+}
+
+fn children<'a, 'input>(
+    node: roxmltree::Node<'a, 'input>,
+    namespace: &'a str,
+    name: &'a str,
+) -> impl Iterator<Item = roxmltree::Node<'a, 'input>> {
+    node.children().filter(move |c| {
+        c.is_element() && c.tag_name().namespace() == Some(namespace) && c.tag_name().name() == name
+    })
+}
+
+fn child<'a, 'input>(
+    node: roxmltree::Node<'a, 'input>,
+    namespace: &'a str,
+    name: &'a str,
+) -> Option<roxmltree::Node<'a, 'input>> {
+    children(node, namespace, name).next()
+}
+
+fn text(node: roxmltree::Node) -> Option<String> {
+    let text: String = node
+        .descendants()
+        .filter(|n| n.is_text())
+        .filter_map(|n| n.text())
+        .collect();
+    let text = text.trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+/// `HTTP/1.1 404 Not Found` -> 404
+fn status(node: roxmltree::Node) -> Option<u16> {
+    text(node)?.split_whitespace().nth(1)?.parse().ok()
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
@@ -0,0 +1,17 @@
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error(transparent)]
+    Http(#[from] reqwest::Error),
+    #[error("unexpected status {status} from {url}")]
+    Status { status: u16, url: String },
+    #[error(transparent)]
+    Url(#[from] url::ParseError),
+    #[error("invalid XML: {0}")]
+    Xml(#[from] roxmltree::Error),
+    #[error("invalid iCalendar data: {0}")]
+    Ics(String),
+    #[error("sync token is no longer valid")]
+    InvalidSyncToken,
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
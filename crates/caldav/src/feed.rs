@@ -0,0 +1,70 @@
+use reqwest::{StatusCode, header};
+
+use crate::{Error, Result};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Fetched {
+    NotModified,
+    Updated { body: String, etag: Option<String> },
+}
+
+/// Downloads an ICS subscription, skipping the body when `etag` still matches.
+pub async fn fetch_feed(http: &reqwest::Client, url: &str, etag: Option<&str>) -> Result<Fetched> {
+    let url = feed_url(url)?;
+
+    let mut request = http.get(url.clone());
+    if let Some(etag) = etag {
+        request = request.header(header::IF_NONE_MATCH, etag);
+    }
+    let response = request.send().await?;
+
+    match response.status() {
+        StatusCode::NOT_MODIFIED => Ok(Fetched::NotModified),
+        status if status.is_success() => {
+            let etag = response
+                .headers()
+                .get(header::ETAG)
+                .and_then(|v| v.to_str().ok())
+                .map(str::to_string);
+            let body = response.text().await?;
+            Ok(Fetched::Updated { body, etag })
+        }
+        status => Err(Error::Status {
+            status: status.as_u16(),
+            url: url.to_string(),
+        }),
+    }
+}
+
+/// `webcal://` links, as handed out by most calendar apps, are plain HTTPS.
+pub fn feed_url(url: &str) -> Result<url::Url> {
+    let url = url.trim();
+    let url = match url.split_once("://") {
+        Some((scheme, rest))
+            if ["webcal", "webcals"]
+                .iter()
+                .any(|s| scheme.eq_ignore_ascii_case(s)) =>
+        {
+            format!("https://{rest}")
+        }
+        _ => url.to_string(),
+    };
+    Ok(url::Url::parse(&url)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_feed_url() {
+        assert_eq!(
+            feed_url(" webcal://example.com/team.ics").unwrap().as_str(),
+            "https://example.com/team.ics"
+        );
+        assert_eq!(
+            feed_url("http://localhost:8080/a.ics").unwrap().as_str(),
+            "http://localhost:8080/a.ics"
+        );
+    }
+}
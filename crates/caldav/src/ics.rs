@@ -0,0 +1,483 @@
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+use chrono_tz::Tz;
+
+use crate::{Error, Result};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct IcsCalendar {
+    /// `X-WR-CALNAME`, set by most feed publishers.
+    pub name: Option<String>,
+    pub events: Vec<IcsEvent>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct IcsEvent {
+    pub uid: String,
+    pub summary: Option<String>,
+    pub description: Option<String>,
+    pub location: Option<String>,
+    pub url: Option<String>,
+    pub start: IcsTime,
+    pub end: Option<IcsTime>,
+    pub duration: Option<Duration>,
+    pub rrule: Option<String>,
+    pub exdates: Vec<IcsTime>,
+    pub rdates: Vec<IcsTime>,
+    /// Set on an override of one instance of a recurring event.
+    pub recurrence_id: Option<IcsTime>,
+    pub cancelled: bool,
+    pub attendees: Vec<Attendee>,
+}
+
+impl IcsEvent {
+    pub fn is_recurring(&self) -> bool {
+        self.rrule.is_some() || !self.rdates.is_empty() || self.recurrence_id.is_some()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Attendee {
+    pub name: Option<String>,
+    pub email: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IcsTime {
+    Date(NaiveDate),
+    /// No zone: the same wall-clock time wherever the reader is.
+    Floating(NaiveDateTime),
+    Utc(DateTime<Utc>),
+    Zoned(NaiveDateTime, Tz),
+}
+
+impl IcsTime {
+    pub fn is_all_day(&self) -> bool {
+        matches!(self, Self::Date(_))
+    }
+
+    /// Dates and floating times are read in `default_tz`.
+    pub fn resolve(&self, default_tz: Tz) -> DateTime<Tz> {
+        let (local, tz) = match *self {
+            Self::Utc(dt) => return dt.with_timezone(&Tz::UTC),
+            Self::Date(date) => (date.and_time(NaiveTime::MIN), default_tz),
+            Self::Floating(local) => (local, default_tz),
+            Self::Zoned(local, tz) => (local, tz),
+        };
+
+        // A wall-clock time skipped by a DST change is moved past the gap.
+        tz.from_local_datetime(&local)
+            .earliest()
+            .or_else(|| {
+                tz.from_local_datetime(&(local + Duration::hours(1)))
+                    .earliest()
+            })
+            .unwrap_or_else(|| tz.from_utc_datetime(&local))
+    }
+}
+
+pub fn parse(input: &str) -> Result<IcsCalendar> {
+    let lines = unfold(input);
+    if !lines
+        .first()
+        .is_some_and(|l| l.trim().eq_ignore_ascii_case("BEGIN:VCALENDAR"))
+    {
+        return Err(Error::Ics("missing BEGIN:VCALENDAR".to_string()));
+    }
+
+    let mut calendar = IcsCalendar {
+        name: None,
+        events: Vec::new(),
+    };
+    let mut event: Option<EventBuilder> = None;
+    // Components nested in an event, such as VALARM.
+    let mut nested = 0usize;
+
+    for line in &lines {
+        let Some(line) = ContentLine::parse(line) else {
+            continue;
+        };
+
+        match (line.name.as_str(), event.as_mut()) {
+            ("BEGIN", None) if line.value.eq_ignore_ascii_case("VEVENT") => {
+                event = Some(EventBuilder::default());
+            }
+            ("END", Some(_)) if nested == 0 && line.value.eq_ignore_ascii_case("VEVENT") => {
+                if let Some(e) = event.take().and_then(EventBuilder::build) {
+                    calendar.events.push(e);
+                }
+            }
+            ("BEGIN", Some(_)) => nested += 1,
+            ("END", Some(_)) => nested = nested.saturating_sub(1),
+            (_, Some(builder)) if nested == 0 => builder.apply(&line),
+            ("X-WR-CALNAME", None) => calendar.name = Some(unescape(&line.value)),
+            _ => {}
+        }
+    }
+
+    Ok(calendar)
+}
+
+fn unfold(input: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+
+    for raw in input.trim_start_matches('\u{feff}').lines() {
+        match (raw.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(rest), Some(last)) => last.push_str(rest),
+            _ if raw.trim().is_empty() => {}
+            _ => lines.push(raw.to_string()),
+        }
+    }
+
+    lines
+}
+
+struct ContentLine {
+    name: String,
+    params: Vec<(String, String)>,
+    value: String,
+}
+
+impl ContentLine {
+    fn parse(line: &str) -> Option<Self> {
+        let mut in_quotes = false;
+        let mut parts = Vec::new();
+        let mut start = 0;
+
+        for (i, c) in line.char_indices() {
+            match c {
+                '"' => in_quotes = !in_quotes,
+                ';' if !in_quotes => {
+                    parts.push(&line[start..i]);
+                    start = i + 1;
+                }
+                ':' if !in_quotes => {
+                    parts.push(&line[start..i]);
+                    let mut parts = parts.into_iter();
+                    let name = parts.next()?.trim().to_ascii_uppercase();
+                    let params = parts
+                        .filter_map(|p| p.split_once('='))
+                        .map(|(k, v)| {
+                            (
+                                k.trim().to_ascii_uppercase(),
+                                v.trim_matches('"').to_string(),
+                            )
+                        })
+                        .collect();
+
+                    return Some(Self {
+                        name,
+                        params,
+                        value: line[i + 1..].to_string(),
+                    });
+                }
+                _ => {}
+            }
+        }
+
+        None
+    }
+
+    fn param(&self, key: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    fn times(&self) -> Vec<IcsTime> {
+        if self
+            .param("VALUE")
+            .is_some_and(|v| v.eq_ignore_ascii_case("PERIOD"))
+        {
+            return vec![];
+        }
+
+        let tz = self.param("TZID").and_then(resolve_tzid);
+        self.value
+            .split(',')
+            .filter_map(|v| parse_time(v.trim(), tz))
+            .collect()
+    }
+}
+
+#[derive(Default)]
+struct EventBuilder {
+    uid: Option<String>,
+    summary: Option<String>,
+    description: Option<String>,
+    location: Option<String>,
+    url: Option<String>,
+    start: Option<IcsTime>,
+    end: Option<IcsTime>,
+    duration: Option<Duration>,
+    rrule: Option<String>,
+    exdates: Vec<IcsTime>,
+    rdates: Vec<IcsTime>,
+    recurrence_id: Option<IcsTime>,
+    cancelled: bool,
+    attendees: Vec<Attendee>,
+}
+
+impl EventBuilder {
+    fn apply(&mut self, line: &ContentLine) {
+        let text = || Some(unescape(&line.value)).filter(|s| !s.is_empty());
+
+        match line.name.as_str() {
+            "UID" => self.uid = text(),
+            "SUMMARY" => self.summary = text(),
+            "DESCRIPTION" => self.description = text(),
+            "LOCATION" => self.location = text(),
+            "URL" => self.url = text(),
+            "STATUS" => self.cancelled = line.value.trim().eq_ignore_ascii_case("CANCELLED"),
+            "DTSTART" => self.start = line.times().into_iter().next(),
+            "DTEND" => self.end = line.times().into_iter().next(),
+            "DURATION" => self.duration = parse_duration(line.value.trim()),
+            "RRULE" => self.rrule = Some(line.value.trim().to_string()),
+            "EXDATE" => self.exdates.extend(line.times()),
+            "RDATE" => self.rdates.extend(line.times()),
+            "RECURRENCE-ID" => self.recurrence_id = line.times().into_iter().next(),
+            "ATTENDEE" | "ORGANIZER" => {
+                let email = line
+                    .value
+                    .trim()
+                    .get(..7)
+                    .filter(|scheme| scheme.eq_ignore_ascii_case("mailto:"))
+                    .map(|_| line.value.trim()[7..].to_string());
+                let name = line.param("CN").map(str::to_string);
+
+                let duplicate = email.is_some()
+                    && self.attendees.iter().any(|a| {
+                        a.email.as_deref().map(str::to_lowercase)
+                            == email.as_deref().map(str::to_lowercase)
+                    });
+                if !duplicate && (email.is_some() || name.is_some()) {
+                    self.attendees.push(Attendee { name, email });
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn build(self) -> Option<IcsEvent> {
+        Some(IcsEvent {
+            uid: self.uid?,
+            summary: self.summary,
+            description: self.description,
+            location: self.location,
+            url: self.url,
+            start: self.start?,
+            end: self.end,
+            duration: self.duration,
+            rrule: self.rrule,
+            exdates: self.exdates,
+            rdates: self.rdates,
+            recurrence_id: self.recurrence_id,
+            cancelled: self.cancelled,
+            attendees: self.attendees,
+        })
+    }
+}
+
+fn unescape(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    let mut chars = value.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n' | 'N') => out.push('\n'),
+            Some(other) => out.push(other),
+            None => out.push('\\'),
+        }
+    }
+
+    out
+}
+
+fn parse_time(value: &str, tz: Option<Tz>) -> Option<IcsTime> {
+    if value.len() == 8 {
+        return NaiveDate::parse_from_str(value, "%Y%m%d")
+            .ok()
+            .map(IcsTime::Date);
+    }
+
+    if let Some(utc) = value.strip_suffix(['Z', 'z']) {
+        let local = NaiveDateTime::parse_from_str(utc, "%Y%m%dT%H%M%S").ok()?;
+        return Some(IcsTime::Utc(local.and_utc()));
+    }
+
+    let local = NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S").ok()?;
+    Some(match tz {
+        Some(tz) => IcsTime::Zoned(local, tz),
+        None => IcsTime::Floating(local),
+    })
+}
+
+/// IANA names, Outlook's Windows names, and Lightning's `/mozilla.org/<date>/<IANA>` form.
+fn resolve_tzid(tzid: &str) -> Option<Tz> {
+    let tzid = tzid.trim();
+    if let Ok(tz) = tzid.parse::<Tz>() {
+        return Some(tz);
+    }
+
+    if let Some(tz) = WINDOWS_ZONES
+        .iter()
+        .find(|(windows, _)| windows.eq_ignore_ascii_case(tzid))
+        .and_then(|(_, iana)| iana.parse().ok())
+    {
+        return Some(tz);
+    }
+
+    // Try the last two (then one) path segments, e.g. `/mozilla.org/20050126_1/Europe/Berlin`.
+    let segments: Vec<&str> = tzid.split('/').filter(|s| !s.is_empty()).collect();
+    (1..=segments.len().min(3))
+        .rev()
+        .find_map(|n| segments[segments.len() - n..].join("/").parse().ok())
+}
+
+const WINDOWS_ZONES: &[(&str, &str)] = &[
+    ("UTC", "Etc/UTC"),
+    ("GMT Standard Time", "Europe/London"),
+    ("Greenwich Standard Time", "Atlantic/Reykjavik"),
+    ("W. Europe Standard Time", "Europe/Berlin"),
+    ("Central Europe Standard Time", "Europe/Budapest"),
+    ("Central European Standard Time", "Europe/Warsaw"),
+    ("Romance Standard Time", "Europe/Paris"),
+    ("E. Europe Standard Time", "Europe/Chisinau"),
+    ("FLE Standard Time", "Europe/Kiev"),
+    ("GTB Standard Time", "Europe/Bucharest"),
+    ("Russian Standard Time", "Europe/Moscow"),
+    ("Turkey Standard Time", "Europe/Istanbul"),
+    ("Israel Standard Time", "Asia/Jerusalem"),
+    ("Arabian Standard Time", "Asia/Dubai"),
+    ("India Standard Time", "Asia/Kolkata"),
+    ("SE Asia Standard Time", "Asia/Bangkok"),
+    ("China Standard Time", "Asia/Shanghai"),
+    ("Singapore Standard Time", "Asia/Singapore"),
+    ("Tokyo Standard Time", "Asia/Tokyo"),
+    ("Korea Standard Time", "Asia/Seoul"),
+    ("AUS Eastern Standard Time", "Australia/Sydney"),
+    ("New Zealand Standard Time", "Pacific/Auckland"),
+    ("Hawaiian Standard Time", "Pacific/Honolulu"),
+    ("Alaskan Standard Time", "America/Anchorage"),
+    ("Pacific Standard Time", "America/Los_Angeles"),
+    ("Mountain Standard Time", "America/Denver"),
+    ("US Mountain Standard Time", "America/Phoenix"),
+    ("Central Standard Time", "America/Chicago"),
+    ("Eastern Standard Time", "America/New_York"),
+    ("Atlantic Standard Time", "America/Halifax"),
+    ("SA Pacific Standard Time", "America/Bogota"),
+    ("E. South America Standard Time", "America/Sao_Paulo"),
+    ("Argentina Standard Time", "America/Buenos_Aires"),
+];
+
+/// `DURATION` values such as `PT1H30M`, `P1D` or `-PT15M`.
+fn parse_duration(value: &str) -> Option<Duration> {
+    let (sign, rest) = match value.strip_prefix('-') {
+        Some(rest) => (-1, rest),
+        None => (1, value.strip_prefix('+').unwrap_or(value)),
+    };
+    let rest = rest.strip_prefix('P')?;
+
+    let mut total = Duration::zero();
+    let mut number = String::new();
+    let mut in_time = false;
+
+    for c in rest.chars() {
+        match c {
+            'T' => in_time = true,
+            '0'..='9' => number.push(c),
+            unit => {
+                let n: i64 = std::mem::take(&mut number).parse().ok()?;
+                total += match (unit, in_time) {
+                    ('W', false) => Duration::weeks(n),
+                    ('D', false) => Duration::days(n),
+                    ('H', true) => Duration::hours(n),
+                    ('M', true) => Duration::minutes(n),
+                    ('S', true) => Duration::seconds(n),
+                    _ => return None,
+                };
+            }
+        }
+    }
+
+    number.is_empty().then_some(total * sign)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let input = "BEGIN:VCALENDAR\r\nVERSION:2.0\r\nX-WR-CALNAME:Team\r\nBEGIN:VTIMEZONE\r\nTZID:W. Europe Standard Time\r\nEND:VTIMEZONE\r\nBEGIN:VEVENT\r\nUID:abc\r\nSUMMARY:Weekly sync\\, team\r\nDESCRIPTION:Agenda:\\nhttps://acme.zoom.us/j/12\r\n 3\r\nDTSTART;TZID=W. Europe Standard Time:20250310T100000\r\nDURATION:PT30M\r\nRRULE:FREQ=WEEKLY;COUNT=4\r\nEXDATE;TZID=\"/mozilla.org/20050126_1/Europe/Berlin\":20250317T100000,20250324T100000\r\nORGANIZER;CN=Ann:mailto:ann@acme.com\r\nATTENDEE;CN=\"Bob; Jr\";PARTSTAT=ACCEPTED:MAILTO:bob@acme.com\r\nATTENDEE:mailto:ann@acme.com\r\nBEGIN:VALARM\r\nDESCRIPTION:Reminder\r\nEND:VALARM\r\nEND:VEVENT\r\nBEGIN:VEVENT\r\nUID:day\r\nDTSTART;VALUE=DATE:20250311\r\nSTATUS:CANCELLED\r\nEND:VEVENT\r\nEND:VCALENDAR\r\n";
+
+        let calendar = parse(input).unwrap();
+        assert_eq!(calendar.name.as_deref(), Some("Team"));
+        assert_eq!(calendar.events.len(), 2);
+
+        let berlin = chrono_tz::Europe::Berlin;
+        let at = |d: u32| {
+            NaiveDate::from_ymd_opt(2025, 3, d)
+                .unwrap()
+                .and_hms_opt(10, 0, 0)
+                .unwrap()
+        };
+
+        let e = &calendar.events[0];
+        assert_eq!(e.summary.as_deref(), Some("Weekly sync, team"));
+        assert_eq!(
+            e.description.as_deref(),
+            Some("Agenda:\nhttps://acme.zoom.us/j/123")
+        );
+        assert_eq!(e.start, IcsTime::Zoned(at(10), berlin));
+        assert_eq!(e.duration, Some(Duration::minutes(30)));
+        assert_eq!(e.rrule.as_deref(), Some("FREQ=WEEKLY;COUNT=4"));
+        assert_eq!(
+            e.exdates,
+            vec![
+                IcsTime::Zoned(at(17), berlin),
+                IcsTime::Zoned(at(24), berlin)
+            ]
+        );
+        assert_eq!(
+            e.attendees,
+            vec![
+                Attendee {
+                    name: Some("Ann".into()),
+                    email: Some("ann@acme.com".into())
+                },
+                Attendee {
+                    name: Some("Bob; Jr".into()),
+                    email: Some("bob@acme.com".into())
+                },
+            ]
+        );
+
+        let e = &calendar.events[1];
+        assert!(e.cancelled);
+        assert!(e.start.is_all_day());
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("PT1H30M"), Some(Duration::minutes(90)));
+        assert_eq!(parse_duration("P1W2D"), Some(Duration::days(9)));
+        assert_eq!(parse_duration("-PT15M"), Some(Duration::minutes(-15)));
+        assert_eq!(parse_duration("PT1H5"), None);
+        assert_eq!(parse_duration("1H"), None);
+    }
+
+    #[test]
+    fn test_resolve_skipped_time() {
+        let local = NaiveDate::from_ymd_opt(2025, 3, 9)
+            .unwrap()
+            .and_hms_opt(2, 30, 0)
+            .unwrap();
+        let resolved = IcsTime::Zoned(local, chrono_tz::America::New_York).resolve(Tz::UTC);
+        assert_eq!(resolved.to_utc().to_rfc3339(), "2025-03-09T07:30:00+00:00");
+    }
+}
@@ -0,0 +1,13 @@
+mod client;
+mod error;
+mod feed;
+mod ics;
+mod recur;
+mod sync;
+
+pub use client::*;
+pub use error::*;
+pub use feed::*;
+pub use ics::*;
+pub use recur::*;
+pub use sync::*;
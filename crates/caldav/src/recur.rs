@@ -0,0 +1,275 @@
+use chrono::{DateTime, Duration, Utc};
+use chrono_tz::Tz;
+use rrule::{RRule, RRuleSet, Unvalidated};
+
+use crate::ics::{IcsEvent, IcsTime};
+
+// Bounds the work for rules like FREQ=MINUTELY with no end.
+const MAX_ITERATIONS: usize = 10_000;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Window {
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    /// Zone for all-day events and floating times.
+    pub default_tz: Tz,
+}
+
+impl Window {
+    fn overlaps(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> bool {
+        start < self.to && (end > self.from || start >= self.from)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Occurrence<'a> {
+    pub event: &'a IcsEvent,
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    /// The instance's slot in its series; `None` for one-off events.
+    pub original_start: Option<DateTime<Utc>>,
+}
+
+/// Occurrences overlapping `window`, with recurring events expanded and overrides applied.
+pub fn expand<'a>(events: &'a [IcsEvent], window: &Window) -> Vec<Occurrence<'a>> {
+    let mut uids: Vec<&str> = Vec::new();
+    for event in events {
+        if !uids.contains(&event.uid.as_str()) {
+            uids.push(&event.uid);
+        }
+    }
+
+    let mut occurrences = Vec::new();
+    for uid in uids {
+        let series: Vec<&IcsEvent> = events.iter().filter(|e| e.uid == uid).collect();
+        let master = series.iter().find(|e| e.recurrence_id.is_none()).copied();
+        let overrides: Vec<&IcsEvent> = series
+            .iter()
+            .filter(|e| e.recurrence_id.is_some())
+            .copied()
+            .collect();
+
+        let overridden: Vec<DateTime<Utc>> = overrides
+            .iter()
+            .filter_map(|e| e.recurrence_id)
+            .map(|t| t.resolve(window.default_tz).to_utc())
+            .collect();
+
+        if let Some(master) = master.filter(|m| !m.cancelled) {
+            for (start, end) in instances(master, window) {
+                if master.is_recurring() && overridden.contains(&start) {
+                    continue;
+                }
+                occurrences.push(Occurrence {
+                    event: master,
+                    start,
+                    end,
+                    original_start: master.is_recurring().then_some(start),
+                });
+            }
+        }
+
+        for (event, original) in overrides.into_iter().zip(overridden) {
+            let (start, end) = span(event, window.default_tz);
+            if !event.cancelled && window.overlaps(start, end) {
+                occurrences.push(Occurrence {
+                    event,
+                    start,
+                    end,
+                    original_start: Some(original),
+                });
+            }
+        }
+    }
+
+    occurrences.sort_by_key(|o| o.start);
+    occurrences
+}
+
+fn span(event: &IcsEvent, default_tz: Tz) -> (DateTime<Utc>, DateTime<Utc>) {
+    let start = event.start.resolve(default_tz).to_utc();
+    (start, start + length(event, default_tz))
+}
+
+fn length(event: &IcsEvent, default_tz: Tz) -> Duration {
+    let start = event.start.resolve(default_tz);
+    let length = match (event.end, event.duration) {
+        (Some(end), _) => end.resolve(default_tz) - start,
+        (None, Some(duration)) => duration,
+        (None, None) if event.start.is_all_day() => Duration::days(1),
+        (None, None) => Duration::zero(),
+    };
+    length.max(Duration::zero())
+}
+
+fn instances(event: &IcsEvent, window: &Window) -> Vec<(DateTime<Utc>, DateTime<Utc>)> {
+    let single = || {
+        let (start, end) = span(event, window.default_tz);
+        if window.overlaps(start, end) {
+            vec![(start, end)]
+        } else {
+            vec![]
+        }
+    };
+
+    if event.rrule.is_none() && event.rdates.is_empty() {
+        return single();
+    }
+
+    // Rules are evaluated in the event's own zone so instances keep their
+    // wall-clock time across DST changes.
+    let tz = match event.start {
+        IcsTime::Zoned(_, tz) => tz,
+        IcsTime::Utc(_) => Tz::UTC,
+        IcsTime::Date(_) | IcsTime::Floating(_) => window.default_tz,
+    };
+    let rrule_tz = rrule::Tz::Tz(tz);
+    let dt_start = event
+        .start
+        .resolve(window.default_tz)
+        .with_timezone(&rrule_tz);
+
+    let set = match &event.rrule {
+        Some(rule) => match rule
+            .parse::<RRule<Unvalidated>>()
+            .and_then(|rule| rule.build(dt_start))
+        {
+            Ok(set) => set,
+            // An unreadable rule still leaves the first instance.
+            Err(_) => return single(),
+        },
+        None => RRuleSet::new(dt_start),
+    };
+    let set = event.rdates.iter().fold(set, |set, rdate| {
+        set.rdate(rdate.resolve(window.default_tz).with_timezone(&rrule_tz))
+    });
+
+    let exdates: Vec<DateTime<Utc>> = event
+        .exdates
+        .iter()
+        .map(|t| t.resolve(window.default_tz).to_utc())
+        .collect();
+    let length = length(event, window.default_tz);
+
+    let mut result = Vec::new();
+    for start in set.limit().into_iter().take(MAX_ITERATIONS) {
+        let start = start.to_utc();
+        if start >= window.to {
+            break;
+        }
+
+        let end = start + length;
+        if !exdates.contains(&start) && window.overlaps(start, end) {
+            result.push((start, end));
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ics::parse;
+    use chrono::TimeZone;
+
+    fn window(from: (u32, u32), to: (u32, u32)) -> Window {
+        Window {
+            from: Utc.with_ymd_and_hms(2025, from.0, from.1, 0, 0, 0).unwrap(),
+            to: Utc.with_ymd_and_hms(2025, to.0, to.1, 0, 0, 0).unwrap(),
+            default_tz: chrono_tz::Asia::Seoul,
+        }
+    }
+
+    fn starts(occurrences: &[Occurrence]) -> Vec<String> {
+        occurrences
+            .iter()
+            .map(|o| o.start.format("%m-%d %H:%M").to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_expand_weekly_with_exdate_and_override() {
+        let calendar = parse(
+            "BEGIN:VCALENDAR
+BEGIN:VEVENT
+UID:sync
+SUMMARY:Sync
+DTSTART;TZID=America/New_York:20250303T090000
+DTEND;TZID=America/New_York:20250303T093000
+RRULE:FREQ=WEEKLY;UNTIL=20250401T000000Z
+EXDATE;TZID=America/New_York:20250317T090000
+END:VEVENT
+BEGIN:VEVENT
+UID:sync
+SUMMARY:Sync (moved)
+RECURRENCE-ID;TZID=America/New_York:20250324T090000
+DTSTART;TZID=America/New_York:20250325T140000
+DTEND;TZID=America/New_York:20250325T150000
+END:VEVENT
+BEGIN:VEVENT
+UID:sync
+RECURRENCE-ID;TZID=America/New_York:20250331T090000
+DTSTART;TZID=America/New_York:20250331T090000
+STATUS:CANCELLED
+END:VEVENT
+END:VCALENDAR",
+        )
+        .unwrap();
+
+        let occurrences = expand(&calendar.events, &window((3, 5), (4, 30)));
+
+        // New York moves to EDT on March 9, so the UTC hour shifts.
+        assert_eq!(starts(&occurrences), vec!["03-10 13:00", "03-25 18:00"]);
+        assert_eq!(
+            occurrences[0].end - occurrences[0].start,
+            Duration::minutes(30)
+        );
+        assert_eq!(
+            occurrences[1].event.summary.as_deref(),
+            Some("Sync (moved)")
+        );
+        assert_eq!(
+            occurrences[1]
+                .original_start
+                .unwrap()
+                .format("%m-%d %H:%M")
+                .to_string(),
+            "03-24 13:00"
+        );
+    }
+
+    #[test]
+    fn test_expand_all_day_and_one_off() {
+        let calendar = parse(
+            "BEGIN:VCALENDAR
+BEGIN:VEVENT
+UID:standup
+DTSTART;VALUE=DATE:20250101
+RRULE:FREQ=MONTHLY;BYMONTHDAY=1
+RDATE;VALUE=DATE:20250315
+END:VEVENT
+BEGIN:VEVENT
+UID:lunch
+DTSTART:20250302T030000Z
+DURATION:PT1H
+END:VEVENT
+BEGIN:VEVENT
+UID:old
+DTSTART:20250102T030000Z
+END:VEVENT
+END:VCALENDAR",
+        )
+        .unwrap();
+
+        let occurrences = expand(&calendar.events, &window((2, 20), (4, 1)));
+
+        // All-day events start at midnight in Seoul.
+        assert_eq!(
+            starts(&occurrences),
+            vec!["02-28 15:00", "03-02 03:00", "03-14 15:00", "03-31 15:00"]
+        );
+        assert_eq!(occurrences[0].end - occurrences[0].start, Duration::days(1));
+        assert_eq!(occurrences[1].original_start, None);
+    }
+}
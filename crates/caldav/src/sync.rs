@@ -0,0 +1,150 @@
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, NaiveDate, Utc};
+
+use crate::client::{CalDavClient, Collection};
+use crate::feed::{Fetched, fetch_feed};
+use crate::ics::{Attendee, IcsCalendar, parse};
+use crate::recur::{Window, expand};
+use crate::{Error, Result};
+
+/// One occurrence within the sync window.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalendarEvent {
+    pub uid: String,
+    pub title: Option<String>,
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub is_all_day: bool,
+    pub location: Option<String>,
+    pub description: Option<String>,
+    pub url: Option<String>,
+    pub attendees: Vec<Attendee>,
+    /// The instance's slot in its series; `None` for one-off events.
+    pub original_start: Option<DateTime<Utc>>,
+}
+
+/// What a previous read of a CalDAV collection left behind.
+#[derive(Debug, Clone, Default)]
+pub struct CollectionState {
+    window_start: Option<NaiveDate>,
+    sync_token: Option<String>,
+    resources: BTreeMap<String, IcsCalendar>,
+}
+
+/// Events of `collection` within `window`.
+///
+/// The collection is re-read in full when the window moves to a new day, and
+/// otherwise only the resources reported by `sync-collection` are fetched.
+pub async fn collection_events(
+    client: &CalDavClient,
+    collection: &Collection,
+    window: &Window,
+    state: &mut CollectionState,
+) -> Result<Vec<CalendarEvent>> {
+    let token = if state.window_start == Some(window.from.date_naive()) {
+        state.sync_token.clone()
+    } else {
+        None
+    };
+
+    match token {
+        Some(token) if collection.sync_token.as_deref() == Some(token.as_str()) => {}
+        Some(token) => match client.sync_collection(&collection.url, &token).await {
+            Ok(changes) => {
+                let resources = client.multiget(&collection.url, &changes.changed).await?;
+
+                // Changed resources the server no longer returns are gone too.
+                for href in changes.changed.iter().chain(&changes.deleted) {
+                    state.resources.remove(href);
+                }
+                for resource in resources {
+                    state
+                        .resources
+                        .insert(resource.href, parse(&resource.data)?);
+                }
+                state.sync_token = changes.sync_token;
+            }
+            Err(Error::InvalidSyncToken) => full_read(client, collection, window, state).await?,
+            Err(e) => return Err(e),
+        },
+        None => full_read(client, collection, window, state).await?,
+    }
+
+    Ok(state
+        .resources
+        .values()
+        .flat_map(|ics| to_events(ics, window))
+        .collect())
+}
+
+async fn full_read(
+    client: &CalDavClient,
+    collection: &Collection,
+    window: &Window,
+    state: &mut CollectionState,
+) -> Result<()> {
+    // The token is read before the query, so edits made in between show up next time.
+    let resources = client
+        .query(&collection.url, window.from, window.to)
+        .await?;
+
+    state.resources = resources
+        .into_iter()
+        .map(|resource| Ok((resource.href, parse(&resource.data)?)))
+        .collect::<Result<_>>()?;
+    state.sync_token = collection.sync_token.clone();
+    state.window_start = Some(window.from.date_naive());
+    Ok(())
+}
+
+/// The last download of an ICS subscription, reused while its ETag matches.
+#[derive(Debug, Clone, Default)]
+pub struct FeedState {
+    etag: Option<String>,
+    calendar: Option<IcsCalendar>,
+}
+
+/// Refreshes an ICS subscription. `webcal://` URLs are accepted.
+pub async fn refresh_feed<'a>(
+    http: &reqwest::Client,
+    url: &str,
+    state: &'a mut FeedState,
+) -> Result<&'a IcsCalendar> {
+    let etag = state.calendar.as_ref().and(state.etag.as_deref());
+
+    match fetch_feed(http, url, etag).await? {
+        Fetched::NotModified => {}
+        Fetched::Updated { body, etag } => {
+            state.calendar = Some(parse(&body)?);
+            state.etag = etag;
+        }
+    }
+
+    Ok(state.calendar.get_or_insert_with(|| IcsCalendar {
+        name: None,
+        events: vec![],
+    }))
+}
+
+pub fn to_events(ics: &IcsCalendar, window: &Window) -> Vec<CalendarEvent> {
+    expand(&ics.events, window)
+        .into_iter()
+        .map(|occurrence| {
+            let event = occurrence.event;
+
+            CalendarEvent {
+                uid: event.uid.clone(),
+                title: event.summary.clone(),
+                start: occurrence.start,
+                end: occurrence.end,
+                is_all_day: event.start.is_all_day(),
+                location: event.location.clone(),
+                description: event.description.clone(),
+                url: event.url.clone(),
+                attendees: event.attendees.clone(),
+                original_start: occurrence.original_start,
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,235 @@
+use caldav::{
+    CalDavClient, CalendarEvent, Collection, CollectionState, FeedState, Window, collection_events,
+    refresh_feed, to_events,
+};
+use chrono::{TimeZone, Utc};
+use wiremock::matchers::{body_string_contains, header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn window() -> Window {
+    Window {
+        from: Utc.with_ymd_and_hms(2025, 3, 1, 0, 0, 0).unwrap(),
+        to: Utc.with_ymd_and_hms(2025, 4, 1, 0, 0, 0).unwrap(),
+        default_tz: chrono_tz::UTC,
+    }
+}
+
+fn multistatus(body: &str) -> ResponseTemplate {
+    ResponseTemplate::new(207).set_body_string(format!(
+        r#"<?xml version="1.0" encoding="utf-8"?>
+<d:multistatus xmlns:d="DAV:" xmlns:c="urn:ietf:params:xml:ns:caldav">{body}</d:multistatus>"#
+    ))
+}
+
+fn resource(href: &str, data: &str) -> String {
+    format!(
+        r#"<d:response><d:href>{href}</d:href><d:propstat><d:prop><d:getetag>"1"</d:getetag><c:calendar-data><![CDATA[{data}]]></c:calendar-data></d:prop><d:status>HTTP/1.1 200 OK</d:status></d:propstat></d:response>"#
+    )
+}
+
+const STANDUP: &str = "BEGIN:VCALENDAR
+BEGIN:VEVENT
+UID:standup
+SUMMARY:Standup
+DTSTART:20250303T090000Z
+DURATION:PT15M
+RRULE:FREQ=WEEKLY;COUNT=3
+ATTENDEE;CN=Ann:mailto:ann@acme.com
+END:VEVENT
+END:VCALENDAR";
+
+const LUNCH: &str = "BEGIN:VCALENDAR
+BEGIN:VEVENT
+UID:lunch
+SUMMARY:Lunch
+DESCRIPTION:Pizza
+LOCATION:Cafe
+DTSTART:20250305T120000Z
+DTEND:20250305T130000Z
+END:VEVENT
+END:VCALENDAR";
+
+async fn mount_discovery(server: &MockServer, sync_token: &str) {
+    Mock::given(method("PROPFIND"))
+        .and(path("/dav/"))
+        .and(header("Depth", "0"))
+        .respond_with(multistatus(
+            r#"<d:response><d:href>/dav/</d:href><d:propstat><d:prop><d:resourcetype><d:collection/></d:resourcetype><d:current-user-principal><d:href>/dav/principals/me/</d:href></d:current-user-principal></d:prop><d:status>HTTP/1.1 200 OK</d:status></d:propstat></d:response>"#,
+        ))
+        .mount(server)
+        .await;
+
+    Mock::given(method("PROPFIND"))
+        .and(path("/dav/principals/me/"))
+        .respond_with(multistatus(
+            r#"<d:response><d:href>/dav/principals/me/</d:href><d:propstat><d:prop><c:calendar-home-set><d:href>/dav/calendars/me/</d:href></c:calendar-home-set></d:prop><d:status>HTTP/1.1 200 OK</d:status></d:propstat></d:response>"#,
+        ))
+        .mount(server)
+        .await;
+
+    Mock::given(method("PROPFIND"))
+        .and(path("/dav/calendars/me/"))
+        .and(header("Depth", "1"))
+        .respond_with(multistatus(&format!(
+            r#"<d:response><d:href>/dav/calendars/me/</d:href><d:propstat><d:prop><d:resourcetype><d:collection/></d:resourcetype></d:prop><d:status>HTTP/1.1 200 OK</d:status></d:propstat></d:response>
+<d:response><d:href>/dav/calendars/me/work/</d:href><d:propstat><d:prop><d:resourcetype><d:collection/><c:calendar/></d:resourcetype><d:displayname>Work</d:displayname><d:sync-token>{sync_token}</d:sync-token><c:supported-calendar-component-set><c:comp name="VEVENT"/></c:supported-calendar-component-set></d:prop><d:status>HTTP/1.1 200 OK</d:status></d:propstat></d:response>
+<d:response><d:href>/dav/calendars/me/tasks/</d:href><d:propstat><d:prop><d:resourcetype><d:collection/><c:calendar/></d:resourcetype><c:supported-calendar-component-set><c:comp name="VTODO"/></c:supported-calendar-component-set></d:prop><d:status>HTTP/1.1 200 OK</d:status></d:propstat></d:response>"#
+        )))
+        .mount(server)
+        .await;
+}
+
+async fn mount_full_query(server: &MockServer) {
+    Mock::given(method("REPORT"))
+        .and(path("/dav/calendars/me/work/"))
+        .and(body_string_contains("calendar-query"))
+        .and(body_string_contains(r#"start="20250301T000000Z""#))
+        .respond_with(multistatus(&format!(
+            "{}{}",
+            resource("/dav/calendars/me/work/standup.ics", STANDUP),
+            resource("/dav/calendars/me/work/lunch.ics", LUNCH)
+        )))
+        .expect(1)
+        .mount(server)
+        .await;
+}
+
+async fn work(client: &CalDavClient) -> Collection {
+    let collections = client.discover().await.unwrap();
+    assert_eq!(collections.len(), 1);
+    collections.into_iter().next().unwrap()
+}
+
+async fn events(client: &CalDavClient, state: &mut CollectionState) -> Vec<CalendarEvent> {
+    let collection = work(client).await;
+    let mut events = collection_events(client, &collection, &window(), state)
+        .await
+        .unwrap();
+    events.sort_by_key(|e| e.start);
+    events
+}
+
+#[tokio::test]
+async fn test_full_then_incremental_sync() {
+    let server = MockServer::start().await;
+    let client = CalDavClient::new(format!("{}/dav/", server.uri()).parse().unwrap())
+        .with_basic_auth("me", "secret");
+    let mut state = CollectionState::default();
+
+    mount_discovery(&server, "tok-1").await;
+    mount_full_query(&server).await;
+
+    assert_eq!(work(&client).await.name.as_deref(), Some("Work"));
+
+    let synced = events(&client, &mut state).await;
+    let titles: Vec<_> = synced.iter().filter_map(|e| e.title.as_deref()).collect();
+    assert_eq!(titles, vec!["Standup", "Lunch", "Standup", "Standup"]);
+    assert_eq!(
+        synced[0].original_start,
+        Some(Utc.with_ymd_and_hms(2025, 3, 3, 9, 0, 0).unwrap())
+    );
+    assert_eq!(
+        synced[0].attendees[0].email.as_deref(),
+        Some("ann@acme.com")
+    );
+    assert_eq!(synced[1].uid, "lunch");
+    assert_eq!(synced[1].description.as_deref(), Some("Pizza"));
+    assert_eq!(synced[1].location.as_deref(), Some("Cafe"));
+    assert_eq!(synced[1].original_start, None);
+
+    // Nothing changed, so the collection isn't queried again.
+    assert_eq!(events(&client, &mut state).await, synced);
+
+    // The standup moves to a daily series and lunch is deleted on the server.
+    server.reset().await;
+    mount_discovery(&server, "tok-2").await;
+
+    Mock::given(method("REPORT"))
+        .and(path("/dav/calendars/me/work/"))
+        .and(body_string_contains("<d:sync-token>tok-1</d:sync-token>"))
+        .respond_with(multistatus(
+            r#"<d:sync-token>tok-2</d:sync-token>
+<d:response><d:href>/dav/calendars/me/work/standup.ics</d:href><d:propstat><d:prop><d:getetag>"2"</d:getetag></d:prop><d:status>HTTP/1.1 200 OK</d:status></d:propstat></d:response>
+<d:response><d:href>/dav/calendars/me/work/lunch.ics</d:href><d:status>HTTP/1.1 404 Not Found</d:status></d:response>"#,
+        ))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("REPORT"))
+        .and(path("/dav/calendars/me/work/"))
+        .and(body_string_contains("calendar-multiget"))
+        .and(body_string_contains("standup.ics"))
+        .respond_with(multistatus(&resource(
+            "/dav/calendars/me/work/standup.ics",
+            &STANDUP.replace("FREQ=WEEKLY;COUNT=3", "FREQ=DAILY;COUNT=2"),
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let days: Vec<_> = events(&client, &mut state)
+        .await
+        .iter()
+        .map(|e| e.start.format("%m-%d").to_string())
+        .collect();
+    assert_eq!(days, vec!["03-03", "03-04"]);
+}
+
+#[tokio::test]
+async fn test_invalid_sync_token_falls_back_to_full_sync() {
+    let server = MockServer::start().await;
+    let client = CalDavClient::new(format!("{}/dav/", server.uri()).parse().unwrap());
+    let mut state = CollectionState::default();
+
+    mount_discovery(&server, "tok-1").await;
+    mount_full_query(&server).await;
+    events(&client, &mut state).await;
+
+    server.reset().await;
+    mount_discovery(&server, "tok-2").await;
+    mount_full_query(&server).await;
+    Mock::given(method("REPORT"))
+        .and(body_string_contains("sync-collection"))
+        .respond_with(ResponseTemplate::new(403))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    assert_eq!(events(&client, &mut state).await.len(), 4);
+}
+
+#[tokio::test]
+async fn test_ics_feed() {
+    let server = MockServer::start().await;
+    let http = reqwest::Client::new();
+    let url = format!("{}/team.ics", server.uri());
+    let mut state = FeedState::default();
+
+    Mock::given(method("GET"))
+        .and(path("/team.ics"))
+        .and(header("If-None-Match", "\"v1\""))
+        .respond_with(ResponseTemplate::new(304))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/team.ics"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("ETag", "\"v1\"")
+                .set_body_string(
+                    LUNCH.replace("BEGIN:VCALENDAR", "BEGIN:VCALENDAR\nX-WR-CALNAME:Team"),
+                ),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let ics = refresh_feed(&http, &url, &mut state).await.unwrap();
+    assert_eq!(ics.name.as_deref(), Some("Team"));
+    assert_eq!(to_events(ics, &window()).len(), 1);
+
+    let ics = refresh_feed(&http, &url, &mut state).await.unwrap();
+    assert_eq!(to_events(ics, &window()).len(), 1);
+}
@@ -0,0 +1,10 @@
+CREATE TABLE IF NOT EXISTS chat_conversations_new (
+  id TEXT PRIMARY KEY,
+  session_id TEXT,
+  -- NULL for conversations over the whole vault
+  user_id TEXT NOT NULL,
+  name TEXT,
+  created_at TEXT NOT NULL,
+  updated_at TEXT NOT NULL,
+  FOREIGN KEY (session_id) REFERENCES sessions(id) ON DELETE CASCADE
+);
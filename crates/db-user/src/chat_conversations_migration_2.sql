@@ -0,0 +1,18 @@
+INSERT INTO
+  chat_conversations_new (
+    id,
+    session_id,
+    user_id,
+    name,
+    created_at,
+    updated_at
+  )
+SELECT
+  id,
+  session_id,
+  user_id,
+  name,
+  created_at,
+  updated_at
+FROM
+  chat_conversations;
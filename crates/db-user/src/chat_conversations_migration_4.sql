@@ -0,0 +1,2 @@
+ALTER TABLE
+  chat_conversations_new RENAME TO chat_conversations;
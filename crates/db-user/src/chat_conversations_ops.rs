@@ -15,12 +15,15 @@ updated_at
                 ) VALUES (?, ?, ?, ?, ?, ?)
                 RETURNING *",
                 vec![
-                    conversation.id,
-                    conversation.session_id,
-                    conversation.user_id,
-                    conversation.name.unwrap_or_default(),
-                    conversation.created_at.to_rfc3339(),
-                    conversation.updated_at.to_rfc3339(),
+                    libsql::Value::Text(conversation.id),
+                    conversation
+                        .session_id
+                        .map(libsql::Value::Text)
+                        .unwrap_or(libsql::Value::Null),
+                    libsql::Value::Text(conversation.user_id),
+                    libsql::Value::Text(conversation.name.unwrap_or_default()),
+                    libsql::Value::Text(conversation.created_at.to_rfc3339()),
+                    libsql::Value::Text(conversation.updated_at.to_rfc3339()),
                 ],
             )
             .await?;
@@ -53,6 +56,29 @@ updated_at
         Ok(conversations)
     }
 
+    pub async fn list_vault_conversations(
+        &self,
+        user_id: impl Into<String>,
+    ) -> Result<Vec<ChatConversation>, crate::Error> {
+        let conn = self.conn()?;
+
+        let mut rows = conn
+            .query(
+                "SELECT * FROM chat_conversations 
+                WHERE user_id = ? AND session_id IS NULL 
+                ORDER BY updated_at DESC",
+                vec![user_id.into()],
+            )
+            .await?;
+
+        let mut conversations = Vec::new();
+        while let Some(row) = rows.next().await? {
+            let conversation: ChatConversation = libsql::de::from_row(&row)?;
+            conversations.push(conversation);
+        }
+        Ok(conversations)
+    }
+
     pub async fn get_conversation(
         &self,
         id: impl Into<String>,
@@ -74,3 +100,39 @@ updated_at
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{ChatConversation, tests::setup_db};
+
+    fn conversation(session_id: Option<&str>) -> ChatConversation {
+        ChatConversation {
+            id: uuid::Uuid::new_v4().to_string(),
+            session_id: session_id.map(str::to_string),
+            user_id: "user".to_string(),
+            name: None,
+            created_at: chrono::Utc::now(),
+            updated_at: chrono::Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_vault_conversations() {
+        let db = setup_db().await;
+
+        let vault = db.create_conversation(conversation(None)).await.unwrap();
+        assert_eq!(vault.session_id, None);
+
+        let listed = db.list_vault_conversations("user").await.unwrap();
+        assert_eq!(listed, vec![vault.clone()]);
+        assert!(
+            db.list_vault_conversations("other")
+                .await
+                .unwrap()
+                .is_empty()
+        );
+
+        let fetched = db.get_conversation(&vault.id).await.unwrap();
+        assert_eq!(fetched, Some(vault));
+    }
+}
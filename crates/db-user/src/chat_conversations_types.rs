@@ -3,7 +3,8 @@ use crate::user_common_derives;
 user_common_derives! {
     pub struct ChatConversation {
         pub id: String,
-        pub session_id: String,
+        /// `None` for conversations over the whole vault.
+        pub session_id: Option<String>,
         pub user_id: String,
         pub name: Option<String>,
         pub created_at: chrono::DateTime<chrono::Utc>,
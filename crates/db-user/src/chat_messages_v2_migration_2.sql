@@ -0,0 +1,20 @@
+INSERT INTO
+  chat_messages_v2_new (
+    id,
+    conversation_id,
+    role,
+    parts,
+    metadata,
+    created_at,
+    updated_at
+  )
+SELECT
+  id,
+  conversation_id,
+  role,
+  parts,
+  metadata,
+  created_at,
+  updated_at
+FROM
+  chat_messages_v2;
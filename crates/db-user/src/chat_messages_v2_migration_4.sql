@@ -0,0 +1,2 @@
+ALTER TABLE
+  chat_messages_v2_new RENAME TO chat_messages_v2;
@@ -139,7 +139,7 @@ impl std::ops::Deref for UserDatabase {
 }
 
 // Append only. Do not reorder.
-const MIGRATIONS: [&str; 27] = [
+const MIGRATIONS: [&str; 35] = [
     include_str!("./calendars_migration.sql"),
     include_str!("./configs_migration.sql"),
     include_str!("./events_migration.sql"),
@@ -167,6 +167,14 @@ const MIGRATIONS: [&str; 27] = [
     include_str!("./templates_migration_1.sql"),
     include_str!("./chat_conversations_migration.sql"),
     include_str!("./chat_messages_v2_migration.sql"),
+    include_str!("./chat_conversations_migration_1.sql"),
+    include_str!("./chat_conversations_migration_2.sql"),
+    include_str!("./chat_messages_v2_migration_1.sql"),
+    include_str!("./chat_messages_v2_migration_2.sql"),
+    include_str!("./chat_messages_v2_migration_3.sql"),
+    include_str!("./chat_conversations_migration_3.sql"),
+    include_str!("./chat_conversations_migration_4.sql"),
+    include_str!("./chat_messages_v2_migration_4.sql"),
 ];
 
 pub async fn migrate(db: &UserDatabase) -> Result<(), crate::Error> {
@@ -0,0 +1,267 @@
+use super::streams::{AudioStream, StreamDirection, StreamIntrospector};
+use crate::InstalledApp;
+
+struct MeetingApp {
+    id: &'static str,
+    name: &'static str,
+    binaries: &'static [&'static str],
+    app_names: &'static [&'static str],
+    sandbox_ids: &'static [&'static str],
+}
+
+const NATIVE_APPS: &[MeetingApp] = &[
+    MeetingApp {
+        id: "us.zoom.Zoom",
+        name: "Zoom",
+        binaries: &["zoom", "zoom.real"],
+        app_names: &["ZOOM VoiceEngine", "Zoom"],
+        sandbox_ids: &["us.zoom.Zoom"],
+    },
+    MeetingApp {
+        id: "com.microsoft.teams",
+        name: "Microsoft Teams",
+        binaries: &["teams", "teams-for-linux"],
+        app_names: &["Microsoft Teams", "teams-for-linux"],
+        sandbox_ids: &["com.github.IsmaelMartinez.teams_for_linux"],
+    },
+    MeetingApp {
+        id: "com.slack.Slack",
+        name: "Slack",
+        binaries: &["slack"],
+        app_names: &["Slack"],
+        sandbox_ids: &["com.slack.Slack"],
+    },
+    MeetingApp {
+        id: "com.cisco.webex",
+        name: "Webex",
+        binaries: &["webex", "CiscoCollabHost"],
+        app_names: &["Webex"],
+        sandbox_ids: &[],
+    },
+];
+
+struct WebMeeting {
+    id: &'static str,
+    name: &'static str,
+    hints: &'static [&'static str],
+}
+
+const WEB_MEETINGS: &[WebMeeting] = &[
+    WebMeeting {
+        id: "com.google.meet",
+        name: "Google Meet",
+        hints: &["meet.google.com", "google meet", "meet - ", "meet – "],
+    },
+    WebMeeting {
+        id: "com.microsoft.teams",
+        name: "Microsoft Teams",
+        hints: &["teams.microsoft.com", "teams.live.com", "microsoft teams"],
+    },
+    WebMeeting {
+        id: "us.zoom.Zoom",
+        name: "Zoom",
+        hints: &["app.zoom.us", "zoom.us/wc", "zoom meeting"],
+    },
+    WebMeeting {
+        id: "com.slack.Slack",
+        name: "Slack",
+        hints: &["app.slack.com", "huddle"],
+    },
+];
+
+const BROWSER_BINARIES: &[&str] = &[
+    "chrome",
+    "google-chrome",
+    "chromium",
+    "chromium-browser",
+    "brave",
+    "msedge",
+    "vivaldi",
+    "opera",
+    "firefox",
+    "firefox-esr",
+    "librewolf",
+    "zen",
+];
+
+/// Maps uncorked capture streams to the apps behind them, resolving known
+/// meeting apps (and meetings running in a browser) to stable ids.
+pub(super) fn mic_using_apps(introspector: &impl StreamIntrospector) -> Vec<InstalledApp> {
+    let streams = introspector.streams();
+
+    let mut apps: Vec<InstalledApp> = streams
+        .iter()
+        .filter(|s| s.direction == StreamDirection::Capture && !s.corked)
+        .filter_map(|capture| resolve(capture, &streams))
+        .collect();
+
+    apps.sort_by(|a, b| a.id.cmp(&b.id));
+    apps.dedup_by(|a, b| a.id == b.id);
+    apps.sort_by(|a, b| a.name.cmp(&b.name));
+    apps
+}
+
+fn resolve(capture: &AudioStream, streams: &[AudioStream]) -> Option<InstalledApp> {
+    if let Some(app) = NATIVE_APPS.iter().find(|app| matches_native(app, capture)) {
+        return Some(InstalledApp {
+            id: app.id.to_string(),
+            name: app.name.to_string(),
+        });
+    }
+
+    if is_browser(capture) {
+        // Browsers name the capture stream generically, but the call's playback
+        // stream from the same browser usually carries the tab title.
+        let meeting = streams
+            .iter()
+            .filter(|s| same_app(s, capture))
+            .filter_map(|s| s.media_name.as_deref())
+            .find_map(web_meeting_for);
+        if let Some(meeting) = meeting {
+            return Some(InstalledApp {
+                id: meeting.id.to_string(),
+                name: meeting.name.to_string(),
+            });
+        }
+    }
+
+    let name = capture
+        .app_name
+        .clone()
+        .or_else(|| capture.binary.clone())?;
+    let id = capture
+        .binary
+        .clone()
+        .or_else(|| capture.app_name.clone())?;
+    Some(InstalledApp { id, name })
+}
+
+fn matches_native(app: &MeetingApp, stream: &AudioStream) -> bool {
+    let binary_match = stream
+        .binary
+        .as_deref()
+        .is_some_and(|b| app.binaries.iter().any(|x| x.eq_ignore_ascii_case(b)));
+    let name_match = stream
+        .app_name
+        .as_deref()
+        .is_some_and(|n| app.app_names.iter().any(|x| x.eq_ignore_ascii_case(n)));
+    let sandbox_match = stream
+        .sandbox_id
+        .as_deref()
+        .is_some_and(|id| app.sandbox_ids.contains(&id));
+
+    binary_match || name_match || sandbox_match
+}
+
+fn is_browser(stream: &AudioStream) -> bool {
+    stream
+        .binary
+        .as_deref()
+        .is_some_and(|b| BROWSER_BINARIES.contains(&b.to_lowercase().as_str()))
+}
+
+fn same_app(a: &AudioStream, b: &AudioStream) -> bool {
+    match (a.process_id, b.process_id) {
+        (Some(x), Some(y)) if x == y => true,
+        _ => a.binary.is_some() && a.binary == b.binary,
+    }
+}
+
+fn web_meeting_for(media_name: &str) -> Option<&'static WebMeeting> {
+    let media_name = media_name.to_lowercase();
+    WEB_MEETINGS
+        .iter()
+        .find(|m| m.hints.iter().any(|hint| media_name.contains(hint)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct MockIntrospector(Vec<AudioStream>);
+
+    impl StreamIntrospector for MockIntrospector {
+        fn streams(&self) -> Vec<AudioStream> {
+            self.0.clone()
+        }
+    }
+
+    fn stream(direction: StreamDirection, binary: &str, app_name: &str) -> AudioStream {
+        AudioStream {
+            direction,
+            corked: false,
+            app_name: Some(app_name.to_string()),
+            binary: Some(binary.to_string()),
+            process_id: None,
+            sandbox_id: None,
+            media_name: None,
+        }
+    }
+
+    fn ids(introspector: &impl StreamIntrospector) -> Vec<String> {
+        mic_using_apps(introspector)
+            .into_iter()
+            .map(|app| app.id)
+            .collect()
+    }
+
+    #[test]
+    fn native_meeting_apps() {
+        let mut flatpak_slack = stream(StreamDirection::Capture, "electron", "Chromium");
+        flatpak_slack.sandbox_id = Some("com.slack.Slack".to_string());
+
+        let introspector = MockIntrospector(vec![
+            stream(StreamDirection::Capture, "zoom", "ZOOM VoiceEngine"),
+            stream(
+                StreamDirection::Capture,
+                "teams-for-linux",
+                "teams-for-linux",
+            ),
+            flatpak_slack,
+        ]);
+
+        assert_eq!(
+            ids(&introspector),
+            vec!["com.microsoft.teams", "com.slack.Slack", "us.zoom.Zoom"]
+        );
+    }
+
+    #[test]
+    fn browser_meeting_from_playback_title() {
+        let mut capture = stream(StreamDirection::Capture, "firefox", "Firefox");
+        capture.process_id = Some(42);
+        capture.media_name = Some("AudioCallbackDriver".to_string());
+
+        let mut playback = stream(StreamDirection::Playback, "firefox", "Firefox");
+        playback.process_id = Some(42);
+        playback.media_name = Some("Meet – abc-defg-hij".to_string());
+
+        let apps = mic_using_apps(&MockIntrospector(vec![capture, playback]));
+        assert_eq!(apps.len(), 1);
+        assert_eq!(apps[0].id, "com.google.meet");
+        assert_eq!(apps[0].name, "Google Meet");
+    }
+
+    #[test]
+    fn unknown_browser_tab_and_other_apps_pass_through() {
+        let introspector = MockIntrospector(vec![
+            stream(StreamDirection::Capture, "chrome", "Google Chrome"),
+            stream(StreamDirection::Capture, "audacity", "Audacity"),
+        ]);
+
+        assert_eq!(ids(&introspector), vec!["audacity", "chrome"]);
+    }
+
+    #[test]
+    fn ignores_corked_capture_and_playback_only() {
+        let mut corked = stream(StreamDirection::Capture, "zoom", "ZOOM VoiceEngine");
+        corked.corked = true;
+
+        let introspector = MockIntrospector(vec![
+            corked,
+            stream(StreamDirection::Playback, "slack", "Slack"),
+        ]);
+
+        assert!(ids(&introspector).is_empty());
+    }
+}
@@ -1,3 +1,6 @@
+mod meeting;
+mod streams;
+
 use super::InstalledApp;
 use std::collections::HashMap;
 use std::fs;
@@ -26,72 +29,7 @@ pub fn list_installed_apps() -> Vec<InstalledApp> {
 }
 
 pub fn list_mic_using_apps() -> Vec<InstalledApp> {
-    use libpulse_binding::context::{Context, FlagSet as ContextFlagSet};
-    use libpulse_binding::mainloop::standard::{IterateResult, Mainloop};
-    use std::cell::RefCell;
-    use std::rc::Rc;
-
-    let mut apps = Vec::new();
-
-    let mut mainloop = match Mainloop::new() {
-        Some(m) => m,
-        None => return apps,
-    };
-
-    let mut context = match Context::new(&mainloop, "hyprnote-detect") {
-        Some(c) => c,
-        None => return apps,
-    };
-
-    if context
-        .connect(None, ContextFlagSet::NOFLAGS, None)
-        .is_err()
-    {
-        return apps;
-    }
-
-    let apps_rc: Rc<RefCell<Vec<InstalledApp>>> = Rc::new(RefCell::new(Vec::new()));
-    let apps_clone = apps_rc.clone();
-
-    let introspect = context.introspect();
-    introspect.get_source_output_info_list(move |result| {
-        use libpulse_binding::callbacks::ListResult;
-
-        if let ListResult::Item(info) = result {
-            let props = &info.proplist;
-            let app_name = props
-                .get_str("application.name")
-                .or_else(|| props.get_str("application.process.binary"))
-                .unwrap_or_default();
-
-            let app_id = props
-                .get_str("application.process.binary")
-                .or_else(|| props.get_str("application.name"))
-                .unwrap_or_default();
-
-            if !app_name.is_empty() && !app_id.is_empty() {
-                apps_clone.borrow_mut().push(InstalledApp {
-                    id: app_id,
-                    name: app_name,
-                });
-            }
-        }
-    });
-
-    for _ in 0..100 {
-        match mainloop.iterate(false) {
-            IterateResult::Quit(_) | IterateResult::Err(_) => break,
-            IterateResult::Success(_) => {}
-        }
-    }
-
-    context.disconnect();
-
-    apps = apps_rc.borrow().clone();
-    apps.sort_by(|a, b| a.id.cmp(&b.id));
-    apps.dedup_by(|a, b| a.id == b.id);
-    apps.sort_by(|a, b| a.name.cmp(&b.name));
-    apps
+    meeting::mic_using_apps(&streams::PulseIntrospector)
 }
 
 fn get_desktop_file_dirs() -> Vec<PathBuf> {
@@ -0,0 +1,137 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use libpulse_binding::callbacks::ListResult;
+use libpulse_binding::context::{Context, FlagSet as ContextFlagSet, State};
+use libpulse_binding::mainloop::standard::{IterateResult, Mainloop};
+use libpulse_binding::proplist::Proplist;
+
+const MAX_ITERATIONS: usize = 200;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum StreamDirection {
+    Capture,
+    Playback,
+}
+
+/// A per-application audio stream, as seen by the sound server.
+///
+/// PipeWire exposes the same streams through `pipewire-pulse`, so this covers
+/// both PulseAudio and PipeWire desktops.
+#[derive(Debug, Clone)]
+pub(super) struct AudioStream {
+    pub direction: StreamDirection,
+    pub corked: bool,
+    pub app_name: Option<String>,
+    pub binary: Option<String>,
+    pub process_id: Option<u32>,
+    /// Flatpak or portal application id, e.g. `us.zoom.Zoom`.
+    pub sandbox_id: Option<String>,
+    /// Browsers put the tab title here.
+    pub media_name: Option<String>,
+}
+
+impl AudioStream {
+    fn from_proplist(direction: StreamDirection, corked: bool, props: &Proplist) -> Self {
+        let get = |key: &str| props.get_str(key).filter(|v| !v.is_empty());
+
+        Self {
+            direction,
+            corked,
+            app_name: get("application.name"),
+            binary: get("application.process.binary"),
+            process_id: get("application.process.id").and_then(|v| v.parse().ok()),
+            sandbox_id: get("pipewire.access.portal.app_id")
+                .or_else(|| get("application.id"))
+                .or_else(|| get("flatpak.app_id")),
+            media_name: get("media.name"),
+        }
+    }
+}
+
+pub(super) trait StreamIntrospector {
+    fn streams(&self) -> Vec<AudioStream>;
+}
+
+pub(super) struct PulseIntrospector;
+
+impl StreamIntrospector for PulseIntrospector {
+    fn streams(&self) -> Vec<AudioStream> {
+        let Some(mut mainloop) = Mainloop::new() else {
+            return Vec::new();
+        };
+        let Some(mut context) = Context::new(&mainloop, "hyprnote-detect") else {
+            return Vec::new();
+        };
+
+        if context
+            .connect(None, ContextFlagSet::NOFLAGS, None)
+            .is_err()
+        {
+            return Vec::new();
+        }
+
+        let mut ready = false;
+        for _ in 0..MAX_ITERATIONS {
+            match mainloop.iterate(true) {
+                IterateResult::Quit(_) | IterateResult::Err(_) => break,
+                IterateResult::Success(_) => {}
+            }
+            match context.get_state() {
+                State::Ready => {
+                    ready = true;
+                    break;
+                }
+                State::Failed | State::Terminated => break,
+                _ => {}
+            }
+        }
+        if !ready {
+            tracing::warn!("pulseaudio_context_not_ready");
+            return Vec::new();
+        }
+
+        let streams: Rc<RefCell<Vec<AudioStream>>> = Rc::new(RefCell::new(Vec::new()));
+        let pending = Rc::new(RefCell::new(2));
+
+        let introspect = context.introspect();
+        {
+            let streams = streams.clone();
+            let pending = pending.clone();
+            introspect.get_source_output_info_list(move |result| match result {
+                ListResult::Item(info) => streams.borrow_mut().push(AudioStream::from_proplist(
+                    StreamDirection::Capture,
+                    info.corked,
+                    &info.proplist,
+                )),
+                ListResult::End | ListResult::Error => *pending.borrow_mut() -= 1,
+            });
+        }
+        {
+            let streams = streams.clone();
+            let pending = pending.clone();
+            introspect.get_sink_input_info_list(move |result| match result {
+                ListResult::Item(info) => streams.borrow_mut().push(AudioStream::from_proplist(
+                    StreamDirection::Playback,
+                    info.corked,
+                    &info.proplist,
+                )),
+                ListResult::End | ListResult::Error => *pending.borrow_mut() -= 1,
+            });
+        }
+
+        for _ in 0..MAX_ITERATIONS {
+            if *pending.borrow() == 0 {
+                break;
+            }
+            match mainloop.iterate(true) {
+                IterateResult::Quit(_) | IterateResult::Err(_) => break,
+                IterateResult::Success(_) => {}
+            }
+        }
+
+        context.disconnect();
+
+        streams.take()
+    }
+}
@@ -4,7 +4,7 @@ use libpulse_binding::mainloop::threaded::Mainloop;
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 
-use crate::{BackgroundTask, DetectEvent};
+use crate::{BackgroundTask, DetectEvent, InstalledApp};
 
 #[derive(Default)]
 pub struct Detector {
@@ -15,6 +15,7 @@ struct DetectorState {
     last_state: bool,
     last_change: Instant,
     debounce_duration: Duration,
+    active_apps: Vec<InstalledApp>,
 }
 
 impl DetectorState {
@@ -23,6 +24,7 @@ impl DetectorState {
             last_state: false,
             last_change: Instant::now(),
             debounce_duration: Duration::from_millis(500),
+            active_apps: Vec::new(),
         }
     }
 
@@ -42,6 +44,56 @@ impl DetectorState {
     }
 }
 
+fn emit(callback: &Mutex<crate::DetectCallback>, event: DetectEvent) {
+    tracing::info!(event = ?event, "detected");
+    if let Ok(guard) = callback.lock() {
+        (*guard)(event);
+    }
+}
+
+// Re-reads the per-app capture streams after any source or source-output
+// change, so apps joining or leaving an already-running mic are reported too.
+fn refresh(callback: &Mutex<crate::DetectCallback>, state: &Mutex<DetectorState>) {
+    let mic_in_use = check_mic_in_use();
+    let apps = if mic_in_use {
+        crate::list_mic_using_apps()
+    } else {
+        Vec::new()
+    };
+
+    let Ok(mut state) = state.lock() else {
+        return;
+    };
+
+    if state.should_trigger(mic_in_use) {
+        if mic_in_use {
+            state.active_apps = apps.clone();
+            drop(state);
+            emit(callback, DetectEvent::MicStarted(apps));
+        } else {
+            let stopped = std::mem::take(&mut state.active_apps);
+            drop(state);
+            emit(callback, DetectEvent::MicStopped(stopped));
+        }
+        return;
+    }
+
+    if !state.last_state {
+        return;
+    }
+
+    let (started, stopped) = super::diff_apps(&state.active_apps, &apps);
+    state.active_apps = apps;
+    drop(state);
+
+    if !started.is_empty() {
+        emit(callback, DetectEvent::MicStarted(started));
+    }
+    if !stopped.is_empty() {
+        emit(callback, DetectEvent::MicStopped(stopped));
+    }
+}
+
 impl crate::Observer for Detector {
     fn start(&mut self, f: crate::DetectCallback) {
         self.background.start(|running, mut rx| async move {
@@ -67,36 +119,30 @@ impl crate::Observer for Detector {
                     }
                 };
 
-                let callback_for_subscribe = callback.clone();
-                let detector_state_for_subscribe = detector_state.clone();
+                let (refresh_tx, refresh_rx) = std::sync::mpsc::channel::<()>();
+                {
+                    let callback = callback.clone();
+                    let detector_state = detector_state.clone();
+                    std::thread::spawn(move || {
+                        while refresh_rx.recv().is_ok() {
+                            // Streams come and go in bursts; coalesce them.
+                            while refresh_rx.try_recv().is_ok() {}
+                            refresh(&callback, &detector_state);
+                        }
+                    });
+                }
 
                 context.set_subscribe_callback(Some(Box::new(
                     move |facility, operation, _index| {
-                        if let Some(pulse::context::subscribe::Facility::Source) = facility
-                            && let Some(pulse::context::subscribe::Operation::Changed) = operation
-                            && let Ok(mut state) = detector_state_for_subscribe.lock()
-                        {
-                            let mic_in_use = check_mic_in_use();
-
-                            if state.should_trigger(mic_in_use) {
-                                if mic_in_use {
-                                    let cb = callback_for_subscribe.clone();
-                                    std::thread::spawn(move || {
-                                        let apps = crate::list_mic_using_apps();
-                                        tracing::info!("mic_started_detected: {:?}", apps);
-
-                                        if let Ok(guard) = cb.lock() {
-                                            let event = DetectEvent::MicStarted(apps);
-                                            tracing::info!(event = ?event, "detected");
-                                            (*guard)(event);
-                                        }
-                                    });
-                                } else if let Ok(guard) = callback_for_subscribe.lock() {
-                                    let event = DetectEvent::MicStopped(vec![]);
-                                    tracing::info!(event = ?event, "detected");
-                                    (*guard)(event);
-                                }
-                            }
+                        use pulse::context::subscribe::{Facility, Operation};
+
+                        let relevant = matches!(
+                            (facility, operation),
+                            (Some(Facility::Source), Some(Operation::Changed))
+                                | (Some(Facility::SourceOutput), Some(_))
+                        );
+                        if relevant {
+                            let _ = refresh_tx.send(());
                         }
                     },
                 )));
@@ -133,7 +179,8 @@ impl crate::Observer for Detector {
                 tracing::info!("pulseaudio_context_connected");
 
                 context.subscribe(
-                    pulse::context::subscribe::InterestMaskSet::SOURCE,
+                    pulse::context::subscribe::InterestMaskSet::SOURCE
+                        | pulse::context::subscribe::InterestMaskSet::SOURCE_OUTPUT,
                     |success| {
                         if success {
                             tracing::info!("subscribed_to_pulseaudio_source_events");
@@ -146,6 +193,9 @@ impl crate::Observer for Detector {
                 let initial_mic_state = check_mic_in_use();
                 if let Ok(mut state) = detector_state.lock() {
                     state.last_state = initial_mic_state;
+                    if initial_mic_state {
+                        state.active_apps = crate::list_mic_using_apps();
+                    }
                 }
 
                 let _ = tx.blocking_send(());
@@ -18,6 +18,29 @@ pub struct MicDetector {
     inner: PlatformDetector,
 }
 
+#[cfg(any(target_os = "macos", target_os = "linux"))]
+fn diff_apps(
+    previous: &[crate::InstalledApp],
+    current: &[crate::InstalledApp],
+) -> (Vec<crate::InstalledApp>, Vec<crate::InstalledApp>) {
+    let previous_ids: std::collections::HashSet<_> = previous.iter().map(|app| &app.id).collect();
+    let current_ids: std::collections::HashSet<_> = current.iter().map(|app| &app.id).collect();
+
+    let started = current
+        .iter()
+        .filter(|app| !previous_ids.contains(&app.id))
+        .cloned()
+        .collect();
+
+    let stopped = previous
+        .iter()
+        .filter(|app| !current_ids.contains(&app.id))
+        .cloned()
+        .collect();
+
+    (started, stopped)
+}
+
 impl crate::Observer for MicDetector {
     fn start(&mut self, f: crate::DetectCallback) {
         self.inner.start(f);
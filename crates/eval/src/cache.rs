@@ -58,10 +58,7 @@ impl CachingClient {
         });
 
         let canonical = serde_json::to_string(&key_input).unwrap_or_default();
-        let mut hasher = Sha256::new();
-        hasher.update(canonical.as_bytes());
-        let result = hasher.finalize();
-        hex::encode(result)
+        sha256_hex(&canonical)
     }
 
     fn cache_path(&self, key: &str) -> Option<PathBuf> {
@@ -101,12 +98,17 @@ impl CachingClient {
             return Ok(cached);
         }
 
-        let response = self
+        let mut request = self
             .agent
             .post(url)
-            .header("Authorization", &format!("Bearer {}", api_key))
-            .header("Content-Type", "application/json")
-            .send(body)?;
+            .header("Content-Type", "application/json");
+
+        // Local OpenAI-compatible servers usually run without auth.
+        if !api_key.is_empty() {
+            request = request.header("Authorization", &format!("Bearer {}", api_key));
+        }
+
+        let response = request.send(body)?;
 
         let response_body = response.into_body().read_to_string()?;
 
@@ -116,6 +118,12 @@ impl CachingClient {
     }
 }
 
+pub(crate) fn sha256_hex(input: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(input.as_bytes());
+    hex::encode(hasher.finalize())
+}
+
 mod hex {
     pub fn encode(bytes: impl AsRef<[u8]>) -> String {
         bytes
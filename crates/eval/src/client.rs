@@ -24,6 +24,8 @@ pub enum ClientError {
     ModelError { model: String, message: String },
     #[error("Grader error for rubric '{rubric}': {message}")]
     GraderError { rubric: String, message: String },
+    #[error("No recorded fixture for request {0}; re-run with --record to capture it")]
+    MissingFixture(String),
 }
 
 impl ClientError {
@@ -116,18 +118,18 @@ pub struct JsonSchemaFormat {
     pub strict: bool,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ChatCompletionResponse {
     pub id: String,
     pub choices: Vec<ChatChoice>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ChatChoice {
     pub message: ChatChoiceMessage,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ChatChoiceMessage {
     pub content: String,
 }
@@ -209,41 +211,85 @@ impl OpenRouterClient {
             agent: Self::create_agent(),
         }
     }
+}
 
-    fn make_request(
+impl ChatCompleter for OpenRouterClient {
+    fn create_chat_completion(
         &self,
         request: &ChatCompletionRequest,
     ) -> Result<ChatCompletionResponse, ClientError> {
-        let url = format!("{}/chat/completions", OPENROUTER_BASE_URL);
-        let body = serde_json::to_string(request)?;
+        post_with_retry(&self.client, OPENROUTER_BASE_URL, &self.api_key, request)
+    }
+}
 
-        let response = self.client.post(&url, &self.api_key, &body)?;
-        let resp: ChatCompletionResponse = serde_json::from_str(&response)?;
+/// Client for any OpenAI-compatible endpoint, e.g. the local-llm server or Ollama.
+pub struct OpenAICompatibleClient {
+    base_url: String,
+    api_key: String,
+    client: CachingClient,
+}
 
-        if resp.choices.is_empty() {
-            return Err(ClientError::NoChoices);
+impl OpenAICompatibleClient {
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self::with_cache_dir(base_url, None)
+    }
+
+    pub fn with_cache_dir(base_url: impl Into<String>, cache_dir: Option<String>) -> Self {
+        Self {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            api_key: String::new(),
+            client: CachingClient::new(cache_dir),
         }
+    }
 
-        Ok(resp)
+    pub fn with_api_key(mut self, api_key: String) -> Self {
+        self.api_key = api_key;
+        self
     }
 }
 
-impl ChatCompleter for OpenRouterClient {
+impl ChatCompleter for OpenAICompatibleClient {
     fn create_chat_completion(
         &self,
         request: &ChatCompletionRequest,
     ) -> Result<ChatCompletionResponse, ClientError> {
-        let retry_strategy = ExponentialBuilder::default()
-            .with_min_delay(Duration::from_millis(DEFAULT_RETRY_INTERVAL_MS))
-            .with_max_times(5);
+        post_with_retry(&self.client, &self.base_url, &self.api_key, request)
+    }
+}
+
+fn post_chat_completion(
+    client: &CachingClient,
+    base_url: &str,
+    api_key: &str,
+    request: &ChatCompletionRequest,
+) -> Result<ChatCompletionResponse, ClientError> {
+    let url = format!("{}/chat/completions", base_url);
+    let body = serde_json::to_string(request)?;
 
-        let result = (|| self.make_request(request))
-            .retry(retry_strategy)
-            .when(|e| e.is_retryable())
-            .call();
+    let response = client.post(&url, api_key, &body)?;
+    let resp: ChatCompletionResponse = serde_json::from_str(&response)?;
 
-        result
+    if resp.choices.is_empty() {
+        return Err(ClientError::NoChoices);
     }
+
+    Ok(resp)
+}
+
+fn post_with_retry(
+    client: &CachingClient,
+    base_url: &str,
+    api_key: &str,
+    request: &ChatCompletionRequest,
+) -> Result<ChatCompletionResponse, ClientError> {
+    let retry_strategy = ExponentialBuilder::default()
+        .with_min_delay(Duration::from_millis(DEFAULT_RETRY_INTERVAL_MS))
+        .with_max_times(5);
+
+    (|| post_chat_completion(client, base_url, api_key, request))
+        .retry(retry_strategy)
+        .when(|e| e.is_retryable())
+        .call()
 }
 
 impl UsageResolver for OpenRouterClient {
@@ -28,6 +28,9 @@ pub const MODEL_CACHE_DURATION_SECS: u64 = 300;
 /// Pass rate threshold for multi-sample grading
 pub const PASS_RATE_THRESHOLD: f64 = 0.5;
 
+/// Minimum recall for reference-based graders to pass
+pub const REFERENCE_RECALL_THRESHOLD: f64 = 0.6;
+
 /// Confidence level for statistical calculations
 pub const CONFIDENCE_LEVEL: f64 = 0.95;
 
@@ -0,0 +1,164 @@
+use crate::{GraderSpec, RubricSpec, action_item_grader, entity_grader, section_coverage_grader};
+
+/// A hand-written note for a transcript, used as the reference for reference-based graders.
+#[derive(Debug, Clone)]
+pub struct ReferenceNote {
+    pub markdown: &'static str,
+    pub sections: &'static [&'static str],
+    pub action_items: &'static [&'static str],
+}
+
+/// A run of consecutive words from one speaker.
+#[derive(Debug, Clone)]
+pub struct SpeakerTurn {
+    pub speaker: String,
+    pub text: String,
+}
+
+/// A meeting transcript from `hypr-data` paired with its reference note.
+#[derive(Debug, Clone)]
+pub struct MeetingSample {
+    pub id: &'static str,
+    pub turns: Vec<SpeakerTurn>,
+    pub reference: ReferenceNote,
+}
+
+impl MeetingSample {
+    /// The transcript as one `speaker: text` line per turn.
+    pub fn transcript(&self) -> String {
+        self.turns
+            .iter()
+            .map(|turn| format!("{}: {}", turn.speaker, turn.text))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Metadata consumed by the reference-based graders.
+    pub fn meta(&self) -> serde_json::Value {
+        serde_json::json!({
+            "transcript": self.transcript(),
+            "sections": self.reference.sections,
+            "action_items": self.reference.action_items,
+        })
+    }
+}
+
+pub fn meeting_samples() -> Vec<MeetingSample> {
+    vec![
+        MeetingSample {
+            id: "sprint_planning",
+            turns: speaker_turns(hypr_data::english_8::WORDS_JSON),
+            reference: ReferenceNote {
+                markdown: include_str!("../assets/sprint_planning.md"),
+                sections: &[
+                    "Onboarding flow",
+                    "Analytics dashboard",
+                    "Mobile performance",
+                ],
+                action_items: &[
+                    "Simplify form validation and add progress indicators",
+                    "Ship an MVP of the analytics dashboard",
+                    "Implement lazy loading and optimize API calls",
+                ],
+            },
+        },
+        MeetingSample {
+            id: "sprint_review",
+            turns: speaker_turns(hypr_data::english_9::WORDS_JSON),
+            reference: ReferenceNote {
+                markdown: include_str!("../assets/sprint_review.md"),
+                sections: &[
+                    "Onboarding flow",
+                    "Analytics dashboard",
+                    "Mobile performance",
+                    "Retrospective",
+                ],
+                action_items: &[
+                    "Show the dashboard MVP to enterprise clients",
+                    "Carry the API gateway fix over to next sprint",
+                    "Plan the next sprint after client feedback",
+                ],
+            },
+        },
+    ]
+}
+
+/// Rubrics that score a note against its sample's reference, without calling a model.
+pub fn reference_rubrics() -> Vec<RubricSpec> {
+    vec![
+        RubricSpec {
+            name: "section_coverage".to_string(),
+            description: "Note has a section for each topic in the reference note".to_string(),
+            grader: GraderSpec::FuncWithMeta(section_coverage_grader),
+        },
+        RubricSpec {
+            name: "action_item_recall".to_string(),
+            description: "Note lists the action items from the reference note".to_string(),
+            grader: GraderSpec::FuncWithMeta(action_item_grader),
+        },
+        RubricSpec {
+            name: "no_hallucinated_entities".to_string(),
+            description: "Note only names people and things mentioned in the transcript"
+                .to_string(),
+            grader: GraderSpec::FuncWithMeta(entity_grader),
+        },
+    ]
+}
+
+/// Groups `Word2` JSON into speaker turns.
+fn speaker_turns(words_json: &str) -> Vec<SpeakerTurn> {
+    let words: Vec<serde_json::Value> = serde_json::from_str(words_json).unwrap_or_default();
+
+    let mut turns: Vec<SpeakerTurn> = Vec::new();
+    let mut current_speaker = None;
+
+    for word in &words {
+        let text = word["text"].as_str().unwrap_or_default();
+        let speaker = word["speaker"]["value"]["index"].as_u64();
+
+        match turns.last_mut() {
+            Some(turn) if speaker == current_speaker => {
+                turn.text.push(' ');
+                turn.text.push_str(text);
+            }
+            _ => {
+                turns.push(SpeakerTurn {
+                    speaker: speaker.map_or("Unknown".to_string(), |i| format!("Speaker {}", i)),
+                    text: text.to_string(),
+                });
+                current_speaker = speaker;
+            }
+        }
+    }
+
+    turns
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_speaker_turns() {
+        let transcript = meeting_samples()[0].transcript();
+        assert!(transcript.starts_with("Speaker 0: alright team let's kick off"));
+        assert!(transcript.contains("\nSpeaker 1: sure duck"));
+    }
+
+    #[test]
+    fn test_reference_notes_pass_reference_rubrics() {
+        for sample in meeting_samples() {
+            let meta: HashMap<String, serde_json::Value> =
+                serde_json::from_value(sample.meta()).unwrap();
+
+            for rubric in reference_rubrics() {
+                let GraderSpec::FuncWithMeta(grader) = rubric.grader else {
+                    unreachable!();
+                };
+                let (passed, reasoning) = grader(sample.reference.markdown, &meta);
+                assert!(passed, "{} / {}: {}", sample.id, rubric.name, reasoning);
+            }
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::cache::sha256_hex;
+use crate::{ChatCompleter, ChatCompletionRequest, ChatCompletionResponse, ClientError};
+
+#[derive(Serialize, Deserialize)]
+struct Fixture {
+    request: ChatCompletionRequest,
+    response: ChatCompletionResponse,
+}
+
+/// Replays recorded completions from a directory, so evals can run without network.
+///
+/// Fixtures are keyed by the full request, so any change to a prompt or template
+/// surfaces as [`ClientError::MissingFixture`] until it is re-recorded.
+pub struct FixtureClient {
+    dir: PathBuf,
+    recorder: Option<Arc<dyn ChatCompleter>>,
+}
+
+impl FixtureClient {
+    pub fn replay(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            recorder: None,
+        }
+    }
+
+    /// Forwards requests without a fixture to `inner` and records its responses.
+    pub fn record(dir: impl Into<PathBuf>, inner: Arc<dyn ChatCompleter>) -> Self {
+        Self {
+            dir: dir.into(),
+            recorder: Some(inner),
+        }
+    }
+
+    pub fn fixture_key(request: &ChatCompletionRequest) -> Result<String, ClientError> {
+        Ok(sha256_hex(&serde_json::to_string(request)?))
+    }
+
+    fn fixture_path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{}.json", key))
+    }
+}
+
+impl ChatCompleter for FixtureClient {
+    fn create_chat_completion(
+        &self,
+        request: &ChatCompletionRequest,
+    ) -> Result<ChatCompletionResponse, ClientError> {
+        let key = Self::fixture_key(request)?;
+        let path = self.fixture_path(&key);
+
+        if let Ok(content) = fs::read_to_string(&path) {
+            let fixture: Fixture = serde_json::from_str(&content)?;
+            return Ok(fixture.response);
+        }
+
+        let Some(inner) = &self.recorder else {
+            return Err(ClientError::MissingFixture(key));
+        };
+
+        let response = inner.create_chat_completion(request)?;
+        let fixture = Fixture {
+            request: request.clone(),
+            response,
+        };
+
+        fs::create_dir_all(&self.dir)?;
+        fs::write(&path, serde_json::to_string_pretty(&fixture)? + "\n")?;
+
+        Ok(fixture.response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{MockClient, MockResponse};
+
+    fn request(content: &str) -> ChatCompletionRequest {
+        ChatCompletionRequest {
+            model: "test-model".to_string(),
+            messages: vec![crate::ChatMessage {
+                role: "user".to_string(),
+                content: content.to_string(),
+            }],
+            temperature: None,
+            n: None,
+            response_format: None,
+        }
+    }
+
+    #[test]
+    fn test_record_then_replay() {
+        let dir = tempfile::tempdir().unwrap();
+        let mock = Arc::new(MockClient::with_response(MockResponse::new("# Notes")));
+
+        let recorder = FixtureClient::record(dir.path(), mock.clone());
+        recorder.create_chat_completion(&request("hello")).unwrap();
+        recorder.create_chat_completion(&request("hello")).unwrap();
+        assert_eq!(mock.call_count(), 1);
+
+        let replay = FixtureClient::replay(dir.path());
+        let response = replay.create_chat_completion(&request("hello")).unwrap();
+        assert_eq!(response.choices[0].message.content, "# Notes");
+    }
+
+    #[test]
+    fn test_replay_missing_fixture() {
+        let dir = tempfile::tempdir().unwrap();
+        let replay = FixtureClient::replay(dir.path());
+
+        let err = replay
+            .create_chat_completion(&request("changed prompt"))
+            .unwrap_err();
+        assert!(matches!(err, ClientError::MissingFixture(_)));
+    }
+}
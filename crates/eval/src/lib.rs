@@ -10,6 +10,8 @@
 //! - Response caching for reproducibility
 //! - Progress tracking
 //! - OpenRouter API integration
+//! - Local OpenAI-compatible endpoints and recorded fixtures for offline runs
+//! - Meeting transcript dataset with reference-based metrics
 //!
 //! ## Quick Start
 //!
@@ -40,7 +42,10 @@
 mod cache;
 mod client;
 mod config;
+mod dataset;
+mod fixture;
 mod format;
+mod metrics;
 mod models;
 mod rubric;
 mod stats;
@@ -55,17 +60,23 @@ pub use testing::*;
 // Re-export core types at root for convenience
 pub use client::{
     ChatCompleter, ChatCompletionRequest, ChatCompletionResponse, ChatMessage, ClientError,
-    GraderResponse, OpenRouterClient, Usage, UsageResolver, generate_chat_multi_with_generation_id,
-    generate_chat_with_generation_id, generate_structured_grader_response,
-    generate_structured_grader_response_multi, generate_text_multi_with_generation_id,
-    generate_text_with_generation_id,
+    GraderResponse, OpenAICompatibleClient, OpenRouterClient, Usage, UsageResolver,
+    generate_chat_multi_with_generation_id, generate_chat_with_generation_id,
+    generate_structured_grader_response, generate_structured_grader_response_multi,
+    generate_text_multi_with_generation_id, generate_text_with_generation_id,
 };
 pub use config::{Config, parse_config};
 pub use constants::*;
+pub use dataset::{MeetingSample, ReferenceNote, SpeakerTurn, meeting_samples, reference_rubrics};
+pub use fixture::FixtureClient;
 pub use format::{
     CheckResult, GradeResult, Rule, count_list_items_in_section, extract_text, find_headings,
     find_list_items, find_lists, first_inline_child, grade, split_by_headings,
 };
+pub use metrics::{
+    Recall, action_item_grader, action_item_recall, entity_grader, hallucinated_entities,
+    section_coverage, section_coverage_grader,
+};
 pub use models::{fetch_openrouter_models, filter_models};
 pub use rubric::{Score, grade_with_func, grade_with_llm, is_non_empty};
 pub use stats::{
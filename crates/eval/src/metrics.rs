@@ -0,0 +1,315 @@
+use std::collections::{HashMap, HashSet};
+
+use markdown::mdast::Node;
+use markdown::{ParseOptions, to_mdast};
+
+use crate::constants::REFERENCE_RECALL_THRESHOLD;
+use crate::{extract_text, find_headings, find_list_items, split_by_headings};
+
+const STOPWORDS: &[&str] = &[
+    "a", "an", "and", "are", "as", "at", "be", "by", "for", "from", "in", "into", "is", "it", "of",
+    "on", "or", "our", "over", "that", "the", "their", "this", "to", "up", "we", "with",
+];
+
+const ACTION_HEADINGS: &[&str] = &[
+    "action",
+    "next step",
+    "todo",
+    "to-do",
+    "follow-up",
+    "follow up",
+];
+
+/// How many reference items were found in the generated note.
+#[derive(Debug, Clone, Default)]
+pub struct Recall {
+    pub matched: Vec<String>,
+    pub missing: Vec<String>,
+}
+
+impl Recall {
+    pub fn score(&self) -> f64 {
+        let total = self.matched.len() + self.missing.len();
+        if total == 0 {
+            1.0
+        } else {
+            self.matched.len() as f64 / total as f64
+        }
+    }
+
+    fn summary(&self, label: &str) -> String {
+        let total = self.matched.len() + self.missing.len();
+        if self.missing.is_empty() {
+            format!("{}: {}/{}", label, self.matched.len(), total)
+        } else {
+            format!(
+                "{}: {}/{}. Missing: {}",
+                label,
+                self.matched.len(),
+                total,
+                self.missing.join("; ")
+            )
+        }
+    }
+}
+
+/// Checks which reference sections show up as headings in the note.
+pub fn section_coverage(md: &str, sections: &[impl AsRef<str>]) -> Recall {
+    let Ok(ast) = to_mdast(md, &ParseOptions::default()) else {
+        return recall(sections, &[]);
+    };
+
+    let headings: Vec<String> = find_headings(&ast)
+        .into_iter()
+        .map(|h| extract_text(&Node::Heading(h.clone())))
+        .collect();
+
+    recall(sections, &headings)
+}
+
+/// Checks which reference action items appear in the note's action item section,
+/// or anywhere in its lists if it has none.
+pub fn action_item_recall(md: &str, action_items: &[impl AsRef<str>]) -> Recall {
+    let Ok(ast) = to_mdast(md, &ParseOptions::default()) else {
+        return recall(action_items, &[]);
+    };
+
+    let action_sections: Vec<_> = split_by_headings(&ast)
+        .into_iter()
+        .filter(|section| match section.first() {
+            Some(heading @ Node::Heading(_)) => {
+                let text = extract_text(heading).to_lowercase();
+                ACTION_HEADINGS.iter().any(|keyword| text.contains(keyword))
+            }
+            _ => false,
+        })
+        .collect();
+
+    let items: Vec<String> = if action_sections.is_empty() {
+        list_item_texts(&ast)
+    } else {
+        action_sections
+            .iter()
+            .flat_map(|section| section.iter().flat_map(|node| list_item_texts(node)))
+            .collect()
+    };
+
+    recall(action_items, &items)
+}
+
+/// Returns capitalized names in the note's body that never appear in the transcript.
+///
+/// Sentence-initial words are skipped, since their capitalization says nothing
+/// about whether they are names.
+pub fn hallucinated_entities(md: &str, transcript: &str) -> Vec<String> {
+    let Ok(ast) = to_mdast(md, &ParseOptions::default()) else {
+        return vec![];
+    };
+
+    let known: HashSet<String> = words(transcript).collect();
+
+    let mut paragraphs = Vec::new();
+    collect_paragraphs(&ast, &mut paragraphs);
+
+    let mut result: Vec<String> = Vec::new();
+    for paragraph in paragraphs {
+        let text = extract_text(paragraph);
+        for sentence in text.split(['.', '!', '?', '\n']) {
+            let mut entity: Vec<&str> = Vec::new();
+            for (i, word) in sentence.split_whitespace().enumerate() {
+                let word = word.trim_matches(|c: char| !c.is_alphanumeric());
+                let capitalized = word.chars().next().is_some_and(char::is_uppercase);
+
+                if i > 0 && capitalized && word.chars().count() > 1 {
+                    entity.push(word);
+                    continue;
+                }
+
+                push_unknown(&mut result, &entity, &known);
+                entity.clear();
+            }
+            push_unknown(&mut result, &entity, &known);
+        }
+    }
+
+    result
+}
+
+pub fn section_coverage_grader(
+    output: &str,
+    meta: &HashMap<String, serde_json::Value>,
+) -> (bool, String) {
+    let result = section_coverage(output, &meta_strings(meta, "sections"));
+    (
+        result.score() >= REFERENCE_RECALL_THRESHOLD,
+        result.summary("sections covered"),
+    )
+}
+
+pub fn action_item_grader(
+    output: &str,
+    meta: &HashMap<String, serde_json::Value>,
+) -> (bool, String) {
+    let result = action_item_recall(output, &meta_strings(meta, "action_items"));
+    (
+        result.score() >= REFERENCE_RECALL_THRESHOLD,
+        result.summary("action items recalled"),
+    )
+}
+
+pub fn entity_grader(output: &str, meta: &HashMap<String, serde_json::Value>) -> (bool, String) {
+    let transcript = meta
+        .get("transcript")
+        .and_then(|v| v.as_str())
+        .unwrap_or_default();
+
+    let entities = hallucinated_entities(output, transcript);
+    if entities.is_empty() {
+        (true, "no entities missing from the transcript".to_string())
+    } else {
+        (false, format!("not in transcript: {}", entities.join(", ")))
+    }
+}
+
+fn meta_strings(meta: &HashMap<String, serde_json::Value>, key: &str) -> Vec<String> {
+    meta.get(key)
+        .and_then(|v| v.as_array())
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(|v| v.as_str().map(str::to_string))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn recall(expected: &[impl AsRef<str>], candidates: &[String]) -> Recall {
+    let candidates: Vec<HashSet<String>> = candidates.iter().map(|c| terms(c)).collect();
+
+    let mut result = Recall::default();
+    for item in expected {
+        let item = item.as_ref();
+        let wanted = terms(item);
+        let found = candidates.iter().any(|candidate| {
+            let overlap = wanted.intersection(candidate).count();
+            !wanted.is_empty() && overlap * 2 >= wanted.len()
+        });
+
+        if found {
+            result.matched.push(item.to_string());
+        } else {
+            result.missing.push(item.to_string());
+        }
+    }
+    result
+}
+
+fn list_item_texts(node: &Node) -> Vec<String> {
+    find_list_items(node)
+        .into_iter()
+        .map(|li| extract_text(&Node::ListItem(li.clone())))
+        .collect()
+}
+
+fn collect_paragraphs<'a>(node: &'a Node, result: &mut Vec<&'a Node>) {
+    if let Node::Paragraph(_) = node {
+        result.push(node);
+        return;
+    }
+    if let Some(children) = node.children() {
+        for child in children {
+            collect_paragraphs(child, result);
+        }
+    }
+}
+
+fn push_unknown(result: &mut Vec<String>, entity: &[&str], known: &HashSet<String>) {
+    if entity.is_empty() {
+        return;
+    }
+
+    let name = entity.join(" ");
+    let unknown = words(&name).any(|w| w.len() > 1 && !known.contains(&w));
+    if unknown && !result.contains(&name) {
+        result.push(name);
+    }
+}
+
+fn words(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(str::to_lowercase)
+}
+
+fn terms(text: &str) -> HashSet<String> {
+    words(text)
+        .filter(|w| !STOPWORDS.contains(&w.as_str()))
+        .map(|w| match w.strip_suffix('s') {
+            Some(stem) if stem.len() > 2 && !stem.ends_with('s') => stem.to_string(),
+            _ => w,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOTE: &str = "# Onboarding Flow\n\n- Drop-off at step three is 30%\n\n# Analytics Dashboard\n\n- Ship an MVP for enterprise clients\n\n# Action Items\n\n- Yujong to simplify form validation\n- Add progress indicators\n";
+
+    #[test]
+    fn test_section_coverage() {
+        let result = section_coverage(
+            NOTE,
+            &[
+                "Onboarding flow",
+                "Analytics dashboards",
+                "Mobile performance",
+            ],
+        );
+        assert_eq!(result.matched.len(), 2);
+        assert_eq!(result.missing, vec!["Mobile performance".to_string()]);
+    }
+
+    #[test]
+    fn test_action_item_recall_prefers_action_section() {
+        let result = action_item_recall(
+            NOTE,
+            &[
+                "Simplify the form validation",
+                "Add progress indicators to onboarding",
+                "Ship the analytics MVP",
+            ],
+        );
+        assert_eq!(result.matched.len(), 2);
+        assert_eq!(result.missing, vec!["Ship the analytics MVP".to_string()]);
+
+        let without_section = "# Notes\n\n- Ship the analytics MVP\n";
+        let result = action_item_recall(without_section, &["Ship the analytics MVP"]);
+        assert_eq!(result.score(), 1.0);
+    }
+
+    #[test]
+    fn test_hallucinated_entities() {
+        let transcript = "alright team yujong can you walk us through the user stories";
+        let md = "# Sprint\n\n- Led by Yujong and Sarah Connor.\n- Yujong owns onboarding\n";
+        assert_eq!(
+            hallucinated_entities(md, transcript),
+            vec!["Sarah Connor".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_graders_read_meta() {
+        let meta: HashMap<String, serde_json::Value> = serde_json::from_value(serde_json::json!({
+            "transcript": "yujong and duck planned onboarding and an mvp",
+            "sections": ["Onboarding flow", "Analytics dashboard"],
+            "action_items": ["Simplify form validation"],
+        }))
+        .unwrap();
+
+        assert!(section_coverage_grader(NOTE, &meta).0);
+        assert!(action_item_grader(NOTE, &meta).0);
+        assert!(entity_grader(NOTE, &meta).0);
+    }
+}
@@ -0,0 +1,123 @@
+use std::io::{Cursor, Write};
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use zip::CompressionMethod;
+use zip::write::SimpleFileOptions;
+
+use crate::session::{Note, SessionMeta, Transcript};
+use crate::transcript::segments;
+use crate::{Result, Session};
+
+const BUNDLE_VERSION: u32 = 1;
+const MANIFEST_FILE: &str = "manifest.json";
+const SESSION_FILE: &str = "session.json";
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct BundleOptions {
+    pub include_audio: bool,
+}
+
+impl Default for BundleOptions {
+    fn default() -> Self {
+        Self {
+            include_audio: true,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Manifest {
+    pub version: u32,
+    pub session_id: String,
+    pub title: String,
+    pub created_at: String,
+    pub files: Vec<String>,
+}
+
+/// The session as one self-describing document, independent of the vault layout.
+#[derive(Debug, Serialize)]
+pub struct PortableSession<'a> {
+    pub meta: &'a SessionMeta,
+    pub participants: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memo: Option<&'a str>,
+    pub notes: &'a [Note],
+    pub segments: Vec<PortableSegment>,
+    pub transcripts: &'a [Transcript],
+}
+
+#[derive(Debug, Serialize)]
+pub struct PortableSegment {
+    pub speaker: String,
+    pub start_ms: u64,
+    pub end_ms: u64,
+    pub text: String,
+}
+
+pub fn render_json(session: &Session) -> Result<String> {
+    let portable = PortableSession {
+        meta: &session.meta,
+        participants: session.participant_names(),
+        memo: session.memo.as_deref(),
+        notes: &session.notes,
+        segments: segments(session)
+            .into_iter()
+            .map(|segment| PortableSegment {
+                text: segment.text(),
+                speaker: segment.speaker,
+                start_ms: segment.start_ms,
+                end_ms: segment.end_ms,
+            })
+            .collect(),
+        transcripts: &session.transcripts,
+    };
+    Ok(serde_json::to_string_pretty(&portable)?)
+}
+
+/// A zip with `manifest.json`, the portable `session.json` and, optionally,
+/// the recording. Entries carry a fixed timestamp so identical sessions
+/// produce identical archives.
+pub fn render_zip(session: &Session, options: &BundleOptions) -> Result<Vec<u8>> {
+    let mut files = vec![(SESSION_FILE.to_string(), render_json(session)?.into_bytes())];
+
+    if options.include_audio
+        && let Some(path) = &session.audio
+        && let Some(name) = path.file_name()
+    {
+        files.push((name.to_string_lossy().to_string(), std::fs::read(path)?));
+    }
+
+    let manifest = Manifest {
+        version: BUNDLE_VERSION,
+        session_id: session.meta.id.clone(),
+        title: session.title().to_string(),
+        created_at: session.meta.created_at.clone(),
+        files: files.iter().map(|(name, _)| name.clone()).collect(),
+    };
+
+    let mut zip = zip::ZipWriter::new(Cursor::new(Vec::new()));
+    zip.start_file(MANIFEST_FILE, file_options())?;
+    zip.write_all(serde_json::to_string_pretty(&manifest)?.as_bytes())?;
+
+    for (name, bytes) in files {
+        // Recordings are already compressed.
+        let options = if name == SESSION_FILE {
+            file_options()
+        } else {
+            file_options().compression_method(CompressionMethod::Stored)
+        };
+        zip.start_file(name, options)?;
+        zip.write_all(&bytes)?;
+    }
+
+    Ok(zip.finish()?.into_inner())
+}
+
+pub(crate) fn file_options() -> SimpleFileOptions {
+    SimpleFileOptions::default()
+        .compression_method(CompressionMethod::Deflated)
+        .last_modified_time(zip::DateTime::default())
+        .unix_permissions(0o644)
+}
@@ -0,0 +1,416 @@
+use std::io::{Cursor, Write};
+
+use pulldown_cmark::{Event, HeadingLevel, Options, Parser, Tag, TagEnd};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+use crate::transcript::{format_duration, format_timestamp, segments};
+use crate::{Result, Session};
+
+const CONTENT_TYPES: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types"><Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/><Default Extension="xml" ContentType="application/xml"/><Override PartName="/word/document.xml" ContentType="application/vnd.openxmlformats-officedocument.wordprocessingml.document.main+xml"/><Override PartName="/word/styles.xml" ContentType="application/vnd.openxmlformats-officedocument.wordprocessingml.styles+xml"/></Types>"#;
+
+const ROOT_RELS: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="word/document.xml"/></Relationships>"#;
+
+const STYLES: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:styles xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:docDefaults><w:rPrDefault><w:rPr><w:rFonts w:ascii="Calibri" w:hAnsi="Calibri" w:eastAsia="Calibri" w:cs="Calibri"/><w:sz w:val="22"/></w:rPr></w:rPrDefault><w:pPrDefault><w:pPr><w:spacing w:after="160" w:line="276" w:lineRule="auto"/></w:pPr></w:pPrDefault></w:docDefaults><w:style w:type="paragraph" w:default="1" w:styleId="Normal"><w:name w:val="Normal"/></w:style><w:style w:type="paragraph" w:styleId="Title"><w:name w:val="Title"/><w:basedOn w:val="Normal"/><w:pPr><w:spacing w:after="80"/></w:pPr><w:rPr><w:b/><w:sz w:val="48"/></w:rPr></w:style><w:style w:type="paragraph" w:styleId="Subtitle"><w:name w:val="Subtitle"/><w:basedOn w:val="Normal"/><w:rPr><w:color w:val="6B7280"/></w:rPr></w:style><w:style w:type="paragraph" w:styleId="Heading1"><w:name w:val="heading 1"/><w:basedOn w:val="Normal"/><w:pPr><w:keepNext/><w:spacing w:before="360" w:after="120"/><w:outlineLvl w:val="0"/></w:pPr><w:rPr><w:b/><w:sz w:val="36"/></w:rPr></w:style><w:style w:type="paragraph" w:styleId="Heading2"><w:name w:val="heading 2"/><w:basedOn w:val="Normal"/><w:pPr><w:keepNext/><w:spacing w:before="240" w:after="120"/><w:outlineLvl w:val="1"/></w:pPr><w:rPr><w:b/><w:sz w:val="28"/></w:rPr></w:style><w:style w:type="paragraph" w:styleId="Heading3"><w:name w:val="heading 3"/><w:basedOn w:val="Normal"/><w:pPr><w:keepNext/><w:spacing w:before="200" w:after="80"/><w:outlineLvl w:val="2"/></w:pPr><w:rPr><w:b/><w:sz w:val="24"/></w:rPr></w:style><w:style w:type="paragraph" w:styleId="Quote"><w:name w:val="Quote"/><w:basedOn w:val="Normal"/><w:pPr><w:pBdr><w:left w:val="single" w:sz="12" w:space="8" w:color="D1D5DB"/></w:pBdr><w:ind w:left="360"/></w:pPr><w:rPr><w:color w:val="4B5563"/></w:rPr></w:style><w:style w:type="paragraph" w:styleId="Code"><w:name w:val="Code"/><w:basedOn w:val="Normal"/><w:pPr><w:shd w:val="clear" w:color="auto" w:fill="F3F4F6"/></w:pPr><w:rPr><w:rFonts w:ascii="Courier New" w:hAnsi="Courier New"/><w:sz w:val="20"/></w:rPr></w:style><w:style w:type="character" w:styleId="Hyperlink"><w:name w:val="Hyperlink"/><w:rPr><w:color w:val="2563EB"/><w:u w:val="single"/></w:rPr></w:style></w:styles>"#;
+
+const DOCUMENT_START: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"><w:body>"#;
+
+// A4 with 2.5cm margins, matching the PDF export.
+const DOCUMENT_END: &str = r#"<w:sectPr><w:pgSz w:w="11906" w:h="16838"/><w:pgMar w:top="1417" w:right="1417" w:bottom="1417" w:left="1417" w:header="708" w:footer="708" w:gutter="0"/></w:sectPr></w:body></w:document>"#;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct DocxOptions {
+    pub include_memo: bool,
+    pub include_transcript: bool,
+    pub timestamps: bool,
+}
+
+impl Default for DocxOptions {
+    fn default() -> Self {
+        Self {
+            include_memo: false,
+            include_transcript: true,
+            timestamps: true,
+        }
+    }
+}
+
+/// Renders a Word document. The package is written by hand: one document
+/// part, a stylesheet and relationships for any links.
+pub fn render(session: &Session, options: &DocxOptions) -> Result<Vec<u8>> {
+    let document = build_document(session, options);
+
+    let mut zip = zip::ZipWriter::new(Cursor::new(Vec::new()));
+    let file_options = crate::bundle::file_options();
+    for (name, content) in [
+        ("[Content_Types].xml", CONTENT_TYPES.to_string()),
+        ("_rels/.rels", ROOT_RELS.to_string()),
+        ("word/document.xml", document.body),
+        ("word/styles.xml", STYLES.to_string()),
+        ("word/_rels/document.xml.rels", document.relationships),
+    ] {
+        zip.start_file(name, file_options)?;
+        zip.write_all(content.as_bytes())?;
+    }
+
+    Ok(zip.finish()?.into_inner())
+}
+
+struct Document {
+    body: String,
+    relationships: String,
+}
+
+fn build_document(session: &Session, options: &DocxOptions) -> Document {
+    let mut writer = Writer::default();
+
+    writer.paragraph("Title", &[run(session.title(), Format::default())]);
+    let mut meta = vec![session.date()];
+    if let Some(duration) = session.duration_ms() {
+        meta.push(format_duration(duration));
+    }
+    writer.paragraph("Subtitle", &[run(&meta.join(" · "), Format::default())]);
+    if let Some(event) = session.event_title() {
+        writer.paragraph(
+            "Subtitle",
+            &[run(&format!("Meeting: {}", event), Format::default())],
+        );
+    }
+    let participants = session.participant_names();
+    if !participants.is_empty() {
+        writer.paragraph(
+            "Subtitle",
+            &[run(
+                &format!("Participants: {}", participants.join(", ")),
+                Format::default(),
+            )],
+        );
+    }
+
+    for note in &session.notes {
+        if let Some(title) = &note.title {
+            writer.paragraph("Heading1", &[run(title, Format::default())]);
+        }
+        writer.markdown(&note.content);
+    }
+
+    if options.include_memo
+        && let Some(memo) = &session.memo
+    {
+        writer.paragraph("Heading1", &[run("Memo", Format::default())]);
+        writer.markdown(memo);
+    }
+
+    let segments = segments(session);
+    if options.include_transcript && !segments.is_empty() {
+        writer.paragraph("Heading1", &[run("Transcript", Format::default())]);
+        for segment in segments {
+            let mut runs = Vec::new();
+            if options.timestamps {
+                runs.push(run(
+                    &format!("{}  ", format_timestamp(segment.start_ms)),
+                    Format::default(),
+                ));
+            }
+            runs.push(run(
+                &format!("{}: ", segment.speaker),
+                Format {
+                    bold: true,
+                    ..Default::default()
+                },
+            ));
+            runs.push(run(&segment.text(), Format::default()));
+            writer.paragraph("Normal", &runs);
+        }
+    }
+
+    let mut relationships = String::from(
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/styles" Target="styles.xml"/>"#,
+    );
+    for (index, url) in writer.links.iter().enumerate() {
+        relationships.push_str(&format!(
+            r#"<Relationship Id="{}" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/hyperlink" Target="{}" TargetMode="External"/>"#,
+            link_id(index),
+            escape(url)
+        ));
+    }
+    relationships.push_str("</Relationships>");
+
+    Document {
+        body: format!("{}{}{}", DOCUMENT_START, writer.body, DOCUMENT_END),
+        relationships,
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Format {
+    bold: bool,
+    italic: bool,
+    strike: bool,
+    code: bool,
+    hyperlink: bool,
+}
+
+fn run(text: &str, format: Format) -> String {
+    let mut properties = String::new();
+    if format.hyperlink {
+        properties.push_str(r#"<w:rStyle w:val="Hyperlink"/>"#);
+    }
+    if format.bold {
+        properties.push_str("<w:b/>");
+    }
+    if format.italic {
+        properties.push_str("<w:i/>");
+    }
+    if format.strike {
+        properties.push_str("<w:strike/>");
+    }
+    if format.code {
+        properties.push_str(r#"<w:rFonts w:ascii="Courier New" w:hAnsi="Courier New"/>"#);
+    }
+
+    let mut xml = String::from("<w:r>");
+    if !properties.is_empty() {
+        xml.push_str(&format!("<w:rPr>{}</w:rPr>", properties));
+    }
+    for (index, line) in text.split('\n').enumerate() {
+        if index > 0 {
+            xml.push_str("<w:br/>");
+        }
+        xml.push_str(&format!(
+            r#"<w:t xml:space="preserve">{}</w:t>"#,
+            escape(line)
+        ));
+    }
+    xml.push_str("</w:r>");
+    xml
+}
+
+fn link_id(index: usize) -> String {
+    format!("rId{}", index + 2)
+}
+
+/// Converts markdown to WordprocessingML paragraphs. Lists are rendered as
+/// indented paragraphs with a literal marker so no numbering part is needed.
+#[derive(Default)]
+struct Writer {
+    body: String,
+    links: Vec<String>,
+    paragraph: Option<String>,
+    format: Format,
+    link: Option<String>,
+    lists: Vec<Option<u64>>,
+    quote_depth: usize,
+    in_code_block: bool,
+}
+
+impl Writer {
+    fn paragraph(&mut self, style: &str, runs: &[String]) {
+        self.open(style, 0);
+        for run in runs {
+            self.push_run(run.clone());
+        }
+        self.close();
+    }
+
+    fn open(&mut self, style: &str, indent: usize) {
+        self.close();
+        let mut xml = format!(r#"<w:p><w:pPr><w:pStyle w:val="{}"/>"#, style);
+        if indent > 0 {
+            xml.push_str(&format!(
+                r#"<w:ind w:left="{}" w:hanging="360"/>"#,
+                indent * 360
+            ));
+        }
+        xml.push_str("</w:pPr>");
+        self.paragraph = Some(xml);
+    }
+
+    fn close(&mut self) {
+        if let Some(xml) = self.paragraph.take() {
+            self.body.push_str(&xml);
+            self.body.push_str("</w:p>");
+        }
+    }
+
+    fn push_run(&mut self, run: String) {
+        if self.paragraph.is_none() {
+            let style = self.block_style();
+            self.open(style, self.lists.len());
+        }
+        if let Some(paragraph) = self.paragraph.as_mut() {
+            paragraph.push_str(&run);
+        }
+    }
+
+    fn push_text(&mut self, text: &str) {
+        let format = Format {
+            hyperlink: self.link.is_some(),
+            ..self.format
+        };
+        let run = run(text, format);
+        match &self.link {
+            Some(id) => {
+                let run = format!(r#"<w:hyperlink r:id="{}">{}</w:hyperlink>"#, id, run);
+                self.push_run(run);
+            }
+            None => self.push_run(run),
+        }
+    }
+
+    fn block_style(&self) -> &'static str {
+        if self.in_code_block {
+            "Code"
+        } else if self.quote_depth > 0 {
+            "Quote"
+        } else {
+            "Normal"
+        }
+    }
+
+    fn markdown(&mut self, md: &str) {
+        let options =
+            Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS;
+
+        for event in Parser::new_ext(md, options) {
+            match event {
+                Event::Start(Tag::Heading { level, .. }) => {
+                    let style = match level {
+                        HeadingLevel::H1 => "Heading1",
+                        HeadingLevel::H2 => "Heading2",
+                        _ => "Heading3",
+                    };
+                    self.open(style, 0);
+                }
+                Event::End(TagEnd::Heading(_)) => self.close(),
+
+                // Tight list items carry their text directly; loose ones wrap
+                // it in paragraphs, the first of which continues the marker.
+                Event::Start(Tag::Paragraph) if self.paragraph.is_none() => {
+                    let style = self.block_style();
+                    self.open(style, self.lists.len());
+                }
+                Event::End(TagEnd::Paragraph) => self.close(),
+
+                Event::Start(Tag::BlockQuote(_)) => {
+                    self.close();
+                    self.quote_depth += 1;
+                }
+                Event::End(TagEnd::BlockQuote(_)) => {
+                    self.close();
+                    self.quote_depth = self.quote_depth.saturating_sub(1);
+                }
+
+                Event::Start(Tag::CodeBlock(_)) => {
+                    self.close();
+                    self.in_code_block = true;
+                }
+                Event::End(TagEnd::CodeBlock) => {
+                    if let Some(paragraph) = self.paragraph.as_mut()
+                        && paragraph.ends_with("<w:br/></w:r>")
+                    {
+                        // Drop the break left by the block's trailing newline.
+                        let trimmed = paragraph.len() - "<w:br/></w:r>".len();
+                        paragraph.truncate(trimmed);
+                        paragraph.push_str("</w:r>");
+                    }
+                    self.close();
+                    self.in_code_block = false;
+                }
+
+                Event::Start(Tag::List(start)) => {
+                    self.close();
+                    self.lists.push(start);
+                }
+                Event::End(TagEnd::List(_)) => {
+                    self.close();
+                    self.lists.pop();
+                }
+                Event::Start(Tag::Item) => {
+                    self.open("Normal", self.lists.len());
+                    let marker = match self.lists.last_mut() {
+                        Some(Some(number)) => {
+                            *number += 1;
+                            format!("{}. ", *number - 1)
+                        }
+                        _ => "• ".to_string(),
+                    };
+                    self.push_run(run(&marker, Format::default()));
+                }
+                Event::End(TagEnd::Item) => self.close(),
+                Event::TaskListMarker(checked) => {
+                    self.push_run(run(if checked { "☑ " } else { "☐ " }, Format::default()));
+                }
+
+                Event::Start(Tag::Strong) => self.format.bold = true,
+                Event::End(TagEnd::Strong) => self.format.bold = false,
+                Event::Start(Tag::Emphasis) => self.format.italic = true,
+                Event::End(TagEnd::Emphasis) => self.format.italic = false,
+                Event::Start(Tag::Strikethrough) => self.format.strike = true,
+                Event::End(TagEnd::Strikethrough) => self.format.strike = false,
+
+                Event::Start(Tag::Link { dest_url, .. }) => {
+                    self.link = Some(link_id(self.links.len()));
+                    self.links.push(dest_url.to_string());
+                }
+                Event::End(TagEnd::Link) => self.link = None,
+
+                // Table cells become tab-separated rows.
+                Event::Start(Tag::TableRow | Tag::TableHead) => self.open("Normal", 0),
+                Event::End(TagEnd::TableRow | TagEnd::TableHead) => self.close(),
+                Event::End(TagEnd::TableCell) => self.push_run("<w:r><w:tab/></w:r>".to_string()),
+
+                Event::Text(text) => self.push_text(&text),
+                Event::Code(text) => {
+                    let format = self.format;
+                    self.format.code = true;
+                    self.push_text(&text);
+                    self.format = format;
+                }
+                Event::SoftBreak => self.push_text(" "),
+                Event::HardBreak => self.push_run("<w:r><w:br/></w:r>".to_string()),
+
+                _ => {}
+            }
+        }
+        self.close();
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_markdown_to_paragraphs() {
+        let mut writer = Writer::default();
+        writer.markdown("## Plan\n\n- **Ship** it\n- See [docs](https://hyprnote.com)\n");
+
+        assert_eq!(writer.links, vec!["https://hyprnote.com".to_string()]);
+        assert!(
+            writer
+                .body
+                .starts_with(r#"<w:p><w:pPr><w:pStyle w:val="Heading2"/></w:pPr>"#)
+        );
+        assert_eq!(writer.body.matches("<w:p>").count(), 3);
+        assert!(
+            writer.body.contains(
+                r#"<w:r><w:rPr><w:b/></w:rPr><w:t xml:space="preserve">Ship</w:t></w:r>"#
+            )
+        );
+        assert!(writer.body.contains(r#"<w:hyperlink r:id="rId2"><w:r><w:rPr><w:rStyle w:val="Hyperlink"/></w:rPr><w:t xml:space="preserve">docs</w:t></w:r></w:hyperlink>"#));
+    }
+}
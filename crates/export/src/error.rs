@@ -0,0 +1,27 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("failed to read session: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("failed to parse JSON: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("failed to parse frontmatter: {0}")]
+    Frontmatter(#[from] hypr_frontmatter::Error),
+
+    #[error("failed to write archive: {0}")]
+    Zip(#[from] zip::result::ZipError),
+
+    #[error("not a session folder: {0}")]
+    NotASession(String),
+
+    #[error("Typst compile error: {0}")]
+    TypstCompile(String),
+
+    #[error("Typst PDF error: {0}")]
+    TypstPdf(String),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
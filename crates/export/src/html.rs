@@ -0,0 +1,130 @@
+use pulldown_cmark::{Options, Parser};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+use crate::Session;
+use crate::transcript::{format_duration, format_timestamp, segments};
+
+const STYLE: &str = r#"
+body { font-family: -apple-system, BlinkMacSystemFont, "Segoe UI", Helvetica, Arial, sans-serif; color: #111827; line-height: 1.6; max-width: 760px; margin: 0 auto; padding: 48px 24px; }
+header { border-bottom: 1px solid #e5e7eb; margin-bottom: 32px; padding-bottom: 16px; }
+h1 { font-size: 28px; margin: 0 0 8px; }
+h2 { font-size: 20px; margin-top: 32px; }
+a { color: #2563eb; }
+blockquote { border-left: 2px solid #d1d5db; background: #f9fafb; margin: 0; padding: 8px 16px; }
+table { border-collapse: collapse; }
+th, td { border: 1px solid #e5e7eb; padding: 4px 8px; }
+.meta { color: #6b7280; margin: 4px 0; }
+.participants { color: #6b7280; padding-left: 20px; }
+.transcript p { background: #f8fafc; border-radius: 4px; padding: 8px 12px; }
+.speaker { color: #2563eb; }
+.time { color: #6b7280; font-variant-numeric: tabular-nums; margin-right: 8px; }
+footer { color: #6b7280; font-size: 12px; margin-top: 48px; }
+"#;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct HtmlOptions {
+    pub include_memo: bool,
+    pub include_transcript: bool,
+    pub timestamps: bool,
+}
+
+impl Default for HtmlOptions {
+    fn default() -> Self {
+        Self {
+            include_memo: false,
+            include_transcript: true,
+            timestamps: true,
+        }
+    }
+}
+
+/// A single self-contained HTML page with inline styles.
+pub fn render(session: &Session, options: &HtmlOptions) -> String {
+    let title = escape(session.title());
+    let mut html = String::new();
+
+    html.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+    html.push_str("<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n");
+    html.push_str(&format!("<title>{}</title>\n", title));
+    html.push_str(&format!("<style>{}</style>\n", STYLE));
+    html.push_str("</head>\n<body>\n");
+
+    html.push_str(&format!("<header>\n<h1>{}</h1>\n", title));
+    let mut meta = vec![escape(&session.date())];
+    if let Some(duration) = session.duration_ms() {
+        meta.push(format_duration(duration));
+    }
+    html.push_str(&format!("<p class=\"meta\">{}</p>\n", meta.join(" · ")));
+    if let Some(event) = session.event_title() {
+        html.push_str(&format!(
+            "<p class=\"meta\">Meeting: {}</p>\n",
+            escape(event)
+        ));
+    }
+    let participants = session.participant_names();
+    if !participants.is_empty() {
+        html.push_str("<ul class=\"participants\">\n");
+        for name in participants {
+            html.push_str(&format!("<li>{}</li>\n", escape(&name)));
+        }
+        html.push_str("</ul>\n");
+    }
+    html.push_str("</header>\n");
+
+    for note in &session.notes {
+        html.push_str("<section class=\"note\">\n");
+        if let Some(title) = &note.title {
+            html.push_str(&format!("<h2>{}</h2>\n", escape(title)));
+        }
+        html.push_str(&markdown_to_html(&note.content));
+        html.push_str("</section>\n");
+    }
+
+    if options.include_memo
+        && let Some(memo) = &session.memo
+    {
+        html.push_str("<section class=\"memo\">\n<h2>Memo</h2>\n");
+        html.push_str(&markdown_to_html(memo));
+        html.push_str("</section>\n");
+    }
+
+    let segments = segments(session);
+    if options.include_transcript && !segments.is_empty() {
+        html.push_str("<section class=\"transcript\">\n<h2>Transcript</h2>\n");
+        for segment in segments {
+            html.push_str("<p>");
+            if options.timestamps {
+                html.push_str(&format!(
+                    "<span class=\"time\">{}</span>",
+                    format_timestamp(segment.start_ms)
+                ));
+            }
+            html.push_str(&format!(
+                "<strong class=\"speaker\">{}:</strong> {}</p>\n",
+                escape(&segment.speaker),
+                escape(&segment.text())
+            ));
+        }
+        html.push_str("</section>\n");
+    }
+
+    html.push_str("<footer>Exported from Hyprnote</footer>\n</body>\n</html>\n");
+    html
+}
+
+fn markdown_to_html(md: &str) -> String {
+    let options =
+        Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS;
+    let mut html = String::new();
+    pulldown_cmark::html::push_html(&mut html, Parser::new_ext(md, options));
+    html
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -0,0 +1,82 @@
+pub mod bundle;
+pub mod docx;
+pub mod error;
+pub mod html;
+pub mod pdf;
+pub mod session;
+pub mod subtitle;
+pub mod text;
+pub mod transcript;
+pub mod vault;
+
+pub use bundle::BundleOptions;
+pub use docx::DocxOptions;
+pub use error::{Error, Result};
+pub use html::HtmlOptions;
+pub use pdf::PdfOptions;
+pub use session::Session;
+pub use subtitle::SubtitleOptions;
+pub use text::TextOptions;
+pub use vault::{VaultFlavor, VaultOptions, export_vault};
+
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(tag = "format", content = "options", rename_all = "camelCase")]
+pub enum ExportFormat {
+    Docx(DocxOptions),
+    Html(HtmlOptions),
+    #[cfg(feature = "pdf")]
+    Pdf(PdfOptions),
+    Srt(SubtitleOptions),
+    Vtt(SubtitleOptions),
+    Text(TextOptions),
+    /// The portable `session.json` on its own.
+    Json,
+    /// A zip of `session.json`, a manifest and the recording.
+    Bundle(BundleOptions),
+}
+
+impl ExportFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Docx(_) => "docx",
+            ExportFormat::Html(_) => "html",
+            #[cfg(feature = "pdf")]
+            ExportFormat::Pdf(_) => "pdf",
+            ExportFormat::Srt(_) => "srt",
+            ExportFormat::Vtt(_) => "vtt",
+            ExportFormat::Text(_) => "txt",
+            ExportFormat::Json => "json",
+            ExportFormat::Bundle(_) => "zip",
+        }
+    }
+}
+
+pub fn export(session: &Session, format: &ExportFormat) -> Result<Vec<u8>> {
+    match format {
+        ExportFormat::Docx(options) => docx::render(session, options),
+        ExportFormat::Html(options) => Ok(html::render(session, options).into_bytes()),
+        #[cfg(feature = "pdf")]
+        ExportFormat::Pdf(options) => pdf::render(session, options),
+        ExportFormat::Srt(options) => Ok(subtitle::render_srt(session, options).into_bytes()),
+        ExportFormat::Vtt(options) => Ok(subtitle::render_vtt(session, options).into_bytes()),
+        ExportFormat::Text(options) => Ok(text::render(session, options).into_bytes()),
+        ExportFormat::Json => Ok(bundle::render_json(session)?.into_bytes()),
+        ExportFormat::Bundle(options) => bundle::render_zip(session, options),
+    }
+}
+
+/// Loads the session folder at `session_dir` and writes it to `output`.
+pub fn export_session(
+    session_dir: impl AsRef<Path>,
+    format: &ExportFormat,
+    output: impl AsRef<Path>,
+) -> Result<()> {
+    let session = Session::load(session_dir)?;
+    std::fs::write(output, export(&session, format)?)?;
+    Ok(())
+}
@@ -1,4 +1,4 @@
-use crate::PdfInput;
+use super::PdfInput;
 
 use super::markdown::markdown_to_typst;
 use super::utils::escape_typst_string;
@@ -0,0 +1,76 @@
+#[cfg(feature = "pdf")]
+mod compile;
+mod content;
+mod markdown;
+mod types;
+mod utils;
+#[cfg(feature = "pdf")]
+mod world;
+
+#[cfg(feature = "pdf")]
+pub use compile::compile_to_pdf;
+pub use content::build_typst_content;
+pub use types::*;
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+use crate::Session;
+use crate::transcript::{format_duration, segments};
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct PdfOptions {
+    pub include_cover: bool,
+    pub include_transcript: bool,
+}
+
+impl Default for PdfOptions {
+    fn default() -> Self {
+        Self {
+            include_cover: true,
+            include_transcript: true,
+        }
+    }
+}
+
+pub fn input(session: &Session, options: &PdfOptions) -> PdfInput {
+    let enhanced_md = session
+        .notes
+        .iter()
+        .map(|note| match &note.title {
+            Some(title) => format!("# {}\n\n{}", title, note.content.trim()),
+            None => note.content.trim().to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n");
+
+    let transcript = options.include_transcript.then(|| Transcript {
+        items: segments(session)
+            .into_iter()
+            .map(|segment| TranscriptItem {
+                text: segment.text(),
+                speaker: Some(segment.speaker),
+            })
+            .collect(),
+    });
+
+    let metadata = options.include_cover.then(|| PdfMetadata {
+        title: session.title().to_string(),
+        created_at: session.date(),
+        participants: session.participant_names(),
+        event_title: session.event_title().map(str::to_string),
+        duration: session.duration_ms().map(format_duration),
+    });
+
+    PdfInput {
+        enhanced_md,
+        transcript,
+        metadata,
+    }
+}
+
+#[cfg(feature = "pdf")]
+pub fn render(session: &Session, options: &PdfOptions) -> crate::Result<Vec<u8>> {
+    compile_to_pdf(&build_typst_content(&input(session, options)))
+}
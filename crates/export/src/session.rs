@@ -0,0 +1,318 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use hypr_frontmatter::Document;
+use serde::{Deserialize, Serialize};
+
+use crate::{Error, Result};
+
+const META_FILE: &str = "_meta.json";
+const MEMO_FILE: &str = "_memo.md";
+const TRANSCRIPT_FILE: &str = "transcript.json";
+pub(crate) const HUMANS_DIR: &str = "humans";
+const AUDIO_FILES: &[&str] = &["audio.wav", "audio.ogg", "audio.flac", "audio.opus"];
+
+/// `_meta.json`, as written by the desktop app's session persister.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SessionMeta {
+    pub id: String,
+    #[serde(default)]
+    pub user_id: String,
+    #[serde(default)]
+    pub created_at: String,
+    #[serde(default)]
+    pub title: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub event: Option<serde_json::Value>,
+    #[serde(default)]
+    pub participants: Vec<Participant>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Participant {
+    pub human_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Note {
+    #[serde(default)]
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub template_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub position: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_deserializing)]
+    pub content: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Transcript {
+    pub id: String,
+    #[serde(default)]
+    pub started_at: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ended_at: Option<f64>,
+    #[serde(default)]
+    pub words: Vec<Word>,
+    #[serde(default)]
+    pub speaker_hints: Vec<SpeakerHint>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Word {
+    pub id: String,
+    pub text: String,
+    pub start_ms: f64,
+    pub end_ms: f64,
+    #[serde(default)]
+    pub channel: i64,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SpeakerHint {
+    pub word_id: String,
+    #[serde(rename = "type")]
+    pub hint_type: String,
+    #[serde(deserialize_with = "hint_value")]
+    pub value: serde_json::Value,
+}
+
+#[derive(Deserialize)]
+struct TranscriptFile {
+    #[serde(default)]
+    transcripts: Vec<Transcript>,
+}
+
+#[derive(Deserialize)]
+struct HumanFrontmatter {
+    #[serde(default)]
+    name: Option<String>,
+}
+
+/// Everything an exporter needs from one `sessions/<id>/` folder.
+#[derive(Debug, Clone, Default)]
+pub struct Session {
+    pub dir: PathBuf,
+    pub meta: SessionMeta,
+    pub memo: Option<String>,
+    /// Enhanced notes, ordered by `position`.
+    pub notes: Vec<Note>,
+    pub transcripts: Vec<Transcript>,
+    pub audio: Option<PathBuf>,
+    /// Every other file in the folder, such as the recording or pasted images.
+    pub attachments: Vec<PathBuf>,
+    /// Human id to display name, for participants and assigned speakers.
+    pub humans: HashMap<String, String>,
+}
+
+impl Session {
+    /// Loads a session folder. Participant names are read from the vault's
+    /// `humans/` folder, found next to the enclosing `sessions/` folder.
+    pub fn load(dir: impl AsRef<Path>) -> Result<Self> {
+        let dir = dir.as_ref();
+        let meta_path = dir.join(META_FILE);
+        if !meta_path.is_file() {
+            return Err(Error::NotASession(dir.display().to_string()));
+        }
+        let meta: SessionMeta = serde_json::from_str(&std::fs::read_to_string(meta_path)?)?;
+
+        let mut memo = None;
+        let mut notes = Vec::new();
+        let mut entries: Vec<PathBuf> = std::fs::read_dir(dir)?
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| path.is_file())
+            .collect();
+        entries.sort();
+
+        let (markdown, others): (Vec<_>, Vec<_>) = entries
+            .into_iter()
+            .partition(|path| path.extension().is_some_and(|ext| ext == "md"));
+        let attachments = others
+            .into_iter()
+            .filter(|path| {
+                path.file_name()
+                    .is_some_and(|name| name != META_FILE && name != TRANSCRIPT_FILE)
+            })
+            .collect();
+
+        for path in markdown {
+            let raw = std::fs::read_to_string(&path)?;
+            if path.file_name().is_some_and(|name| name == MEMO_FILE) {
+                memo = Some(read_content(&raw)).filter(|c| !c.trim().is_empty());
+                continue;
+            }
+
+            let note = match Document::<Note>::from_str(&raw) {
+                Ok(doc) => Note {
+                    content: doc.content,
+                    ..doc.frontmatter
+                },
+                Err(_) => Note {
+                    content: raw,
+                    ..Default::default()
+                },
+            };
+            let title = note.title.clone().or_else(|| {
+                path.file_stem()
+                    .map(|stem| stem.to_string_lossy().to_string())
+            });
+            notes.push(Note { title, ..note });
+        }
+        notes.sort_by_key(|note| note.position.unwrap_or(i64::MAX));
+
+        let transcript_path = dir.join(TRANSCRIPT_FILE);
+        let transcripts = if transcript_path.is_file() {
+            let file: TranscriptFile =
+                serde_json::from_str(&std::fs::read_to_string(transcript_path)?)?;
+            file.transcripts
+        } else {
+            Vec::new()
+        };
+
+        let audio = AUDIO_FILES
+            .iter()
+            .map(|name| dir.join(name))
+            .find(|path| path.is_file());
+
+        let mut session = Self {
+            dir: dir.to_path_buf(),
+            meta,
+            memo,
+            notes,
+            transcripts,
+            audio,
+            attachments,
+            humans: HashMap::new(),
+        };
+        if let Some(humans_dir) = vault_base(dir).map(|base| base.join(HUMANS_DIR)) {
+            session.humans = load_humans(&humans_dir, &session.human_ids());
+        }
+        Ok(session)
+    }
+
+    pub fn title(&self) -> &str {
+        if self.meta.title.trim().is_empty() {
+            "Untitled"
+        } else {
+            self.meta.title.trim()
+        }
+    }
+
+    /// `created_at` as a human-readable date, e.g. `March 1, 2024`.
+    pub fn date(&self) -> String {
+        chrono::DateTime::parse_from_rfc3339(&self.meta.created_at)
+            .map(|dt| dt.format("%B %-d, %Y").to_string())
+            .unwrap_or_else(|_| self.meta.created_at.clone())
+    }
+
+    pub fn event_title(&self) -> Option<&str> {
+        self.meta
+            .event
+            .as_ref()
+            .and_then(|event| event.get("title"))
+            .and_then(|title| title.as_str())
+            .filter(|title| !title.trim().is_empty())
+    }
+
+    /// Display names of participants, in `_meta.json` order.
+    pub fn participant_names(&self) -> Vec<String> {
+        self.meta
+            .participants
+            .iter()
+            .filter_map(|p| self.humans.get(&p.human_id).cloned())
+            .collect()
+    }
+
+    /// Milliseconds between the first transcript starting and the last word.
+    pub fn duration_ms(&self) -> Option<u64> {
+        let first = self.transcripts.first()?.started_at;
+        self.transcripts
+            .iter()
+            .filter_map(|t| t.words.last().map(|w| t.started_at - first + w.end_ms))
+            .reduce(f64::max)
+            .map(|ms| ms.max(0.0) as u64)
+    }
+
+    fn human_ids(&self) -> Vec<String> {
+        let assigned = self
+            .transcripts
+            .iter()
+            .flat_map(|t| &t.speaker_hints)
+            .filter_map(|hint| hint.human_id().map(str::to_string));
+
+        std::iter::once(self.meta.user_id.clone())
+            .filter(|id| !id.is_empty())
+            .chain(self.meta.participants.iter().map(|p| p.human_id.clone()))
+            .chain(assigned)
+            .collect()
+    }
+}
+
+impl SpeakerHint {
+    pub(crate) fn human_id(&self) -> Option<&str> {
+        if self.hint_type != "user_speaker_assignment" {
+            return None;
+        }
+        self.value.get("human_id")?.as_str()
+    }
+
+    pub(crate) fn speaker_index(&self) -> Option<u64> {
+        if self.hint_type != "provider_speaker_index" {
+            return None;
+        }
+        self.value.get("speaker_index")?.as_u64()
+    }
+}
+
+// Hints are stored either as an object or as its JSON-encoded string.
+fn hint_value<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<serde_json::Value, D::Error> {
+    Ok(match serde_json::Value::deserialize(deserializer)? {
+        serde_json::Value::String(raw) => {
+            serde_json::from_str(&raw).unwrap_or(serde_json::Value::String(raw))
+        }
+        value => value,
+    })
+}
+
+fn read_content(raw: &str) -> String {
+    match Document::<serde_json::Value>::from_str(raw) {
+        Ok(doc) => doc.content,
+        Err(_) => raw.to_string(),
+    }
+}
+
+// `<vault>/sessions/[folders/]<id>` -> `<vault>`
+fn vault_base(dir: &Path) -> Option<&Path> {
+    dir.ancestors()
+        .find(|path| path.file_name().is_some_and(|name| name == "sessions"))
+        .and_then(Path::parent)
+}
+
+fn load_humans(dir: &Path, ids: &[String]) -> HashMap<String, String> {
+    let mut humans = HashMap::new();
+    for id in ids {
+        if humans.contains_key(id) {
+            continue;
+        }
+        let Ok(raw) = std::fs::read_to_string(dir.join(format!("{}.md", id))) else {
+            continue;
+        };
+        let name = Document::<HumanFrontmatter>::from_str(&raw)
+            .ok()
+            .and_then(|doc| doc.frontmatter.name)
+            .filter(|name| !name.trim().is_empty());
+        if let Some(name) = name {
+            humans.insert(id.clone(), name);
+        }
+    }
+    humans
+}
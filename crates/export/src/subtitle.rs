@@ -0,0 +1,190 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+use crate::Session;
+use crate::transcript::{Segment, TimedWord, join_words, segments};
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct SubtitleOptions {
+    /// Label cues with the speaker: a `<v>` tag in WebVTT, a `Name: ` prefix in SRT.
+    pub speakers: bool,
+    pub max_chars_per_cue: u32,
+    pub max_cue_duration_ms: u32,
+}
+
+impl Default for SubtitleOptions {
+    fn default() -> Self {
+        Self {
+            speakers: true,
+            max_chars_per_cue: 84,
+            max_cue_duration_ms: 7000,
+        }
+    }
+}
+
+/// A single subtitle cue. `speaker`, when set, labels the cue in the output.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cue {
+    pub speaker: Option<String>,
+    pub start_ms: u64,
+    pub end_ms: u64,
+    pub text: String,
+}
+
+pub fn render_srt(session: &Session, options: &SubtitleOptions) -> String {
+    write_srt(&cues(session, options))
+}
+
+pub fn render_vtt(session: &Session, options: &SubtitleOptions) -> String {
+    write_vtt(&cues(session, options))
+}
+
+pub fn write_srt(cues: &[Cue]) -> String {
+    let mut output = String::new();
+
+    for (index, cue) in cues.iter().enumerate() {
+        output.push_str(&format!(
+            "{}\n{} --> {}\n",
+            index + 1,
+            format_time(cue.start_ms, ','),
+            format_time(cue.end_ms, ',')
+        ));
+        if let Some(speaker) = &cue.speaker {
+            output.push_str(&format!("{}: ", speaker));
+        }
+        output.push_str(&cue.text);
+        output.push_str("\n\n");
+    }
+
+    output
+}
+
+pub fn write_vtt(cues: &[Cue]) -> String {
+    let mut output = String::from("WEBVTT\n\n");
+
+    for cue in cues {
+        output.push_str(&format!(
+            "{} --> {}\n",
+            format_time(cue.start_ms, '.'),
+            format_time(cue.end_ms, '.')
+        ));
+        if let Some(speaker) = &cue.speaker {
+            output.push_str(&format!("<v {}>", escape_vtt(speaker)));
+        }
+        output.push_str(&escape_vtt(&cue.text));
+        output.push_str("\n\n");
+    }
+
+    output
+}
+
+fn cues(session: &Session, options: &SubtitleOptions) -> Vec<Cue> {
+    segments(session)
+        .into_iter()
+        .flat_map(|segment| split_segment(segment, options))
+        .collect()
+}
+
+// Breaks a segment into cues short enough to read, never splitting a word.
+fn split_segment(segment: Segment, options: &SubtitleOptions) -> Vec<Cue> {
+    let mut cues = Vec::new();
+    let mut current: Vec<TimedWord> = Vec::new();
+    let speaker = options.speakers.then_some(segment.speaker.as_str());
+
+    for word in segment.words {
+        if let Some(first) = current.first() {
+            let chars = join_words(&current).chars().count() + 1 + word.text.trim().chars().count();
+            let duration = word.end_ms.saturating_sub(first.start_ms);
+            if chars > options.max_chars_per_cue as usize
+                || duration > options.max_cue_duration_ms as u64
+            {
+                cues.push(to_cue(speaker, std::mem::take(&mut current)));
+            }
+        }
+        current.push(word);
+    }
+    if !current.is_empty() {
+        cues.push(to_cue(speaker, current));
+    }
+
+    cues
+}
+
+fn to_cue(speaker: Option<&str>, words: Vec<TimedWord>) -> Cue {
+    Cue {
+        speaker: speaker.map(str::to_string),
+        start_ms: words.first().map(|w| w.start_ms).unwrap_or_default(),
+        end_ms: words.iter().map(|w| w.end_ms).max().unwrap_or_default(),
+        text: join_words(&words),
+    }
+}
+
+fn format_time(ms: u64, separator: char) -> String {
+    let seconds = ms / 1000;
+    format!(
+        "{:02}:{:02}:{:02}{}{:03}",
+        seconds / 3600,
+        (seconds / 60) % 60,
+        seconds % 60,
+        separator,
+        ms % 1000
+    )
+}
+
+fn escape_vtt(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn word(text: &str, start_ms: u64) -> TimedWord {
+        TimedWord {
+            text: format!(" {}", text),
+            start_ms,
+            end_ms: start_ms + 500,
+        }
+    }
+
+    #[test]
+    fn test_split_segment() {
+        let segment = Segment {
+            speaker: "Jane".to_string(),
+            start_ms: 0,
+            end_ms: 9500,
+            words: vec![
+                word("one", 0),
+                word("two", 500),
+                word("three", 1000),
+                word("four", 9000),
+            ],
+        };
+        let options = SubtitleOptions {
+            max_chars_per_cue: 9,
+            ..Default::default()
+        };
+
+        let texts: Vec<_> = split_segment(segment, &options)
+            .into_iter()
+            .map(|cue| (cue.start_ms, cue.end_ms, cue.text))
+            .collect();
+        assert_eq!(
+            texts,
+            vec![
+                (0, 1000, "one two".to_string()),
+                (1000, 1500, "three".to_string()),
+                (9000, 9500, "four".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_format_time() {
+        assert_eq!(format_time(3_723_045, ','), "01:02:03,045");
+        assert_eq!(format_time(1_500, '.'), "00:00:01.500");
+    }
+}
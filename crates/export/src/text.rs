@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+use crate::Session;
+use crate::transcript::{format_timestamp, segments};
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct TextOptions {
+    pub timestamps: bool,
+    pub speakers: bool,
+}
+
+impl Default for TextOptions {
+    fn default() -> Self {
+        Self {
+            timestamps: true,
+            speakers: true,
+        }
+    }
+}
+
+/// Plain-text transcript, one paragraph per speaker segment.
+pub fn render(session: &Session, options: &TextOptions) -> String {
+    let mut output = String::new();
+
+    for segment in segments(session) {
+        if !output.is_empty() {
+            output.push('\n');
+        }
+        if options.timestamps {
+            output.push_str(&format!("[{}] ", format_timestamp(segment.start_ms)));
+        }
+        if options.speakers {
+            output.push_str(&format!("{}: ", segment.speaker));
+        }
+        output.push_str(&segment.text());
+        output.push('\n');
+    }
+
+    output
+}
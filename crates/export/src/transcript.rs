@@ -0,0 +1,265 @@
+use std::collections::HashMap;
+
+use crate::Session;
+
+// Same default as the desktop app's segment builder.
+const MAX_GAP_MS: u64 = 2000;
+
+const DIRECT_MIC_CHANNEL: i64 = 0;
+const REMOTE_PARTY_CHANNEL: i64 = 1;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimedWord {
+    pub text: String,
+    pub start_ms: u64,
+    pub end_ms: u64,
+}
+
+/// A run of words from one speaker, as shown in the transcript view.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Segment {
+    pub speaker: String,
+    pub start_ms: u64,
+    pub end_ms: u64,
+    pub words: Vec<TimedWord>,
+}
+
+impl Segment {
+    pub fn text(&self) -> String {
+        join_words(&self.words)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct SpeakerKey {
+    channel: i64,
+    speaker_index: Option<u64>,
+    human_id: Option<String>,
+}
+
+/// Groups every transcript in the session into speaker segments, with times
+/// relative to the start of the first transcript.
+pub fn segments(session: &Session) -> Vec<Segment> {
+    let Some(origin) = session.transcripts.first().map(|t| t.started_at) else {
+        return Vec::new();
+    };
+
+    let mut words: Vec<(SpeakerKey, TimedWord)> = Vec::new();
+    for transcript in &session.transcripts {
+        let offset = (transcript.started_at - origin).max(0.0);
+
+        let mut humans: HashMap<&str, &str> = HashMap::new();
+        let mut indices: HashMap<&str, u64> = HashMap::new();
+        for hint in &transcript.speaker_hints {
+            if let Some(human_id) = hint.human_id() {
+                humans.insert(&hint.word_id, human_id);
+            } else if let Some(index) = hint.speaker_index() {
+                indices.insert(&hint.word_id, index);
+            }
+        }
+
+        // An assignment on any word of a provider speaker names all of its words.
+        let mut humans_by_index: HashMap<(i64, u64), &str> = HashMap::new();
+        for word in &transcript.words {
+            if let (Some(index), Some(human_id)) =
+                (indices.get(word.id.as_str()), humans.get(word.id.as_str()))
+            {
+                humans_by_index.insert((word.channel, *index), human_id);
+            }
+        }
+
+        for word in &transcript.words {
+            let speaker_index = indices.get(word.id.as_str()).copied();
+            let human_id = humans.get(word.id.as_str()).copied().or_else(|| {
+                speaker_index.and_then(|index| humans_by_index.get(&(word.channel, index)).copied())
+            });
+
+            words.push((
+                SpeakerKey {
+                    channel: word.channel,
+                    speaker_index,
+                    human_id: human_id.map(str::to_string),
+                },
+                TimedWord {
+                    text: word.text.clone(),
+                    start_ms: (offset + word.start_ms).max(0.0).round() as u64,
+                    end_ms: (offset + word.end_ms).max(0.0).round() as u64,
+                },
+            ));
+        }
+    }
+    words.sort_by_key(|(_, word)| word.start_ms);
+
+    let mut segments: Vec<(SpeakerKey, Segment)> = Vec::new();
+    for (key, word) in words {
+        if let Some((last_key, last)) = segments.last_mut()
+            && *last_key == key
+            && word.start_ms.saturating_sub(last.end_ms) <= MAX_GAP_MS
+        {
+            last.end_ms = last.end_ms.max(word.end_ms);
+            last.words.push(word);
+            continue;
+        }
+
+        segments.push((
+            key.clone(),
+            Segment {
+                speaker: render_label(session, &key),
+                start_ms: word.start_ms,
+                end_ms: word.end_ms,
+                words: vec![word],
+            },
+        ));
+    }
+
+    segments.into_iter().map(|(_, segment)| segment).collect()
+}
+
+// Mirrors `SegmentKey.renderLabel` in the desktop app.
+fn render_label(session: &Session, key: &SpeakerKey) -> String {
+    if let Some(name) = key.human_id.as_ref().and_then(|id| session.humans.get(id)) {
+        return name.clone();
+    }
+
+    let self_id = &session.meta.user_id;
+    if key.channel == DIRECT_MIC_CHANNEL && !self_id.is_empty() {
+        return session
+            .humans
+            .get(self_id)
+            .cloned()
+            .unwrap_or_else(|| "You".to_string());
+    }
+
+    if let Some(index) = key.speaker_index {
+        return format!("Speaker {}", index + 1);
+    }
+
+    let channel = match key.channel {
+        DIRECT_MIC_CHANNEL => "A",
+        REMOTE_PARTY_CHANNEL => "B",
+        _ => "C",
+    };
+    format!("Speaker {}", channel)
+}
+
+/// Joins transcript words, which usually carry their own leading space.
+pub fn join_words(words: &[TimedWord]) -> String {
+    let mut text = String::new();
+    for word in words {
+        if !text.is_empty() && !word.text.starts_with(char::is_whitespace) {
+            text.push(' ');
+        }
+        text.push_str(&word.text);
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// `3723000` -> `01:02:03`
+pub fn format_timestamp(ms: u64) -> String {
+    let seconds = ms / 1000;
+    format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3600,
+        (seconds / 60) % 60,
+        seconds % 60
+    )
+}
+
+/// `"1h 2m"`, `"12m"` or `"45s"`.
+pub fn format_duration(ms: u64) -> String {
+    let minutes = ms / 60_000;
+    match (minutes / 60, minutes % 60) {
+        (0, 0) => format!("{}s", ms / 1000),
+        (0, m) => format!("{}m", m),
+        (h, m) => format!("{}h {}m", h, m),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::session::{SpeakerHint, Transcript, Word};
+
+    fn word(id: &str, text: &str, start_ms: f64, channel: i64) -> Word {
+        Word {
+            id: id.to_string(),
+            text: text.to_string(),
+            start_ms,
+            end_ms: start_ms + 400.0,
+            channel,
+        }
+    }
+
+    fn hint(word_id: &str, hint_type: &str, value: serde_json::Value) -> SpeakerHint {
+        SpeakerHint {
+            word_id: word_id.to_string(),
+            hint_type: hint_type.to_string(),
+            value,
+        }
+    }
+
+    #[test]
+    fn test_segments() {
+        let mut session = Session::default();
+        session.meta.user_id = "me".to_string();
+        session
+            .humans
+            .insert("jane".to_string(), "Jane".to_string());
+        session.transcripts.push(Transcript {
+            id: "t".to_string(),
+            words: vec![
+                word("0", " Hello", 0.0, 0),
+                word("1", " there", 400.0, 0),
+                word("2", " Hi", 1000.0, 1),
+                word("3", " again", 1400.0, 1),
+                word("4", " Later", 9000.0, 1),
+                word("5", " Bye", 9500.0, 1),
+            ],
+            speaker_hints: vec![
+                hint(
+                    "2",
+                    "provider_speaker_index",
+                    serde_json::json!({ "speaker_index": 0 }),
+                ),
+                hint(
+                    "3",
+                    "provider_speaker_index",
+                    serde_json::json!({ "speaker_index": 0 }),
+                ),
+                hint(
+                    "3",
+                    "user_speaker_assignment",
+                    serde_json::json!({ "human_id": "jane" }),
+                ),
+                hint(
+                    "5",
+                    "provider_speaker_index",
+                    serde_json::json!({ "speaker_index": 1 }),
+                ),
+            ],
+            ..Default::default()
+        });
+
+        let segments: Vec<_> = segments(&session)
+            .iter()
+            .map(|s| (s.speaker.clone(), s.start_ms, s.text()))
+            .collect();
+        assert_eq!(
+            segments,
+            vec![
+                ("You".to_string(), 0, "Hello there".to_string()),
+                ("Jane".to_string(), 1000, "Hi again".to_string()),
+                ("Speaker B".to_string(), 9000, "Later".to_string()),
+                ("Speaker 2".to_string(), 9500, "Bye".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_format_timestamp() {
+        assert_eq!(format_timestamp(0), "00:00:00");
+        assert_eq!(format_timestamp(3_723_000), "01:02:03");
+        assert_eq!(format_duration(45_000), "45s");
+        assert_eq!(format_duration(3_720_000), "1h 2m");
+    }
+}
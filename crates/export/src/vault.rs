@@ -0,0 +1,810 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use chrono::{Datelike, NaiveDate};
+use hypr_frontmatter::Document;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+use crate::session::HUMANS_DIR;
+use crate::transcript::segments;
+use crate::{Result, Session};
+
+const ORGANIZATIONS_DIR: &str = "organizations";
+const SESSIONS_DIR: &str = "sessions";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub enum VaultFlavor {
+    Obsidian,
+    Logseq,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct VaultOptions {
+    pub flavor: VaultFlavor,
+    pub include_memo: bool,
+    pub include_transcript: bool,
+    pub daily_notes: bool,
+}
+
+impl Default for VaultOptions {
+    fn default() -> Self {
+        Self {
+            flavor: VaultFlavor::Obsidian,
+            include_memo: false,
+            include_transcript: true,
+            daily_notes: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct VaultExportStats {
+    pub meetings: u32,
+    pub people: u32,
+    pub organizations: u32,
+    pub daily_notes: u32,
+    pub attachments: u32,
+    /// Session folders that could not be read.
+    pub skipped: u32,
+}
+
+#[derive(Deserialize)]
+struct PersonFrontmatter {
+    #[serde(default)]
+    name: String,
+    #[serde(default)]
+    emails: Vec<String>,
+    #[serde(default)]
+    email: Option<String>,
+    #[serde(default)]
+    org_id: String,
+    #[serde(default)]
+    job_title: String,
+}
+
+#[derive(Deserialize)]
+struct OrganizationFrontmatter {
+    #[serde(default)]
+    name: String,
+}
+
+struct Person {
+    id: String,
+    name: String,
+    emails: Vec<String>,
+    org_id: String,
+    job_title: String,
+    memo: String,
+}
+
+struct Organization {
+    id: String,
+    name: String,
+}
+
+enum Property {
+    Text(String),
+    Link(String),
+    Links(Vec<String>),
+    Tags(Vec<String>),
+}
+
+/// A page in the target vault, before it is rendered for a flavor.
+#[derive(Default)]
+struct Page {
+    properties: Vec<(&'static str, Property)>,
+    body: String,
+}
+
+/// Rewrites a Hyprnote vault as an Obsidian or Logseq vault: one page per
+/// meeting, person and organization, wiki-linked together, plus daily notes
+/// linking each day's meetings. Attachments are copied next to the pages.
+pub fn export_vault(
+    vault_base: impl AsRef<Path>,
+    output: impl AsRef<Path>,
+    options: &VaultOptions,
+) -> Result<VaultExportStats> {
+    let vault_base = vault_base.as_ref();
+    let output = output.as_ref();
+    let layout = Layout(options.flavor);
+    let mut stats = VaultExportStats::default();
+
+    let people = load_people(&vault_base.join(HUMANS_DIR))?;
+    let organizations = load_organizations(&vault_base.join(ORGANIZATIONS_DIR))?;
+
+    let mut session_dirs = Vec::new();
+    find_sessions(&vault_base.join(SESSIONS_DIR), &mut session_dirs)?;
+    let mut sessions = Vec::new();
+    for dir in session_dirs {
+        match Session::load(&dir) {
+            Ok(session) => sessions.push(session),
+            Err(_) => stats.skipped += 1,
+        }
+    }
+    sessions.sort_by(|a, b| {
+        (&a.meta.created_at, a.title(), &a.meta.id).cmp(&(
+            &b.meta.created_at,
+            b.title(),
+            &b.meta.id,
+        ))
+    });
+
+    let mut names = PageNames::default();
+    let person_pages: HashMap<&str, String> = people
+        .iter()
+        .map(|p| (p.id.as_str(), names.claim(&p.name)))
+        .collect();
+    let org_pages: HashMap<&str, String> = organizations
+        .iter()
+        .map(|o| (o.id.as_str(), names.claim(&o.name)))
+        .collect();
+
+    let mut meetings_by_person: HashMap<&str, Vec<String>> = HashMap::new();
+    let mut meetings_by_day: BTreeMap<NaiveDate, Vec<String>> = BTreeMap::new();
+
+    for session in &sessions {
+        let date = session_date(session);
+        let title = match date {
+            Some(date) => format!("{} {}", date.format("%Y-%m-%d"), session.title()),
+            None => session.title().to_string(),
+        };
+        let page_name = names.claim(&title);
+
+        let participants: Vec<&str> = session
+            .meta
+            .participants
+            .iter()
+            .filter(|p| person_pages.contains_key(p.human_id.as_str()))
+            .map(|p| p.human_id.as_str())
+            .collect();
+        for id in &participants {
+            meetings_by_person
+                .entry(id)
+                .or_default()
+                .push(page_name.clone());
+        }
+        if let Some(date) = date {
+            meetings_by_day
+                .entry(date)
+                .or_default()
+                .push(page_name.clone());
+        }
+
+        let attachments = copy_attachments(session, output, &layout)?;
+        stats.attachments += attachments.len() as u32;
+
+        let page = meeting_page(
+            session,
+            options,
+            &layout,
+            date,
+            participants
+                .iter()
+                .map(|id| person_pages[id].clone())
+                .collect(),
+            &person_pages,
+            &attachments,
+        );
+        write_page(
+            output,
+            layout.meetings_dir(),
+            &page_name,
+            &layout.render(&page)?,
+        )?;
+        stats.meetings += 1;
+    }
+
+    for person in &people {
+        let mut page = Page::default();
+        page.properties
+            .push(("hyprnote", Property::Text(person.id.clone())));
+        page.properties
+            .push(("emails", Property::Text(person.emails.join(", "))));
+        page.properties
+            .push(("role", Property::Text(person.job_title.clone())));
+        if let Some(org) = org_pages.get(person.org_id.as_str()) {
+            page.properties
+                .push(("organization", Property::Link(org.clone())));
+        }
+
+        let mut sections = Vec::new();
+        if !person.memo.trim().is_empty() {
+            sections.push(person.memo.trim().to_string());
+        }
+        if let Some(meetings) = meetings_by_person.get(person.id.as_str()) {
+            sections.push(link_list("Meetings", meetings));
+        }
+        page.body = sections.join("\n\n");
+
+        let name = &person_pages[person.id.as_str()];
+        write_page(output, layout.people_dir(), name, &layout.render(&page)?)?;
+        stats.people += 1;
+    }
+
+    for org in &organizations {
+        let members: Vec<String> = people
+            .iter()
+            .filter(|p| p.org_id == org.id)
+            .map(|p| person_pages[p.id.as_str()].clone())
+            .collect();
+
+        let mut page = Page::default();
+        page.properties
+            .push(("hyprnote", Property::Text(org.id.clone())));
+        if !members.is_empty() {
+            page.body = link_list("People", &members);
+        }
+
+        let name = &org_pages[org.id.as_str()];
+        write_page(
+            output,
+            layout.organizations_dir(),
+            name,
+            &layout.render(&page)?,
+        )?;
+        stats.organizations += 1;
+    }
+
+    if options.daily_notes {
+        for (date, meetings) in &meetings_by_day {
+            let page = Page {
+                properties: Vec::new(),
+                body: link_list("Meetings", meetings),
+            };
+            write_page(
+                output,
+                layout.daily_dir(),
+                &layout.daily_file(*date),
+                &layout.render(&page)?,
+            )?;
+            stats.daily_notes += 1;
+        }
+    }
+
+    Ok(stats)
+}
+
+fn meeting_page(
+    session: &Session,
+    options: &VaultOptions,
+    layout: &Layout,
+    date: Option<NaiveDate>,
+    participants: Vec<String>,
+    person_pages: &HashMap<&str, String>,
+    attachments: &HashMap<String, String>,
+) -> Page {
+    let tags = session.meta.tags.clone().unwrap_or_default();
+
+    let mut page = Page::default();
+    page.properties
+        .push(("hyprnote", Property::Text(session.meta.id.clone())));
+    if let Some(date) = date {
+        page.properties
+            .push(("date", Property::Link(layout.daily_title(date))));
+    }
+    if let Some(event) = session.event_title() {
+        page.properties
+            .push(("event", Property::Text(event.to_string())));
+    }
+    page.properties
+        .push(("participants", Property::Links(participants)));
+    page.properties.push(("tags", Property::Tags(tags.clone())));
+
+    let mut sections = Vec::new();
+    if !tags.is_empty() {
+        sections.push(
+            tags.iter()
+                .map(|t| layout.tag(t))
+                .collect::<Vec<_>>()
+                .join(" "),
+        );
+    }
+
+    let mut linked = HashSet::new();
+    for note in &session.notes {
+        let (content, used) = rewrite_links(note.content.trim(), attachments, layout);
+        linked.extend(used);
+        match &note.title {
+            Some(title) => sections.push(format!("## {}\n\n{}", title, content)),
+            None => sections.push(content),
+        }
+    }
+
+    if options.include_memo
+        && let Some(memo) = &session.memo
+    {
+        let (content, used) = rewrite_links(memo.trim(), attachments, layout);
+        linked.extend(used);
+        sections.push(format!("## Memo\n\n{}", content));
+    }
+
+    let segments = segments(session);
+    if options.include_transcript && !segments.is_empty() {
+        // Speakers are labelled by name, so link the ones that have a page.
+        let speaker_pages: HashMap<&str, &str> = session
+            .humans
+            .iter()
+            .filter_map(|(id, name)| Some((name.as_str(), person_pages.get(id.as_str())?.as_str())))
+            .collect();
+
+        let lines: Vec<String> = segments
+            .iter()
+            .map(|segment| {
+                let speaker = match speaker_pages.get(segment.speaker.as_str()) {
+                    Some(page) => format!("[[{}]]", page),
+                    None => segment.speaker.clone(),
+                };
+                format!("**{}**: {}", speaker, segment.text())
+            })
+            .collect();
+        sections.push(format!("## Transcript\n\n{}", lines.join("\n\n")));
+    }
+
+    let mut unlinked: Vec<(&String, &String)> = attachments
+        .iter()
+        .filter(|(original, _)| !linked.contains(*original))
+        .collect();
+    unlinked.sort();
+    if !unlinked.is_empty() {
+        let lines: Vec<String> = unlinked
+            .iter()
+            .map(|(original, copied)| {
+                format!("- ![{}]({})", original, layout.attachment_link(copied))
+            })
+            .collect();
+        sections.push(format!("## Attachments\n\n{}", lines.join("\n")));
+    }
+
+    page.body = sections.join("\n\n");
+    page
+}
+
+struct Layout(VaultFlavor);
+
+impl Layout {
+    fn meetings_dir(&self) -> &'static str {
+        match self.0 {
+            VaultFlavor::Obsidian => "Meetings",
+            VaultFlavor::Logseq => "pages",
+        }
+    }
+
+    fn people_dir(&self) -> &'static str {
+        match self.0 {
+            VaultFlavor::Obsidian => "People",
+            VaultFlavor::Logseq => "pages",
+        }
+    }
+
+    fn organizations_dir(&self) -> &'static str {
+        match self.0 {
+            VaultFlavor::Obsidian => "Organizations",
+            VaultFlavor::Logseq => "pages",
+        }
+    }
+
+    fn daily_dir(&self) -> &'static str {
+        match self.0 {
+            VaultFlavor::Obsidian => "Daily",
+            VaultFlavor::Logseq => "journals",
+        }
+    }
+
+    fn attachments_dir(&self) -> &'static str {
+        match self.0 {
+            VaultFlavor::Obsidian => "Attachments",
+            VaultFlavor::Logseq => "assets",
+        }
+    }
+
+    // Pages and attachments are both one folder below the vault root.
+    fn attachment_link(&self, file_name: &str) -> String {
+        format!("../{}/{}", self.attachments_dir(), file_name)
+    }
+
+    fn daily_file(&self, date: NaiveDate) -> String {
+        match self.0 {
+            VaultFlavor::Obsidian => date.format("%Y-%m-%d").to_string(),
+            VaultFlavor::Logseq => date.format("%Y_%m_%d").to_string(),
+        }
+    }
+
+    /// Page name of a daily note, in each app's default journal format.
+    fn daily_title(&self, date: NaiveDate) -> String {
+        match self.0 {
+            VaultFlavor::Obsidian => date.format("%Y-%m-%d").to_string(),
+            VaultFlavor::Logseq => format!(
+                "{} {}, {}",
+                date.format("%b"),
+                ordinal(date.day()),
+                date.year()
+            ),
+        }
+    }
+
+    fn tag(&self, name: &str) -> String {
+        let name = name.trim().trim_start_matches('#');
+        match self.0 {
+            VaultFlavor::Obsidian => format!("#{}", obsidian_tag(name)),
+            VaultFlavor::Logseq if name.contains(char::is_whitespace) => format!("#[[{}]]", name),
+            VaultFlavor::Logseq => format!("#{}", name),
+        }
+    }
+
+    fn render(&self, page: &Page) -> Result<String> {
+        match self.0 {
+            VaultFlavor::Obsidian => {
+                let mut frontmatter = serde_json::Map::new();
+                for (key, property) in &page.properties {
+                    let value = match property {
+                        Property::Text(text) if !text.is_empty() => serde_json::json!(text),
+                        Property::Link(link) => serde_json::json!(format!("[[{}]]", link)),
+                        Property::Links(links) if !links.is_empty() => serde_json::json!(
+                            links
+                                .iter()
+                                .map(|l| format!("[[{}]]", l))
+                                .collect::<Vec<_>>()
+                        ),
+                        Property::Tags(tags) if !tags.is_empty() => serde_json::json!(
+                            tags.iter().map(|t| obsidian_tag(t)).collect::<Vec<_>>()
+                        ),
+                        _ => continue,
+                    };
+                    frontmatter.insert(key.to_string(), value);
+                }
+
+                if frontmatter.is_empty() {
+                    return Ok(format!("{}\n", page.body));
+                }
+                let mut rendered = Document::new(frontmatter, page.body.as_str()).render()?;
+                rendered.push('\n');
+                Ok(rendered)
+            }
+            VaultFlavor::Logseq => {
+                let mut rendered = String::new();
+                for (key, property) in &page.properties {
+                    let value = match property {
+                        Property::Text(text) => text.clone(),
+                        Property::Link(link) => format!("[[{}]]", link),
+                        Property::Links(links) => links
+                            .iter()
+                            .map(|l| format!("[[{}]]", l))
+                            .collect::<Vec<_>>()
+                            .join(", "),
+                        Property::Tags(tags) => tags.join(", "),
+                    };
+                    if !value.is_empty() {
+                        rendered.push_str(&format!("{}:: {}\n", key, value));
+                    }
+                }
+                if !rendered.is_empty() {
+                    rendered.push('\n');
+                }
+                rendered.push_str(&to_outline(&page.body));
+                Ok(rendered)
+            }
+        }
+    }
+}
+
+/// Logseq pages are outlines: every paragraph is a `- ` block, and lists
+/// nest under the heading they follow.
+fn to_outline(md: &str) -> String {
+    let mut outline = String::new();
+    let mut lines = md.lines();
+    let mut under_heading = false;
+
+    while let Some(line) = lines.next() {
+        let trimmed = line.trim_start();
+        if trimmed.is_empty() {
+            continue;
+        }
+
+        if trimmed.starts_with("```") {
+            outline.push_str(&format!("- {}\n", trimmed));
+            for inner in lines.by_ref() {
+                outline.push_str(&format!("  {}\n", inner));
+                if inner.trim_start().starts_with("```") {
+                    break;
+                }
+            }
+            continue;
+        }
+
+        if let Some(item) = list_item(trimmed) {
+            let depth = (line.len() - trimmed.len()) / 2 + usize::from(under_heading);
+            outline.push_str(&format!("{}- {}\n", "\t".repeat(depth), item));
+            continue;
+        }
+
+        under_heading = trimmed.trim_start_matches('#').starts_with(' ');
+        outline.push_str(&format!("- {}\n", trimmed));
+    }
+
+    outline
+}
+
+fn list_item(line: &str) -> Option<&str> {
+    if let Some(item) = ["- ", "* ", "+ "].iter().find_map(|m| line.strip_prefix(m)) {
+        return Some(item);
+    }
+    let digits = line.chars().take_while(char::is_ascii_digit).count();
+    if digits == 0 {
+        return None;
+    }
+    line[digits..].strip_prefix(". ")
+}
+
+/// Points markdown links and images at copied attachments. Returns the
+/// rewritten text and the original file names it linked.
+fn rewrite_links(
+    md: &str,
+    attachments: &HashMap<String, String>,
+    layout: &Layout,
+) -> (String, HashSet<String>) {
+    let mut rewritten = String::new();
+    let mut used = HashSet::new();
+    let mut rest = md;
+
+    while let Some(pos) = rest.find("](") {
+        let (head, tail) = rest.split_at(pos + 2);
+        rewritten.push_str(head);
+
+        let end = tail.find(')').unwrap_or(tail.len());
+        let target = &tail[..end];
+        let (destination, title) = match target.find(" \"") {
+            Some(i) => (&target[..i], &target[i..]),
+            None => (target, ""),
+        };
+        let name = destination
+            .trim_matches(|c| c == '<' || c == '>')
+            .trim_start_matches("./")
+            .replace("%20", " ");
+
+        match attachments.get(&name) {
+            Some(copied) => {
+                rewritten.push_str(&layout.attachment_link(copied));
+                rewritten.push_str(title);
+                used.insert(name);
+            }
+            None => rewritten.push_str(target),
+        }
+        rest = &tail[end..];
+    }
+    rewritten.push_str(rest);
+
+    (rewritten, used)
+}
+
+/// Copies a session's attachments, returning original to copied file name.
+/// Copies are prefixed with the session id so names never collide.
+fn copy_attachments(
+    session: &Session,
+    output: &Path,
+    layout: &Layout,
+) -> Result<HashMap<String, String>> {
+    let mut copied = HashMap::new();
+    if session.attachments.is_empty() {
+        return Ok(copied);
+    }
+
+    let dir = output.join(layout.attachments_dir());
+    std::fs::create_dir_all(&dir)?;
+    let prefix: String = session.meta.id.chars().take(8).collect();
+
+    for path in &session.attachments {
+        let Some(name) = path.file_name().map(|n| n.to_string_lossy().to_string()) else {
+            continue;
+        };
+        let target = format!(
+            "{}-{}",
+            prefix,
+            name.split_whitespace().collect::<Vec<_>>().join("-")
+        );
+        std::fs::copy(path, dir.join(&target))?;
+        copied.insert(name, target);
+    }
+
+    Ok(copied)
+}
+
+fn link_list(heading: &str, pages: &[String]) -> String {
+    let links: Vec<String> = pages.iter().map(|p| format!("- [[{}]]", p)).collect();
+    format!("## {}\n\n{}", heading, links.join("\n"))
+}
+
+fn write_page(output: &Path, dir: &str, name: &str, content: &str) -> Result<()> {
+    let dir = output.join(dir);
+    std::fs::create_dir_all(&dir)?;
+    std::fs::write(dir.join(format!("{}.md", name)), content)?;
+    Ok(())
+}
+
+/// Hands out unique, file-system safe page names. Obsidian and Logseq both
+/// resolve `[[links]]` by name across folders, so names are unique vault-wide.
+#[derive(Default)]
+struct PageNames(HashSet<String>);
+
+impl PageNames {
+    fn claim(&mut self, name: &str) -> String {
+        let base = sanitize_page_name(name);
+        let mut candidate = base.clone();
+        let mut n = 2;
+        while !self.0.insert(candidate.to_lowercase()) {
+            candidate = format!("{} ({})", base, n);
+            n += 1;
+        }
+        candidate
+    }
+}
+
+fn sanitize_page_name(name: &str) -> String {
+    let cleaned: String = name
+        .chars()
+        .map(|c| match c {
+            '\\' | '/' | ':' | '*' | '?' | '"' | '<' | '>' | '|' | '#' | '^' | '[' | ']' => ' ',
+            c => c,
+        })
+        .collect();
+    let cleaned = cleaned.split_whitespace().collect::<Vec<_>>().join(" ");
+    if cleaned.is_empty() {
+        "Untitled".to_string()
+    } else {
+        cleaned
+    }
+}
+
+// Obsidian tags can't contain spaces.
+fn obsidian_tag(name: &str) -> String {
+    name.trim()
+        .trim_start_matches('#')
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+fn ordinal(day: u32) -> String {
+    let suffix = match (day % 10, day % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{}{}", day, suffix)
+}
+
+fn session_date(session: &Session) -> Option<NaiveDate> {
+    chrono::DateTime::parse_from_rfc3339(&session.meta.created_at)
+        .ok()
+        .map(|dt| dt.date_naive())
+}
+
+fn find_sessions(dir: &Path, out: &mut Vec<PathBuf>) -> Result<()> {
+    if !dir.is_dir() {
+        return Ok(());
+    }
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if !path.is_dir() {
+            continue;
+        }
+        if path.join("_meta.json").is_file() {
+            out.push(path);
+        } else {
+            find_sessions(&path, out)?;
+        }
+    }
+    out.sort();
+    Ok(())
+}
+
+fn markdown_files(dir: &Path) -> Result<Vec<(String, String)>> {
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+    let mut files = Vec::new();
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|ext| ext == "md")
+            && let Some(id) = path.file_stem().map(|s| s.to_string_lossy().to_string())
+        {
+            files.push((id, std::fs::read_to_string(&path)?));
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+fn load_people(dir: &Path) -> Result<Vec<Person>> {
+    let mut people: Vec<Person> = markdown_files(dir)?
+        .into_iter()
+        .filter_map(|(id, raw)| {
+            let doc = Document::<PersonFrontmatter>::from_str(&raw).ok()?;
+            let fm = doc.frontmatter;
+            let mut emails = fm.emails;
+            emails.extend(fm.email.into_iter().flat_map(|e| {
+                e.split(',')
+                    .map(|e| e.trim().to_string())
+                    .collect::<Vec<_>>()
+            }));
+            emails.retain(|e| !e.is_empty());
+            Some(Person {
+                id,
+                name: fm.name.trim().to_string(),
+                emails,
+                org_id: fm.org_id,
+                job_title: fm.job_title,
+                memo: doc.content,
+            })
+        })
+        .filter(|p| !p.name.is_empty())
+        .collect();
+    people.sort_by(|a, b| (&a.name, &a.id).cmp(&(&b.name, &b.id)));
+    Ok(people)
+}
+
+fn load_organizations(dir: &Path) -> Result<Vec<Organization>> {
+    let mut organizations: Vec<Organization> = markdown_files(dir)?
+        .into_iter()
+        .filter_map(|(id, raw)| {
+            let doc = Document::<OrganizationFrontmatter>::from_str(&raw).ok()?;
+            Some(Organization {
+                id,
+                name: doc.frontmatter.name.trim().to_string(),
+            })
+        })
+        .filter(|o| !o.name.is_empty())
+        .collect();
+    organizations.sort_by(|a, b| (&a.name, &a.id).cmp(&(&b.name, &b.id)));
+    Ok(organizations)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_outline() {
+        let md = "#product\n\n## Decisions\n\n- Ship it\n  - Friday\n\nDone.\n\n```\nlet x;\n```";
+        assert_eq!(
+            to_outline(md),
+            "- #product\n- ## Decisions\n\t- Ship it\n\t\t- Friday\n- Done.\n- ```\n  let x;\n  ```\n"
+        );
+    }
+
+    #[test]
+    fn test_rewrite_links() {
+        let attachments = HashMap::from([(
+            "white board.png".to_string(),
+            "abc-white-board.png".to_string(),
+        )]);
+        let (md, used) = rewrite_links(
+            "![board](white%20board.png \"Board\") and [site](https://hyprnote.com)",
+            &attachments,
+            &Layout(VaultFlavor::Obsidian),
+        );
+        assert_eq!(
+            md,
+            "![board](../Attachments/abc-white-board.png \"Board\") and [site](https://hyprnote.com)"
+        );
+        assert!(used.contains("white board.png"));
+    }
+
+    #[test]
+    fn test_page_names() {
+        let mut names = PageNames::default();
+        assert_eq!(names.claim("Q1 [draft]: plan"), "Q1 draft plan");
+        assert_eq!(names.claim("q1 draft plan"), "q1 draft plan (2)");
+        assert_eq!(names.claim("  "), "Untitled");
+        assert_eq!(
+            Layout(VaultFlavor::Logseq).daily_title(NaiveDate::from_ymd_opt(2024, 3, 22).unwrap()),
+            "Mar 22nd, 2024"
+        );
+    }
+}
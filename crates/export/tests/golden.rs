@@ -0,0 +1,129 @@
+use std::io::Read;
+
+use export::{
+    BundleOptions, DocxOptions, ExportFormat, HtmlOptions, PdfOptions, Session, SubtitleOptions,
+    TextOptions,
+};
+
+fn session() -> Session {
+    Session::load(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/data/vault/sessions/work/5b3e0c1a-weekly-sync"
+    ))
+    .unwrap()
+}
+
+fn export_string(format: ExportFormat) -> String {
+    String::from_utf8(export::export(&session(), &format).unwrap()).unwrap()
+}
+
+fn read_zip(bytes: Vec<u8>) -> Vec<(String, Vec<u8>)> {
+    let mut archive = zip::ZipArchive::new(std::io::Cursor::new(bytes)).unwrap();
+    (0..archive.len())
+        .map(|i| {
+            let mut file = archive.by_index(i).unwrap();
+            let mut content = Vec::new();
+            file.read_to_end(&mut content).unwrap();
+            (file.name().to_string(), content)
+        })
+        .collect()
+}
+
+#[test]
+fn test_text() {
+    insta::assert_snapshot!(export_string(ExportFormat::Text(TextOptions::default())));
+}
+
+#[test]
+fn test_text_without_timestamps() {
+    insta::assert_snapshot!(export_string(ExportFormat::Text(TextOptions {
+        timestamps: false,
+        speakers: true,
+    })));
+}
+
+#[test]
+fn test_srt() {
+    insta::assert_snapshot!(export_string(ExportFormat::Srt(SubtitleOptions {
+        max_chars_per_cue: 32,
+        ..Default::default()
+    })));
+}
+
+#[test]
+fn test_vtt() {
+    insta::assert_snapshot!(export_string(ExportFormat::Vtt(SubtitleOptions::default())));
+}
+
+#[test]
+fn test_html() {
+    insta::assert_snapshot!(export_string(ExportFormat::Html(HtmlOptions {
+        include_memo: true,
+        ..Default::default()
+    })));
+}
+
+#[test]
+fn test_json() {
+    insta::assert_snapshot!(export_string(ExportFormat::Json));
+}
+
+#[test]
+fn test_pdf_source() {
+    let input = export::pdf::input(&session(), &PdfOptions::default());
+    insta::assert_snapshot!(export::pdf::build_typst_content(&input));
+}
+
+#[cfg(feature = "pdf")]
+#[test]
+fn test_pdf() {
+    let bytes = export::export(&session(), &ExportFormat::Pdf(PdfOptions::default())).unwrap();
+    assert!(bytes.starts_with(b"%PDF-"));
+}
+
+#[test]
+fn test_docx() {
+    let bytes = export::export(&session(), &ExportFormat::Docx(DocxOptions::default())).unwrap();
+    let files = read_zip(bytes);
+
+    let names: Vec<_> = files.iter().map(|(name, _)| name.as_str()).collect();
+    assert_eq!(
+        names,
+        vec![
+            "[Content_Types].xml",
+            "_rels/.rels",
+            "word/document.xml",
+            "word/styles.xml",
+            "word/_rels/document.xml.rels",
+        ]
+    );
+
+    let part = |name: &str| {
+        let (_, content) = files.iter().find(|(n, _)| n == name).unwrap();
+        String::from_utf8(content.clone()).unwrap()
+    };
+    insta::assert_snapshot!("docx_document", part("word/document.xml"));
+    insta::assert_snapshot!("docx_relationships", part("word/_rels/document.xml.rels"));
+}
+
+#[test]
+fn test_bundle() {
+    let format = ExportFormat::Bundle(BundleOptions::default());
+    let bytes = export::export(&session(), &format).unwrap();
+    assert_eq!(bytes, export::export(&session(), &format).unwrap());
+
+    let files = read_zip(bytes);
+    let names: Vec<_> = files.iter().map(|(name, _)| name.as_str()).collect();
+    assert_eq!(names, vec!["manifest.json", "session.json", "audio.ogg"]);
+    assert_eq!(files[2].1, b"OggS\0fake-audio");
+    insta::assert_snapshot!(String::from_utf8(files[0].1.clone()).unwrap());
+
+    let without_audio = export::export(
+        &session(),
+        &ExportFormat::Bundle(BundleOptions {
+            include_audio: false,
+        }),
+    )
+    .unwrap();
+    assert_eq!(read_zip(without_audio).len(), 2);
+}
@@ -0,0 +1,70 @@
+use std::path::Path;
+
+use export::vault::VaultExportStats;
+use export::{VaultFlavor, VaultOptions, export_vault};
+
+// Every exported file, with text files inlined, in path order.
+fn render_tree(root: &Path) -> String {
+    fn walk(dir: &Path, files: &mut Vec<std::path::PathBuf>) {
+        for entry in std::fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                walk(&path, files);
+            } else {
+                files.push(path);
+            }
+        }
+    }
+
+    let mut files = Vec::new();
+    walk(root, &mut files);
+    files.sort();
+
+    let mut tree = String::new();
+    for file in files {
+        let relative = file.strip_prefix(root).unwrap().display().to_string();
+        tree.push_str(&format!("=== {} ===\n", relative));
+        if file.extension().is_some_and(|ext| ext == "md") {
+            tree.push_str(&std::fs::read_to_string(&file).unwrap());
+        }
+        tree.push('\n');
+    }
+    tree
+}
+
+fn export(flavor: VaultFlavor) -> (VaultExportStats, String) {
+    let output = tempfile::tempdir().unwrap();
+    let stats = export_vault(
+        concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/vault"),
+        output.path(),
+        &VaultOptions {
+            flavor,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    (stats, render_tree(output.path()))
+}
+
+#[test]
+fn test_obsidian() {
+    let (stats, tree) = export(VaultFlavor::Obsidian);
+    assert_eq!(
+        stats,
+        VaultExportStats {
+            meetings: 2,
+            people: 2,
+            organizations: 1,
+            daily_notes: 1,
+            attachments: 2,
+            skipped: 0,
+        }
+    );
+    insta::assert_snapshot!(tree);
+}
+
+#[test]
+fn test_logseq() {
+    let (_, tree) = export(VaultFlavor::Logseq);
+    insta::assert_snapshot!(tree);
+}
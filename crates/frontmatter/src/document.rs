@@ -1,9 +1,10 @@
 use std::str::FromStr;
 
 use serde::{Deserialize, Serialize, de::DeserializeOwned};
-use serde_yaml::Value;
+use serde_yaml::{Mapping, Value};
 
 use crate::Error;
+use crate::schema::{self, Schema, VERSION_KEY};
 
 const DELIMITER: &str = "---";
 
@@ -70,36 +71,58 @@ impl<T> Document<T> {
     }
 }
 
-impl<T: DeserializeOwned> FromStr for Document<T> {
-    type Err = Error;
+fn split(s: &str) -> Result<(&str, String), Error> {
+    let s = s.trim_start();
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let s = s.trim_start();
+    if !s.starts_with(DELIMITER) {
+        return Err(Error::MissingOpeningDelimiter);
+    }
 
-        if !s.starts_with(DELIMITER) {
-            return Err(Error::MissingOpeningDelimiter);
-        }
+    let after_opening = &s[DELIMITER.len()..];
+    let after_opening = strip_line_ending(after_opening);
 
-        let after_opening = &s[DELIMITER.len()..];
-        let after_opening = strip_line_ending(after_opening);
+    let closing_pos =
+        find_closing_delimiter(after_opening).ok_or(Error::MissingClosingDelimiter)?;
 
-        let closing_pos =
-            find_closing_delimiter(after_opening).ok_or(Error::MissingClosingDelimiter)?;
+    let yaml_str = &after_opening[..closing_pos];
+    let after_closing = &after_opening[closing_pos..];
+    let after_closing = strip_line_ending(after_closing);
+    let after_closing = after_closing
+        .strip_prefix(DELIMITER)
+        .unwrap_or(after_closing);
 
-        let yaml_str = &after_opening[..closing_pos];
-        let after_closing = &after_opening[closing_pos..];
-        let after_closing = strip_line_ending(after_closing);
-        let after_closing = after_closing
-            .strip_prefix(DELIMITER)
-            .unwrap_or(after_closing);
+    let content = strip_line_ending(strip_line_ending(after_closing)).to_string();
 
-        let frontmatter: T = if yaml_str.trim().is_empty() {
-            serde_yaml::from_str("{}")?
-        } else {
-            serde_yaml::from_str(yaml_str)?
-        };
+    Ok((yaml_str, content))
+}
 
-        let content = strip_line_ending(strip_line_ending(after_closing)).to_string();
+fn parse_yaml<T: DeserializeOwned>(yaml_str: &str) -> Result<T, Error> {
+    if yaml_str.trim().is_empty() {
+        Ok(serde_yaml::from_str("{}")?)
+    } else {
+        Ok(serde_yaml::from_str(yaml_str)?)
+    }
+}
+
+fn render_value(value: Value, content: &str) -> Result<String, Error> {
+    let sorted = sort_value(value);
+    let yaml = serde_yaml::to_string(&sorted)?;
+    let mut output = String::new();
+    output.push_str(DELIMITER);
+    output.push('\n');
+    output.push_str(&yaml);
+    output.push_str(DELIMITER);
+    output.push_str("\n\n");
+    output.push_str(content);
+    Ok(output)
+}
+
+impl<T: DeserializeOwned> FromStr for Document<T> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (yaml_str, content) = split(s)?;
+        let frontmatter = parse_yaml(yaml_str)?;
 
         Ok(Document {
             frontmatter,
@@ -108,19 +131,56 @@ impl<T: DeserializeOwned> FromStr for Document<T> {
     }
 }
 
+/// A document parsed through its [`Schema`], with the version it was stored at.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Versioned<T> {
+    pub document: Document<T>,
+    pub from_version: u32,
+    /// Whether the stored frontmatter carried a `schema_version` key.
+    pub stamped: bool,
+}
+
+impl<T: Schema> Versioned<T> {
+    pub fn is_upgraded(&self) -> bool {
+        self.from_version < T::VERSION
+    }
+
+    /// True when writing the document back with [`Document::render_versioned`] would
+    /// change its stored schema, i.e. it was upgraded or never stamped.
+    pub fn needs_rewrite(&self) -> bool {
+        !self.stamped || self.is_upgraded()
+    }
+}
+
+impl<T: Schema> Document<T> {
+    /// Parses the frontmatter, upgrading it to `T::VERSION` and validating the result.
+    pub fn parse_versioned(s: &str) -> Result<Versioned<T>, Error> {
+        let (yaml_str, content) = split(s)?;
+        let mapping: Mapping = parse_yaml(yaml_str)?;
+        let (frontmatter, stored) = schema::migrate(mapping)?;
+
+        Ok(Versioned {
+            document: Document {
+                frontmatter,
+                content,
+            },
+            from_version: stored.unwrap_or(1),
+            stamped: stored.is_some(),
+        })
+    }
+
+    /// Like [`Document::render`], stamping `schema_version` with `T::VERSION`.
+    pub fn render_versioned(&self) -> Result<String, Error> {
+        let mut mapping = schema::into_mapping(&self.frontmatter)?;
+        mapping.insert(VERSION_KEY.into(), T::VERSION.into());
+        render_value(Value::Mapping(mapping), &self.content)
+    }
+}
+
 impl<T: Serialize> Document<T> {
     pub fn render(&self) -> Result<String, Error> {
         let value = serde_yaml::to_value(&self.frontmatter)?;
-        let sorted = sort_value(value);
-        let yaml = serde_yaml::to_string(&sorted)?;
-        let mut output = String::new();
-        output.push_str(DELIMITER);
-        output.push('\n');
-        output.push_str(&yaml);
-        output.push_str(DELIMITER);
-        output.push_str("\n\n");
-        output.push_str(&self.content);
-        Ok(output)
+        render_value(value, &self.content)
     }
 }
 
@@ -8,4 +8,19 @@ pub enum Error {
     MissingClosingDelimiter,
     #[error("failed to parse YAML frontmatter: {0}")]
     YamlParse(#[from] serde_yaml::Error),
+    #[error("invalid frontmatter at `{key}`: {message}")]
+    Invalid { key: String, message: String },
+    #[error("schema version {found} is newer than the supported version {supported}")]
+    UnsupportedVersion { found: u32, supported: u32 },
+    #[error("no upgrade from schema version {from}")]
+    MissingUpgrade { from: u32 },
+}
+
+impl Error {
+    pub fn invalid(key: impl Into<String>, message: impl std::fmt::Display) -> Self {
+        Self::Invalid {
+            key: key.into(),
+            message: message.to_string(),
+        }
+    }
 }
@@ -1,8 +1,10 @@
 mod document;
 mod error;
+mod schema;
 
-pub use document::Document;
+pub use document::{Document, Versioned};
 pub use error::Error;
+pub use schema::{Schema, Upgrade, VERSION_KEY, upgrade};
 
 #[cfg(test)]
 mod tests {
@@ -310,4 +312,113 @@ And another --- line."#;
             assert_eq!(doc.content, parsed.content);
         }
     }
+
+    mod schema {
+        use super::*;
+
+        #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+        struct Person {
+            name: String,
+            #[serde(default)]
+            emails: Vec<String>,
+        }
+
+        #[derive(Deserialize)]
+        struct PersonV1 {
+            name: String,
+            #[serde(default)]
+            email: String,
+        }
+
+        impl Schema for Person {
+            const VERSION: u32 = 2;
+            const UPGRADES: &'static [Upgrade] = &[|m| {
+                upgrade(m, |old: PersonV1| Person {
+                    name: old.name,
+                    emails: old.email.split(',').map(|e| e.trim().to_string()).collect(),
+                })
+            }];
+
+            fn validate(&self) -> Result<(), Error> {
+                match self.emails.iter().position(|e| !e.contains('@')) {
+                    Some(i) => Err(Error::invalid(
+                        format!("emails[{i}]"),
+                        "not an email address",
+                    )),
+                    None => Ok(()),
+                }
+            }
+        }
+
+        #[test]
+        fn upgrades_unversioned() {
+            let input = "---\nname: Jane\nemail: a@x.com, b@x.com\n---\n\nBody";
+
+            let parsed = Document::<Person>::parse_versioned(input).unwrap();
+            assert_eq!(parsed.from_version, 1);
+            assert!(parsed.is_upgraded());
+            assert!(!parsed.stamped);
+            assert!(parsed.needs_rewrite());
+            assert_eq!(
+                parsed.document.frontmatter.emails,
+                vec!["a@x.com", "b@x.com"]
+            );
+
+            insta::assert_snapshot!(parsed.document.render_versioned().unwrap(), @r"
+            ---
+            emails:
+            - a@x.com
+            - b@x.com
+            name: Jane
+            schema_version: 2
+            ---
+
+            Body
+            ");
+        }
+
+        #[test]
+        fn current_version_is_not_upgraded() {
+            let input = "---\nschema_version: 2\nname: Jane\nemails: [a@x.com]\n---\n";
+
+            let parsed = Document::<Person>::parse_versioned(input).unwrap();
+            assert!(!parsed.is_upgraded());
+            assert!(!parsed.needs_rewrite());
+        }
+
+        #[test]
+        fn errors_point_at_key() {
+            let cases = [
+                ("schema_version: 2\nemails: []", "name"),
+                ("schema_version: 2\nname: Jane\nemails: {}", "emails"),
+                (
+                    "schema_version: 2\nname: Jane\nemails: [a@x.com, nope]",
+                    "emails[1]",
+                ),
+                ("schema_version: two\nname: Jane", "schema_version"),
+                ("name: [Jane]", "name"),
+            ];
+
+            for (yaml, expected) in cases {
+                let input = format!("---\n{yaml}\n---\n");
+                match Document::<Person>::parse_versioned(&input) {
+                    Err(Error::Invalid { key, .. }) => assert_eq!(key, expected, "{yaml}"),
+                    other => panic!("{yaml}: {other:?}"),
+                }
+            }
+        }
+
+        #[test]
+        fn newer_version_is_rejected() {
+            let input = "---\nschema_version: 3\nname: Jane\n---\n";
+            let result = Document::<Person>::parse_versioned(input);
+            assert!(matches!(
+                result,
+                Err(Error::UnsupportedVersion {
+                    found: 3,
+                    supported: 2
+                })
+            ));
+        }
+    }
 }
@@ -0,0 +1,92 @@
+use serde::{Serialize, de::DeserializeOwned};
+use serde_yaml::{Mapping, Value};
+
+use crate::Error;
+
+pub const VERSION_KEY: &str = "schema_version";
+
+/// Rewrites a frontmatter mapping from one schema version to the next.
+pub type Upgrade = fn(Mapping) -> Result<Mapping, Error>;
+
+/// Frontmatter of one entity type. Files without a `schema_version` key are version 1.
+pub trait Schema: Serialize + DeserializeOwned {
+    const VERSION: u32;
+
+    /// `UPGRADES[n]` turns a version `n + 1` mapping into version `n + 2`.
+    const UPGRADES: &'static [Upgrade] = &[];
+
+    fn validate(&self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+/// Typed [`Upgrade`] body: reads the mapping as `Old` and writes back `New`.
+pub fn upgrade<Old, New>(mapping: Mapping, f: impl FnOnce(Old) -> New) -> Result<Mapping, Error>
+where
+    Old: DeserializeOwned,
+    New: Serialize,
+{
+    let old = from_mapping(mapping)?;
+    into_mapping(&f(old))
+}
+
+/// Returns the frontmatter with the `schema_version` it was stored at, if any.
+pub(crate) fn migrate<T: Schema>(mut mapping: Mapping) -> Result<(T, Option<u32>), Error> {
+    let stored = mapping
+        .remove(VERSION_KEY)
+        .map(|value| {
+            value
+                .as_u64()
+                .and_then(|v| u32::try_from(v).ok())
+                .filter(|v| *v >= 1)
+                .ok_or_else(|| Error::invalid(VERSION_KEY, "expected a positive integer"))
+        })
+        .transpose()?;
+    let from = stored.unwrap_or(1);
+
+    if from > T::VERSION {
+        return Err(Error::UnsupportedVersion {
+            found: from,
+            supported: T::VERSION,
+        });
+    }
+
+    for version in from..T::VERSION {
+        let upgrade = T::UPGRADES
+            .get(version as usize - 1)
+            .ok_or(Error::MissingUpgrade { from: version })?;
+        mapping = upgrade(mapping)?;
+    }
+
+    let frontmatter: T = from_mapping(mapping)?;
+    frontmatter.validate()?;
+
+    Ok((frontmatter, stored))
+}
+
+pub(crate) fn into_mapping<T: Serialize>(value: &T) -> Result<Mapping, Error> {
+    match serde_yaml::to_value(value)? {
+        Value::Mapping(mapping) => Ok(mapping),
+        Value::Null => Ok(Mapping::new()),
+        _ => Err(Error::invalid(".", "frontmatter must be a mapping")),
+    }
+}
+
+fn from_mapping<T: DeserializeOwned>(mapping: Mapping) -> Result<T, Error> {
+    serde_path_to_error::deserialize(Value::Mapping(mapping)).map_err(|e| {
+        let path = e.path().to_string();
+        let message = e.into_inner().to_string();
+
+        // serde reports a missing field at its parent, so point at the field itself.
+        let key = match message
+            .strip_prefix("missing field `")
+            .and_then(|rest| rest.strip_suffix('`'))
+        {
+            Some(field) if path == "." => field.to_string(),
+            Some(field) => format!("{path}.{field}"),
+            None => path,
+        };
+
+        Error::Invalid { key, message }
+    })
+}
@@ -0,0 +1,7 @@
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("unsupported schema: {0}")]
+    UnsupportedSchema(String),
+    #[error("unresolved reference: {0}")]
+    UnresolvedReference(String),
+}
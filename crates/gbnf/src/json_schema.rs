@@ -0,0 +1,715 @@
+use std::collections::{BTreeMap, HashMap};
+
+use serde_json::{Map, Value, json};
+
+use crate::Error;
+
+// (name, body, dependencies)
+const PRIMITIVES: &[(&str, &str, &[&str])] = &[
+    ("ws", r##"| " " | "\n" [ \t]{0,20}"##, &[]),
+    (
+        "char",
+        r##"[^"\\\x7F\x00-\x1F] | "\\" (["\\bfnrt/] | "u" [0-9a-fA-F]{4})"##,
+        &[],
+    ),
+    ("string", r##""\"" char* "\"" ws"##, &["char", "ws"]),
+    (
+        "number",
+        r##""-"? ([0-9] | [1-9] [0-9]{0,15}) ("." [0-9]+)? ([eE] [-+]? [0-9]+)? ws"##,
+        &["ws"],
+    ),
+    (
+        "integer",
+        r##""-"? ([0-9] | [1-9] [0-9]{0,15}) ws"##,
+        &["ws"],
+    ),
+    ("boolean", r##"("true" | "false") ws"##, &["ws"]),
+    ("null", r##""null" ws"##, &["ws"]),
+    (
+        "value",
+        r##"object | array | string | number | boolean | null"##,
+        &["object", "array", "string", "number", "boolean", "null"],
+    ),
+    (
+        "object",
+        r##""{" ws (string ":" ws value ("," ws string ":" ws value)*)? "}" ws"##,
+        &["string", "value", "ws"],
+    ),
+    (
+        "array",
+        r##""[" ws (value ("," ws value)*)? "]" ws"##,
+        &["value", "ws"],
+    ),
+    (
+        "date",
+        r##"[0-9]{4} "-" ("0" [1-9] | "1" [0-2]) "-" ("0" [1-9] | [12] [0-9] | "3" [01])"##,
+        &[],
+    ),
+    (
+        "time",
+        r##"([01] [0-9] | "2" [0-3]) ":" [0-5] [0-9] ":" [0-5] [0-9] ("." [0-9]{1,6})? ("Z" | [+-] ([01] [0-9] | "2" [0-3]) ":" [0-5] [0-9])"##,
+        &[],
+    ),
+    ("date-string", r##""\"" date "\"" ws"##, &["date", "ws"]),
+    ("time-string", r##""\"" time "\"" ws"##, &["time", "ws"]),
+    (
+        "date-time-string",
+        r##""\"" date "T" time "\"" ws"##,
+        &["date", "time", "ws"],
+    ),
+    (
+        "uuid-string",
+        r##""\"" [0-9a-fA-F]{8} "-" [0-9a-fA-F]{4} "-" [0-9a-fA-F]{4} "-" [0-9a-fA-F]{4} "-" [0-9a-fA-F]{12} "\"" ws"##,
+        &["ws"],
+    ),
+    (
+        "email-string",
+        r##""\"" [a-zA-Z0-9._%+-]+ "@" [a-zA-Z0-9-]+ ("." [a-zA-Z0-9-]+)+ "\"" ws"##,
+        &["ws"],
+    ),
+];
+
+/// The body of a shared rule like `char`, for hand-written grammars that need the same escaping.
+pub(crate) fn primitive_body(name: &str) -> Option<&'static str> {
+    PRIMITIVES
+        .iter()
+        .find(|(p, _, _)| *p == name)
+        .map(|(_, body, _)| *body)
+}
+
+/// Compiles a JSON schema into a grammar whose `root` only accepts matching
+/// JSON documents. Supports the subset used for structured outputs: objects,
+/// arrays, enums and `const`, string formats and length bounds, `anyOf`/`oneOf`
+/// and local `$ref`s. Numeric bounds and `pattern` are not enforced.
+pub fn from_json_schema(schema: &Value) -> Result<String, Error> {
+    let mut compiler = Compiler::default();
+    compiler.document(schema, "root")?;
+    Ok(compiler.finish())
+}
+
+/// How a model family writes out its tool calls.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ToolCallFormat {
+    /// `<tool_call>{"name": .., "arguments": ..}</tool_call>`, used by Hermes and Qwen.
+    #[default]
+    Hermes,
+    /// A bare `{"name": .., "parameters": ..}` object, used by Llama 3.x.
+    Llama3,
+}
+
+/// Builds a grammar for tool calls in `format`, each calling one of `tools`
+/// (name and parameters schema) with matching arguments.
+pub fn from_tool_schemas<'a>(
+    tools: impl IntoIterator<Item = (&'a str, Option<&'a Value>)>,
+    format: ToolCallFormat,
+) -> Result<String, Error> {
+    let no_parameters = json!({ "type": "object", "additionalProperties": false });
+    let arguments_key = match format {
+        ToolCallFormat::Hermes => "arguments",
+        ToolCallFormat::Llama3 => "parameters",
+    };
+
+    let mut compiler = Compiler::default();
+    compiler.fresh("root");
+    compiler.fresh("call");
+
+    let mut calls = vec![];
+    for (name, parameters) in tools {
+        let base = sanitize(name);
+        let rule = compiler.fresh(&format!("{}-call", base));
+        let arguments = compiler.document(
+            parameters.unwrap_or(&no_parameters),
+            &format!("{}-arguments", base),
+        )?;
+        compiler.define(
+            &rule,
+            format!(
+                r##""{{" ws "\"name\"" ws ":" ws {} ws "," ws "\"{}\"" ws ":" ws {} "}}" ws"##,
+                literal(&Value::String(name.to_string())),
+                arguments_key,
+                arguments
+            ),
+        );
+        calls.push(rule);
+    }
+
+    if calls.is_empty() {
+        return Err(Error::UnsupportedSchema("no tools".to_string()));
+    }
+
+    compiler.primitive("ws");
+    match format {
+        ToolCallFormat::Hermes => {
+            compiler.define(
+                "call",
+                format!(
+                    r##""<tool_call>\n" ({}) "\n</tool_call>""##,
+                    calls.join(" | ")
+                ),
+            );
+            compiler.define("root", r##"call ("\n" call)*"##.to_string());
+        }
+        ToolCallFormat::Llama3 => {
+            compiler.define("call", calls.join(" | "));
+            compiler.define("root", "call".to_string());
+        }
+    }
+    Ok(compiler.finish())
+}
+
+#[derive(Default)]
+struct Compiler {
+    rules: BTreeMap<String, String>,
+    refs: HashMap<String, String>,
+    document: Value,
+    document_rule: String,
+}
+
+impl Compiler {
+    fn document(&mut self, schema: &Value, name: &str) -> Result<String, Error> {
+        self.document = schema.clone();
+        self.document_rule = self.fresh(name);
+        self.refs.clear();
+
+        let name = self.document_rule.clone();
+        let expr = self.visit(schema, &name)?;
+        if expr != name {
+            self.define(&name, expr);
+        }
+        Ok(name)
+    }
+
+    fn finish(self) -> String {
+        let root = std::iter::once(format!("root ::= {}", self.rules["root"]));
+        let rest = self
+            .rules
+            .iter()
+            .filter(|(name, _)| name.as_str() != "root")
+            .map(|(name, body)| format!("{} ::= {}", name, body));
+
+        root.chain(rest).collect::<Vec<_>>().join("\n")
+    }
+
+    fn define(&mut self, name: &str, body: String) {
+        self.rules.insert(name.to_string(), body);
+    }
+
+    fn fresh(&mut self, name: &str) -> String {
+        let taken = |rules: &BTreeMap<String, String>, n: &str| {
+            rules.contains_key(n) || PRIMITIVES.iter().any(|(p, _, _)| *p == n)
+        };
+
+        let mut candidate = name.to_string();
+        let mut i = 1;
+        while taken(&self.rules, &candidate) {
+            candidate = format!("{}{}", name, i);
+            i += 1;
+        }
+
+        // Reserved until the caller defines it, so recursive references resolve.
+        self.rules.insert(candidate.clone(), String::new());
+        candidate
+    }
+
+    fn primitive(&mut self, name: &str) -> String {
+        if !self.rules.contains_key(name)
+            && let Some((_, body, deps)) = PRIMITIVES.iter().find(|(p, _, _)| *p == name)
+        {
+            self.define(name, body.to_string());
+            for dep in *deps {
+                self.primitive(dep);
+            }
+        }
+        name.to_string()
+    }
+
+    fn rule(&mut self, name: &str, body: String) -> String {
+        let name = match self.rules.get(name) {
+            Some(reserved) if reserved.is_empty() => name.to_string(),
+            _ => self.fresh(name),
+        };
+        self.define(&name, body);
+        name
+    }
+
+    fn visit(&mut self, schema: &Value, name: &str) -> Result<String, Error> {
+        let schema = match schema {
+            Value::Bool(true) => return Ok(self.primitive("value")),
+            Value::Bool(false) => {
+                return Err(Error::UnsupportedSchema("false".to_string()));
+            }
+            Value::Object(schema) => schema,
+            _ => return Err(Error::UnsupportedSchema(schema.to_string())),
+        };
+
+        if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+            return self.reference(reference);
+        }
+
+        if let Some(value) = schema.get("const") {
+            self.primitive("ws");
+            return Ok(self.rule(name, format!("{} ws", literal(value))));
+        }
+
+        if let Some(values) = schema.get("enum").and_then(Value::as_array) {
+            if values.is_empty() {
+                return Err(Error::UnsupportedSchema("empty enum".to_string()));
+            }
+            self.primitive("ws");
+            let alternatives: Vec<_> = values.iter().map(literal).collect();
+            return Ok(self.rule(name, format!("({}) ws", alternatives.join(" | "))));
+        }
+
+        for key in ["anyOf", "oneOf"] {
+            if let Some(variants) = schema.get(key).and_then(Value::as_array) {
+                let alternatives = variants
+                    .iter()
+                    .enumerate()
+                    .map(|(i, variant)| self.visit(variant, &format!("{}-{}", name, i)))
+                    .collect::<Result<Vec<_>, _>>()?;
+                return Ok(self.rule(name, alternatives.join(" | ")));
+            }
+        }
+
+        if let Some(variants) = schema.get("allOf").and_then(Value::as_array) {
+            return match variants.as_slice() {
+                [variant] => self.visit(variant, name),
+                _ => Err(Error::UnsupportedSchema("allOf".to_string())),
+            };
+        }
+
+        match schema.get("type") {
+            Some(Value::Array(types)) => {
+                let alternatives = types
+                    .iter()
+                    .map(|ty| {
+                        let mut variant = schema.clone();
+                        variant.insert("type".to_string(), ty.clone());
+                        let ty = ty.as_str().unwrap_or_default();
+                        self.visit(&Value::Object(variant), &format!("{}-{}", name, ty))
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(self.rule(name, alternatives.join(" | ")))
+            }
+            Some(Value::String(ty)) => match ty.as_str() {
+                "object" => self.object(schema, name),
+                "array" => self.array(schema, name),
+                "string" => Ok(self.string(schema, name)),
+                "number" | "integer" | "boolean" | "null" => Ok(self.primitive(ty)),
+                _ => Err(Error::UnsupportedSchema(format!("type {}", ty))),
+            },
+            Some(ty) => Err(Error::UnsupportedSchema(format!("type {}", ty))),
+            None if schema.contains_key("properties") => self.object(schema, name),
+            None if schema.contains_key("items") || schema.contains_key("prefixItems") => {
+                self.array(schema, name)
+            }
+            None => Ok(self.primitive("value")),
+        }
+    }
+
+    fn reference(&mut self, reference: &str) -> Result<String, Error> {
+        if reference == "#" {
+            return Ok(self.document_rule.clone());
+        }
+        if let Some(name) = self.refs.get(reference) {
+            return Ok(name.clone());
+        }
+
+        let target = reference
+            .strip_prefix('#')
+            .and_then(|pointer| self.document.pointer(pointer))
+            .cloned()
+            .ok_or_else(|| Error::UnresolvedReference(reference.to_string()))?;
+
+        let last = reference.rsplit('/').next().unwrap_or_default();
+        let name = self.fresh(&sanitize(last));
+        self.refs.insert(reference.to_string(), name.clone());
+
+        let expr = self.visit(&target, &name)?;
+        if expr != name {
+            self.define(&name, expr);
+        }
+        Ok(name)
+    }
+
+    fn object(&mut self, schema: &Map<String, Value>, name: &str) -> Result<String, Error> {
+        self.primitive("ws");
+
+        let properties = schema
+            .get("properties")
+            .and_then(Value::as_object)
+            .filter(|p| !p.is_empty());
+
+        let Some(properties) = properties else {
+            return match schema.get("additionalProperties") {
+                Some(Value::Bool(false)) => Ok(self.rule(name, r##""{" ws "}" ws"##.to_string())),
+                Some(value @ Value::Object(_)) => {
+                    let string = self.primitive("string");
+                    let value = self.visit(value, &format!("{}-value", name))?;
+                    let entry = format!(r##"{} ":" ws {}"##, string, value);
+                    Ok(self.rule(
+                        name,
+                        format!(r##""{{" ws ({} ("," ws {})*)? "}}" ws"##, entry, entry),
+                    ))
+                }
+                _ => Ok(self.primitive("object")),
+            };
+        };
+
+        let required: Vec<&str> = schema
+            .get("required")
+            .and_then(Value::as_array)
+            .map(|r| r.iter().filter_map(Value::as_str).collect())
+            .unwrap_or_default();
+
+        let mut required_entries = vec![];
+        for key in &required {
+            if let Some(property) = properties.get(*key) {
+                required_entries.push(self.property(key, property, name)?);
+            }
+        }
+
+        let mut optional_entries = vec![];
+        for (key, property) in properties {
+            if !required.contains(&key.as_str()) {
+                optional_entries.push(self.property(key, property, name)?);
+            }
+        }
+
+        let optional = |entries: &[String]| {
+            entries
+                .iter()
+                .map(|e| format!(r##" ("," ws {})?"##, e))
+                .collect::<String>()
+        };
+
+        let body = if required_entries.is_empty() {
+            // Whichever optional property comes first can't have a leading comma.
+            let alternatives: Vec<_> = (0..optional_entries.len())
+                .map(|i| {
+                    format!(
+                        "{}{}",
+                        optional_entries[i],
+                        optional(&optional_entries[i + 1..])
+                    )
+                })
+                .collect();
+            format!(r##""{{" ws ({})? "}}" ws"##, alternatives.join(" | "))
+        } else {
+            format!(
+                r##""{{" ws {}{} "}}" ws"##,
+                required_entries.join(r##" "," ws "##),
+                optional(&optional_entries)
+            )
+        };
+
+        Ok(self.rule(name, body))
+    }
+
+    fn property(&mut self, key: &str, property: &Value, name: &str) -> Result<String, Error> {
+        let value = self.visit(property, &format!("{}-{}", name, sanitize(key)))?;
+        Ok(format!(
+            r##"{} ws ":" ws {}"##,
+            literal(&Value::String(key.to_string())),
+            value
+        ))
+    }
+
+    fn array(&mut self, schema: &Map<String, Value>, name: &str) -> Result<String, Error> {
+        self.primitive("ws");
+
+        if let Some(prefix) = schema.get("prefixItems").and_then(Value::as_array) {
+            let items = prefix
+                .iter()
+                .enumerate()
+                .map(|(i, item)| self.visit(item, &format!("{}-{}", name, i)))
+                .collect::<Result<Vec<_>, _>>()?;
+            return Ok(self.rule(
+                name,
+                format!(r##""[" ws {} "]" ws"##, items.join(r##" "," ws "##)),
+            ));
+        }
+
+        let item = match schema.get("items") {
+            Some(items) => self.visit(items, &format!("{}-item", name))?,
+            None => self.primitive("value"),
+        };
+
+        let min = bound(schema, "minItems").unwrap_or(0);
+        let max = bound(schema, "maxItems");
+        let rest = format!(r##"("," ws {})"##, item);
+
+        let body = match (min, max) {
+            (_, Some(0)) => r##""[" ws "]" ws"##.to_string(),
+            (0, max) => format!(
+                r##""[" ws ({} {})? "]" ws"##,
+                item,
+                repeat(&rest, 0, max.map(|m| m - 1))
+            ),
+            (min, max) => format!(
+                r##""[" ws {} {} "]" ws"##,
+                item,
+                repeat(&rest, min - 1, max.map(|m| m.max(min) - 1))
+            ),
+        };
+
+        Ok(self.rule(name, body))
+    }
+
+    fn string(&mut self, schema: &Map<String, Value>, name: &str) -> String {
+        let format = match schema.get("format").and_then(Value::as_str) {
+            Some("date") => Some("date-string"),
+            Some("time") => Some("time-string"),
+            Some("date-time") => Some("date-time-string"),
+            Some("uuid") => Some("uuid-string"),
+            Some("email") => Some("email-string"),
+            _ => None,
+        };
+        if let Some(format) = format {
+            return self.primitive(format);
+        }
+
+        let min = bound(schema, "minLength").unwrap_or(0);
+        let max = bound(schema, "maxLength");
+        if min == 0 && max.is_none() {
+            return self.primitive("string");
+        }
+
+        self.primitive("char");
+        self.primitive("ws");
+        self.rule(
+            name,
+            format!(r##""\"" {} "\"" ws"##, repeat("char", min, max)),
+        )
+    }
+}
+
+fn bound(schema: &Map<String, Value>, key: &str) -> Option<u64> {
+    schema.get(key).and_then(Value::as_u64)
+}
+
+fn repeat(item: &str, min: u64, max: Option<u64>) -> String {
+    match (min, max) {
+        (0, None) => format!("{}*", item),
+        (1, None) => format!("{}+", item),
+        (0, Some(1)) => format!("{}?", item),
+        (min, None) => format!("{}{{{},}}", item, min),
+        (min, Some(max)) if min == max => format!("{}{{{}}}", item, min),
+        (min, Some(max)) => format!("{}{{{},{}}}", item, min, max),
+    }
+}
+
+// Matches `value` serialized as compact JSON.
+fn literal(value: &Value) -> String {
+    let json = value.to_string();
+    let mut out = String::with_capacity(json.len() + 2);
+    out.push('"');
+    for c in json.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn sanitize(name: &str) -> String {
+    let name: String = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect();
+
+    if name.is_empty() {
+        "rule".to_string()
+    } else {
+        name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_object_schema() {
+        let grammar = from_json_schema(&json!({
+            "type": "object",
+            "properties": {
+                "title": { "type": "string", "minLength": 1, "maxLength": 80 },
+                "date": { "type": "string", "format": "date" },
+                "priority": { "enum": ["low", "high"] },
+                "tags": { "type": "array", "items": { "type": "string" }, "maxItems": 3 },
+                "owner": { "type": ["string", "null"] }
+            },
+            "required": ["title", "priority"]
+        }))
+        .unwrap();
+
+        insta::assert_snapshot!(grammar, @r#"
+        root ::= "{" ws "\"title\"" ws ":" ws root-title "," ws "\"priority\"" ws ":" ws root-priority ("," ws "\"date\"" ws ":" ws date-string)? ("," ws "\"owner\"" ws ":" ws root-owner)? ("," ws "\"tags\"" ws ":" ws root-tags)? "}" ws
+        char ::= [^"\\\x7F\x00-\x1F] | "\\" (["\\bfnrt/] | "u" [0-9a-fA-F]{4})
+        date ::= [0-9]{4} "-" ("0" [1-9] | "1" [0-2]) "-" ("0" [1-9] | [12] [0-9] | "3" [01])
+        date-string ::= "\"" date "\"" ws
+        null ::= "null" ws
+        root-owner ::= string | null
+        root-priority ::= ("\"low\"" | "\"high\"") ws
+        root-tags ::= "[" ws (string ("," ws string){0,2})? "]" ws
+        root-title ::= "\"" char{1,80} "\"" ws
+        string ::= "\"" char* "\"" ws
+        ws ::= | " " | "\n" [ \t]{0,20}
+        "#);
+    }
+
+    #[test]
+    fn test_optional_only_object() {
+        let grammar = from_json_schema(&json!({
+            "type": "object",
+            "properties": {
+                "a": { "type": "integer" },
+                "b": { "type": "boolean" }
+            }
+        }))
+        .unwrap();
+
+        insta::assert_snapshot!(grammar, @r#"
+        root ::= "{" ws ("\"a\"" ws ":" ws integer ("," ws "\"b\"" ws ":" ws boolean)? | "\"b\"" ws ":" ws boolean)? "}" ws
+        boolean ::= ("true" | "false") ws
+        integer ::= "-"? ([0-9] | [1-9] [0-9]{0,15}) ws
+        ws ::= | " " | "\n" [ \t]{0,20}
+        "#);
+    }
+
+    #[test]
+    fn test_recursive_ref() {
+        let grammar = from_json_schema(&json!({
+            "$ref": "#/$defs/node",
+            "$defs": {
+                "node": {
+                    "type": "object",
+                    "properties": {
+                        "name": { "type": "string" },
+                        "children": { "type": "array", "items": { "$ref": "#/$defs/node" } }
+                    },
+                    "required": ["name", "children"]
+                }
+            }
+        }))
+        .unwrap();
+
+        insta::assert_snapshot!(grammar, @r#"
+        root ::= node
+        char ::= [^"\\\x7F\x00-\x1F] | "\\" (["\\bfnrt/] | "u" [0-9a-fA-F]{4})
+        node ::= "{" ws "\"name\"" ws ":" ws string "," ws "\"children\"" ws ":" ws node-children "}" ws
+        node-children ::= "[" ws (node ("," ws node)*)? "]" ws
+        string ::= "\"" char* "\"" ws
+        ws ::= | " " | "\n" [ \t]{0,20}
+        "#);
+    }
+
+    #[test]
+    fn test_tool_schemas() {
+        let parameters = json!({
+            "type": "object",
+            "properties": { "queries": { "type": "array", "items": { "type": "string" }, "minItems": 1 } },
+            "required": ["queries"]
+        });
+
+        let grammar = from_tool_schemas(
+            [("search_vault", Some(&parameters)), ("list_events", None)],
+            ToolCallFormat::Hermes,
+        )
+        .unwrap();
+
+        insta::assert_snapshot!(grammar, @r#"
+        root ::= call ("\n" call)*
+        call ::= "<tool_call>\n" (search-vault-call | list-events-call) "\n</tool_call>"
+        char ::= [^"\\\x7F\x00-\x1F] | "\\" (["\\bfnrt/] | "u" [0-9a-fA-F]{4})
+        list-events-arguments ::= "{" ws "}" ws
+        list-events-call ::= "{" ws "\"name\"" ws ":" ws "\"list_events\"" ws "," ws "\"arguments\"" ws ":" ws list-events-arguments "}" ws
+        search-vault-arguments ::= "{" ws "\"queries\"" ws ":" ws search-vault-arguments-queries "}" ws
+        search-vault-arguments-queries ::= "[" ws string ("," ws string)* "]" ws
+        search-vault-call ::= "{" ws "\"name\"" ws ":" ws "\"search_vault\"" ws "," ws "\"arguments\"" ws ":" ws search-vault-arguments "}" ws
+        string ::= "\"" char* "\"" ws
+        ws ::= | " " | "\n" [ \t]{0,20}
+        "#);
+    }
+
+    #[test]
+    fn test_llama3_tool_schemas() {
+        let parameters = json!({
+            "type": "object",
+            "properties": { "query": { "type": "string" } },
+            "required": ["query"]
+        });
+
+        let grammar = from_tool_schemas(
+            [("search_vault", Some(&parameters))],
+            ToolCallFormat::Llama3,
+        )
+        .unwrap();
+
+        insta::assert_snapshot!(grammar, @r#"
+        root ::= call
+        call ::= search-vault-call
+        char ::= [^"\\\x7F\x00-\x1F] | "\\" (["\\bfnrt/] | "u" [0-9a-fA-F]{4})
+        search-vault-arguments ::= "{" ws "\"query\"" ws ":" ws string "}" ws
+        search-vault-call ::= "{" ws "\"name\"" ws ":" ws "\"search_vault\"" ws "," ws "\"parameters\"" ws ":" ws search-vault-arguments "}" ws
+        string ::= "\"" char* "\"" ws
+        ws ::= | " " | "\n" [ \t]{0,20}
+        "#);
+    }
+
+    #[test]
+    fn test_unsupported_schema() {
+        assert!(matches!(
+            from_json_schema(&json!({ "$ref": "#/$defs/missing" })),
+            Err(Error::UnresolvedReference(_))
+        ));
+        assert!(matches!(
+            from_json_schema(&json!({ "allOf": [{ "type": "string" }, { "type": "number" }] })),
+            Err(Error::UnsupportedSchema(_))
+        ));
+    }
+
+    #[test]
+    #[ignore]
+    fn test_json_schema_grammar() {
+        let gbnf = gbnf_validator::Validator::new().unwrap();
+
+        let grammar = from_json_schema(&json!({
+            "type": "object",
+            "properties": {
+                "title": { "type": "string", "maxLength": 20 },
+                "due": { "type": "string", "format": "date" },
+                "done": { "type": "boolean" }
+            },
+            "required": ["title", "done"]
+        }))
+        .unwrap();
+
+        for (input, expected) in [
+            (r#"{"title": "Ship it", "done": false}"#, true),
+            (
+                r#"{"title": "Ship it", "done": true, "due": "2025-01-10"}"#,
+                true,
+            ),
+            (
+                r#"{"title": "Ship it", "done": true, "due": "next week"}"#,
+                false,
+            ),
+            (r#"{"done": true}"#, false),
+            (
+                r#"{"title": "This title is far too long", "done": true}"#,
+                false,
+            ),
+        ] {
+            let result = gbnf.validate(&grammar, input).unwrap();
+            assert_eq!(result, expected, "failed: {}", input);
+        }
+    }
+}
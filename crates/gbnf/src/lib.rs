@@ -1,5 +1,11 @@
 // https://github.com/ggml-org/llama.cpp/blob/master/grammars/README.md
 
+mod error;
+mod json_schema;
+
+pub use error::*;
+pub use json_schema::*;
+
 #[derive(specta::Type, serde::Serialize, serde::Deserialize)]
 #[serde(tag = "task")]
 pub enum Grammar {
@@ -11,6 +17,8 @@ pub enum Grammar {
     Tags,
     #[serde(rename = "email-to-name")]
     EmailToName,
+    #[serde(rename = "action-items")]
+    ActionItems,
 }
 
 impl Grammar {
@@ -20,6 +28,7 @@ impl Grammar {
             Grammar::Title => build_title_grammar(),
             Grammar::Tags => build_tags_grammar(),
             Grammar::EmailToName => build_email_to_name_grammar(),
+            Grammar::ActionItems => build_action_items_grammar(),
         }
     }
 }
@@ -98,6 +107,21 @@ fn build_email_to_name_grammar() -> String {
     .join("\n")
 }
 
+fn build_action_items_grammar() -> String {
+    let char = format!("char ::= {}", json_schema::primitive_body("char").unwrap());
+
+    [
+        r##"root ::= "{" ws "\"action_items\"" ws ":" ws "[" ws (item (ws "," ws item)*)? ws "]" ws "," ws "\"decisions\"" ws ":" ws "[" ws (decision (ws "," ws decision)*)? ws "]" ws "}" ws"##,
+        r##"item ::= "{" ws "\"description\"" ws ":" ws string ws "," ws "\"owner\"" ws ":" ws (string | "null") ws "," ws "\"due_date\"" ws ":" ws (date | "null") ws "}""##,
+        r##"decision ::= "{" ws "\"description\"" ws ":" ws string ws "}""##,
+        r##"string ::= "\"" char+ "\"""##,
+        &char,
+        r##"date ::= "\"" [0-9] [0-9] [0-9] [0-9] "-" [0-1] [0-9] "-" [0-3] [0-9] "\"""##,
+        r##"ws ::= [ \t\n]*"##,
+    ]
+    .join("\n")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -170,6 +194,52 @@ mod tests {
         }
     }
 
+    #[test]
+    #[ignore]
+    fn test_action_items_grammar() {
+        let gbnf = gbnf_validator::Validator::new().unwrap();
+
+        for (input, expected) in [
+            (
+                serde_json::json!({
+                    "action_items": [
+                        {"description": "Send the release notes", "owner": "Jane Doe", "due_date": "2025-01-10"},
+                        {"description": "Book a room", "owner": null, "due_date": null},
+                    ],
+                    "decisions": [{"description": "Launch on Monday"}],
+                })
+                .to_string(),
+                true,
+            ),
+            (
+                serde_json::json!({"action_items": [], "decisions": []}).to_string(),
+                true,
+            ),
+            (
+                serde_json::json!({
+                    "action_items": [],
+                    "decisions": [{"description": "Call it \"v2\" in the changelog"}],
+                })
+                .to_string(),
+                true,
+            ),
+            (
+                serde_json::json!({
+                    "action_items": [{"description": "Book a room", "owner": null, "due_date": "next week"}],
+                    "decisions": [],
+                })
+                .to_string(),
+                false,
+            ),
+            (serde_json::json!({"action_items": []}).to_string(), false),
+        ] {
+            let result = gbnf
+                .validate(&build_action_items_grammar(), &input)
+                .unwrap();
+            assert_eq!(result, expected, "failed: {}", input);
+        }
+    }
+
     #[test]
     fn test_enhance_grammar() {
         let input_1 = "<headers>\n- Objective\n- Key Takeaways\n- Importance of Complementary Skills\n- Benefits of Using Online Resources\n- Advice for Undergrad Students\n</headers># Objective\n\n- **Search is the Best Way to Find Answers**: The speaker emphasizes the importance of utilizing online resources like Google to find answers to questions.\n- **Value in Complementary Skills**: The speaker highlights the need to acquire complementary skills to traditional research methods.\n\n# Key Takeaways\n\n- **Complementary skills include both traditional research and online resource utilization**: The speaker suggests that skills like using a blank sheet of paper with no Internet and effective Google searching are essential.\n- **Online resources can help find pre-solved problems**: The speaker advises investing time in finding existing resources and communities that have already solved problems.\n\n# Importance of Complementary Skills\n\n- **Traditional research is just the starting point**: The speaker suggests that traditional research methods are just the beginning and should be complemented with other skills.\n- **Effective use of online resources can save time and effort**: The speaker highlights the benefits of utilizing online resources in research and problem-solving.\n\n# Benefits of Using Online Resources\n\n- **Access to knowledge from experts and communities**: The speaker suggests that online resources provide access to knowledge and expertise from experienced individuals.\n- **Time-saving and efficient**: The speaker emphasizes the benefits of finding pre-solved problems through online resources.\n\n# Advice for Undergrad Students\n\n- **Start by searching online**: The speaker advises undergrad students to start by searching online for answers to questions and exploring different resources.\n- **Be open to finding existing solutions**: The speaker emphasizes the importance of being open to finding pre-solved problems and leveraging existing resources.\n\n";
@@ -62,6 +62,18 @@ struct WordInTranscript {
     channel: i32,
 }
 
+#[derive(Serialize)]
+struct SpeakerHintInTranscript {
+    id: String,
+    user_id: String,
+    created_at: String,
+    transcript_id: String,
+    word_id: String,
+    #[serde(rename = "type")]
+    hint_type: String,
+    value: String,
+}
+
 #[derive(Serialize)]
 struct SessionParticipantOutput {
     user_id: String,
@@ -216,6 +228,21 @@ fn insert_transcripts_and_words(tables: &mut Map<String, Value>, data: &Collecti
             })
             .collect();
 
+        let hints_json: Vec<SpeakerHintInTranscript> = transcript
+            .speaker_hints
+            .iter()
+            .enumerate()
+            .map(|(idx, hint)| SpeakerHintInTranscript {
+                id: format!("{}-hint-{}", transcript.id, idx),
+                user_id: user_id.to_string(),
+                created_at: normalize_datetime(&transcript.created_at),
+                transcript_id: transcript.id.clone(),
+                word_id: hint.word_id.clone(),
+                hint_type: hint.hint_type.clone(),
+                value: hint.value.clone(),
+            })
+            .collect();
+
         let value = TranscriptOutput {
             user_id: user_id.to_string(),
             created_at: normalize_datetime(&transcript.created_at),
@@ -223,7 +250,7 @@ fn insert_transcripts_and_words(tables: &mut Map<String, Value>, data: &Collecti
             started_at: transcript.start_ms.unwrap_or(0.0) as i64,
             ended_at: transcript.end_ms.map(|ms| ms as i64).unwrap_or(0),
             words: serde_json::to_string(&words_json).unwrap_or_else(|_| "[]".to_string()),
-            speaker_hints: "[]".to_string(),
+            speaker_hints: serde_json::to_string(&hints_json).unwrap_or_else(|_| "[]".to_string()),
         };
         transcript_entries.insert(transcript.id.clone(), serde_json::to_value(value).unwrap());
     }
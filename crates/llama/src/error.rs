@@ -2,6 +2,8 @@ use serde::{Serialize, ser::Serializer};
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
+    #[error(transparent)]
+    GrammarError(#[from] hypr_gbnf::Error),
     #[error(transparent)]
     GgufError(#[from] hypr_gguf::Error),
     #[error(transparent)]
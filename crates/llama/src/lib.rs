@@ -17,10 +17,12 @@ use hypr_gguf::GgufExt;
 
 mod error;
 mod parser;
+mod structured;
 mod types;
 
 pub use error::*;
 pub use parser::{Response, StreamingParser};
+pub use structured::*;
 pub use types::*;
 
 const DEFAULT_MAX_INPUT_TOKENS: u32 = 1024 * 16;
@@ -36,12 +38,14 @@ pub enum ModelName {
 
 pub struct Llama {
     pub name: ModelName,
+    pub tool_call_format: ToolCallFormat,
     task_sender: tokio::sync::mpsc::UnboundedSender<Task>,
 }
 
 pub enum Task {
     Generate {
         request: LlamaRequest,
+        tool_grammar: Option<ToolGrammar>,
         response_sender: tokio::sync::mpsc::UnboundedSender<Response>,
         callback: Box<dyn FnMut(f64) + Send + 'static>,
         cancellation_token: CancellationToken,
@@ -84,7 +88,11 @@ impl Llama {
         }
     }
 
-    fn get_sampler(model: &LlamaModel, grammar: Option<&str>) -> LlamaSampler {
+    fn get_sampler(
+        model: &LlamaModel,
+        grammar: Option<&str>,
+        tool_grammar: Option<&ToolGrammar>,
+    ) -> LlamaSampler {
         let mut samplers = Vec::new();
 
         if let Some(grammar) = grammar {
@@ -95,6 +103,11 @@ impl Llama {
             if cfg!(debug_assertions) {
                 println!("---\n{:?}\n---", grammar);
             }
+        } else if let Some(ToolGrammar::Lazy { grammar, triggers }) = tool_grammar
+            && let Some(grammar_sampler) =
+                LlamaSampler::grammar_lazy(model, grammar, "root", triggers, &[])
+        {
+            samplers.push(grammar_sampler);
         }
 
         {
@@ -140,7 +153,8 @@ impl Llama {
                     "messages": request.messages,
                     "tools": request.tools,
                     "add_generation_prompt": true,
-                    "enable_thinking": true
+                    // A grammar leaves no room for a `<think>` block before the answer.
+                    "enable_thinking": request.grammar.is_none()
                 }))
                 .unwrap()
         };
@@ -253,6 +267,8 @@ impl Llama {
         mut batch: LlamaBatch,
         last_index: i32,
         request: &LlamaRequest,
+        tool_grammar: Option<&ToolGrammar>,
+        tool_call_format: ToolCallFormat,
         response_sender: tokio::sync::mpsc::UnboundedSender<Response>,
         progress_data_ptr: *mut std::ffi::c_void,
         cancellation_token: CancellationToken,
@@ -260,8 +276,8 @@ impl Llama {
     ) {
         let mut n_cur = batch.n_tokens();
         let mut decoder = encoding_rs::UTF_8.new_decoder();
-        let mut sampler = Self::get_sampler(model, request.grammar.as_deref());
-        let mut parser = StreamingParser::new();
+        let mut sampler = Self::get_sampler(model, request.grammar.as_deref(), tool_grammar);
+        let mut parser = StreamingParser::with_format(tool_call_format);
 
         'generation: while n_cur <= last_index + max_output_tokens as i32 {
             if cancellation_token.is_cancelled() || response_sender.is_closed() {
@@ -301,6 +317,12 @@ impl Llama {
             ctx.decode(&mut batch).unwrap();
         }
 
+        for response in parser.finish() {
+            if response_sender.send(response).is_err() {
+                break;
+            }
+        }
+
         drop(response_sender);
 
         unsafe {
@@ -316,6 +338,7 @@ impl Llama {
         Self::setup_log();
 
         let template = model_path.chat_format()?.unwrap();
+        let tool_call_format = tool_call_format(&template);
 
         let backend = Self::get_backend();
         let model = Self::load_model(model_path)?;
@@ -333,6 +356,7 @@ impl Llama {
                     match task {
                         Task::Generate {
                             request,
+                            tool_grammar,
                             response_sender,
                             callback,
                             cancellation_token,
@@ -358,6 +382,8 @@ impl Llama {
                                         batch,
                                         last_index,
                                         &request,
+                                        tool_grammar.as_ref(),
+                                        tool_call_format,
                                         response_sender,
                                         progress_data_ptr,
                                         cancellation_token,
@@ -375,7 +401,11 @@ impl Llama {
             }
         });
 
-        Ok(Self { name, task_sender })
+        Ok(Self {
+            name,
+            tool_call_format,
+            task_sender,
+        })
     }
 
     pub fn generate_stream(
@@ -390,7 +420,7 @@ impl Llama {
 
     pub fn generate_stream_with_callback(
         &self,
-        request: LlamaRequest,
+        mut request: LlamaRequest,
         callback: Box<dyn FnMut(f64) + Send + 'static>,
     ) -> Result<
         (
@@ -403,8 +433,24 @@ impl Llama {
             tokio::sync::mpsc::unbounded_channel::<Response>();
         let cancellation_token = CancellationToken::new();
 
+        // An explicit grammar already decides the shape of the reply.
+        let tool_grammar = match (&request.grammar, &request.tools) {
+            (None, Some(tools)) => {
+                tool_grammar(tools, request.tool_choice.as_ref(), self.tool_call_format)?
+            }
+            _ => None,
+        };
+        let tool_grammar = match tool_grammar {
+            Some(ToolGrammar::Strict(grammar)) => {
+                request.grammar = Some(grammar);
+                None
+            }
+            lazy => lazy,
+        };
+
         let task = Task::Generate {
             request,
+            tool_grammar,
             response_sender,
             callback,
             cancellation_token: cancellation_token.clone(),
@@ -456,10 +502,12 @@ mod tests {
                 LlamaMessage {
                     role: "system".into(),
                     content: "Summarize the text the user gives you.".into(),
+                    ..Default::default()
                 },
                 LlamaMessage {
                     role: "user".into(),
                     content: hypr_data::english_3::WORDS_JSON.repeat(1),
+                    ..Default::default()
                 },
             ],
             ..Default::default()
@@ -475,6 +523,7 @@ mod tests {
             messages: vec![LlamaMessage {
                 role: "user".into(),
                 content: "response, and say hi".into(),
+                ..Default::default()
             }],
             tools: Some(vec![
                 async_openai::types::ChatCompletionTool {
@@ -522,10 +571,12 @@ mod tests {
                 LlamaMessage {
                     role: "system".into(),
                     content: "You are helpful assistamt.".into(),
+                    ..Default::default()
                 },
                 LlamaMessage {
                     role: "user".into(),
                     content: "hello".into(),
+                    ..Default::default()
                 },
             ],
             max_tokens: Some(5),
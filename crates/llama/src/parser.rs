@@ -7,6 +7,8 @@ use nom::{
 };
 use std::collections::HashMap;
 
+use crate::ToolCallFormat;
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum Response {
     TextDelta(String),
@@ -19,6 +21,9 @@ pub enum Response {
 
 pub struct StreamingParser {
     buffer: String,
+    format: ToolCallFormat,
+    // Llama 3.x calls are bare JSON, so only the start of a reply can be one.
+    in_text: bool,
 }
 
 impl Default for StreamingParser {
@@ -29,8 +34,14 @@ impl Default for StreamingParser {
 
 impl StreamingParser {
     pub fn new() -> Self {
+        Self::with_format(ToolCallFormat::default())
+    }
+
+    pub fn with_format(format: ToolCallFormat) -> Self {
         Self {
             buffer: String::new(),
+            format,
+            in_text: false,
         }
     }
 
@@ -43,14 +54,35 @@ impl StreamingParser {
         }
 
         if !self.buffer.is_empty() && !self.looks_like_block_start() {
-            let text = self.buffer.clone();
-            self.buffer.clear();
-            responses.push(Response::TextDelta(text));
+            responses.push(self.take_text());
+        }
+
+        responses
+    }
+
+    /// Flushes whatever is left once generation stops, e.g. an unterminated block.
+    pub fn finish(&mut self) -> Vec<Response> {
+        let mut responses = Vec::new();
+
+        while let Some(response) = self.try_parse_next() {
+            responses.push(response);
+        }
+
+        if !self.buffer.is_empty() {
+            responses.push(self.take_text());
         }
 
         responses
     }
 
+    fn take_text(&mut self) -> Response {
+        let text = std::mem::take(&mut self.buffer);
+        if !text.trim().is_empty() {
+            self.in_text = true;
+        }
+        Response::TextDelta(text)
+    }
+
     fn try_parse_next(&mut self) -> Option<Response> {
         if let Ok((remaining, content)) = parse_think_block(&self.buffer) {
             self.buffer = remaining.to_string();
@@ -65,11 +97,33 @@ impl StreamingParser {
             return Some(Response::ToolCall { name, arguments });
         }
 
+        if self.format == ToolCallFormat::Llama3 && !self.in_text {
+            match parse_json_tool_call(&self.buffer) {
+                JsonToolCall::Complete {
+                    name,
+                    arguments,
+                    consumed,
+                } => {
+                    self.buffer = self.buffer[consumed..].to_string();
+                    return Some(Response::ToolCall { name, arguments });
+                }
+                JsonToolCall::Incomplete => return None,
+                JsonToolCall::NotToolCall => {
+                    if !self.buffer.trim().is_empty() {
+                        self.in_text = true;
+                    }
+                }
+            }
+        }
+
         if let Some(pos) = self.find_next_block_start()
             && pos > 0
         {
             let text = self.buffer[..pos].to_string();
             self.buffer = self.buffer[pos..].to_string();
+            if !text.trim().is_empty() {
+                self.in_text = true;
+            }
             return Some(Response::TextDelta(text));
         }
 
@@ -77,8 +131,15 @@ impl StreamingParser {
     }
 
     fn looks_like_block_start(&self) -> bool {
-        self.buffer.trim_start().starts_with("<think>")
-            || self.buffer.trim_start().starts_with("<tool_call>")
+        let trimmed = self.buffer.trim_start();
+
+        trimmed.starts_with("<think>")
+            || trimmed.starts_with("<tool_call>")
+            || (self.format == ToolCallFormat::Llama3
+                && !self.in_text
+                && (trimmed.is_empty()
+                    || trimmed.starts_with('{')
+                    || trimmed.starts_with(LLAMA3_PYTHON_TAG)))
     }
 
     fn find_next_block_start(&self) -> Option<usize> {
@@ -94,6 +155,67 @@ impl StreamingParser {
     }
 }
 
+const LLAMA3_PYTHON_TAG: &str = "<|python_tag|>";
+
+enum JsonToolCall {
+    Complete {
+        name: String,
+        arguments: HashMap<String, serde_json::Value>,
+        consumed: usize,
+    },
+    Incomplete,
+    NotToolCall,
+}
+
+// `{"name": "f", "parameters": {..}}`, optionally after `<|python_tag|>`.
+// Several calls may follow each other, separated by `;`.
+fn parse_json_tool_call(input: &str) -> JsonToolCall {
+    let start = input.len() - input.trim_start().len();
+    let mut offset = start;
+    if input[offset..].starts_with(LLAMA3_PYTHON_TAG) {
+        offset += LLAMA3_PYTHON_TAG.len();
+    }
+
+    let body = &input[offset..];
+    let body_start = body.len() - body.trim_start().len();
+    if body.trim().is_empty() {
+        return JsonToolCall::Incomplete;
+    }
+    if !body[body_start..].starts_with('{') {
+        return JsonToolCall::NotToolCall;
+    }
+
+    let mut values =
+        serde_json::Deserializer::from_str(&body[body_start..]).into_iter::<serde_json::Value>();
+    let value = match values.next() {
+        Some(Ok(value)) => value,
+        Some(Err(e)) if e.is_eof() => return JsonToolCall::Incomplete,
+        _ => return JsonToolCall::NotToolCall,
+    };
+
+    let Some(name) = value["name"].as_str() else {
+        return JsonToolCall::NotToolCall;
+    };
+    let arguments = match value.get("parameters").or_else(|| value.get("arguments")) {
+        Some(serde_json::Value::Object(map)) => map.clone().into_iter().collect(),
+        Some(serde_json::Value::String(s)) => serde_json::from_str(s).unwrap_or_default(),
+        _ => return JsonToolCall::NotToolCall,
+    };
+
+    let mut consumed = offset + body_start + values.byte_offset();
+    let rest = &input[consumed..];
+    let separator = rest.trim_start();
+    if let Some(after) = separator.strip_prefix(';') {
+        consumed += rest.len() - after.len();
+    }
+
+    JsonToolCall::Complete {
+        name: name.to_string(),
+        arguments,
+        consumed,
+    }
+}
+
 fn parse_think_block(input: &str) -> IResult<&str, String> {
     let mut parser = map(
         terminated(
@@ -121,7 +243,11 @@ fn parse_tool_call_block(input: &str) -> IResult<&str, (String, String)> {
         .ok_or_else(|| nom::Err::Error(nom::error::Error::new(input, nom::error::ErrorKind::Tag)))?
         .to_string();
 
-    let arguments = parsed["arguments"].to_string();
+    // Some models encode the arguments as a JSON string.
+    let arguments = match &parsed["arguments"] {
+        serde_json::Value::String(s) => s.clone(),
+        v => v.to_string(),
+    };
 
     Ok((input, (name, arguments)))
 }
@@ -231,6 +357,108 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_string_arguments() {
+        let mut parser = StreamingParser::new();
+
+        let items = parser.process_chunk(concat!(
+            "<tool_call>\n",
+            r#"{"name": "greet", "arguments": "{\"text\": \"Hi\"}"}"#,
+            "\n</tool_call>"
+        ));
+
+        assert_eq!(
+            items,
+            vec![Response::ToolCall {
+                name: "greet".to_string(),
+                arguments: HashMap::from([(
+                    "text".to_string(),
+                    serde_json::Value::String("Hi".to_string())
+                )])
+            }]
+        );
+    }
+
+    #[test]
+    fn test_llama3_tool_calls() {
+        let mut parser = StreamingParser::with_format(ToolCallFormat::Llama3);
+
+        let items = {
+            let mut items = vec![];
+            items.extend(parser.process_chunk("<|python_tag|>"));
+            items.extend(parser.process_chunk(r#"{"name": "greet", "#));
+            items.extend(parser.process_chunk(r#""parameters": {"text": "Hi"}}; "#));
+            items.extend(parser.process_chunk(r#"{"name": "wave", "parameters": {}}"#));
+            items.extend(parser.finish());
+            items
+        };
+
+        assert_eq!(
+            items,
+            vec![
+                Response::ToolCall {
+                    name: "greet".to_string(),
+                    arguments: HashMap::from([(
+                        "text".to_string(),
+                        serde_json::Value::String("Hi".to_string())
+                    )])
+                },
+                Response::ToolCall {
+                    name: "wave".to_string(),
+                    arguments: HashMap::new()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_llama3_text() {
+        let mut parser = StreamingParser::with_format(ToolCallFormat::Llama3);
+
+        let items = {
+            let mut items = vec![];
+            items.extend(parser.process_chunk("Sure, "));
+            items.extend(parser.process_chunk(r#"{"name": "greet"}"#));
+            items.extend(parser.finish());
+            items
+        };
+
+        assert_eq!(
+            items,
+            vec![
+                Response::TextDelta("Sure, ".to_string()),
+                Response::TextDelta(r#"{"name": "greet"}"#.to_string()),
+            ]
+        );
+
+        let mut parser = StreamingParser::with_format(ToolCallFormat::Llama3);
+        let items = {
+            let mut items = vec![];
+            items.extend(parser.process_chunk(r#"{"title": "#));
+            items.extend(parser.process_chunk(r#""Standup"}"#));
+            items
+        };
+
+        assert_eq!(
+            items,
+            vec![Response::TextDelta(r#"{"title": "Standup"}"#.to_string())]
+        );
+    }
+
+    #[test]
+    fn test_finish_flushes_unterminated_block() {
+        let mut parser = StreamingParser::new();
+
+        let mut items = parser.process_chunk("<tool_call>\n{\"name\": ");
+        assert!(items.is_empty());
+
+        items.extend(parser.finish());
+        assert_eq!(
+            items,
+            vec![Response::TextDelta("<tool_call>\n{\"name\": ".to_string())]
+        );
+    }
+
     #[test]
     fn test_summary() {
         let reasoning = r###"
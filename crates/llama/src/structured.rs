@@ -0,0 +1,155 @@
+use async_openai::types::{
+    ChatCompletionTool, ChatCompletionToolChoiceOption, CreateChatCompletionRequest, ResponseFormat,
+};
+
+pub use hypr_gbnf::ToolCallFormat;
+
+/// Grammar for requests that ask for a JSON schema, so local models return the
+/// same structured output as hosted ones. Tool calls are constrained separately,
+/// by the model, since their syntax depends on its chat template.
+pub fn grammar_from_openai(
+    request: &CreateChatCompletionRequest,
+) -> Result<Option<String>, crate::Error> {
+    let schema = match &request.response_format {
+        Some(ResponseFormat::JsonSchema { json_schema }) => json_schema
+            .schema
+            .clone()
+            .unwrap_or_else(|| serde_json::json!({})),
+        Some(ResponseFormat::JsonObject) => serde_json::json!({ "type": "object" }),
+        _ => return Ok(None),
+    };
+
+    Ok(Some(hypr_gbnf::from_json_schema(&schema)?))
+}
+
+pub enum ToolGrammar {
+    /// The model must call a tool right away.
+    Strict(String),
+    /// Only enforced once the model starts a tool call, so it can still answer in prose.
+    Lazy {
+        grammar: String,
+        triggers: Vec<String>,
+    },
+}
+
+pub fn tool_call_format(chat_template: &str) -> ToolCallFormat {
+    if !chat_template.contains("<tool_call>") && chat_template.contains("<|start_header_id|>") {
+        ToolCallFormat::Llama3
+    } else {
+        ToolCallFormat::Hermes
+    }
+}
+
+pub(crate) fn tool_grammar(
+    tools: &[ChatCompletionTool],
+    tool_choice: Option<&ChatCompletionToolChoiceOption>,
+    format: ToolCallFormat,
+) -> Result<Option<ToolGrammar>, crate::Error> {
+    let tools: Vec<_> = match tool_choice {
+        Some(ChatCompletionToolChoiceOption::None) => return Ok(None),
+        Some(ChatCompletionToolChoiceOption::Named(choice)) => tools
+            .iter()
+            .filter(|tool| tool.function.name == choice.function.name)
+            .collect(),
+        _ => tools.iter().collect(),
+    };
+
+    if tools.is_empty() {
+        return Ok(None);
+    }
+
+    let grammar = hypr_gbnf::from_tool_schemas(
+        tools.iter().map(|tool| {
+            (
+                tool.function.name.as_str(),
+                tool.function.parameters.as_ref(),
+            )
+        }),
+        format,
+    )?;
+
+    Ok(Some(match tool_choice {
+        Some(ChatCompletionToolChoiceOption::Required)
+        | Some(ChatCompletionToolChoiceOption::Named(_)) => ToolGrammar::Strict(grammar),
+        _ => ToolGrammar::Lazy {
+            grammar,
+            triggers: match format {
+                ToolCallFormat::Hermes => vec!["<tool_call>".to_string()],
+                ToolCallFormat::Llama3 => vec!["{\"name\"".to_string()],
+            },
+        },
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_openai::types::{
+        ChatCompletionNamedToolChoice, ChatCompletionToolType, FunctionName, FunctionObject,
+    };
+
+    fn tool(name: &str) -> ChatCompletionTool {
+        ChatCompletionTool {
+            r#type: ChatCompletionToolType::Function,
+            function: FunctionObject {
+                name: name.into(),
+                description: None,
+                strict: None,
+                parameters: Some(serde_json::json!({
+                    "type": "object",
+                    "properties": { "text": { "type": "string" } },
+                    "required": ["text"]
+                })),
+            },
+        }
+    }
+
+    #[test]
+    fn test_tool_grammar() {
+        let tools = vec![tool("greet"), tool("wave")];
+
+        let Some(ToolGrammar::Lazy { grammar, triggers }) =
+            tool_grammar(&tools, None, ToolCallFormat::Hermes).unwrap()
+        else {
+            panic!("expected a lazy grammar");
+        };
+        assert!(grammar.contains("greet-call | wave-call"));
+        assert_eq!(triggers, vec!["<tool_call>".to_string()]);
+
+        let named = ChatCompletionToolChoiceOption::Named(ChatCompletionNamedToolChoice {
+            r#type: ChatCompletionToolType::Function,
+            function: FunctionName {
+                name: "wave".into(),
+            },
+        });
+        let Some(ToolGrammar::Strict(grammar)) =
+            tool_grammar(&tools, Some(&named), ToolCallFormat::Llama3).unwrap()
+        else {
+            panic!("expected a strict grammar");
+        };
+        assert!(grammar.contains("\"\\\"parameters\\\"\""));
+        assert!(!grammar.contains("greet"));
+
+        assert!(
+            tool_grammar(
+                &tools,
+                Some(&ChatCompletionToolChoiceOption::None),
+                ToolCallFormat::Hermes
+            )
+            .unwrap()
+            .is_none()
+        );
+    }
+
+    #[test]
+    fn test_tool_call_format() {
+        assert_eq!(
+            tool_call_format("{{- '<tool_call>\\n{\"name\": \"' }}"),
+            ToolCallFormat::Hermes
+        );
+        assert_eq!(
+            tool_call_format("{{- '<|start_header_id|>' + message['role'] }}"),
+            ToolCallFormat::Llama3
+        );
+    }
+}
@@ -1,7 +1,7 @@
 use async_openai::types::{
     ChatCompletionRequestAssistantMessageContent, ChatCompletionRequestMessage,
     ChatCompletionRequestSystemMessageContent, ChatCompletionRequestToolMessageContent,
-    ChatCompletionRequestUserMessageContent, ChatCompletionTool,
+    ChatCompletionRequestUserMessageContent, ChatCompletionTool, ChatCompletionToolChoiceOption,
 };
 
 pub use llama_cpp_2::model::LlamaChatMessage;
@@ -11,13 +11,35 @@ pub struct LlamaRequest {
     pub grammar: Option<String>,
     pub messages: Vec<LlamaMessage>,
     pub tools: Option<Vec<ChatCompletionTool>>,
+    pub tool_choice: Option<ChatCompletionToolChoiceOption>,
     pub max_tokens: Option<u32>,
 }
 
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
 pub struct LlamaMessage {
     pub role: String,
     pub content: String,
+    /// Calls made by an earlier assistant turn, replayed so the chat template
+    /// can render them next to their results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_calls: Option<Vec<LlamaToolCall>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_call_id: Option<String>,
+}
+
+// Same shape as OpenAI's, except `arguments` is parsed: chat templates
+// serialize it themselves.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct LlamaToolCall {
+    pub id: String,
+    pub r#type: String,
+    pub function: LlamaFunctionCall,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct LlamaFunctionCall {
+    pub name: String,
+    pub arguments: serde_json::Value,
 }
 
 pub trait FromOpenAI {
@@ -36,16 +58,39 @@ impl FromOpenAI for LlamaMessage {
                 LlamaMessage {
                     role: "system".into(),
                     content: content.clone(),
+                    ..Default::default()
                 }
             }
             ChatCompletionRequestMessage::Assistant(assistant) => {
                 let content = match &assistant.content {
-                    Some(ChatCompletionRequestAssistantMessageContent::Text(text)) => text,
+                    Some(ChatCompletionRequestAssistantMessageContent::Text(text)) => text.clone(),
+                    // Turns that only call tools come without content.
+                    None => String::new(),
                     _ => todo!(),
                 };
+
+                let tool_calls = assistant.tool_calls.as_ref().map(|calls| {
+                    calls
+                        .iter()
+                        .map(|call| LlamaToolCall {
+                            id: call.id.clone(),
+                            r#type: "function".into(),
+                            function: LlamaFunctionCall {
+                                name: call.function.name.clone(),
+                                arguments: serde_json::from_str(&call.function.arguments)
+                                    .unwrap_or_else(|_| {
+                                        serde_json::Value::String(call.function.arguments.clone())
+                                    }),
+                            },
+                        })
+                        .collect()
+                });
+
                 LlamaMessage {
                     role: "assistant".into(),
-                    content: content.clone(),
+                    content,
+                    tool_calls,
+                    ..Default::default()
                 }
             }
             ChatCompletionRequestMessage::User(user) => {
@@ -57,6 +102,7 @@ impl FromOpenAI for LlamaMessage {
                 LlamaMessage {
                     role: "user".into(),
                     content: content.clone(),
+                    ..Default::default()
                 }
             }
             ChatCompletionRequestMessage::Tool(tool) => {
@@ -68,6 +114,8 @@ impl FromOpenAI for LlamaMessage {
                 LlamaMessage {
                     role: "tool".into(),
                     content: content.clone(),
+                    tool_call_id: Some(tool.tool_call_id.clone()),
+                    ..Default::default()
                 }
             }
             _ => todo!(),
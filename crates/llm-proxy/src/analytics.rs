@@ -15,11 +15,47 @@ pub struct GenerationEvent {
     pub base_url: String,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheOutcome {
+    Hit,
+    /// Waited on an identical request that was already in flight.
+    Coalesced,
+    Miss,
+}
+
+impl CacheOutcome {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Hit => "hit",
+            Self::Coalesced => "coalesced",
+            Self::Miss => "miss",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CacheEvent {
+    pub fingerprint: Option<String>,
+    pub user_id: Option<String>,
+    pub key: String,
+    pub outcome: CacheOutcome,
+    pub model: Option<String>,
+    pub stream: bool,
+}
+
 pub trait AnalyticsReporter: Send + Sync {
     fn report_generation(
         &self,
         event: GenerationEvent,
     ) -> std::pin::Pin<Box<dyn std::future::Future<Output = ()> + Send + '_>>;
+
+    fn report_cache(
+        &self,
+        event: CacheEvent,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = ()> + Send + '_>> {
+        let _ = event;
+        Box::pin(async {})
+    }
 }
 
 impl AnalyticsReporter for AnalyticsClient {
@@ -60,4 +96,25 @@ impl AnalyticsReporter for AnalyticsClient {
             let _ = self.event(distinct_id, payload.build()).await;
         })
     }
+
+    fn report_cache(
+        &self,
+        event: CacheEvent,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = ()> + Send + '_>> {
+        Box::pin(async move {
+            let payload = AnalyticsPayload::builder("$llm_cache")
+                .with("outcome", event.outcome.as_str())
+                .with("stream", event.stream)
+                .with("$ai_model", event.model.unwrap_or_default());
+
+            let payload = if let Some(user_id) = &event.user_id {
+                payload.with("user_id", user_id.clone())
+            } else {
+                payload
+            };
+
+            let distinct_id = event.fingerprint.unwrap_or(event.key);
+            let _ = self.event(distinct_id, payload.build()).await;
+        })
+    }
 }
@@ -0,0 +1,253 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use axum::{body::Body, response::Response};
+use bytes::Bytes;
+use sha2::{Digest, Sha256};
+use tokio::sync::watch;
+
+use crate::types::ChatCompletionRequest;
+
+const DEFAULT_TTL_SECS: u64 = 60 * 60;
+const DEFAULT_MAX_ENTRIES: usize = 1_000;
+
+/// Fields that don't change what the model generates.
+const IGNORED_FIELDS: &[&str] = &["stream", "stream_options", "user", "metadata"];
+
+#[derive(Clone)]
+pub struct CacheConfig {
+    pub ttl: Duration,
+    pub max_entries: usize,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self {
+            ttl: Duration::from_secs(DEFAULT_TTL_SECS),
+            max_entries: DEFAULT_MAX_ENTRIES,
+        }
+    }
+}
+
+impl CacheConfig {
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    pub fn with_max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = max_entries;
+        self
+    }
+}
+
+struct Entry {
+    body: Bytes,
+    expires_at: Instant,
+}
+
+/// In-memory cache of successful upstream responses, keyed by request content
+/// and scoped to the user or device that sent it. Concurrent identical requests
+/// wait for the first one instead of each going upstream.
+pub struct ResponseCache {
+    config: CacheConfig,
+    entries: Mutex<HashMap<String, Entry>>,
+    in_flight: Mutex<HashMap<String, watch::Receiver<()>>>,
+}
+
+pub(crate) enum Lookup {
+    Hit(Bytes),
+    /// Served from the response of an identical request that was in flight.
+    Coalesced(Bytes),
+    Miss(Lease),
+}
+
+/// Held by the request that goes upstream for a key. Dropping it, stored or
+/// not, releases anyone waiting on the same key.
+pub(crate) struct Lease {
+    cache: Arc<ResponseCache>,
+    key: String,
+    leader: Option<watch::Sender<()>>,
+}
+
+impl Lease {
+    pub(crate) fn store(self, body: Bytes) {
+        self.cache.insert(self.key.clone(), body);
+    }
+}
+
+impl Drop for Lease {
+    fn drop(&mut self) {
+        if self.leader.is_some() {
+            self.cache.in_flight.lock().unwrap().remove(&self.key);
+        }
+    }
+}
+
+impl ResponseCache {
+    pub fn new(config: CacheConfig) -> Self {
+        Self {
+            config,
+            entries: Mutex::new(HashMap::new()),
+            in_flight: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub(crate) async fn lookup(self: &Arc<Self>, key: String) -> Lookup {
+        if let Some(body) = self.get(&key) {
+            return Lookup::Hit(body);
+        }
+
+        let waiting = {
+            let mut in_flight = self.in_flight.lock().unwrap();
+            match in_flight.get(&key) {
+                Some(rx) => rx.clone(),
+                None => {
+                    let (tx, rx) = watch::channel(());
+                    in_flight.insert(key.clone(), rx);
+                    return Lookup::Miss(Lease {
+                        cache: self.clone(),
+                        key,
+                        leader: Some(tx),
+                    });
+                }
+            }
+        };
+
+        // Resolves when the leader's lease is dropped.
+        let mut waiting = waiting;
+        let _ = waiting.changed().await;
+
+        match self.get(&key) {
+            Some(body) => Lookup::Coalesced(body),
+            // The leader failed; go upstream without holding others up.
+            None => Lookup::Miss(Lease {
+                cache: self.clone(),
+                key,
+                leader: None,
+            }),
+        }
+    }
+
+    fn get(&self, key: &str) -> Option<Bytes> {
+        let mut entries = self.entries.lock().unwrap();
+        match entries.get(key) {
+            Some(entry) if entry.expires_at > Instant::now() => Some(entry.body.clone()),
+            Some(_) => {
+                entries.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    fn insert(&self, key: String, body: Bytes) {
+        if self.config.max_entries == 0 {
+            return;
+        }
+
+        let now = Instant::now();
+        let mut entries = self.entries.lock().unwrap();
+        if entries.len() >= self.config.max_entries {
+            entries.retain(|_, entry| entry.expires_at > now);
+        }
+        if entries.len() >= self.config.max_entries
+            && let Some(oldest) = entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.expires_at)
+                .map(|(key, _)| key.clone())
+        {
+            entries.remove(&oldest);
+        }
+
+        entries.insert(
+            key,
+            Entry {
+                body,
+                expires_at: now + self.config.ttl,
+            },
+        );
+    }
+}
+
+/// A content hash of everything that shapes the response: the models it will be
+/// routed to, messages, tools, sampling parameters and whether it streams.
+/// `scope` keeps one user's responses from being replayed to another.
+pub(crate) fn key(
+    request: &ChatCompletionRequest,
+    models: &[String],
+    stream: bool,
+    scope: &str,
+) -> String {
+    let mut value = serde_json::to_value(request).unwrap_or_default();
+    if let Some(obj) = value.as_object_mut() {
+        for field in IGNORED_FIELDS {
+            obj.remove(*field);
+        }
+        obj.insert("models".to_string(), models.into());
+        obj.insert("stream".to_string(), stream.into());
+        obj.insert("scope".to_string(), scope.into());
+    }
+
+    let canonical = canonicalize(value).to_string();
+    format!("{:x}", Sha256::digest(canonical.as_bytes()))
+}
+
+/// Sorts object keys so the key doesn't depend on the client's field order.
+fn canonicalize(value: serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(obj) => {
+            let mut fields: Vec<_> = obj.into_iter().collect();
+            fields.sort_by(|a, b| a.0.cmp(&b.0));
+            serde_json::Value::Object(
+                fields
+                    .into_iter()
+                    .map(|(k, v)| (k, canonicalize(v)))
+                    .collect(),
+            )
+        }
+        serde_json::Value::Array(items) => {
+            serde_json::Value::Array(items.into_iter().map(canonicalize).collect())
+        }
+        other => other,
+    }
+}
+
+/// Whether a recorded stream ran to its `data: [DONE]` event.
+pub(crate) fn is_complete_stream(body: &[u8]) -> bool {
+    body.split(|b| *b == b'\n')
+        .map(<[u8]>::trim_ascii)
+        .rfind(|line| !line.is_empty())
+        .and_then(|line| line.strip_prefix(b"data:"))
+        .is_some_and(|data| data.trim_ascii() == b"[DONE]")
+}
+
+pub(crate) fn replay(body: Bytes, stream: bool) -> Response {
+    let builder = Response::builder().status(200).header("X-Cache", "HIT");
+
+    if !stream {
+        return builder
+            .header("Content-Type", "application/json")
+            .body(Body::from(body))
+            .unwrap();
+    }
+
+    // Send the stored stream back event by event, as it arrived.
+    let mut events = vec![];
+    let mut rest = body;
+    while let Some(end) = rest.windows(2).position(|w| w == b"\n\n") {
+        events.push(rest.split_to(end + 2));
+    }
+    if !rest.is_empty() {
+        events.push(rest);
+    }
+
+    builder
+        .header("Content-Type", "text/event-stream")
+        .header("Cache-Control", "no-cache")
+        .body(Body::from_stream(futures_util::stream::iter(
+            events.into_iter().map(Ok::<_, std::io::Error>),
+        )))
+        .unwrap()
+}
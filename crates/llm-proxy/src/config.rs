@@ -2,6 +2,7 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use crate::analytics::AnalyticsReporter;
+use crate::cache::{CacheConfig, ResponseCache};
 use crate::env::ApiKey;
 use crate::provider::{OpenRouterProvider, Provider};
 
@@ -24,6 +25,40 @@ impl Default for RetryConfig {
     }
 }
 
+/// Sends models matching `pattern` to `provider`. A trailing `*` matches any
+/// suffix, and that suffix is the model name the provider receives, so
+/// `azure/*` turns `azure/gpt-4o` into the `gpt-4o` deployment.
+#[derive(Clone)]
+pub struct ModelRoute {
+    pub pattern: String,
+    pub provider: Arc<dyn Provider>,
+    pub api_key: String,
+}
+
+impl ModelRoute {
+    pub fn new(
+        pattern: impl Into<String>,
+        provider: Arc<dyn Provider>,
+        api_key: impl Into<ApiKey>,
+    ) -> Self {
+        Self {
+            pattern: pattern.into(),
+            provider,
+            api_key: api_key.into().0,
+        }
+    }
+
+    pub(crate) fn upstream_model(&self, model: &str) -> Option<String> {
+        match self.pattern.strip_suffix('*') {
+            Some(prefix) => model
+                .strip_prefix(prefix)
+                .filter(|rest| !rest.is_empty())
+                .map(String::from),
+            None => (self.pattern == model).then(|| model.to_string()),
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct LlmProxyConfig {
     pub api_key: String,
@@ -32,7 +67,11 @@ pub struct LlmProxyConfig {
     pub models_default: Vec<String>,
     pub analytics: Option<Arc<dyn AnalyticsReporter>>,
     pub provider: Arc<dyn Provider>,
+    /// Checked in order before falling back to `provider` and `api_key`.
+    pub routes: Vec<ModelRoute>,
     pub retry_config: RetryConfig,
+    /// Off unless set; shared by every router built from this config.
+    pub cache: Option<Arc<ResponseCache>>,
 }
 
 impl LlmProxyConfig {
@@ -51,7 +90,9 @@ impl LlmProxyConfig {
             ],
             analytics: None,
             provider: Arc::new(OpenRouterProvider::default()),
+            routes: vec![],
             retry_config: RetryConfig::default(),
+            cache: None,
         }
     }
 
@@ -80,8 +121,18 @@ impl LlmProxyConfig {
         self
     }
 
+    pub fn with_route(mut self, route: ModelRoute) -> Self {
+        self.routes.push(route);
+        self
+    }
+
     pub fn with_retry_config(mut self, retry_config: RetryConfig) -> Self {
         self.retry_config = retry_config;
         self
     }
+
+    pub fn with_cache(mut self, cache_config: CacheConfig) -> Self {
+        self.cache = Some(Arc::new(ResponseCache::new(cache_config)));
+        self
+    }
 }
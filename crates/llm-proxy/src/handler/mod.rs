@@ -18,8 +18,11 @@ use axum::{
 use backon::{ExponentialBuilder, Retryable};
 use reqwest::Client;
 
-use crate::analytics::{AnalyticsReporter, GenerationEvent};
+use crate::analytics::{AnalyticsReporter, CacheEvent, CacheOutcome, GenerationEvent};
+use crate::cache::{self, Lookup};
 use crate::config::LlmProxyConfig;
+use crate::provider::ProviderError;
+use crate::routing::{self, Upstream};
 use crate::types::{ChatCompletionRequest, ToolChoice};
 
 async fn report_with_cost(
@@ -49,6 +52,14 @@ pub(super) fn spawn_analytics_report(
     }
 }
 
+fn spawn_cache_report(analytics: Option<Arc<dyn AnalyticsReporter>>, event: CacheEvent) {
+    if let Some(analytics) = analytics {
+        tokio::spawn(async move {
+            analytics.report_cache(event).await;
+        });
+    }
+}
+
 fn is_retryable_error(error: &reqwest::Error) -> bool {
     error.is_timeout() || error.is_connect()
 }
@@ -57,6 +68,7 @@ enum ProxyError {
     UpstreamRequest(reqwest::Error),
     Timeout,
     BodyRead(reqwest::Error),
+    InvalidResponse(ProviderError),
 }
 
 impl IntoResponse for ProxyError {
@@ -104,6 +116,16 @@ impl IntoResponse for ProxyError {
                     "Failed to read response".to_string(),
                 )
             }
+            Self::InvalidResponse(e) => {
+                tracing::error!(error = %e, "response_translation_failed");
+                sentry::configure_scope(|scope| {
+                    scope.set_tag("upstream.status", "invalid_response");
+                });
+                (
+                    StatusCode::BAD_GATEWAY,
+                    "Failed to read response".to_string(),
+                )
+            }
         };
         (status, message).into_response()
     }
@@ -190,14 +212,10 @@ async fn completions_handler(
         has_tools = %needs_tool_calling,
         message_count = %request.messages.len(),
         model_count = %models.len(),
-        provider = %state.config.provider.name(),
         "llm_completion_request_received"
     );
 
-    let provider = &state.config.provider;
-
     sentry::configure_scope(|scope| {
-        scope.set_tag("llm.provider", provider.name());
         if let Some(model) = models.first() {
             scope.set_tag("llm.model", model);
         }
@@ -211,13 +229,139 @@ async fn completions_handler(
         scope.set_context("llm_request", sentry::protocol::Context::Other(ctx));
     });
 
-    let provider_request = match provider.build_request(&request, models, stream) {
-        Ok(req) => req,
-        Err(e) => {
-            tracing::error!(error = %e, "failed_to_build_provider_request");
-            return (StatusCode::INTERNAL_SERVER_ERROR, "Invalid request").into_response();
+    // Responses are only shared within one user, or one device when signed out.
+    // Requests that carry neither skip the cache.
+    let scope = analytics_ctx
+        .user_id
+        .as_deref()
+        .or(analytics_ctx.fingerprint.as_deref());
+
+    let mut lease = None;
+    if let Some(cache) = &state.config.cache
+        && let Some(scope) = scope
+    {
+        let key = cache::key(&request, &models, stream, scope);
+        let lookup = cache.lookup(key.clone()).await;
+
+        let outcome = match &lookup {
+            Lookup::Hit(_) => CacheOutcome::Hit,
+            Lookup::Coalesced(_) => CacheOutcome::Coalesced,
+            Lookup::Miss(_) => CacheOutcome::Miss,
+        };
+        tracing::info!(outcome = %outcome.as_str(), "llm_cache_lookup");
+        spawn_cache_report(
+            state.config.analytics.clone(),
+            CacheEvent {
+                fingerprint: analytics_ctx.fingerprint.clone(),
+                user_id: analytics_ctx.user_id.clone(),
+                key,
+                outcome,
+                model: models.first().cloned(),
+                stream,
+            },
+        );
+
+        match lookup {
+            Lookup::Hit(body) | Lookup::Coalesced(body) => return cache::replay(body, stream),
+            Lookup::Miss(l) => lease = Some(l),
         }
-    };
+    }
+
+    let attempts = routing::plan(&state.config, models);
+    let attempt_count = attempts.len();
+
+    for (i, attempt) in attempts.into_iter().enumerate() {
+        let is_last = i + 1 == attempt_count;
+        let provider = &attempt.upstream.provider;
+        let model = attempt.models.first().cloned().unwrap_or_default();
+
+        sentry::configure_scope(|scope| {
+            scope.set_tag("llm.provider", provider.name());
+        });
+
+        let provider_request = match provider.build_request(&request, attempt.models, stream) {
+            Ok(req) => req,
+            Err(e) => {
+                tracing::error!(error = %e, provider = %provider.name(), "failed_to_build_provider_request");
+                if is_last {
+                    return (StatusCode::INTERNAL_SERVER_ERROR, "Invalid request").into_response();
+                }
+                continue;
+            }
+        };
+
+        let result =
+            send_with_retry(&state, &attempt.upstream, &model, stream, &provider_request).await;
+
+        let response = match result {
+            Ok(Ok(resp)) if !is_last && routing::should_fall_back(resp.status()) => {
+                tracing::warn!(
+                    http_status = %resp.status().as_u16(),
+                    provider = %provider.name(),
+                    model = %model,
+                    "falling_back_to_next_provider"
+                );
+                continue;
+            }
+            Ok(Ok(resp)) => resp,
+            Ok(Err(e)) if !is_last => {
+                tracing::warn!(
+                    error = %e,
+                    provider = %provider.name(),
+                    model = %model,
+                    "falling_back_to_next_provider"
+                );
+                continue;
+            }
+            Ok(Err(e)) => return ProxyError::UpstreamRequest(e).into_response(),
+            Err(_) if !is_last => {
+                tracing::warn!(
+                    provider = %provider.name(),
+                    model = %model,
+                    "falling_back_to_next_provider"
+                );
+                continue;
+            }
+            Err(_) => return ProxyError::Timeout.into_response(),
+        };
+
+        return if stream {
+            handle_stream_response(
+                state,
+                attempt.upstream,
+                model,
+                response,
+                start_time,
+                analytics_ctx,
+                lease,
+            )
+            .await
+        } else {
+            handle_non_stream_response(
+                state,
+                attempt.upstream,
+                model,
+                response,
+                start_time,
+                analytics_ctx,
+                lease,
+            )
+            .await
+        };
+    }
+
+    unreachable!("routing::plan always returns at least one attempt")
+}
+
+async fn send_with_retry(
+    state: &AppState,
+    upstream: &Upstream,
+    model: &str,
+    stream: bool,
+    body: &serde_json::Value,
+) -> Result<Result<reqwest::Response, reqwest::Error>, tokio::time::error::Elapsed> {
+    let provider = &upstream.provider;
+    let url = provider.request_url(model, stream);
 
     let retry_config = &state.config.retry_config;
     let backoff = ExponentialBuilder::default()
@@ -225,22 +369,26 @@ async fn completions_handler(
         .with_max_delay(Duration::from_secs(retry_config.max_delay_secs))
         .with_max_times(retry_config.num_retries);
 
-    let result = tokio::time::timeout(state.config.timeout, async {
+    tokio::time::timeout(state.config.timeout, async {
         (|| async {
             let mut req_builder = state
                 .client
-                .post(provider.base_url())
-                .header("Content-Type", "application/json")
-                .header(
-                    "Authorization",
-                    provider.build_auth_header(&state.config.api_key),
+                .post(&url)
+                .header("Content-Type", "application/json");
+
+            // Local OpenAI-compatible servers often run without a key.
+            if !upstream.api_key.is_empty() {
+                req_builder = req_builder.header(
+                    provider.auth_header_name(),
+                    provider.build_auth_header(&upstream.api_key),
                 );
+            }
 
             for (key, value) in provider.additional_headers() {
                 req_builder = req_builder.header(key, value);
             }
 
-            req_builder.json(&provider_request).send().await
+            req_builder.json(body).send().await
         })
         .retry(backoff)
         .notify(|err, dur: Duration| {
@@ -254,17 +402,5 @@ async fn completions_handler(
         .when(is_retryable_error)
         .await
     })
-    .await;
-
-    let response = match result {
-        Ok(Ok(resp)) => resp,
-        Ok(Err(e)) => return ProxyError::UpstreamRequest(e).into_response(),
-        Err(_) => return ProxyError::Timeout.into_response(),
-    };
-
-    if stream {
-        handle_stream_response(state, response, start_time, analytics_ctx).await
-    } else {
-        handle_non_stream_response(state, response, start_time, analytics_ctx).await
-    }
+    .await
 }
@@ -7,14 +7,19 @@ use axum::{
 };
 
 use crate::analytics::GenerationEvent;
+use crate::cache::Lease;
+use crate::routing::Upstream;
 
 use super::{AnalyticsContext, AppState, ProxyError, spawn_analytics_report};
 
 pub(super) async fn handle_non_stream_response(
     state: AppState,
+    upstream: Upstream,
+    model: String,
     response: reqwest::Response,
     start_time: Instant,
     analytics_ctx: AnalyticsContext,
+    lease: Option<Lease>,
 ) -> Response {
     let status = response.status();
     let http_status = status.as_u16();
@@ -36,7 +41,23 @@ pub(super) async fn handle_non_stream_response(
         Err(e) => return ProxyError::BodyRead(e).into_response(),
     };
 
-    if let Ok(metadata) = state.config.provider.parse_response(&body_bytes) {
+    let provider = &upstream.provider;
+    let body_bytes = if status.is_success() {
+        match provider.transform_response(&model, body_bytes) {
+            Ok(b) => b,
+            Err(e) => return ProxyError::InvalidResponse(e).into_response(),
+        }
+    } else {
+        body_bytes
+    };
+
+    if status.is_success()
+        && let Some(lease) = lease
+    {
+        lease.store(body_bytes.clone());
+    }
+
+    if let Ok(metadata) = provider.parse_response(&body_bytes) {
         sentry::configure_scope(|scope| {
             let mut ctx = BTreeMap::new();
             ctx.insert(
@@ -63,15 +84,15 @@ pub(super) async fn handle_non_stream_response(
             latency: start_time.elapsed().as_secs_f64(),
             http_status,
             total_cost: None,
-            provider_name: state.config.provider.name().to_string(),
-            base_url: state.config.provider.base_url().to_string(),
+            provider_name: provider.name().to_string(),
+            base_url: provider.base_url().to_string(),
         };
 
         spawn_analytics_report(
             state.config.analytics.clone(),
-            state.config.provider.clone(),
+            provider.clone(),
             state.client.clone(),
-            state.config.api_key.clone(),
+            upstream.api_key.clone(),
             event,
         );
     }
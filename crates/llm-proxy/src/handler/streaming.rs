@@ -6,22 +6,31 @@ use axum::{body::Body, response::Response};
 use futures_util::StreamExt;
 
 use crate::analytics::GenerationEvent;
+use crate::cache::Lease;
+use crate::routing::Upstream;
 
 use super::{AnalyticsContext, AppState, report_with_cost};
 
 pub(super) async fn handle_stream_response(
     state: AppState,
+    upstream: Upstream,
+    model: String,
     response: reqwest::Response,
     start_time: Instant,
     analytics_ctx: AnalyticsContext,
+    lease: Option<Lease>,
 ) -> Response {
     let status = response.status();
     let http_status = status.as_u16();
     let latency_ms = start_time.elapsed().as_millis();
     let analytics = state.config.analytics.clone();
-    let api_key = state.config.api_key.clone();
     let client = state.client.clone();
-    let provider = state.config.provider.clone();
+    let Upstream { provider, api_key } = upstream;
+    // Error bodies are passed through untouched.
+    let mut translator = status
+        .is_success()
+        .then(|| provider.stream_translator(&model))
+        .flatten();
 
     tracing::info!(
         http_status = %http_status,
@@ -41,26 +50,60 @@ pub(super) async fn handle_stream_response(
 
     let upstream = response.bytes_stream();
 
+    // Only successful streams that reach `[DONE]` are worth replaying.
+    let mut lease = lease.filter(|_| status.is_success());
+
     let output_stream = stream! {
         let mut accumulator = crate::provider::StreamAccumulator::new();
+        let mut recorded = Vec::new();
 
         futures_util::pin_mut!(upstream);
 
         while let Some(chunk_result) = upstream.next().await {
             match chunk_result {
                 Ok(chunk) => {
+                    let chunk = match translator.as_mut() {
+                        Some(translator) => bytes::Bytes::from(translator.push(&chunk)),
+                        None => chunk,
+                    };
+                    if chunk.is_empty() {
+                        continue;
+                    }
                     if analytics.is_some() {
                         provider.parse_stream_chunk(&chunk, &mut accumulator);
                     }
+                    if lease.is_some() {
+                        recorded.extend_from_slice(&chunk);
+                    }
                     yield Ok::<_, std::io::Error>(chunk);
                 }
                 Err(e) => {
+                    lease = None;
                     yield Err(std::io::Error::other(e));
                     break;
                 }
             }
         }
 
+        if let Some(translator) = translator.as_mut() {
+            let rest = bytes::Bytes::from(translator.finish());
+            if !rest.is_empty() {
+                if analytics.is_some() {
+                    provider.parse_stream_chunk(&rest, &mut accumulator);
+                }
+                if lease.is_some() {
+                    recorded.extend_from_slice(&rest);
+                }
+                yield Ok(rest);
+            }
+        }
+
+        if let Some(lease) = lease.take()
+            && crate::cache::is_complete_stream(&recorded)
+        {
+            lease.store(recorded.into());
+        }
+
         if let Some(analytics) = analytics
             && let Some(generation_id) = accumulator.generation_id {
                 let event = GenerationEvent {
@@ -1,12 +1,15 @@
 mod analytics;
+mod cache;
 mod config;
 mod env;
 mod handler;
 mod openapi;
 pub mod provider;
+mod routing;
 mod types;
 
-pub use analytics::{AnalyticsReporter, GenerationEvent};
+pub use analytics::{AnalyticsReporter, CacheEvent, CacheOutcome, GenerationEvent};
+pub use cache::{CacheConfig, ResponseCache};
 pub use config::*;
 pub use env::{ApiKey, Env};
 pub use handler::{chat_completions_router, router};
@@ -0,0 +1,429 @@
+use std::collections::HashMap;
+
+use bytes::Bytes;
+use serde_json::{Value, json};
+
+use crate::types::{ChatCompletionRequest, Role, ToolChoice};
+
+use super::translate::{
+    ChunkWriter, DONE, Part, SseBuffer, ToolCall, chat_completion, content_parts, content_text,
+    finish_reason, function_tools, stop_sequences, tool_call_id, tool_call_json, tool_calls, usage,
+};
+use super::{Provider, ProviderError, StreamTranslator, single_model};
+
+pub const ANTHROPIC_URL: &str = "https://api.anthropic.com/v1/messages";
+const ANTHROPIC_VERSION: &str = "2023-06-01";
+/// The Messages API requires `max_tokens`; OpenAI clients often leave it out.
+const DEFAULT_MAX_TOKENS: u32 = 4096;
+
+pub struct AnthropicProvider {
+    pub base_url: String,
+}
+
+impl Default for AnthropicProvider {
+    fn default() -> Self {
+        Self {
+            base_url: ANTHROPIC_URL.to_string(),
+        }
+    }
+}
+
+impl AnthropicProvider {
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into(),
+        }
+    }
+}
+
+impl Provider for AnthropicProvider {
+    fn name(&self) -> &str {
+        "anthropic"
+    }
+
+    fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    fn build_request(
+        &self,
+        request: &ChatCompletionRequest,
+        models: Vec<String>,
+        stream: bool,
+    ) -> Result<serde_json::Value, ProviderError> {
+        let mut system = vec![];
+        let mut messages: Vec<(&str, Vec<Value>)> = vec![];
+
+        for message in &request.messages {
+            let (role, blocks) = match message.role {
+                Role::System => {
+                    system.push(content_text(message.content.as_ref()));
+                    continue;
+                }
+                Role::User => ("user", content_blocks(message.content.as_ref())),
+                Role::Assistant => {
+                    let mut blocks = content_blocks(message.content.as_ref());
+                    blocks.extend(tool_calls(message).into_iter().map(|call| {
+                        json!({"type": "tool_use", "id": call.id, "name": call.name, "input": call.arguments})
+                    }));
+                    ("assistant", blocks)
+                }
+                Role::Tool => (
+                    "user",
+                    vec![json!({
+                        "type": "tool_result",
+                        "tool_use_id": tool_call_id(message).unwrap_or_default(),
+                        "content": content_text(message.content.as_ref()),
+                    })],
+                ),
+            };
+            if blocks.is_empty() {
+                continue;
+            }
+
+            // Tool results arrive as one message each but must share a user turn.
+            match messages.last_mut() {
+                Some((last, content)) if *last == role => content.extend(blocks),
+                _ => messages.push((role, blocks)),
+            }
+        }
+
+        let mut body = json!({
+            "model": single_model(&models)?,
+            "messages": messages
+                .into_iter()
+                .map(|(role, content)| json!({"role": role, "content": content}))
+                .collect::<Vec<_>>(),
+            "max_tokens": request.max_tokens.unwrap_or(DEFAULT_MAX_TOKENS),
+            "stream": stream,
+        });
+
+        let system = system.join("\n\n");
+        if !system.is_empty() {
+            body["system"] = system.into();
+        }
+        if let Some(temperature) = request.temperature {
+            body["temperature"] = temperature.into();
+        }
+        if let Some(top_p) = request.extra.get("top_p") {
+            body["top_p"] = top_p.clone();
+        }
+        let stop = stop_sequences(request);
+        if !stop.is_empty() {
+            body["stop_sequences"] = stop.into();
+        }
+
+        let tools = function_tools(request);
+        if !tools.is_empty() {
+            body["tools"] = tools
+                .into_iter()
+                .map(|tool| {
+                    json!({
+                        "name": tool.name,
+                        "description": tool.description,
+                        "input_schema": tool.parameters,
+                    })
+                })
+                .collect();
+            if let Some(choice) = &request.tool_choice {
+                body["tool_choice"] = match choice {
+                    ToolChoice::String(s) if s == "required" => json!({"type": "any"}),
+                    ToolChoice::String(s) if s == "none" => json!({"type": "none"}),
+                    ToolChoice::String(_) => json!({"type": "auto"}),
+                    ToolChoice::Object { function, .. } => {
+                        json!({"type": "tool", "name": function.get("name")})
+                    }
+                };
+            }
+        }
+
+        Ok(body)
+    }
+
+    fn transform_response(&self, _model: &str, body: Bytes) -> Result<Bytes, ProviderError> {
+        let parsed: Value =
+            serde_json::from_slice(&body).map_err(|e| ProviderError::ParseError(e.to_string()))?;
+
+        let mut text = String::new();
+        let mut calls = vec![];
+        for block in parsed["content"].as_array().into_iter().flatten() {
+            match block["type"].as_str() {
+                Some("text") => text.push_str(block["text"].as_str().unwrap_or_default()),
+                Some("tool_use") => calls.push(ToolCall {
+                    id: block["id"].as_str().unwrap_or_default().to_string(),
+                    name: block["name"].as_str().unwrap_or_default().to_string(),
+                    arguments: block["input"].clone(),
+                }),
+                _ => {}
+            }
+        }
+
+        let completion = chat_completion(
+            parsed["id"].as_str().unwrap_or_default(),
+            parsed["model"].as_str().unwrap_or_default(),
+            text,
+            calls,
+            finish_reason(parsed["stop_reason"].as_str().unwrap_or_default()),
+            usage(
+                parsed["usage"]["input_tokens"].as_u64().unwrap_or(0),
+                parsed["usage"]["output_tokens"].as_u64().unwrap_or(0),
+            ),
+        );
+        Ok(serde_json::to_vec(&completion)?.into())
+    }
+
+    fn stream_translator(&self, _model: &str) -> Option<Box<dyn StreamTranslator>> {
+        Some(Box::new(AnthropicStream::default()))
+    }
+
+    fn auth_header_name(&self) -> &str {
+        "x-api-key"
+    }
+
+    fn build_auth_header(&self, api_key: &str) -> String {
+        api_key.to_string()
+    }
+
+    fn additional_headers(&self) -> Vec<(String, String)> {
+        vec![(
+            "anthropic-version".to_string(),
+            ANTHROPIC_VERSION.to_string(),
+        )]
+    }
+}
+
+fn content_blocks(content: Option<&Value>) -> Vec<Value> {
+    content_parts(content)
+        .into_iter()
+        .map(|part| match part {
+            Part::Text(text) => json!({"type": "text", "text": text}),
+            Part::InlineImage { media_type, data } => json!({
+                "type": "image",
+                "source": {"type": "base64", "media_type": media_type, "data": data},
+            }),
+            Part::ImageUrl(url) => json!({
+                "type": "image",
+                "source": {"type": "url", "url": url},
+            }),
+        })
+        .collect()
+}
+
+#[derive(Default)]
+struct AnthropicStream {
+    sse: SseBuffer,
+    writer: Option<ChunkWriter>,
+    /// Content block index to OpenAI tool call index.
+    tool_indices: HashMap<u64, usize>,
+    input_tokens: u64,
+    output_tokens: u64,
+    stop_reason: Option<String>,
+}
+
+impl StreamTranslator for AnthropicStream {
+    fn push(&mut self, chunk: &[u8]) -> Vec<u8> {
+        let mut out = vec![];
+
+        for event in self.sse.push(chunk) {
+            let Ok(data) = serde_json::from_str::<Value>(&event.data) else {
+                continue;
+            };
+
+            match data["type"].as_str() {
+                Some("message_start") => {
+                    let message = &data["message"];
+                    self.input_tokens = message["usage"]["input_tokens"].as_u64().unwrap_or(0);
+                    let writer = ChunkWriter::new(
+                        message["id"].as_str().unwrap_or_default(),
+                        message["model"].as_str().unwrap_or_default(),
+                    );
+                    out.extend(writer.delta(json!({"role": "assistant", "content": ""})));
+                    self.writer = Some(writer);
+                }
+                Some("content_block_start") if data["content_block"]["type"] == "tool_use" => {
+                    let Some(writer) = &self.writer else { continue };
+                    let index = self.tool_indices.len();
+                    self.tool_indices
+                        .insert(data["index"].as_u64().unwrap_or(0), index);
+
+                    let call = ToolCall {
+                        id: data["content_block"]["id"]
+                            .as_str()
+                            .unwrap_or_default()
+                            .to_string(),
+                        name: data["content_block"]["name"]
+                            .as_str()
+                            .unwrap_or_default()
+                            .to_string(),
+                        arguments: Value::Null,
+                    };
+                    let mut call = tool_call_json(index, &call);
+                    call["function"]["arguments"] = "".into();
+                    out.extend(writer.delta(json!({"tool_calls": [call]})));
+                }
+                Some("content_block_delta") => {
+                    let Some(writer) = &self.writer else { continue };
+                    let delta = &data["delta"];
+                    match delta["type"].as_str() {
+                        Some("text_delta") => {
+                            out.extend(writer.delta(json!({"content": delta["text"]})));
+                        }
+                        Some("input_json_delta") => {
+                            let block = data["index"].as_u64().unwrap_or(0);
+                            let Some(index) = self.tool_indices.get(&block) else {
+                                continue;
+                            };
+                            out.extend(writer.delta(json!({"tool_calls": [{
+                                "index": index,
+                                "function": {"arguments": delta["partial_json"]},
+                            }]})));
+                        }
+                        _ => {}
+                    }
+                }
+                Some("message_delta") => {
+                    if let Some(reason) = data["delta"]["stop_reason"].as_str() {
+                        self.stop_reason = Some(reason.to_string());
+                    }
+                    if let Some(tokens) = data["usage"]["output_tokens"].as_u64() {
+                        self.output_tokens = tokens;
+                    }
+                }
+                Some("message_stop") => {
+                    let Some(writer) = &self.writer else { continue };
+                    out.extend(writer.finish(
+                        finish_reason(self.stop_reason.as_deref().unwrap_or_default()),
+                        usage(self.input_tokens, self.output_tokens),
+                    ));
+                    out.extend(DONE);
+                }
+                Some("error") => {
+                    out.extend(format!("data: {}\n\n", json!({"error": data["error"]})).bytes());
+                }
+                _ => {}
+            }
+        }
+
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_request() {
+        let request: ChatCompletionRequest = serde_json::from_value(json!({
+            "messages": [
+                {"role": "system", "content": "Be brief."},
+                {"role": "user", "content": "Weather in Paris?"},
+                {"role": "assistant", "content": null, "tool_calls": [{
+                    "id": "call_1", "type": "function",
+                    "function": {"name": "weather", "arguments": "{\"city\":\"Paris\"}"}
+                }]},
+                {"role": "tool", "tool_call_id": "call_1", "content": "Sunny"},
+            ],
+            "tools": [{"type": "function", "function": {
+                "name": "weather",
+                "parameters": {"type": "object", "properties": {"city": {"type": "string"}}}
+            }}],
+            "tool_choice": "required",
+            "stop": "END",
+        }))
+        .unwrap();
+
+        let body = AnthropicProvider::default()
+            .build_request(&request, vec!["claude-haiku-4-5".into()], false)
+            .unwrap();
+
+        assert_eq!(body["model"], "claude-haiku-4-5");
+        assert_eq!(body["system"], "Be brief.");
+        assert_eq!(body["max_tokens"], DEFAULT_MAX_TOKENS);
+        assert_eq!(body["stop_sequences"], json!(["END"]));
+        assert_eq!(body["tool_choice"], json!({"type": "any"}));
+        assert_eq!(body["tools"][0]["input_schema"]["type"], "object");
+
+        let messages = body["messages"].as_array().unwrap();
+        assert_eq!(messages.len(), 3);
+        assert_eq!(messages[1]["content"][0]["type"], "tool_use");
+        assert_eq!(messages[1]["content"][0]["input"], json!({"city": "Paris"}));
+        assert_eq!(messages[2]["role"], "user");
+        assert_eq!(messages[2]["content"][0]["tool_use_id"], "call_1");
+    }
+
+    #[test]
+    fn test_transform_response() {
+        let body = json!({
+            "id": "msg_1",
+            "model": "claude-haiku-4-5",
+            "content": [
+                {"type": "text", "text": "Checking."},
+                {"type": "tool_use", "id": "toolu_1", "name": "weather", "input": {"city": "Paris"}},
+            ],
+            "stop_reason": "tool_use",
+            "usage": {"input_tokens": 12, "output_tokens": 7},
+        });
+
+        let provider = AnthropicProvider::default();
+        let out = provider
+            .transform_response(
+                "claude-haiku-4-5",
+                serde_json::to_vec(&body).unwrap().into(),
+            )
+            .unwrap();
+        let out: Value = serde_json::from_slice(&out).unwrap();
+
+        let choice = &out["choices"][0];
+        assert_eq!(choice["finish_reason"], "tool_calls");
+        assert_eq!(choice["message"]["content"], "Checking.");
+        assert_eq!(
+            choice["message"]["tool_calls"][0]["function"]["arguments"],
+            r#"{"city":"Paris"}"#
+        );
+
+        let metadata = provider
+            .parse_response(&serde_json::to_vec(&out).unwrap())
+            .unwrap();
+        assert_eq!(metadata.generation_id, "msg_1");
+        assert_eq!(metadata.input_tokens, 12);
+        assert_eq!(metadata.output_tokens, 7);
+    }
+
+    #[test]
+    fn test_stream_translator() {
+        let upstream = [
+            r#"{"type":"message_start","message":{"id":"msg_1","model":"claude-haiku-4-5","usage":{"input_tokens":9,"output_tokens":1}}}"#,
+            r#"{"type":"content_block_start","index":0,"content_block":{"type":"text","text":""}}"#,
+            r#"{"type":"content_block_delta","index":0,"delta":{"type":"text_delta","text":"Hi"}}"#,
+            r#"{"type":"content_block_start","index":1,"content_block":{"type":"tool_use","id":"toolu_1","name":"weather","input":{}}}"#,
+            r#"{"type":"content_block_delta","index":1,"delta":{"type":"input_json_delta","partial_json":"{\"city\":"}}"#,
+            r#"{"type":"message_delta","delta":{"stop_reason":"tool_use"},"usage":{"output_tokens":5}}"#,
+            r#"{"type":"message_stop"}"#,
+        ]
+        .map(|data| format!("event: x\ndata: {}\n\n", data))
+        .concat();
+
+        let provider = AnthropicProvider::default();
+        let mut translator = provider.stream_translator("claude-haiku-4-5").unwrap();
+        // Feed it in awkward pieces to exercise buffering.
+        let out: Vec<u8> = upstream
+            .as_bytes()
+            .chunks(17)
+            .flat_map(|chunk| translator.push(chunk))
+            .collect();
+        let out = String::from_utf8(out).unwrap();
+
+        assert!(out.contains(r#""content":"Hi""#));
+        assert!(out.contains(r#""name":"weather""#));
+        assert!(out.contains(r#""arguments":"{\"city\":""#));
+        assert!(out.contains(r#""finish_reason":"tool_calls""#));
+        assert!(out.ends_with("data: [DONE]\n\n"));
+
+        let mut accumulator = super::super::StreamAccumulator::new();
+        provider.parse_stream_chunk(out.as_bytes(), &mut accumulator);
+        assert_eq!(accumulator.generation_id.as_deref(), Some("msg_1"));
+        assert_eq!(accumulator.input_tokens, 9);
+        assert_eq!(accumulator.output_tokens, 5);
+    }
+}
@@ -0,0 +1,62 @@
+use crate::types::ChatCompletionRequest;
+
+use super::openai::build_openai_request;
+use super::{Provider, ProviderError};
+
+pub const DEFAULT_API_VERSION: &str = "2024-10-21";
+
+/// Azure OpenAI. Models are deployment names, which Azure takes in the path
+/// rather than the body.
+pub struct AzureOpenAiProvider {
+    /// The resource endpoint, e.g. `https://my-resource.openai.azure.com`.
+    pub endpoint: String,
+    pub api_version: String,
+}
+
+impl AzureOpenAiProvider {
+    pub fn new(endpoint: impl Into<String>) -> Self {
+        Self {
+            endpoint: endpoint.into().trim_end_matches('/').to_string(),
+            api_version: DEFAULT_API_VERSION.to_string(),
+        }
+    }
+
+    pub fn with_api_version(mut self, api_version: impl Into<String>) -> Self {
+        self.api_version = api_version.into();
+        self
+    }
+}
+
+impl Provider for AzureOpenAiProvider {
+    fn name(&self) -> &str {
+        "azure_openai"
+    }
+
+    fn base_url(&self) -> &str {
+        &self.endpoint
+    }
+
+    fn request_url(&self, model: &str, _stream: bool) -> String {
+        format!(
+            "{}/openai/deployments/{}/chat/completions?api-version={}",
+            self.endpoint, model, self.api_version
+        )
+    }
+
+    fn build_request(
+        &self,
+        request: &ChatCompletionRequest,
+        _models: Vec<String>,
+        stream: bool,
+    ) -> Result<serde_json::Value, ProviderError> {
+        build_openai_request(request, stream)
+    }
+
+    fn auth_header_name(&self) -> &str {
+        "api-key"
+    }
+
+    fn build_auth_header(&self, api_key: &str) -> String {
+        api_key.to_string()
+    }
+}
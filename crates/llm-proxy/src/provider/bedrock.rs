@@ -0,0 +1,534 @@
+use bytes::Bytes;
+use serde_json::{Value, json};
+
+use crate::types::{ChatCompletionRequest, Role, ToolChoice};
+
+use super::translate::{
+    ChunkWriter, DONE, Part, ToolCall, chat_completion, content_parts, content_text, finish_reason,
+    function_tools, stop_sequences, tool_call_id, tool_call_json, tool_calls, usage,
+};
+use super::{Provider, ProviderError, StreamTranslator, single_model};
+
+/// Amazon Bedrock through the Converse API, which takes the same request shape
+/// for every model family. Authenticates with a Bedrock API key as a bearer
+/// token, so the server needs no AWS SDK or SigV4 signing.
+pub struct BedrockProvider {
+    /// e.g. `https://bedrock-runtime.us-east-1.amazonaws.com`.
+    pub base_url: String,
+}
+
+impl BedrockProvider {
+    pub fn new(region: &str) -> Self {
+        Self {
+            base_url: format!("https://bedrock-runtime.{}.amazonaws.com", region),
+        }
+    }
+
+    pub fn with_base_url(base_url: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+        }
+    }
+}
+
+impl Provider for BedrockProvider {
+    fn name(&self) -> &str {
+        "bedrock"
+    }
+
+    fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    fn request_url(&self, model: &str, stream: bool) -> String {
+        // Model ids can be ARNs or carry a `:version` suffix.
+        let model = model.replace(':', "%3A").replace('/', "%2F");
+        let action = if stream {
+            "converse-stream"
+        } else {
+            "converse"
+        };
+        format!("{}/model/{}/{}", self.base_url, model, action)
+    }
+
+    fn build_request(
+        &self,
+        request: &ChatCompletionRequest,
+        models: Vec<String>,
+        _stream: bool,
+    ) -> Result<serde_json::Value, ProviderError> {
+        // The model only appears in the URL.
+        single_model(&models)?;
+
+        let mut system = vec![];
+        let mut messages: Vec<(&str, Vec<Value>)> = vec![];
+
+        for message in &request.messages {
+            let (role, blocks) = match message.role {
+                Role::System => {
+                    system.push(json!({"text": content_text(message.content.as_ref())}));
+                    continue;
+                }
+                Role::User => ("user", content_blocks(message.content.as_ref())),
+                Role::Assistant => {
+                    let mut blocks = content_blocks(message.content.as_ref());
+                    blocks.extend(tool_calls(message).into_iter().map(|call| {
+                        json!({"toolUse": {"toolUseId": call.id, "name": call.name, "input": call.arguments}})
+                    }));
+                    ("assistant", blocks)
+                }
+                Role::Tool => (
+                    "user",
+                    vec![json!({"toolResult": {
+                        "toolUseId": tool_call_id(message).unwrap_or_default(),
+                        "content": [{"text": content_text(message.content.as_ref())}],
+                    }})],
+                ),
+            };
+            if blocks.is_empty() {
+                continue;
+            }
+
+            match messages.last_mut() {
+                Some((last, content)) if *last == role => content.extend(blocks),
+                _ => messages.push((role, blocks)),
+            }
+        }
+
+        let mut body = json!({
+            "messages": messages
+                .into_iter()
+                .map(|(role, content)| json!({"role": role, "content": content}))
+                .collect::<Vec<_>>(),
+        });
+        if !system.is_empty() {
+            body["system"] = system.into();
+        }
+
+        let mut inference = serde_json::Map::new();
+        if let Some(max_tokens) = request.max_tokens {
+            inference.insert("maxTokens".to_string(), max_tokens.into());
+        }
+        if let Some(temperature) = request.temperature {
+            inference.insert("temperature".to_string(), temperature.into());
+        }
+        if let Some(top_p) = request.extra.get("top_p") {
+            inference.insert("topP".to_string(), top_p.clone());
+        }
+        let stop = stop_sequences(request);
+        if !stop.is_empty() {
+            inference.insert("stopSequences".to_string(), stop.into());
+        }
+        if !inference.is_empty() {
+            body["inferenceConfig"] = inference.into();
+        }
+
+        let tools = function_tools(request);
+        if !tools.is_empty() {
+            let mut tool_config = json!({
+                "tools": tools
+                    .into_iter()
+                    .map(|tool| json!({"toolSpec": {
+                        "name": tool.name,
+                        "description": tool.description,
+                        "inputSchema": {"json": tool.parameters},
+                    }}))
+                    .collect::<Vec<_>>(),
+            });
+            // Converse has no "none"; leaving the choice unset means auto.
+            match &request.tool_choice {
+                Some(ToolChoice::String(s)) if s == "required" => {
+                    tool_config["toolChoice"] = json!({"any": {}});
+                }
+                Some(ToolChoice::Object { function, .. }) => {
+                    tool_config["toolChoice"] = json!({"tool": {"name": function.get("name")}});
+                }
+                _ => {}
+            }
+            body["toolConfig"] = tool_config;
+        }
+
+        Ok(body)
+    }
+
+    fn transform_response(&self, model: &str, body: Bytes) -> Result<Bytes, ProviderError> {
+        let parsed: Value =
+            serde_json::from_slice(&body).map_err(|e| ProviderError::ParseError(e.to_string()))?;
+
+        let mut text = String::new();
+        let mut calls = vec![];
+        for block in parsed["output"]["message"]["content"]
+            .as_array()
+            .into_iter()
+            .flatten()
+        {
+            if let Some(t) = block["text"].as_str() {
+                text.push_str(t);
+            } else if let Some(tool_use) = block.get("toolUse") {
+                calls.push(ToolCall {
+                    id: tool_use["toolUseId"]
+                        .as_str()
+                        .unwrap_or_default()
+                        .to_string(),
+                    name: tool_use["name"].as_str().unwrap_or_default().to_string(),
+                    arguments: tool_use["input"].clone(),
+                });
+            }
+        }
+
+        // Converse responses carry no id of their own.
+        let completion = chat_completion(
+            &generation_id(),
+            model,
+            text,
+            calls,
+            finish_reason(parsed["stopReason"].as_str().unwrap_or_default()),
+            usage(
+                parsed["usage"]["inputTokens"].as_u64().unwrap_or(0),
+                parsed["usage"]["outputTokens"].as_u64().unwrap_or(0),
+            ),
+        );
+        Ok(serde_json::to_vec(&completion)?.into())
+    }
+
+    fn stream_translator(&self, model: &str) -> Option<Box<dyn StreamTranslator>> {
+        Some(Box::new(BedrockStream {
+            frames: EventStreamBuffer::default(),
+            writer: ChunkWriter::new(generation_id(), model),
+            tool_indices: Default::default(),
+            stop_reason: None,
+            done: false,
+        }))
+    }
+}
+
+fn generation_id() -> String {
+    format!("bedrock-{}", uuid::Uuid::new_v4().simple())
+}
+
+fn content_blocks(content: Option<&Value>) -> Vec<Value> {
+    content_parts(content)
+        .into_iter()
+        .filter_map(|part| match part {
+            Part::Text(text) => Some(json!({"text": text})),
+            Part::InlineImage { media_type, data } => {
+                let format = media_type.strip_prefix("image/")?.to_string();
+                Some(json!({"image": {"format": format, "source": {"bytes": data}}}))
+            }
+            // Converse only takes inline bytes or S3 locations.
+            Part::ImageUrl(_) => None,
+        })
+        .collect()
+}
+
+struct BedrockStream {
+    frames: EventStreamBuffer,
+    writer: ChunkWriter,
+    /// Content block index to OpenAI tool call index.
+    tool_indices: std::collections::HashMap<u64, usize>,
+    stop_reason: Option<String>,
+    done: bool,
+}
+
+impl BedrockStream {
+    fn close(&mut self, usage: Value) -> Vec<u8> {
+        self.done = true;
+        let mut out = self.writer.finish(
+            finish_reason(self.stop_reason.as_deref().unwrap_or_default()),
+            usage,
+        );
+        out.extend(DONE);
+        out
+    }
+}
+
+impl StreamTranslator for BedrockStream {
+    fn push(&mut self, chunk: &[u8]) -> Vec<u8> {
+        let mut out = vec![];
+
+        for frame in self.frames.push(chunk) {
+            if self.done {
+                break;
+            }
+            let Ok(data) = serde_json::from_slice::<Value>(&frame.payload) else {
+                continue;
+            };
+
+            if frame.message_type.as_deref() == Some("exception") {
+                let error = json!({"error": {
+                    "type": frame.event_type,
+                    "message": data["message"],
+                }});
+                out.extend(format!("data: {}\n\n", error).bytes());
+                continue;
+            }
+
+            match frame.event_type.as_deref() {
+                Some("messageStart") => {
+                    out.extend(
+                        self.writer
+                            .delta(json!({"role": "assistant", "content": ""})),
+                    );
+                }
+                Some("contentBlockStart") => {
+                    let Some(tool_use) = data["start"].get("toolUse") else {
+                        continue;
+                    };
+                    let index = self.tool_indices.len();
+                    self.tool_indices
+                        .insert(data["contentBlockIndex"].as_u64().unwrap_or(0), index);
+
+                    let call = ToolCall {
+                        id: tool_use["toolUseId"]
+                            .as_str()
+                            .unwrap_or_default()
+                            .to_string(),
+                        name: tool_use["name"].as_str().unwrap_or_default().to_string(),
+                        arguments: Value::Null,
+                    };
+                    let mut call = tool_call_json(index, &call);
+                    call["function"]["arguments"] = "".into();
+                    out.extend(self.writer.delta(json!({"tool_calls": [call]})));
+                }
+                Some("contentBlockDelta") => {
+                    let delta = &data["delta"];
+                    if let Some(text) = delta["text"].as_str() {
+                        out.extend(self.writer.delta(json!({"content": text})));
+                    } else if let Some(input) = delta["toolUse"]["input"].as_str() {
+                        let block = data["contentBlockIndex"].as_u64().unwrap_or(0);
+                        let Some(index) = self.tool_indices.get(&block) else {
+                            continue;
+                        };
+                        out.extend(self.writer.delta(json!({"tool_calls": [{
+                            "index": index,
+                            "function": {"arguments": input},
+                        }]})));
+                    }
+                }
+                Some("messageStop") => {
+                    self.stop_reason = data["stopReason"].as_str().map(String::from);
+                }
+                // Usage arrives last, after `messageStop`.
+                Some("metadata") => {
+                    out.extend(self.close(usage(
+                        data["usage"]["inputTokens"].as_u64().unwrap_or(0),
+                        data["usage"]["outputTokens"].as_u64().unwrap_or(0),
+                    )));
+                }
+                _ => {}
+            }
+        }
+
+        out
+    }
+
+    fn finish(&mut self) -> Vec<u8> {
+        if self.done || self.stop_reason.is_none() {
+            return vec![];
+        }
+        self.close(usage(0, 0))
+    }
+}
+
+struct Frame {
+    event_type: Option<String>,
+    message_type: Option<String>,
+    payload: Vec<u8>,
+}
+
+/// Decodes `application/vnd.amazon.eventstream` frames: a 12-byte prelude
+/// (total length, headers length, CRC), headers, payload and a trailing CRC.
+/// The CRCs aren't checked; TLS already covers integrity.
+#[derive(Default)]
+struct EventStreamBuffer {
+    buf: Vec<u8>,
+}
+
+impl EventStreamBuffer {
+    fn push(&mut self, chunk: &[u8]) -> Vec<Frame> {
+        self.buf.extend_from_slice(chunk);
+
+        let mut frames = vec![];
+        while self.buf.len() >= 12 {
+            let total = u32::from_be_bytes(self.buf[0..4].try_into().unwrap()) as usize;
+            let headers_len = u32::from_be_bytes(self.buf[4..8].try_into().unwrap()) as usize;
+            if total < 16 + headers_len {
+                // Not a frame we can make sense of; drop what we have.
+                self.buf.clear();
+                break;
+            }
+            if self.buf.len() < total {
+                break;
+            }
+
+            let frame: Vec<u8> = self.buf.drain(..total).collect();
+            let headers = parse_headers(&frame[12..12 + headers_len]);
+            let header = |name: &str| {
+                headers
+                    .iter()
+                    .find(|(n, _)| n == name)
+                    .map(|(_, v)| v.clone())
+            };
+
+            frames.push(Frame {
+                event_type: header(":event-type").or_else(|| header(":exception-type")),
+                message_type: header(":message-type"),
+                payload: frame[12 + headers_len..total - 4].to_vec(),
+            });
+        }
+        frames
+    }
+}
+
+/// String-valued headers only; other values are skipped over.
+fn parse_headers(mut bytes: &[u8]) -> Vec<(String, String)> {
+    let mut headers = vec![];
+
+    while let Some((&name_len, rest)) = bytes.split_first() {
+        let name_len = name_len as usize;
+        if rest.len() < name_len + 1 {
+            break;
+        }
+        let name = String::from_utf8_lossy(&rest[..name_len]).to_string();
+        let value_type = rest[name_len];
+        let rest = &rest[name_len + 1..];
+
+        let value_len = match value_type {
+            0 | 1 => 0,
+            2 => 1,
+            3 => 2,
+            4 => 4,
+            5 | 8 => 8,
+            9 => 16,
+            6 | 7 if rest.len() >= 2 => 2 + u16::from_be_bytes([rest[0], rest[1]]) as usize,
+            _ => break,
+        };
+        if rest.len() < value_len {
+            break;
+        }
+        if value_type == 7 {
+            headers.push((
+                name,
+                String::from_utf8_lossy(&rest[2..value_len]).to_string(),
+            ));
+        }
+        bytes = &rest[value_len..];
+    }
+
+    headers
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame(event_type: &str, payload: Value) -> Vec<u8> {
+        let mut headers = vec![];
+        for (name, value) in [(":event-type", event_type), (":message-type", "event")] {
+            headers.push(name.len() as u8);
+            headers.extend(name.as_bytes());
+            headers.push(7);
+            headers.extend((value.len() as u16).to_be_bytes());
+            headers.extend(value.as_bytes());
+        }
+        let payload = payload.to_string().into_bytes();
+        let total = 16 + headers.len() + payload.len();
+
+        let mut out = vec![];
+        out.extend((total as u32).to_be_bytes());
+        out.extend((headers.len() as u32).to_be_bytes());
+        out.extend([0; 4]);
+        out.extend(headers);
+        out.extend(payload);
+        out.extend([0; 4]);
+        out
+    }
+
+    #[test]
+    fn test_build_request() {
+        let request: ChatCompletionRequest = serde_json::from_value(json!({
+            "messages": [
+                {"role": "system", "content": "Be brief."},
+                {"role": "user", "content": [{"type": "text", "text": "Weather in Paris?"}]},
+                {"role": "assistant", "tool_calls": [{
+                    "id": "call_1", "type": "function",
+                    "function": {"name": "weather", "arguments": "{\"city\":\"Paris\"}"}
+                }]},
+                {"role": "tool", "tool_call_id": "call_1", "content": "Sunny"},
+            ],
+            "tools": [{"type": "function", "function": {"name": "weather"}}],
+            "max_tokens": 100,
+        }))
+        .unwrap();
+
+        let provider = BedrockProvider::new("us-east-1");
+        let model = "anthropic.claude-3-5-haiku-20241022-v1:0";
+        let body = provider
+            .build_request(&request, vec![model.into()], true)
+            .unwrap();
+
+        assert_eq!(
+            provider.request_url(model, true),
+            "https://bedrock-runtime.us-east-1.amazonaws.com/model/anthropic.claude-3-5-haiku-20241022-v1%3A0/converse-stream"
+        );
+        assert_eq!(body["system"], json!([{"text": "Be brief."}]));
+        assert_eq!(body["inferenceConfig"], json!({"maxTokens": 100}));
+        assert_eq!(
+            body["toolConfig"]["tools"][0]["toolSpec"]["name"],
+            "weather"
+        );
+        assert_eq!(
+            body["messages"][1]["content"][0]["toolUse"]["input"],
+            json!({"city": "Paris"})
+        );
+        assert_eq!(
+            body["messages"][2]["content"][0]["toolResult"]["toolUseId"],
+            "call_1"
+        );
+    }
+
+    #[test]
+    fn test_stream_translator() {
+        let upstream = [
+            frame("messageStart", json!({"role": "assistant"})),
+            frame(
+                "contentBlockDelta",
+                json!({"contentBlockIndex": 0, "delta": {"text": "Hi"}}),
+            ),
+            frame(
+                "contentBlockStart",
+                json!({"contentBlockIndex": 1, "start": {"toolUse": {"toolUseId": "t1", "name": "weather"}}}),
+            ),
+            frame(
+                "contentBlockDelta",
+                json!({"contentBlockIndex": 1, "delta": {"toolUse": {"input": "{\"city\":"}}}),
+            ),
+            frame("messageStop", json!({"stopReason": "tool_use"})),
+            frame(
+                "metadata",
+                json!({"usage": {"inputTokens": 9, "outputTokens": 5}}),
+            ),
+        ]
+        .concat();
+
+        let provider = BedrockProvider::new("us-east-1");
+        let mut translator = provider.stream_translator("nova-lite").unwrap();
+        let out: Vec<u8> = upstream
+            .chunks(13)
+            .flat_map(|chunk| translator.push(chunk))
+            .collect();
+        let out = String::from_utf8(out).unwrap();
+
+        assert!(out.contains(r#""content":"Hi""#));
+        assert!(out.contains(r#""name":"weather""#));
+        assert!(out.contains(r#""finish_reason":"tool_calls""#));
+        assert!(out.ends_with("data: [DONE]\n\n"));
+        assert!(translator.finish().is_empty());
+
+        let mut accumulator = super::super::StreamAccumulator::new();
+        provider.parse_stream_chunk(out.as_bytes(), &mut accumulator);
+        assert_eq!(accumulator.model.as_deref(), Some("nova-lite"));
+        assert_eq!(accumulator.input_tokens, 9);
+        assert_eq!(accumulator.output_tokens, 5);
+    }
+}
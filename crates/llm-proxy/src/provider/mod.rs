@@ -1,11 +1,21 @@
+mod anthropic;
+mod azure;
+mod bedrock;
+mod openai;
 mod openrouter;
+mod translate;
 
+pub use anthropic::AnthropicProvider;
+pub use azure::AzureOpenAiProvider;
+pub use bedrock::BedrockProvider;
+pub use openai::OpenAiProvider;
 pub use openrouter::OpenRouterProvider;
 
+use bytes::Bytes;
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
 
-use crate::types::ChatCompletionRequest;
+use crate::types::{ChatCompletionRequest, UsageInfo};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GenerationMetadata {
@@ -51,11 +61,38 @@ pub enum ProviderError {
     InvalidRequest(String),
 }
 
+/// Rewrites a provider's native stream into OpenAI `chat.completion.chunk`
+/// events. Chunks may split events anywhere, so implementations buffer.
+pub trait StreamTranslator: Send {
+    fn push(&mut self, chunk: &[u8]) -> Vec<u8>;
+
+    fn finish(&mut self) -> Vec<u8> {
+        vec![]
+    }
+}
+
+/// Clients always speak the OpenAI chat completions format. Providers with a
+/// different native API translate requests in `build_request` and responses in
+/// `transform_response` / `stream_translator`; `parse_response` and
+/// `parse_stream_chunk` only ever see the OpenAI shape.
 pub trait Provider: Send + Sync {
     fn name(&self) -> &str;
 
     fn base_url(&self) -> &str;
 
+    /// Where to send a request for `model`, the first of the models passed to
+    /// `build_request`.
+    fn request_url(&self, model: &str, stream: bool) -> String {
+        let _ = (model, stream);
+        self.base_url().to_string()
+    }
+
+    /// Whether `build_request` can take several models and let the upstream
+    /// fall back between them, like OpenRouter's `models`.
+    fn supports_model_fallback(&self) -> bool {
+        false
+    }
+
     fn build_request(
         &self,
         request: &ChatCompletionRequest,
@@ -63,9 +100,25 @@ pub trait Provider: Send + Sync {
         stream: bool,
     ) -> Result<serde_json::Value, ProviderError>;
 
-    fn parse_response(&self, body: &[u8]) -> Result<GenerationMetadata, ProviderError>;
+    /// Converts a successful non-streaming body for `model` into an OpenAI chat
+    /// completion.
+    fn transform_response(&self, model: &str, body: Bytes) -> Result<Bytes, ProviderError> {
+        let _ = model;
+        Ok(body)
+    }
 
-    fn parse_stream_chunk(&self, chunk: &[u8], accumulator: &mut StreamAccumulator);
+    fn stream_translator(&self, model: &str) -> Option<Box<dyn StreamTranslator>> {
+        let _ = model;
+        None
+    }
+
+    fn parse_response(&self, body: &[u8]) -> Result<GenerationMetadata, ProviderError> {
+        parse_openai_response(body)
+    }
+
+    fn parse_stream_chunk(&self, chunk: &[u8], accumulator: &mut StreamAccumulator) {
+        parse_openai_stream_chunk(chunk, accumulator)
+    }
 
     fn fetch_cost(
         &self,
@@ -77,6 +130,10 @@ pub trait Provider: Send + Sync {
         Box::pin(async { None })
     }
 
+    fn auth_header_name(&self) -> &str {
+        "Authorization"
+    }
+
     fn build_auth_header(&self, api_key: &str) -> String {
         format!("Bearer {}", api_key)
     }
@@ -85,3 +142,73 @@ pub trait Provider: Send + Sync {
         vec![]
     }
 }
+
+#[derive(Debug, Deserialize)]
+struct OpenAiResponse {
+    pub id: String,
+    pub model: Option<String>,
+    pub usage: Option<UsageInfo>,
+}
+
+pub(crate) fn parse_openai_response(body: &[u8]) -> Result<GenerationMetadata, ProviderError> {
+    let parsed: OpenAiResponse =
+        serde_json::from_slice(body).map_err(|e| ProviderError::ParseError(e.to_string()))?;
+
+    Ok(GenerationMetadata {
+        generation_id: parsed.id,
+        model: parsed.model,
+        input_tokens: parsed.usage.as_ref().map(|u| u.input_tokens()).unwrap_or(0),
+        output_tokens: parsed
+            .usage
+            .as_ref()
+            .map(|u| u.output_tokens())
+            .unwrap_or(0),
+    })
+}
+
+pub(crate) fn parse_openai_stream_chunk(chunk: &[u8], accumulator: &mut StreamAccumulator) {
+    let Ok(text) = std::str::from_utf8(chunk) else {
+        return;
+    };
+
+    for line in text.lines() {
+        let Some(data) = line.strip_prefix("data: ") else {
+            continue;
+        };
+
+        if data.trim() == "[DONE]" {
+            continue;
+        }
+
+        let Ok(parsed) = serde_json::from_str::<serde_json::Value>(data) else {
+            continue;
+        };
+
+        if accumulator.generation_id.is_none() {
+            accumulator.generation_id = parsed.get("id").and_then(|v| v.as_str()).map(String::from);
+        }
+
+        if accumulator.model.is_none() {
+            accumulator.model = parsed
+                .get("model")
+                .and_then(|v| v.as_str())
+                .map(String::from);
+        }
+
+        if let Some(usage) = parsed
+            .get("usage")
+            .and_then(|u| serde_json::from_value::<UsageInfo>(u.clone()).ok())
+        {
+            accumulator.input_tokens = usage.input_tokens();
+            accumulator.output_tokens = usage.output_tokens();
+        }
+    }
+}
+
+/// The first model, which providers without server-side fallback send as `model`.
+fn single_model(models: &[String]) -> Result<&str, ProviderError> {
+    models
+        .first()
+        .map(String::as_str)
+        .ok_or_else(|| ProviderError::InvalidRequest("no model configured".to_string()))
+}
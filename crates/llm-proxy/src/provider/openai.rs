@@ -0,0 +1,81 @@
+use crate::types::ChatCompletionRequest;
+
+use super::{Provider, ProviderError, single_model};
+
+pub const OPENAI_URL: &str = "https://api.openai.com/v1/chat/completions";
+
+/// OpenAI itself, or any server speaking its chat completions API, such as
+/// Ollama (`http://localhost:11434/v1/chat/completions`) or vLLM.
+pub struct OpenAiProvider {
+    pub name: String,
+    pub base_url: String,
+}
+
+impl Default for OpenAiProvider {
+    fn default() -> Self {
+        Self {
+            name: "openai".to_string(),
+            base_url: OPENAI_URL.to_string(),
+        }
+    }
+}
+
+impl OpenAiProvider {
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn compatible(name: impl Into<String>, base_url: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            base_url: base_url.into(),
+        }
+    }
+}
+
+impl Provider for OpenAiProvider {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    fn build_request(
+        &self,
+        request: &ChatCompletionRequest,
+        models: Vec<String>,
+        stream: bool,
+    ) -> Result<serde_json::Value, ProviderError> {
+        let mut body = build_openai_request(request, stream)?;
+        body.as_object_mut().unwrap().insert(
+            "model".to_string(),
+            single_model(&models)?.to_string().into(),
+        );
+        Ok(body)
+    }
+}
+
+pub(super) fn build_openai_request(
+    request: &ChatCompletionRequest,
+    stream: bool,
+) -> Result<serde_json::Value, ProviderError> {
+    let mut body = serde_json::to_value(request)?;
+    let obj = body.as_object_mut().unwrap();
+
+    obj.remove("model");
+    obj.insert("stream".to_string(), serde_json::Value::Bool(stream));
+    if stream {
+        // Without this the stream carries no token counts for analytics.
+        obj.insert(
+            "stream_options".to_string(),
+            serde_json::json!({"include_usage": true}),
+        );
+    }
+
+    Ok(body)
+}
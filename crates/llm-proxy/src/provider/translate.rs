@@ -0,0 +1,276 @@
+//! Shared pieces for providers whose native API isn't OpenAI's.
+
+use serde_json::{Value, json};
+
+use crate::types::{ChatCompletionRequest, ChatMessage};
+
+pub(super) const DONE: &[u8] = b"data: [DONE]\n\n";
+
+pub(super) enum Part {
+    Text(String),
+    /// A base64 `data:` URL, split into media type and payload.
+    InlineImage {
+        media_type: String,
+        data: String,
+    },
+    ImageUrl(String),
+}
+
+/// The parts of an OpenAI message `content`, which is either a string or an
+/// array of typed parts.
+pub(super) fn content_parts(content: Option<&Value>) -> Vec<Part> {
+    match content {
+        Some(Value::String(text)) if !text.is_empty() => vec![Part::Text(text.clone())],
+        Some(Value::Array(parts)) => parts
+            .iter()
+            .filter_map(|part| match part.get("type").and_then(Value::as_str) {
+                Some("text") => part
+                    .get("text")
+                    .and_then(Value::as_str)
+                    .map(|text| Part::Text(text.to_string())),
+                Some("image_url") => {
+                    let url = part.pointer("/image_url/url").and_then(Value::as_str)?;
+                    Some(match parse_data_url(url) {
+                        Some((media_type, data)) => Part::InlineImage {
+                            media_type: media_type.to_string(),
+                            data: data.to_string(),
+                        },
+                        None => Part::ImageUrl(url.to_string()),
+                    })
+                }
+                _ => None,
+            })
+            .collect(),
+        _ => vec![],
+    }
+}
+
+pub(super) fn content_text(content: Option<&Value>) -> String {
+    content_parts(content)
+        .into_iter()
+        .filter_map(|part| match part {
+            Part::Text(text) => Some(text),
+            _ => None,
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn parse_data_url(url: &str) -> Option<(&str, &str)> {
+    let rest = url.strip_prefix("data:")?;
+    let (media_type, data) = rest.split_once(";base64,")?;
+    Some((media_type, data))
+}
+
+pub(super) struct ToolCall {
+    pub id: String,
+    pub name: String,
+    pub arguments: Value,
+}
+
+/// `tool_calls` on an assistant message, with the JSON-encoded arguments parsed.
+pub(super) fn tool_calls(message: &ChatMessage) -> Vec<ToolCall> {
+    let Some(calls) = message.extra.get("tool_calls").and_then(Value::as_array) else {
+        return vec![];
+    };
+
+    calls
+        .iter()
+        .filter_map(|call| {
+            let function = call.get("function")?;
+            let arguments = function
+                .get("arguments")
+                .and_then(Value::as_str)
+                .and_then(|args| serde_json::from_str(args).ok())
+                .unwrap_or_else(|| json!({}));
+            Some(ToolCall {
+                id: call.get("id")?.as_str()?.to_string(),
+                name: function.get("name")?.as_str()?.to_string(),
+                arguments,
+            })
+        })
+        .collect()
+}
+
+pub(super) fn tool_call_id(message: &ChatMessage) -> Option<&str> {
+    message.extra.get("tool_call_id").and_then(Value::as_str)
+}
+
+pub(super) struct FunctionTool<'a> {
+    pub name: &'a str,
+    pub description: Option<&'a str>,
+    pub parameters: Value,
+}
+
+pub(super) fn function_tools(request: &ChatCompletionRequest) -> Vec<FunctionTool<'_>> {
+    request
+        .tools
+        .iter()
+        .flatten()
+        .filter_map(|tool| {
+            let function = tool.get("function")?;
+            Some(FunctionTool {
+                name: function.get("name")?.as_str()?,
+                description: function.get("description").and_then(Value::as_str),
+                parameters: function
+                    .get("parameters")
+                    .cloned()
+                    .unwrap_or_else(|| json!({"type": "object", "properties": {}})),
+            })
+        })
+        .collect()
+}
+
+pub(super) fn stop_sequences(request: &ChatCompletionRequest) -> Vec<String> {
+    match request.extra.get("stop") {
+        Some(Value::String(stop)) => vec![stop.clone()],
+        Some(Value::Array(stops)) => stops
+            .iter()
+            .filter_map(|s| s.as_str().map(String::from))
+            .collect(),
+        _ => vec![],
+    }
+}
+
+/// Anthropic and Bedrock share these stop reasons.
+pub(super) fn finish_reason(stop_reason: &str) -> &'static str {
+    match stop_reason {
+        "tool_use" => "tool_calls",
+        "max_tokens" => "length",
+        "guardrail_intervened" | "content_filtered" | "refusal" => "content_filter",
+        _ => "stop",
+    }
+}
+
+pub(super) fn usage(input_tokens: u64, output_tokens: u64) -> Value {
+    json!({
+        "prompt_tokens": input_tokens,
+        "completion_tokens": output_tokens,
+        "total_tokens": input_tokens + output_tokens,
+    })
+}
+
+pub(super) fn tool_call_json(index: usize, call: &ToolCall) -> Value {
+    json!({
+        "index": index,
+        "id": call.id,
+        "type": "function",
+        "function": {"name": call.name, "arguments": call.arguments.to_string()},
+    })
+}
+
+pub(super) fn chat_completion(
+    id: &str,
+    model: &str,
+    text: String,
+    tool_calls: Vec<ToolCall>,
+    finish_reason: &str,
+    usage: Value,
+) -> Value {
+    let mut message = json!({
+        "role": "assistant",
+        "content": if text.is_empty() && !tool_calls.is_empty() { Value::Null } else { text.into() },
+    });
+    if !tool_calls.is_empty() {
+        message["tool_calls"] = tool_calls
+            .iter()
+            .enumerate()
+            .map(|(i, call)| tool_call_json(i, call))
+            .collect();
+    }
+
+    json!({
+        "id": id,
+        "object": "chat.completion",
+        "created": unix_now(),
+        "model": model,
+        "choices": [{"index": 0, "message": message, "finish_reason": finish_reason}],
+        "usage": usage,
+    })
+}
+
+/// Writes `chat.completion.chunk` events for one generation.
+pub(super) struct ChunkWriter {
+    pub id: String,
+    pub model: String,
+    created: u64,
+}
+
+impl ChunkWriter {
+    pub fn new(id: impl Into<String>, model: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            model: model.into(),
+            created: unix_now(),
+        }
+    }
+
+    pub fn delta(&self, delta: Value) -> Vec<u8> {
+        self.event(
+            json!([{"index": 0, "delta": delta, "finish_reason": null}]),
+            None,
+        )
+    }
+
+    pub fn finish(&self, finish_reason: &str, usage: Value) -> Vec<u8> {
+        self.event(
+            json!([{"index": 0, "delta": {}, "finish_reason": finish_reason}]),
+            Some(usage),
+        )
+    }
+
+    fn event(&self, choices: Value, usage: Option<Value>) -> Vec<u8> {
+        let mut chunk = json!({
+            "id": self.id,
+            "object": "chat.completion.chunk",
+            "created": self.created,
+            "model": self.model,
+            "choices": choices,
+        });
+        if let Some(usage) = usage {
+            chunk["usage"] = usage;
+        }
+        format!("data: {}\n\n", chunk).into_bytes()
+    }
+}
+
+/// Splits a byte stream into SSE events, holding back a trailing partial one.
+#[derive(Default)]
+pub(super) struct SseBuffer {
+    buf: Vec<u8>,
+}
+
+pub(super) struct SseEvent {
+    pub data: String,
+}
+
+impl SseBuffer {
+    pub fn push(&mut self, chunk: &[u8]) -> Vec<SseEvent> {
+        self.buf.extend(chunk.iter().filter(|b| **b != b'\r'));
+
+        let mut events = vec![];
+        while let Some(end) = self.buf.windows(2).position(|w| w == b"\n\n") {
+            let raw: Vec<u8> = self.buf.drain(..end + 2).collect();
+            let raw = String::from_utf8_lossy(&raw);
+
+            let data: Vec<&str> = raw
+                .lines()
+                .filter_map(|line| line.strip_prefix("data:"))
+                .map(|value| value.strip_prefix(' ').unwrap_or(value))
+                .collect();
+            if !data.is_empty() {
+                events.push(SseEvent {
+                    data: data.join("\n"),
+                });
+            }
+        }
+        events
+    }
+}
+
+fn unix_now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
@@ -0,0 +1,71 @@
+use std::sync::Arc;
+
+use crate::config::LlmProxyConfig;
+use crate::provider::Provider;
+
+#[derive(Clone)]
+pub(crate) struct Upstream {
+    pub provider: Arc<dyn Provider>,
+    pub api_key: String,
+}
+
+impl Upstream {
+    fn same_as(&self, other: &Upstream) -> bool {
+        Arc::ptr_eq(&self.provider, &other.provider) && self.api_key == other.api_key
+    }
+}
+
+/// One upstream request. The handler moves on to the next attempt when an
+/// upstream is unreachable, rate limited or failing.
+pub(crate) struct Attempt {
+    pub upstream: Upstream,
+    pub models: Vec<String>,
+}
+
+/// Resolves `models`, in preference order, to upstream attempts. Adjacent
+/// models on a provider with its own fallback (OpenRouter) share an attempt.
+pub(crate) fn plan(config: &LlmProxyConfig, models: Vec<String>) -> Vec<Attempt> {
+    let default = Upstream {
+        provider: config.provider.clone(),
+        api_key: config.api_key.clone(),
+    };
+    if models.is_empty() {
+        return vec![Attempt {
+            upstream: default,
+            models,
+        }];
+    }
+
+    let mut attempts: Vec<Attempt> = vec![];
+    for model in models {
+        let (upstream, model) = config
+            .routes
+            .iter()
+            .find_map(|route| {
+                let upstream = Upstream {
+                    provider: route.provider.clone(),
+                    api_key: route.api_key.clone(),
+                };
+                route.upstream_model(&model).map(|m| (upstream, m))
+            })
+            .unwrap_or_else(|| (default.clone(), model));
+
+        match attempts.last_mut() {
+            Some(last)
+                if last.upstream.same_as(&upstream)
+                    && upstream.provider.supports_model_fallback() =>
+            {
+                last.models.push(model);
+            }
+            _ => attempts.push(Attempt {
+                upstream,
+                models: vec![model],
+            }),
+        }
+    }
+    attempts
+}
+
+pub(crate) fn should_fall_back(status: reqwest::StatusCode) -> bool {
+    status.is_server_error() || status == reqwest::StatusCode::TOO_MANY_REQUESTS
+}
@@ -147,6 +147,409 @@ mod error_handling {
     }
 }
 
+mod routing {
+    use super::*;
+
+    use llm_proxy::ModelRoute;
+    use llm_proxy::provider::{AnthropicProvider, OpenAiProvider};
+    use wiremock::matchers::{body_partial_json, header, method};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn routes_model_to_native_provider() {
+        let harness = TestHarness::new().await;
+        let anthropic = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(header("x-api-key", "anthropic-key"))
+            .and(header("anthropic-version", "2023-06-01"))
+            .and(body_partial_json(serde_json::json!({
+                "model": "claude-haiku-4-5",
+                "messages": [{"role": "user", "content": [{"type": "text", "text": "Hello"}]}],
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "msg_123",
+                "model": "claude-haiku-4-5",
+                "content": [{"type": "text", "text": "hi"}],
+                "stop_reason": "end_turn",
+                "usage": {"input_tokens": 10, "output_tokens": 1},
+            })))
+            .expect(1)
+            .mount(&anthropic)
+            .await;
+
+        let config = harness
+            .config()
+            .with_models_default(vec!["anthropic/claude-haiku-4-5".into()])
+            .with_route(ModelRoute::new(
+                "anthropic/*",
+                Arc::new(AnthropicProvider::new(anthropic.uri())),
+                "anthropic-key",
+            ));
+
+        let response = router(config)
+            .oneshot(build_request(simple_message("Hello")))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = response_to_json(response).await;
+        assert_eq!(body["object"], "chat.completion");
+        assert_eq!(body["choices"][0]["message"]["content"], "hi");
+        assert_eq!(body["choices"][0]["finish_reason"], "stop");
+
+        let event = harness.analytics.get_single_event().await;
+        assert_eq!(event.generation_id, "msg_123");
+        assert_eq!(event.provider_name, "anthropic");
+        assert_eq!(event.input_tokens, 10);
+    }
+
+    #[tokio::test]
+    async fn falls_back_on_server_error() {
+        let harness = TestHarness::new().await;
+        harness
+            .mount_error_response(503, serde_json::json!({"error": {"message": "overloaded"}}))
+            .await;
+
+        let local = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(body_partial_json(serde_json::json!({"model": "llama3.2"})))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(completion_response(
+                    "chatcmpl-local",
+                    "llama3.2",
+                    "hello",
+                )),
+            )
+            .expect(1)
+            .mount(&local)
+            .await;
+
+        let config = harness
+            .config_no_analytics()
+            .with_models_default(vec!["openai/gpt-4.1-nano".into(), "ollama/llama3.2".into()])
+            .with_route(ModelRoute::new(
+                "ollama/*",
+                Arc::new(OpenAiProvider::compatible("ollama", local.uri())),
+                "",
+            ));
+
+        let response = router(config)
+            .oneshot(build_request(simple_message("Hello")))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = response_to_json(response).await;
+        assert_eq!(body["id"], "chatcmpl-local");
+    }
+
+    #[tokio::test]
+    async fn last_provider_error_is_returned() {
+        let harness = TestHarness::new().await;
+        harness
+            .mount_error_response(429, serde_json::json!({"error": {"message": "Rate limit"}}))
+            .await;
+
+        let local = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(
+                ResponseTemplate::new(500).set_body_json(serde_json::json!({"error": "boom"})),
+            )
+            .expect(1)
+            .mount(&local)
+            .await;
+
+        let config = harness
+            .config_no_analytics()
+            .with_models_default(vec!["openai/gpt-4.1-nano".into(), "vllm/qwen".into()])
+            .with_route(ModelRoute::new(
+                "vllm/*",
+                Arc::new(OpenAiProvider::compatible("vllm", local.uri())),
+                "",
+            ));
+
+        let response = router(config)
+            .oneshot(build_request(simple_message("Hello")))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status().as_u16(), 500);
+    }
+}
+
+mod caching {
+    use super::*;
+
+    use llm_proxy::CacheConfig;
+    use wiremock::matchers::method;
+    use wiremock::{Mock, ResponseTemplate};
+
+    #[tokio::test]
+    async fn replays_identical_request() {
+        let harness = TestHarness::new().await;
+        harness
+            .mount_json_response(completion_response(
+                "gen-cached",
+                "openai/gpt-4.1-nano",
+                "hello",
+            ))
+            .await;
+
+        let app = router(harness.config().with_cache(CacheConfig::default()));
+
+        let first = app
+            .clone()
+            .oneshot(build_request_as(simple_message("Hello"), "user-a"))
+            .await
+            .unwrap();
+        assert_eq!(first.status(), StatusCode::OK);
+        assert!(first.headers().get("X-Cache").is_none());
+        let first = response_to_json(first).await;
+
+        // Same request with fields in a different order.
+        let second = app
+            .oneshot(build_request_as(
+                serde_json::json!({
+                    "max_tokens": 10,
+                    "messages": [{"content": "Hello", "role": "user"}],
+                }),
+                "user-a",
+            ))
+            .await
+            .unwrap();
+        assert_eq!(second.headers().get("X-Cache").unwrap(), "HIT");
+        assert_eq!(response_to_json(second).await, first);
+
+        assert_eq!(
+            harness.analytics.cache_outcomes(2).await,
+            vec!["hit", "miss"]
+        );
+    }
+
+    #[tokio::test]
+    async fn replays_stream() {
+        let harness = TestHarness::new().await;
+        harness
+            .mount_stream_response(&stream_chunks("gen-stream-cached"))
+            .await;
+
+        let app = router(
+            harness
+                .config_no_analytics()
+                .with_cache(CacheConfig::default()),
+        );
+
+        let first = app
+            .clone()
+            .oneshot(build_request_as(stream_request("Hello"), "user-a"))
+            .await
+            .unwrap();
+        let first = response_to_string(first).await;
+
+        let second = app
+            .oneshot(build_request_as(stream_request("Hello"), "user-a"))
+            .await
+            .unwrap();
+        assert_eq!(
+            second.headers().get("Content-Type").unwrap(),
+            "text/event-stream"
+        );
+        assert_eq!(response_to_string(second).await, first);
+        assert!(first.contains("gen-stream-cached"));
+    }
+
+    #[tokio::test]
+    async fn expired_entries_go_upstream() {
+        let harness = TestHarness::new().await;
+        Mock::given(method("POST"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(completion_response(
+                    "gen-expired",
+                    "openai/gpt-4.1-nano",
+                    "hello",
+                )),
+            )
+            .expect(2)
+            .mount(&harness.mock_server)
+            .await;
+
+        let app = router(
+            harness
+                .config_no_analytics()
+                .with_cache(CacheConfig::default().with_ttl(std::time::Duration::ZERO)),
+        );
+
+        for _ in 0..2 {
+            let response = app
+                .clone()
+                .oneshot(build_request_as(simple_message("Hello"), "user-a"))
+                .await
+                .unwrap();
+            assert!(response.headers().get("X-Cache").is_none());
+        }
+    }
+
+    #[tokio::test]
+    async fn coalesces_concurrent_requests() {
+        let harness = TestHarness::new().await;
+        Mock::given(method("POST"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(completion_response(
+                        "gen-coalesced",
+                        "openai/gpt-4.1-nano",
+                        "hello",
+                    ))
+                    .set_delay(std::time::Duration::from_millis(300)),
+            )
+            .expect(1)
+            .mount(&harness.mock_server)
+            .await;
+
+        let app = router(harness.config().with_cache(CacheConfig::default()));
+
+        let (a, b) = tokio::join!(
+            app.clone()
+                .oneshot(build_request_as(simple_message("Hello"), "user-a")),
+            app.clone()
+                .oneshot(build_request_as(simple_message("Hello"), "user-a")),
+        );
+        let (a, b) = (a.unwrap(), b.unwrap());
+        assert_eq!(a.status(), StatusCode::OK);
+        assert_eq!(b.status(), StatusCode::OK);
+        assert_eq!(response_to_json(a).await["id"], "gen-coalesced");
+        assert_eq!(response_to_json(b).await["id"], "gen-coalesced");
+
+        assert_eq!(
+            harness.analytics.cache_outcomes(2).await,
+            vec!["coalesced", "miss"]
+        );
+    }
+
+    #[tokio::test]
+    async fn errors_are_not_cached() {
+        let harness = TestHarness::new().await;
+        Mock::given(method("POST"))
+            .respond_with(
+                ResponseTemplate::new(400)
+                    .set_body_json(serde_json::json!({"error": {"message": "bad"}})),
+            )
+            .expect(2)
+            .mount(&harness.mock_server)
+            .await;
+
+        let app = router(
+            harness
+                .config_no_analytics()
+                .with_cache(CacheConfig::default()),
+        );
+
+        for _ in 0..2 {
+            let response = app
+                .clone()
+                .oneshot(build_request_as(simple_message("Hello"), "user-a"))
+                .await
+                .unwrap();
+            assert_eq!(response.status().as_u16(), 400);
+        }
+    }
+
+    #[tokio::test]
+    async fn scopes_entries_per_user() {
+        let harness = TestHarness::new().await;
+        Mock::given(method("POST"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(completion_response(
+                    "gen-scoped",
+                    "openai/gpt-4.1-nano",
+                    "hello",
+                )),
+            )
+            .expect(2)
+            .mount(&harness.mock_server)
+            .await;
+
+        let app = router(
+            harness
+                .config_no_analytics()
+                .with_cache(CacheConfig::default()),
+        );
+
+        for (user, cached) in [("user-a", false), ("user-b", false), ("user-a", true)] {
+            let response = app
+                .clone()
+                .oneshot(build_request_as(simple_message("Hello"), user))
+                .await
+                .unwrap();
+            assert_eq!(response.headers().get("X-Cache").is_some(), cached);
+        }
+    }
+
+    #[tokio::test]
+    async fn anonymous_requests_skip_cache() {
+        let harness = TestHarness::new().await;
+        Mock::given(method("POST"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(completion_response(
+                    "gen-anonymous",
+                    "openai/gpt-4.1-nano",
+                    "hello",
+                )),
+            )
+            .expect(2)
+            .mount(&harness.mock_server)
+            .await;
+
+        let app = router(
+            harness
+                .config_no_analytics()
+                .with_cache(CacheConfig::default()),
+        );
+
+        for _ in 0..2 {
+            let response = app
+                .clone()
+                .oneshot(build_request(simple_message("Hello")))
+                .await
+                .unwrap();
+            assert!(response.headers().get("X-Cache").is_none());
+        }
+    }
+
+    #[tokio::test]
+    async fn truncated_stream_is_not_cached() {
+        let harness = TestHarness::new().await;
+        let chunks = stream_chunks("gen-truncated");
+        Mock::given(method("POST"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(chunks[..2].join("\n\n"))
+                    .insert_header("Content-Type", "text/event-stream"),
+            )
+            .expect(2)
+            .mount(&harness.mock_server)
+            .await;
+
+        let app = router(
+            harness
+                .config_no_analytics()
+                .with_cache(CacheConfig::default()),
+        );
+
+        for _ in 0..2 {
+            let response = app
+                .clone()
+                .oneshot(build_request_as(stream_request("Hello"), "user-a"))
+                .await
+                .unwrap();
+            assert!(response.headers().get("X-Cache").is_none());
+            response_to_string(response).await;
+        }
+    }
+}
+
 mod e2e {
     use super::*;
 
@@ -3,13 +3,14 @@ use std::sync::{Arc, Mutex};
 use axum::body::Body;
 use axum::http::Request;
 use llm_proxy::provider::OpenRouterProvider;
-use llm_proxy::{AnalyticsReporter, GenerationEvent, LlmProxyConfig};
+use llm_proxy::{AnalyticsReporter, CacheEvent, GenerationEvent, LlmProxyConfig};
 use wiremock::matchers::{header, method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 #[derive(Default, Clone)]
 pub struct MockAnalytics {
     events: Arc<Mutex<Vec<GenerationEvent>>>,
+    cache_events: Arc<Mutex<Vec<CacheEvent>>>,
 }
 
 impl AnalyticsReporter for MockAnalytics {
@@ -22,6 +23,16 @@ impl AnalyticsReporter for MockAnalytics {
             events.lock().unwrap().push(event);
         })
     }
+
+    fn report_cache(
+        &self,
+        event: CacheEvent,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = ()> + Send + '_>> {
+        let cache_events = self.cache_events.clone();
+        Box::pin(async move {
+            cache_events.lock().unwrap().push(event);
+        })
+    }
 }
 
 impl MockAnalytics {
@@ -29,6 +40,19 @@ impl MockAnalytics {
         self.events.lock().unwrap().clone()
     }
 
+    pub async fn cache_outcomes(&self, expected: usize) -> Vec<&'static str> {
+        let start = std::time::Instant::now();
+        loop {
+            let events = self.cache_events.lock().unwrap().clone();
+            if events.len() >= expected || start.elapsed() > std::time::Duration::from_secs(10) {
+                let mut outcomes: Vec<_> = events.iter().map(|e| e.outcome.as_str()).collect();
+                outcomes.sort();
+                return outcomes;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        }
+    }
+
     pub async fn get_single_event(&self) -> GenerationEvent {
         let timeout = std::time::Duration::from_secs(10);
         let poll_interval = std::time::Duration::from_millis(50);
@@ -134,6 +158,15 @@ pub fn build_request(body: serde_json::Value) -> Request<Body> {
         .unwrap()
 }
 
+/// A request from a signed-in user, which is what the cache is scoped by.
+pub fn build_request_as(body: serde_json::Value, user_id: &str) -> Request<Body> {
+    let mut request = build_request(body);
+    request
+        .extensions_mut()
+        .insert(hypr_analytics::AuthenticatedUserId(user_id.to_string()));
+    request
+}
+
 pub fn simple_message(content: &str) -> serde_json::Value {
     serde_json::json!({
         "messages": [{"role": "user", "content": content}],
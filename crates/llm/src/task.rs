@@ -15,10 +15,12 @@ pub async fn generate_title(
             hypr_llama::LlamaMessage {
                 role: "system".into(),
                 content: render(Template::TitleSystem, &ctx).unwrap(),
+                ..Default::default()
             },
             hypr_llama::LlamaMessage {
                 role: "user".into(),
                 content: render(Template::TitleUser, &ctx).unwrap(),
+                ..Default::default()
             },
         ],
         max_tokens: Some(30),
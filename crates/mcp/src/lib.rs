@@ -4,6 +4,6 @@ mod service;
 
 pub use auth::McpAuth;
 pub use prompt::render_prompt;
-pub use service::create_service;
+pub use service::{create_service, create_service_with_config};
 
 pub use rmcp;
@@ -10,9 +10,16 @@ where
     S: ServerHandler + Send + 'static,
     F: Fn() -> Result<S, std::io::Error> + Send + Sync + 'static,
 {
-    StreamableHttpService::new(
-        factory,
-        LocalSessionManager::default().into(),
-        StreamableHttpServerConfig::default(),
-    )
+    create_service_with_config(factory, StreamableHttpServerConfig::default())
+}
+
+pub fn create_service_with_config<S, F>(
+    factory: F,
+    config: StreamableHttpServerConfig,
+) -> StreamableHttpService<S>
+where
+    S: ServerHandler + Send + 'static,
+    F: Fn() -> Result<S, std::io::Error> + Send + Sync + 'static,
+{
+    StreamableHttpService::new(factory, LocalSessionManager::default().into(), config)
 }
@@ -0,0 +1,129 @@
+use hypr_detect::InstalledApp;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, specta::Type)]
+#[serde(rename_all = "snake_case")]
+pub enum Provider {
+    Zoom,
+    GoogleMeet,
+    Teams,
+    Webex,
+}
+
+impl Provider {
+    pub fn from_url(url: &url::Url) -> Option<Self> {
+        let host = url.host_str()?.to_lowercase();
+        let path = url.path();
+
+        if host == "zoom.us" || host.ends_with(".zoom.us") {
+            return (path.starts_with("/j/") || path.starts_with("/my/")).then_some(Self::Zoom);
+        }
+        if host == "meet.google.com" {
+            return (path.len() > 1).then_some(Self::GoogleMeet);
+        }
+        if host == "teams.microsoft.com" || host == "teams.live.com" {
+            return Some(Self::Teams);
+        }
+        if host.ends_with(".webex.com") {
+            return Some(Self::Webex);
+        }
+
+        None
+    }
+
+    fn native_app_ids(&self) -> &'static [&'static str] {
+        match self {
+            Self::Zoom => &["us.zoom.xos", "us.zoom.Zoom", "zoom"],
+            Self::GoogleMeet => &[],
+            Self::Teams => &[
+                "com.microsoft.teams",
+                "com.microsoft.teams2",
+                "teams",
+                "teams-for-linux",
+            ],
+            Self::Webex => &["Cisco-Systems.Spark", "com.webex.meetingmanager", "webex"],
+        }
+    }
+}
+
+const BROWSER_APP_IDS: &[&str] = &[
+    "com.google.Chrome",
+    "com.apple.Safari",
+    "org.mozilla.firefox",
+    "com.microsoft.edgemac",
+    "com.brave.Browser",
+    "company.thebrowser.Browser",
+    "com.vivaldi.Vivaldi",
+    "google-chrome",
+    "chromium",
+    "firefox",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum AppFit {
+    /// The provider's own client holds the mic.
+    Native,
+    /// A browser holds the mic and the provider can run on the web.
+    Browser,
+    /// Some other app holds the mic.
+    Other,
+}
+
+pub(crate) fn app_fit(provider: Provider, apps: &[InstalledApp]) -> Option<AppFit> {
+    if apps.is_empty() {
+        return None;
+    }
+
+    let is =
+        |ids: &[&str], app: &InstalledApp| ids.iter().any(|id| id.eq_ignore_ascii_case(&app.id));
+
+    if apps.iter().any(|app| is(provider.native_app_ids(), app)) {
+        Some(AppFit::Native)
+    } else if apps.iter().any(|app| is(BROWSER_APP_IDS, app)) {
+        Some(AppFit::Browser)
+    } else {
+        Some(AppFit::Other)
+    }
+}
+
+/// Conference links found in free text such as an event's notes or location.
+pub fn find_links(text: &str) -> Vec<(Provider, url::Url)> {
+    text.split(|c: char| c.is_whitespace() || matches!(c, '<' | '>' | '"' | '\'' | '(' | ')'))
+        .filter(|token| token.starts_with("https://") || token.starts_with("http://"))
+        .filter_map(|token| url::Url::parse(token.trim_end_matches(['.', ',', ';'])).ok())
+        .filter_map(|url| Provider::from_url(&url).map(|provider| (provider, url)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_links() {
+        let text = "Join: <https://us02web.zoom.us/j/8123456789?pwd=abc>. Backup https://meet.google.com/abc-defg-hij, docs https://example.com/x";
+        let providers: Vec<_> = find_links(text).into_iter().map(|(p, _)| p).collect();
+        assert_eq!(providers, vec![Provider::Zoom, Provider::GoogleMeet]);
+    }
+
+    #[test]
+    fn test_app_fit() {
+        let app = |id: &str| InstalledApp {
+            id: id.to_string(),
+            name: id.to_string(),
+        };
+
+        assert_eq!(app_fit(Provider::Zoom, &[]), None);
+        assert_eq!(
+            app_fit(Provider::Zoom, &[app("us.zoom.xos")]),
+            Some(AppFit::Native)
+        );
+        assert_eq!(
+            app_fit(Provider::GoogleMeet, &[app("com.google.Chrome")]),
+            Some(AppFit::Browser)
+        );
+        assert_eq!(
+            app_fit(Provider::Teams, &[app("us.zoom.xos")]),
+            Some(AppFit::Other)
+        );
+    }
+}
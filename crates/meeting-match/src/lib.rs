@@ -0,0 +1,5 @@
+mod conference;
+mod matcher;
+
+pub use conference::{Provider, find_links};
+pub use matcher::*;
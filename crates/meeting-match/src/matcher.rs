@@ -0,0 +1,368 @@
+use chrono::{DateTime, Duration, Utc};
+use hypr_detect::InstalledApp;
+
+use crate::conference::{AppFit, Provider, app_fit, find_links};
+
+/// Confidence at which a session is linked without asking.
+pub const LINK_THRESHOLD: f32 = 0.75;
+/// Below this, a candidate isn't worth offering.
+pub const PROMPT_THRESHOLD: f32 = 0.25;
+/// The runner-up must trail by this much for an automatic link.
+const AMBIGUITY_MARGIN: f32 = 0.15;
+const MAX_CANDIDATES: usize = 3;
+
+/// Events starting this soon are treated as the meeting being joined early.
+pub const EARLY_JOIN: Duration = Duration::minutes(15);
+// Longer events are all-day placeholders rather than meetings.
+const MAX_MEETING_LENGTH: Duration = Duration::hours(12);
+
+#[derive(Debug, Clone)]
+pub struct MatchContext {
+    pub now: DateTime<Utc>,
+    /// Apps holding the microphone, from `hypr_detect::list_mic_using_apps`.
+    pub mic_apps: Vec<InstalledApp>,
+    /// Emails of the people already added to the note being recorded.
+    pub participant_emails: Vec<String>,
+}
+
+impl MatchContext {
+    pub fn now(mic_apps: Vec<InstalledApp>, participant_emails: Vec<String>) -> Self {
+        Self {
+            now: Utc::now(),
+            mic_apps,
+            participant_emails,
+        }
+    }
+}
+
+/// A row of the vault's `events` table (`events.json`), keyed by its row id.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct Event {
+    pub id: String,
+    pub title: String,
+    pub started_at: String,
+    pub ended_at: String,
+    #[serde(default)]
+    pub is_all_day: bool,
+    #[serde(default)]
+    pub location: Option<String>,
+    #[serde(default)]
+    pub meeting_link: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub attendees: Vec<Attendee>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct Attendee {
+    pub email: String,
+    /// Whether this is the calendar owner, as reported by the calendar.
+    #[serde(default)]
+    pub is_self: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct MeetingMatch {
+    pub event_id: String,
+    pub title: String,
+    pub conference: Option<Provider>,
+    pub confidence: f32,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, specta::Type)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum MatchOutcome {
+    /// Confident enough to link the session.
+    Linked {
+        meeting: MeetingMatch,
+    },
+    /// Ask the user to pick, best first.
+    Prompt {
+        candidates: Vec<MeetingMatch>,
+    },
+    NoMatch,
+}
+
+pub fn match_meeting(ctx: &MatchContext, events: &[Event]) -> MatchOutcome {
+    let mut candidates: Vec<MeetingMatch> = events
+        .iter()
+        .filter_map(|event| score(ctx, event))
+        .filter(|m| m.confidence >= PROMPT_THRESHOLD)
+        .collect();
+
+    candidates.sort_by(|a, b| b.confidence.total_cmp(&a.confidence));
+    candidates.truncate(MAX_CANDIDATES);
+
+    let Some(best) = candidates.first() else {
+        return MatchOutcome::NoMatch;
+    };
+
+    let runner_up = candidates.get(1).map_or(0.0, |m| m.confidence);
+    if best.confidence >= LINK_THRESHOLD && best.confidence - runner_up >= AMBIGUITY_MARGIN {
+        return MatchOutcome::Linked {
+            meeting: candidates.swap_remove(0),
+        };
+    }
+
+    MatchOutcome::Prompt { candidates }
+}
+
+fn score(ctx: &MatchContext, event: &Event) -> Option<MeetingMatch> {
+    if event.is_all_day {
+        return None;
+    }
+
+    let start = parse_time(&event.started_at)?;
+    let end = parse_time(&event.ended_at).unwrap_or(start);
+    if end - start > MAX_MEETING_LENGTH {
+        return None;
+    }
+    if ctx.now < start - EARLY_JOIN || ctx.now > end {
+        return None;
+    }
+
+    // Up to 0.5 for timing: full when listening starts right at the start,
+    // fading over an hour but never below 0.15 while the event is running.
+    let offset = (ctx.now - start).num_seconds().unsigned_abs() as f32;
+    let timing = (0.5 * (1.0 - offset / 3600.0)).max(0.15);
+
+    // Up to 0.35 when the event's conference link agrees with the app using the mic.
+    let conference = [&event.meeting_link, &event.location, &event.description]
+        .into_iter()
+        .flatten()
+        .flat_map(|text| find_links(text))
+        .map(|(provider, _)| provider)
+        .next();
+    let conferencing = match conference.and_then(|p| Some((p, app_fit(p, &ctx.mic_apps)?))) {
+        Some((_, AppFit::Native)) | Some((Provider::GoogleMeet, AppFit::Browser)) => 0.35,
+        Some((_, AppFit::Browser)) => 0.2,
+        Some((_, AppFit::Other)) => -0.15,
+        None => 0.0,
+    };
+
+    Some(MeetingMatch {
+        event_id: event.id.clone(),
+        title: event.title.clone(),
+        conference,
+        confidence: (timing + conferencing + attendance(ctx, event)).clamp(0.0, 1.0),
+    })
+}
+
+// Events without an attendee list carry no signal either way.
+fn attendance(ctx: &MatchContext, event: &Event) -> f32 {
+    if event.attendees.is_empty() {
+        return 0.0;
+    }
+
+    // 0.1 when the user is invited; a list without them is someone else's meeting
+    // showing up on a shared calendar.
+    let invited = if event.attendees.iter().any(|a| a.is_self) {
+        0.1
+    } else {
+        -0.1
+    };
+
+    // Up to 0.25 when the people already on the note were invited too.
+    let known = ctx
+        .participant_emails
+        .iter()
+        .filter(|email| {
+            event
+                .attendees
+                .iter()
+                .any(|a| a.email.trim().eq_ignore_ascii_case(email.trim()))
+        })
+        .count();
+    let overlap = match (known, ctx.participant_emails.len()) {
+        (_, 0) => 0.0,
+        (0, _) => -0.1,
+        (known, total) => 0.25 * known as f32 / total as f32,
+    };
+
+    invited + overlap
+}
+
+fn parse_time(s: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(s)
+        .ok()
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(h: u32, m: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 3, 10, h, m, 0).unwrap()
+    }
+
+    fn event(id: &str, start: DateTime<Utc>, end: DateTime<Utc>, link: &str) -> Event {
+        Event {
+            id: id.to_string(),
+            title: format!("Meeting {id}"),
+            started_at: start.to_rfc3339(),
+            ended_at: end.to_rfc3339(),
+            is_all_day: false,
+            location: None,
+            meeting_link: (!link.is_empty()).then(|| link.to_string()),
+            description: None,
+            attendees: vec![],
+        }
+    }
+
+    fn attendees(emails: &[&str], user: &str) -> Vec<Attendee> {
+        emails
+            .iter()
+            .map(|email| Attendee {
+                email: email.to_string(),
+                is_self: *email == user,
+            })
+            .collect()
+    }
+
+    fn ctx(now: DateTime<Utc>, apps: &[&str]) -> MatchContext {
+        MatchContext {
+            now,
+            mic_apps: apps
+                .iter()
+                .map(|id| InstalledApp {
+                    id: id.to_string(),
+                    name: id.to_string(),
+                })
+                .collect(),
+            participant_emails: vec![],
+        }
+    }
+
+    #[test]
+    fn test_links_event_with_matching_conference_app() {
+        let standup = event("a", at(10, 0), at(10, 30), "https://acme.zoom.us/j/123");
+        let other = event(
+            "b",
+            at(9, 30),
+            at(10, 30),
+            "https://meet.google.com/abc-defg-hij",
+        );
+
+        let outcome = match_meeting(&ctx(at(10, 2), &["us.zoom.xos"]), &[other, standup]);
+
+        let MatchOutcome::Linked { meeting } = outcome else {
+            panic!("{outcome:?}");
+        };
+        assert_eq!(meeting.event_id, "a");
+        assert_eq!(meeting.title, "Meeting a");
+        assert_eq!(meeting.conference, Some(Provider::Zoom));
+    }
+
+    #[test]
+    fn test_reads_links_from_description() {
+        let mut review = event("a", at(10, 0), at(11, 0), "");
+        review.description = Some("Join: <https://meet.google.com/abc-defg-hij>".to_string());
+
+        let outcome = match_meeting(&ctx(at(10, 0), &["com.google.Chrome"]), &[review]);
+
+        let MatchOutcome::Linked { meeting } = outcome else {
+            panic!("{outcome:?}");
+        };
+        assert_eq!(meeting.conference, Some(Provider::GoogleMeet));
+    }
+
+    #[test]
+    fn test_prompts_when_ambiguous() {
+        let events = [
+            event("a", at(10, 0), at(11, 0), ""),
+            event("b", at(10, 0), at(10, 30), ""),
+            event("c", at(8, 0), at(9, 0), ""),
+        ];
+
+        let outcome = match_meeting(&ctx(at(10, 1), &[]), &events);
+
+        let MatchOutcome::Prompt { candidates } = outcome else {
+            panic!("{outcome:?}");
+        };
+        let ids: Vec<_> = candidates.iter().map(|m| m.event_id.as_str()).collect();
+        assert_eq!(ids, vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_conflicting_app_lowers_confidence() {
+        let events = [event(
+            "a",
+            at(10, 0),
+            at(11, 0),
+            "https://teams.microsoft.com/l/meetup-join/x",
+        )];
+
+        let matching = match_meeting(&ctx(at(10, 0), &["com.microsoft.teams2"]), &events);
+        let conflicting = match_meeting(&ctx(at(10, 0), &["us.zoom.xos"]), &events);
+
+        assert!(matches!(matching, MatchOutcome::Linked { .. }));
+        assert!(matches!(conflicting, MatchOutcome::Prompt { .. }));
+    }
+
+    #[test]
+    fn test_ignores_all_day_and_distant_events() {
+        let mut all_day = event("all-day", at(9, 0), at(10, 30), "");
+        all_day.is_all_day = true;
+        let events = [
+            all_day,
+            event("long", at(0, 0), at(23, 59), ""),
+            event("later", at(11, 0), at(12, 0), ""),
+            event("over", at(8, 0), at(9, 0), ""),
+        ];
+
+        let outcome = match_meeting(&ctx(at(10, 0), &[]), &events);
+        assert_eq!(outcome, MatchOutcome::NoMatch);
+    }
+
+    #[test]
+    fn test_links_event_sharing_note_participants() {
+        let mut sync = event("a", at(10, 0), at(11, 0), "");
+        sync.attendees = attendees(&["me@acme.com", "jane@acme.com"], "me@acme.com");
+        let other = event("b", at(10, 0), at(11, 0), "");
+
+        let mut ctx = ctx(at(10, 0), &[]);
+        ctx.participant_emails = vec!["Jane@Acme.com".to_string()];
+        let outcome = match_meeting(&ctx, &[other, sync]);
+
+        let MatchOutcome::Linked { meeting } = outcome else {
+            panic!("{outcome:?}");
+        };
+        assert_eq!(meeting.event_id, "a");
+    }
+
+    #[test]
+    fn test_prefers_events_the_user_is_invited_to() {
+        let mut mine = event("a", at(10, 0), at(11, 0), "");
+        mine.attendees = attendees(&["me@acme.com", "jane@acme.com"], "me@acme.com");
+        let mut shared = event("b", at(10, 0), at(11, 0), "");
+        shared.attendees = attendees(&["bob@acme.com", "jane@acme.com"], "me@acme.com");
+        let unlisted = event("c", at(10, 0), at(11, 0), "");
+
+        let outcome = match_meeting(&ctx(at(10, 0), &[]), &[shared, unlisted, mine]);
+
+        let MatchOutcome::Prompt { candidates } = outcome else {
+            panic!("{outcome:?}");
+        };
+        let ids: Vec<_> = candidates.iter().map(|m| m.event_id.as_str()).collect();
+        assert_eq!(ids, vec!["a", "c", "b"]);
+    }
+
+    #[test]
+    fn test_unrelated_participants_lower_confidence() {
+        let mut sync = event("a", at(10, 0), at(11, 0), "");
+        sync.attendees = attendees(&["me@acme.com", "jane@acme.com"], "me@acme.com");
+        let events = [sync];
+
+        let mut ctx = ctx(at(10, 0), &[]);
+        ctx.participant_emails = vec!["jane@acme.com".to_string()];
+        let invited = match_meeting(&ctx, &events);
+        ctx.participant_emails = vec!["bob@other.com".to_string()];
+        let uninvited = match_meeting(&ctx, &events);
+
+        assert!(matches!(invited, MatchOutcome::Linked { .. }));
+        assert!(matches!(uninvited, MatchOutcome::Prompt { .. }));
+    }
+}
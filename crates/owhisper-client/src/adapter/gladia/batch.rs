@@ -163,7 +163,7 @@ impl GladiaAdapter {
         let mime_type = match file_path.extension().and_then(|e| e.to_str()) {
             Some("wav") => "audio/wav",
             Some("mp3") => "audio/mpeg",
-            Some("ogg") => "audio/ogg",
+            Some("ogg" | "opus") => "audio/ogg",
             Some("flac") => "audio/flac",
             Some("m4a") => "audio/mp4",
             Some("webm") => "audio/webm",
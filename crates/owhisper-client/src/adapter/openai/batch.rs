@@ -149,7 +149,7 @@ fn mime_type_from_extension(path: &Path) -> &'static str {
         Some("m4a") => "audio/mp4",
         Some("wav") => "audio/wav",
         Some("webm") => "audio/webm",
-        Some("ogg") => "audio/ogg",
+        Some("ogg" | "opus") => "audio/ogg",
         Some("flac") => "audio/flac",
         _ => "application/octet-stream",
     }
@@ -0,0 +1,69 @@
+use crate::{Participant, Session, common_derives};
+use hypr_askama_utils::filters;
+
+common_derives! {
+    #[derive(askama::Template)]
+    #[template(path = "action-items.system.md.jinja")]
+    pub struct ActionItemsSystem {
+        pub language: Option<String>,
+    }
+}
+
+common_derives! {
+    #[derive(askama::Template)]
+    #[template(path = "action-items.user.md.jinja")]
+    pub struct ActionItemsUser {
+        pub session: Session,
+        pub participants: Vec<Participant>,
+        pub enhanced_note: String,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hypr_askama_utils::{tpl_assert, tpl_snapshot};
+
+    tpl_assert!(
+        test_language_as_specified,
+        ActionItemsSystem {
+            language: Some("ko".to_string()),
+        },
+        |v| v.contains("Korean")
+    );
+
+    tpl_snapshot!(
+        test_action_items_user,
+        ActionItemsUser {
+            session: Session {
+                title: Some("Launch sync".to_string()),
+                started_at: Some("2025-01-06 10:00".to_string()),
+                ended_at: None,
+                event: None,
+            },
+            participants: vec![
+                Participant {
+                    name: "Jane Doe".to_string(),
+                    job_title: None,
+                },
+            ],
+            enhanced_note: "# Launch\n\n- Jane will send the release notes by Friday".to_string(),
+        },
+        @"
+    # Context
+
+
+    Session: Launch sync
+    Date: 2025-01-06 10:00
+    Participants:
+    - Jane Doe
+      
+
+    # Note
+
+    # Launch
+
+    - Jane will send the release notes by Friday
+    "
+    );
+}
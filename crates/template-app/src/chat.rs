@@ -23,6 +23,14 @@ common_derives! {
     }
 }
 
+common_derives! {
+    #[derive(askama::Template)]
+    #[template(path = "chat-vault.system.md.jinja")]
+    pub struct ChatVaultSystem {
+        pub language: Option<String>,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -69,14 +77,20 @@ mod tests {
                         Segment {
                             text: "Hello".to_string(),
                             speaker: "Speaker 1".to_string(),
+                            start_ms: None,
+                            end_ms: None,
                         },
                         Segment {
                             text: "Hi".to_string(),
                             speaker: "Speaker 2".to_string(),
+                            start_ms: None,
+                            end_ms: None,
                         },
                         Segment {
                             text: "By the way, we are going to have a meeting next week".to_string(),
                             speaker: "Speaker 3".to_string(),
+                            start_ms: None,
+                            end_ms: None,
                         },
                     ],
                     started_at: Some(1715702400),
@@ -146,4 +160,33 @@ mod tests {
 
     You should treat meeting transcript and enhanced meeting summary as the information with more weight than the original (manually written) note.
     "#);
+
+    tpl_snapshot_with_assert!(
+        test_chat_vault_system,
+        ChatVaultSystem {
+            language: Some("ko".to_string()),
+        },
+        |v| v.contains("search_vault") && v.contains("Korean"),
+        fixed_date = "2025-01-01",
+        @r#"
+    # General Instructions
+
+    Current date: 2025-01-01
+
+    - You are a helpful AI meeting assistant in Hyprnote. You answer questions using every meeting the user has recorded, not just one.
+    - Always respond in Korean, unless the user explicitly asks for a different language.
+    - Always keep your responses concise, professional, and directly relevant to the user's questions.
+
+    # Searching
+
+    - Before answering, plan your searches and call `search_vault` with them. Pass up to four short keyword queries: the people, companies and topics in the question, plus other words they may have been said as (for example "SSO", "single sign-on" and "SAML").
+    - If the results don't answer the question, search again with different queries before giving up.
+    - Answer only from the search results. If they don't contain the answer, say so instead of guessing.
+
+    # Citations
+
+    - Each search result comes with a "Cite as" link to the meeting and the moment it was said.
+    - Back every claim with the link of the result it came from, placed right after the claim and copied exactly.
+    - When the answer draws on several meetings, group it by meeting, most recent first, and say when each one took place.
+    "#);
 }
@@ -0,0 +1,320 @@
+use crate::{Segment, Transcript, common_derives};
+
+/// Counts tokens for the model the prompt is sent to.
+pub trait TokenCounter {
+    fn count(&self, text: &str) -> usize;
+}
+
+impl<F: Fn(&str) -> usize> TokenCounter for F {
+    fn count(&self, text: &str) -> usize {
+        self(text)
+    }
+}
+
+/// About four characters per token, for when the model's tokenizer isn't at hand.
+pub struct ApproxTokenCounter;
+
+impl TokenCounter for ApproxTokenCounter {
+    fn count(&self, text: &str) -> usize {
+        text.chars().count().div_ceil(4)
+    }
+}
+
+common_derives! {
+    /// Limits for one chunk. `max_tokens` covers the transcript only, so leave
+    /// room for the rest of the prompt and the reply.
+    pub struct ChunkBudget {
+        pub max_tokens: u32,
+        pub max_duration_ms: Option<u64>,
+    }
+}
+
+/// Splits transcripts into chunks that each fit `budget`, breaking between
+/// speaker turns where possible. A turn that doesn't fit in a chunk of its own
+/// is split between segments, and a segment between words.
+pub fn chunk_transcripts(
+    transcripts: &[Transcript],
+    budget: &ChunkBudget,
+    counter: &dyn TokenCounter,
+) -> Vec<Vec<Transcript>> {
+    let mut chunker = Chunker {
+        budget,
+        counter,
+        chunks: vec![],
+        current: vec![],
+        current_source: None,
+        tokens: 0,
+        started_ms: None,
+    };
+
+    for (source, transcript) in transcripts.iter().enumerate() {
+        for turn in transcript.segments.chunk_by(|a, b| a.speaker == b.speaker) {
+            chunker.push_turn(source, transcript, turn);
+        }
+    }
+
+    chunker.flush();
+    chunker.chunks
+}
+
+struct Chunker<'a> {
+    budget: &'a ChunkBudget,
+    counter: &'a dyn TokenCounter,
+    chunks: Vec<Vec<Transcript>>,
+    current: Vec<Transcript>,
+    current_source: Option<usize>,
+    tokens: usize,
+    started_ms: Option<u64>,
+}
+
+impl Chunker<'_> {
+    fn push_turn(&mut self, source: usize, transcript: &Transcript, turn: &[Segment]) {
+        let tokens = turn.iter().map(|s| self.segment_tokens(s)).sum();
+        let ended_ms = turn.last().and_then(|s| s.end_ms.or(s.start_ms));
+
+        if !self.fits(tokens, ended_ms) {
+            self.flush();
+        }
+        if self.fits(tokens, ended_ms) {
+            self.append(source, transcript, turn.to_vec(), tokens);
+            return;
+        }
+
+        for segment in turn {
+            if self.segment_tokens(segment) > self.max_tokens() {
+                for piece in self.split_words(segment) {
+                    self.push_segment(source, transcript, piece);
+                }
+            } else {
+                self.push_segment(source, transcript, segment.clone());
+            }
+        }
+    }
+
+    fn push_segment(&mut self, source: usize, transcript: &Transcript, segment: Segment) {
+        let tokens = self.segment_tokens(&segment);
+        if !self.fits(tokens, segment.end_ms.or(segment.start_ms)) {
+            self.flush();
+        }
+        self.append(source, transcript, vec![segment], tokens);
+    }
+
+    fn append(
+        &mut self,
+        source: usize,
+        transcript: &Transcript,
+        segments: Vec<Segment>,
+        tokens: usize,
+    ) {
+        if self.current_source != Some(source) {
+            self.current.push(Transcript {
+                segments: vec![],
+                started_at: transcript.started_at,
+                ended_at: transcript.ended_at,
+            });
+            self.current_source = Some(source);
+        }
+
+        if self.started_ms.is_none() {
+            self.started_ms = segments.first().and_then(|s| s.start_ms);
+        }
+        self.tokens += tokens;
+        self.current.last_mut().unwrap().segments.extend(segments);
+    }
+
+    fn flush(&mut self) {
+        if !self.current.is_empty() {
+            self.chunks.push(std::mem::take(&mut self.current));
+        }
+        self.current_source = None;
+        self.tokens = 0;
+        self.started_ms = None;
+    }
+
+    fn fits(&self, tokens: usize, ended_ms: Option<u64>) -> bool {
+        if self.current.is_empty() {
+            return tokens <= self.max_tokens();
+        }
+
+        let within_duration = match (self.budget.max_duration_ms, self.started_ms, ended_ms) {
+            (Some(max), Some(started), Some(ended)) => ended.saturating_sub(started) <= max,
+            _ => true,
+        };
+        self.tokens + tokens <= self.max_tokens() && within_duration
+    }
+
+    fn max_tokens(&self) -> usize {
+        self.budget.max_tokens as usize
+    }
+
+    fn segment_tokens(&self, segment: &Segment) -> usize {
+        self.counter
+            .count(&format!("{}: {}\n", segment.speaker, segment.text))
+    }
+
+    fn split_words(&self, segment: &Segment) -> Vec<Segment> {
+        let overhead = self.counter.count(&format!("{}: \n", segment.speaker));
+        let limit = self.max_tokens().saturating_sub(overhead);
+
+        let mut pieces: Vec<String> = vec![];
+        let mut piece = String::new();
+        let mut piece_tokens = 0;
+        for word in segment.text.split_whitespace() {
+            let tokens = self.counter.count(word) + 1;
+            if !piece.is_empty() && piece_tokens + tokens > limit {
+                pieces.push(std::mem::take(&mut piece));
+                piece_tokens = 0;
+            }
+            if !piece.is_empty() {
+                piece.push(' ');
+            }
+            piece.push_str(word);
+            piece_tokens += tokens;
+        }
+        if !piece.is_empty() {
+            pieces.push(piece);
+        }
+
+        pieces
+            .into_iter()
+            .map(|text| Segment {
+                text,
+                ..segment.clone()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(speaker: &str, text: &str, start_ms: u64) -> Segment {
+        Segment {
+            text: text.to_string(),
+            speaker: speaker.to_string(),
+            start_ms: Some(start_ms),
+            end_ms: Some(start_ms + 1_000),
+        }
+    }
+
+    fn transcript(segments: Vec<Segment>) -> Transcript {
+        Transcript {
+            segments,
+            started_at: Some(1719859200000),
+            ended_at: None,
+        }
+    }
+
+    fn words(text: &str) -> usize {
+        text.split_whitespace().count()
+    }
+
+    fn texts(chunks: &[Vec<Transcript>]) -> Vec<Vec<String>> {
+        chunks
+            .iter()
+            .map(|chunk| {
+                chunk
+                    .iter()
+                    .flat_map(|t| &t.segments)
+                    .map(|s| format!("{}: {}", s.speaker, s.text))
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_fits_in_one_chunk() {
+        let transcripts = vec![transcript(vec![
+            segment("Alice", "hello there", 0),
+            segment("Bob", "hi", 1_000),
+        ])];
+        let budget = ChunkBudget {
+            max_tokens: 100,
+            max_duration_ms: None,
+        };
+
+        let chunks = chunk_transcripts(&transcripts, &budget, &words);
+        assert_eq!(texts(&chunks), vec![vec!["Alice: hello there", "Bob: hi"]]);
+        assert_eq!(chunks[0][0].started_at, transcripts[0].started_at);
+    }
+
+    #[test]
+    fn test_splits_between_speaker_turns() {
+        let transcripts = vec![transcript(vec![
+            segment("Alice", "one two", 0),
+            segment("Alice", "three", 1_000),
+            segment("Bob", "four five", 2_000),
+            segment("Alice", "six", 3_000),
+        ])];
+        let budget = ChunkBudget {
+            max_tokens: 7,
+            max_duration_ms: None,
+        };
+
+        let chunks = chunk_transcripts(&transcripts, &budget, &words);
+        assert_eq!(
+            texts(&chunks),
+            vec![
+                vec!["Alice: one two", "Alice: three"],
+                vec!["Bob: four five", "Alice: six"],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_splits_on_duration() {
+        let transcripts = vec![transcript(vec![
+            segment("Alice", "a", 0),
+            segment("Bob", "b", 30_000),
+            segment("Alice", "c", 60_000),
+            segment("Bob", "d", 90_000),
+        ])];
+        let budget = ChunkBudget {
+            max_tokens: 100,
+            max_duration_ms: Some(60_000),
+        };
+
+        let chunks = chunk_transcripts(&transcripts, &budget, &words);
+        assert_eq!(
+            texts(&chunks),
+            vec![vec!["Alice: a", "Bob: b"], vec!["Alice: c", "Bob: d"]]
+        );
+    }
+
+    #[test]
+    fn test_splits_long_segment_between_words() {
+        let transcripts = vec![transcript(vec![segment("Alice", "a b c d e f g h", 0)])];
+        let budget = ChunkBudget {
+            max_tokens: 5,
+            max_duration_ms: None,
+        };
+
+        let chunks = chunk_transcripts(&transcripts, &budget, &words);
+        assert_eq!(
+            texts(&chunks),
+            vec![
+                vec!["Alice: a b"],
+                vec!["Alice: c d"],
+                vec!["Alice: e f"],
+                vec!["Alice: g h"],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_chunk_spans_transcripts() {
+        let transcripts = vec![
+            transcript(vec![segment("Alice", "first", 0)]),
+            transcript(vec![segment("Bob", "second", 0)]),
+        ];
+        let budget = ChunkBudget {
+            max_tokens: 100,
+            max_duration_ms: None,
+        };
+
+        let chunks = chunk_transcripts(&transcripts, &budget, &words);
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].len(), 2);
+    }
+}
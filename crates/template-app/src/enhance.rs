@@ -20,6 +20,30 @@ common_derives! {
     }
 }
 
+common_derives! {
+    #[derive(askama::Template)]
+    #[template(path = "enhance.chunk.user.md.jinja")]
+    pub struct EnhanceChunkUser {
+        pub session: Session,
+        pub participants: Vec<Participant>,
+        pub template: Option<EnhanceTemplate>,
+        pub transcripts: Vec<Transcript>,
+        pub chunk: u32,
+        pub total_chunks: u32,
+    }
+}
+
+common_derives! {
+    #[derive(askama::Template)]
+    #[template(path = "enhance.reduce.user.md.jinja")]
+    pub struct EnhanceReduceUser {
+        pub session: Session,
+        pub participants: Vec<Participant>,
+        pub template: Option<EnhanceTemplate>,
+        pub chunk_notes: Vec<String>,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -108,11 +132,14 @@ mod tests {
                         description: Some("Section 2 description".to_string()),
                     },
                 ],
+                body: None,
             }),
             transcripts: vec![Transcript {
                 segments: vec![Segment {
                     text: "Hello".to_string(),
                     speaker: "John Doe".to_string(),
+                    start_ms: None,
+                    end_ms: None,
                 }],
                 started_at: Some(1719859200),
                 ended_at: Some(1719862800),
@@ -143,4 +170,106 @@ mod tests {
     1. Section 1 - Section 1 description
     2. Section 2 - Section 2 description
     ");
+
+    tpl_snapshot!(
+        test_enhance_chunk_user_formatting,
+        EnhanceChunkUser {
+            session: Session {
+                title: Some("Meeting".to_string()),
+                started_at: None,
+                ended_at: None,
+                event: None,
+            },
+            participants: vec![],
+            template: Some(EnhanceTemplate {
+                title: "Meeting".to_string(),
+                description: None,
+                sections: vec![
+                    TemplateSection {
+                        title: "Decisions".to_string(),
+                        description: None,
+                    },
+                    TemplateSection {
+                        title: "Action Items".to_string(),
+                        description: None,
+                    },
+                ],
+                body: None,
+            }),
+            transcripts: vec![Transcript {
+                segments: vec![Segment {
+                    text: "Ship on Friday".to_string(),
+                    speaker: "John Doe".to_string(),
+                    start_ms: Some(0),
+                    end_ms: Some(1200),
+                }],
+                started_at: None,
+                ended_at: None,
+            }],
+            chunk: 2,
+            total_chunks: 3,
+        }, @"
+    # Context
+
+
+    Session: Meeting
+
+    # Transcript (Part 2 of 3)
+
+
+    John Doe: Ship on Friday
+
+    # Instructions
+
+    This is one part of a longer meeting. Take notes on this part only; they will be combined with notes from the other parts.
+
+    - Capture the topics discussed, decisions made, action items and concrete details such as names, numbers and dates.
+    - Do not add an introduction or conclusion, and do not guess what was said in other parts.
+    - Group the notes under these sections where they fit:
+      - Decisions
+      - Action Items
+    ");
+
+    tpl_snapshot!(
+        test_enhance_reduce_user_formatting,
+        EnhanceReduceUser {
+            session: Session {
+                title: Some("Meeting".to_string()),
+                started_at: None,
+                ended_at: None,
+                event: None,
+            },
+            participants: vec![],
+            template: None,
+            chunk_notes: vec![
+                "# Release\n- Ship on Friday".to_string(),
+                "# Testing\n- Tests due Thursday".to_string(),
+            ],
+        }, @"
+    # Context
+
+
+    Session: Meeting
+
+    # Notes
+
+    The meeting was too long to read at once, so notes were taken on each part in order. Combine them into one summary, merging points that repeat across parts and keeping every concrete detail.
+
+    ## Part 1
+
+    # Release
+    - Ship on Friday
+
+    ## Part 2
+
+    # Testing
+    - Tests due Thursday
+
+    # Output Template
+
+    # Instructions
+
+    1. Analyze the content and decide the sections to use.
+    2. Generate a well-formatted markdown summary.
+    ");
 }
@@ -1,15 +1,23 @@
+mod action_items;
 mod chat;
+mod chunk;
 mod enhance;
+mod retrieve;
 mod title;
 mod tool;
 mod types;
+mod user_template;
 mod validate;
 
+pub use action_items::*;
 pub use chat::*;
+pub use chunk::*;
 pub use enhance::*;
+pub use retrieve::*;
 pub use title::*;
 pub use tool::*;
 pub use types::*;
+pub use user_template::*;
 pub use validate::*;
 
 #[macro_export]
@@ -25,10 +33,16 @@ common_derives! {
     pub enum Template {
         EnhanceSystem(EnhanceSystem),
         EnhanceUser(EnhanceUser),
+        EnhanceChunkUser(EnhanceChunkUser),
+        EnhanceReduceUser(EnhanceReduceUser),
         TitleSystem(TitleSystem),
         TitleUser(TitleUser),
         ChatSystem(ChatSystem),
+        ChatVaultSystem(ChatVaultSystem),
         ToolSearchSessions(ToolSearchSessions),
+        ToolSearchVault(ToolSearchVault),
+        ActionItemsSystem(ActionItemsSystem),
+        ActionItemsUser(ActionItemsUser),
     }
 }
 
@@ -40,16 +54,24 @@ pub enum Error {
     ParseError(String),
     #[error("validation error: {0}")]
     ValidationError(ValidationError),
+    #[error("template error: {}", .0.iter().map(|d| d.to_string()).collect::<Vec<_>>().join("; "))]
+    UserTemplateError(Vec<TemplateDiagnostic>),
 }
 
 pub fn render(t: Template) -> Result<String, Error> {
     let value = match t {
         Template::EnhanceSystem(t) => askama::Template::render(&t),
         Template::EnhanceUser(t) => askama::Template::render(&t),
+        Template::EnhanceChunkUser(t) => askama::Template::render(&t),
+        Template::EnhanceReduceUser(t) => askama::Template::render(&t),
         Template::TitleSystem(t) => askama::Template::render(&t),
         Template::TitleUser(t) => askama::Template::render(&t),
         Template::ChatSystem(t) => askama::Template::render(&t),
+        Template::ChatVaultSystem(t) => askama::Template::render(&t),
         Template::ToolSearchSessions(t) => askama::Template::render(&t),
+        Template::ToolSearchVault(t) => askama::Template::render(&t),
+        Template::ActionItemsSystem(t) => askama::Template::render(&t),
+        Template::ActionItemsUser(t) => askama::Template::render(&t),
     }?;
 
     Ok(value)
@@ -0,0 +1,233 @@
+use std::collections::HashSet;
+
+use crate::{Segment, Transcript, common_derives};
+
+common_derives! {
+    /// A run of consecutive segments that matched a search.
+    pub struct TranscriptSpan {
+        pub start_ms: Option<u64>,
+        pub end_ms: Option<u64>,
+        pub segments: Vec<Segment>,
+        pub score: f32,
+    }
+}
+
+common_derives! {
+    pub struct SpanOptions {
+        pub max_spans: u32,
+        /// Segments kept on each side of a match, so the span reads on its own.
+        pub context_segments: u32,
+    }
+}
+
+const STOPWORDS: &[&str] = &[
+    "a", "about", "across", "after", "all", "an", "and", "any", "are", "as", "at", "be", "by",
+    "did", "do", "does", "for", "from", "had", "has", "have", "how", "in", "is", "it", "its",
+    "last", "of", "on", "or", "our", "over", "said", "say", "says", "that", "the", "their", "them",
+    "they", "this", "to", "was", "we", "were", "what", "when", "where", "which", "who", "why",
+    "with", "you", "your",
+];
+
+/// Picks the parts of `transcript` that best match `queries`, in transcript
+/// order. Segments are scored by the query terms they contain, with rarer terms
+/// weighing more; nearby matches are merged into one span.
+pub fn select_transcript_spans(
+    transcript: &Transcript,
+    queries: &[String],
+    options: &SpanOptions,
+) -> Vec<TranscriptSpan> {
+    let terms = query_terms(queries);
+    let segments = &transcript.segments;
+    if terms.is_empty() || segments.is_empty() || options.max_spans == 0 {
+        return vec![];
+    }
+
+    let matches: Vec<Vec<usize>> = segments
+        .iter()
+        .map(|segment| {
+            let tokens = tokenize(&segment.text);
+            (0..terms.len())
+                .filter(|&t| tokens.iter().any(|token| term_matches(&terms[t], token)))
+                .collect()
+        })
+        .collect();
+
+    let n = segments.len() as f32;
+    let weights: Vec<f32> = (0..terms.len())
+        .map(|t| {
+            let df = matches.iter().filter(|m| m.contains(&t)).count() as f32;
+            (1.0 + n / (1.0 + df)).ln()
+        })
+        .collect();
+
+    let mut candidates: Vec<(usize, f32)> = matches
+        .iter()
+        .enumerate()
+        .filter(|(_, m)| !m.is_empty())
+        .map(|(i, m)| (i, m.iter().map(|&t| weights[t]).sum()))
+        .collect();
+    candidates.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+
+    let context = options.context_segments as usize;
+    let mut windows: Vec<(usize, usize, f32)> = vec![];
+    for (i, score) in candidates {
+        let (start, end) = (
+            i.saturating_sub(context),
+            (i + context).min(segments.len() - 1),
+        );
+
+        if let Some(window) = windows
+            .iter_mut()
+            .find(|(s, e, _)| start <= e + 1 && *s <= end + 1)
+        {
+            window.0 = window.0.min(start);
+            window.1 = window.1.max(end);
+            window.2 += score;
+        } else if windows.len() < options.max_spans as usize {
+            windows.push((start, end, score));
+        }
+    }
+    windows.sort_by_key(|(start, _, _)| *start);
+
+    windows
+        .into_iter()
+        .map(|(start, end, score)| {
+            let segments = segments[start..=end].to_vec();
+            TranscriptSpan {
+                start_ms: segments.iter().find_map(|s| s.start_ms),
+                end_ms: segments.iter().rev().find_map(|s| s.end_ms),
+                segments,
+                score,
+            }
+        })
+        .collect()
+}
+
+fn query_terms(queries: &[String]) -> Vec<String> {
+    let mut seen = HashSet::new();
+    queries
+        .iter()
+        .flat_map(|q| tokenize(q))
+        .filter(|t| t.chars().count() >= 2 && !STOPWORDS.contains(&t.as_str()))
+        .filter(|t| seen.insert(t.clone()))
+        .collect()
+}
+
+fn tokenize(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|t| !t.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+// Longer terms also match as a prefix, so "customer" finds "customers".
+fn term_matches(term: &str, token: &str) -> bool {
+    token == term || (term.chars().count() >= 4 && token.starts_with(term))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(speaker: &str, text: &str, start_ms: u64) -> Segment {
+        Segment {
+            speaker: speaker.to_string(),
+            text: text.to_string(),
+            start_ms: Some(start_ms),
+            end_ms: Some(start_ms + 5_000),
+        }
+    }
+
+    fn transcript() -> Transcript {
+        Transcript {
+            segments: vec![
+                segment("Alice", "Thanks for joining, everyone.", 0),
+                segment("Bob", "Let's start with the roadmap.", 5_000),
+                segment("Carol", "Acme asked about SSO again.", 10_000),
+                segment("Bob", "They need SAML before renewal.", 15_000),
+                segment("Alice", "Pricing stays the same this year.", 20_000),
+                segment("Bob", "Anything else?", 25_000),
+                segment(
+                    "Carol",
+                    "Their security team wants SSO audit logs too.",
+                    30_000,
+                ),
+            ],
+            started_at: None,
+            ended_at: None,
+        }
+    }
+
+    fn options(max_spans: u32, context_segments: u32) -> SpanOptions {
+        SpanOptions {
+            max_spans,
+            context_segments,
+        }
+    }
+
+    fn texts(spans: &[TranscriptSpan]) -> Vec<Vec<&str>> {
+        spans
+            .iter()
+            .map(|s| s.segments.iter().map(|seg| seg.text.as_str()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_spans_in_transcript_order() {
+        let spans = select_transcript_spans(
+            &transcript(),
+            &["What did Acme say about SSO?".to_string()],
+            &options(3, 0),
+        );
+
+        assert_eq!(
+            texts(&spans),
+            vec![
+                vec!["Acme asked about SSO again."],
+                vec!["Their security team wants SSO audit logs too."],
+            ]
+        );
+        assert_eq!(spans[0].start_ms, Some(10_000));
+        assert_eq!(spans[0].end_ms, Some(15_000));
+        assert!(spans[0].score > spans[1].score);
+    }
+
+    #[test]
+    fn test_nearby_matches_merge() {
+        let spans = select_transcript_spans(
+            &transcript(),
+            &["SSO".to_string(), "SAML".to_string()],
+            &options(3, 1),
+        );
+
+        assert_eq!(spans.len(), 1);
+        assert_eq!(spans[0].segments.len(), 6);
+        assert_eq!(spans[0].segments[0].text, "Let's start with the roadmap.");
+        assert_eq!(spans[0].start_ms, Some(5_000));
+        assert_eq!(spans[0].end_ms, Some(35_000));
+    }
+
+    #[test]
+    fn test_max_spans_keeps_best() {
+        let spans =
+            select_transcript_spans(&transcript(), &["acme sso".to_string()], &options(1, 0));
+
+        assert_eq!(texts(&spans), vec![vec!["Acme asked about SSO again."]]);
+    }
+
+    #[test]
+    fn test_no_matches() {
+        assert!(
+            select_transcript_spans(&transcript(), &["kubernetes".to_string()], &options(3, 1))
+                .is_empty()
+        );
+        assert!(
+            select_transcript_spans(
+                &transcript(),
+                &["what did they say".to_string()],
+                &options(3, 1)
+            )
+            .is_empty()
+        );
+    }
+}
@@ -1,7 +1,7 @@
 #[allow(unused_imports)]
 use hypr_askama_utils::filters;
 
-use crate::{SessionContext, common_derives};
+use crate::{Segment, SessionContext, common_derives};
 
 common_derives! {
     pub struct ToolSearchSessionItem {
@@ -22,3 +22,85 @@ common_derives! {
         pub results: Vec<ToolSearchSessionItem>,
     }
 }
+
+common_derives! {
+    /// One search result for `search_vault`. Transcript matches carry their
+    /// segments and where they start; other matches carry an excerpt instead.
+    pub struct VaultPassage {
+        pub session_id: String,
+        pub title: Option<String>,
+        pub date: Option<String>,
+        pub start_ms: Option<u64>,
+        pub segments: Vec<Segment>,
+        pub excerpt: Option<String>,
+    }
+}
+
+common_derives! {
+    #[derive(askama::Template)]
+    #[template(path = "tool.search-vault.md.jinja")]
+    pub struct ToolSearchVault {
+        pub queries: Vec<String>,
+        pub passages: Vec<VaultPassage>,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hypr_askama_utils::tpl_snapshot;
+
+    tpl_snapshot!(
+        test_tool_search_vault,
+        ToolSearchVault {
+            queries: vec!["Acme SSO".to_string(), "single sign-on".to_string()],
+            passages: vec![
+                VaultPassage {
+                    session_id: "s2".to_string(),
+                    title: Some("Acme renewal".to_string()),
+                    date: Some("2025-03-02".to_string()),
+                    start_ms: Some(754_000),
+                    segments: vec![Segment {
+                        speaker: "Carol".to_string(),
+                        text: "They won't sign without SSO.".to_string(),
+                        start_ms: Some(754_000),
+                        end_ms: Some(758_000),
+                    }],
+                    excerpt: None,
+                },
+                VaultPassage {
+                    session_id: "s1".to_string(),
+                    title: None,
+                    date: None,
+                    start_ms: None,
+                    segments: vec![],
+                    excerpt: Some("Follow up with Acme on SAML.".to_string()),
+                },
+            ],
+        },
+        @r#"
+    Search results for "Acme SSO", "single sign-on":
+
+    [S1] Acme renewal (2025-03-02)
+    Cite as: [Acme renewal 12:34](#session/s2/754000)
+    > Carol: They won't sign without SSO.
+
+    [S2] Untitled session
+    Cite as: [Untitled session](#session/s1)
+    > Follow up with Acme on SAML.
+    "#
+    );
+
+    tpl_snapshot!(
+        test_tool_search_vault_empty,
+        ToolSearchVault {
+            queries: vec!["kubernetes".to_string()],
+            passages: vec![],
+        },
+        @r#"
+    Search results for "kubernetes":
+
+    - No matching meetings found.
+    "#
+    );
+}
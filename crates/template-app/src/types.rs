@@ -12,6 +12,10 @@ common_derives! {
     pub struct Segment {
         pub text: String,
         pub speaker: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub start_ms: Option<u64>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub end_ms: Option<u64>,
     }
 }
 
@@ -49,6 +53,9 @@ common_derives! {
         pub title: String,
         pub description: Option<String>,
         pub sections: Vec<TemplateSection>,
+        /// Rendered user template, used in place of `sections` when set.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub body: Option<String>,
     }
 }
 
@@ -72,12 +79,12 @@ mod tests {
         TestTranscripts {
             transcripts: vec![
                 Transcript {
-                    segments: vec![Segment { speaker: "Alice".to_string(), text: "First meeting".to_string() }],
+                    segments: vec![Segment { speaker: "Alice".to_string(), text: "First meeting".to_string(), start_ms: None, end_ms: None }],
                     started_at: None,
                     ended_at: None,
                 },
                 Transcript {
-                    segments: vec![Segment { speaker: "Bob".to_string(), text: "Second meeting".to_string() }],
+                    segments: vec![Segment { speaker: "Bob".to_string(), text: "Second meeting".to_string(), start_ms: None, end_ms: None }],
                     started_at: None,
                     ended_at: None,
                 },
@@ -218,6 +225,7 @@ mod tests {
                     TemplateSection { title: "Summary".to_string(), description: Some("Brief overview".to_string()) },
                     TemplateSection { title: "Action Items".to_string(), description: None },
                 ],
+                body: None,
             }),
         },
         @"
@@ -234,6 +242,29 @@ mod tests {
     "
     );
 
+    tpl_snapshot!(
+        test_macro_template_numbered_body,
+        TestTemplateNumbered {
+            template: Some(EnhanceTemplate {
+                title: "1:1".to_string(),
+                description: None,
+                sections: vec![],
+                body: Some("## Wins\n## Blockers for Alice & Bob".to_string()),
+            }),
+        },
+        @"
+
+
+    # Summary Template
+
+    Name: 1:1
+
+    Structure:
+    ## Wins
+    ## Blockers for Alice & Bob
+    "
+    );
+
     tpl_snapshot!(
         test_macro_template_numbered_none,
         TestTemplateNumbered {
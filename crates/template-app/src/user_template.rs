@@ -0,0 +1,1031 @@
+use std::collections::{HashMap, HashSet};
+
+use askama_parser::node::{Whitespace, Ws};
+use askama_parser::{Ast, Expr, Node, Span, Syntax, Target, WithSpan};
+use serde_json::{Value, json};
+
+use crate::{Error, Event, Participant, Session, common_derives};
+
+/// Variables a user template can read. Loop variables and `loop` come on top.
+pub const USER_TEMPLATE_VARIABLES: &[&str] = &["session", "participants", "language"];
+
+const FILTERS: &[&str] = &[
+    "current_date",
+    "language",
+    "is_english",
+    "is_korean",
+    "lower",
+    "upper",
+    "trim",
+    "capitalize",
+    "join",
+];
+const METHODS: &[&str] = &["is_some", "is_none", "is_empty", "len", "as_ref", "unwrap"];
+const COMPARISONS: &[&str] = &["==", "!=", "<", "<=", ">", ">="];
+
+common_derives! {
+    /// A problem in a user template. Lines and columns start at 1.
+    #[derive(Debug)]
+    pub struct TemplateDiagnostic {
+        pub line: u32,
+        pub column: u32,
+        pub message: String,
+        /// The snippet the problem is in, or `None` for the template itself.
+        pub snippet: Option<String>,
+    }
+}
+
+impl std::fmt::Display for TemplateDiagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(snippet) = &self.snippet {
+            write!(f, "{snippet}:")?;
+        }
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)
+    }
+}
+
+common_derives! {
+    pub struct UserTemplateContext {
+        pub session: Session,
+        pub participants: Vec<Participant>,
+        pub language: Option<String>,
+    }
+}
+
+common_derives! {
+    pub struct UserTemplatePreview {
+        pub output: Option<String>,
+        pub diagnostics: Vec<TemplateDiagnostic>,
+    }
+}
+
+impl UserTemplateContext {
+    /// A made-up meeting for previewing templates before they're used.
+    pub fn sample() -> Self {
+        Self {
+            session: Session {
+                title: Some("Acme renewal call".to_string()),
+                started_at: Some("2025-01-15 14:00".to_string()),
+                ended_at: Some("2025-01-15 14:45".to_string()),
+                event: Some(Event {
+                    name: "Acme renewal call".to_string(),
+                }),
+            },
+            participants: vec![
+                Participant {
+                    name: "Alice Kim".to_string(),
+                    job_title: Some("Account Executive".to_string()),
+                },
+                Participant {
+                    name: "Bob Smith".to_string(),
+                    job_title: None,
+                },
+            ],
+            language: None,
+        }
+    }
+
+    // Field names follow the Rust structs, as they would in a built-in template.
+    fn to_value(&self) -> Value {
+        json!({
+            "session": {
+                "title": self.session.title,
+                "started_at": self.session.started_at,
+                "ended_at": self.session.ended_at,
+                "event": self.session.event.as_ref().map(|e| json!({ "name": e.name })),
+            },
+            "participants": self
+                .participants
+                .iter()
+                .map(|p| json!({ "name": p.name, "job_title": p.job_title }))
+                .collect::<Vec<_>>(),
+            "language": self.language,
+        })
+    }
+}
+
+/// Checks a user template against the subset of askama that user templates may
+/// use: expressions, `if` and `if let Some(..)`, `for` loops and `include`s of
+/// `snippets`. Returns every problem found; an empty list means it will render.
+pub fn check_user_template(
+    src: &str,
+    snippets: &HashMap<String, String>,
+) -> Vec<TemplateDiagnostic> {
+    let mut checker = Checker {
+        snippets,
+        diagnostics: vec![],
+        including: vec![],
+    };
+    let mut scope = vec![];
+    checker.source(Unit { src, snippet: None }, &mut scope);
+
+    let mut seen = HashSet::new();
+    checker
+        .diagnostics
+        .into_iter()
+        .filter(|d| seen.insert(d.to_string()))
+        .collect()
+}
+
+pub fn render_user_template(
+    src: &str,
+    snippets: &HashMap<String, String>,
+    ctx: &UserTemplateContext,
+) -> Result<String, Error> {
+    let diagnostics = check_user_template(src, snippets);
+    if !diagnostics.is_empty() {
+        return Err(Error::UserTemplateError(diagnostics));
+    }
+
+    let mut renderer = Renderer {
+        snippets,
+        root: ctx.to_value(),
+        out: String::new(),
+        next_ws: None,
+        skip_ws: Whitespace::Preserve,
+    };
+    let mut scope = vec![];
+    renderer
+        .source(Unit { src, snippet: None }, &mut scope)
+        .map_err(|d| Error::UserTemplateError(vec![d]))?;
+    renderer.flush_ws(Ws(None, None));
+
+    Ok(renderer.out)
+}
+
+/// Renders `src` against `ctx`, or against [`UserTemplateContext::sample`] when
+/// there's no session to preview with.
+pub fn preview_user_template(
+    src: &str,
+    snippets: &HashMap<String, String>,
+    ctx: Option<UserTemplateContext>,
+) -> UserTemplatePreview {
+    let ctx = ctx.unwrap_or_else(UserTemplateContext::sample);
+    match render_user_template(src, snippets, &ctx) {
+        Ok(output) => UserTemplatePreview {
+            output: Some(output),
+            diagnostics: vec![],
+        },
+        Err(Error::UserTemplateError(diagnostics)) => UserTemplatePreview {
+            output: None,
+            diagnostics,
+        },
+        Err(e) => UserTemplatePreview {
+            output: None,
+            diagnostics: vec![TemplateDiagnostic {
+                line: 1,
+                column: 1,
+                message: e.to_string(),
+                snippet: None,
+            }],
+        },
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Unit<'s> {
+    src: &'s str,
+    snippet: Option<&'s str>,
+}
+
+impl<'s> Unit<'s> {
+    fn diagnostic(&self, offset: usize, message: impl Into<String>) -> TemplateDiagnostic {
+        let before = self.src.get(..offset).unwrap_or(self.src);
+        let line = before.matches('\n').count() + 1;
+        let column = before.rsplit('\n').next().unwrap_or("").chars().count() + 1;
+
+        TemplateDiagnostic {
+            line: line as u32,
+            column: column as u32,
+            message: message.into(),
+            snippet: self.snippet.map(str::to_string),
+        }
+    }
+
+    fn parse(&self) -> Result<Ast<'s>, TemplateDiagnostic> {
+        Ast::from_str(self.src, None, &Syntax::default()).map_err(|e| {
+            let message = e
+                .message
+                .as_deref()
+                .unwrap_or("failed to parse template")
+                .to_string();
+            self.diagnostic(e.offset, message)
+        })
+    }
+}
+
+fn offset(span: Span) -> usize {
+    span.byte_range().map(|r| r.start).unwrap_or(0)
+}
+
+fn node_name(node: &Node<'_>) -> Option<&'static str> {
+    Some(match node {
+        Node::Call(_) => "call",
+        Node::Let(_) | Node::Declare(_) => "let",
+        Node::Match(_) => "match",
+        Node::Extends(_) => "extends",
+        Node::BlockDef(_) => "block",
+        Node::Import(_) => "import",
+        Node::Macro(_) => "macro",
+        Node::Break(_) => "break",
+        Node::Continue(_) => "continue",
+        Node::FilterBlock(_) => "filter",
+        _ => return None,
+    })
+}
+
+fn node_span(node: &Node<'_>) -> Span {
+    match node {
+        Node::Lit(n) => n.span(),
+        Node::Comment(n) => n.span(),
+        Node::Expr(_, n) => n.span(),
+        Node::Call(n) => n.span(),
+        Node::Let(n) => n.span(),
+        Node::Declare(n) => n.span(),
+        Node::If(n) => n.span(),
+        Node::Match(n) => n.span(),
+        Node::Loop(n) => n.span(),
+        Node::Extends(n) => n.span(),
+        Node::BlockDef(n) => n.span(),
+        Node::Include(n) => n.span(),
+        Node::Import(n) => n.span(),
+        Node::Macro(n) => n.span(),
+        Node::Raw(n) => n.span(),
+        Node::Break(n) | Node::Continue(n) => n.span(),
+        Node::FilterBlock(n) => n.span(),
+    }
+}
+
+/// The name bound by `Some(name)`, the only pattern `if let` accepts.
+fn some_binding<'a>(target: &Target<'a>) -> Option<&'a str> {
+    match target {
+        Target::Tuple(t) => {
+            let (path, targets) = &**t;
+            match (path.as_slice(), targets.as_slice()) {
+                ([some], [Target::Name(name)]) if *some.name == "Some" => Some(**name),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+struct Checker<'s> {
+    snippets: &'s HashMap<String, String>,
+    diagnostics: Vec<TemplateDiagnostic>,
+    including: Vec<&'s str>,
+}
+
+impl<'s> Checker<'s> {
+    fn source(&mut self, unit: Unit<'s>, scope: &mut Vec<&'s str>) {
+        match unit.parse() {
+            Ok(ast) => self.nodes(unit, ast.nodes(), scope),
+            Err(d) => self.diagnostics.push(d),
+        }
+    }
+
+    fn nodes(&mut self, unit: Unit<'s>, nodes: &[Box<Node<'s>>], scope: &mut Vec<&'s str>) {
+        for node in nodes {
+            self.node(unit, node, scope);
+        }
+    }
+
+    fn node(&mut self, unit: Unit<'s>, node: &Node<'s>, scope: &mut Vec<&'s str>) {
+        match node {
+            Node::Lit(_) | Node::Comment(_) | Node::Raw(_) => {}
+            Node::Expr(_, expr) => self.expr(unit, expr, scope),
+            Node::If(if_) => {
+                for branch in &if_.branches {
+                    let bound = scope.len();
+                    if let Some(cond) = &branch.cond {
+                        self.expr(unit, &cond.expr, scope);
+                        if let Some(target) = &cond.target {
+                            match some_binding(target) {
+                                Some(name) => scope.push(name),
+                                None => self.push_tag(
+                                    unit,
+                                    cond.span(),
+                                    "only `if let Some(name) = ...` is supported",
+                                ),
+                            }
+                        }
+                    }
+                    self.nodes(unit, &branch.nodes, scope);
+                    scope.truncate(bound);
+                }
+            }
+            Node::Loop(loop_) => {
+                self.expr(unit, &loop_.iter, scope);
+                let bound = scope.len();
+                match &loop_.var {
+                    Target::Name(name) => scope.push(name),
+                    _ => self.push_tag(
+                        unit,
+                        loop_.span(),
+                        "loop over one variable, as in `for p in participants`",
+                    ),
+                }
+                scope.push("loop");
+                if let Some(cond) = &loop_.cond {
+                    self.expr(unit, cond, scope);
+                }
+                self.nodes(unit, &loop_.body, scope);
+                scope.truncate(bound);
+                self.nodes(unit, &loop_.else_nodes, scope);
+            }
+            Node::Include(include) => {
+                let Some((name, src)) = self.snippets.get_key_value(include.path) else {
+                    self.push_tag(
+                        unit,
+                        include.span(),
+                        format!("no snippet named `{}`", include.path),
+                    );
+                    return;
+                };
+                if self.including.contains(&name.as_str()) {
+                    self.push_tag(unit, include.span(), format!("`{name}` includes itself"));
+                    return;
+                }
+
+                self.including.push(name);
+                self.source(
+                    Unit {
+                        src,
+                        snippet: Some(name),
+                    },
+                    scope,
+                );
+                self.including.pop();
+            }
+            node => {
+                let name = node_name(node).unwrap_or("this tag");
+                self.push_tag(
+                    unit,
+                    node_span(node),
+                    format!("`{name}` isn't supported in templates"),
+                );
+            }
+        }
+    }
+
+    fn expr(&mut self, unit: Unit<'s>, expr: &WithSpan<Box<Expr<'s>>>, scope: &[&'s str]) {
+        match &***expr {
+            Expr::BoolLit(_) | Expr::NumLit(..) | Expr::StrLit(_) => {}
+            Expr::IsDefined(_) | Expr::IsNotDefined(_) => {}
+            Expr::Var(name) => {
+                if !scope.contains(name) && !USER_TEMPLATE_VARIABLES.contains(name) {
+                    self.push(unit, expr.span(), format!("unknown variable `{name}`"));
+                }
+            }
+            Expr::AssociatedItem(obj, _) => self.expr(unit, obj, scope),
+            Expr::Index(obj, index) => {
+                self.expr(unit, obj, scope);
+                self.expr(unit, index, scope);
+            }
+            Expr::Group(inner) | Expr::Unary(_, inner) => self.expr(unit, inner, scope),
+            Expr::BinOp(op) => {
+                if !COMPARISONS.contains(&op.op) && !matches!(op.op, "&&" | "||") {
+                    self.push(
+                        unit,
+                        expr.span(),
+                        format!("`{}` isn't supported in templates", op.op),
+                    );
+                }
+                self.expr(unit, &op.lhs, scope);
+                self.expr(unit, &op.rhs, scope);
+            }
+            Expr::Filter(filter) => {
+                let name = match &filter.name {
+                    askama_parser::PathOrIdentifier::Identifier(name) => Some(**name),
+                    _ => None,
+                };
+                if !name.is_some_and(|name| FILTERS.contains(&name)) {
+                    self.push(
+                        unit,
+                        expr.span(),
+                        format!("unknown filter; available: {}", FILTERS.join(", ")),
+                    );
+                }
+                for arg in &filter.arguments {
+                    self.expr(unit, arg, scope);
+                }
+            }
+            Expr::Call(call) => match &**call.path {
+                Expr::AssociatedItem(obj, method)
+                    if METHODS.contains(&*method.name) && call.args.is_empty() =>
+                {
+                    self.expr(unit, obj, scope)
+                }
+                _ => self.push(
+                    unit,
+                    expr.span(),
+                    format!("only these methods can be called: {}", METHODS.join(", ")),
+                ),
+            },
+            _ => self.push(
+                unit,
+                expr.span(),
+                "this expression isn't supported in templates",
+            ),
+        }
+    }
+
+    fn push(&mut self, unit: Unit<'s>, span: Span, message: impl Into<String>) {
+        self.diagnostics
+            .push(unit.diagnostic(offset(span), message));
+    }
+
+    // Tag spans start after `{%`; point at the tag itself instead.
+    fn push_tag(&mut self, unit: Unit<'s>, span: Span, message: impl Into<String>) {
+        let offset = offset(span);
+        let start = unit.src[..offset].rfind("{%").unwrap_or(offset);
+        self.diagnostics.push(unit.diagnostic(start, message));
+    }
+}
+
+type Scope<'s> = Vec<(&'s str, Value)>;
+
+struct Renderer<'s> {
+    snippets: &'s HashMap<String, String>,
+    root: Value,
+    out: String,
+    next_ws: Option<&'s str>,
+    skip_ws: Whitespace,
+}
+
+// Whitespace follows askama's generator: the whitespace a literal ends with is
+// held back until the next tag says whether to keep it, and the tag's `-`/`~`
+// on the other side decides what happens to the whitespace after it.
+impl<'s> Renderer<'s> {
+    fn source(&mut self, unit: Unit<'s>, scope: &mut Scope<'s>) -> Result<(), TemplateDiagnostic> {
+        let ast = unit.parse()?;
+        self.nodes(unit, ast.nodes(), scope)
+    }
+
+    fn nodes(
+        &mut self,
+        unit: Unit<'s>,
+        nodes: &[Box<Node<'s>>],
+        scope: &mut Scope<'s>,
+    ) -> Result<(), TemplateDiagnostic> {
+        for node in nodes {
+            self.node(unit, node, scope)?;
+        }
+        Ok(())
+    }
+
+    fn node(
+        &mut self,
+        unit: Unit<'s>,
+        node: &Node<'s>,
+        scope: &mut Scope<'s>,
+    ) -> Result<(), TemplateDiagnostic> {
+        let fail = |span: Span, message: String| unit.diagnostic(offset(span), message);
+
+        match node {
+            Node::Lit(lit) => self.write_lit(*lit.lws, *lit.val, *lit.rws),
+            Node::Comment(comment) => self.handle_ws(comment.ws),
+            Node::Raw(raw) => {
+                self.handle_ws(raw.ws1);
+                self.write_lit(*raw.lit.lws, *raw.lit.val, *raw.lit.rws);
+                self.handle_ws(raw.ws2);
+            }
+            Node::Expr(ws, expr) => {
+                let value = self.eval(expr, scope).map_err(|(s, m)| fail(s, m))?;
+                let text = display(&value).map_err(|m| fail(expr.span(), m))?;
+                self.handle_ws(*ws);
+                self.out.push_str(&text);
+            }
+            Node::If(if_) => {
+                let mut taken = None;
+                for (i, branch) in if_.branches.iter().enumerate() {
+                    let bound = scope.len();
+                    let matched = match &branch.cond {
+                        None => true,
+                        Some(cond) => {
+                            let value =
+                                self.eval(&cond.expr, scope).map_err(|(s, m)| fail(s, m))?;
+                            match cond.target.as_ref().and_then(some_binding) {
+                                Some(name) if !value.is_null() => {
+                                    scope.push((name, value));
+                                    true
+                                }
+                                Some(_) => false,
+                                None => truthy(&value).map_err(|m| fail(cond.expr.span(), m))?,
+                            }
+                        }
+                    };
+                    if matched {
+                        taken = Some((i, bound));
+                        break;
+                    }
+                }
+
+                self.handle_ws(if_.branches[0].ws);
+                match taken {
+                    Some((i, bound)) => {
+                        let branch = &if_.branches[i];
+                        if i > 0 {
+                            self.prepare_ws(branch.ws);
+                        }
+                        self.nodes(unit, &branch.nodes, scope)?;
+                        scope.truncate(bound);
+
+                        let closing = if_.branches.get(i + 1).map(|b| b.ws).unwrap_or(if_.ws);
+                        self.flush_ws(closing);
+                        self.prepare_ws(if_.ws);
+                    }
+                    None => self.prepare_ws(if_.ws),
+                }
+            }
+            Node::Loop(loop_) => {
+                let Target::Name(var) = &loop_.var else {
+                    unreachable!("checked before rendering");
+                };
+                let iter = self.eval(&loop_.iter, scope).map_err(|(s, m)| fail(s, m))?;
+                let Value::Array(items) = iter else {
+                    return Err(fail(
+                        loop_.iter.span(),
+                        "can only loop over a list".to_string(),
+                    ));
+                };
+
+                let mut kept = vec![];
+                for item in items {
+                    if let Some(cond) = &loop_.cond {
+                        scope.push((var, item.clone()));
+                        let keep = self.eval(cond, scope).map_err(|(s, m)| fail(s, m))?;
+                        scope.pop();
+                        if !truthy(&keep).map_err(|m| fail(cond.span(), m))? {
+                            continue;
+                        }
+                    }
+                    kept.push(item);
+                }
+
+                self.handle_ws(loop_.ws1);
+                let total = kept.len();
+                for (index, item) in kept.into_iter().enumerate() {
+                    self.prepare_ws(loop_.ws1);
+                    scope.push((var, item));
+                    scope.push((
+                        "loop",
+                        json!({
+                            "index": index + 1,
+                            "index0": index,
+                            "first": index == 0,
+                            "last": index + 1 == total,
+                        }),
+                    ));
+                    self.nodes(unit, &loop_.body, scope)?;
+                    scope.truncate(scope.len() - 2);
+                    self.flush_ws(loop_.ws2);
+                }
+                if total == 0 && !loop_.else_nodes.is_empty() {
+                    self.prepare_ws(loop_.ws2);
+                    self.nodes(unit, &loop_.else_nodes, scope)?;
+                }
+                self.handle_ws(loop_.ws3);
+            }
+            Node::Include(include) => {
+                let (name, src) = self
+                    .snippets
+                    .get_key_value(include.path)
+                    .expect("checked before rendering");
+                self.flush_ws(include.ws);
+                self.source(
+                    Unit {
+                        src,
+                        snippet: Some(name),
+                    },
+                    scope,
+                )?;
+                self.prepare_ws(include.ws);
+            }
+            _ => unreachable!("checked before rendering"),
+        }
+
+        Ok(())
+    }
+
+    fn write_lit(&mut self, lws: &'s str, val: &'s str, rws: &'s str) {
+        if !lws.is_empty() {
+            match self.skip_ws {
+                Whitespace::Suppress => {}
+                _ if val.is_empty() => self.next_ws = Some(lws),
+                Whitespace::Preserve => self.out.push_str(lws),
+                Whitespace::Minimize => self.out.push_str(minimized(lws)),
+            }
+        }
+        if !val.is_empty() {
+            self.skip_ws = Whitespace::Preserve;
+            self.out.push_str(val);
+        }
+        if !rws.is_empty() {
+            self.next_ws = Some(rws);
+        }
+    }
+
+    fn handle_ws(&mut self, ws: Ws) {
+        self.flush_ws(ws);
+        self.prepare_ws(ws);
+    }
+
+    fn flush_ws(&mut self, ws: Ws) {
+        let Some(pending) = self.next_ws.take() else {
+            return;
+        };
+        match ws.0.unwrap_or_default() {
+            Whitespace::Preserve => self.out.push_str(pending),
+            Whitespace::Minimize => self.out.push_str(minimized(pending)),
+            Whitespace::Suppress => {}
+        }
+    }
+
+    fn prepare_ws(&mut self, ws: Ws) {
+        self.skip_ws = ws.1.unwrap_or_default();
+    }
+
+    fn lookup(&self, name: &str, scope: &Scope<'s>) -> Option<Value> {
+        scope
+            .iter()
+            .rev()
+            .find(|(n, _)| *n == name)
+            .map(|(_, v)| v.clone())
+            .or_else(|| self.root.get(name).cloned())
+    }
+
+    fn eval(
+        &self,
+        expr: &WithSpan<Box<Expr<'s>>>,
+        scope: &Scope<'s>,
+    ) -> Result<Value, (Span, String)> {
+        let fail = |message: String| (expr.span(), message);
+
+        Ok(match &***expr {
+            Expr::BoolLit(b) => Value::Bool(*b),
+            Expr::NumLit(raw, _) => raw
+                .replace('_', "")
+                .parse::<i64>()
+                .map(Value::from)
+                .or_else(|_| raw.replace('_', "").parse::<f64>().map(Value::from))
+                .map_err(|_| fail(format!("`{raw}` isn't a number")))?,
+            Expr::StrLit(s) => Value::String(unescape(s.content)),
+            Expr::IsDefined(name) => Value::Bool(self.lookup(name, scope).is_some()),
+            Expr::IsNotDefined(name) => Value::Bool(self.lookup(name, scope).is_none()),
+            Expr::Var(name) => self
+                .lookup(name, scope)
+                .ok_or_else(|| fail(format!("unknown variable `{name}`")))?,
+            Expr::Group(inner) => self.eval(inner, scope)?,
+            Expr::AssociatedItem(obj, item) => {
+                let value = self.eval(obj, scope)?;
+                match value {
+                    Value::Object(mut map) => map
+                        .remove(*item.name)
+                        .ok_or_else(|| fail(format!("no field named `{}`", *item.name)))?,
+                    Value::Null => {
+                        return Err(fail(format!(
+                            "`{}` is read from an empty value; check it with `if let Some(..)` first",
+                            *item.name
+                        )));
+                    }
+                    _ => return Err(fail(format!("`{}` isn't a field here", *item.name))),
+                }
+            }
+            Expr::Index(obj, index) => {
+                let value = self.eval(obj, scope)?;
+                let index = self.eval(index, scope)?;
+                match (&value, &index) {
+                    (Value::Array(items), Value::Number(n)) => n
+                        .as_u64()
+                        .and_then(|n| items.get(n as usize).cloned())
+                        .ok_or_else(|| fail(format!("no item at index {n}")))?,
+                    (Value::Object(map), Value::String(key)) => map
+                        .get(key)
+                        .cloned()
+                        .ok_or_else(|| fail(format!("no field named `{key}`")))?,
+                    _ => return Err(fail("can't index into this value".to_string())),
+                }
+            }
+            Expr::Unary(op, inner) => {
+                let value = self.eval(inner, scope)?;
+                match (*op, &value) {
+                    ("!", Value::Bool(b)) => Value::Bool(!b),
+                    ("-", Value::Number(n)) => n
+                        .as_i64()
+                        .map(|n| Value::from(-n))
+                        .or_else(|| n.as_f64().map(|n| Value::from(-n)))
+                        .unwrap_or(Value::Null),
+                    _ => return Err(fail(format!("`{op}` doesn't apply to this value"))),
+                }
+            }
+            Expr::BinOp(op) => match op.op {
+                "&&" | "||" => {
+                    let lhs = self.eval(&op.lhs, scope)?;
+                    let lhs = truthy(&lhs).map_err(|m| (op.lhs.span(), m))?;
+                    if (op.op == "&&") != lhs {
+                        Value::Bool(lhs)
+                    } else {
+                        let rhs = self.eval(&op.rhs, scope)?;
+                        Value::Bool(truthy(&rhs).map_err(|m| (op.rhs.span(), m))?)
+                    }
+                }
+                cmp => {
+                    let lhs = self.eval(&op.lhs, scope)?;
+                    let rhs = self.eval(&op.rhs, scope)?;
+                    Value::Bool(compare(cmp, &lhs, &rhs).map_err(fail)?)
+                }
+            },
+            Expr::Filter(filter) => {
+                let mut args = vec![];
+                for arg in &filter.arguments {
+                    args.push(self.eval(arg, scope)?);
+                }
+                let name = match &filter.name {
+                    askama_parser::PathOrIdentifier::Identifier(name) => **name,
+                    _ => unreachable!("checked before rendering"),
+                };
+                apply_filter(name, args).map_err(fail)?
+            }
+            Expr::Call(call) => {
+                let Expr::AssociatedItem(obj, method) = &**call.path else {
+                    unreachable!("checked before rendering");
+                };
+                let value = self.eval(obj, scope)?;
+                call_method(&method.name, value).map_err(fail)?
+            }
+            _ => unreachable!("checked before rendering"),
+        })
+    }
+}
+
+fn minimized(ws: &str) -> &'static str {
+    if ws.contains('\n') { "\n" } else { " " }
+}
+
+fn display(value: &Value) -> Result<String, String> {
+    match value {
+        Value::Null => Ok(String::new()),
+        Value::Bool(b) => Ok(b.to_string()),
+        Value::Number(n) => Ok(n.to_string()),
+        Value::String(s) => Ok(s.clone()),
+        Value::Array(_) | Value::Object(_) => {
+            Err("can't print a list or an object; print one of its fields instead".to_string())
+        }
+    }
+}
+
+fn truthy(value: &Value) -> Result<bool, String> {
+    match value {
+        Value::Bool(b) => Ok(*b),
+        _ => {
+            Err("a condition must be true or false; try `.is_some()` or `.is_empty()`".to_string())
+        }
+    }
+}
+
+fn compare(op: &str, lhs: &Value, rhs: &Value) -> Result<bool, String> {
+    use std::cmp::Ordering;
+
+    let ordering = match (lhs, rhs) {
+        (Value::Number(a), Value::Number(b)) => a.as_f64().partial_cmp(&b.as_f64()),
+        (Value::String(a), Value::String(b)) => Some(a.cmp(b)),
+        (Value::Bool(a), Value::Bool(b)) => Some(a.cmp(b)),
+        _ if matches!(op, "==" | "!=") => {
+            return Ok((lhs == rhs) == (op == "=="));
+        }
+        _ => None,
+    };
+    let ordering = ordering.ok_or_else(|| format!("can't compare these values with `{op}`"))?;
+
+    Ok(match op {
+        "==" => ordering == Ordering::Equal,
+        "!=" => ordering != Ordering::Equal,
+        "<" => ordering == Ordering::Less,
+        "<=" => ordering != Ordering::Greater,
+        ">" => ordering == Ordering::Greater,
+        ">=" => ordering != Ordering::Less,
+        _ => unreachable!("checked before rendering"),
+    })
+}
+
+fn call_method(method: &str, value: Value) -> Result<Value, String> {
+    Ok(match (method, value) {
+        ("is_some", value) => Value::Bool(!value.is_null()),
+        ("is_none", value) => Value::Bool(value.is_null()),
+        ("as_ref", value) => value,
+        ("unwrap", Value::Null) => return Err("`unwrap()` on an empty value".to_string()),
+        ("unwrap", value) => value,
+        ("is_empty", Value::String(s)) => Value::Bool(s.is_empty()),
+        ("is_empty", Value::Array(items)) => Value::Bool(items.is_empty()),
+        ("len", Value::String(s)) => Value::from(s.chars().count()),
+        ("len", Value::Array(items)) => Value::from(items.len()),
+        (method, _) => return Err(format!("`{method}()` doesn't apply to this value")),
+    })
+}
+
+fn apply_filter(name: &str, args: Vec<Value>) -> Result<Value, String> {
+    let mut args = args.into_iter();
+    let input = args.next().unwrap_or(Value::Null);
+    let as_language = |value: &Value| value.as_str().map(str::to_string);
+    let to_err = |e: askama::Error| e.to_string();
+
+    Ok(match name {
+        "current_date" => Value::String(
+            hypr_askama_utils::current_date::default()
+                .execute(&input, &())
+                .map_err(to_err)?,
+        ),
+        "language" => Value::String(
+            hypr_askama_utils::language::default()
+                .execute(&as_language(&input), &())
+                .map_err(to_err)?,
+        ),
+        "is_english" => Value::Bool(
+            hypr_askama_utils::is_english::default()
+                .execute(&as_language(&input), &())
+                .map_err(to_err)?,
+        ),
+        "is_korean" => Value::Bool(
+            hypr_askama_utils::is_korean::default()
+                .execute(&as_language(&input), &())
+                .map_err(to_err)?,
+        ),
+        "lower" => Value::String(display(&input)?.to_lowercase()),
+        "upper" => Value::String(display(&input)?.to_uppercase()),
+        "trim" => Value::String(display(&input)?.trim().to_string()),
+        "capitalize" => {
+            let text = display(&input)?;
+            let mut chars = text.chars();
+            Value::String(match chars.next() {
+                Some(first) => first
+                    .to_uppercase()
+                    .chain(chars.flat_map(char::to_lowercase))
+                    .collect(),
+                None => String::new(),
+            })
+        }
+        "join" => {
+            let Value::Array(items) = input else {
+                return Err("`join` needs a list".to_string());
+            };
+            let separator = args
+                .next()
+                .as_ref()
+                .map(display)
+                .transpose()?
+                .unwrap_or_default();
+            Value::String(
+                items
+                    .iter()
+                    .map(display)
+                    .collect::<Result<Vec<_>, _>>()?
+                    .join(&separator),
+            )
+        }
+        _ => unreachable!("checked before rendering"),
+    })
+}
+
+fn unescape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some('t') => out.push('\t'),
+            Some('r') => out.push('\r'),
+            Some('0') => out.push('\0'),
+            Some(other) => out.push(other),
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snippets(entries: &[(&str, &str)]) -> HashMap<String, String> {
+        entries
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    fn render(src: &str) -> String {
+        render_user_template(src, &HashMap::new(), &UserTemplateContext::sample()).unwrap()
+    }
+
+    #[test]
+    fn test_conditionals_and_loops() {
+        let src = r#"{% if let Some(title) = session.title -%}
+# {{ title }}
+{% endif -%}
+{% for p in participants -%}
+{{ loop.index }}. {{ p.name }}{% if let Some(job) = p.job_title %} ({{ job }}){% endif %}
+{% endfor -%}
+{% if participants.len() > 1 %}Group call{% else %}1:1{% endif %}"#;
+
+        insta::assert_snapshot!(render(src), @r"
+        # Acme renewal call
+        1. Alice Kim (Account Executive)
+        2. Bob Smith
+        Group call
+        ");
+    }
+
+    #[test]
+    fn test_filters() {
+        assert_eq!(
+            render("{{ \"hello\"|upper }} {{ session.title|lower }}"),
+            "HELLO acme renewal call"
+        );
+        assert_eq!(render("{{ language|language }}"), "English");
+        assert_eq!(render("{% if language|is_english %}en{% endif %}"), "en");
+    }
+
+    #[test]
+    fn test_include_snippet() {
+        let snippets = snippets(&[(
+            "attendees",
+            "{% for p in participants %}- {{ p.name }}\n{% endfor %}",
+        )]);
+        let output = render_user_template(
+            "## Attendees\n{% include \"attendees\" %}",
+            &snippets,
+            &UserTemplateContext::sample(),
+        )
+        .unwrap();
+
+        assert_eq!(output, "## Attendees\n- Alice Kim\n- Bob Smith\n");
+    }
+
+    #[test]
+    fn test_diagnostics_have_positions() {
+        let diagnostics = check_user_template(
+            "# Notes\n{{ transcript }}\n{% macro m() %}{% endmacro %}\n{{ p|shout }}",
+            &HashMap::new(),
+        );
+        let rendered: Vec<_> = diagnostics.iter().map(|d| d.to_string()).collect();
+
+        assert_eq!(
+            rendered,
+            vec![
+                "2:4: unknown variable `transcript`",
+                "3:1: `macro` isn't supported in templates",
+                "4:5: unknown filter; available: current_date, language, is_english, is_korean, lower, upper, trim, capitalize, join",
+                "4:4: unknown variable `p`",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_syntax_error_position() {
+        let diagnostics = check_user_template(
+            "line one\n{% if session.title.is_some() %}\nno end",
+            &HashMap::new(),
+        );
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].line, 2);
+    }
+
+    #[test]
+    fn test_include_problems() {
+        let snippets = snippets(&[
+            ("a", "{% include \"b\" %}"),
+            ("b", "{% include \"a\" %}{{ nope }}"),
+        ]);
+        let diagnostics =
+            check_user_template("{% include \"a\" %}\n{% include \"c\" %}", &snippets);
+        let rendered: Vec<_> = diagnostics.iter().map(|d| d.to_string()).collect();
+
+        assert_eq!(
+            rendered,
+            vec![
+                "b:1:1: `a` includes itself",
+                "b:1:21: unknown variable `nope`",
+                "2:1: no snippet named `c`",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_runtime_error_in_preview() {
+        let preview = preview_user_template(
+            "\n{% if session.title %}x{% endif %}",
+            &HashMap::new(),
+            None,
+        );
+
+        assert!(preview.output.is_none());
+        assert_eq!(preview.diagnostics.len(), 1);
+        assert_eq!(preview.diagnostics[0].line, 2);
+        assert!(preview.diagnostics[0].message.contains("true or false"));
+    }
+}
@@ -1,10 +1,18 @@
 use markdown::mdast;
 
 pub fn mdast_to_markdown(node: &mdast::Node) -> Result<String, String> {
-    let task_items = collect_task_items(node);
+    Ok(unescape_markdown(&serialize(node)?))
+}
+
+fn serialize(node: &mdast::Node) -> Result<String, String> {
+    let mut node = node.clone();
+    lower_gfm(&mut node)?;
+    let mut inline_html = Vec::new();
+    hide_inline_html(&mut node, &mut inline_html);
+    let task_items = collect_task_items(&node);
 
     let md = mdast_util_to_markdown::to_markdown_with_options(
-        node,
+        &node,
         &mdast_util_to_markdown::Options {
             bullet: '-',
             ..Default::default()
@@ -13,7 +21,219 @@ pub fn mdast_to_markdown(node: &mdast::Node) -> Result<String, String> {
     .map_err(|e| e.to_string())?;
 
     let md = inject_task_checkboxes(&md, &task_items);
-    Ok(unescape_markdown(&md))
+    Ok(restore_inline_html(&md, &inline_html))
+}
+
+// `mdast_util_to_markdown` has no handlers for the GFM extensions, so they are
+// spelled out with raw HTML nodes around their content.
+fn lower_gfm(node: &mut mdast::Node) -> Result<(), String> {
+    let Some(children) = node.children_mut() else {
+        return Ok(());
+    };
+
+    let mut lowered = Vec::with_capacity(children.len());
+    for mut child in std::mem::take(children) {
+        lower_gfm(&mut child)?;
+
+        match child {
+            mdast::Node::Delete(delete) => {
+                lowered.push(html("~~"));
+                lowered.extend(delete.children);
+                lowered.push(html("~~"));
+            }
+            mdast::Node::FootnoteReference(reference) => lowered.push(html(&format!(
+                "[^{}]",
+                reference.label.as_ref().unwrap_or(&reference.identifier)
+            ))),
+            // A root with any phrasing child is serialized as one paragraph, so
+            // block-level HTML goes in a paragraph of its own.
+            mdast::Node::FootnoteDefinition(definition) => {
+                lowered.push(mdast::Node::Paragraph(mdast::Paragraph {
+                    children: vec![html(&serialize_footnote_definition(&definition)?)],
+                    position: None,
+                }))
+            }
+            mdast::Node::Table(table) => lowered.push(mdast::Node::Paragraph(mdast::Paragraph {
+                children: lower_table(table),
+                position: None,
+            })),
+            other => lowered.push(other),
+        }
+    }
+
+    *children = lowered;
+    Ok(())
+}
+
+fn html(value: &str) -> mdast::Node {
+    mdast::Node::Html(mdast::Html {
+        value: value.to_string(),
+        position: None,
+    })
+}
+
+const PLACEHOLDER_START: char = '\u{E000}';
+const PLACEHOLDER_END: char = '\u{E001}';
+
+// `mdast_util_to_markdown` puts a space in front of HTML inside a paragraph, so
+// HTML is swapped for placeholder text and put back after serializing.
+fn hide_inline_html(node: &mut mdast::Node, inline_html: &mut Vec<String>) {
+    let is_phrasing_parent = matches!(
+        node,
+        mdast::Node::Paragraph(_)
+            | mdast::Node::Heading(_)
+            | mdast::Node::Strong(_)
+            | mdast::Node::Emphasis(_)
+            | mdast::Node::Link(_)
+            | mdast::Node::TableCell(_)
+    );
+
+    let Some(children) = node.children_mut() else {
+        return;
+    };
+
+    for child in children {
+        match child {
+            // One placeholder per line, so that lines still get their `> ` or
+            // list indent.
+            mdast::Node::Html(html) if is_phrasing_parent => {
+                let lines: Vec<String> = html
+                    .value
+                    .split('\n')
+                    .map(|line| {
+                        inline_html.push(line.to_string());
+                        placeholder(inline_html.len() - 1)
+                    })
+                    .collect();
+                *child = mdast::Node::Text(mdast::Text {
+                    value: lines.join("\n"),
+                    position: None,
+                });
+            }
+            _ => hide_inline_html(child, inline_html),
+        }
+    }
+}
+
+fn placeholder(index: usize) -> String {
+    format!("{}{}{}", PLACEHOLDER_START, index, PLACEHOLDER_END)
+}
+
+fn restore_inline_html(md: &str, inline_html: &[String]) -> String {
+    let mut md = md.to_string();
+    for (i, html) in inline_html.iter().enumerate() {
+        md = md.replace(&placeholder(i), html);
+    }
+    md
+}
+
+fn serialize_footnote_definition(definition: &mdast::FootnoteDefinition) -> Result<String, String> {
+    let root = mdast::Node::Root(mdast::Root {
+        children: definition.children.clone(),
+        position: None,
+    });
+    let content = serialize(&root)?;
+
+    let mut result = format!(
+        "[^{}]:",
+        definition.label.as_ref().unwrap_or(&definition.identifier)
+    );
+    for (i, line) in content.trim_end_matches('\n').lines().enumerate() {
+        match (i, line.is_empty()) {
+            (0, _) => result.push(' '),
+            (_, true) => result.push('\n'),
+            (_, false) => result.push_str("\n    "),
+        }
+        result.push_str(line);
+    }
+    Ok(result)
+}
+
+fn lower_table(table: mdast::Table) -> Vec<mdast::Node> {
+    let columns = table
+        .children
+        .iter()
+        .map(|row| row.children().map_or(0, |cells| cells.len()))
+        .max()
+        .unwrap_or(0)
+        .max(table.align.len());
+    if columns == 0 {
+        return vec![];
+    }
+
+    let delimiter: Vec<&str> = (0..columns)
+        .map(|i| match table.align.get(i) {
+            Some(mdast::AlignKind::Left) => ":---",
+            Some(mdast::AlignKind::Right) => "---:",
+            Some(mdast::AlignKind::Center) => ":---:",
+            _ => "---",
+        })
+        .collect();
+
+    let mut result = Vec::new();
+    for (i, row) in table.children.into_iter().enumerate() {
+        if i > 0 {
+            result.push(html("\n"));
+        }
+
+        let mut cells = match row {
+            mdast::Node::TableRow(row) => row.children,
+            _ => vec![],
+        };
+        cells.resize_with(columns, || {
+            mdast::Node::TableCell(mdast::TableCell {
+                children: vec![],
+                position: None,
+            })
+        });
+
+        result.push(html("| "));
+        for (j, cell) in cells.into_iter().enumerate() {
+            if j > 0 {
+                result.push(html(" | "));
+            }
+            if let mdast::Node::TableCell(cell) = cell {
+                result.extend(lower_table_cell(cell.children));
+            }
+        }
+        result.push(html(" |"));
+        if i == 0 {
+            result.push(html(&format!("\n| {} |", delimiter.join(" | "))));
+        }
+    }
+
+    result
+}
+
+// A cell is a single line, so breaks become `<br>` and pipes are escaped with
+// a character reference (`\|` would be unescaped again).
+fn lower_table_cell(children: Vec<mdast::Node>) -> Vec<mdast::Node> {
+    let mut result = Vec::new();
+    for child in children {
+        match child {
+            mdast::Node::Break(_) => result.push(html("<br>")),
+            mdast::Node::Text(text) if text.value.contains('|') => {
+                for (i, part) in text.value.split('|').enumerate() {
+                    if i > 0 {
+                        result.push(html("&#124;"));
+                    }
+                    if !part.is_empty() {
+                        result.push(mdast::Node::Text(mdast::Text {
+                            value: part.to_string(),
+                            position: None,
+                        }));
+                    }
+                }
+            }
+            mut other => {
+                if let Some(children) = other.children_mut() {
+                    *children = lower_table_cell(std::mem::take(children));
+                }
+                result.push(other);
+            }
+        }
+    }
+    result
 }
 
 fn unescape_markdown(md: &str) -> String {
@@ -98,7 +318,8 @@ fn inject_task_checkboxes(md: &str, task_items: &[Option<bool>]) -> String {
     let mut task_index = 0;
 
     for line in md.lines() {
-        let trimmed = line.trim_start();
+        // Lists inside blockquotes sit behind `> ` markers.
+        let trimmed = line.trim_start_matches([' ', '>']);
         if trimmed.starts_with("- ") || trimmed.starts_with("* ") {
             if task_index < task_items.len() {
                 if let Some(checked) = task_items[task_index] {
@@ -122,7 +343,7 @@ fn inject_task_checkboxes(md: &str, task_items: &[Option<bool>]) -> String {
             while chars.peek().is_some_and(|c| c.is_ascii_digit()) {
                 chars.next();
             }
-            if chars.next() == Some('.')
+            if matches!(chars.next(), Some('.' | ')'))
                 && chars.next() == Some(' ')
                 && task_index < task_items.len()
             {
@@ -1,6 +1,8 @@
 use markdown::mdast;
 use serde_json::{Value, json};
 
+use crate::to_ast::{IMAGE_HTML_ATTRS, MENTION_SCHEME, TIMESTAMP_SCHEME};
+
 pub fn md_to_tiptap_json(md: &str) -> Result<Value, String> {
     let mdast = markdown::to_mdast(md, &markdown::ParseOptions::gfm())
         .map_err(|e| format!("Failed to parse markdown: {}", e))?;
@@ -29,13 +31,23 @@ fn mdast_to_tiptap(node: &mdast::Node) -> Value {
 
 fn convert_block_node(node: &mdast::Node) -> Option<Value> {
     match node {
-        mdast::Node::Paragraph(p) => Some(convert_paragraph(p)),
+        // Images are block nodes in the editor.
+        mdast::Node::Paragraph(p) => match p.children.as_slice() {
+            [mdast::Node::Image(img)] => Some(convert_image(img)),
+            [mdast::Node::Html(html)] => {
+                convert_html_image(&html.value).or_else(|| Some(convert_paragraph(p)))
+            }
+            _ => Some(convert_paragraph(p)),
+        },
         mdast::Node::Heading(h) => Some(convert_heading(h)),
         mdast::Node::List(l) => Some(convert_list(l)),
         mdast::Node::Code(c) => Some(convert_code_block(c)),
         mdast::Node::Blockquote(b) => Some(convert_blockquote(b)),
         mdast::Node::ThematicBreak(_) => Some(json!({ "type": "horizontalRule" })),
         mdast::Node::Image(img) => Some(convert_image(img)),
+        mdast::Node::Html(html) => convert_html_image(&html.value),
+        mdast::Node::Table(t) => Some(convert_table(t)),
+        mdast::Node::FootnoteDefinition(d) => Some(convert_footnote_definition(d)),
         _ => None,
     }
 }
@@ -185,55 +197,244 @@ fn convert_image(img: &mdast::Image) -> Value {
     })
 }
 
+fn convert_table(t: &mdast::Table) -> Value {
+    let rows: Vec<Value> = t
+        .children
+        .iter()
+        .enumerate()
+        .map(|(i, row)| {
+            let cell_type = if i == 0 { "tableHeader" } else { "tableCell" };
+            let cells: Vec<Value> = row
+                .children()
+                .into_iter()
+                .flatten()
+                .map(|cell| {
+                    let paragraph = mdast::Paragraph {
+                        children: cell.children().cloned().unwrap_or_default(),
+                        position: None,
+                    };
+                    json!({
+                        "type": cell_type,
+                        "content": [convert_paragraph(&paragraph)]
+                    })
+                })
+                .collect();
+            json!({
+                "type": "tableRow",
+                "content": cells
+            })
+        })
+        .collect();
+
+    json!({
+        "type": "table",
+        "content": rows
+    })
+}
+
+fn convert_footnote_definition(d: &mdast::FootnoteDefinition) -> Value {
+    let content: Vec<Value> = d.children.iter().filter_map(convert_block_node).collect();
+    json!({
+        "type": "footnoteDefinition",
+        "attrs": { "id": d.label.as_ref().unwrap_or(&d.identifier) },
+        "content": content
+    })
+}
+
+fn convert_html_image(html: &str) -> Option<Value> {
+    let html = html.trim();
+    if !html.starts_with("<img") {
+        return None;
+    }
+
+    let tag_attrs = parse_html_attrs(html);
+    let get = |name: &str| {
+        tag_attrs
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.clone())
+    };
+
+    let mut attrs = json!({ "src": get("src").unwrap_or_default() });
+    for key in ["alt", "title"] {
+        if let Some(value) = get(key) {
+            attrs[key] = json!(value);
+        }
+    }
+    for (key, html_name) in IMAGE_HTML_ATTRS {
+        if let Some(value) = get(html_name) {
+            attrs[key] = match value.parse::<u64>() {
+                Ok(n) if key != "attachmentId" => json!(n),
+                _ => json!(value),
+            };
+        }
+    }
+
+    Some(json!({
+        "type": "image",
+        "attrs": attrs
+    }))
+}
+
+// Only needs to read back the `<img>` tags written by `to_ast`.
+fn parse_html_attrs(tag: &str) -> Vec<(String, String)> {
+    let mut attrs = Vec::new();
+    let mut rest = tag;
+
+    while let Some(eq) = rest.find("=\"") {
+        let name = rest[..eq]
+            .rsplit(|c: char| c.is_whitespace())
+            .next()
+            .unwrap_or("")
+            .to_string();
+        let value_start = eq + 2;
+        let Some(len) = rest[value_start..].find('"') else {
+            break;
+        };
+        let value = rest[value_start..value_start + len]
+            .replace("&quot;", "\"")
+            .replace("&lt;", "<")
+            .replace("&gt;", ">")
+            .replace("&amp;", "&");
+        attrs.push((name, value));
+        rest = &rest[value_start + len + 1..];
+    }
+
+    attrs
+}
+
+// Marks in the order the editor's schema keeps them, so that `***a***` and
+// `**a**` nested in `*...*` produce the same JSON.
+const MARK_ORDER: [&str; 6] = ["bold", "code", "italic", "strike", "link", "highlight"];
+
 fn convert_inline_nodes(nodes: &[mdast::Node]) -> Vec<Value> {
-    nodes.iter().filter_map(convert_inline_node).collect()
+    let mut content = Vec::new();
+    collect_inline_nodes(nodes, &mut Vec::new(), &mut content);
+    content
 }
 
-fn convert_inline_node(node: &mdast::Node) -> Option<Value> {
-    match node {
-        mdast::Node::Text(t) => Some(json!({ "type": "text", "text": t.value })),
-        mdast::Node::Strong(s) => Some(convert_marked_text(&s.children, "bold")),
-        mdast::Node::Emphasis(e) => Some(convert_marked_text(&e.children, "italic")),
-        mdast::Node::InlineCode(c) => Some(json!({
-            "type": "text",
-            "text": c.value,
-            "marks": [{ "type": "code" }]
-        })),
-        mdast::Node::Link(l) => Some(convert_link(l)),
-        mdast::Node::Delete(d) => Some(convert_marked_text(&d.children, "strike")),
-        mdast::Node::Break(_) => Some(json!({ "type": "hardBreak" })),
-        mdast::Node::Image(img) => Some(convert_image(img)),
-        _ => None,
+fn collect_inline_nodes(nodes: &[mdast::Node], marks: &mut Vec<Value>, content: &mut Vec<Value>) {
+    for node in nodes {
+        match node {
+            mdast::Node::Text(t) => push_text(content, &t.value, marks),
+            mdast::Node::InlineCode(c) => {
+                marks.push(json!({ "type": "code" }));
+                push_text(content, &c.value, marks);
+                marks.pop();
+            }
+            mdast::Node::Strong(s) => {
+                collect_marked_nodes(&s.children, json!({ "type": "bold" }), marks, content)
+            }
+            mdast::Node::Emphasis(e) => {
+                collect_marked_nodes(&e.children, json!({ "type": "italic" }), marks, content)
+            }
+            mdast::Node::Delete(d) => {
+                collect_marked_nodes(&d.children, json!({ "type": "strike" }), marks, content)
+            }
+            mdast::Node::Link(l) => match convert_link_node(l) {
+                Some(value) => content.push(value),
+                None => {
+                    let mut link_attrs = json!({ "href": l.url });
+                    if let Some(title) = &l.title {
+                        link_attrs["title"] = json!(title);
+                    }
+                    let mark = json!({ "type": "link", "attrs": link_attrs });
+                    collect_marked_nodes(&l.children, mark, marks, content);
+                }
+            },
+            mdast::Node::Break(_) => content.push(json!({ "type": "hardBreak" })),
+            mdast::Node::Image(img) => content.push(convert_image(img)),
+            mdast::Node::FootnoteReference(r) => content.push(json!({
+                "type": "footnoteReference",
+                "attrs": { "id": r.label.as_ref().unwrap_or(&r.identifier) }
+            })),
+            mdast::Node::Html(html) => match html.value.trim() {
+                "<mark>" => marks.push(json!({ "type": "highlight" })),
+                "</mark>" => {
+                    if let Some(i) = marks.iter().rposition(|m| m["type"] == "highlight") {
+                        marks.remove(i);
+                    }
+                }
+                "<br>" | "<br/>" | "<br />" => content.push(json!({ "type": "hardBreak" })),
+                value => content.extend(convert_html_image(value)),
+            },
+            _ => {}
+        }
     }
 }
 
-fn convert_marked_text(children: &[mdast::Node], mark_type: &str) -> Value {
-    let text = extract_text(children);
-    let mut existing_marks = extract_marks(children);
-    existing_marks.push(json!({ "type": mark_type }));
+fn collect_marked_nodes(
+    children: &[mdast::Node],
+    mark: Value,
+    marks: &mut Vec<Value>,
+    content: &mut Vec<Value>,
+) {
+    // Truncating also drops a `<mark>` left open inside this node.
+    let depth = marks.len();
+    marks.push(mark);
+    collect_inline_nodes(children, marks, content);
+    marks.truncate(depth);
+}
 
-    json!({
-        "type": "text",
-        "text": text,
-        "marks": existing_marks
-    })
+fn push_text(content: &mut Vec<Value>, text: &str, marks: &[Value]) {
+    if text.is_empty() {
+        return;
+    }
+
+    let mut marks = marks.to_vec();
+    marks.sort_by_key(|m| {
+        MARK_ORDER
+            .iter()
+            .position(|t| m["type"] == *t)
+            .unwrap_or(MARK_ORDER.len())
+    });
+    marks.dedup();
+
+    if let Some(last) = content.last_mut()
+        && last["type"] == "text"
+        && last
+            .get("marks")
+            .map_or(marks.is_empty(), |m| *m == json!(marks))
+        && let Some(last_text) = last["text"].as_str()
+    {
+        last["text"] = json!(format!("{}{}", last_text, text));
+        return;
+    }
+
+    let mut node = json!({ "type": "text", "text": text });
+    if !marks.is_empty() {
+        node["marks"] = json!(marks);
+    }
+    content.push(node);
 }
 
-fn convert_link(l: &mdast::Link) -> Value {
+/// Mentions and transcript timestamps are stored as links with their own scheme.
+fn convert_link_node(l: &mdast::Link) -> Option<Value> {
+    if let Some(ms) = l.url.strip_prefix(TIMESTAMP_SCHEME) {
+        return Some(json!({
+            "type": "timestamp",
+            "attrs": { "ms": ms.parse::<u64>().ok()? }
+        }));
+    }
+
+    let target = l.url.strip_prefix(MENTION_SCHEME)?;
     let text = extract_text(&l.children);
-    let mut existing_marks = extract_marks(&l.children);
+    let mut chars = text.chars();
+    let trigger = chars.next()?;
 
-    let mut link_attrs = json!({ "href": l.url });
-    if let Some(title) = &l.title {
-        link_attrs["title"] = json!(title);
+    let mut attrs = json!({ "label": chars.as_str() });
+    match target.split_once(':') {
+        Some((kind, id)) => {
+            attrs["id"] = json!(id);
+            attrs["type"] = json!(kind);
+        }
+        None => attrs["id"] = json!(target),
     }
-    existing_marks.push(json!({ "type": "link", "attrs": link_attrs }));
 
-    json!({
-        "type": "text",
-        "text": text,
-        "marks": existing_marks
-    })
+    Some(json!({
+        "type": format!("mention-{}", trigger),
+        "attrs": attrs
+    }))
 }
 
 fn extract_text(nodes: &[mdast::Node]) -> String {
@@ -250,25 +451,3 @@ fn extract_text(nodes: &[mdast::Node]) -> String {
         })
         .collect()
 }
-
-fn extract_marks(nodes: &[mdast::Node]) -> Vec<Value> {
-    let mut marks = Vec::new();
-    for node in nodes {
-        match node {
-            mdast::Node::Strong(s) => {
-                marks.push(json!({ "type": "bold" }));
-                marks.extend(extract_marks(&s.children));
-            }
-            mdast::Node::Emphasis(e) => {
-                marks.push(json!({ "type": "italic" }));
-                marks.extend(extract_marks(&e.children));
-            }
-            mdast::Node::Delete(d) => {
-                marks.push(json!({ "type": "strike" }));
-                marks.extend(extract_marks(&d.children));
-            }
-            _ => {}
-        }
-    }
-    marks
-}
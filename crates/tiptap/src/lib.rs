@@ -152,7 +152,7 @@ mod tests {
 
         ***
 
-        [A ](https://example.com)[link](https://example.com)
+        [A link](https://example.com)
         ");
     }
 
@@ -467,7 +467,7 @@ mod tests {
         insta::assert_snapshot!(to_md(json), @r"
         Text with ***bold and italic*** combined.
 
-        **Bold with *****nested italic***** inside** normal text.
+        **Bold with *nested italic* inside** normal text.
 
         **[Bold link](https://example.com)** and *`italic code`*
         ");
@@ -813,6 +813,167 @@ mod tests {
         assert_eq!(result.trim(), md);
     }
 
+    #[test]
+    fn test_gfm_extensions() {
+        let json = serde_json::json!({
+            "type": "doc",
+            "content": [
+                {
+                    "type": "table",
+                    "content": [
+                        {
+                            "type": "tableRow",
+                            "content": [
+                                {
+                                    "type": "tableHeader",
+                                    "content": [{ "type": "paragraph", "content": [{ "type": "text", "text": "Owner" }] }]
+                                },
+                                {
+                                    "type": "tableHeader",
+                                    "content": [{ "type": "paragraph", "content": [{ "type": "text", "text": "Status" }] }]
+                                }
+                            ]
+                        },
+                        {
+                            "type": "tableRow",
+                            "content": [
+                                {
+                                    "type": "tableCell",
+                                    "content": [{
+                                        "type": "paragraph",
+                                        "content": [{
+                                            "type": "mention-@",
+                                            "attrs": { "id": "jane", "type": "human", "label": "Jane" }
+                                        }]
+                                    }]
+                                },
+                                {
+                                    "type": "tableCell",
+                                    "content": [
+                                        {
+                                            "type": "paragraph",
+                                            "content": [
+                                                { "type": "text", "text": "done", "marks": [{ "type": "strike" }] },
+                                                { "type": "text", "text": " a|b" }
+                                            ]
+                                        },
+                                        { "type": "paragraph", "content": [{ "type": "text", "text": "line two" }] }
+                                    ]
+                                }
+                            ]
+                        }
+                    ]
+                },
+                {
+                    "type": "paragraph",
+                    "content": [
+                        { "type": "text", "text": "Agreed at " },
+                        { "type": "timestamp", "attrs": { "ms": 754000 } },
+                        { "type": "text", "text": " to " },
+                        { "type": "text", "text": "ship", "marks": [{ "type": "highlight" }] },
+                        { "type": "footnoteReference", "attrs": { "id": "1" } }
+                    ]
+                },
+                {
+                    "type": "footnoteDefinition",
+                    "attrs": { "id": "1" },
+                    "content": [{ "type": "paragraph", "content": [{ "type": "text", "text": "Pending legal." }] }]
+                },
+                {
+                    "type": "image",
+                    "attrs": { "src": "a.png", "alt": "chart", "width": 320, "height": null, "attachmentId": "att-1" }
+                }
+            ]
+        });
+
+        insta::assert_snapshot!(to_md(json), @r#"
+        | Owner | Status |
+        | --- | --- |
+        | [@Jane](mention:human:jane) | ~~done~~ a&#124;b<br>line two |
+
+        Agreed at [12:34](timestamp:754000) to <mark>ship</mark>[^1]
+
+        [^1]: Pending legal.
+
+        <img src="a.png" alt="chart" width="320" data-attachment-id="att-1">
+        "#);
+    }
+
+    #[test]
+    fn test_md_to_tiptap_gfm_extensions() {
+        let md = "| A | B |\n| --- | --- |\n| [@Jane](mention:human:jane) | x&#124;y<br>z |\n\n\
+                  At [01:02:03](timestamp:3723000), <mark>**ship**</mark>[^n]\n\n\
+                  [^n]: Note\n\n\
+                  <img src=\"a.png\" width=\"320\" data-attachment-id=\"att-1\">";
+        let json = md_to_tiptap_json(md).unwrap();
+
+        let table = &json["content"][0];
+        assert_eq!(table["type"], "table");
+        assert_eq!(table["content"][0]["content"][0]["type"], "tableHeader");
+        let cells = &table["content"][1]["content"];
+        assert_eq!(cells[0]["type"], "tableCell");
+        assert_eq!(
+            cells[0]["content"][0]["content"][0],
+            serde_json::json!({
+                "type": "mention-@",
+                "attrs": { "id": "jane", "type": "human", "label": "Jane" }
+            })
+        );
+        assert_eq!(
+            cells[1]["content"][0]["content"],
+            serde_json::json!([
+                { "type": "text", "text": "x|y" },
+                { "type": "hardBreak" },
+                { "type": "text", "text": "z" }
+            ])
+        );
+
+        assert_eq!(
+            json["content"][1]["content"],
+            serde_json::json!([
+                { "type": "text", "text": "At " },
+                { "type": "timestamp", "attrs": { "ms": 3723000 } },
+                { "type": "text", "text": ", " },
+                { "type": "text", "text": "ship", "marks": [{ "type": "bold" }, { "type": "highlight" }] },
+                { "type": "footnoteReference", "attrs": { "id": "n" } }
+            ])
+        );
+        assert_eq!(json["content"][2]["type"], "footnoteDefinition");
+        assert_eq!(json["content"][2]["attrs"]["id"], "n");
+        assert_eq!(
+            json["content"][3],
+            serde_json::json!({
+                "type": "image",
+                "attrs": { "src": "a.png", "width": 320, "attachmentId": "att-1" }
+            })
+        );
+    }
+
+    #[test]
+    fn test_md_to_tiptap_nested_marks() {
+        let md = "**bold *both* bold** and ~~*gone*~~";
+        let json = md_to_tiptap_json(md).unwrap();
+
+        assert_eq!(
+            json["content"][0]["content"],
+            serde_json::json!([
+                { "type": "text", "text": "bold ", "marks": [{ "type": "bold" }] },
+                { "type": "text", "text": "both", "marks": [{ "type": "bold" }, { "type": "italic" }] },
+                { "type": "text", "text": " bold", "marks": [{ "type": "bold" }] },
+                { "type": "text", "text": " and " },
+                { "type": "text", "text": "gone", "marks": [{ "type": "italic" }, { "type": "strike" }] }
+            ])
+        );
+    }
+
+    #[test]
+    fn test_roundtrip_table() {
+        let md = "| Name | Role |\n| --- | --- |\n| **Jane** | PM |\n| John | `eng` |";
+        let json = md_to_tiptap_json(md).unwrap();
+        let result = to_md(json);
+        assert_eq!(result.trim(), md);
+    }
+
     #[test]
     fn test_multibyte_chars_no_panic() {
         let json = serde_json::json!({
@@ -31,7 +31,13 @@ fn convert_node(node: &serde_json::Value) -> Option<mdast::Node> {
         "blockquote" => Some(convert_blockquote(node)),
         "horizontalRule" => Some(convert_horizontal_rule()),
         "hardBreak" => Some(convert_hard_break()),
-        "image" => Some(convert_image(node)),
+        // Markdown has no block images, only a paragraph holding one.
+        "image" => Some(mdast::Node::Paragraph(mdast::Paragraph {
+            children: vec![convert_image(node)],
+            position: None,
+        })),
+        "table" => Some(convert_table(node)),
+        "footnoteDefinition" => Some(convert_footnote_definition(node)),
         "text" => convert_text(node),
         _ => None,
     }
@@ -197,21 +203,43 @@ fn convert_hard_break() -> mdast::Node {
 
 fn convert_image(node: &serde_json::Value) -> mdast::Node {
     let attrs = node.get("attrs");
-    let url = attrs
-        .and_then(|a| a.get("src"))
+    let attr = |key: &str| attrs.and_then(|a| a.get(key)).filter(|v| !v.is_null());
+
+    let url = attr("src")
         .and_then(|s| s.as_str())
         .unwrap_or("")
         .to_string();
-    let alt = attrs
-        .and_then(|a| a.get("alt"))
+    let alt = attr("alt")
         .and_then(|a| a.as_str())
         .unwrap_or("")
         .to_string();
-    let title = attrs
-        .and_then(|a| a.get("title"))
+    let title = attr("title")
         .and_then(|t| t.as_str())
         .map(|s| s.to_string());
 
+    // Markdown images can't carry size or attachment, so fall back to an `<img>` tag.
+    let extra: Vec<(&str, String)> = IMAGE_HTML_ATTRS
+        .iter()
+        .filter_map(|(key, html)| Some((*html, attr_to_string(attr(key)?))))
+        .collect();
+    if !extra.is_empty() {
+        let mut value = format!("<img src=\"{}\"", escape_html_attr(&url));
+        if !alt.is_empty() {
+            value.push_str(&format!(" alt=\"{}\"", escape_html_attr(&alt)));
+        }
+        if let Some(title) = &title {
+            value.push_str(&format!(" title=\"{}\"", escape_html_attr(title)));
+        }
+        for (key, v) in extra {
+            value.push_str(&format!(" {}=\"{}\"", key, escape_html_attr(&v)));
+        }
+        value.push('>');
+        return mdast::Node::Html(mdast::Html {
+            value,
+            position: None,
+        });
+    }
+
     mdast::Node::Image(mdast::Image {
         url,
         alt,
@@ -220,6 +248,168 @@ fn convert_image(node: &serde_json::Value) -> mdast::Node {
     })
 }
 
+/// Image attrs that only survive as `<img>` attributes, with their HTML name.
+pub(crate) const IMAGE_HTML_ATTRS: [(&str, &str); 3] = [
+    ("width", "width"),
+    ("height", "height"),
+    ("attachmentId", "data-attachment-id"),
+];
+
+fn attr_to_string(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn escape_html_attr(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+fn convert_table(node: &serde_json::Value) -> mdast::Node {
+    let rows: Vec<mdast::Node> = children_of_type(node, "tableRow")
+        .map(|row| {
+            let cells = row
+                .get("content")
+                .and_then(|c| c.as_array())
+                .map(|cells| cells.iter().map(convert_table_cell).collect())
+                .unwrap_or_default();
+            mdast::Node::TableRow(mdast::TableRow {
+                children: cells,
+                position: None,
+            })
+        })
+        .collect();
+
+    let columns = rows
+        .iter()
+        .map(|row| row.children().map_or(0, |cells| cells.len()))
+        .max()
+        .unwrap_or(0);
+
+    mdast::Node::Table(mdast::Table {
+        align: vec![mdast::AlignKind::None; columns],
+        children: rows,
+        position: None,
+    })
+}
+
+// Cells hold block content in tiptap but only a single line in markdown.
+fn convert_table_cell(cell: &serde_json::Value) -> mdast::Node {
+    let mut children = Vec::new();
+    for block in cell
+        .get("content")
+        .and_then(|c| c.as_array())
+        .into_iter()
+        .flatten()
+    {
+        if !children.is_empty() {
+            children.push(convert_hard_break());
+        }
+        children.extend(convert_inline_content(block));
+    }
+
+    mdast::Node::TableCell(mdast::TableCell {
+        children,
+        position: None,
+    })
+}
+
+fn convert_footnote_definition(node: &serde_json::Value) -> mdast::Node {
+    mdast::Node::FootnoteDefinition(mdast::FootnoteDefinition {
+        identifier: footnote_id(node),
+        label: Some(footnote_id(node)),
+        children: convert_content(node),
+        position: None,
+    })
+}
+
+fn convert_footnote_reference(node: &serde_json::Value) -> mdast::Node {
+    mdast::Node::FootnoteReference(mdast::FootnoteReference {
+        identifier: footnote_id(node),
+        label: Some(footnote_id(node)),
+        position: None,
+    })
+}
+
+fn footnote_id(node: &serde_json::Value) -> String {
+    node.get("attrs")
+        .and_then(|a| a.get("id"))
+        .map(attr_to_string)
+        .unwrap_or_default()
+}
+
+/// `mention-@` nodes become `[@label](mention:type:id)`.
+fn convert_mention(node: &serde_json::Value, trigger: &str) -> mdast::Node {
+    let attrs = node.get("attrs");
+    let attr = |key: &str| {
+        attrs
+            .and_then(|a| a.get(key))
+            .and_then(|v| v.as_str())
+            .unwrap_or("")
+    };
+
+    let url = match attr("type") {
+        "" => format!("{}{}", MENTION_SCHEME, attr("id")),
+        kind => format!("{}{}:{}", MENTION_SCHEME, kind, attr("id")),
+    };
+
+    mdast::Node::Link(mdast::Link {
+        url,
+        title: None,
+        children: vec![mdast::Node::Text(mdast::Text {
+            value: format!("{}{}", trigger, attr("label")),
+            position: None,
+        })],
+        position: None,
+    })
+}
+
+/// Transcript timestamps become `[01:02](timestamp:62000)`.
+fn convert_timestamp(node: &serde_json::Value) -> mdast::Node {
+    let ms = node
+        .get("attrs")
+        .and_then(|a| a.get("ms"))
+        .and_then(|ms| ms.as_u64())
+        .unwrap_or(0);
+
+    mdast::Node::Link(mdast::Link {
+        url: format!("{}{}", TIMESTAMP_SCHEME, ms),
+        title: None,
+        children: vec![mdast::Node::Text(mdast::Text {
+            value: format_timestamp(ms),
+            position: None,
+        })],
+        position: None,
+    })
+}
+
+pub(crate) const MENTION_SCHEME: &str = "mention:";
+pub(crate) const TIMESTAMP_SCHEME: &str = "timestamp:";
+
+fn format_timestamp(ms: u64) -> String {
+    let seconds = ms / 1000;
+    match seconds / 3600 {
+        0 => format!("{:02}:{:02}", seconds / 60, seconds % 60),
+        hours => format!("{}:{:02}:{:02}", hours, (seconds / 60) % 60, seconds % 60),
+    }
+}
+
+fn children_of_type<'a>(
+    node: &'a serde_json::Value,
+    node_type: &'a str,
+) -> impl Iterator<Item = &'a serde_json::Value> {
+    node.get("content")
+        .and_then(|c| c.as_array())
+        .into_iter()
+        .flatten()
+        .filter(move |child| child.get("type").and_then(|t| t.as_str()) == Some(node_type))
+}
+
 fn convert_text(node: &serde_json::Value) -> Option<mdast::Node> {
     let text = node.get("text")?.as_str()?;
     Some(mdast::Node::Text(mdast::Text {
@@ -233,83 +423,159 @@ fn convert_inline_content(node: &serde_json::Value) -> Vec<mdast::Node> {
         return vec![];
     };
 
-    content.iter().filter_map(convert_inline_node).collect()
+    let items: Vec<Inline> = content.iter().map(Inline::new).collect();
+    convert_inline_run(&items)
 }
 
-fn convert_inline_node(node: &serde_json::Value) -> Option<mdast::Node> {
-    let node_type = node.get("type")?.as_str()?;
+struct Inline<'a> {
+    node: &'a serde_json::Value,
+    code: bool,
+    // Marks that wrap their content, in the order tiptap lists them.
+    marks: Vec<&'a serde_json::Value>,
+}
 
-    match node_type {
-        "text" => convert_text_with_marks(node),
-        "hardBreak" => Some(convert_hard_break()),
-        "image" => Some(convert_image(node)),
-        _ => None,
+impl<'a> Inline<'a> {
+    fn new(node: &'a serde_json::Value) -> Self {
+        let marks: Vec<&serde_json::Value> = node
+            .get("marks")
+            .and_then(|m| m.as_array())
+            .into_iter()
+            .flatten()
+            .collect();
+
+        Self {
+            node,
+            code: marks.iter().any(|m| mark_type(m) == Some("code")),
+            marks: marks
+                .into_iter()
+                .filter(|m| {
+                    matches!(
+                        mark_type(m),
+                        Some("bold" | "strong" | "italic" | "em" | "link" | "strike" | "highlight")
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    fn without(&self, mark: &serde_json::Value) -> Self {
+        Self {
+            node: self.node,
+            code: self.code,
+            marks: self.marks.iter().copied().filter(|m| *m != mark).collect(),
+        }
     }
 }
 
-fn convert_text_with_marks(node: &serde_json::Value) -> Option<mdast::Node> {
-    let text = node.get("text")?.as_str()?;
-    let marks = node.get("marks").and_then(|m| m.as_array());
+fn mark_type(mark: &serde_json::Value) -> Option<&str> {
+    mark.get("type").and_then(|t| t.as_str())
+}
 
-    let text_node = mdast::Node::Text(mdast::Text {
-        value: text.to_string(),
-        position: None,
-    });
+// Adjacent nodes sharing a mark are wrapped together, so `**a *b* c**` doesn't
+// turn into `**a *****b***** c**`. The mark spanning the most nodes goes outermost.
+fn convert_inline_run(items: &[Inline]) -> Vec<mdast::Node> {
+    let mut result = Vec::new();
+    let mut i = 0;
+
+    while i < items.len() {
+        let longest = items[i]
+            .marks
+            .iter()
+            .rev()
+            .map(|mark| {
+                let len = items[i..]
+                    .iter()
+                    .take_while(|item| item.marks.contains(mark))
+                    .count();
+                (*mark, len)
+            })
+            .max_by_key(|(_, len)| *len);
+
+        let Some((mark, len)) = longest else {
+            result.extend(convert_inline_node(&items[i]));
+            i += 1;
+            continue;
+        };
 
-    let Some(marks) = marks else {
-        return Some(text_node);
-    };
+        let inner: Vec<Inline> = items[i..i + len]
+            .iter()
+            .map(|item| item.without(mark))
+            .collect();
+        result.extend(wrap_mark(mark, convert_inline_run(&inner)));
+        i += len;
+    }
 
-    let mut result = text_node;
+    result
+}
 
-    for mark in marks.iter().rev() {
-        let mark_type = mark.get("type").and_then(|t| t.as_str());
-        result = match mark_type {
-            Some("bold") | Some("strong") => mdast::Node::Strong(mdast::Strong {
-                children: vec![result],
-                position: None,
-            }),
-            Some("italic") | Some("em") => mdast::Node::Emphasis(mdast::Emphasis {
-                children: vec![result],
+fn convert_inline_node(item: &Inline) -> Option<mdast::Node> {
+    let node = item.node;
+    let node_type = node.get("type")?.as_str()?;
+
+    match node_type {
+        "text" if item.code => Some(mdast::Node::InlineCode(mdast::InlineCode {
+            value: node.get("text")?.as_str()?.to_string(),
+            position: None,
+        })),
+        "text" => convert_text(node),
+        "hardBreak" => Some(convert_hard_break()),
+        "image" => Some(convert_image(node)),
+        "timestamp" => Some(convert_timestamp(node)),
+        "footnoteReference" => Some(convert_footnote_reference(node)),
+        t => t
+            .strip_prefix("mention-")
+            .map(|trigger| convert_mention(node, trigger)),
+    }
+}
+
+fn wrap_mark(mark: &serde_json::Value, children: Vec<mdast::Node>) -> Vec<mdast::Node> {
+    let node = match mark_type(mark) {
+        Some("bold") | Some("strong") => mdast::Node::Strong(mdast::Strong {
+            children,
+            position: None,
+        }),
+        Some("italic") | Some("em") => mdast::Node::Emphasis(mdast::Emphasis {
+            children,
+            position: None,
+        }),
+        Some("link") => {
+            let attrs = mark.get("attrs");
+            let url = attrs
+                .and_then(|a| a.get("href"))
+                .and_then(|h| h.as_str())
+                .unwrap_or("")
+                .to_string();
+            let title = attrs
+                .and_then(|a| a.get("title"))
+                .and_then(|t| t.as_str())
+                .map(|s| s.to_string());
+
+            mdast::Node::Link(mdast::Link {
+                url,
+                title,
+                children,
                 position: None,
-            }),
-            Some("code") => {
-                if let mdast::Node::Text(t) = result {
-                    mdast::Node::InlineCode(mdast::InlineCode {
-                        value: t.value,
-                        position: None,
-                    })
-                } else {
-                    result
-                }
-            }
-            Some("link") => {
-                let url = mark
-                    .get("attrs")
-                    .and_then(|a| a.get("href"))
-                    .and_then(|h| h.as_str())
-                    .unwrap_or("")
-                    .to_string();
-                let title = mark
-                    .get("attrs")
-                    .and_then(|a| a.get("title"))
-                    .and_then(|t| t.as_str())
-                    .map(|s| s.to_string());
-
-                mdast::Node::Link(mdast::Link {
-                    url,
-                    title,
-                    children: vec![result],
+            })
+        }
+        Some("strike") => mdast::Node::Delete(mdast::Delete {
+            children,
+            position: None,
+        }),
+        // No markdown syntax for highlights; `<mark>` is what most renderers accept.
+        Some("highlight") => {
+            let html = |value: &str| {
+                mdast::Node::Html(mdast::Html {
+                    value: value.to_string(),
                     position: None,
                 })
-            }
-            Some("strike") => mdast::Node::Delete(mdast::Delete {
-                children: vec![result],
-                position: None,
-            }),
-            _ => result,
-        };
-    }
+            };
+            return std::iter::once(html("<mark>"))
+                .chain(children)
+                .chain(std::iter::once(html("</mark>")))
+                .collect();
+        }
+        _ => return children,
+    };
 
-    Some(result)
+    vec![node]
 }
@@ -0,0 +1,71 @@
+use std::collections::BTreeSet;
+
+use serde_json::Value;
+
+use tiptap::md_to_tiptap_json;
+
+// `kitchen-sink.json` is also loaded by the editor's schema test in
+// packages/tiptap, so every node and mark type the parser emits must show up
+// in it.
+const KITCHEN_SINK_MD: &str = include_str!("data/kitchen-sink.md");
+const KITCHEN_SINK_JSON: &str = include_str!("data/kitchen-sink.json");
+
+fn collect_types(node: &Value, nodes: &mut BTreeSet<String>, marks: &mut BTreeSet<String>) {
+    if let Some(t) = node["type"].as_str() {
+        nodes.insert(t.to_string());
+    }
+    for mark in node["marks"].as_array().into_iter().flatten() {
+        if let Some(t) = mark["type"].as_str() {
+            marks.insert(t.to_string());
+        }
+    }
+    for child in node["content"].as_array().into_iter().flatten() {
+        collect_types(child, nodes, marks);
+    }
+}
+
+#[test]
+fn kitchen_sink_matches_fixture() {
+    let expected: Value = serde_json::from_str(KITCHEN_SINK_JSON).unwrap();
+    assert_eq!(md_to_tiptap_json(KITCHEN_SINK_MD).unwrap(), expected);
+}
+
+#[test]
+fn kitchen_sink_covers_every_emitted_type() {
+    let json = md_to_tiptap_json(KITCHEN_SINK_MD).unwrap();
+    let mut nodes = BTreeSet::new();
+    let mut marks = BTreeSet::new();
+    collect_types(&json, &mut nodes, &mut marks);
+
+    assert_eq!(
+        nodes.iter().map(String::as_str).collect::<Vec<_>>(),
+        vec![
+            "blockquote",
+            "bulletList",
+            "codeBlock",
+            "doc",
+            "footnoteDefinition",
+            "footnoteReference",
+            "hardBreak",
+            "heading",
+            "horizontalRule",
+            "image",
+            "listItem",
+            "mention-@",
+            "orderedList",
+            "paragraph",
+            "table",
+            "tableCell",
+            "tableHeader",
+            "tableRow",
+            "taskItem",
+            "taskList",
+            "text",
+            "timestamp",
+        ]
+    );
+    assert_eq!(
+        marks.iter().map(String::as_str).collect::<Vec<_>>(),
+        vec!["bold", "code", "highlight", "italic", "link", "strike"]
+    );
+}
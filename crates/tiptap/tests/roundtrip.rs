@@ -0,0 +1,282 @@
+use quickcheck::{Arbitrary, Gen};
+use quickcheck_macros::quickcheck;
+use serde_json::{Value, json};
+
+use tiptap::{md_to_tiptap_json, tiptap_json_to_md};
+
+// Documents in the normalized shape the parser produces: marks in schema
+// order, no adjacent text nodes with the same marks, no whitespace at mark
+// boundaries and no two lists back to back (markdown would merge them).
+#[derive(Clone, Debug)]
+struct Doc(Value);
+
+impl Arbitrary for Doc {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let mut content = blocks(g, 2);
+
+        if bool::arbitrary(g) {
+            content.push(json!({
+                "type": "paragraph",
+                "content": [
+                    { "type": "text", "text": words(g) },
+                    { "type": "footnoteReference", "attrs": { "id": "1" } }
+                ]
+            }));
+            content.push(json!({
+                "type": "footnoteDefinition",
+                "attrs": { "id": "1" },
+                "content": [paragraph(g)]
+            }));
+        }
+
+        Doc(json!({ "type": "doc", "content": content }))
+    }
+}
+
+#[quickcheck]
+fn roundtrip(doc: Doc) -> Result<(), String> {
+    let md = tiptap_json_to_md(&doc.0)?;
+    let parsed = md_to_tiptap_json(&md)?;
+
+    if parsed == doc.0 {
+        Ok(())
+    } else {
+        Err(format!(
+            "markdown:\n{}\n\nexpected:\n{}\n\nparsed:\n{}",
+            md, doc.0, parsed
+        ))
+    }
+}
+
+fn blocks(g: &mut Gen, depth: usize) -> Vec<Value> {
+    let mut result: Vec<Value> = Vec::new();
+
+    for _ in 0..between(g, 1, 4) {
+        let block = block(g, depth);
+        if result.last().is_some_and(is_list) && is_list(&block) {
+            result.push(paragraph(g));
+        }
+        result.push(block);
+    }
+
+    result
+}
+
+fn block(g: &mut Gen, depth: usize) -> Value {
+    let choices: &[&str] = if depth == 0 {
+        &[
+            "paragraph",
+            "heading",
+            "codeBlock",
+            "horizontalRule",
+            "image",
+        ]
+    } else {
+        &[
+            "paragraph",
+            "heading",
+            "codeBlock",
+            "horizontalRule",
+            "image",
+            "bulletList",
+            "orderedList",
+            "taskList",
+            "blockquote",
+            "table",
+        ]
+    };
+
+    match *g.choose(choices).unwrap() {
+        "heading" => json!({
+            "type": "heading",
+            "attrs": { "level": between(g, 1, 6) },
+            "content": inline(g)
+        }),
+        "codeBlock" => {
+            let lines: Vec<String> = (0..between(g, 1, 3)).map(|_| words(g)).collect();
+            let mut code = json!({
+                "type": "codeBlock",
+                "content": [{ "type": "text", "text": lines.join("\n") }]
+            });
+            if bool::arbitrary(g) {
+                code["attrs"] = json!({ "language": g.choose(&["rust", "ts", "sql"]).unwrap() });
+            }
+            code
+        }
+        "horizontalRule" => json!({ "type": "horizontalRule" }),
+        "image" => image(g),
+        "bulletList" => list(g, "bulletList", depth),
+        "orderedList" => list(g, "orderedList", depth),
+        "taskList" => list(g, "taskList", depth),
+        "blockquote" => json!({
+            "type": "blockquote",
+            "content": blocks(g, depth - 1)
+        }),
+        "table" => table(g),
+        _ => paragraph(g),
+    }
+}
+
+fn list(g: &mut Gen, list_type: &str, depth: usize) -> Value {
+    let items: Vec<Value> = (0..between(g, 1, 3))
+        .map(|_| {
+            let mut content = vec![paragraph(g)];
+            if depth > 1 && bool::arbitrary(g) {
+                content.push(nested_list(g, list_type, depth - 1));
+            }
+
+            if list_type == "taskList" {
+                json!({
+                    "type": "taskItem",
+                    "attrs": { "checked": bool::arbitrary(g) },
+                    "content": content
+                })
+            } else {
+                json!({ "type": "listItem", "content": content })
+            }
+        })
+        .collect();
+
+    let mut list = json!({ "type": list_type, "content": items });
+    if list_type == "orderedList" {
+        list["attrs"] = json!({ "start": between(g, 1, 3) });
+    }
+    list
+}
+
+// Only a list starting at 1 can interrupt the paragraph of its parent item.
+fn nested_list(g: &mut Gen, list_type: &str, depth: usize) -> Value {
+    let mut list = list(g, list_type, depth);
+    if list_type == "orderedList" {
+        list["attrs"]["start"] = json!(1);
+    }
+    list
+}
+
+fn table(g: &mut Gen) -> Value {
+    let columns = between(g, 1, 3);
+    let rows: Vec<Value> = (0..between(g, 2, 4))
+        .map(|row| {
+            let cell_type = if row == 0 { "tableHeader" } else { "tableCell" };
+            let cells: Vec<Value> = (0..columns)
+                .map(|_| json!({ "type": cell_type, "content": [paragraph(g)] }))
+                .collect();
+            json!({ "type": "tableRow", "content": cells })
+        })
+        .collect();
+
+    json!({ "type": "table", "content": rows })
+}
+
+fn image(g: &mut Gen) -> Value {
+    let mut attrs = json!({ "src": format!("https://example.com/{}.png", word(g)) });
+    if bool::arbitrary(g) {
+        attrs["alt"] = json!(words(g));
+    }
+    if bool::arbitrary(g) {
+        attrs["title"] = json!(words(g));
+    }
+    if bool::arbitrary(g) {
+        attrs["width"] = json!(between(g, 1, 1000));
+    }
+    if bool::arbitrary(g) {
+        attrs["attachmentId"] = json!(word(g));
+    }
+
+    json!({ "type": "image", "attrs": attrs })
+}
+
+fn paragraph(g: &mut Gen) -> Value {
+    json!({ "type": "paragraph", "content": inline(g) })
+}
+
+fn inline(g: &mut Gen) -> Vec<Value> {
+    let mut content: Vec<Value> = Vec::new();
+
+    for i in 0..between(g, 1, 4) {
+        if i > 0 {
+            push_text(&mut content, " ", Vec::new());
+        }
+
+        match between(g, 0, 9) {
+            0 => content.push(json!({
+                "type": "mention-@",
+                "attrs": {
+                    "id": word(g),
+                    "type": g.choose(&["human", "session", "organization"]).unwrap(),
+                    "label": words(g)
+                }
+            })),
+            1 => content.push(json!({
+                "type": "timestamp",
+                "attrs": { "ms": u32::arbitrary(g) }
+            })),
+            _ => {
+                let text = words(g);
+                push_text(&mut content, &text, marks(g));
+            }
+        }
+    }
+
+    content
+}
+
+fn marks(g: &mut Gen) -> Vec<Value> {
+    let mut marks = Vec::new();
+    for mark in ["bold", "code", "italic", "strike", "link", "highlight"] {
+        if between(g, 0, 3) != 0 {
+            continue;
+        }
+        marks.push(match mark {
+            "link" => json!({
+                "type": "link",
+                "attrs": { "href": format!("https://example.com/{}", word(g)) }
+            }),
+            _ => json!({ "type": mark }),
+        });
+    }
+    marks
+}
+
+fn push_text(content: &mut Vec<Value>, text: &str, marks: Vec<Value>) {
+    if let Some(last) = content.last_mut()
+        && last["type"] == "text"
+        && last
+            .get("marks")
+            .map_or(marks.is_empty(), |m| *m == json!(marks))
+    {
+        last["text"] = json!(format!("{}{}", last["text"].as_str().unwrap(), text));
+        return;
+    }
+
+    let mut node = json!({ "type": "text", "text": text });
+    if !marks.is_empty() {
+        node["marks"] = json!(marks);
+    }
+    content.push(node);
+}
+
+fn is_list(block: &Value) -> bool {
+    matches!(
+        block["type"].as_str(),
+        Some("bulletList" | "orderedList" | "taskList")
+    )
+}
+
+fn words(g: &mut Gen) -> String {
+    let words: Vec<String> = (0..between(g, 1, 3)).map(|_| word(g)).collect();
+    words.join(" ")
+}
+
+fn word(g: &mut Gen) -> String {
+    (0..between(g, 1, 8))
+        .map(|_| {
+            *g.choose(&['a', 'e', 'k', 'm', 'o', 'r', 's', 't', 'x'])
+                .unwrap()
+        })
+        .collect()
+}
+
+fn between(g: &mut Gen, min: usize, max: usize) -> usize {
+    min + usize::arbitrary(g) % (max - min + 1)
+}
@@ -0,0 +1,21 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("failed to read file: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("failed to parse JSON: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("failed to read DOCX: {0}")]
+    Docx(#[from] zip::result::ZipError),
+
+    #[error("unsupported file: {0}")]
+    UnsupportedFile(String),
+
+    #[error("no transcript found in {0}")]
+    EmptyTranscript(String),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
@@ -0,0 +1,166 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use crate::{Cue, Result};
+
+/// A Fireflies.ai transcript, as returned by its GraphQL API or the JSON
+/// download.
+#[derive(Debug, Default)]
+pub struct Transcript {
+    pub title: Option<String>,
+    pub date: Option<DateTime<Utc>>,
+    pub cues: Vec<Cue>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Document {
+    Wrapped { data: Wrapper },
+    Transcript(RawTranscript),
+    Sentences(Vec<RawSentence>),
+}
+
+#[derive(Deserialize)]
+struct Wrapper {
+    transcript: RawTranscript,
+}
+
+#[derive(Deserialize)]
+struct RawTranscript {
+    #[serde(default)]
+    title: Option<String>,
+    #[serde(default)]
+    date: Option<serde_json::Value>,
+    #[serde(default, alias = "dateString")]
+    date_string: Option<String>,
+    sentences: Vec<RawSentence>,
+}
+
+#[derive(Deserialize)]
+struct RawSentence {
+    #[serde(default, alias = "speakerName")]
+    speaker_name: Option<String>,
+    #[serde(default, alias = "sentence")]
+    text: String,
+    /// The API sends this next to `text`, before filler words are removed.
+    #[serde(default)]
+    raw_text: Option<String>,
+    #[serde(alias = "startTime")]
+    start_time: f64,
+    #[serde(default, alias = "endTime")]
+    end_time: Option<f64>,
+}
+
+pub fn parse(input: &str) -> Result<Transcript> {
+    let raw = match serde_json::from_str::<Document>(input)? {
+        Document::Wrapped { data } => data.transcript,
+        Document::Transcript(transcript) => transcript,
+        Document::Sentences(sentences) => RawTranscript {
+            title: None,
+            date: None,
+            date_string: None,
+            sentences,
+        },
+    };
+
+    let date = raw
+        .date
+        .as_ref()
+        .and_then(parse_date)
+        .or_else(|| raw.date_string.as_deref().and_then(parse_date_str));
+
+    let cues = raw
+        .sentences
+        .into_iter()
+        .filter_map(|s| {
+            let text = Some(s.text.trim())
+                .filter(|t| !t.is_empty())
+                .or_else(|| s.raw_text.as_deref().map(str::trim))
+                .filter(|t| !t.is_empty())?
+                .to_string();
+
+            Some(Cue {
+                start_ms: seconds_to_ms(s.start_time),
+                end_ms: s.end_time.map(seconds_to_ms),
+                speaker: s
+                    .speaker_name
+                    .map(|name| name.trim().to_string())
+                    .filter(|name| !name.is_empty()),
+                text,
+            })
+        })
+        .collect();
+
+    Ok(Transcript {
+        title: raw.title.filter(|t| !t.trim().is_empty()),
+        date,
+        cues,
+    })
+}
+
+// Fireflies reports `date` as epoch milliseconds, `dateString` as ISO 8601.
+fn parse_date(value: &serde_json::Value) -> Option<DateTime<Utc>> {
+    match value {
+        serde_json::Value::Number(n) => DateTime::from_timestamp_millis(n.as_f64()? as i64),
+        serde_json::Value::String(s) => parse_date_str(s),
+        _ => None,
+    }
+}
+
+fn parse_date_str(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .ok()
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+fn seconds_to_ms(seconds: f64) -> u64 {
+    (seconds.max(0.0) * 1000.0).round() as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_api_response() {
+        let input = r#"{"data":{"transcript":{"title":"Weekly sync","date":1709287200000,"sentences":[
+            {"index":0,"speaker_name":"Jane Doe","text":"Hi all.","start_time":0.5,"end_time":1.25},
+            {"index":1,"speaker_name":"John Smith","text":"  ","start_time":1.3,"end_time":1.4},
+            {"index":2,"speaker_name":null,"text":"Bye.","start_time":2,"end_time":2.5}
+        ]}}}"#;
+
+        let transcript = parse(input).unwrap();
+        assert_eq!(transcript.title.as_deref(), Some("Weekly sync"));
+        assert_eq!(
+            transcript.date.unwrap().to_rfc3339(),
+            "2024-03-01T10:00:00+00:00"
+        );
+        assert_eq!(
+            transcript.cues,
+            vec![
+                Cue {
+                    start_ms: 500,
+                    end_ms: Some(1250),
+                    speaker: Some("Jane Doe".to_string()),
+                    text: "Hi all.".to_string(),
+                },
+                Cue {
+                    start_ms: 2000,
+                    end_ms: Some(2500),
+                    speaker: None,
+                    text: "Bye.".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_sentence_array() {
+        let input = r#"[{"speakerName":"Jane","sentence":"Hello","startTime":1,"endTime":2}]"#;
+
+        let transcript = parse(input).unwrap();
+        assert_eq!(transcript.title, None);
+        assert_eq!(transcript.cues[0].speaker.as_deref(), Some("Jane"));
+        assert_eq!(transcript.cues[0].start_ms, 1000);
+    }
+}
@@ -0,0 +1,373 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use hypr_importer_core::ir::{
+    Collection, Human, Session, SessionParticipant, SpeakerHint, Transcript, Word,
+};
+
+use crate::{Cue, Error, Result, fireflies, otter, speaker, subtitle, zoom};
+
+// Used to estimate timings when an export only stamps where a paragraph starts.
+const FALLBACK_MS_PER_WORD: u64 = 400;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// Otter.ai TXT, DOCX or SRT export.
+    Otter,
+    /// Fireflies.ai JSON transcript.
+    Fireflies,
+    /// Zoom cloud-recording `.vtt` transcript.
+    Zoom,
+    /// Any WebVTT or SRT file.
+    Subtitles,
+}
+
+impl Format {
+    fn extensions(&self) -> &'static [&'static str] {
+        match self {
+            Format::Otter => &["txt", "docx", "srt"],
+            Format::Fireflies => &["json"],
+            Format::Zoom => &["vtt"],
+            Format::Subtitles => &["vtt", "srt"],
+        }
+    }
+
+    fn supports(&self, path: &Path) -> bool {
+        path.extension()
+            .and_then(|e| e.to_str())
+            .is_some_and(|e| self.extensions().contains(&e.to_lowercase().as_str()))
+    }
+}
+
+struct ParsedFile {
+    title: Option<String>,
+    started_at: Option<DateTime<Utc>>,
+    cues: Vec<Cue>,
+}
+
+/// Imports a single export file, or every supported file under a directory,
+/// as one session per file.
+pub fn import_all_from_path(path: &Path, format: Format) -> Result<Collection> {
+    if !path.is_dir() {
+        let mut collection = Collection::default();
+        import_file(path, format, &mut collection)?;
+        return Ok(collection);
+    }
+
+    let mut files = Vec::new();
+    collect_files(path, format, &mut files)?;
+    files.sort();
+
+    let mut collection = Collection::default();
+    for file in files {
+        if let Err(error) = import_file(&file, format, &mut collection) {
+            tracing::warn!("skipping {}: {}", file.display(), error);
+        }
+    }
+    Ok(collection)
+}
+
+fn collect_files(dir: &Path, format: Format, out: &mut Vec<std::path::PathBuf>) -> Result<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_files(&path, format, out)?;
+        } else if format.supports(&path) {
+            out.push(path);
+        }
+    }
+    Ok(())
+}
+
+fn parse_file(path: &Path, bytes: &[u8], format: Format) -> Result<ParsedFile> {
+    if !format.supports(path) {
+        return Err(Error::UnsupportedFile(path.display().to_string()));
+    }
+    let text = || String::from_utf8_lossy(bytes).into_owned();
+
+    let parsed = match format {
+        Format::Otter => ParsedFile {
+            title: None,
+            started_at: None,
+            cues: otter::parse_file(path)?,
+        },
+        Format::Fireflies => {
+            let transcript = fireflies::parse(&text())?;
+            ParsedFile {
+                title: transcript.title,
+                started_at: transcript.date,
+                cues: transcript.cues,
+            }
+        }
+        Format::Zoom => ParsedFile {
+            title: None,
+            started_at: zoom::recording_started_at(path),
+            cues: zoom::parse(&text()),
+        },
+        Format::Subtitles => {
+            let mut cues = subtitle::parse(&text());
+            speaker::apply_labels(&mut cues, false);
+            ParsedFile {
+                title: None,
+                started_at: None,
+                cues,
+            }
+        }
+    };
+
+    if parsed.cues.is_empty() {
+        return Err(Error::EmptyTranscript(path.display().to_string()));
+    }
+    Ok(parsed)
+}
+
+fn import_file(path: &Path, format: Format, collection: &mut Collection) -> Result<()> {
+    let bytes = std::fs::read(path)?;
+    let parsed = parse_file(path, &bytes, format)?;
+
+    // Derived from the content so re-importing the same export is stable.
+    let session_id = uuid::Uuid::new_v5(&uuid::Uuid::NAMESPACE_OID, &bytes).to_string();
+
+    let started_at = parsed.started_at.or_else(|| {
+        std::fs::metadata(path)
+            .and_then(|m| m.modified())
+            .ok()
+            .map(DateTime::<Utc>::from)
+    });
+    let created_at = started_at.map(|dt| dt.to_rfc3339()).unwrap_or_default();
+
+    let title = parsed.title.unwrap_or_else(|| file_title(path));
+
+    let mut humans: HashMap<String, String> = HashMap::new();
+    for label in parsed.cues.iter().filter_map(|c| c.speaker.as_deref()) {
+        if speaker::is_generic(label) || humans.contains_key(label) {
+            continue;
+        }
+        let human_id = human_id(label);
+        humans.insert(label.to_string(), human_id.clone());
+
+        if !collection.humans.iter().any(|h| h.id == human_id) {
+            collection.humans.push(Human {
+                id: human_id.clone(),
+                user_id: String::new(),
+                created_at: created_at.clone(),
+                name: label.to_string(),
+                email: None,
+                org_id: None,
+                job_title: None,
+                linkedin_username: None,
+            });
+        }
+        collection.participants.push(SessionParticipant {
+            id: format!("{}_{}", session_id, human_id),
+            user_id: String::new(),
+            session_id: session_id.clone(),
+            human_id,
+            source: "imported".to_string(),
+        });
+    }
+
+    let (words, speaker_hints) = cues_to_words(&session_id, &parsed.cues, &humans);
+    let start_ms = words.first().and_then(|w| w.start_ms);
+    let end_ms = words.last().and_then(|w| w.end_ms);
+    let started_at_ms = started_at.map(|dt| dt.timestamp_millis() as f64);
+
+    collection.sessions.push(Session {
+        id: session_id.clone(),
+        user_id: String::new(),
+        created_at: created_at.clone(),
+        title: title.clone(),
+        raw_md: None,
+        enhanced_content: None,
+        folder_id: None,
+        event_id: None,
+    });
+
+    collection.transcripts.push(Transcript {
+        id: session_id.clone(),
+        user_id: String::new(),
+        created_at,
+        session_id,
+        title,
+        started_at: started_at_ms.unwrap_or_default(),
+        ended_at: started_at_ms.zip(end_ms).map(|(start, end)| start + end),
+        start_ms,
+        end_ms,
+        words,
+        speaker_hints,
+    });
+
+    Ok(())
+}
+
+/// Splits cues into words, spreading each cue's duration over its words by
+/// length, and assigns named speakers to their [`Human`]s.
+fn cues_to_words(
+    session_id: &str,
+    cues: &[Cue],
+    humans: &HashMap<String, String>,
+) -> (Vec<Word>, Vec<SpeakerHint>) {
+    let mut words = Vec::new();
+    let mut hints = Vec::new();
+    let mut speaker_indices: HashMap<&str, usize> = HashMap::new();
+
+    for cue in cues {
+        let tokens: Vec<&str> = cue.text.split_whitespace().collect();
+        if tokens.is_empty() {
+            continue;
+        }
+
+        let end_ms = cue
+            .end_ms
+            .filter(|end| *end > cue.start_ms)
+            .unwrap_or(cue.start_ms + FALLBACK_MS_PER_WORD * tokens.len() as u64);
+        let duration = (end_ms - cue.start_ms) as f64;
+        let total_chars: usize = tokens.iter().map(|t| t.chars().count()).sum();
+
+        let mut offset = 0;
+        for token in tokens {
+            let id = format!("{}-{}", session_id, words.len());
+            let start = cue.start_ms as f64 + duration * offset as f64 / total_chars as f64;
+            offset += token.chars().count();
+            let end = cue.start_ms as f64 + duration * offset as f64 / total_chars as f64;
+
+            if let Some(label) = cue.speaker.as_deref() {
+                let value = match humans.get(label) {
+                    Some(human_id) => (
+                        "user_speaker_assignment",
+                        serde_json::json!({ "human_id": human_id }),
+                    ),
+                    None => {
+                        let next = speaker_indices.len();
+                        let index = *speaker_indices.entry(label).or_insert(next);
+                        (
+                            "provider_speaker_index",
+                            serde_json::json!({ "speaker_index": index }),
+                        )
+                    }
+                };
+                hints.push(SpeakerHint {
+                    word_id: id.clone(),
+                    hint_type: value.0.to_string(),
+                    value: value.1.to_string(),
+                });
+            }
+
+            words.push(Word {
+                id,
+                text: format!(" {}", token),
+                start_ms: Some(start.round()),
+                end_ms: Some(end.round()),
+                channel: 0,
+                speaker: cue.speaker.clone(),
+            });
+        }
+    }
+
+    (words, hints)
+}
+
+fn human_id(name: &str) -> String {
+    uuid::Uuid::new_v5(&uuid::Uuid::NAMESPACE_OID, name.to_lowercase().as_bytes()).to_string()
+}
+
+// `GMT20240301-100000_Recording.transcript.vtt` -> `GMT20240301-100000_Recording`
+fn file_title(path: &Path) -> String {
+    let name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    name.split('.').next().unwrap_or_default().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(dir: &Path, name: &str, content: &str) -> std::path::PathBuf {
+        let path = dir.join(name);
+        std::fs::write(&path, content).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_import_zoom_vtt() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = write(
+            dir.path(),
+            "GMT20240301-100000_Recording.transcript.vtt",
+            "WEBVTT\n\n1\n00:00:01.000 --> 00:00:02.000\nJane Doe: Hi team\n\n2\n00:00:02.000 --> 00:00:03.000\nSpeaker 2: Hello\n",
+        );
+
+        let collection = import_all_from_path(&path, Format::Zoom).unwrap();
+        let session = &collection.sessions[0];
+        assert_eq!(session.title, "GMT20240301-100000_Recording");
+        assert_eq!(session.created_at, "2024-03-01T10:00:00+00:00");
+
+        assert_eq!(collection.humans.len(), 1);
+        assert_eq!(collection.humans[0].name, "Jane Doe");
+        assert_eq!(collection.participants[0].session_id, session.id);
+
+        let transcript = &collection.transcripts[0];
+        let texts: Vec<_> = transcript.words.iter().map(|w| w.text.as_str()).collect();
+        assert_eq!(texts, vec![" Hi", " team", " Hello"]);
+        assert_eq!(transcript.words[0].start_ms, Some(1000.0));
+        assert_eq!(transcript.words[1].end_ms, Some(2000.0));
+        assert_eq!(transcript.started_at, 1_709_287_200_000.0);
+
+        assert_eq!(transcript.speaker_hints.len(), 3);
+        assert_eq!(
+            transcript.speaker_hints[0].value,
+            format!(r#"{{"human_id":"{}"}}"#, collection.humans[0].id)
+        );
+        assert_eq!(
+            transcript.speaker_hints[2].hint_type,
+            "provider_speaker_index"
+        );
+    }
+
+    #[test]
+    fn test_import_directory_skips_unsupported_and_empty() {
+        let dir = tempfile::tempdir().unwrap();
+        write(
+            dir.path(),
+            "a.srt",
+            "1\n00:00:00,000 --> 00:00:01,000\nHello\n",
+        );
+        write(dir.path(), "b.srt", "");
+        write(dir.path(), "notes.md", "# not a transcript");
+
+        let collection = import_all_from_path(dir.path(), Format::Subtitles).unwrap();
+        assert_eq!(collection.sessions.len(), 1);
+        assert_eq!(collection.sessions[0].title, "a");
+        assert!(collection.humans.is_empty());
+    }
+
+    #[test]
+    fn test_import_is_stable() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = write(
+            dir.path(),
+            "meeting.json",
+            r#"{"title":"Sync","sentences":[{"speaker_name":"Jane","text":"Hi","start_time":0,"end_time":1}]}"#,
+        );
+
+        let first = import_all_from_path(&path, Format::Fireflies).unwrap();
+        let second = import_all_from_path(&path, Format::Fireflies).unwrap();
+        assert_eq!(first.sessions[0].id, second.sessions[0].id);
+        assert_eq!(first.humans[0].id, second.humans[0].id);
+        assert_eq!(first.sessions[0].title, "Sync");
+    }
+
+    #[test]
+    fn test_import_unsupported_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = write(dir.path(), "meeting.txt", "hello");
+
+        assert!(matches!(
+            import_all_from_path(&path, Format::Zoom),
+            Err(Error::UnsupportedFile(_))
+        ));
+    }
+}
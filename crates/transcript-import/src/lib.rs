@@ -0,0 +1,11 @@
+pub mod error;
+pub mod fireflies;
+pub mod importer;
+pub mod otter;
+pub mod speaker;
+pub mod subtitle;
+pub mod zoom;
+
+pub use error::{Error, Result};
+pub use importer::{Format, import_all_from_path};
+pub use subtitle::Cue;
@@ -0,0 +1,176 @@
+use std::io::Read;
+use std::path::Path;
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::subtitle::{self, Cue, decode_entities};
+use crate::{Error, Result, speaker};
+
+// `Jane Doe  0:04` / `Speaker 1  1:02:03` / a bare `0:04` without speakers.
+static SEGMENT_HEADER: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^(?:(.+?)\s+)?(\d{1,2}:\d{2}(?::\d{2})?)$").unwrap());
+
+/// Reads an Otter.ai export. Otter offers TXT, DOCX and SRT downloads; the
+/// first two share the same "speaker + timestamp, then paragraph" layout.
+pub fn parse_file(path: &Path) -> Result<Vec<Cue>> {
+    let extension = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.to_lowercase());
+
+    match extension.as_deref() {
+        Some("txt") => Ok(parse_txt(&std::fs::read_to_string(path)?)),
+        Some("docx") => parse_docx(std::fs::File::open(path)?),
+        Some("srt") => {
+            let mut cues = subtitle::parse(&std::fs::read_to_string(path)?);
+            speaker::apply_labels(&mut cues, false);
+            Ok(cues)
+        }
+        _ => Err(Error::UnsupportedFile(path.display().to_string())),
+    }
+}
+
+pub fn parse_txt(input: &str) -> Vec<Cue> {
+    let input = input.trim_start_matches('\u{feff}').replace("\r\n", "\n");
+    let mut cues: Vec<Cue> = Vec::new();
+    let mut after_blank = true;
+
+    for line in input.lines().map(str::trim) {
+        if line.is_empty() {
+            after_blank = true;
+            continue;
+        }
+
+        let header = after_blank
+            .then(|| SEGMENT_HEADER.captures(line))
+            .flatten()
+            .and_then(|caps| {
+                let start_ms = subtitle::parse_timestamp(&caps[2])?;
+                Some((caps.get(1).map(|m| m.as_str().to_string()), start_ms))
+            });
+        after_blank = false;
+
+        if let Some((speaker, start_ms)) = header {
+            cues.push(Cue {
+                start_ms,
+                end_ms: None,
+                speaker,
+                text: String::new(),
+            });
+            continue;
+        }
+
+        // Otter appends a "Transcribed by https://otter.ai" footer.
+        if line.starts_with("Transcribed by") {
+            continue;
+        }
+
+        if let Some(cue) = cues.last_mut() {
+            if !cue.text.is_empty() {
+                cue.text.push(' ');
+            }
+            cue.text.push_str(line);
+        }
+    }
+
+    cues.retain(|c| !c.text.is_empty());
+
+    // Otter only stamps where a paragraph starts.
+    let starts: Vec<u64> = cues.iter().skip(1).map(|c| c.start_ms).collect();
+    for (cue, next_start) in cues.iter_mut().zip(starts) {
+        cue.end_ms = Some(next_start.max(cue.start_ms));
+    }
+
+    cues
+}
+
+pub fn parse_docx(reader: impl std::io::Read + std::io::Seek) -> Result<Vec<Cue>> {
+    let mut archive = zip::ZipArchive::new(reader)?;
+    let mut xml = String::new();
+    archive
+        .by_name("word/document.xml")?
+        .read_to_string(&mut xml)?;
+
+    Ok(parse_txt(&document_paragraphs(&xml).join("\n\n")))
+}
+
+/// Plain text of each `<w:p>` in a WordprocessingML body.
+fn document_paragraphs(xml: &str) -> Vec<String> {
+    let mut paragraphs = Vec::new();
+    let mut current = String::new();
+    let mut rest = xml;
+
+    while let Some(open) = rest.find('<') {
+        let Some(close) = rest[open..].find('>').map(|i| open + i) else {
+            break;
+        };
+        let tag = &rest[open + 1..close];
+        let name = tag
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default();
+        let after = &rest[close + 1..];
+        rest = after;
+
+        match name {
+            "w:t" if !tag.ends_with('/') => {
+                let end = after.find("</w:t>").unwrap_or(after.len());
+                current.push_str(&decode_entities(&after[..end]));
+                rest = &after[end..];
+            }
+            "w:tab" => current.push('\t'),
+            "w:br" | "w:cr" => current.push('\n'),
+            // Closing tags have an empty name before the `/`.
+            "" if tag.trim_end() == "/w:p" => {
+                paragraphs.push(std::mem::take(&mut current));
+            }
+            _ => {}
+        }
+    }
+
+    if !current.is_empty() {
+        paragraphs.push(current);
+    }
+    paragraphs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TXT: &str = "Jane Doe  0:00\nWelcome everyone.\nLet's start.\n\nJohn Smith  1:05\nThanks, Jane.\n\nJane Doe  1:01:02\nWrapping up.\n\nTranscribed by https://otter.ai\n";
+
+    #[test]
+    fn test_parse_txt() {
+        let cues = parse_txt(TXT);
+
+        assert_eq!(cues.len(), 3);
+        assert_eq!(cues[0].speaker.as_deref(), Some("Jane Doe"));
+        assert_eq!(cues[0].text, "Welcome everyone. Let's start.");
+        assert_eq!(cues[0].end_ms, Some(65_000));
+        assert_eq!(cues[1].speaker.as_deref(), Some("John Smith"));
+        assert_eq!(cues[2].start_ms, 3_662_000);
+        assert_eq!(cues[2].end_ms, None);
+        assert_eq!(cues[2].text, "Wrapping up.");
+    }
+
+    #[test]
+    fn test_parse_txt_without_speakers() {
+        let cues = parse_txt("0:00\nHello\n\n0:10\nWorld\n");
+
+        assert_eq!(cues.len(), 2);
+        assert_eq!(cues[0].speaker, None);
+        assert_eq!(cues[1].start_ms, 10_000);
+    }
+
+    #[test]
+    fn test_document_paragraphs() {
+        let xml = r#"<w:document><w:body><w:p><w:r><w:t>Jane Doe</w:t></w:r><w:r><w:tab/><w:t xml:space="preserve">0:00</w:t></w:r></w:p><w:p><w:pPr/><w:r><w:t>Q&amp;A first.</w:t></w:r></w:p></w:body></w:document>"#;
+
+        assert_eq!(
+            document_paragraphs(xml),
+            vec!["Jane Doe\t0:00".to_string(), "Q&A first.".to_string()]
+        );
+    }
+}
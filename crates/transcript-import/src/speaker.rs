@@ -0,0 +1,95 @@
+use crate::Cue;
+
+const MAX_LABEL_CHARS: usize = 40;
+const MAX_LABEL_WORDS: usize = 4;
+
+/// Splits a `Jane Doe: text` style speaker prefix off a line.
+pub fn split_label(text: &str) -> Option<(&str, &str)> {
+    let (label, rest) = text.split_once(": ")?;
+    let label = label.trim();
+
+    let plausible = !label.is_empty()
+        && label.chars().count() <= MAX_LABEL_CHARS
+        && label.split_whitespace().count() <= MAX_LABEL_WORDS
+        && label.chars().next().is_some_and(char::is_alphabetic)
+        && !label.contains(['.', ',', '?', '!', '"', '(', ')']);
+
+    plausible.then(|| (label, rest.trim()))
+}
+
+/// Moves `Name: ` prefixes into [`Cue::speaker`].
+///
+/// Without `force`, prefixes are only treated as labels when most cues have
+/// one, so a stray "Note: ..." in an unlabelled file stays part of the text.
+pub fn apply_labels(cues: &mut [Cue], force: bool) {
+    let labelled = cues
+        .iter()
+        .filter(|c| c.speaker.is_none() && split_label(&c.text).is_some())
+        .count();
+    let unlabelled = cues.iter().filter(|c| c.speaker.is_none()).count();
+
+    if labelled == 0 || (!force && labelled * 2 < unlabelled) {
+        return;
+    }
+
+    for cue in cues.iter_mut().filter(|c| c.speaker.is_none()) {
+        if let Some((label, rest)) = split_label(&cue.text) {
+            let (label, rest) = (label.to_string(), rest.to_string());
+            cue.speaker = Some(label);
+            cue.text = rest;
+        }
+    }
+}
+
+/// Labels like "Speaker 1" or "Unknown Speaker" name a voice, not a person,
+/// so they are kept on words but don't become contacts.
+pub fn is_generic(label: &str) -> bool {
+    let label = label.to_lowercase();
+    let label = label.trim_end_matches(|c: char| c.is_ascii_digit() || c == ' ');
+    matches!(
+        label,
+        "speaker" | "unknown" | "unknown speaker" | "guest" | "participant"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cue(text: &str) -> Cue {
+        Cue {
+            start_ms: 0,
+            end_ms: None,
+            speaker: None,
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_split_label() {
+        assert_eq!(
+            split_label("Jane Doe: Hello there"),
+            Some(("Jane Doe", "Hello there"))
+        );
+        assert_eq!(split_label("Well, as I said: no"), None);
+        assert_eq!(split_label("No label here"), None);
+    }
+
+    #[test]
+    fn test_apply_labels_needs_majority() {
+        let mut cues = vec![cue("Note: this is fine"), cue("Plain"), cue("Also plain")];
+        apply_labels(&mut cues, false);
+        assert!(cues.iter().all(|c| c.speaker.is_none()));
+
+        apply_labels(&mut cues, true);
+        assert_eq!(cues[0].speaker.as_deref(), Some("Note"));
+        assert_eq!(cues[0].text, "this is fine");
+    }
+
+    #[test]
+    fn test_is_generic() {
+        assert!(is_generic("Speaker 12"));
+        assert!(is_generic("Unknown Speaker"));
+        assert!(!is_generic("Jane Doe"));
+    }
+}
@@ -0,0 +1,152 @@
+/// A timed piece of transcript text, as found in subtitle files and most
+/// meeting tool exports.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cue {
+    pub start_ms: u64,
+    /// Unknown for exports that only timestamp where each paragraph starts.
+    pub end_ms: Option<u64>,
+    pub speaker: Option<String>,
+    pub text: String,
+}
+
+/// Parses WebVTT or SRT cues. The two formats only differ in their header
+/// and decimal separator, so one parser handles both; blocks without a
+/// timing line (`WEBVTT`, `NOTE`, `STYLE`, ...) are skipped.
+pub fn parse(input: &str) -> Vec<Cue> {
+    let input = input.trim_start_matches('\u{feff}').replace("\r\n", "\n");
+
+    input
+        .split("\n\n")
+        .filter_map(|block| {
+            let mut lines = block.lines().skip_while(|line| !line.contains("-->"));
+            let (start, end) = lines.next()?.split_once("-->")?;
+            let start_ms = parse_timestamp(start.trim())?;
+            // WebVTT allows cue settings after the end time.
+            let end_ms = parse_timestamp(end.split_whitespace().next()?)?;
+
+            let raw = lines.collect::<Vec<_>>().join(" ");
+            let (speaker, raw) = match voice_span(&raw) {
+                Some((speaker, rest)) => (Some(speaker), rest),
+                None => (None, raw.as_str()),
+            };
+
+            let text = decode_entities(&strip_tags(raw)).trim().to_string();
+            if text.is_empty() {
+                return None;
+            }
+
+            Some(Cue {
+                start_ms,
+                end_ms: Some(end_ms),
+                speaker,
+                text,
+            })
+        })
+        .collect()
+}
+
+/// Parses `HH:MM:SS.mmm`, `MM:SS.mmm` and their SRT (`,`) and
+/// fraction-less variants into milliseconds.
+pub fn parse_timestamp(value: &str) -> Option<u64> {
+    let value = value.replace(',', ".");
+    let (clock, fraction) = value.split_once('.').unwrap_or((&value, ""));
+
+    let parts = clock
+        .split(':')
+        .map(|p| p.trim().parse::<u64>().ok())
+        .collect::<Option<Vec<_>>>()?;
+    let seconds = match parts.as_slice() {
+        [h, m, s] => h * 3600 + m * 60 + s,
+        [m, s] => m * 60 + s,
+        _ => return None,
+    };
+
+    let millis = if fraction.is_empty() {
+        0
+    } else {
+        let digits: String = fraction.chars().chain("000".chars()).take(3).collect();
+        digits.parse::<u64>().ok()?
+    };
+
+    Some(seconds * 1000 + millis)
+}
+
+// `<v Jane Doe>Hello</v>` or `<v.loud Jane Doe>Hello`.
+fn voice_span(text: &str) -> Option<(String, &str)> {
+    let rest = text.trim_start().strip_prefix("<v")?;
+    let (tag, rest) = rest.split_once('>')?;
+    let name = tag.split_once(' ').map(|(_, name)| name.trim())?;
+    (!name.is_empty()).then(|| (name.to_string(), rest))
+}
+
+fn strip_tags(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut in_tag = false;
+    for c in text.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => out.push(c),
+            _ => {}
+        }
+    }
+    out
+}
+
+pub(crate) fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&nbsp;", " ")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_timestamp() {
+        assert_eq!(parse_timestamp("00:01:02.345"), Some(62_345));
+        assert_eq!(parse_timestamp("01:02.5"), Some(62_500));
+        assert_eq!(parse_timestamp("1:00:00,001"), Some(3_600_001));
+        assert_eq!(parse_timestamp("0:15"), Some(15_000));
+        assert_eq!(parse_timestamp("abc"), None);
+    }
+
+    #[test]
+    fn test_parse_vtt() {
+        let input = "WEBVTT\n\nNOTE exported\n\n1\n00:00:01.000 --> 00:00:04.000 align:start\n<v Jane Doe>Hello <b>there</b></v>\n\n00:00:04.500 --> 00:00:06.000\nQ&amp;A\nlater\n";
+
+        assert_eq!(
+            parse(input),
+            vec![
+                Cue {
+                    start_ms: 1000,
+                    end_ms: Some(4000),
+                    speaker: Some("Jane Doe".to_string()),
+                    text: "Hello there".to_string(),
+                },
+                Cue {
+                    start_ms: 4500,
+                    end_ms: Some(6000),
+                    speaker: None,
+                    text: "Q&A later".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_srt() {
+        let input = "\u{feff}1\r\n00:00:00,500 --> 00:00:02,000\r\n<i>First</i>\r\n\r\n2\r\n00:00:02,000 --> 00:00:03,250\r\nSecond\r\n";
+
+        let cues = parse(input);
+        assert_eq!(cues.len(), 2);
+        assert_eq!(cues[0].text, "First");
+        assert_eq!(cues[1].start_ms, 2000);
+        assert_eq!(cues[1].end_ms, Some(3250));
+    }
+}
@@ -0,0 +1,53 @@
+use std::path::Path;
+use std::sync::LazyLock;
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+use regex::Regex;
+
+use crate::{Cue, speaker, subtitle};
+
+// Cloud recordings download as `GMT20240301-100000_Recording.transcript.vtt`.
+static RECORDING_TIME: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"GMT(\d{8})-(\d{6})").unwrap());
+
+/// Parses a Zoom cloud-recording transcript. Zoom writes plain WebVTT with
+/// the speaker as a `Name: ` prefix on every cue.
+pub fn parse(input: &str) -> Vec<Cue> {
+    let mut cues = subtitle::parse(input);
+    speaker::apply_labels(&mut cues, true);
+    cues
+}
+
+/// Recording start time encoded in a Zoom download's file name.
+pub fn recording_started_at(path: &Path) -> Option<DateTime<Utc>> {
+    let name = path.file_name()?.to_str()?;
+    let caps = RECORDING_TIME.captures(name)?;
+    NaiveDateTime::parse_from_str(&format!("{}{}", &caps[1], &caps[2]), "%Y%m%d%H%M%S")
+        .ok()
+        .map(|naive| naive.and_utc())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let input = "WEBVTT\n\n1\n00:00:01.000 --> 00:00:03.500\nJane Doe: Can everyone hear me?\n\n2\n00:00:04.000 --> 00:00:05.000\nYes.\n";
+
+        let cues = parse(input);
+        assert_eq!(cues[0].speaker.as_deref(), Some("Jane Doe"));
+        assert_eq!(cues[0].text, "Can everyone hear me?");
+        assert_eq!(cues[1].speaker, None);
+    }
+
+    #[test]
+    fn test_recording_started_at() {
+        let path = Path::new("/tmp/GMT20240301-100000_Recording.transcript.vtt");
+        assert_eq!(
+            recording_started_at(path).unwrap().to_rfc3339(),
+            "2024-03-01T10:00:00+00:00"
+        );
+        assert_eq!(recording_started_at(Path::new("meeting.vtt")), None);
+    }
+}
@@ -0,0 +1,23 @@
+//! `tests/data/fireflies.json` is a GraphQL `transcript` response, which
+//! carries both `text` and `raw_text` on every sentence.
+
+use transcript_import::fireflies::parse;
+
+const FIXTURE: &str = include_str!("data/fireflies.json");
+
+#[test]
+fn test_parse_prefers_text_over_raw_text() {
+    let transcript = parse(FIXTURE).unwrap();
+
+    assert_eq!(transcript.title.as_deref(), Some("Design review"));
+    let texts: Vec<_> = transcript.cues.iter().map(|c| c.text.as_str()).collect();
+    assert_eq!(
+        texts,
+        vec![
+            "Let's look at the new onboarding flow.",
+            "Sounds good.",
+            "The first step drops a third of users.",
+        ]
+    );
+    assert_eq!(transcript.cues[1].speaker.as_deref(), Some("John Smith"));
+}
@@ -2,10 +2,12 @@ import type { TablesSchema } from "tinybase/with-schemas";
 
 import type { InferTinyBaseSchema } from "./shared";
 import {
+  actionItemSchema,
   calendarSchema,
   chatGroupSchema,
   chatMessageSchema,
   chatShortcutSchema,
+  decisionSchema,
   enhancedNoteSchema,
   eventSchema,
   generalSchema,
@@ -18,6 +20,7 @@ import {
   sessionSchema,
   tagSchema,
   templateSchema,
+  templateSnippetSchema,
   transcriptSchema,
 } from "./zod";
 
@@ -79,6 +82,7 @@ export const tableSchemaForTinybase = {
     recurrence_series_id: { type: "string" },
     has_recurrence_rules: { type: "boolean" },
     is_all_day: { type: "boolean" },
+    participants_json: { type: "string" },
   } as const satisfies InferTinyBaseSchema<typeof eventSchema>,
   mapping_session_participant: {
     user_id: { type: "string" },
@@ -109,7 +113,13 @@ export const tableSchemaForTinybase = {
     title: { type: "string" },
     description: { type: "string" },
     sections: { type: "string" },
+    body: { type: "string" },
   } as const satisfies InferTinyBaseSchema<typeof templateSchema>,
+  template_snippets: {
+    user_id: { type: "string" },
+    name: { type: "string" },
+    content: { type: "string" },
+  } as const satisfies InferTinyBaseSchema<typeof templateSnippetSchema>,
   chat_groups: {
     user_id: { type: "string" },
     created_at: { type: "string" },
@@ -132,6 +142,23 @@ export const tableSchemaForTinybase = {
     position: { type: "number" },
     title: { type: "string" },
   } as const satisfies InferTinyBaseSchema<typeof enhancedNoteSchema>,
+  action_items: {
+    user_id: { type: "string" },
+    created_at: { type: "string" },
+    session_id: { type: "string" },
+    enhanced_note_id: { type: "string" },
+    description: { type: "string" },
+    owner_human_id: { type: "string" },
+    due_date: { type: "string" },
+    status: { type: "string" },
+  } as const satisfies InferTinyBaseSchema<typeof actionItemSchema>,
+  decisions: {
+    user_id: { type: "string" },
+    created_at: { type: "string" },
+    session_id: { type: "string" },
+    enhanced_note_id: { type: "string" },
+    description: { type: "string" },
+  } as const satisfies InferTinyBaseSchema<typeof decisionSchema>,
   prompts: {
     user_id: { type: "string" },
     task_type: { type: "string" },
@@ -31,6 +31,13 @@ export const sessionEventSchema = z.object({
   recurrence_series_id: z.string().optional(),
 });
 
+export const eventParticipantSchema = z.object({
+  name: z.string().optional(),
+  email: z.string().optional(),
+  is_organizer: z.boolean().optional(),
+  is_current_user: z.boolean().optional(),
+});
+
 export const ignoredEventEntrySchema = z.discriminatedUnion("is_recurrent", [
   z.object({
     tracking_id: z.string(),
@@ -71,9 +78,19 @@ export const eventSchema = z.object({
     z.boolean().optional(),
   ),
   is_all_day: z.preprocess((val) => val ?? undefined, z.boolean().optional()),
+  participants_json: z.preprocess(
+    (val) => val ?? undefined,
+    z.string().optional(),
+  ),
 });
 
-export const calendarProviderSchema = z.enum(["apple", "google", "outlook"]);
+export const calendarProviderSchema = z.enum([
+  "apple",
+  "google",
+  "outlook",
+  "caldav",
+  "ics",
+]);
 export type CalendarProvider = z.infer<typeof calendarProviderSchema>;
 
 export const calendarSchema = z.object({
@@ -168,6 +185,13 @@ export const templateSchema = z.object({
     jsonObject(z.array(z.string())).optional(),
   ),
   sections: jsonObject(z.array(templateSectionSchema)),
+  body: z.preprocess((val) => val ?? undefined, z.string().optional()),
+});
+
+export const templateSnippetSchema = z.object({
+  user_id: z.string(),
+  name: z.string(),
+  content: z.string(),
 });
 
 export const chatGroupSchema = z.object({
@@ -201,6 +225,37 @@ export const enhancedNoteSchema = z.object({
   title: z.preprocess((val) => val ?? undefined, z.string().optional()),
 });
 
+export const actionItemStatusSchema = z.enum(["open", "done"]);
+export type ActionItemStatus = z.infer<typeof actionItemStatusSchema>;
+
+export const actionItemSchema = z.object({
+  user_id: z.string(),
+  created_at: z.string(),
+  session_id: z.string(),
+  enhanced_note_id: z.preprocess(
+    (val) => val ?? undefined,
+    z.string().optional(),
+  ),
+  description: z.string(),
+  owner_human_id: z.preprocess(
+    (val) => val ?? undefined,
+    z.string().optional(),
+  ),
+  due_date: z.preprocess((val) => val ?? undefined, z.string().optional()),
+  status: actionItemStatusSchema,
+});
+
+export const decisionSchema = z.object({
+  user_id: z.string(),
+  created_at: z.string(),
+  session_id: z.string(),
+  enhanced_note_id: z.preprocess(
+    (val) => val ?? undefined,
+    z.string().optional(),
+  ),
+  description: z.string(),
+});
+
 export const promptSchema = z.object({
   user_id: z.string(),
   task_type: z.string(),
@@ -236,6 +291,9 @@ export const generalSchema = z.object({
   autostart: z.boolean().default(false),
   telemetry_consent: z.boolean().default(true),
   save_recordings: z.boolean().default(true),
+  recording_format: z.string().optional(),
+  recording_stems: z.string().optional(),
+  speaker_separation: z.boolean().default(false),
   notification_event: z.boolean().default(true),
   notification_detect: z.boolean().default(true),
   respect_dnd: z.boolean().default(false),
@@ -269,12 +327,21 @@ export const aiProviderSchema = z
     },
   );
 
+export const calendarAccountSchema = z.object({
+  kind: z.enum(["caldav", "ics"]),
+  name: z.string(),
+  url: z.url(),
+  username: z.string(),
+  password: z.string(),
+});
+
 export type ProviderSpeakerIndexHint = z.infer<
   typeof providerSpeakerIndexSchema
 >;
 
 export type Human = z.infer<typeof humanSchema>;
 export type SessionEvent = z.infer<typeof sessionEventSchema>;
+export type EventParticipant = z.infer<typeof eventParticipantSchema>;
 export type IgnoredEvent = z.infer<typeof ignoredEventEntrySchema>;
 export type IgnoredRecurringSeries = z.infer<
   typeof ignoredRecurringSeriesEntrySchema
@@ -295,12 +362,16 @@ export type MappingTagSession = z.infer<typeof mappingTagSessionSchema>;
 export type MappingMention = z.infer<typeof mappingMentionSchema>;
 export type Template = z.infer<typeof templateSchema>;
 export type TemplateSection = z.infer<typeof templateSectionSchema>;
+export type TemplateSnippet = z.infer<typeof templateSnippetSchema>;
 export type ChatGroup = z.infer<typeof chatGroupSchema>;
 export type ChatMessage = z.infer<typeof chatMessageSchema>;
 export type ChatShortcut = z.infer<typeof chatShortcutSchema>;
 export type EnhancedNote = z.infer<typeof enhancedNoteSchema>;
+export type ActionItem = z.infer<typeof actionItemSchema>;
+export type Decision = z.infer<typeof decisionSchema>;
 export type Prompt = z.infer<typeof promptSchema>;
 export type AIProvider = z.infer<typeof aiProviderSchema>;
+export type CalendarAccount = z.infer<typeof calendarAccountSchema>;
 export type General = z.infer<typeof generalSchema>;
 
 export type SessionStorage = ToStorageType<typeof sessionSchema>;
@@ -308,8 +379,13 @@ export type TranscriptStorage = ToStorageType<typeof transcriptSchema>;
 export type WordStorage = ToStorageType<typeof wordSchema>;
 export type SpeakerHintStorage = ToStorageType<typeof speakerHintSchema>;
 export type TemplateStorage = ToStorageType<typeof templateSchema>;
+export type TemplateSnippetStorage = ToStorageType<
+  typeof templateSnippetSchema
+>;
 export type ChatMessageStorage = ToStorageType<typeof chatMessageSchema>;
 export type EnhancedNoteStorage = ToStorageType<typeof enhancedNoteSchema>;
+export type ActionItemStorage = ToStorageType<typeof actionItemSchema>;
+export type DecisionStorage = ToStorageType<typeof decisionSchema>;
 export type HumanStorage = ToStorageType<typeof humanSchema>;
 export type OrganizationStorage = ToStorageType<typeof organizationSchema>;
 export type PromptStorage = ToStorageType<typeof promptSchema>;
@@ -18,7 +18,9 @@ import { AIHighlight } from "../ai-highlight";
 import { StreamingAnimation } from "../animation";
 import { ClipboardTextSerializer } from "../clipboard";
 import CustomListKeymap from "../custom-list-keymap";
+import { FootnoteDefinition, FootnoteReference } from "../footnote";
 import { Hashtag } from "../hashtag";
+import { Timestamp } from "../timestamp";
 import { Placeholder, type PlaceholderFunction } from "./placeholder";
 import { SearchAndReplace } from "./search-and-replace";
 
@@ -188,6 +190,9 @@ export const getExtensions = (
   TableHeader,
   TableCell,
   Highlight,
+  FootnoteReference,
+  FootnoteDefinition,
+  Timestamp,
   AIHighlight,
   CustomListKeymap,
   StreamingAnimation,
@@ -0,0 +1,42 @@
+import { getSchema, type JSONContent } from "@tiptap/core";
+import { describe, expect, test } from "vitest";
+
+import kitchenSink from "../../../../../crates/tiptap/tests/data/kitchen-sink.json";
+import { mention } from "../../editor/mention";
+import { getExtensions } from ".";
+
+// The fixture is what the Rust markdown parser (crates/tiptap) produces for
+// notes read from the vault; every type in it must be registered here.
+describe("markdown parser output", () => {
+  const schema = getSchema([
+    ...getExtensions(),
+    mention({ trigger: "@", handleSearch: async () => [] }),
+  ]);
+
+  const collectTypes = (
+    node: JSONContent,
+    nodes: Set<string>,
+    marks: Set<string>,
+  ) => {
+    if (node.type) {
+      nodes.add(node.type);
+    }
+    node.marks?.forEach((mark) => marks.add(mark.type));
+    node.content?.forEach((child) => collectTypes(child, nodes, marks));
+  };
+
+  test("only uses registered node and mark types", () => {
+    const nodes = new Set<string>();
+    const marks = new Set<string>();
+    collectTypes(kitchenSink as JSONContent, nodes, marks);
+
+    expect([...nodes].filter((type) => !schema.nodes[type])).toEqual([]);
+    expect([...marks].filter((type) => !schema.marks[type])).toEqual([]);
+  });
+
+  test("is valid against the editor schema", () => {
+    expect(() =>
+      schema.nodeFromJSON(kitchenSink as JSONContent).check(),
+    ).not.toThrow();
+  });
+});
@@ -0,0 +1,76 @@
+import { mergeAttributes, Node } from "@tiptap/core";
+
+// Footnotes come from markdown notes (`[^1]` and `[^1]: ...`). They are kept
+// so the markdown round-trips, but there is no UI for adding them.
+
+export const FootnoteReference = Node.create({
+  name: "footnoteReference",
+  group: "inline",
+  inline: true,
+  atom: true,
+
+  addAttributes() {
+    return {
+      id: {
+        default: null,
+        parseHTML: (element) => element.getAttribute("data-footnote-id"),
+        renderHTML: (attributes) => ({
+          "data-footnote-id": attributes.id,
+        }),
+      },
+    };
+  },
+
+  parseHTML() {
+    return [{ tag: "sup[data-footnote-id]" }];
+  },
+
+  renderHTML({ node, HTMLAttributes }) {
+    return [
+      "sup",
+      mergeAttributes(HTMLAttributes, { class: "footnote-reference" }),
+      `[${node.attrs.id}]`,
+    ];
+  },
+
+  renderText({ node }) {
+    return `[${node.attrs.id}]`;
+  },
+
+  renderMarkdown: (node: { attrs?: { id?: string } }) =>
+    `[^${node.attrs?.id ?? ""}]`,
+});
+
+export const FootnoteDefinition = Node.create({
+  name: "footnoteDefinition",
+  group: "block",
+  content: "block+",
+  defining: true,
+
+  addAttributes() {
+    return {
+      id: {
+        default: null,
+        parseHTML: (element) => element.getAttribute("data-footnote-id"),
+        renderHTML: (attributes) => ({
+          "data-footnote-id": attributes.id,
+        }),
+      },
+    };
+  },
+
+  parseHTML() {
+    return [{ tag: "div[data-footnote-id]" }];
+  },
+
+  renderHTML({ HTMLAttributes }) {
+    return [
+      "div",
+      mergeAttributes(HTMLAttributes, { class: "footnote-definition" }),
+      0,
+    ];
+  },
+
+  renderMarkdown: (node, helpers) =>
+    `[^${node.attrs?.id ?? ""}]: ${helpers.renderChildren(node.content ?? [], "\n\n")}`,
+});
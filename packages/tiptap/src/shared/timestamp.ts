@@ -0,0 +1,49 @@
+import { mergeAttributes, Node } from "@tiptap/core";
+
+// A point in the session's transcript, written to markdown as
+// `[01:02](timestamp:62000)`.
+export const Timestamp = Node.create({
+  name: "timestamp",
+  group: "inline",
+  inline: true,
+  atom: true,
+
+  addAttributes() {
+    return {
+      ms: {
+        default: 0,
+        parseHTML: (element) => Number(element.getAttribute("data-ms") ?? 0),
+        renderHTML: (attributes) => ({ "data-ms": attributes.ms }),
+      },
+    };
+  },
+
+  parseHTML() {
+    return [{ tag: "span[data-ms]" }];
+  },
+
+  renderHTML({ node, HTMLAttributes }) {
+    return [
+      "span",
+      mergeAttributes(HTMLAttributes, { class: "transcript-timestamp" }),
+      formatTimestamp(node.attrs.ms),
+    ];
+  },
+
+  renderText({ node }) {
+    return formatTimestamp(node.attrs.ms);
+  },
+
+  renderMarkdown: (node: { attrs?: { ms?: number } }) => {
+    const ms = node.attrs?.ms ?? 0;
+    return `[${formatTimestamp(ms)}](timestamp:${ms})`;
+  },
+});
+
+export function formatTimestamp(ms: number): string {
+  const seconds = Math.floor(ms / 1000);
+  const hours = Math.floor(seconds / 3600);
+  const mm = String(Math.floor(seconds / 60) % 60).padStart(2, "0");
+  const ss = String(seconds % 60).padStart(2, "0");
+  return hours > 0 ? `${hours}:${mm}:${ss}` : `${mm}:${ss}`;
+}
@@ -0,0 +1,116 @@
+// @ts-nocheck
+
+// This file was generated by [tauri-specta](https://github.com/oscartbeaumont/tauri-specta). Do not edit this file manually.
+
+/** user-defined commands **/
+
+
+export const commands = {
+async listCalendars(account: CalendarAccount) : Promise<Result<RemoteCalendar[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:caldav|list_calendars", { account }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async listEvents(account: CalendarAccount, filter: EventFilter) : Promise<Result<RemoteEvent[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:caldav|list_events", { account, filter }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+}
+}
+
+/** user-defined events **/
+
+
+
+/** user-defined constants **/
+
+
+
+/** user-defined types **/
+
+export type Attendee = { name: string | null; email: string | null }
+/**
+ * Credentials are kept in the app's settings and passed with every call.
+ */
+export type CalendarAccount = { type: "caldav"; url: string; username: string | null; password: string | null } | { type: "ics"; url: string }
+export type EventFilter = { from: string; to: string; calendar_id: string; 
+/**
+ * Zone for all-day events and floating times. Defaults to UTC.
+ */
+timezone: string | null }
+export type RemoteCalendar = { 
+/**
+ * The collection URL, or the feed URL for an ICS subscription.
+ */
+id: string; name: string }
+export type RemoteEvent = { uid: string; calendar_id: string; title: string | null; start_date: string; end_date: string; is_all_day: boolean; location: string | null; description: string | null; url: string | null; attendees: Attendee[]; 
+/**
+ * Set on every instance of a recurring event.
+ */
+original_start: string | null }
+
+/** tauri-specta globals **/
+
+import {
+	invoke as TAURI_INVOKE,
+	Channel as TAURI_CHANNEL,
+} from "@tauri-apps/api/core";
+import * as TAURI_API_EVENT from "@tauri-apps/api/event";
+import { type WebviewWindow as __WebviewWindow__ } from "@tauri-apps/api/webviewWindow";
+
+type __EventObj__<T> = {
+	listen: (
+		cb: TAURI_API_EVENT.EventCallback<T>,
+	) => ReturnType<typeof TAURI_API_EVENT.listen<T>>;
+	once: (
+		cb: TAURI_API_EVENT.EventCallback<T>,
+	) => ReturnType<typeof TAURI_API_EVENT.once<T>>;
+	emit: null extends T
+		? (payload?: T) => ReturnType<typeof TAURI_API_EVENT.emit>
+		: (payload: T) => ReturnType<typeof TAURI_API_EVENT.emit>;
+};
+
+export type Result<T, E> =
+	| { status: "ok"; data: T }
+	| { status: "error"; error: E };
+
+function __makeEvents__<T extends Record<string, any>>(
+	mappings: Record<keyof T, string>,
+) {
+	return new Proxy(
+		{} as unknown as {
+			[K in keyof T]: __EventObj__<T[K]> & {
+				(handle: __WebviewWindow__): __EventObj__<T[K]>;
+			};
+		},
+		{
+			get: (_, event) => {
+				const name = mappings[event as keyof T];
+
+				return new Proxy((() => {}) as any, {
+					apply: (_, __, [window]: [__WebviewWindow__]) => ({
+						listen: (arg: any) => window.listen(name, arg),
+						once: (arg: any) => window.once(name, arg),
+						emit: (arg: any) => window.emit(name, arg),
+					}),
+					get: (_, command: keyof __EventObj__<any>) => {
+						switch (command) {
+							case "listen":
+								return (arg: any) => TAURI_API_EVENT.listen(name, arg);
+							case "once":
+								return (arg: any) => TAURI_API_EVENT.once(name, arg);
+							case "emit":
+								return (arg: any) => TAURI_API_EVENT.emit(name, arg);
+						}
+					},
+				});
+			},
+		},
+	);
+}
@@ -0,0 +1,27 @@
+use crate::CalDavPluginExt;
+use crate::types::{CalendarAccount, EventFilter, RemoteCalendar, RemoteEvent};
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn list_calendars<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    account: CalendarAccount,
+) -> Result<Vec<RemoteCalendar>, String> {
+    app.caldav()
+        .list_calendars(account)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn list_events<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    account: CalendarAccount,
+    filter: EventFilter,
+) -> Result<Vec<RemoteEvent>, String> {
+    app.caldav()
+        .list_events(account, filter)
+        .await
+        .map_err(|e| e.to_string())
+}
@@ -0,0 +1,145 @@
+use hypr_caldav::{CalDavClient, Window, collection_events, feed_url, refresh_feed, to_events};
+
+use crate::types::{CalendarAccount, EventFilter, RemoteCalendar, RemoteEvent};
+use crate::{CalDavState, Error};
+
+pub struct CalDav<'a, R: tauri::Runtime, M: tauri::Manager<R>> {
+    manager: &'a M,
+    _runtime: std::marker::PhantomData<fn() -> R>,
+}
+
+impl<'a, R: tauri::Runtime, M: tauri::Manager<R>> CalDav<'a, R, M> {
+    pub async fn list_calendars(
+        &self,
+        account: CalendarAccount,
+    ) -> Result<Vec<RemoteCalendar>, crate::Error> {
+        match account {
+            CalendarAccount::CalDav {
+                url,
+                username,
+                password,
+            } => {
+                let client = client(&url, username, password)?;
+                Ok(client
+                    .discover()
+                    .await?
+                    .into_iter()
+                    .map(|collection| RemoteCalendar {
+                        id: collection.url.to_string(),
+                        name: collection
+                            .name
+                            .unwrap_or_else(|| collection.url.path().to_string()),
+                    })
+                    .collect())
+            }
+            CalendarAccount::Ics { url } => {
+                let url = feed_url(&url)?;
+                let id = url.to_string();
+
+                let state = self.manager.state::<CalDavState>();
+                let mut feed = state.feeds.lock().unwrap().remove(&id).unwrap_or_default();
+                let name = refresh_feed(&state.http, url.as_str(), &mut feed)
+                    .await
+                    .map(|ics| ics.name.clone());
+                state.feeds.lock().unwrap().insert(id.clone(), feed);
+
+                let name = name?
+                    .or_else(|| url.host_str().map(str::to_string))
+                    .unwrap_or_else(|| id.clone());
+                Ok(vec![RemoteCalendar { id, name }])
+            }
+        }
+    }
+
+    /// Occurrences in the filter's range, with recurring events expanded.
+    ///
+    /// CalDAV collections are synced incrementally with their `sync-token`, and
+    /// ICS feeds are only downloaded again when their ETag changes.
+    pub async fn list_events(
+        &self,
+        account: CalendarAccount,
+        filter: EventFilter,
+    ) -> Result<Vec<RemoteEvent>, crate::Error> {
+        let window = Window {
+            from: filter.from,
+            to: filter.to,
+            default_tz: filter
+                .timezone
+                .as_deref()
+                .and_then(|tz| tz.parse().ok())
+                .unwrap_or(chrono_tz::UTC),
+        };
+        let id = filter.calendar_id;
+        let state = self.manager.state::<CalDavState>();
+
+        let events = match account {
+            CalendarAccount::CalDav {
+                url,
+                username,
+                password,
+            } => {
+                let client = client(&url, username, password)?;
+                let collection = client
+                    .discover()
+                    .await?
+                    .into_iter()
+                    .find(|c| c.url.as_str() == id)
+                    .ok_or_else(|| Error::CalendarNotFound(id.clone()))?;
+
+                // Taken out of the map so calendars can sync concurrently.
+                let mut synced = state
+                    .collections
+                    .lock()
+                    .unwrap()
+                    .remove(&id)
+                    .unwrap_or_default();
+                let events = collection_events(&client, &collection, &window, &mut synced).await;
+                state.collections.lock().unwrap().insert(id.clone(), synced);
+                events?
+            }
+            CalendarAccount::Ics { .. } => {
+                let mut feed = state.feeds.lock().unwrap().remove(&id).unwrap_or_default();
+                let events = refresh_feed(&state.http, &id, &mut feed)
+                    .await
+                    .map(|ics| to_events(ics, &window));
+                state.feeds.lock().unwrap().insert(id.clone(), feed);
+                events?
+            }
+        };
+
+        Ok(events
+            .into_iter()
+            .map(|event| RemoteEvent::new(&id, event))
+            .collect())
+    }
+}
+
+fn client(
+    url: &str,
+    username: Option<String>,
+    password: Option<String>,
+) -> Result<CalDavClient, crate::Error> {
+    let client = CalDavClient::new(url.parse().map_err(hypr_caldav::Error::from)?);
+    Ok(match username {
+        Some(username) => client.with_basic_auth(username, password.unwrap_or_default()),
+        None => client,
+    })
+}
+
+pub trait CalDavPluginExt<R: tauri::Runtime> {
+    fn caldav(&self) -> CalDav<'_, R, Self>
+    where
+        Self: tauri::Manager<R> + Sized;
+}
+
+impl<R: tauri::Runtime, T: tauri::Manager<R>> CalDavPluginExt<R> for T {
+    fn caldav(&self) -> CalDav<'_, R, Self>
+    where
+        Self: Sized,
+    {
+        CalDav {
+            manager: self,
+            _runtime: std::marker::PhantomData,
+        }
+    }
+}
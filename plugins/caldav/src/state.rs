@@ -0,0 +1,12 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use hypr_caldav::{CollectionState, FeedState};
+
+/// What the last sync of each calendar left behind, keyed by calendar id.
+#[derive(Default)]
+pub struct CalDavState {
+    pub(crate) http: reqwest::Client,
+    pub(crate) collections: Mutex<HashMap<String, CollectionState>>,
+    pub(crate) feeds: Mutex<HashMap<String, FeedState>>,
+}
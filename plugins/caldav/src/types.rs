@@ -0,0 +1,80 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+/// Credentials are kept in the app's settings and passed with every call.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum CalendarAccount {
+    CalDav {
+        url: String,
+        username: Option<String>,
+        password: Option<String>,
+    },
+    Ics {
+        url: String,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+pub struct RemoteCalendar {
+    /// The collection URL, or the feed URL for an ICS subscription.
+    pub id: String,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+pub struct EventFilter {
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    pub calendar_id: String,
+    /// Zone for all-day events and floating times. Defaults to UTC.
+    pub timezone: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+pub struct Attendee {
+    pub name: Option<String>,
+    pub email: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+pub struct RemoteEvent {
+    pub uid: String,
+    pub calendar_id: String,
+    pub title: Option<String>,
+    pub start_date: DateTime<Utc>,
+    pub end_date: DateTime<Utc>,
+    pub is_all_day: bool,
+    pub location: Option<String>,
+    pub description: Option<String>,
+    pub url: Option<String>,
+    pub attendees: Vec<Attendee>,
+    /// Set on every instance of a recurring event.
+    pub original_start: Option<DateTime<Utc>>,
+}
+
+impl RemoteEvent {
+    pub(crate) fn new(calendar_id: &str, event: hypr_caldav::CalendarEvent) -> Self {
+        Self {
+            uid: event.uid,
+            calendar_id: calendar_id.to_string(),
+            title: event.title,
+            start_date: event.start,
+            end_date: event.end,
+            is_all_day: event.is_all_day,
+            location: event.location,
+            description: event.description,
+            url: event.url,
+            attendees: event
+                .attendees
+                .into_iter()
+                .map(|a| Attendee {
+                    name: a.name,
+                    email: a.email,
+                })
+                .collect(),
+            original_start: event.original_start,
+        }
+    }
+}
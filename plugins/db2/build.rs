@@ -1,4 +1,12 @@
-const COMMANDS: &[&str] = &["execute_local", "execute_cloud"];
+const COMMANDS: &[&str] = &[
+    "execute_local",
+    "execute_cloud",
+    "create_conversation",
+    "list_vault_conversations",
+    "list_messages_v2",
+    "create_message_v2",
+    "update_message_v2_parts",
+];
 
 fn main() {
     tauri_plugin::Builder::new(COMMANDS).build();
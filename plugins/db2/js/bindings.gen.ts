@@ -21,6 +21,46 @@ async executeCloud(sql: string, args: string[]) : Promise<Result<JsonValue[], st
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
+},
+async createConversation(conversation: ChatConversation) : Promise<Result<ChatConversation, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:db2|create_conversation", { conversation }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async listVaultConversations(userId: string) : Promise<Result<ChatConversation[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:db2|list_vault_conversations", { userId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async listMessagesV2(conversationId: string) : Promise<Result<ChatMessageV2[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:db2|list_messages_v2", { conversationId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async createMessageV2(message: ChatMessageV2) : Promise<Result<ChatMessageV2, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:db2|create_message_v2", { message }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async updateMessageV2Parts(id: string, parts: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:db2|update_message_v2_parts", { id, parts }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
 }
 }
 
@@ -34,6 +74,13 @@ async executeCloud(sql: string, args: string[]) : Promise<Result<JsonValue[], st
 
 /** user-defined types **/
 
+export type ChatConversation = { id: string; 
+/**
+ * `None` for conversations over the whole vault.
+ */
+session_id: string | null; user_id: string; name: string | null; created_at: string; updated_at: string }
+export type ChatMessageV2 = { id: string; conversation_id: string; role: ChatMessageV2Role; parts: string; metadata: string | null; created_at: string; updated_at: string }
+export type ChatMessageV2Role = "system" | "user" | "assistant"
 export type JsonValue = null | boolean | number | string | JsonValue[] | Partial<{ [key in string]: JsonValue }>
 
 /** tauri-specta globals **/
@@ -25,3 +25,79 @@ pub(crate) async fn execute_cloud<R: tauri::Runtime>(
         .await
         .map_err(|e| e.to_string())
 }
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn create_conversation<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    conversation: hypr_db_user::ChatConversation,
+) -> Result<hypr_db_user::ChatConversation, String> {
+    app.db2()
+        .user_db()
+        .await
+        .map_err(|e| e.to_string())?
+        .create_conversation(conversation)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn list_vault_conversations<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    user_id: String,
+) -> Result<Vec<hypr_db_user::ChatConversation>, String> {
+    app.db2()
+        .user_db()
+        .await
+        .map_err(|e| e.to_string())?
+        .list_vault_conversations(user_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn list_messages_v2<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    conversation_id: String,
+) -> Result<Vec<hypr_db_user::ChatMessageV2>, String> {
+    app.db2()
+        .user_db()
+        .await
+        .map_err(|e| e.to_string())?
+        .list_messages_v2(conversation_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn create_message_v2<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    message: hypr_db_user::ChatMessageV2,
+) -> Result<hypr_db_user::ChatMessageV2, String> {
+    app.db2()
+        .user_db()
+        .await
+        .map_err(|e| e.to_string())?
+        .create_message_v2(message)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn update_message_v2_parts<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    id: String,
+    parts: String,
+) -> Result<(), String> {
+    app.db2()
+        .user_db()
+        .await
+        .map_err(|e| e.to_string())?
+        .update_message_v2_parts(id, parts)
+        .await
+        .map_err(|e| e.to_string())
+}
@@ -12,6 +12,8 @@ pub enum Error {
     IoError(#[from] std::io::Error),
     #[error(transparent)]
     SettingsError(#[from] tauri_plugin_settings::Error),
+    #[error("local database is not initialized")]
+    LocalDatabaseNotInitialized,
 }
 
 impl Serialize for Error {
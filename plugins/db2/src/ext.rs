@@ -24,6 +24,7 @@ impl<'a, R: tauri::Runtime, M: tauri::Manager<R>> Database2<'a, R, M> {
                     .unwrap()
             }
         };
+        hypr_db_user::migrate(&hypr_db_user::UserDatabase::from(db.clone())).await?;
         {
             let state = self.manager.state::<crate::ManagedState>();
             let mut guard = state.lock().await;
@@ -32,6 +33,17 @@ impl<'a, R: tauri::Runtime, M: tauri::Manager<R>> Database2<'a, R, M> {
         Ok(())
     }
 
+    pub async fn user_db(&self) -> Result<hypr_db_user::UserDatabase, crate::Error> {
+        let state = self.manager.state::<crate::ManagedState>();
+        let guard = state.lock().await;
+
+        let db = guard
+            .local_db
+            .clone()
+            .ok_or(crate::Error::LocalDatabaseNotInitialized)?;
+        Ok(hypr_db_user::UserDatabase::from(db))
+    }
+
     pub async fn init_cloud(&self, connection_str: &str) -> Result<(), crate::Error> {
         let (client, connection) =
             tokio_postgres::connect(connection_str, tokio_postgres::NoTls).await?;
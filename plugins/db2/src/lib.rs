@@ -24,6 +24,11 @@ fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
         .commands(tauri_specta::collect_commands![
             commands::execute_local::<tauri::Wry>,
             commands::execute_cloud::<tauri::Wry>,
+            commands::create_conversation::<tauri::Wry>,
+            commands::list_vault_conversations::<tauri::Wry>,
+            commands::list_messages_v2::<tauri::Wry>,
+            commands::create_message_v2::<tauri::Wry>,
+            commands::update_message_v2_parts::<tauri::Wry>,
         ])
         .error_handling(tauri_specta::ErrorHandlingMode::Result)
 }
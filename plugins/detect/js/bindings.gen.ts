@@ -70,6 +70,14 @@ async setMicActiveThreshold(secs: number) : Promise<Result<null, string>> {
     else return { status: "error", error: e  as any };
 }
 }
+async matchMeeting(events: Event[], participantEmails: string[]) : Promise<Result<MatchOutcome, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:detect|match_meeting", { events, participantEmails }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+}
 }
 
 /** user-defined events **/
@@ -87,8 +95,28 @@ detectEvent: "plugin:detect:detect-event"
 
 /** user-defined types **/
 
+export type Attendee = { email: string; 
+/**
+ * Whether this is the calendar owner, as reported by the calendar.
+ */
+is_self?: boolean }
 export type DetectEvent = { type: "micDetected"; key: string; apps: InstalledApp[]; duration_secs: number } | { type: "micStopped"; apps: InstalledApp[] } | { type: "micMuted"; value: boolean } | { type: "sleepStateChanged"; value: boolean }
+/**
+ * A row of the vault's `events` table (`events.json`), keyed by its row id.
+ */
+export type Event = { id: string; title: string; started_at: string; ended_at: string; is_all_day?: boolean; location?: string | null; meeting_link?: string | null; description?: string | null; attendees?: Attendee[] }
 export type InstalledApp = { id: string; name: string }
+export type MatchOutcome = 
+/**
+ * Confident enough to link the session.
+ */
+{ type: "linked"; meeting: MeetingMatch } | 
+/**
+ * Ask the user to pick, best first.
+ */
+{ type: "prompt"; candidates: MeetingMatch[] } | { type: "no_match" }
+export type MeetingMatch = { event_id: string; title: string; conference: Provider | null; confidence: number }
+export type Provider = "zoom" | "google_meet" | "teams" | "webex"
 
 /** tauri-specta globals **/
 
@@ -16,6 +16,16 @@ pub(crate) async fn list_mic_using_applications<R: tauri::Runtime>(
     Ok(app.detect().list_mic_using_applications())
 }
 
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn match_meeting<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    events: Vec<hypr_meeting_match::Event>,
+    participant_emails: Vec<String>,
+) -> Result<hypr_meeting_match::MatchOutcome, String> {
+    Ok(app.detect().match_meeting(&events, participant_emails))
+}
+
 #[tauri::command]
 #[specta::specta]
 pub(crate) async fn list_default_ignored_bundle_ids<R: tauri::Runtime>(
@@ -12,6 +12,19 @@ impl<'a, R: tauri::Runtime, M: tauri::Manager<R>> Detect<'a, R, M> {
         hypr_detect::list_mic_using_apps()
     }
 
+    /// Picks the calendar event the user is most likely joining right now.
+    pub fn match_meeting(
+        &self,
+        events: &[hypr_meeting_match::Event],
+        participant_emails: Vec<String>,
+    ) -> hypr_meeting_match::MatchOutcome {
+        let ctx = hypr_meeting_match::MatchContext::now(
+            self.list_mic_using_applications(),
+            participant_emails,
+        );
+        hypr_meeting_match::match_meeting(&ctx, events)
+    }
+
     pub fn list_default_ignored_bundle_ids(&self) -> Vec<String> {
         crate::policy::default_ignored_bundle_ids()
     }
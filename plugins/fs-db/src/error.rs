@@ -10,6 +10,11 @@ pub enum Error {
     DbParser(#[from] hypr_db_parser::Error),
     #[error(transparent)]
     Frontmatter(#[from] hypr_frontmatter::Error),
+    #[error("{}: {source}", path.display())]
+    InvalidFrontmatter {
+        path: std::path::PathBuf,
+        source: hypr_frontmatter::Error,
+    },
     #[error(transparent)]
     Json(#[from] serde_json::Error),
     #[error(transparent)]
@@ -1,13 +1,12 @@
 use std::path::PathBuf;
-use std::str::FromStr;
 
 use hypr_frontmatter::Document;
 use tauri_plugin_settings::SettingsPluginExt;
 
 use crate::Error;
 use crate::types::{
-    self, EnhancedNoteData, EnhancedNoteFrontmatterWrite, MemoFrontmatter, MemoFrontmatterWrite,
-    SessionContent, SessionEnhancedNotes, SessionTranscript, TranscriptData, TranscriptEntryWrite,
+    self, EnhancedNoteData, EnhancedNoteFrontmatter, MemoFrontmatter, SessionContent,
+    SessionEnhancedNotes, SessionTranscript, TranscriptData, TranscriptEntryWrite,
     TranscriptFileWrite,
 };
 
@@ -64,7 +63,12 @@ impl<'a, R: tauri::Runtime, M: tauri::Manager<R>> FsDb<'a, R, M> {
         }
 
         let content = tokio::fs::read_to_string(&memo_path).await?;
-        let doc: Document<MemoFrontmatter> = Document::from_str(&content)?;
+        let doc = Document::<MemoFrontmatter>::parse_versioned(&content)
+            .map_err(|source| Error::InvalidFrontmatter {
+                path: memo_path.clone(),
+                source,
+            })?
+            .document;
         let tiptap_json = hypr_tiptap::md_to_tiptap_json(&doc.content).map_err(Error::Tiptap)?;
 
         Ok(SessionContent {
@@ -114,8 +118,13 @@ impl<'a, R: tauri::Runtime, M: tauri::Manager<R>> FsDb<'a, R, M> {
                 continue;
             }
 
-            if let Some(note) = types::load_enhanced_note(&path, session_id).await {
-                notes.push(note);
+            // One unreadable note must not hide the rest of the session.
+            match types::load_enhanced_note(&path, session_id).await {
+                Ok(Some(note)) => notes.push(note),
+                Ok(None) => {}
+                Err(e) => {
+                    tracing::warn!(path = %path.display(), error = %e, "failed_to_load_enhanced_note");
+                }
             }
         }
 
@@ -131,13 +140,18 @@ impl<'a, R: tauri::Runtime, M: tauri::Manager<R>> FsDb<'a, R, M> {
         let tiptap_value: serde_json::Value = serde_json::from_str(raw_md)?;
         let markdown = hypr_tiptap::tiptap_json_to_md(&tiptap_value).map_err(Error::Tiptap)?;
 
-        let frontmatter = MemoFrontmatterWrite {
-            id: session_id.to_string(),
-            session_id: session_id.to_string(),
+        let frontmatter = MemoFrontmatter {
+            id: Some(session_id.to_string()),
+            session_id: Some(session_id.to_string()),
+            extra: Default::default(),
         };
 
         let doc = Document::new(frontmatter, markdown);
-        tokio::fs::write(session_dir.join(types::files::MEMO), doc.render()?).await?;
+        tokio::fs::write(
+            session_dir.join(types::files::MEMO),
+            doc.render_versioned()?,
+        )
+        .await?;
 
         Ok(())
     }
@@ -183,16 +197,17 @@ impl<'a, R: tauri::Runtime, M: tauri::Manager<R>> FsDb<'a, R, M> {
         let tiptap_value: serde_json::Value = serde_json::from_str(&note.content)?;
         let markdown = hypr_tiptap::tiptap_json_to_md(&tiptap_value).map_err(Error::Tiptap)?;
 
-        let frontmatter = EnhancedNoteFrontmatterWrite {
+        let frontmatter = EnhancedNoteFrontmatter {
             id: note.id,
             session_id: note.session_id,
             template_id: note.template_id,
             position: note.position,
             title: note.title,
+            extra: Default::default(),
         };
 
         let doc = Document::new(frontmatter, markdown);
-        tokio::fs::write(session_dir.join(filename), doc.render()?).await?;
+        tokio::fs::write(session_dir.join(filename), doc.render_versioned()?).await?;
 
         Ok(())
     }
@@ -70,6 +70,8 @@ mod tests {
             super::super::v1_0_4_nightly_2_repair_transcripts::Migrate.introduced_in();
         let event_sync_from_sqlite =
             super::super::v1_0_7_nightly_1_events_sync::Migrate.introduced_in();
+        let frontmatter_schema =
+            super::super::v1_0_8_nightly_1_frontmatter_schema::Migrate.introduced_in();
 
         struct Case {
             from: DetectedVersion,
@@ -163,6 +165,16 @@ mod tests {
                 to: "1.0.6",
                 expected: vec![],
             },
+            Case {
+                from: DetectedVersion::FromFile(v("1.0.7")),
+                to: "1.0.8-nightly.1",
+                expected: vec![frontmatter_schema],
+            },
+            Case {
+                from: DetectedVersion::FromFile(v("1.0.6")),
+                to: "1.0.8",
+                expected: vec![event_sync_from_sqlite, frontmatter_schema],
+            },
         ];
 
         for Case { from, to, expected } in cases {
@@ -0,0 +1,210 @@
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+
+use hypr_frontmatter::{Document, Schema};
+use hypr_version::Version;
+
+use super::utils::{FileOp, apply_ops};
+use super::version_from_name;
+use crate::Result;
+use crate::types::{
+    EnhancedNoteFrontmatter, HumanFrontmatter, MemoFrontmatter, OrganizationFrontmatter, files,
+    is_enhanced_note,
+};
+
+// Files that fail to parse are left untouched and listed here.
+const REPORT_FILE: &str = ".hyprnote/frontmatter-errors.txt";
+
+pub struct Migrate;
+
+impl super::Migration for Migrate {
+    fn introduced_in(&self) -> &'static Version {
+        version_from_name!()
+    }
+
+    fn run<'a>(&self, base_dir: &'a Path) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>> {
+        Box::pin(run_inner(base_dir))
+    }
+}
+
+async fn run_inner(base_dir: &Path) -> Result<()> {
+    let mut ops = Vec::new();
+    let mut diagnostics = Vec::new();
+
+    for path in markdown_files(&base_dir.join("sessions")) {
+        if path.file_name().is_some_and(|n| n == files::MEMO) {
+            upgrade_file::<MemoFrontmatter>(&path, &mut ops, &mut diagnostics);
+        } else if std::fs::read_to_string(&path).is_ok_and(|c| is_enhanced_note(&c)) {
+            upgrade_file::<EnhancedNoteFrontmatter>(&path, &mut ops, &mut diagnostics);
+        }
+    }
+    for path in markdown_files(&base_dir.join("humans")) {
+        upgrade_file::<HumanFrontmatter>(&path, &mut ops, &mut diagnostics);
+    }
+    for path in markdown_files(&base_dir.join("organizations")) {
+        upgrade_file::<OrganizationFrontmatter>(&path, &mut ops, &mut diagnostics);
+    }
+
+    if !diagnostics.is_empty() {
+        let lines: Vec<String> = diagnostics
+            .iter()
+            .map(|(path, error)| {
+                let path = path.strip_prefix(base_dir).unwrap_or(path);
+                format!("{}: {}", path.display(), error)
+            })
+            .collect();
+
+        ops.push(FileOp::Write {
+            path: base_dir.join(REPORT_FILE),
+            content: lines.join("\n") + "\n",
+            force: true,
+        });
+    }
+
+    apply_ops(ops)?;
+    Ok(())
+}
+
+fn upgrade_file<T: Schema>(
+    path: &Path,
+    ops: &mut Vec<FileOp>,
+    diagnostics: &mut Vec<(PathBuf, hypr_frontmatter::Error)>,
+) {
+    let Ok(content) = std::fs::read_to_string(path) else {
+        return;
+    };
+
+    // Files already at the current version keep their bytes, even if rendering
+    // them again would reorder keys.
+    let rendered = Document::<T>::parse_versioned(&content).and_then(|versioned| {
+        versioned
+            .needs_rewrite()
+            .then(|| versioned.document.render_versioned())
+            .transpose()
+    });
+
+    match rendered {
+        Ok(Some(rendered)) => ops.push(FileOp::Write {
+            path: path.to_path_buf(),
+            content: rendered,
+            force: true,
+        }),
+        Ok(None) => {}
+        Err(error) => diagnostics.push((path.to_path_buf(), error)),
+    }
+}
+
+fn markdown_files(dir: &Path) -> Vec<PathBuf> {
+    let mut result = Vec::new();
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return result;
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            result.extend(markdown_files(&path));
+        } else if path.extension().is_some_and(|ext| ext == "md") {
+            result.push(path);
+        }
+    }
+
+    result.sort();
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn write(path: &Path, content: &str) {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+    }
+
+    fn read(path: &Path) -> String {
+        std::fs::read_to_string(path).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_upgrades_legacy_human_email() {
+        let tmp = tempdir().unwrap();
+        let base = tmp.path();
+        let path = base.join("humans/h1.md");
+
+        write(
+            &path,
+            "---\nemail: a@x.com, b@x.com\nname: Jane\nnickname: JJ\n---\n\nMemo",
+        );
+        run_inner(base).await.unwrap();
+
+        assert_eq!(
+            read(&path),
+            "---\nemails:\n- a@x.com\n- b@x.com\njob_title: ''\nlinkedin_username: ''\nname: Jane\nnickname: JJ\norg_id: ''\npinned: false\nschema_version: 2\nuser_id: ''\n---\n\nMemo"
+        );
+        assert!(!base.join(REPORT_FILE).exists());
+    }
+
+    #[tokio::test]
+    async fn test_stamps_session_notes() {
+        let tmp = tempdir().unwrap();
+        let base = tmp.path();
+        let memo = base.join("sessions/folder/s1/_memo.md");
+        let note = base.join("sessions/folder/s1/Summary.md");
+
+        write(&memo, "---\nid: s1\nsession_id: s1\n---\n\nHello");
+        write(
+            &note,
+            "---\nid: n1\nposition: 1\nsession_id: s1\n---\n\nNote",
+        );
+        run_inner(base).await.unwrap();
+
+        assert_eq!(
+            read(&memo),
+            "---\nid: s1\nschema_version: 1\nsession_id: s1\n---\n\nHello"
+        );
+        assert_eq!(
+            read(&note),
+            "---\nid: n1\nposition: 1\nschema_version: 1\nsession_id: s1\n---\n\nNote"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_skips_current_and_foreign_files() {
+        let tmp = tempdir().unwrap();
+        let base = tmp.path();
+        let current = base.join("sessions/s1/Summary.md");
+        let foreign = base.join("sessions/s1/notes.md");
+        let plain = base.join("sessions/s1/README.md");
+        let current_content = "---\nsession_id: s1\nid: n1\nschema_version: 1\n---\n\nNote";
+        let foreign_content = "---\ntags: [todo]\n---\n\nMine";
+        let plain_content = "# Just markdown";
+
+        write(&current, current_content);
+        write(&foreign, foreign_content);
+        write(&plain, plain_content);
+        run_inner(base).await.unwrap();
+
+        assert_eq!(read(&current), current_content);
+        assert_eq!(read(&foreign), foreign_content);
+        assert_eq!(read(&plain), plain_content);
+        assert!(!base.join(REPORT_FILE).exists());
+    }
+
+    #[tokio::test]
+    async fn test_reports_invalid_files() {
+        let tmp = tempdir().unwrap();
+        let base = tmp.path();
+        let note = base.join("sessions/s1/Summary.md");
+        let original = "---\nid: n1\nsession_id: s1\nposition: first\n---\n\nNote";
+
+        write(&note, original);
+        run_inner(base).await.unwrap();
+
+        assert_eq!(read(&note), original);
+        let report = read(&base.join(REPORT_FILE));
+        assert!(report.starts_with("sessions/s1/Summary.md: invalid frontmatter at `position`"));
+    }
+}
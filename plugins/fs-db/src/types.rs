@@ -1,7 +1,7 @@
+use std::collections::BTreeMap;
 use std::path::Path;
-use std::str::FromStr;
 
-use hypr_frontmatter::Document;
+use hypr_frontmatter::{Document, Schema, Upgrade, upgrade};
 use serde::{Deserialize, Serialize};
 use specta::Type;
 
@@ -77,18 +77,21 @@ pub(crate) mod files {
     pub const TRANSCRIPT: &str = "transcript.json";
 }
 
-#[derive(Debug, Deserialize)]
+// Keys this crate doesn't model, kept so rewriting a file never drops them.
+pub(crate) type ExtraKeys = BTreeMap<String, serde_json::Value>;
+
+#[derive(Debug, Serialize, Deserialize)]
 pub(crate) struct MemoFrontmatter {
-    #[allow(dead_code)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
-    #[allow(dead_code)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub session_id: Option<String>,
+    #[serde(flatten)]
+    pub extra: ExtraKeys,
 }
 
-#[derive(Debug, Serialize)]
-pub(crate) struct MemoFrontmatterWrite {
-    pub id: String,
-    pub session_id: String,
+impl Schema for MemoFrontmatter {
+    const VERSION: u32 = 1;
 }
 
 #[derive(Debug, Deserialize)]
@@ -170,28 +173,128 @@ pub(crate) struct SpeakerHintEntryWrite {
     pub value: serde_json::Value,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub(crate) struct EnhancedNoteFrontmatter {
     pub id: String,
     pub session_id: String,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub template_id: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "is_zero")]
     pub position: i32,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
+    #[serde(flatten)]
+    pub extra: ExtraKeys,
 }
 
-#[derive(Debug, Serialize)]
-pub(crate) struct EnhancedNoteFrontmatterWrite {
-    pub id: String,
-    pub session_id: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub template_id: Option<String>,
-    #[serde(skip_serializing_if = "is_zero")]
-    pub position: i32,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub title: Option<String>,
+impl Schema for EnhancedNoteFrontmatter {
+    const VERSION: u32 = 1;
+
+    fn validate(&self) -> Result<(), hypr_frontmatter::Error> {
+        if self.id.trim().is_empty() {
+            return Err(hypr_frontmatter::Error::invalid("id", "must not be empty"));
+        }
+        if self.session_id.trim().is_empty() {
+            return Err(hypr_frontmatter::Error::invalid(
+                "session_id",
+                "must not be empty",
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Other markdown (user files, exports) can sit next to the notes in a session
+/// folder; only files whose frontmatter names a session are enhanced notes.
+pub(crate) fn is_enhanced_note(content: &str) -> bool {
+    #[derive(Deserialize)]
+    struct Probe {
+        #[serde(default)]
+        session_id: Option<serde::de::IgnoredAny>,
+    }
+
+    content
+        .parse::<Document<Probe>>()
+        .is_ok_and(|doc| doc.frontmatter.session_id.is_some())
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct HumanFrontmatter {
+    #[serde(default)]
+    pub user_id: String,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub emails: Vec<String>,
+    #[serde(default)]
+    pub org_id: String,
+    #[serde(default)]
+    pub job_title: String,
+    #[serde(default)]
+    pub linkedin_username: String,
+    #[serde(default)]
+    pub pinned: bool,
+    #[serde(flatten)]
+    pub extra: ExtraKeys,
+}
+
+// v1 files may carry a comma-separated `email` string instead of `emails`.
+#[derive(Deserialize)]
+struct HumanFrontmatterV1 {
+    #[serde(default)]
+    email: Option<String>,
+    #[serde(default)]
+    emails: Option<Vec<String>>,
+    #[serde(flatten)]
+    rest: ExtraKeys,
+}
+
+#[derive(Serialize)]
+struct HumanFrontmatterV2 {
+    emails: Vec<String>,
+    #[serde(flatten)]
+    rest: ExtraKeys,
+}
+
+impl Schema for HumanFrontmatter {
+    const VERSION: u32 = 2;
+    const UPGRADES: &'static [Upgrade] = &[|mapping| {
+        upgrade(mapping, |v1: HumanFrontmatterV1| HumanFrontmatterV2 {
+            emails: v1.emails.unwrap_or_else(|| {
+                v1.email
+                    .iter()
+                    .flat_map(|e| e.split(','))
+                    .map(|e| e.trim().to_string())
+                    .filter(|e| !e.is_empty())
+                    .collect()
+            }),
+            rest: v1.rest,
+        })
+    }];
+
+    fn validate(&self) -> Result<(), hypr_frontmatter::Error> {
+        match self.emails.iter().position(|e| !e.contains('@')) {
+            Some(i) => Err(hypr_frontmatter::Error::invalid(
+                format!("emails[{i}]"),
+                "not an email address",
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct OrganizationFrontmatter {
+    #[serde(default)]
+    pub user_id: String,
+    #[serde(default)]
+    pub name: String,
+    #[serde(flatten)]
+    pub extra: ExtraKeys,
+}
+
+impl Schema for OrganizationFrontmatter {
+    const VERSION: u32 = 1;
 }
 
 fn is_zero(n: &i32) -> bool {
@@ -312,22 +415,34 @@ pub(crate) async fn load_transcript_file(path: &Path) -> TranscriptFile {
     }
 }
 
-pub(crate) async fn load_enhanced_note(path: &Path, session_id: &str) -> Option<EnhancedNoteData> {
-    let content = tokio::fs::read_to_string(path).await.ok()?;
-    let doc: Document<EnhancedNoteFrontmatter> = Document::from_str(&content).ok()?;
+pub(crate) async fn load_enhanced_note(
+    path: &Path,
+    session_id: &str,
+) -> crate::Result<Option<EnhancedNoteData>> {
+    let content = tokio::fs::read_to_string(path).await?;
+    if !is_enhanced_note(&content) {
+        return Ok(None);
+    }
+
+    let doc = Document::<EnhancedNoteFrontmatter>::parse_versioned(&content)
+        .map_err(|source| crate::Error::InvalidFrontmatter {
+            path: path.to_path_buf(),
+            source,
+        })?
+        .document;
 
     if doc.frontmatter.session_id != session_id {
-        return None;
+        return Ok(None);
     }
 
-    let tiptap_json = hypr_tiptap::md_to_tiptap_json(&doc.content).ok()?;
+    let tiptap_json = hypr_tiptap::md_to_tiptap_json(&doc.content).map_err(crate::Error::Tiptap)?;
 
-    Some(EnhancedNoteData {
+    Ok(Some(EnhancedNoteData {
         id: doc.frontmatter.id,
         session_id: doc.frontmatter.session_id,
         template_id: doc.frontmatter.template_id,
         position: doc.frontmatter.position,
         title: doc.frontmatter.title,
         content: tiptap_json.to_string(),
-    })
+    }))
 }
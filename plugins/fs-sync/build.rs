@@ -15,6 +15,8 @@ const COMMANDS: &[&str] = &[
     "audio_path",
     "session_dir",
     "load_session_content",
+    "export_session",
+    "export_vault",
     "delete_session_folder",
     "scan_and_read",
     "chat_dir",
@@ -22,6 +24,8 @@ const COMMANDS: &[&str] = &[
     "attachment_save",
     "attachment_list",
     "attachment_remove",
+    "media_probe",
+    "media_import",
 ];
 
 fn main() {
@@ -118,6 +118,22 @@ async audioPath(sessionId: string) : Promise<Result<string, string>> {
     else return { status: "error", error: e  as any };
 }
 },
+async mediaProbe(sourcePath: string) : Promise<Result<MediaProbeResult, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:fs-sync|media_probe", { sourcePath }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async mediaImport(sourcePath: string, trackSelection: MediaTrackSelection) : Promise<Result<ImportedMediaSession, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:fs-sync|media_import", { sourcePath, trackSelection }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async sessionDir(sessionId: string) : Promise<Result<string, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("plugin:fs-sync|session_dir", { sessionId }) };
@@ -134,6 +150,22 @@ async loadSessionContent(sessionId: string) : Promise<Result<SessionContentData,
     else return { status: "error", error: e  as any };
 }
 },
+async exportSession(sessionId: string, format: ExportFormat, outputPath: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:fs-sync|export_session", { sessionId, format, outputPath }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async exportVault(options: VaultOptions, outputDir: string) : Promise<Result<VaultExportStats, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:fs-sync|export_vault", { options, outputDir }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async deleteSessionFolder(sessionId: string) : Promise<Result<null, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("plugin:fs-sync|delete_session_folder", { sessionId }) };
@@ -204,20 +236,42 @@ async attachmentRemove(sessionId: string, attachmentId: string) : Promise<Result
 
 export type AttachmentInfo = { attachmentId: string; path: string; extension: string; modifiedAt: string }
 export type AttachmentSaveResult = { path: string; attachmentId: string }
+export type BundleOptions = { includeAudio: boolean }
 export type CleanupTarget = { type: "files"; subdir: string; extension: string } | { type: "dirs"; subdir: string; marker_file: string } | { type: "filesRecursive"; subdir: string; marker_file: string; extension: string }
+export type DocxOptions = { includeMemo: boolean; includeTranscript: boolean; timestamps: boolean }
+export type ExportFormat = { format: "docx"; options: DocxOptions } | { format: "html"; options: HtmlOptions } | { format: "pdf"; options: PdfOptions } | { format: "srt"; options: SubtitleOptions } | { format: "vtt"; options: SubtitleOptions } | { format: "text"; options: TextOptions } | { format: "json" } | { format: "bundle"; options: BundleOptions }
 export type FolderInfo = { name: string; parent_folder_id: string | null }
+export type HtmlOptions = { includeMemo: boolean; includeTranscript: boolean; timestamps: boolean }
+export type ImportedMediaSession = { sessionId: string; audioPath: string; title: string; createdAt: string }
 export type JsonValue = null | boolean | number | string | JsonValue[] | Partial<{ [key in string]: JsonValue }>
 export type ListFoldersResult = { folders: Partial<{ [key in string]: FolderInfo }>; session_folder_map: Partial<{ [key in string]: string }> }
+export type MediaProbeResult = { tracks: MediaTrackInfo[]; durationSecs: number | null }
+export type MediaTrackInfo = { id: number; codec: string; language: string | null; sampleRate: number | null; channels: number | null; durationSecs: number | null; isDefault: boolean }
+export type MediaTrackSelection = { type: "default" } | { type: "track"; id: number } | { type: "mixAll" }
 export type ParsedDocument = { frontmatter: Partial<{ [key in string]: JsonValue }>; content: string }
+export type PdfOptions = { includeCover: boolean; includeTranscript: boolean }
 export type ScanResult = { files: Partial<{ [key in string]: string }>; dirs: string[] }
 export type SessionContentData = { sessionId: string; meta: SessionMetaData | null; rawMemoTiptapJson: JsonValue | null; transcript: TranscriptData | null; notes: SessionNoteData[] }
 export type SessionMetaData = { id: string; userId: string; createdAt: string | null; title: string | null; event: JsonValue | null; eventId: string | null; participants: SessionMetaParticipant[]; tags: string[] }
 export type SessionMetaParticipant = { id: string; userId: string; sessionId: string; humanId: string; source: string }
 export type SessionNoteData = { id: string; sessionId: string; templateId: string | null; position: number | null; title: string | null; tiptapJson: JsonValue }
+export type SubtitleOptions = { 
+/**
+ * Label cues with the speaker: a `<v>` tag in WebVTT, a `Name: ` prefix in SRT.
+ */
+speakers: boolean; maxCharsPerCue: number; maxCueDurationMs: number }
+export type TextOptions = { timestamps: boolean; speakers: boolean }
 export type TranscriptData = { transcripts: TranscriptEntry[] }
 export type TranscriptEntry = { id: string; userId: string | null; createdAt: string | null; sessionId: string; startedAt: number | null; endedAt: number | null; words: TranscriptWord[]; speakerHints: TranscriptSpeakerHint[] }
 export type TranscriptSpeakerHint = { id: string | null; speakerId: string | null; startWordId: string; endWordId: string }
 export type TranscriptWord = { id: string | null; text: string; startMs: number; endMs: number; channel: number }
+export type VaultExportStats = { meetings: number; people: number; organizations: number; dailyNotes: number; attachments: number; 
+/**
+ * Session folders that could not be read.
+ */
+skipped: number }
+export type VaultFlavor = "obsidian" | "logseq"
+export type VaultOptions = { flavor: VaultFlavor; includeMemo: boolean; includeTranscript: boolean; dailyNotes: boolean }
 
 /** tauri-specta globals **/
 
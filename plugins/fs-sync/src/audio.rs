@@ -1,16 +1,16 @@
-use std::fs::{File, copy, remove_file, rename, write};
-use std::io::ErrorKind;
-use std::num::{NonZeroU8, NonZeroU32};
+use std::fs::{File, copy, remove_file, rename};
+use std::io::{BufWriter, ErrorKind, Write};
+use std::num::NonZeroU32;
 use std::path::{Path, PathBuf};
 
-use hypr_audio_utils::{
-    Source, VorbisEncodeSettings, encode_vorbis_mono, mix_down_to_mono, resample_audio,
-};
+use hypr_audio_utils::{TrackSelection, VorbisEncodeSettings, VorbisMonoWriter, stream_media_mono};
 
 use crate::error::{AudioImportError, AudioProcessingError};
+use crate::types::ImportedMediaSession;
 
 const TARGET_SAMPLE_RATE_HZ: u32 = 16_000;
-const AUDIO_FORMATS: [&str; 2] = ["audio.wav", "audio.ogg"];
+const AUDIO_FORMATS: [&str; 4] = ["audio.wav", "audio.ogg", "audio.flac", "audio.opus"];
+const STEM_PREFIXES: [&str; 2] = ["audio_mic.", "audio_spk."];
 
 pub fn exists(session_dir: &Path) -> std::io::Result<bool> {
     AUDIO_FORMATS
@@ -28,9 +28,74 @@ pub fn delete(session_dir: &Path) -> std::io::Result<()> {
             std::fs::remove_file(&path)?;
         }
     }
+    for stem in stem_files(session_dir) {
+        std::fs::remove_file(&stem)?;
+    }
     Ok(())
 }
 
+fn stem_files(session_dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(session_dir) else {
+        return vec![];
+    };
+
+    entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.is_file()
+                && path
+                    .file_name()
+                    .and_then(|n| n.to_str())
+                    .is_some_and(|n| STEM_PREFIXES.iter().any(|p| n.starts_with(p)))
+        })
+        .collect()
+}
+
+/// Removes per-channel stems (`audio_mic.*`, `audio_spk.*`) that were last
+/// written more than `max_age` ago. The combined recording is never touched.
+pub fn prune_stems(sessions_base: &Path, max_age: std::time::Duration) -> u32 {
+    let Ok(entries) = std::fs::read_dir(sessions_base) else {
+        return 0;
+    };
+
+    let mut removed = 0;
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if !path.is_dir() {
+            continue;
+        }
+
+        let is_session = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .is_some_and(crate::path::is_uuid);
+        if !is_session {
+            removed += prune_stems(&path, max_age);
+            continue;
+        }
+
+        for stem in stem_files(&path) {
+            let expired = std::fs::metadata(&stem)
+                .and_then(|m| m.modified())
+                .ok()
+                .and_then(|modified| modified.elapsed().ok())
+                .is_some_and(|age| age > max_age);
+            if !expired {
+                continue;
+            }
+
+            match std::fs::remove_file(&stem) {
+                Ok(()) => removed += 1,
+                Err(e) => {
+                    tracing::warn!(path = %stem.display(), error = %e, "failed_to_prune_stem")
+                }
+            }
+        }
+    }
+    removed
+}
+
 pub fn path(session_dir: &Path) -> Option<PathBuf> {
     AUDIO_FORMATS
         .iter()
@@ -41,6 +106,14 @@ pub fn path(session_dir: &Path) -> Option<PathBuf> {
 pub fn import_to_session(
     session_dir: &Path,
     source_path: &Path,
+) -> Result<PathBuf, AudioImportError> {
+    import_media_to_session(session_dir, source_path, &TrackSelection::Default)
+}
+
+pub fn import_media_to_session(
+    session_dir: &Path,
+    source_path: &Path,
+    selection: &TrackSelection,
 ) -> Result<PathBuf, AudioImportError> {
     std::fs::create_dir_all(session_dir)?;
 
@@ -51,7 +124,7 @@ pub fn import_to_session(
         std::fs::remove_file(&tmp_path)?;
     }
 
-    match import_audio(source_path, &tmp_path, &target_path) {
+    match import_media(source_path, selection, &tmp_path, &target_path) {
         Ok(final_path) => Ok(final_path),
         Err(error) => {
             if tmp_path.exists() {
@@ -62,24 +135,107 @@ pub fn import_to_session(
     }
 }
 
+/// Imports `source_path` into a freshly created session folder under
+/// `sessions_base`. The folder is removed again if the import fails.
+pub fn import_media_as_new_session(
+    sessions_base: &Path,
+    source_path: &Path,
+    selection: &TrackSelection,
+) -> Result<ImportedMediaSession, AudioImportError> {
+    let session_id = uuid::Uuid::new_v4().to_string();
+    let session_dir = sessions_base.join(&session_id);
+
+    let audio_path = match import_media_to_session(&session_dir, source_path, selection) {
+        Ok(path) => path,
+        Err(error) => {
+            let _ = std::fs::remove_dir_all(&session_dir);
+            return Err(error);
+        }
+    };
+
+    let title = source_path
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    Ok(ImportedMediaSession {
+        session_id,
+        audio_path: audio_path.to_string_lossy().to_string(),
+        title,
+        created_at: recorded_at(source_path).to_rfc3339(),
+    })
+}
+
+/// Best guess at when the media was recorded: Zoom cloud recordings carry a
+/// `GMTYYYYMMDD-HHMMSS` prefix, everything else falls back to the file's
+/// modification time.
+fn recorded_at(source_path: &Path) -> chrono::DateTime<chrono::Utc> {
+    let from_name = source_path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .and_then(|n| n.strip_prefix("GMT"))
+        .and_then(|n| n.get(..15))
+        .and_then(|n| chrono::NaiveDateTime::parse_from_str(n, "%Y%m%d-%H%M%S").ok())
+        .map(|dt| dt.and_utc());
+
+    from_name
+        .or_else(|| {
+            std::fs::metadata(source_path)
+                .and_then(|m| m.modified())
+                .ok()
+                .map(chrono::DateTime::<chrono::Utc>::from)
+        })
+        .unwrap_or_else(chrono::Utc::now)
+}
+
 pub fn import_audio(
     source_path: &Path,
     tmp_path: &Path,
     target_path: &Path,
 ) -> Result<PathBuf, AudioProcessingError> {
-    let file = File::open(source_path)?;
-    match rodio::Decoder::try_from(file) {
-        Ok(decoder) => import_audio_from_decoder(decoder, tmp_path, target_path),
-        Err(_original_err) => {
+    import_media(source_path, &TrackSelection::Default, tmp_path, target_path)
+}
+
+pub fn import_media(
+    source_path: &Path,
+    selection: &TrackSelection,
+    tmp_path: &Path,
+    target_path: &Path,
+) -> Result<PathBuf, AudioProcessingError> {
+    let target_sample_rate = NonZeroU32::new(TARGET_SAMPLE_RATE_HZ)
+        .ok_or(AudioProcessingError::InvalidTargetSampleRate)?;
+
+    // The partly written file is useless if decoding fails midway.
+    let samples = encode_for_import(source_path, selection, tmp_path, target_sample_rate)
+        .inspect_err(|_| {
+            let _ = remove_file(tmp_path);
+        })?;
+    if samples == 0 {
+        let _ = remove_file(tmp_path);
+        return Err(AudioProcessingError::EmptyInput);
+    }
+
+    move_into_place(tmp_path, target_path)?;
+
+    Ok(target_path.to_path_buf())
+}
+
+fn encode_for_import(
+    source_path: &Path,
+    selection: &TrackSelection,
+    ogg_path: &Path,
+    sample_rate: NonZeroU32,
+) -> Result<usize, AudioProcessingError> {
+    match encode_to_ogg(source_path, selection, ogg_path, sample_rate) {
+        Err(AudioProcessingError::AudioUtils(_original_err))
+            if !matches!(_original_err, hypr_audio_utils::Error::TrackNotFound(_)) =>
+        {
             #[cfg(target_os = "macos")]
             {
                 let wav_path = hypr_afconvert::to_wav(source_path)
                     .map_err(|e| AudioProcessingError::AfconvertFailed(e.to_string()))?;
-                let result = (|| {
-                    let file = File::open(&wav_path)?;
-                    let decoder = rodio::Decoder::try_from(file)?;
-                    import_audio_from_decoder(decoder, tmp_path, target_path)
-                })();
+                let result =
+                    encode_to_ogg(&wav_path, &TrackSelection::Default, ogg_path, sample_rate);
                 let _ = std::fs::remove_file(&wav_path);
                 result
             }
@@ -88,45 +244,32 @@ pub fn import_audio(
                 Err(_original_err.into())
             }
         }
+        result => result,
     }
 }
 
-fn import_audio_from_decoder<R: std::io::Read + std::io::Seek>(
-    decoder: rodio::Decoder<R>,
-    tmp_path: &Path,
-    target_path: &Path,
-) -> Result<PathBuf, AudioProcessingError> {
-    let channel_count_raw = decoder.channels().max(1);
-    let channel_count_u8 = u8::try_from(channel_count_raw).map_err(|_| {
-        AudioProcessingError::UnsupportedChannelCount {
-            count: channel_count_raw,
-        }
-    })?;
-    let channel_count =
-        NonZeroU8::new(channel_count_u8).ok_or(AudioProcessingError::InvalidChannelCount)?;
-
-    let samples = resample_audio(decoder, TARGET_SAMPLE_RATE_HZ)?;
-    let mono_samples = if channel_count.get() > 1 {
-        mix_down_to_mono(&samples, channel_count)
-    } else {
-        samples
-    };
-
-    if mono_samples.is_empty() {
-        return Err(AudioProcessingError::EmptyInput);
-    }
-
-    let target_sample_rate = NonZeroU32::new(TARGET_SAMPLE_RATE_HZ)
-        .ok_or(AudioProcessingError::InvalidTargetSampleRate)?;
+/// Decodes `source_path` straight into an Ogg Vorbis file, block by block, so
+/// long recordings are never held in memory. Returns the number of samples.
+fn encode_to_ogg(
+    source_path: &Path,
+    selection: &TrackSelection,
+    ogg_path: &Path,
+    sample_rate: NonZeroU32,
+) -> Result<usize, AudioProcessingError> {
+    let file = BufWriter::new(File::create(ogg_path)?);
+    let mut writer = VorbisMonoWriter::new(file, sample_rate, VorbisEncodeSettings::default())?;
 
-    let ogg_buffer = encode_vorbis_mono(
-        &mono_samples,
-        target_sample_rate,
-        VorbisEncodeSettings::default(),
-    )?;
+    let mut samples = 0;
+    stream_media_mono(source_path, selection, sample_rate.get(), |block| {
+        samples += block.len();
+        writer.write(block)
+    })?;
+    writer.finish()?.flush()?;
 
-    write(tmp_path, &ogg_buffer)?;
+    Ok(samples)
+}
 
+fn move_into_place(tmp_path: &Path, target_path: &Path) -> std::io::Result<()> {
     match rename(tmp_path, target_path) {
         Ok(()) => {}
         Err(err) => {
@@ -152,17 +295,17 @@ fn import_audio_from_decoder<R: std::io::Read + std::io::Seek>(
                             copy(tmp_path, target_path)?;
                             remove_file(tmp_path)?;
                         } else {
-                            return Err(rename_err.into());
+                            return Err(rename_err);
                         }
                     }
                 }
             } else {
-                return Err(err.into());
+                return Err(err);
             }
         }
     }
 
-    Ok(target_path.to_path_buf())
+    Ok(())
 }
 
 #[cfg(test)]
@@ -170,6 +313,27 @@ mod tests {
     use super::*;
     use assert_fs::TempDir;
 
+    #[test]
+    fn prune_stems_keeps_recent_and_combined_audio() {
+        let temp = TempDir::new().unwrap();
+        let session = temp
+            .path()
+            .join("work")
+            .join("550e8400-e29b-41d4-a716-446655440000");
+        std::fs::create_dir_all(&session).unwrap();
+        for name in ["audio.flac", "audio_mic.flac", "audio_spk.flac"] {
+            std::fs::write(session.join(name), b"x").unwrap();
+        }
+
+        assert_eq!(
+            prune_stems(temp.path(), std::time::Duration::from_secs(3600)),
+            0
+        );
+        assert_eq!(prune_stems(temp.path(), std::time::Duration::ZERO), 2);
+        assert!(session.join("audio.flac").exists());
+        assert!(!session.join("audio_mic.flac").exists());
+    }
+
     macro_rules! test_import_audio {
         ($($name:ident: $path:expr),* $(,)?) => {
             $(
@@ -201,5 +365,43 @@ mod tests {
         test_import_aac: hypr_data::english_1::AUDIO_AAC_PATH,
         test_import_aiff: hypr_data::english_1::AUDIO_AIFF_PATH,
         test_import_caf: hypr_data::english_1::AUDIO_CAF_PATH,
+        test_import_webm: hypr_data::english_1::AUDIO_WEBM_PATH,
+        test_import_opus: hypr_data::english_1::AUDIO_OPUS_PATH,
+    }
+
+    #[test]
+    fn import_media_as_new_session_creates_session_folder() {
+        let temp = TempDir::new().unwrap();
+        let source = temp.path().join("GMT20240315-143000_Recording.m4a");
+        std::fs::copy(hypr_data::english_1::AUDIO_M4A_PATH, &source).unwrap();
+
+        let sessions = temp.path().join("sessions");
+        let imported =
+            import_media_as_new_session(&sessions, &source, &TrackSelection::MixAll).unwrap();
+
+        assert!(crate::path::is_uuid(&imported.session_id));
+        assert!(
+            sessions
+                .join(&imported.session_id)
+                .join("audio.ogg")
+                .exists()
+        );
+        assert_eq!(imported.title, "GMT20240315-143000_Recording");
+        assert_eq!(imported.created_at, "2024-03-15T14:30:00+00:00");
+    }
+
+    #[test]
+    fn failed_import_leaves_no_session_folder() {
+        let temp = TempDir::new().unwrap();
+        let sessions = temp.path().join("sessions");
+
+        let result = import_media_as_new_session(
+            &sessions,
+            std::path::Path::new(hypr_data::english_1::AUDIO_M4A_PATH),
+            &TrackSelection::Track(42),
+        );
+
+        assert!(result.is_err());
+        assert_eq!(std::fs::read_dir(&sessions).unwrap().count(), 0);
     }
 }
@@ -11,7 +11,10 @@ use crate::FsSyncPluginExt;
 use crate::frontmatter::ParsedDocument;
 use crate::session::find_session_dir;
 use crate::session_content::load_session_content as load_session_content_from_fs;
-use crate::types::{CleanupTarget, ListFoldersResult, ScanResult, SessionContentData};
+use crate::types::{
+    CleanupTarget, ImportedMediaSession, ListFoldersResult, MediaProbeResult, MediaTrackSelection,
+    ScanResult, SessionContentData,
+};
 
 macro_rules! spawn_blocking {
     ($body:expr) => {
@@ -226,6 +229,39 @@ pub(crate) async fn audio_import<R: tauri::Runtime>(
         .map_err(|e| e.to_string())
 }
 
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn media_probe(source_path: String) -> Result<MediaProbeResult, String> {
+    spawn_blocking!({
+        hypr_audio_utils::probe_media(&source_path)
+            .map(MediaProbeResult::from)
+            .map_err(|e| e.to_string())
+    })
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn media_import<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    source_path: String,
+    track_selection: MediaTrackSelection,
+) -> Result<ImportedMediaSession, String> {
+    let base = app
+        .settings()
+        .cached_vault_base()
+        .map_err(|e| e.to_string())?;
+    let sessions_dir = base.join("sessions");
+
+    spawn_blocking!({
+        crate::audio::import_media_as_new_session(
+            &sessions_dir,
+            &PathBuf::from(&source_path),
+            &track_selection.into(),
+        )
+        .map_err(|e| e.to_string())
+    })
+}
+
 #[tauri::command]
 #[specta::specta]
 pub(crate) async fn audio_path<R: tauri::Runtime>(
@@ -247,6 +283,36 @@ pub(crate) async fn session_dir<R: tauri::Runtime>(
     resolve_session_dir(&app, &session_id).map(|p| p.to_string_lossy().to_string())
 }
 
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn export_session<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    session_id: String,
+    format: hypr_export::ExportFormat,
+    output_path: String,
+) -> Result<(), String> {
+    let session_dir = resolve_session_dir(&app, &session_id)?;
+    spawn_blocking!({
+        hypr_export::export_session(&session_dir, &format, &output_path).map_err(|e| e.to_string())
+    })
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn export_vault<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    options: hypr_export::VaultOptions,
+    output_dir: String,
+) -> Result<hypr_export::vault::VaultExportStats, String> {
+    let base = app
+        .settings()
+        .cached_vault_base()
+        .map_err(|e| e.to_string())?;
+    spawn_blocking!({
+        hypr_export::export_vault(&base, &output_dir, &options).map_err(|e| e.to_string())
+    })
+}
+
 #[tauri::command]
 #[specta::specta]
 pub(crate) async fn load_session_content<R: tauri::Runtime>(
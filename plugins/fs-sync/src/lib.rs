@@ -17,6 +17,7 @@ mod types;
 
 pub use types::*;
 
+pub use audio::prune_stems;
 pub use error::{Error, Result};
 pub use ext::*;
 pub use path::is_uuid;
@@ -42,8 +43,12 @@ fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
             commands::audio_delete::<tauri::Wry>,
             commands::audio_import::<tauri::Wry>,
             commands::audio_path::<tauri::Wry>,
+            commands::media_probe,
+            commands::media_import::<tauri::Wry>,
             commands::session_dir::<tauri::Wry>,
             commands::load_session_content::<tauri::Wry>,
+            commands::export_session::<tauri::Wry>,
+            commands::export_vault::<tauri::Wry>,
             commands::delete_session_folder::<tauri::Wry>,
             commands::scan_and_read::<tauri::Wry>,
             commands::chat_dir::<tauri::Wry>,
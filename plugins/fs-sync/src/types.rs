@@ -138,3 +138,73 @@ pub struct SessionContentData {
     pub transcript: Option<TranscriptData>,
     pub notes: Vec<SessionNoteData>,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct MediaTrackInfo {
+    pub id: u32,
+    pub codec: String,
+    pub language: Option<String>,
+    pub sample_rate: Option<u32>,
+    pub channels: Option<u16>,
+    pub duration_secs: Option<f64>,
+    pub is_default: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct MediaProbeResult {
+    pub tracks: Vec<MediaTrackInfo>,
+    pub duration_secs: Option<f64>,
+}
+
+impl From<hypr_audio_utils::MediaInfo> for MediaProbeResult {
+    fn from(info: hypr_audio_utils::MediaInfo) -> Self {
+        Self {
+            tracks: info
+                .tracks
+                .into_iter()
+                .map(|t| MediaTrackInfo {
+                    id: t.id,
+                    codec: t.codec,
+                    language: t.language,
+                    sample_rate: t.sample_rate,
+                    channels: t.channels,
+                    duration_secs: t.duration_secs,
+                    is_default: t.is_default,
+                })
+                .collect(),
+            duration_secs: info.duration_secs,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Type)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum MediaTrackSelection {
+    #[default]
+    Default,
+    Track {
+        id: u32,
+    },
+    MixAll,
+}
+
+impl From<MediaTrackSelection> for hypr_audio_utils::TrackSelection {
+    fn from(selection: MediaTrackSelection) -> Self {
+        match selection {
+            MediaTrackSelection::Default => Self::Default,
+            MediaTrackSelection::Track { id } => Self::Track(id),
+            MediaTrackSelection::MixAll => Self::MixAll,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportedMediaSession {
+    pub session_id: String,
+    pub audio_path: String,
+    pub title: String,
+    pub created_at: String,
+}
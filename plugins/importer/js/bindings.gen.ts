@@ -14,21 +14,37 @@ async listAvailableSources() : Promise<Result<ImportSourceInfo[], string>> {
     else return { status: "error", error: e  as any };
 }
 },
-async runImport(source: ImportSourceKind, userId: string) : Promise<Result<ImportDataResult, string>> {
+async runImport(source: ImportSourceKind, userId: string, policy: ConflictPolicy) : Promise<Result<ImportDataResult, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("plugin:importer|run_import", { source, userId }) };
+    return { status: "ok", data: await TAURI_INVOKE("plugin:importer|run_import", { source, userId, policy }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async runImportDry(source: ImportSourceKind) : Promise<Result<ImportStats, string>> {
+async runImportDry(source: ImportSourceKind) : Promise<Result<ImportPlan, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("plugin:importer|run_import_dry", { source }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
+},
+async runImportFromPath(path: string, transform: TransformKind, userId: string, policy: ConflictPolicy) : Promise<Result<ImportDataResult, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:importer|run_import_from_path", { path, transform, userId, policy }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async runImportFromPathDry(path: string, transform: TransformKind) : Promise<Result<ImportPlan, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:importer|run_import_from_path_dry", { path, transform }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
 }
 }
 
@@ -42,12 +58,42 @@ async runImportDry(source: ImportSourceKind) : Promise<Result<ImportStats, strin
 
 /** user-defined types **/
 
+/**
+ * What to do with an imported session that matches one already in the vault
+ * but differs from it.
+ */
+export type ConflictPolicy = 
+/**
+ * Keep the vault's session untouched.
+ */
+"skip" | 
+/**
+ * Replace the vault's session and transcript with the imported ones.
+ */
+"overwrite" | 
+/**
+ * Keep the vault's session and only add what it is missing.
+ */
+"merge"
 export type ImportDataResult = { stats: ImportStats; data: JsonValue }
+export type ImportPlan = { stats: ImportStats; sessions: SessionPlan[]; newCount: number; duplicateCount: number; conflictCount: number; existingHumansCount: number }
 export type ImportSourceInfo = { kind: ImportSourceKind | null; transform: TransformKind; name: string; path: string; revealPath: string }
-export type ImportSourceKind = "granola" | "hyprnote_v0_stable" | "hyprnote_v0_nightly" | "as_is"
+export type ImportSourceKind = "granola" | "hyprnote_v0_stable" | "hyprnote_v0_nightly" | "apple_notes" | "as_is"
 export type ImportStats = { sessionsCount: number; transcriptsCount: number; humansCount: number; organizationsCount: number; participantsCount: number; templatesCount: number; enhancedNotesCount: number }
 export type JsonValue = null | boolean | number | string | JsonValue[] | Partial<{ [key in string]: JsonValue }>
-export type TransformKind = "hyprnote_v0" | "granola" | "as_is"
+export type MatchKind = "source_id" | "title_and_date" | "transcript_fingerprint"
+export type PlanStatus = "new" | 
+/**
+ * Already in the vault with the same title and transcript.
+ */
+"duplicate" | 
+/**
+ * Matches a vault session whose title or transcript differs.
+ */
+"conflict"
+export type SessionMatch = { sessionId: string; kind: MatchKind }
+export type SessionPlan = { sessionId: string; title: string; status: PlanStatus; matched: SessionMatch | null }
+export type TransformKind = "hyprnote_v0" | "granola" | "apple_notes" | "otter" | "fireflies" | "zoom" | "subtitles" | "as_is"
 
 /** tauri-specta globals **/
 
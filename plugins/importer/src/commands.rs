@@ -1,5 +1,7 @@
 use crate::ext::ImporterPluginExt;
-use crate::types::{ImportDataResult, ImportSourceInfo, ImportSourceKind, ImportStats};
+use crate::types::{
+    ConflictPolicy, ImportDataResult, ImportPlan, ImportSourceInfo, ImportSourceKind, TransformKind,
+};
 
 #[tauri::command]
 #[specta::specta]
@@ -16,9 +18,10 @@ pub async fn run_import<R: tauri::Runtime>(
     app: tauri::AppHandle<R>,
     source: ImportSourceKind,
     user_id: String,
+    policy: ConflictPolicy,
 ) -> Result<ImportDataResult, String> {
     app.importer()
-        .run_import(source, user_id)
+        .run_import(source, user_id, policy)
         .await
         .map_err(|e| e.to_string())
 }
@@ -28,9 +31,37 @@ pub async fn run_import<R: tauri::Runtime>(
 pub async fn run_import_dry<R: tauri::Runtime>(
     app: tauri::AppHandle<R>,
     source: ImportSourceKind,
-) -> Result<ImportStats, String> {
+) -> Result<ImportPlan, String> {
     app.importer()
         .run_import_dry(source)
         .await
         .map_err(|e| e.to_string())
 }
+
+#[tauri::command]
+#[specta::specta]
+pub async fn run_import_from_path<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    path: String,
+    transform: TransformKind,
+    user_id: String,
+    policy: ConflictPolicy,
+) -> Result<ImportDataResult, String> {
+    app.importer()
+        .run_import_from_path(path.into(), transform, user_id, policy)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn run_import_from_path_dry<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    path: String,
+    transform: TransformKind,
+) -> Result<ImportPlan, String> {
+    app.importer()
+        .run_import_from_path_dry(path.into(), transform)
+        .await
+        .map_err(|e| e.to_string())
+}
@@ -6,6 +6,12 @@ pub enum Error {
     #[error("granola error: {0}")]
     Granola(#[from] hypr_granola::error::Error),
 
+    #[error("apple notes error: {0}")]
+    AppleNotes(#[from] hypr_apple_note::Error),
+
+    #[error("transcript import error: {0}")]
+    TranscriptImport(#[from] hypr_transcript_import::Error),
+
     #[error("io error: {0}")]
     Io(#[from] std::io::Error),
 
@@ -1,13 +1,19 @@
+use std::path::PathBuf;
+
+use crate::plan::{self, VaultIndex};
 use crate::types::{
-    ImportDataResult, ImportSource, ImportSourceInfo, ImportSourceKind, ImportStats,
+    ConflictPolicy, ImportDataResult, ImportPlan, ImportSource, ImportSourceInfo, ImportSourceKind,
+    ImportStats, TransformKind,
 };
 use hypr_importer_core::output::to_tinybase_json;
+use tauri_plugin_settings::SettingsPluginExt;
 
-pub struct Importer<R: tauri::Runtime> {
+pub struct Importer<'a, R: tauri::Runtime, M: tauri::Manager<R>> {
+    manager: &'a M,
     _runtime: std::marker::PhantomData<fn() -> R>,
 }
 
-impl<R: tauri::Runtime> Importer<R> {
+impl<'a, R: tauri::Runtime, M: tauri::Manager<R>> Importer<'a, R, M> {
     pub fn list_available_sources(&self) -> Vec<ImportSourceInfo> {
         crate::sources::list_available_sources()
     }
@@ -16,21 +22,39 @@ impl<R: tauri::Runtime> Importer<R> {
         &self,
         source_kind: ImportSourceKind,
         user_id: String,
+        policy: ConflictPolicy,
     ) -> Result<ImportDataResult, crate::Error> {
         let source = ImportSource::from(source_kind.clone());
-        self.run_import_from_source(&source, user_id).await
+        self.run_import_from_source(&source, user_id, policy).await
+    }
+
+    /// Imports a user-picked export file (or folder of them) with the given transform.
+    pub async fn run_import_from_path(
+        &self,
+        path: PathBuf,
+        transform: TransformKind,
+        user_id: String,
+        policy: ConflictPolicy,
+    ) -> Result<ImportDataResult, crate::Error> {
+        let source = ImportSource::from_path(path, transform);
+        self.run_import_from_source(&source, user_id, policy).await
     }
 
     pub async fn run_import_from_source(
         &self,
         source: &ImportSource,
         user_id: String,
+        policy: ConflictPolicy,
     ) -> Result<ImportDataResult, crate::Error> {
         if !source.is_available() {
             return Err(crate::Error::SourceNotAvailable(source.name.clone()));
         }
 
         let data = crate::sources::import_all(source).await?;
+        let vault = self.vault_index()?;
+        let plan = plan::plan(&data, &vault);
+        let data = plan::apply(data, &plan, &vault, policy);
+
         let stats = ImportStats::from_data(&data);
         let tinybase_json = to_tinybase_json(&data, &user_id);
 
@@ -43,31 +67,52 @@ impl<R: tauri::Runtime> Importer<R> {
     pub async fn run_import_dry(
         &self,
         source_kind: ImportSourceKind,
-    ) -> Result<ImportStats, crate::Error> {
+    ) -> Result<ImportPlan, crate::Error> {
         let source = ImportSource::from(source_kind.clone());
         self.run_import_dry_from_source(&source).await
     }
 
+    pub async fn run_import_from_path_dry(
+        &self,
+        path: PathBuf,
+        transform: TransformKind,
+    ) -> Result<ImportPlan, crate::Error> {
+        let source = ImportSource::from_path(path, transform);
+        self.run_import_dry_from_source(&source).await
+    }
+
+    /// Reads the source and diffs it against the vault without writing anything.
     pub async fn run_import_dry_from_source(
         &self,
         source: &ImportSource,
-    ) -> Result<ImportStats, crate::Error> {
+    ) -> Result<ImportPlan, crate::Error> {
         if !source.is_available() {
             return Err(crate::Error::SourceNotAvailable(source.name.clone()));
         }
 
         let data = crate::sources::import_all(source).await?;
-        Ok(ImportStats::from_data(&data))
+        Ok(plan::plan(&data, &self.vault_index()?))
+    }
+
+    fn vault_index(&self) -> Result<VaultIndex, crate::Error> {
+        let base = self.manager.app_handle().settings().cached_vault_base()?;
+        Ok(VaultIndex::load(&base))
     }
 }
 
 pub trait ImporterPluginExt<R: tauri::Runtime> {
-    fn importer(&self) -> Importer<R>;
+    fn importer(&self) -> Importer<'_, R, Self>
+    where
+        Self: tauri::Manager<R> + Sized;
 }
 
 impl<R: tauri::Runtime, T: tauri::Manager<R>> ImporterPluginExt<R> for T {
-    fn importer(&self) -> Importer<R> {
+    fn importer(&self) -> Importer<'_, R, Self>
+    where
+        Self: Sized,
+    {
         Importer {
+            manager: self,
             _runtime: std::marker::PhantomData,
         }
     }
@@ -3,6 +3,7 @@ use tauri::Wry;
 mod commands;
 mod error;
 mod ext;
+mod plan;
 mod sources;
 mod types;
 
@@ -20,6 +21,8 @@ fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
             commands::list_available_sources::<Wry>,
             commands::run_import::<Wry>,
             commands::run_import_dry::<Wry>,
+            commands::run_import_from_path::<Wry>,
+            commands::run_import_from_path_dry::<Wry>,
         ])
         .error_handling(tauri_specta::ErrorHandlingMode::Result)
 }
@@ -0,0 +1,596 @@
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use crate::types::{
+    Collection, ConflictPolicy, ImportPlan, ImportStats, MatchKind, PlanStatus, SessionMatch,
+    SessionPlan, Word,
+};
+
+const META_FILE: &str = "_meta.json";
+const TRANSCRIPT_FILE: &str = "transcript.json";
+
+// Imports of the same meeting from different tools rarely agree on the exact start.
+const TITLE_DATE_WINDOW_MINUTES: i64 = 30;
+
+/// The sessions and people already in the vault, as far as matching needs.
+#[derive(Debug, Default)]
+pub struct VaultIndex {
+    sessions: Vec<VaultSession>,
+    humans: HashSet<String>,
+}
+
+#[derive(Debug)]
+struct VaultSession {
+    id: String,
+    title: String,
+    created_at: Option<DateTime<Utc>>,
+    transcript_ids: Vec<String>,
+    fingerprint: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct MetaJson {
+    #[serde(default)]
+    title: String,
+    #[serde(default)]
+    created_at: String,
+}
+
+#[derive(Deserialize)]
+struct TranscriptJson {
+    #[serde(default)]
+    transcripts: Vec<TranscriptEntry>,
+}
+
+#[derive(Deserialize)]
+struct TranscriptEntry {
+    id: String,
+    #[serde(default)]
+    words: Vec<WordEntry>,
+}
+
+#[derive(Deserialize)]
+struct WordEntry {
+    #[serde(default)]
+    text: String,
+}
+
+impl VaultIndex {
+    pub fn load(vault_base: &Path) -> Self {
+        let mut index = Self::default();
+        index.load_sessions(&vault_base.join("sessions"));
+
+        if let Ok(entries) = std::fs::read_dir(vault_base.join("humans")) {
+            index.humans = entries
+                .flatten()
+                .filter_map(|e| {
+                    let path = e.path();
+                    (path.extension()? == "md")
+                        .then(|| path.file_stem()?.to_str().map(String::from))
+                        .flatten()
+                })
+                .collect();
+        }
+
+        index
+    }
+
+    // Sessions live at `sessions/<id>/` or nested under folders.
+    fn load_sessions(&mut self, dir: &Path) {
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            return;
+        };
+
+        for path in entries.flatten().map(|e| e.path()).filter(|p| p.is_dir()) {
+            let Some(meta) = std::fs::read_to_string(path.join(META_FILE))
+                .ok()
+                .and_then(|s| serde_json::from_str::<MetaJson>(&s).ok())
+            else {
+                self.load_sessions(&path);
+                continue;
+            };
+
+            let transcripts = std::fs::read_to_string(path.join(TRANSCRIPT_FILE))
+                .ok()
+                .and_then(|s| serde_json::from_str::<TranscriptJson>(&s).ok())
+                .map(|t| t.transcripts)
+                .unwrap_or_default();
+
+            self.sessions.push(VaultSession {
+                id: path
+                    .file_name()
+                    .map(|n| n.to_string_lossy().to_string())
+                    .unwrap_or_default(),
+                title: meta.title,
+                created_at: parse_datetime(&meta.created_at),
+                transcript_ids: transcripts.iter().map(|t| t.id.clone()).collect(),
+                fingerprint: fingerprint(
+                    transcripts
+                        .iter()
+                        .flat_map(|t| t.words.iter().map(|w| w.text.as_str())),
+                ),
+            });
+        }
+    }
+
+    fn session(&self, id: &str) -> Option<&VaultSession> {
+        self.sessions.iter().find(|s| s.id == id)
+    }
+
+    fn find_match(
+        &self,
+        claimed: &HashSet<&str>,
+        id: &str,
+        title: &str,
+        created_at: Option<DateTime<Utc>>,
+        fingerprint: Option<&str>,
+    ) -> Option<(&VaultSession, MatchKind)> {
+        if let Some(session) = self.session(id) {
+            return Some((session, MatchKind::SourceId));
+        }
+
+        // Each vault session is matched by at most one imported session.
+        let mut unclaimed = self
+            .sessions
+            .iter()
+            .filter(|s| !claimed.contains(s.id.as_str()));
+
+        if let Some(fingerprint) = fingerprint
+            && let Some(session) = unclaimed
+                .clone()
+                .find(|s| s.fingerprint.as_deref() == Some(fingerprint))
+        {
+            return Some((session, MatchKind::TranscriptFingerprint));
+        }
+
+        let title = normalize_title(title);
+        let created_at = created_at?;
+        if title.is_empty() {
+            return None;
+        }
+        unclaimed
+            .find(|s| {
+                normalize_title(&s.title) == title
+                    && s.created_at.is_some_and(|at| {
+                        (at - created_at).num_minutes().abs() <= TITLE_DATE_WINDOW_MINUTES
+                    })
+            })
+            .map(|s| (s, MatchKind::TitleAndDate))
+    }
+}
+
+/// Diffs an import against the vault without changing either.
+pub fn plan(data: &Collection, vault: &VaultIndex) -> ImportPlan {
+    let mut plan = ImportPlan {
+        stats: ImportStats::from_data(data),
+        existing_humans_count: data
+            .humans
+            .iter()
+            .filter(|h| vault.humans.contains(&h.id))
+            .count(),
+        ..Default::default()
+    };
+
+    let mut claimed = HashSet::new();
+    for session in &data.sessions {
+        let fingerprint = fingerprint(
+            data.transcripts
+                .iter()
+                .filter(|t| t.session_id == session.id)
+                .flat_map(|t| t.words.iter().map(|w: &Word| w.text.as_str())),
+        );
+
+        let matched = vault.find_match(
+            &claimed,
+            &session.id,
+            &session.title,
+            parse_datetime(&session.created_at),
+            fingerprint.as_deref(),
+        );
+
+        if let Some((existing, _)) = matched {
+            claimed.insert(existing.id.as_str());
+        }
+
+        let status = match matched {
+            None => PlanStatus::New,
+            Some((existing, _))
+                if normalize_title(&existing.title) == normalize_title(&session.title)
+                    && (fingerprint.is_none() || fingerprint == existing.fingerprint) =>
+            {
+                PlanStatus::Duplicate
+            }
+            Some(_) => PlanStatus::Conflict,
+        };
+
+        match status {
+            PlanStatus::New => plan.new_count += 1,
+            PlanStatus::Duplicate => plan.duplicate_count += 1,
+            PlanStatus::Conflict => plan.conflict_count += 1,
+        }
+
+        plan.sessions.push(SessionPlan {
+            session_id: session.id.clone(),
+            title: session.title.clone(),
+            status,
+            matched: matched.map(|(existing, kind)| SessionMatch {
+                session_id: existing.id.clone(),
+                kind,
+            }),
+        });
+    }
+
+    plan
+}
+
+enum Action<'a> {
+    Keep,
+    Drop,
+    Overwrite(&'a VaultSession),
+    Merge(&'a VaultSession),
+}
+
+/// Narrows an import down to what should be written under `policy`.
+///
+/// Duplicates and people already in the vault are always left out, so
+/// running the same import twice writes nothing the second time.
+pub fn apply(
+    mut data: Collection,
+    plan: &ImportPlan,
+    vault: &VaultIndex,
+    policy: ConflictPolicy,
+) -> Collection {
+    let actions: HashMap<&str, Action> = plan
+        .sessions
+        .iter()
+        .map(|s| {
+            let existing = s
+                .matched
+                .as_ref()
+                .and_then(|m| vault.session(&m.session_id));
+            let action = match (s.status, existing, policy) {
+                (PlanStatus::New, _, _) => Action::Keep,
+                (PlanStatus::Conflict, Some(existing), ConflictPolicy::Overwrite) => {
+                    Action::Overwrite(existing)
+                }
+                (PlanStatus::Conflict, Some(existing), ConflictPolicy::Merge) => {
+                    Action::Merge(existing)
+                }
+                _ => Action::Drop,
+            };
+            (s.session_id.as_str(), action)
+        })
+        .collect();
+
+    let target = |session_id: &str| match actions.get(session_id) {
+        Some(Action::Drop) => None,
+        Some(Action::Overwrite(existing)) | Some(Action::Merge(existing)) => {
+            Some(existing.id.clone())
+        }
+        _ => Some(session_id.to_string()),
+    };
+
+    data.sessions
+        .retain_mut(|session| match actions.get(session.id.as_str()) {
+            Some(Action::Drop) | Some(Action::Merge(_)) => false,
+            Some(Action::Overwrite(existing)) => {
+                session.id = existing.id.clone();
+                true
+            }
+            _ => true,
+        });
+
+    let mut reused_transcripts: HashMap<String, usize> = HashMap::new();
+    data.transcripts.retain_mut(|transcript| {
+        match actions.get(transcript.session_id.as_str()) {
+            Some(Action::Drop) => false,
+            Some(Action::Merge(existing)) if !existing.transcript_ids.is_empty() => false,
+            Some(Action::Overwrite(existing)) | Some(Action::Merge(existing)) => {
+                // Reuse the vault's transcript ids so they are replaced rather
+                // than duplicated.
+                let used = reused_transcripts.entry(existing.id.clone()).or_default();
+                if let Some(id) = existing.transcript_ids.get(*used) {
+                    transcript.id = id.clone();
+                    *used += 1;
+                }
+                transcript.session_id = existing.id.clone();
+                true
+            }
+            _ => true,
+        }
+    });
+
+    data.participants
+        .retain_mut(|p| match target(&p.session_id) {
+            Some(id) => {
+                p.id = p.id.replace(&p.session_id, &id);
+                p.session_id = id;
+                true
+            }
+            None => false,
+        });
+    data.enhanced_notes
+        .retain_mut(|n| match target(&n.session_id) {
+            Some(id) => {
+                n.session_id = id;
+                true
+            }
+            None => false,
+        });
+    data.tag_mappings
+        .retain_mut(|m| match target(&m.session_id) {
+            Some(id) => {
+                m.id = m.id.replace(&m.session_id, &id);
+                m.session_id = id;
+                true
+            }
+            None => false,
+        });
+
+    data.humans.retain(|h| !vault.humans.contains(&h.id));
+
+    data
+}
+
+/// Stable id for a transcript's wording, ignoring case, punctuation and
+/// spacing so the same meeting exported by two tools still matches.
+fn fingerprint<'a>(words: impl Iterator<Item = &'a str>) -> Option<String> {
+    let normalized = words
+        .flat_map(str::split_whitespace)
+        .map(|w| {
+            w.chars()
+                .filter(|c| c.is_alphanumeric())
+                .flat_map(char::to_lowercase)
+                .collect::<String>()
+        })
+        .filter(|w| !w.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    (!normalized.is_empty())
+        .then(|| uuid::Uuid::new_v5(&uuid::Uuid::NAMESPACE_OID, normalized.as_bytes()).to_string())
+}
+
+fn normalize_title(title: &str) -> String {
+    title
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+fn parse_datetime(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|dt| dt.with_timezone(&Utc))
+        .ok()
+        .or_else(|| {
+            chrono::NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S")
+                .ok()
+                .map(|dt| dt.and_utc())
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Human, Session, SessionParticipant, Transcript};
+
+    fn session(id: &str, title: &str, created_at: &str) -> Session {
+        Session {
+            id: id.to_string(),
+            user_id: String::new(),
+            created_at: created_at.to_string(),
+            title: title.to_string(),
+            raw_md: None,
+            enhanced_content: None,
+            folder_id: None,
+            event_id: None,
+        }
+    }
+
+    fn transcript(id: &str, session_id: &str, text: &str) -> Transcript {
+        Transcript {
+            id: id.to_string(),
+            user_id: String::new(),
+            created_at: String::new(),
+            session_id: session_id.to_string(),
+            title: String::new(),
+            started_at: 0.0,
+            ended_at: None,
+            start_ms: None,
+            end_ms: None,
+            words: text
+                .split(' ')
+                .enumerate()
+                .map(|(i, w)| Word {
+                    id: format!("{id}-{i}"),
+                    text: format!(" {w}"),
+                    start_ms: None,
+                    end_ms: None,
+                    channel: 0,
+                    speaker: None,
+                })
+                .collect(),
+            speaker_hints: vec![],
+        }
+    }
+
+    fn vault_session(id: &str, title: &str, created_at: &str, text: &str) -> VaultSession {
+        VaultSession {
+            id: id.to_string(),
+            title: title.to_string(),
+            created_at: parse_datetime(created_at),
+            transcript_ids: vec![format!("{id}-transcript")],
+            fingerprint: fingerprint(std::iter::once(text)),
+        }
+    }
+
+    fn vault() -> VaultIndex {
+        VaultIndex {
+            sessions: vec![
+                vault_session("a", "Weekly sync", "2024-03-01T10:00:00Z", "Hello team."),
+                vault_session("b", "Design review", "2024-03-02T10:00:00Z", "Draft notes"),
+                vault_session("c", "Retro", "2024-04-01T10:00:00Z", "Let's look"),
+            ],
+            humans: HashSet::from(["jane".to_string()]),
+        }
+    }
+
+    fn collection() -> Collection {
+        Collection {
+            sessions: vec![
+                session("a", "Weekly sync", "2024-03-01T10:00:00Z"),
+                session("x", "design review", "2024-03-02T10:20:00Z"),
+                session("y", "Renamed", "2024-05-01T10:00:00Z"),
+                session("z", "Brand new", "2024-06-01T10:00:00Z"),
+            ],
+            transcripts: vec![
+                transcript("t-a", "a", "hello TEAM"),
+                transcript("t-x", "x", "something else"),
+                transcript("t-y", "y", "Let's look!"),
+            ],
+            humans: vec![Human {
+                id: "jane".to_string(),
+                user_id: String::new(),
+                created_at: String::new(),
+                name: "Jane".to_string(),
+                email: None,
+                org_id: None,
+                job_title: None,
+                linkedin_username: None,
+            }],
+            participants: vec![SessionParticipant {
+                id: "x_jane".to_string(),
+                user_id: String::new(),
+                session_id: "x".to_string(),
+                human_id: "jane".to_string(),
+                source: "imported".to_string(),
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_plan_matches_sessions() {
+        let plan = plan(&collection(), &vault());
+
+        let summary: Vec<_> = plan
+            .sessions
+            .iter()
+            .map(|s| {
+                (
+                    s.status,
+                    s.matched.as_ref().map(|m| (m.session_id.as_str(), m.kind)),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (PlanStatus::Duplicate, Some(("a", MatchKind::SourceId))),
+                (PlanStatus::Conflict, Some(("b", MatchKind::TitleAndDate))),
+                (
+                    PlanStatus::Conflict,
+                    Some(("c", MatchKind::TranscriptFingerprint))
+                ),
+                (PlanStatus::New, None),
+            ]
+        );
+        assert_eq!(
+            (plan.new_count, plan.duplicate_count, plan.conflict_count),
+            (1, 1, 2)
+        );
+        assert_eq!(plan.existing_humans_count, 1);
+    }
+
+    #[test]
+    fn test_apply_skip() {
+        let vault = vault();
+        let data = collection();
+        let plan = plan(&data, &vault);
+        let applied = apply(data, &plan, &vault, ConflictPolicy::Skip);
+
+        let ids: Vec<_> = applied.sessions.iter().map(|s| s.id.as_str()).collect();
+        assert_eq!(ids, vec!["z"]);
+        assert!(applied.transcripts.is_empty());
+        assert!(applied.participants.is_empty());
+        assert!(applied.humans.is_empty());
+    }
+
+    #[test]
+    fn test_apply_overwrite_reuses_vault_ids() {
+        let vault = vault();
+        let data = collection();
+        let plan = plan(&data, &vault);
+        let applied = apply(data, &plan, &vault, ConflictPolicy::Overwrite);
+
+        let ids: Vec<_> = applied.sessions.iter().map(|s| s.id.as_str()).collect();
+        assert_eq!(ids, vec!["b", "c", "z"]);
+        assert_eq!(applied.transcripts[0].id, "b-transcript");
+        assert_eq!(applied.transcripts[0].session_id, "b");
+        assert_eq!(applied.transcripts[1].id, "c-transcript");
+        assert_eq!(applied.participants[0].id, "b_jane");
+    }
+
+    #[test]
+    fn test_apply_merge_keeps_vault_session() {
+        let vault = vault();
+        let data = collection();
+        let plan = plan(&data, &vault);
+        let applied = apply(data, &plan, &vault, ConflictPolicy::Merge);
+
+        let ids: Vec<_> = applied.sessions.iter().map(|s| s.id.as_str()).collect();
+        assert_eq!(ids, vec!["z"]);
+        assert!(applied.transcripts.is_empty());
+        assert_eq!(applied.participants[0].session_id, "b");
+    }
+
+    #[test]
+    fn test_reimport_is_idempotent() {
+        let vault = VaultIndex {
+            sessions: vec![vault_session("z", "Brand new", "2024-06-01T10:00:00Z", "")],
+            humans: HashSet::new(),
+        };
+        let data = Collection {
+            sessions: vec![session("z", "Brand new", "2024-06-01T10:00:00Z")],
+            ..Default::default()
+        };
+
+        let plan = plan(&data, &vault);
+        assert_eq!(plan.duplicate_count, 1);
+        let applied = apply(data, &plan, &vault, ConflictPolicy::Overwrite);
+        assert!(applied.sessions.is_empty());
+    }
+
+    #[test]
+    fn test_load_vault() {
+        let dir = tempfile::tempdir().unwrap();
+        let session_dir = dir.path().join("sessions/Work/a");
+        std::fs::create_dir_all(&session_dir).unwrap();
+        std::fs::write(
+            session_dir.join(META_FILE),
+            r#"{"id":"a","title":"Weekly sync","created_at":"2024-03-01T10:00:00Z","participants":[]}"#,
+        )
+        .unwrap();
+        std::fs::write(
+            session_dir.join(TRANSCRIPT_FILE),
+            r#"{"transcripts":[{"id":"t1","words":[{"text":" Hello"},{"text":" team."}]}]}"#,
+        )
+        .unwrap();
+        std::fs::create_dir_all(dir.path().join("humans")).unwrap();
+        std::fs::write(dir.path().join("humans/jane.md"), "---\n---\n").unwrap();
+
+        let vault = VaultIndex::load(dir.path());
+        let session = vault.session("a").unwrap();
+        assert_eq!(session.title, "Weekly sync");
+        assert_eq!(session.transcript_ids, vec!["t1"]);
+        assert_eq!(
+            session.fingerprint,
+            fingerprint(std::iter::once("hello team"))
+        );
+        assert!(vault.humans.contains("jane"));
+    }
+}
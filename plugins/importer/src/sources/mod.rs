@@ -1,6 +1,8 @@
+mod apple_notes;
 mod as_is;
 mod granola;
 mod hyprnote;
+mod transcript_file;
 
 pub use as_is::AsIsData;
 
@@ -10,6 +12,13 @@ pub async fn import_all(source: &ImportSource) -> Result<Collection, crate::Erro
     match source.transform {
         TransformKind::HyprnoteV0 => hyprnote::v0::import_all_from_path(&source.path).await,
         TransformKind::Granola => granola::import_all_from_path(&source.path).await,
+        TransformKind::AppleNotes => apple_notes::import_all_from_path(&source.path).await,
+        TransformKind::Otter
+        | TransformKind::Fireflies
+        | TransformKind::Zoom
+        | TransformKind::Subtitles => {
+            transcript_file::import_all_from_path(&source.path, source.transform).await
+        }
         TransformKind::AsIs => as_is::load_data(&source.path),
     }
 }
@@ -18,6 +27,7 @@ pub fn all_sources() -> Vec<ImportSource> {
     [
         ImportSource::hyprnote_stable(),
         ImportSource::hyprnote_nightly(),
+        ImportSource::apple_notes(),
     ]
     .into_iter()
     .flatten()
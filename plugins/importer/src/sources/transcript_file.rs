@@ -0,0 +1,24 @@
+use crate::types::{Collection, TransformKind};
+use hypr_transcript_import::Format;
+use std::path::{Path, PathBuf};
+
+pub async fn import_all_from_path(
+    path: &Path,
+    transform: TransformKind,
+) -> Result<Collection, crate::Error> {
+    let format = match transform {
+        TransformKind::Otter => Format::Otter,
+        TransformKind::Fireflies => Format::Fireflies,
+        TransformKind::Zoom => Format::Zoom,
+        TransformKind::Subtitles => Format::Subtitles,
+        _ => return Err(crate::Error::InvalidData(format!("{transform:?}"))),
+    };
+
+    let path = PathBuf::from(path);
+    let data = tokio::task::spawn_blocking(move || {
+        hypr_transcript_import::import_all_from_path(&path, format)
+    })
+    .await
+    .map_err(|e| crate::Error::InvalidData(e.to_string()))??;
+    Ok(data)
+}
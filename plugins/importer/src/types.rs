@@ -11,6 +11,11 @@ pub use hypr_importer_core::ir::{
 pub enum TransformKind {
     HyprnoteV0,
     Granola,
+    AppleNotes,
+    Otter,
+    Fireflies,
+    Zoom,
+    Subtitles,
     AsIs,
 }
 
@@ -20,6 +25,7 @@ pub enum ImportSourceKind {
     Granola,
     HyprnoteV0Stable,
     HyprnoteV0Nightly,
+    AppleNotes,
     AsIs,
 }
 
@@ -75,6 +81,21 @@ impl ImportSource {
         })
     }
 
+    /// `None` when this machine has no Notes database.
+    pub fn apple_notes() -> Option<Self> {
+        let path = hypr_apple_note::importer::default_note_store_path();
+        path.exists().then(|| Self::apple_notes_at(path))
+    }
+
+    fn apple_notes_at(path: PathBuf) -> Self {
+        Self {
+            kind: Some(ImportSourceKind::AppleNotes),
+            transform: TransformKind::AppleNotes,
+            path,
+            name: "Apple Notes".to_string(),
+        }
+    }
+
     pub fn is_available(&self) -> bool {
         self.path.exists()
     }
@@ -113,6 +134,10 @@ impl From<ImportSourceKind> for ImportSource {
             ImportSourceKind::HyprnoteV0Stable => Self::hyprnote_stable().unwrap(),
             ImportSourceKind::HyprnoteV0Nightly => Self::hyprnote_nightly().unwrap(),
             ImportSourceKind::Granola => Self::granola().unwrap(),
+            // Without a store this reports the source as unavailable on import.
+            ImportSourceKind::AppleNotes => Self::apple_notes().unwrap_or_else(|| {
+                Self::apple_notes_at(hypr_apple_note::importer::default_note_store_path())
+            }),
             ImportSourceKind::AsIs => Self {
                 kind: Some(ImportSourceKind::AsIs),
                 transform: TransformKind::AsIs,
@@ -165,3 +190,62 @@ pub struct ImportDataResult {
     pub stats: ImportStats,
     pub data: serde_json::Value,
 }
+
+/// What to do with an imported session that matches one already in the vault
+/// but differs from it.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, specta::Type, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictPolicy {
+    /// Keep the vault's session untouched.
+    #[default]
+    Skip,
+    /// Replace the vault's session and transcript with the imported ones.
+    Overwrite,
+    /// Keep the vault's session and only add what it is missing.
+    Merge,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, specta::Type, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PlanStatus {
+    New,
+    /// Already in the vault with the same title and transcript.
+    Duplicate,
+    /// Matches a vault session whose title or transcript differs.
+    Conflict,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, specta::Type, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum MatchKind {
+    SourceId,
+    TitleAndDate,
+    TranscriptFingerprint,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionMatch {
+    pub session_id: String,
+    pub kind: MatchKind,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionPlan {
+    pub session_id: String,
+    pub title: String,
+    pub status: PlanStatus,
+    pub matched: Option<SessionMatch>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, specta::Type)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportPlan {
+    pub stats: ImportStats,
+    pub sessions: Vec<SessionPlan>,
+    pub new_count: usize,
+    pub duplicate_count: usize,
+    pub conflict_count: usize,
+    pub existing_humans_count: usize,
+}
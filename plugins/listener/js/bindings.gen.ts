@@ -109,13 +109,23 @@ sessionProgressEvent: "plugin:listener:session-progress-event"
 
 /** user-defined types **/
 
+export type AudioChannel = "mic" | "speaker"
+export type AudioQualityIssue = { kind: "clipping"; channel: AudioChannel } | { kind: "silence"; channel: AudioChannel } | { kind: "dc_offset"; channel: AudioChannel } | { kind: "echo_residual" } | { kind: "sample_rate_drift" }
 export type DegradedError = { type: "authentication_failed"; provider: string } | { type: "upstream_unavailable"; message: string } | { type: "connection_timeout" } | { type: "stream_error"; message: string }
-export type SessionDataEvent = { type: "audio_amplitude"; session_id: string; mic: number; speaker: number } | { type: "mic_muted"; session_id: string; value: boolean } | { type: "stream_response"; session_id: string; response: StreamResponse }
+export type RecordingFormat = "wav" | "flac" | "opus"
+export type RecordingOptions = { format?: RecordingFormat; stems?: StemRetention }
+export type SessionDataEvent = { type: "audio_amplitude"; session_id: string; mic: number; speaker: number } | { type: "mic_muted"; session_id: string; value: boolean } | { type: "audio_quality_warning"; session_id: string; issue: AudioQualityIssue } | { type: "audio_quality_recovered"; session_id: string; issue: AudioQualityIssue } | { type: "stream_response"; session_id: string; response: StreamResponse }
 export type SessionErrorEvent = { type: "audio_error"; session_id: string; error: string; device: string | null; is_fatal: boolean } | { type: "connection_error"; session_id: string; error: string }
 export type SessionLifecycleEvent = { type: "inactive"; session_id: string; error: string | null } | { type: "active"; session_id: string; error?: DegradedError | null } | { type: "finalizing"; session_id: string }
-export type SessionParams = { session_id: string; languages: string[]; onboarding: boolean; record_enabled: boolean; model: string; base_url: string; api_key: string; keywords: string[] }
+export type SessionParams = { session_id: string; languages: string[]; onboarding: boolean; record_enabled: boolean; model: string; base_url: string; api_key: string; keywords: string[]; recording?: RecordingOptions; 
+/**
+ * Split the mic channel into per-speaker virtual channels using local
+ * diarization, for in-person meetings around a single mic.
+ */
+speaker_separation?: boolean }
 export type SessionProgressEvent = { type: "audio_initializing"; session_id: string } | { type: "audio_ready"; session_id: string; device: string | null } | { type: "connecting"; session_id: string } | { type: "connected"; session_id: string; adapter: string }
 export type State = "active" | "inactive" | "finalizing"
+export type StemRetention = { type: "discard" } | { type: "keep" } | { type: "keep_for_days"; days: number }
 export type StreamAlternatives = { transcript: string; words: StreamWord[]; confidence: number; languages?: string[] }
 export type StreamChannel = { alternatives: StreamAlternatives[] }
 export type StreamExtra = { started_unix_millis: number }
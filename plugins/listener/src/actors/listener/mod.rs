@@ -1,4 +1,5 @@
 mod adapters;
+mod separation;
 mod stream;
 
 use std::time::{Duration, Instant, SystemTime};
@@ -16,6 +17,7 @@ use super::session::session_span;
 use crate::{DegradedError, SessionDataEvent, SessionErrorEvent, SessionProgressEvent};
 
 use adapters::spawn_rx_task;
+use separation::SpeakerSeparation;
 
 pub(super) const LISTEN_STREAM_TIMEOUT: Duration = Duration::from_secs(15 * 60);
 pub(super) const LISTEN_CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
@@ -40,6 +42,7 @@ pub struct ListenerArgs {
     pub api_key: String,
     pub keywords: Vec<String>,
     pub mode: crate::actors::ChannelMode,
+    pub speaker_separation: bool,
     pub session_started_at: Instant,
     pub session_started_at_unix: SystemTime,
     pub session_id: String,
@@ -50,6 +53,7 @@ pub struct ListenerState {
     tx: ChannelSender,
     rx_task: tokio::task::JoinHandle<()>,
     shutdown_tx: Option<tokio::sync::oneshot::Sender<()>>,
+    separation: Option<SpeakerSeparation>,
 }
 
 pub(super) enum ChannelSender {
@@ -115,11 +119,16 @@ impl Actor for ListenerActor {
                 tracing::error!(?error, "failed_to_emit_connected");
             }
 
+            let separation = (args.speaker_separation && args.mode.uses_mic()).then(|| {
+                SpeakerSeparation::spawn(hypr_pyannote_local::embedding::EmbeddingExtractor::new())
+            });
+
             let state = ListenerState {
                 args,
                 tx,
                 rx_task,
                 shutdown_tx: Some(shutdown_tx),
+                separation,
             };
 
             Ok(state)
@@ -151,12 +160,18 @@ impl Actor for ListenerActor {
 
         match message {
             ListenerMsg::AudioSingle(audio) => {
+                if let Some(separation) = &state.separation {
+                    separation.push_mic(&audio);
+                }
                 if let ChannelSender::Single(tx) = &state.tx {
                     let _ = tx.try_send(MixedMessage::Audio(audio));
                 }
             }
 
             ListenerMsg::AudioDual(mic, spk) => {
+                if let Some(separation) = &state.separation {
+                    separation.push_mic(&mic);
+                }
                 if let ChannelSender::Dual(tx) = &state.tx {
                     let _ = tx.try_send(MixedMessage::Audio((mic, spk)));
                 }
@@ -209,6 +224,10 @@ impl Actor for ListenerActor {
                     crate::actors::ChannelMode::MicAndSpeaker => {}
                 }
 
+                if let Some(separation) = &state.separation {
+                    separation.relabel(&mut response);
+                }
+
                 if let Err(error) = (SessionDataEvent::StreamResponse {
                     session_id: state.args.session_id.clone(),
                     response: Box::new(response),
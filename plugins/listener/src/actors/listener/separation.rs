@@ -0,0 +1,382 @@
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+use owhisper_interface::stream::StreamResponse;
+
+use crate::actors::SAMPLE_RATE;
+
+/// Virtual channels start after the real ones (`DirectMic`, `RemoteParty`,
+/// `MixedCapture`), so they never collide with a physical channel.
+const FIRST_VIRTUAL_CHANNEL: i32 = 3;
+
+const WINDOW_SECS: f64 = 1.5;
+const MIN_WINDOW_RMS: f32 = 0.01;
+const SIMILARITY_THRESHOLD: f32 = 0.55;
+const MAX_SPEAKERS: usize = 8;
+/// Responses trail the audio by a few seconds at most.
+const TIMELINE_RETENTION_SECS: f64 = 120.0;
+/// Mic chunks waiting for the embedder. Once it falls this far behind, new
+/// audio is dropped rather than queued without bound.
+const PENDING_CHUNKS: usize = 256;
+
+pub(super) trait SpeakerEmbedder: Send + 'static {
+    fn embed(&mut self, samples: &[f32]) -> Option<Vec<f32>>;
+}
+
+impl SpeakerEmbedder for hypr_pyannote_local::embedding::EmbeddingExtractor {
+    fn embed(&mut self, samples: &[f32]) -> Option<Vec<f32>> {
+        match self.compute(samples.iter().copied()) {
+            Ok(embedding) => Some(embedding),
+            Err(error) => {
+                tracing::warn!(?error, "speaker_embedding_failed");
+                None
+            }
+        }
+    }
+}
+
+/// Splits the mic channel into one virtual channel per in-person speaker.
+///
+/// Mic audio is embedded on a background thread in fixed windows and
+/// clustered online. Mic transcript responses are then relabeled with the
+/// channel of whichever speaker dominated their time span. The first speaker
+/// heard keeps the physical mic channel. Only final responses are relabeled;
+/// partials stay on the mic channel until their final replaces them.
+pub(super) struct SpeakerSeparation {
+    tx: std::sync::mpsc::SyncSender<Vec<f32>>,
+    timeline: Arc<Mutex<SpeakerTimeline>>,
+}
+
+impl SpeakerSeparation {
+    pub(super) fn spawn(embedder: impl SpeakerEmbedder) -> Self {
+        let (tx, rx) = std::sync::mpsc::sync_channel::<Vec<f32>>(PENDING_CHUNKS);
+        let timeline = Arc::new(Mutex::new(SpeakerTimeline::default()));
+
+        let mut worker = SeparationWorker::new(embedder, timeline.clone());
+        std::thread::spawn(move || {
+            while let Ok(samples) = rx.recv() {
+                worker.feed(&samples);
+            }
+        });
+
+        Self { tx, timeline }
+    }
+
+    /// Takes 16-bit little-endian mic audio, exactly as sent upstream.
+    pub(super) fn push_mic(&self, audio: &[u8]) {
+        let samples = hypr_audio_utils::bytes_to_f32_samples(audio);
+        if let Err(std::sync::mpsc::TrySendError::Full(_)) = self.tx.try_send(samples) {
+            tracing::warn!("speaker_separation_lagging");
+        }
+    }
+
+    pub(super) fn relabel(&self, response: &mut StreamResponse) {
+        let StreamResponse::TranscriptResponse {
+            start,
+            duration,
+            is_final,
+            channel_index,
+            ..
+        } = response
+        else {
+            return;
+        };
+        if !*is_final || channel_index.first() != Some(&0) {
+            return;
+        }
+
+        let Ok(timeline) = self.timeline.lock() else {
+            return;
+        };
+        let Some(speaker) = timeline.dominant_speaker(*start, *start + *duration) else {
+            return;
+        };
+        let total = virtual_channel(timeline.speaker_count().saturating_sub(1)) + 1;
+        drop(timeline);
+
+        response.set_channel_index(virtual_channel(speaker), total.max(2));
+    }
+}
+
+fn virtual_channel(speaker: usize) -> i32 {
+    match speaker {
+        0 => 0,
+        n => FIRST_VIRTUAL_CHANNEL + n as i32 - 1,
+    }
+}
+
+struct SeparationWorker<E> {
+    embedder: E,
+    clusterer: OnlineClusterer,
+    timeline: Arc<Mutex<SpeakerTimeline>>,
+    pending: Vec<f32>,
+    offset_samples: u64,
+    window_samples: usize,
+}
+
+impl<E: SpeakerEmbedder> SeparationWorker<E> {
+    fn new(embedder: E, timeline: Arc<Mutex<SpeakerTimeline>>) -> Self {
+        Self {
+            embedder,
+            clusterer: OnlineClusterer::new(SIMILARITY_THRESHOLD, MAX_SPEAKERS),
+            timeline,
+            pending: Vec::new(),
+            offset_samples: 0,
+            window_samples: (SAMPLE_RATE as f64 * WINDOW_SECS) as usize,
+        }
+    }
+
+    fn feed(&mut self, samples: &[f32]) {
+        self.pending.extend_from_slice(samples);
+
+        while self.pending.len() >= self.window_samples {
+            let window: Vec<f32> = self.pending.drain(..self.window_samples).collect();
+            let start = self.offset_samples as f64 / SAMPLE_RATE as f64;
+            self.offset_samples += window.len() as u64;
+            let end = self.offset_samples as f64 / SAMPLE_RATE as f64;
+
+            if rms(&window) < MIN_WINDOW_RMS {
+                continue;
+            }
+            let Some(embedding) = self.embedder.embed(&window) else {
+                continue;
+            };
+
+            let speaker = self.clusterer.assign(&embedding);
+            if let Ok(mut timeline) = self.timeline.lock() {
+                timeline.push(SpeakerSpan {
+                    start,
+                    end,
+                    speaker,
+                });
+                timeline.set_speaker_count(self.clusterer.len());
+            }
+        }
+    }
+}
+
+fn rms(samples: &[f32]) -> f32 {
+    if samples.is_empty() {
+        return 0.0;
+    }
+    (samples.iter().map(|s| s * s).sum::<f32>() / samples.len() as f32).sqrt()
+}
+
+/// Assigns embeddings to the closest speaker centroid by cosine similarity,
+/// opening a new speaker when nothing is close enough.
+struct OnlineClusterer {
+    centroids: Vec<(Vec<f32>, u32)>,
+    threshold: f32,
+    max_speakers: usize,
+}
+
+impl OnlineClusterer {
+    fn new(threshold: f32, max_speakers: usize) -> Self {
+        Self {
+            centroids: Vec::new(),
+            threshold,
+            max_speakers: max_speakers.max(1),
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.centroids.len()
+    }
+
+    fn assign(&mut self, embedding: &[f32]) -> usize {
+        let best = self
+            .centroids
+            .iter()
+            .enumerate()
+            .map(|(i, (centroid, _))| (i, cosine_similarity(centroid, embedding)))
+            .max_by(|a, b| a.1.total_cmp(&b.1));
+
+        match best {
+            Some((i, similarity))
+                if similarity >= self.threshold || self.centroids.len() >= self.max_speakers =>
+            {
+                let (centroid, count) = &mut self.centroids[i];
+                *count += 1;
+                let weight = 1.0 / *count as f32;
+                for (c, e) in centroid.iter_mut().zip(embedding) {
+                    *c += (e - *c) * weight;
+                }
+                i
+            }
+            _ => {
+                self.centroids.push((embedding.to_vec(), 1));
+                self.centroids.len() - 1
+            }
+        }
+    }
+}
+
+fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm_a = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let norm_b = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm_a == 0.0 || norm_b == 0.0 {
+        return 0.0;
+    }
+    dot / (norm_a * norm_b)
+}
+
+#[derive(Debug, Clone, Copy)]
+struct SpeakerSpan {
+    start: f64,
+    end: f64,
+    speaker: usize,
+}
+
+#[derive(Default)]
+struct SpeakerTimeline {
+    spans: VecDeque<SpeakerSpan>,
+    speakers: usize,
+}
+
+impl SpeakerTimeline {
+    fn push(&mut self, span: SpeakerSpan) {
+        self.spans.push_back(span);
+        while self
+            .spans
+            .front()
+            .is_some_and(|s| s.end < span.end - TIMELINE_RETENTION_SECS)
+        {
+            self.spans.pop_front();
+        }
+    }
+
+    fn speaker_count(&self) -> usize {
+        self.speakers
+    }
+
+    fn set_speaker_count(&mut self, speakers: usize) {
+        self.speakers = speakers;
+    }
+
+    fn dominant_speaker(&self, start: f64, end: f64) -> Option<usize> {
+        let mut overlap = vec![0.0f64; self.speakers];
+        for span in &self.spans {
+            let o = span.end.min(end) - span.start.max(start);
+            if o > 0.0
+                && let Some(total) = overlap.get_mut(span.speaker)
+            {
+                *total += o;
+            }
+        }
+
+        overlap
+            .into_iter()
+            .enumerate()
+            .filter(|(_, o)| *o > 0.0)
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(speaker, _)| speaker)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Two "voices": the embedding is just which half of the window is louder.
+    struct FakeEmbedder;
+
+    impl SpeakerEmbedder for FakeEmbedder {
+        fn embed(&mut self, samples: &[f32]) -> Option<Vec<f32>> {
+            let mid = samples.len() / 2;
+            Some(vec![rms(&samples[..mid]), rms(&samples[mid..])])
+        }
+    }
+
+    fn voice(first_half_loud: bool) -> Vec<f32> {
+        let window = (SAMPLE_RATE as f64 * WINDOW_SECS) as usize;
+        (0..window)
+            .map(|i| {
+                let loud = (i < window / 2) == first_half_loud;
+                if loud { 0.5 } else { 0.05 }
+            })
+            .collect()
+    }
+
+    fn mic_response(start: f64, duration: f64, is_final: bool) -> StreamResponse {
+        serde_json::from_value(serde_json::json!({
+            "type": "Results",
+            "start": start,
+            "duration": duration,
+            "is_final": is_final,
+            "speech_final": true,
+            "from_finalize": false,
+            "channel": { "alternatives": [] },
+            "metadata": {
+                "request_id": "",
+                "model_info": { "name": "", "version": "", "arch": "" },
+                "model_uuid": ""
+            },
+            "channel_index": [0, 2]
+        }))
+        .unwrap()
+    }
+
+    fn channel_of(response: &StreamResponse) -> Vec<i32> {
+        match response {
+            StreamResponse::TranscriptResponse { channel_index, .. } => channel_index.clone(),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn clusterer_separates_distinct_embeddings() {
+        let mut clusterer = OnlineClusterer::new(0.9, 4);
+        assert_eq!(clusterer.assign(&[1.0, 0.0]), 0);
+        assert_eq!(clusterer.assign(&[0.0, 1.0]), 1);
+        assert_eq!(clusterer.assign(&[0.95, 0.05]), 0);
+        assert_eq!(clusterer.len(), 2);
+    }
+
+    #[test]
+    fn clusterer_caps_speaker_count() {
+        let mut clusterer = OnlineClusterer::new(0.99, 2);
+        clusterer.assign(&[1.0, 0.0, 0.0]);
+        clusterer.assign(&[0.0, 1.0, 0.0]);
+        let third = clusterer.assign(&[0.0, 0.0, 1.0]);
+        assert!(third < 2);
+        assert_eq!(clusterer.len(), 2);
+    }
+
+    #[test]
+    fn relabels_mic_responses_by_dominant_speaker() {
+        let timeline = Arc::new(Mutex::new(SpeakerTimeline::default()));
+        let mut worker = SeparationWorker::new(FakeEmbedder, timeline.clone());
+
+        worker.feed(&voice(true));
+        worker.feed(&voice(false));
+        worker.feed(&vec![0.0; worker.window_samples]);
+        worker.feed(&voice(true));
+
+        let (tx, _rx) = std::sync::mpsc::sync_channel(PENDING_CHUNKS);
+        let separation = SpeakerSeparation { tx, timeline };
+
+        let mut first = mic_response(0.2, 1.0, true);
+        separation.relabel(&mut first);
+        assert_eq!(channel_of(&first), vec![0, FIRST_VIRTUAL_CHANNEL + 1]);
+
+        let mut second = mic_response(1.6, 1.2, true);
+        separation.relabel(&mut second);
+        assert_eq!(channel_of(&second)[0], FIRST_VIRTUAL_CHANNEL);
+
+        // Silence has no speaker, so the response keeps its physical channel.
+        let mut silent = mic_response(3.1, 1.2, true);
+        separation.relabel(&mut silent);
+        assert_eq!(channel_of(&silent), vec![0, 2]);
+
+        // Partials stay on the mic channel until their final arrives.
+        let mut partial = mic_response(1.6, 1.2, false);
+        separation.relabel(&mut partial);
+        assert_eq!(channel_of(&partial), vec![0, 2]);
+
+        let mut remote = mic_response(1.6, 1.2, true);
+        remote.set_channel_index(1, 2);
+        separation.relabel(&mut remote);
+        assert_eq!(channel_of(&remote), vec![1, 2]);
+    }
+}
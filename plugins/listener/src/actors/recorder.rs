@@ -1,15 +1,19 @@
 use std::fs::File;
 use std::io::BufWriter;
-use std::path::PathBuf;
+use std::num::{NonZeroU8, NonZeroU32};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Instant, SystemTime};
 
 use hypr_audio_utils::{
+    FlacWriter, OggOpusWriter, OpusEncodeSettings, copy_flac_into, copy_opus_into,
     decode_vorbis_to_mono_wav_file, decode_vorbis_to_wav_file, mix_audio_f32,
     ogg_has_identical_channels,
 };
 use ractor::{Actor, ActorName, ActorProcessingErr, ActorRef};
-use tauri_plugin_fs_sync::find_session_dir;
+use tauri_plugin_fs_sync::{find_session_dir, prune_stems};
+
+use super::{RecordingFormat, RecordingOptions, StemRetention};
 
 const FLUSH_INTERVAL: std::time::Duration = std::time::Duration::from_millis(1000);
 
@@ -21,13 +25,15 @@ pub enum RecMsg {
 pub struct RecArgs {
     pub app_dir: PathBuf,
     pub session_id: String,
+    pub started_at: SystemTime,
+    pub options: RecordingOptions,
 }
 
 pub struct RecState {
-    writer: Option<hound::WavWriter<BufWriter<File>>>,
-    writer_mic: Option<hound::WavWriter<BufWriter<File>>>,
-    writer_spk: Option<hound::WavWriter<BufWriter<File>>>,
-    wav_path: PathBuf,
+    writer: Option<AudioWriter>,
+    writer_mic: Option<AudioWriter>,
+    writer_spk: Option<AudioWriter>,
+    audio_path: PathBuf,
     last_flush: Instant,
     is_stereo: bool,
 }
@@ -54,65 +60,60 @@ impl Actor for RecorderActor {
         let dir = find_session_dir(&args.app_dir, &args.session_id);
         std::fs::create_dir_all(&dir)?;
 
+        if let StemRetention::KeepForDays { days } = args.options.stems {
+            let sessions_base = args.app_dir.clone();
+            let max_age = std::time::Duration::from_secs(days as u64 * 24 * 60 * 60);
+            std::thread::spawn(move || {
+                let removed = prune_stems(&sessions_base, max_age);
+                tracing::info!(removed, "stems_pruned");
+            });
+        }
+
         let filename_base = "audio".to_string();
-        let wav_path = dir.join(format!("{}.wav", filename_base));
-        let ogg_path = dir.join(format!("{}.ogg", filename_base));
-
-        let is_stereo = if ogg_path.exists() {
-            let has_identical = ogg_has_identical_channels(&ogg_path).map_err(into_actor_err)?;
-            if has_identical {
-                decode_vorbis_to_mono_wav_file(&ogg_path, &wav_path).map_err(into_actor_err)?;
-            } else {
-                decode_vorbis_to_wav_file(&ogg_path, &wav_path).map_err(into_actor_err)?;
+        // A session that already has audio keeps its format, so resuming never
+        // leaves two competing recordings in one folder.
+        let format = existing_format(&dir, &filename_base).unwrap_or(args.options.format);
+        let audio_path = dir.join(format!("{}.{}", filename_base, format.extension()));
+
+        let tags = RecordingTags::new(&args.session_id, args.started_at);
+        let tags = &tags.pairs()[..];
+
+        let is_stereo = match format {
+            RecordingFormat::Wav => {
+                let ogg_path = dir.join(format!("{}.ogg", filename_base));
+                if ogg_path.exists() {
+                    let has_identical =
+                        ogg_has_identical_channels(&ogg_path).map_err(into_actor_err)?;
+                    if has_identical {
+                        decode_vorbis_to_mono_wav_file(&ogg_path, &audio_path)
+                            .map_err(into_actor_err)?;
+                    } else {
+                        decode_vorbis_to_wav_file(&ogg_path, &audio_path)
+                            .map_err(into_actor_err)?;
+                    }
+                    std::fs::remove_file(&ogg_path)?;
+                    !has_identical
+                } else if audio_path.exists() {
+                    let reader = hound::WavReader::open(&audio_path)?;
+                    reader.spec().channels == 2
+                } else {
+                    true
+                }
             }
-            std::fs::remove_file(&ogg_path)?;
-            !has_identical
-        } else if wav_path.exists() {
-            let reader = hound::WavReader::open(&wav_path)?;
-            reader.spec().channels == 2
-        } else {
-            true
+            RecordingFormat::Flac | RecordingFormat::Opus => true,
         };
 
-        let stereo_spec = hound::WavSpec {
-            channels: 2,
-            sample_rate: super::SAMPLE_RATE,
-            bits_per_sample: 32,
-            sample_format: hound::SampleFormat::Float,
-        };
+        let writer = AudioWriter::open(&audio_path, format, if is_stereo { 2 } else { 1 }, tags)?;
 
-        let mono_spec = hound::WavSpec {
-            channels: 1,
-            sample_rate: super::SAMPLE_RATE,
-            bits_per_sample: 32,
-            sample_format: hound::SampleFormat::Float,
-        };
+        let keep_stems = is_debug_mode() || args.options.stems != StemRetention::Discard;
+        let (writer_mic, writer_spk) = if keep_stems {
+            let mic_path = dir.join(format!("{}_mic.{}", filename_base, format.extension()));
+            let spk_path = dir.join(format!("{}_spk.{}", filename_base, format.extension()));
 
-        let writer = if wav_path.exists() {
-            hound::WavWriter::append(&wav_path)?
-        } else if is_stereo {
-            hound::WavWriter::create(&wav_path, stereo_spec)?
-        } else {
-            hound::WavWriter::create(&wav_path, mono_spec)?
-        };
-
-        let (writer_mic, writer_spk) = if is_debug_mode() {
-            let mic_path = dir.join(format!("{}_mic.wav", filename_base));
-            let spk_path = dir.join(format!("{}_spk.wav", filename_base));
-
-            let mic_writer = if mic_path.exists() {
-                hound::WavWriter::append(&mic_path)?
-            } else {
-                hound::WavWriter::create(&mic_path, mono_spec)?
-            };
-
-            let spk_writer = if spk_path.exists() {
-                hound::WavWriter::append(&spk_path)?
-            } else {
-                hound::WavWriter::create(&spk_path, mono_spec)?
-            };
-
-            (Some(mic_writer), Some(spk_writer))
+            (
+                Some(AudioWriter::open(&mic_path, format, 1, tags)?),
+                Some(AudioWriter::open(&spk_path, format, 1, tags)?),
+            )
         } else {
             (None, None)
         };
@@ -121,7 +122,7 @@ impl Actor for RecorderActor {
             writer: Some(writer),
             writer_mic,
             writer_spk,
-            wav_path,
+            audio_path,
             last_flush: Instant::now(),
             is_stereo,
         })
@@ -137,28 +138,27 @@ impl Actor for RecorderActor {
             RecMsg::AudioSingle(samples) => {
                 if let Some(ref mut writer) = st.writer {
                     if st.is_stereo {
-                        write_mono_as_stereo(writer, &samples)?;
+                        writer.write(&mono_as_stereo(&samples))?;
                     } else {
-                        write_mono_samples(writer, &samples)?;
+                        writer.write(&samples)?;
                     }
                 }
             }
             RecMsg::AudioDual(mic, spk) => {
                 if let Some(ref mut writer) = st.writer {
                     if st.is_stereo {
-                        write_interleaved_stereo(writer, &mic, &spk)?;
+                        writer.write(&interleave_stereo(&mic, &spk))?;
                     } else {
-                        let mixed = mix_audio_f32(&mic, &spk);
-                        write_mono_samples(writer, &mixed)?;
+                        writer.write(&mix_audio_f32(&mic, &spk))?;
                     }
                 }
 
                 if let Some(ref mut writer_mic) = st.writer_mic {
-                    write_mono_samples(writer_mic, &mic)?;
+                    writer_mic.write(&mic)?;
                 }
 
                 if let Some(ref mut writer_spk) = st.writer_spk {
-                    write_mono_samples(writer_spk, &spk)?;
+                    writer_spk.write(&spk)?;
                 }
             }
         }
@@ -172,19 +172,195 @@ impl Actor for RecorderActor {
         _myself: ActorRef<Self::Msg>,
         st: &mut Self::State,
     ) -> Result<(), ActorProcessingErr> {
-        finalize_writer(&mut st.writer, Some(&st.wav_path))?;
+        finalize_writer(&mut st.writer, Some(&st.audio_path))?;
         finalize_writer(&mut st.writer_mic, None)?;
         finalize_writer(&mut st.writer_spk, None)?;
 
-        if st.wav_path.exists() {
-            sync_file(&st.wav_path);
-            sync_dir(&st.wav_path);
+        if st.audio_path.exists() {
+            sync_file(&st.audio_path);
+            sync_dir(&st.audio_path);
+        }
+
+        Ok(())
+    }
+}
+
+enum AudioWriter {
+    Wav(hound::WavWriter<BufWriter<File>>),
+    Flac(FlacWriter<BufWriter<File>>),
+    Opus(OggOpusWriter<BufWriter<File>>),
+}
+
+impl AudioWriter {
+    /// Opens `path` for writing, continuing any audio already recorded there.
+    fn open(
+        path: &Path,
+        format: RecordingFormat,
+        channels: u8,
+        tags: &[(&str, &str)],
+    ) -> Result<Self, ActorProcessingErr> {
+        match format {
+            RecordingFormat::Wav => {
+                let spec = hound::WavSpec {
+                    channels: channels as u16,
+                    sample_rate: super::SAMPLE_RATE,
+                    bits_per_sample: 32,
+                    sample_format: hound::SampleFormat::Float,
+                };
+                let writer = if path.exists() {
+                    hound::WavWriter::append(path)?
+                } else {
+                    hound::WavWriter::create(path, spec)?
+                };
+                Ok(Self::Wav(writer))
+            }
+            RecordingFormat::Flac | RecordingFormat::Opus => {
+                // Compressed streams can't be appended to in place. The previous
+                // recording is moved aside and re-encoded into a fresh stream; if we
+                // crash midway, the `.partial` copy is still the source of truth.
+                let partial = path.with_extension(format!("{}.partial", format.extension()));
+                if path.exists() && !partial.exists() {
+                    std::fs::rename(path, &partial)?;
+                }
+
+                let file = BufWriter::new(File::create(path)?);
+                let mut writer = match format {
+                    RecordingFormat::Flac => Self::Flac(
+                        FlacWriter::new(
+                            file,
+                            NonZeroU8::new(channels).ok_or("invalid_channel_count")?,
+                            NonZeroU32::new(super::SAMPLE_RATE).ok_or("invalid_sample_rate")?,
+                            tags,
+                        )
+                        .map_err(into_actor_err)?,
+                    ),
+                    _ => Self::Opus(
+                        OggOpusWriter::new(
+                            file,
+                            channels,
+                            super::SAMPLE_RATE,
+                            tags,
+                            OpusEncodeSettings::default(),
+                        )
+                        .map_err(into_actor_err)?,
+                    ),
+                };
+
+                if partial.exists() {
+                    let copied = match &mut writer {
+                        Self::Flac(w) => copy_flac_into(&partial, w),
+                        Self::Opus(w) => copy_opus_into(&partial, w),
+                        Self::Wav(_) => Ok(()),
+                    };
+                    writer.flush()?;
+
+                    match copied {
+                        Ok(()) => std::fs::remove_file(&partial)?,
+                        Err(error) => {
+                            // Whatever couldn't be carried over still only exists in
+                            // the old file, so keep it next to the new recording.
+                            let kept = unmerged_path(path, format);
+                            tracing::warn!(?error, ?kept, "recording_resume_partially_failed");
+                            std::fs::rename(&partial, &kept)?;
+                        }
+                    }
+                }
+
+                Ok(writer)
+            }
+        }
+    }
+
+    fn write(&mut self, samples: &[f32]) -> Result<(), ActorProcessingErr> {
+        match self {
+            Self::Wav(w) => {
+                for s in samples {
+                    w.write_sample(*s)?;
+                }
+            }
+            Self::Flac(w) => w.write_interleaved(samples).map_err(into_actor_err)?,
+            Self::Opus(w) => w.write_interleaved(samples).map_err(into_actor_err)?,
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<(), ActorProcessingErr> {
+        match self {
+            Self::Wav(w) => w.flush()?,
+            Self::Flac(w) => w.flush().map_err(into_actor_err)?,
+            Self::Opus(w) => w.flush().map_err(into_actor_err)?,
         }
+        Ok(())
+    }
 
+    fn finalize(self) -> Result<(), ActorProcessingErr> {
+        match self {
+            Self::Wav(mut w) => {
+                w.flush()?;
+                w.finalize()?;
+            }
+            Self::Flac(w) => {
+                w.finish().map_err(into_actor_err)?;
+            }
+            Self::Opus(w) => {
+                w.finish().map_err(into_actor_err)?;
+            }
+        }
         Ok(())
     }
 }
 
+struct RecordingTags {
+    session_id: String,
+    date: String,
+    mic_device: String,
+}
+
+impl RecordingTags {
+    fn new(session_id: &str, started_at: SystemTime) -> Self {
+        Self {
+            session_id: session_id.to_string(),
+            date: chrono::DateTime::<chrono::Utc>::from(started_at).to_rfc3339(),
+            mic_device: hypr_audio::AudioInput::get_default_device_name(),
+        }
+    }
+
+    fn pairs(&self) -> Vec<(&str, &str)> {
+        vec![
+            ("SESSION_ID", self.session_id.as_str()),
+            ("DATE", self.date.as_str()),
+            ("MIC_DEVICE", self.mic_device.as_str()),
+        ]
+    }
+}
+
+fn unmerged_path(path: &Path, format: RecordingFormat) -> PathBuf {
+    let secs = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default();
+    path.with_extension(format!("unmerged-{}.{}", secs, format.extension()))
+}
+
+fn existing_format(dir: &Path, filename_base: &str) -> Option<RecordingFormat> {
+    let exists = |ext: &str| {
+        dir.join(format!("{}.{}", filename_base, ext)).exists()
+            || dir
+                .join(format!("{}.{}.partial", filename_base, ext))
+                .exists()
+    };
+
+    if exists("wav") || exists("ogg") {
+        Some(RecordingFormat::Wav)
+    } else if exists(RecordingFormat::Flac.extension()) {
+        Some(RecordingFormat::Flac)
+    } else if exists(RecordingFormat::Opus.extension()) {
+        Some(RecordingFormat::Opus)
+    } else {
+        None
+    }
+}
+
 fn into_actor_err(err: hypr_audio_utils::Error) -> ActorProcessingErr {
     Box::new(err)
 }
@@ -196,14 +372,14 @@ fn is_debug_mode() -> bool {
             .unwrap_or(false)
 }
 
-fn flush_if_due(state: &mut RecState) -> Result<(), hound::Error> {
+fn flush_if_due(state: &mut RecState) -> Result<(), ActorProcessingErr> {
     if state.last_flush.elapsed() < FLUSH_INTERVAL {
         return Ok(());
     }
     flush_all(state)
 }
 
-fn flush_all(state: &mut RecState) -> Result<(), hound::Error> {
+fn flush_all(state: &mut RecState) -> Result<(), ActorProcessingErr> {
     if let Some(writer) = state.writer.as_mut() {
         writer.flush()?;
     }
@@ -217,48 +393,26 @@ fn flush_all(state: &mut RecState) -> Result<(), hound::Error> {
     Ok(())
 }
 
-fn write_mono_samples(
-    writer: &mut hound::WavWriter<BufWriter<File>>,
-    samples: &[f32],
-) -> Result<(), hound::Error> {
-    for s in samples {
-        writer.write_sample(*s)?;
-    }
-    Ok(())
+fn mono_as_stereo(samples: &[f32]) -> Vec<f32> {
+    samples.iter().flat_map(|&s| [s, s]).collect()
 }
 
-fn write_mono_as_stereo(
-    writer: &mut hound::WavWriter<BufWriter<File>>,
-    samples: &[f32],
-) -> Result<(), hound::Error> {
-    for s in samples {
-        writer.write_sample(*s)?;
-        writer.write_sample(*s)?;
-    }
-    Ok(())
-}
-
-fn write_interleaved_stereo(
-    writer: &mut hound::WavWriter<BufWriter<File>>,
-    mic: &[f32],
-    spk: &[f32],
-) -> Result<(), hound::Error> {
+fn interleave_stereo(mic: &[f32], spk: &[f32]) -> Vec<f32> {
     let max_len = mic.len().max(spk.len());
-    for i in 0..max_len {
-        let m = mic.get(i).copied().unwrap_or(0.0);
-        let s = spk.get(i).copied().unwrap_or(0.0);
-        writer.write_sample(m)?;
-        writer.write_sample(s)?;
-    }
-    Ok(())
+    (0..max_len)
+        .flat_map(|i| {
+            let m = mic.get(i).copied().unwrap_or(0.0);
+            let s = spk.get(i).copied().unwrap_or(0.0);
+            [m, s]
+        })
+        .collect()
 }
 
 fn finalize_writer(
-    writer: &mut Option<hound::WavWriter<BufWriter<File>>>,
+    writer: &mut Option<AudioWriter>,
     path: Option<&std::path::Path>,
-) -> Result<(), hound::Error> {
-    if let Some(mut writer) = writer.take() {
-        writer.flush()?;
+) -> Result<(), ActorProcessingErr> {
+    if let Some(writer) = writer.take() {
         writer.finalize()?;
 
         if let Some(p) = path {
@@ -82,6 +82,8 @@ impl Actor for SessionActor {
                     RecArgs {
                         app_dir: ctx.app_dir.clone(),
                         session_id: ctx.params.session_id.clone(),
+                        started_at: ctx.started_at_system,
+                        options: ctx.params.recording.clone(),
                     },
                     myself.get_cell(),
                 )
@@ -128,6 +130,7 @@ impl Actor for SessionActor {
                     api_key: state.ctx.params.api_key.clone(),
                     keywords: state.ctx.params.keywords.clone(),
                     mode,
+                    speaker_separation: state.ctx.params.speaker_separation,
                     session_started_at: state.ctx.started_at_instant,
                     session_started_at_unix: state.ctx.started_at_system,
                     session_id: state.ctx.params.session_id.clone(),
@@ -357,11 +360,14 @@ async fn try_restart_recorder(supervisor_cell: ActorCell, state: &mut SessionSta
     let sup = supervisor_cell;
     let app_dir = state.ctx.app_dir.clone();
     let session_id = state.ctx.params.session_id.clone();
+    let started_at = state.ctx.started_at_system;
+    let options = state.ctx.params.recording.clone();
 
     let cell = spawn_with_retry(&RETRY_STRATEGY, || {
         let sup = sup.clone();
         let app_dir = app_dir.clone();
         let session_id = session_id.clone();
+        let options = options.clone();
         async move {
             let (r, _) = Actor::spawn_linked(
                 Some(RecorderActor::name()),
@@ -369,6 +375,8 @@ async fn try_restart_recorder(supervisor_cell: ActorCell, state: &mut SessionSta
                 RecArgs {
                     app_dir,
                     session_id,
+                    started_at,
+                    options,
                 },
                 sup,
             )
@@ -17,6 +17,59 @@ pub struct SessionParams {
     pub base_url: String,
     pub api_key: String,
     pub keywords: Vec<String>,
+    #[serde(default)]
+    pub recording: RecordingOptions,
+    /// Split the mic channel into per-speaker virtual channels using local
+    /// diarization, for in-person meetings around a single mic.
+    #[serde(default)]
+    pub speaker_separation: bool,
+}
+
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, specta::Type,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum RecordingFormat {
+    /// Uncompressed 32-bit float WAV.
+    #[default]
+    Wav,
+    /// Lossless 16-bit FLAC, streamed frame by frame.
+    Flac,
+    /// Ogg/Opus, streamed with one-second pages.
+    Opus,
+}
+
+impl RecordingFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            RecordingFormat::Wav => "wav",
+            RecordingFormat::Flac => "flac",
+            RecordingFormat::Opus => "opus",
+        }
+    }
+}
+
+/// Whether the separate mic/speaker recordings are kept next to the combined one.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, specta::Type,
+)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum StemRetention {
+    #[default]
+    Discard,
+    Keep,
+    /// Stems older than `days` are pruned across the vault when a recording starts.
+    KeepForDays {
+        days: u32,
+    },
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct RecordingOptions {
+    #[serde(default)]
+    pub format: RecordingFormat,
+    #[serde(default)]
+    pub stems: StemRetention,
 }
 
 #[derive(Clone)]
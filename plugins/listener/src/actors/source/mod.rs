@@ -1,4 +1,5 @@
 mod pipeline;
+mod quality;
 mod stream;
 
 use std::sync::{
@@ -159,6 +160,7 @@ impl Actor for SourceActor {
         match msg {
             SourceMsg::SetMicMute(muted) => {
                 st.mic_muted.store(muted, Ordering::Relaxed);
+                st.pipeline.set_mic_muted(muted);
             }
             SourceMsg::GetMicMute(reply) => {
                 if !reply.is_closed() {
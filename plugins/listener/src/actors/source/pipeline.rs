@@ -15,6 +15,8 @@ use hypr_aec::AEC;
 use hypr_audio_utils::f32_to_i16_bytes;
 use hypr_vad_ext::VadMask;
 
+use super::quality::QualityMonitor;
+
 const AUDIO_AMPLITUDE_THROTTLE: Duration = Duration::from_millis(100);
 const MAX_BUFFER_CHUNKS: usize = 150;
 
@@ -26,6 +28,7 @@ pub(in crate::actors) struct Pipeline {
     aec: Option<AEC>,
     joiner: Joiner,
     amplitude: AmplitudeEmitter,
+    quality: QualityMonitor,
     audio_buffer: AudioBuffer,
     backlog_quota: f32,
 }
@@ -40,7 +43,8 @@ impl Pipeline {
                 .map_err(|e| tracing::warn!(error = ?e, "aec_init_failed"))
                 .ok(),
             joiner: Joiner::new(),
-            amplitude: AmplitudeEmitter::new(app, session_id),
+            amplitude: AmplitudeEmitter::new(app.clone(), session_id.clone()),
+            quality: QualityMonitor::new(app, session_id),
             audio_buffer: AudioBuffer::new(MAX_BUFFER_CHUNKS),
             backlog_quota: 0.0,
             vad_mask: VadMask::default(),
@@ -53,16 +57,23 @@ impl Pipeline {
             aec.reset();
         }
         self.amplitude.reset();
+        self.quality.reset();
         self.audio_buffer.clear();
         self.backlog_quota = 0.0;
         self.vad_mask = VadMask::default();
     }
 
+    pub(super) fn set_mic_muted(&mut self, muted: bool) {
+        self.quality.set_mic_muted(muted);
+    }
+
     pub(super) fn ingest_mic(&mut self, chunk: AudioChunk) {
+        self.quality.ingest_mic(chunk.data.len());
         self.joiner.push_mic(chunk.data);
     }
 
     pub(super) fn ingest_speaker(&mut self, chunk: AudioChunk) {
+        self.quality.ingest_spk(chunk.data.len());
         self.joiner.push_spk(chunk.data);
     }
 
@@ -73,6 +84,8 @@ impl Pipeline {
     }
 
     fn dispatch(&mut self, mic: Vec<f32>, spk: Vec<f32>, mode: ChannelMode) {
+        self.quality.observe_levels(&mic, &spk, mode);
+
         let mut processed_mic = if let Some(aec) = &mut self.aec {
             match aec.process_streaming(&mic, &spk) {
                 Ok(processed) => processed,
@@ -85,6 +98,8 @@ impl Pipeline {
             mic
         };
 
+        self.quality.observe_echo(&processed_mic, &spk, mode);
+
         self.vad_mask.process(&mut processed_mic);
         let processed_mic = Arc::<[f32]>::from(processed_mic);
         let processed_spk = Arc::<[f32]>::from(spk);
@@ -0,0 +1,639 @@
+use std::collections::VecDeque;
+
+use tauri_specta::Event;
+
+use crate::{AudioChannel, AudioQualityIssue, SessionDataEvent, actors::ChannelMode};
+
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum QualityChange {
+    Raised(AudioQualityIssue),
+    Cleared(AudioQualityIssue),
+}
+
+pub(super) struct QualityMonitor {
+    app: tauri::AppHandle,
+    session_id: String,
+    analyzer: QualityAnalyzer,
+}
+
+impl QualityMonitor {
+    pub(super) fn new(app: tauri::AppHandle, session_id: String) -> Self {
+        Self {
+            app,
+            session_id,
+            analyzer: QualityAnalyzer::new(crate::actors::SAMPLE_RATE),
+        }
+    }
+
+    pub(super) fn reset(&mut self) {
+        let changes = self.analyzer.reset();
+        self.emit(changes);
+    }
+
+    pub(super) fn set_mic_muted(&mut self, muted: bool) {
+        let changes = self.analyzer.set_mic_muted(muted);
+        self.emit(changes);
+    }
+
+    pub(super) fn ingest_mic(&mut self, len: usize) {
+        let changes = self.analyzer.ingest_mic(len);
+        self.emit(changes);
+    }
+
+    pub(super) fn ingest_spk(&mut self, len: usize) {
+        let changes = self.analyzer.ingest_spk(len);
+        self.emit(changes);
+    }
+
+    pub(super) fn observe_levels(&mut self, raw_mic: &[f32], spk: &[f32], mode: ChannelMode) {
+        let changes = self.analyzer.observe_levels(raw_mic, spk, mode);
+        self.emit(changes);
+    }
+
+    pub(super) fn observe_echo(&mut self, aec_mic: &[f32], spk: &[f32], mode: ChannelMode) {
+        let changes = self.analyzer.observe_echo(aec_mic, spk, mode);
+        self.emit(changes);
+    }
+
+    fn emit(&self, changes: Vec<QualityChange>) {
+        for change in changes {
+            let event = match change {
+                QualityChange::Raised(issue) => {
+                    tracing::warn!(?issue, "audio_quality_warning");
+                    SessionDataEvent::AudioQualityWarning {
+                        session_id: self.session_id.clone(),
+                        issue,
+                    }
+                }
+                QualityChange::Cleared(issue) => {
+                    tracing::info!(?issue, "audio_quality_recovered");
+                    SessionDataEvent::AudioQualityRecovered {
+                        session_id: self.session_id.clone(),
+                        issue,
+                    }
+                }
+            };
+
+            if let Err(error) = event.emit(&self.app) {
+                tracing::error!(error = ?error, "session_data_event_emit_failed");
+            }
+        }
+    }
+}
+
+/// Hysteresis around a per-window boolean condition, so a single bad window
+/// doesn't flap a warning in the UI.
+struct Detector {
+    raise_after: u32,
+    clear_after: u32,
+    bad_streak: u32,
+    good_streak: u32,
+    active: bool,
+}
+
+impl Detector {
+    fn new(raise_after: u32, clear_after: u32) -> Self {
+        Self {
+            raise_after,
+            clear_after,
+            bad_streak: 0,
+            good_streak: 0,
+            active: false,
+        }
+    }
+
+    /// Returns `Some(true)` when the issue is raised and `Some(false)` when it clears.
+    fn update(&mut self, bad: bool) -> Option<bool> {
+        if bad {
+            self.bad_streak += 1;
+            self.good_streak = 0;
+            if !self.active && self.bad_streak >= self.raise_after {
+                self.active = true;
+                return Some(true);
+            }
+        } else {
+            self.good_streak += 1;
+            self.bad_streak = 0;
+            if self.active && self.good_streak >= self.clear_after {
+                self.active = false;
+                return Some(false);
+            }
+        }
+        None
+    }
+
+    /// Forgets all history. Returns `true` if the issue was active.
+    fn reset(&mut self) -> bool {
+        let was_active = self.active;
+        self.bad_streak = 0;
+        self.good_streak = 0;
+        self.active = false;
+        was_active
+    }
+}
+
+#[derive(Default)]
+struct WindowStats {
+    samples: usize,
+    clipped: usize,
+    sum: f64,
+    sum_squares: f64,
+}
+
+impl WindowStats {
+    fn push(&mut self, data: &[f32]) {
+        for &x in data.iter().filter(|x| x.is_finite()) {
+            self.samples += 1;
+            if x.abs() >= QualityAnalyzer::CLIP_LEVEL {
+                self.clipped += 1;
+            }
+            self.sum += x as f64;
+            self.sum_squares += (x as f64) * (x as f64);
+        }
+    }
+
+    fn mean(&self) -> f64 {
+        self.sum / self.samples.max(1) as f64
+    }
+
+    fn rms(&self) -> f64 {
+        (self.sum_squares / self.samples.max(1) as f64).sqrt()
+    }
+
+    fn clipped_ratio(&self) -> f64 {
+        self.clipped as f64 / self.samples.max(1) as f64
+    }
+}
+
+struct ChannelMonitor {
+    channel: AudioChannel,
+    window: WindowStats,
+    clipping: Detector,
+    /// `None` for channels where digital silence is normal.
+    silence: Option<Detector>,
+    dc_offset: Detector,
+}
+
+impl ChannelMonitor {
+    fn new(channel: AudioChannel, silence_windows: Option<u32>) -> Self {
+        Self {
+            channel,
+            window: WindowStats::default(),
+            clipping: Detector::new(2, 5),
+            silence: silence_windows.map(|windows| Detector::new(windows, 1)),
+            dc_offset: Detector::new(3, 5),
+        }
+    }
+
+    fn observe(&mut self, data: &[f32], window_len: usize, changes: &mut Vec<QualityChange>) {
+        self.window.push(data);
+        if self.window.samples < window_len {
+            return;
+        }
+
+        let window = std::mem::take(&mut self.window);
+        let channel = self.channel.clone();
+
+        push_change(
+            changes,
+            self.clipping
+                .update(window.clipped_ratio() > QualityAnalyzer::MAX_CLIPPED_RATIO),
+            AudioQualityIssue::Clipping {
+                channel: channel.clone(),
+            },
+        );
+        if let Some(silence) = &mut self.silence {
+            push_change(
+                changes,
+                silence.update(window.rms() < QualityAnalyzer::SILENCE_RMS),
+                AudioQualityIssue::Silence {
+                    channel: channel.clone(),
+                },
+            );
+        }
+        push_change(
+            changes,
+            self.dc_offset
+                .update(window.mean().abs() > QualityAnalyzer::MAX_DC_OFFSET),
+            AudioQualityIssue::DcOffset { channel },
+        );
+    }
+
+    fn reset(&mut self, changes: &mut Vec<QualityChange>) {
+        self.window = WindowStats::default();
+
+        let channel = self.channel.clone();
+        if self.clipping.reset() {
+            changes.push(QualityChange::Cleared(AudioQualityIssue::Clipping {
+                channel: channel.clone(),
+            }));
+        }
+        if self.silence.as_mut().is_some_and(Detector::reset) {
+            changes.push(QualityChange::Cleared(AudioQualityIssue::Silence {
+                channel: channel.clone(),
+            }));
+        }
+        if self.dc_offset.reset() {
+            changes.push(QualityChange::Cleared(AudioQualityIssue::DcOffset {
+                channel,
+            }));
+        }
+    }
+}
+
+fn push_change(changes: &mut Vec<QualityChange>, update: Option<bool>, issue: AudioQualityIssue) {
+    match update {
+        Some(true) => changes.push(QualityChange::Raised(issue)),
+        Some(false) => changes.push(QualityChange::Cleared(issue)),
+        None => {}
+    }
+}
+
+/// Correlates the loudness envelope of the post-AEC mic signal with the speaker
+/// signal. A residual echo follows the far-end envelope closely even when the
+/// exact delay is unknown.
+struct EchoMonitor {
+    envelope: VecDeque<(f32, f32)>,
+    samples_since_check: usize,
+    detector: Detector,
+}
+
+impl EchoMonitor {
+    const ENVELOPE_LEN: usize = 40;
+
+    fn new() -> Self {
+        Self {
+            envelope: VecDeque::with_capacity(Self::ENVELOPE_LEN),
+            samples_since_check: 0,
+            detector: Detector::new(3, 3),
+        }
+    }
+
+    fn observe(
+        &mut self,
+        aec_mic: &[f32],
+        spk: &[f32],
+        window_len: usize,
+        changes: &mut Vec<QualityChange>,
+    ) {
+        if self.envelope.len() == Self::ENVELOPE_LEN {
+            self.envelope.pop_front();
+        }
+        self.envelope.push_back((rms(aec_mic), rms(spk)));
+
+        self.samples_since_check += spk.len();
+        if self.samples_since_check < window_len || self.envelope.len() < Self::ENVELOPE_LEN {
+            return;
+        }
+        self.samples_since_check = 0;
+
+        let spk_mean =
+            self.envelope.iter().map(|(_, s)| *s).sum::<f32>() / self.envelope.len() as f32;
+        let mic_mean =
+            self.envelope.iter().map(|(m, _)| *m).sum::<f32>() / self.envelope.len() as f32;
+
+        // Nothing to echo, or nothing left after cancellation.
+        if spk_mean < QualityAnalyzer::MIN_ECHO_SPEAKER_RMS
+            || mic_mean < QualityAnalyzer::SILENCE_RMS as f32
+        {
+            push_change(
+                changes,
+                self.detector.update(false),
+                AudioQualityIssue::EchoResidual,
+            );
+            return;
+        }
+
+        let correlation = pearson(self.envelope.iter().copied());
+        push_change(
+            changes,
+            self.detector
+                .update(correlation > QualityAnalyzer::ECHO_CORRELATION),
+            AudioQualityIssue::EchoResidual,
+        );
+    }
+
+    fn reset(&mut self, changes: &mut Vec<QualityChange>) {
+        self.envelope.clear();
+        self.samples_since_check = 0;
+        if self.detector.reset() {
+            changes.push(QualityChange::Cleared(AudioQualityIssue::EchoResidual));
+        }
+    }
+}
+
+/// Compares how many samples each device delivered before the joiner pads
+/// either side with silence. Both are resampled to the same rate, so a
+/// sustained mismatch means one device clock (or its reported rate) is off.
+struct DriftMonitor {
+    mic_samples: u64,
+    spk_samples: u64,
+    detector: Detector,
+}
+
+impl DriftMonitor {
+    fn new() -> Self {
+        Self {
+            mic_samples: 0,
+            spk_samples: 0,
+            detector: Detector::new(1, 1),
+        }
+    }
+
+    fn check(&mut self, min_samples: u64, changes: &mut Vec<QualityChange>) {
+        if self.mic_samples < min_samples || self.spk_samples < min_samples {
+            return;
+        }
+
+        let drift = (self.mic_samples as f64 / self.spk_samples as f64 - 1.0).abs();
+        self.mic_samples = 0;
+        self.spk_samples = 0;
+        push_change(
+            changes,
+            self.detector
+                .update(drift > QualityAnalyzer::MAX_DRIFT_RATIO),
+            AudioQualityIssue::SampleRateDrift,
+        );
+    }
+
+    fn reset(&mut self, changes: &mut Vec<QualityChange>) {
+        self.mic_samples = 0;
+        self.spk_samples = 0;
+        if self.detector.reset() {
+            changes.push(QualityChange::Cleared(AudioQualityIssue::SampleRateDrift));
+        }
+    }
+}
+
+pub(super) struct QualityAnalyzer {
+    sample_rate: u32,
+    mic: ChannelMonitor,
+    spk: ChannelMonitor,
+    echo: EchoMonitor,
+    drift: DriftMonitor,
+    mic_muted: bool,
+}
+
+impl QualityAnalyzer {
+    /// Samples at or above this absolute level count as clipped.
+    const CLIP_LEVEL: f32 = 0.999;
+    /// More than 0.1% clipped samples in a window is audible distortion.
+    const MAX_CLIPPED_RATIO: f64 = 0.001;
+    /// Roughly -80 dBFS. Even a quiet room stays well above this; a dead or
+    /// disconnected input does not.
+    const SILENCE_RMS: f64 = 1e-4;
+    const MAX_DC_OFFSET: f64 = 0.05;
+    const MIN_ECHO_SPEAKER_RMS: f32 = 0.01;
+    const ECHO_CORRELATION: f32 = 0.8;
+    const MAX_DRIFT_RATIO: f64 = 0.005;
+
+    const MIC_SILENCE_SECS: u32 = 10;
+    const DRIFT_WINDOW_SECS: u64 = 30;
+
+    pub(super) fn new(sample_rate: u32) -> Self {
+        Self {
+            sample_rate,
+            mic: ChannelMonitor::new(AudioChannel::Mic, Some(Self::MIC_SILENCE_SECS)),
+            // System loopback is digital zero whenever remote participants are quiet,
+            // so silence there says nothing about the capture.
+            spk: ChannelMonitor::new(AudioChannel::Speaker, None),
+            echo: EchoMonitor::new(),
+            drift: DriftMonitor::new(),
+            mic_muted: false,
+        }
+    }
+
+    fn window_len(&self) -> usize {
+        self.sample_rate as usize
+    }
+
+    pub(super) fn reset(&mut self) -> Vec<QualityChange> {
+        let mut changes = Vec::new();
+        self.mic.reset(&mut changes);
+        self.spk.reset(&mut changes);
+        self.echo.reset(&mut changes);
+        self.drift.reset(&mut changes);
+        changes
+    }
+
+    /// A muted mic is silent on purpose, so its analysis is paused while muted.
+    pub(super) fn set_mic_muted(&mut self, muted: bool) -> Vec<QualityChange> {
+        let mut changes = Vec::new();
+        if self.mic_muted != muted {
+            self.mic_muted = muted;
+            self.mic.reset(&mut changes);
+            self.drift.reset(&mut changes);
+        }
+        changes
+    }
+
+    pub(super) fn ingest_mic(&mut self, len: usize) -> Vec<QualityChange> {
+        self.drift.mic_samples += len as u64;
+        self.check_drift()
+    }
+
+    pub(super) fn ingest_spk(&mut self, len: usize) -> Vec<QualityChange> {
+        self.drift.spk_samples += len as u64;
+        self.check_drift()
+    }
+
+    fn check_drift(&mut self) -> Vec<QualityChange> {
+        let mut changes = Vec::new();
+        let min_samples = self.sample_rate as u64 * Self::DRIFT_WINDOW_SECS;
+        self.drift.check(min_samples, &mut changes);
+        changes
+    }
+
+    /// `raw_mic` is the signal before echo cancellation.
+    pub(super) fn observe_levels(
+        &mut self,
+        raw_mic: &[f32],
+        spk: &[f32],
+        mode: ChannelMode,
+    ) -> Vec<QualityChange> {
+        let mut changes = Vec::new();
+        let window_len = self.window_len();
+
+        if mode.uses_mic() && !self.mic_muted {
+            self.mic.observe(raw_mic, window_len, &mut changes);
+        }
+        if mode.uses_speaker() {
+            self.spk.observe(spk, window_len, &mut changes);
+        }
+
+        changes
+    }
+
+    /// `aec_mic` is the signal after echo cancellation.
+    pub(super) fn observe_echo(
+        &mut self,
+        aec_mic: &[f32],
+        spk: &[f32],
+        mode: ChannelMode,
+    ) -> Vec<QualityChange> {
+        let mut changes = Vec::new();
+        let window_len = self.window_len();
+
+        if mode == ChannelMode::MicAndSpeaker && !self.mic_muted {
+            self.echo.observe(aec_mic, spk, window_len, &mut changes);
+        }
+
+        changes
+    }
+}
+
+fn rms(data: &[f32]) -> f32 {
+    let (sum, count) = data
+        .iter()
+        .filter(|x| x.is_finite())
+        .fold((0.0f32, 0usize), |(sum, count), &x| {
+            (sum + x * x, count + 1)
+        });
+    if count == 0 {
+        return 0.0;
+    }
+    (sum / count as f32).sqrt()
+}
+
+fn pearson(pairs: impl Iterator<Item = (f32, f32)> + Clone) -> f32 {
+    let n = pairs.clone().count() as f32;
+    if n < 2.0 {
+        return 0.0;
+    }
+
+    let (sum_x, sum_y) = pairs
+        .clone()
+        .fold((0.0, 0.0), |(sx, sy), (x, y)| (sx + x, sy + y));
+    let (mean_x, mean_y) = (sum_x / n, sum_y / n);
+
+    let (cov, var_x, var_y) = pairs.fold((0.0, 0.0, 0.0), |(c, vx, vy), (x, y)| {
+        let (dx, dy) = (x - mean_x, y - mean_y);
+        (c + dx * dy, vx + dx * dx, vy + dy * dy)
+    });
+
+    if var_x <= f32::EPSILON || var_y <= f32::EPSILON {
+        return 0.0;
+    }
+    cov / (var_x.sqrt() * var_y.sqrt())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RATE: u32 = 1000;
+
+    fn tone(len: usize, amplitude: f32) -> Vec<f32> {
+        (0..len)
+            .map(|i| amplitude * (i as f32 * 0.3).sin())
+            .collect()
+    }
+
+    fn feed(
+        analyzer: &mut QualityAnalyzer,
+        secs: usize,
+        mic: impl Fn(usize) -> Vec<f32>,
+        spk: impl Fn(usize) -> Vec<f32>,
+    ) -> Vec<QualityChange> {
+        let chunk = RATE as usize / 10;
+        let mut changes = Vec::new();
+        for i in 0..secs * 10 {
+            let (m, s) = (mic(i), spk(i));
+            changes.extend(analyzer.ingest_mic(chunk));
+            changes.extend(analyzer.ingest_spk(chunk));
+            changes.extend(analyzer.observe_levels(&m, &s, ChannelMode::MicAndSpeaker));
+            changes.extend(analyzer.observe_echo(&m, &s, ChannelMode::MicAndSpeaker));
+        }
+        changes
+    }
+
+    #[test]
+    fn dead_mic_raises_silence_and_recovers() {
+        let mut analyzer = QualityAnalyzer::new(RATE);
+
+        let changes = feed(&mut analyzer, 12, |_| vec![0.0; 100], |_| tone(100, 0.0));
+        assert!(
+            changes.contains(&QualityChange::Raised(AudioQualityIssue::Silence {
+                channel: AudioChannel::Mic
+            }))
+        );
+
+        let changes = feed(&mut analyzer, 1, |_| tone(100, 0.1), |_| vec![0.0; 100]);
+        assert!(
+            changes.contains(&QualityChange::Cleared(AudioQualityIssue::Silence {
+                channel: AudioChannel::Mic
+            }))
+        );
+    }
+
+    #[test]
+    fn silent_speaker_is_not_reported() {
+        let mut analyzer = QualityAnalyzer::new(RATE);
+
+        let changes = feed(&mut analyzer, 60, |_| tone(100, 0.1), |_| vec![0.0; 100]);
+        assert!(changes.is_empty());
+    }
+
+    #[test]
+    fn muted_mic_is_not_reported_silent() {
+        let mut analyzer = QualityAnalyzer::new(RATE);
+        assert!(analyzer.set_mic_muted(true).is_empty());
+
+        let changes = feed(&mut analyzer, 20, |_| vec![0.0; 100], |_| tone(100, 0.1));
+        assert!(changes.is_empty());
+    }
+
+    #[test]
+    fn clipping_and_dc_offset() {
+        let mut analyzer = QualityAnalyzer::new(RATE);
+
+        let changes = feed(&mut analyzer, 3, |_| vec![1.0; 100], |_| tone(100, 0.1));
+        assert!(
+            changes.contains(&QualityChange::Raised(AudioQualityIssue::Clipping {
+                channel: AudioChannel::Mic
+            }))
+        );
+        assert!(
+            changes.contains(&QualityChange::Raised(AudioQualityIssue::DcOffset {
+                channel: AudioChannel::Mic
+            }))
+        );
+        assert!(!changes.iter().any(|c| matches!(
+            c,
+            QualityChange::Raised(AudioQualityIssue::Clipping {
+                channel: AudioChannel::Speaker
+            })
+        )));
+    }
+
+    #[test]
+    fn echo_residual_follows_speaker_envelope() {
+        let mut analyzer = QualityAnalyzer::new(RATE);
+        let level = |i: usize| if (i / 3).is_multiple_of(2) { 0.5 } else { 0.05 };
+
+        let changes = feed(
+            &mut analyzer,
+            10,
+            |i| tone(100, level(i) * 0.5),
+            |i| tone(100, level(i)),
+        );
+        assert!(changes.contains(&QualityChange::Raised(AudioQualityIssue::EchoResidual)));
+    }
+
+    #[test]
+    fn sample_rate_drift() {
+        let mut analyzer = QualityAnalyzer::new(RATE);
+        let mut changes = Vec::new();
+        for _ in 0..300 {
+            changes.extend(analyzer.ingest_mic(110));
+            changes.extend(analyzer.ingest_spk(100));
+        }
+        assert!(changes.contains(&QualityChange::Raised(AudioQualityIssue::SampleRateDrift)));
+
+        let changes = analyzer.reset();
+        assert_eq!(
+            changes,
+            vec![QualityChange::Cleared(AudioQualityIssue::SampleRateDrift)]
+        );
+    }
+}
@@ -75,6 +75,16 @@ common_event_derives! {
         },
         #[serde(rename = "mic_muted")]
         MicMuted { session_id: String, value: bool },
+        #[serde(rename = "audio_quality_warning")]
+        AudioQualityWarning {
+            session_id: String,
+            issue: AudioQualityIssue,
+        },
+        #[serde(rename = "audio_quality_recovered")]
+        AudioQualityRecovered {
+            session_id: String,
+            issue: AudioQualityIssue,
+        },
         #[serde(rename = "stream_response")]
         StreamResponse {
             session_id: String,
@@ -82,3 +92,20 @@ common_event_derives! {
         },
     }
 }
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, specta::Type)]
+#[serde(rename_all = "snake_case")]
+pub enum AudioChannel {
+    Mic,
+    Speaker,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, specta::Type)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum AudioQualityIssue {
+    Clipping { channel: AudioChannel },
+    Silence { channel: AudioChannel },
+    DcOffset { channel: AudioChannel },
+    EchoResidual,
+    SampleRateDrift,
+}
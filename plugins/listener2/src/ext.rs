@@ -106,7 +106,6 @@ impl<'a, R: tauri::Runtime, M: tauri::Manager<R>> Listener2<'a, R, M> {
         session_id: String,
         words: Vec<crate::VttWord>,
     ) -> Result<String, String> {
-        use aspasia::{Moment, Subtitle, WebVttSubtitle, webvtt::WebVttCue};
         use tauri_plugin_settings::SettingsPluginExt;
 
         let base = self
@@ -120,26 +119,7 @@ impl<'a, R: tauri::Runtime, M: tauri::Manager<R>> Listener2<'a, R, M> {
 
         let vtt_path = session_dir.join("transcript.vtt");
 
-        let cues: Vec<WebVttCue> = words
-            .into_iter()
-            .map(|word| {
-                let start_i64 = i64::try_from(word.start_ms)
-                    .map_err(|_| format!("start_ms {} exceeds i64::MAX", word.start_ms))?;
-                let end_i64 = i64::try_from(word.end_ms)
-                    .map_err(|_| format!("end_ms {} exceeds i64::MAX", word.end_ms))?;
-
-                Ok(WebVttCue {
-                    identifier: word.speaker,
-                    text: word.text,
-                    settings: None,
-                    start: Moment::from(start_i64),
-                    end: Moment::from(end_i64),
-                })
-            })
-            .collect::<Result<_, String>>()?;
-
-        let vtt = WebVttSubtitle::builder().cues(cues).build();
-        vtt.export(&vtt_path).map_err(|e| e.to_string())?;
+        crate::export_words_to_vtt(words, &vtt_path)?;
 
         Ok(vtt_path.to_string_lossy().to_string())
     }
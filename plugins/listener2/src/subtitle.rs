@@ -28,14 +28,54 @@ impl From<TimedSubtitleFile> for Subtitle {
         let tokens = vtt
             .events()
             .iter()
-            .map(|cue| Token {
-                text: cue.text.clone(),
-                start_time: i64::from(cue.start) as u64,
-                end_time: i64::from(cue.end) as u64,
-                speaker: cue.identifier.as_ref().filter(|s| !s.is_empty()).cloned(),
+            .map(|cue| {
+                let identifier = cue.identifier.as_ref().filter(|s| !s.is_empty()).cloned();
+                let (voice, text) = split_voice(&cue.text);
+                Token {
+                    text: unescape(text),
+                    start_time: i64::from(cue.start) as u64,
+                    end_time: i64::from(cue.end) as u64,
+                    speaker: identifier.or(voice.map(unescape)),
+                }
             })
             .collect();
 
         Self { tokens }
     }
 }
+
+impl From<VttWord> for hypr_export::subtitle::Cue {
+    fn from(word: VttWord) -> Self {
+        Self {
+            speaker: word.speaker,
+            start_ms: word.start_ms,
+            end_ms: word.end_ms,
+            text: word.text,
+        }
+    }
+}
+
+// Cues we export carry the speaker as a leading `<v Name>` voice tag.
+fn split_voice(text: &str) -> (Option<&str>, &str) {
+    text.strip_prefix("<v ")
+        .and_then(|rest| rest.split_once('>'))
+        .map(|(voice, text)| (Some(voice.trim()), text))
+        .unwrap_or((None, text))
+}
+
+fn unescape(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_voice() {
+        assert_eq!(split_voice("<v Jane>hello"), (Some("Jane"), "hello"));
+        assert_eq!(split_voice("hello"), (None, "hello"));
+    }
+}
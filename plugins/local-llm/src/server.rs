@@ -6,7 +6,7 @@ use async_openai::types::{
     ChatChoice, ChatChoiceStream, ChatCompletionMessageToolCallChunk,
     ChatCompletionResponseMessage, ChatCompletionStreamResponseDelta, ChatCompletionToolType,
     CreateChatCompletionRequest, CreateChatCompletionResponse, CreateChatCompletionStreamResponse,
-    FunctionCallStream, Role,
+    FinishReason, FunctionCallStream, Role,
 };
 use axum::{
     Router,
@@ -201,7 +201,7 @@ impl LocalProvider {
             .and_then(|v| serde_json::from_value::<hypr_gbnf::Grammar>(v.clone()).ok());
 
         let grammar = match maybe_grammar {
-            None => None,
+            None => hypr_llama::grammar_from_openai(request)?,
             Some(g) => {
                 if model.name == hypr_llama::ModelName::HyprLLM {
                     match &g {
@@ -214,12 +214,11 @@ impl LocalProvider {
             }
         };
 
-        let tools = request.tools.clone();
-
         let request = hypr_llama::LlamaRequest {
             messages,
             grammar,
-            tools,
+            tools: request.tools.clone(),
+            tool_choice: request.tool_choice.clone(),
             max_tokens: request.max_completion_tokens,
         };
 
@@ -406,6 +405,12 @@ async fn build_chat_completion_response(
             }
         }
 
+        let finish_reason = if tool_calls.is_empty() {
+            FinishReason::Stop
+        } else {
+            FinishReason::ToolCalls
+        };
+
         let res = CreateChatCompletionResponse {
             choices: vec![ChatChoice {
                 message: ChatCompletionResponseMessage {
@@ -421,6 +426,7 @@ async fn build_chat_completion_response(
                     },
                     ..empty_message
                 },
+                finish_reason: Some(finish_reason),
                 ..empty_choice
             }],
             ..base_response_template
@@ -428,67 +434,71 @@ async fn build_chat_completion_response(
 
         Ok(ChatCompletionResponse::NonStream(res))
     } else {
-        let source_stream = response_stream_fn()?;
-        let stream = Box::pin(
-            source_stream
-                .enumerate()
-                .map(move |(index, event)| {
-                    let delta_template = empty_stream_response_delta.clone();
-                    let response_template = base_stream_response_template.clone();
-
-                    match event {
-                        StreamEvent::Response(llama_response) => match llama_response {
-                            hypr_llama::Response::TextDelta(chunk) => {
-                                Some(Ok(CreateChatCompletionStreamResponse {
-                                    choices: vec![ChatChoiceStream {
-                                        index: 0,
-                                        delta: ChatCompletionStreamResponseDelta {
-                                            content: Some(chunk),
-                                            ..delta_template
-                                        },
-                                        finish_reason: None,
-                                        logprobs: None,
-                                    }],
-                                    ..response_template
-                                }))
-                            }
-                            hypr_llama::Response::Reasoning(_) => None,
-                            hypr_llama::Response::ToolCall { name, arguments } => {
-                                Some(Ok(CreateChatCompletionStreamResponse {
-                                    choices: vec![ChatChoiceStream {
-                                        index: 0,
-                                        delta: ChatCompletionStreamResponseDelta {
-                                            tool_calls: Some(vec![
-                                                ChatCompletionMessageToolCallChunk {
-                                                    index: index.try_into().unwrap_or(0),
-                                                    id: Some(uuid::Uuid::new_v4().to_string()),
-                                                    r#type: Some(ChatCompletionToolType::Function),
-                                                    function: Some(FunctionCallStream {
-                                                        name: Some(name),
-                                                        arguments: Some(
-                                                            serde_json::to_string(&arguments)
-                                                                .unwrap_or_default(),
-                                                        ),
-                                                    }),
-                                                },
-                                            ]),
-                                            ..delta_template
-                                        },
-                                        finish_reason: None,
-                                        logprobs: None,
-                                    }],
-                                    ..response_template
-                                }))
-                            }
-                        },
-                        StreamEvent::Progress(v) => {
-                            progress_fn(v);
-                            None
+        let mut source_stream = response_stream_fn()?;
+        let stream = Box::pin(async_stream::stream! {
+            let mut tool_call_count: u32 = 0;
+
+            while let Some(event) = source_stream.next().await {
+                let delta = match event {
+                    StreamEvent::Response(hypr_llama::Response::TextDelta(chunk)) => {
+                        ChatCompletionStreamResponseDelta {
+                            content: Some(chunk),
+                            ..empty_stream_response_delta.clone()
                         }
                     }
-                })
-                .filter_map(|x| async move { x }),
-        );
+                    StreamEvent::Response(hypr_llama::Response::Reasoning(_)) => continue,
+                    StreamEvent::Response(hypr_llama::Response::ToolCall { name, arguments }) => {
+                        let index = tool_call_count;
+                        tool_call_count += 1;
+
+                        ChatCompletionStreamResponseDelta {
+                            tool_calls: Some(vec![ChatCompletionMessageToolCallChunk {
+                                index,
+                                id: Some(uuid::Uuid::new_v4().to_string()),
+                                r#type: Some(ChatCompletionToolType::Function),
+                                function: Some(FunctionCallStream {
+                                    name: Some(name),
+                                    arguments: Some(
+                                        serde_json::to_string(&arguments).unwrap_or_default(),
+                                    ),
+                                }),
+                            }]),
+                            ..empty_stream_response_delta.clone()
+                        }
+                    }
+                    StreamEvent::Progress(v) => {
+                        progress_fn(v);
+                        continue;
+                    }
+                };
+
+                yield Ok::<_, StreamBodyError>(CreateChatCompletionStreamResponse {
+                    choices: vec![ChatChoiceStream {
+                        index: 0,
+                        delta,
+                        finish_reason: None,
+                        logprobs: None,
+                    }],
+                    ..base_stream_response_template.clone()
+                });
+            }
+
+            let finish_reason = if tool_call_count == 0 {
+                FinishReason::Stop
+            } else {
+                FinishReason::ToolCalls
+            };
+
+            yield Ok(CreateChatCompletionStreamResponse {
+                choices: vec![ChatChoiceStream {
+                    index: 0,
+                    delta: empty_stream_response_delta,
+                    finish_reason: Some(finish_reason),
+                    logprobs: None,
+                }],
+                ..base_stream_response_template
+            });
+        });
 
         Ok(ChatCompletionResponse::Stream(stream))
     }
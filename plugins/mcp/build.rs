@@ -1,3 +1,13 @@
+const COMMANDS: &[&str] = &[
+    "start_server",
+    "stop_server",
+    "is_server_running",
+    "get_connection",
+    "rotate_token",
+    "get_permissions",
+    "set_permissions",
+];
+
 fn main() {
-    tauri_plugin::Builder::new(&[]).build();
+    tauri_plugin::Builder::new(COMMANDS).build();
 }
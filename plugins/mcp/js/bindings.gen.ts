@@ -11,7 +11,77 @@ import { type WebviewWindow as __WebviewWindow__ } from "@tauri-apps/api/webview
 
 /** user-defined commands **/
 
-export const commands = {};
+export const commands = {
+  async startServer(): Promise<Result<McpConnection, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("plugin:mcp|start_server"),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async stopServer(): Promise<Result<null, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("plugin:mcp|stop_server"),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async isServerRunning(): Promise<boolean> {
+    return await TAURI_INVOKE("plugin:mcp|is_server_running");
+  },
+  async getConnection(): Promise<Result<McpConnection, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("plugin:mcp|get_connection"),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async rotateToken(): Promise<Result<McpConnection, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("plugin:mcp|rotate_token"),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async getPermissions(): Promise<Result<ToolPermissions, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("plugin:mcp|get_permissions"),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+  async setPermissions(permissions: ToolPermissions): Promise<Result<null, string>> {
+    try {
+      return {
+        status: "ok",
+        data: await TAURI_INVOKE("plugin:mcp|set_permissions", { permissions }),
+      };
+    } catch (e) {
+      if (e instanceof Error) throw e;
+      else return { status: "error", error: e as any };
+    }
+  },
+};
 
 /** user-defined events **/
 
@@ -34,6 +104,7 @@ export type CreateIssueParams = {
   labels: string[] | null;
 };
 export type ListSubscriptionsParams = { status: string | null };
+export type McpConnection = { url: string; token: string };
 export type SearchIssueItem = {
   number: number;
   title: string;
@@ -62,6 +133,14 @@ export type SubscriptionItem = {
   trial_end: number | null;
 };
 
+export type ToolPermissions = {
+  search_sessions: boolean;
+  get_session: boolean;
+  list_upcoming_events: boolean;
+  list_action_items: boolean;
+  append_to_note: boolean;
+};
+
 type __EventObj__<T> = {
   listen: (
     cb: TAURI_API_EVENT.EventCallback<T>,
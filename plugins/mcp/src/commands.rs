@@ -0,0 +1,56 @@
+use crate::{McpConnection, McpPluginExt, ToolPermissions};
+
+#[tauri::command]
+#[specta::specta]
+pub async fn start_server<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<McpConnection, String> {
+    app.mcp().start_server().await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn stop_server<R: tauri::Runtime>(app: tauri::AppHandle<R>) -> Result<(), String> {
+    app.mcp().stop_server().await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn is_server_running<R: tauri::Runtime>(app: tauri::AppHandle<R>) -> bool {
+    app.mcp().is_server_running().await
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_connection<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<McpConnection, String> {
+    app.mcp().connection().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn rotate_token<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<McpConnection, String> {
+    app.mcp().rotate_token().await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn get_permissions<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<ToolPermissions, String> {
+    Ok(app.mcp().permissions())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn set_permissions<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    permissions: ToolPermissions,
+) -> Result<(), String> {
+    app.mcp()
+        .set_permissions(permissions)
+        .map_err(|e| e.to_string())
+}
@@ -6,6 +6,28 @@ pub type Result<T> = std::result::Result<T, Error>;
 pub enum Error {
     #[error(transparent)]
     Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+    #[error(transparent)]
+    Http(#[from] reqwest::Error),
+    #[error(transparent)]
+    Store(#[from] tauri_plugin_store2::Error),
+    #[error(transparent)]
+    Settings(#[from] tauri_plugin_settings::Error),
+    #[error(transparent)]
+    Tantivy(#[from] tauri_plugin_tantivy::Error),
+    #[error(transparent)]
+    Export(#[from] hypr_export::Error),
+    #[error(transparent)]
+    Frontmatter(#[from] hypr_frontmatter::Error),
+    #[error("session not found: {0}")]
+    SessionNotFound(String),
+    #[error("note not found: {0}")]
+    NoteNotFound(String),
+    #[error("{0} is not set")]
+    MissingEnv(&'static str),
+    #[error("server already running")]
+    ServerAlreadyRunning,
     #[cfg(mobile)]
     #[error(transparent)]
     PluginInvoke(#[from] tauri::plugin::mobile::PluginInvokeError),
@@ -0,0 +1,140 @@
+use tauri_plugin_store2::Store2PluginExt;
+
+use crate::{McpConnection, StoreKey, ToolPermissions};
+
+pub struct Mcp<'a, R: tauri::Runtime, M: tauri::Manager<R>> {
+    manager: &'a M,
+    _runtime: std::marker::PhantomData<fn() -> R>,
+}
+
+impl<'a, R: tauri::Runtime, M: tauri::Manager<R>> Mcp<'a, R, M> {
+    fn store(&self) -> Result<tauri_plugin_store2::ScopedStore<R, StoreKey>, crate::Error> {
+        Ok(self.manager.store2().scoped_store(crate::PLUGIN_NAME)?)
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.store()
+            .and_then(|store| store.get(StoreKey::Enabled).map_err(Into::into))
+            .ok()
+            .flatten()
+            .unwrap_or(false)
+    }
+
+    pub fn permissions(&self) -> ToolPermissions {
+        self.store()
+            .and_then(|store| store.get(StoreKey::Permissions).map_err(Into::into))
+            .ok()
+            .flatten()
+            .unwrap_or_default()
+    }
+
+    pub fn set_permissions(&self, permissions: ToolPermissions) -> Result<(), crate::Error> {
+        let store = self.store()?;
+        store.set(StoreKey::Permissions, permissions)?;
+        store.save()?;
+        Ok(())
+    }
+
+    pub fn connection(&self) -> Result<McpConnection, crate::Error> {
+        let store = self.store()?;
+        let token = match store.get::<String>(StoreKey::Token)? {
+            Some(token) => token,
+            None => {
+                let token = new_token();
+                store.set(StoreKey::Token, &token)?;
+                store.save()?;
+                token
+            }
+        };
+
+        Ok(McpConnection {
+            url: crate::http::url((std::net::Ipv4Addr::LOCALHOST, crate::http::PORT).into()),
+            token,
+        })
+    }
+
+    /// Replaces the token, which disconnects every agent configured with the old one.
+    pub async fn rotate_token(&self) -> Result<McpConnection, crate::Error> {
+        let store = self.store()?;
+        store.set(StoreKey::Token, new_token())?;
+        store.save()?;
+
+        if self.is_server_running().await {
+            self.stop().await;
+            self.start().await?;
+        }
+        self.connection()
+    }
+
+    pub async fn is_server_running(&self) -> bool {
+        let state = self.manager.state::<crate::SharedState>();
+        let s = state.lock().await;
+        s.server.is_some()
+    }
+
+    #[tracing::instrument(skip_all)]
+    pub async fn start_server(&self) -> Result<McpConnection, crate::Error> {
+        self.start().await?;
+
+        let store = self.store()?;
+        store.set(StoreKey::Enabled, true)?;
+        store.save()?;
+
+        self.connection()
+    }
+
+    #[tracing::instrument(skip_all)]
+    pub async fn stop_server(&self) -> Result<(), crate::Error> {
+        self.stop().await;
+
+        let store = self.store()?;
+        store.set(StoreKey::Enabled, false)?;
+        store.save()?;
+        Ok(())
+    }
+
+    async fn start(&self) -> Result<(), crate::Error> {
+        let token = self.connection()?.token;
+
+        let state = self.manager.state::<crate::SharedState>();
+        let mut s = state.lock().await;
+        if s.server.is_some() {
+            return Err(crate::Error::ServerAlreadyRunning);
+        }
+
+        let app = self.manager.app_handle().clone();
+        s.server = Some(crate::http::run_server(app, token).await?);
+        Ok(())
+    }
+
+    async fn stop(&self) {
+        let state = self.manager.state::<crate::SharedState>();
+        let mut s = state.lock().await;
+
+        if let Some(server) = s.server.take() {
+            let _ = server.shutdown();
+        }
+    }
+}
+
+fn new_token() -> String {
+    uuid::Uuid::new_v4().simple().to_string()
+}
+
+pub trait McpPluginExt<R: tauri::Runtime> {
+    fn mcp(&self) -> Mcp<'_, R, Self>
+    where
+        Self: tauri::Manager<R> + Sized;
+}
+
+impl<R: tauri::Runtime, T: tauri::Manager<R>> McpPluginExt<R> for T {
+    fn mcp(&self) -> Mcp<'_, R, Self>
+    where
+        Self: Sized,
+    {
+        Mcp {
+            manager: self,
+            _runtime: std::marker::PhantomData,
+        }
+    }
+}
@@ -0,0 +1,149 @@
+use std::net::{Ipv4Addr, SocketAddr};
+use std::sync::Arc;
+
+use axum::{
+    Router,
+    extract::{Request, State as AxumState},
+    http::{HeaderValue, StatusCode, header},
+    middleware::{self, Next},
+    response::{IntoResponse, Response},
+};
+use rmcp::transport::streamable_http_server::StreamableHttpServerConfig;
+use tokio_util::sync::CancellationToken;
+
+use crate::server::VaultMcpServer;
+
+pub const PORT: u16 = 28934;
+
+#[derive(Clone)]
+pub struct ServerHandle {
+    pub addr: SocketAddr,
+    pub shutdown: tokio::sync::watch::Sender<()>,
+    sessions: CancellationToken,
+}
+
+impl ServerHandle {
+    pub fn shutdown(self) -> Result<(), tokio::sync::watch::error::SendError<()>> {
+        // Open SSE streams would otherwise hold the graceful shutdown forever.
+        self.sessions.cancel();
+        self.shutdown.send(())
+    }
+}
+
+pub fn url(addr: SocketAddr) -> String {
+    format!("http://{}/mcp", addr)
+}
+
+pub async fn run_server<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    token: String,
+) -> Result<ServerHandle, crate::Error> {
+    let config = StreamableHttpServerConfig::default();
+    let sessions = config.cancellation_token.clone();
+    let service =
+        hypr_mcp::create_service_with_config(move || Ok(VaultMcpServer::new(app.clone())), config);
+
+    let router = Router::new()
+        .nest_service("/mcp", service)
+        .layer(middleware::from_fn_with_state(
+            Arc::<str>::from(token),
+            authorize,
+        ));
+
+    let listener =
+        tokio::net::TcpListener::bind(SocketAddr::from((Ipv4Addr::LOCALHOST, PORT))).await?;
+    let server_addr = listener.local_addr()?;
+
+    let (shutdown_tx, mut shutdown_rx) = tokio::sync::watch::channel(());
+
+    let server_handle = ServerHandle {
+        addr: server_addr,
+        shutdown: shutdown_tx,
+        sessions,
+    };
+
+    tokio::spawn(async move {
+        if let Err(e) = axum::serve(listener, router)
+            .with_graceful_shutdown(async move {
+                shutdown_rx.changed().await.ok();
+            })
+            .await
+        {
+            tracing::error!("mcp_server_error: {}", e);
+        }
+    });
+
+    tracing::info!("mcp_server_started {}", server_addr);
+    Ok(server_handle)
+}
+
+async fn authorize(
+    AxumState(token): AxumState<Arc<str>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    // A web page can reach localhost too. Checking Host and Origin keeps it out
+    // even when it rebinds its own domain to 127.0.0.1.
+    let headers = request.headers();
+    let host_ok = headers.get(header::HOST).is_none_or(is_loopback);
+    let origin_ok = headers.get(header::ORIGIN).is_none_or(is_loopback);
+    if !host_ok || !origin_ok {
+        return StatusCode::FORBIDDEN.into_response();
+    }
+
+    let provided = headers
+        .get(header::AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "));
+    match provided {
+        Some(provided) if constant_time_eq(provided.as_bytes(), token.as_bytes()) => {
+            next.run(request).await
+        }
+        _ => StatusCode::UNAUTHORIZED.into_response(),
+    }
+}
+
+/// `localhost:1234`, `http://127.0.0.1`, `[::1]:80` and the like.
+fn is_loopback(value: &HeaderValue) -> bool {
+    let Ok(value) = value.to_str() else {
+        return false;
+    };
+    let authority = value.split_once("://").map_or(value, |(_, rest)| rest);
+    let authority = authority.split('/').next().unwrap_or_default();
+    let host = match authority.strip_prefix('[') {
+        Some(rest) => rest.split(']').next().unwrap_or_default(),
+        None => authority.split(':').next().unwrap_or_default(),
+    };
+
+    matches!(host, "localhost" | "127.0.0.1" | "::1")
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_loopback() {
+        for value in [
+            "localhost:28934",
+            "127.0.0.1",
+            "http://localhost:3000",
+            "http://[::1]:28934/mcp",
+        ] {
+            assert!(is_loopback(&HeaderValue::from_static(value)), "{value}");
+        }
+
+        for value in [
+            "evil.example.com",
+            "http://localhost.evil.example.com",
+            "https://127.0.0.1.nip.io",
+            "null",
+        ] {
+            assert!(!is_loopback(&HeaderValue::from_static(value)), "{value}");
+        }
+    }
+}
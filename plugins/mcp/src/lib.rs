@@ -1,14 +1,47 @@
+use std::sync::Arc;
+
+use tauri::Wry;
+use tokio::sync::Mutex;
+
+mod commands;
 mod error;
+mod ext;
+mod http;
+mod server;
+mod stdio;
+mod store;
+mod tools;
 pub mod types;
+mod vault;
 
 pub use error::{Error, Result};
+pub use ext::*;
+pub use http::{PORT, ServerHandle};
+pub use stdio::{TOKEN_ENV, URL_ENV, run_stdio};
+pub use store::*;
+pub use types::{McpConnection, ToolPermissions};
 
 const PLUGIN_NAME: &str = "mcp";
 
+pub type SharedState = Arc<Mutex<State>>;
+
+#[derive(Default)]
+pub struct State {
+    pub server: Option<ServerHandle>,
+}
+
 fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
     tauri_specta::Builder::<R>::new()
         .plugin_name(PLUGIN_NAME)
-        .commands(tauri_specta::collect_commands![])
+        .commands(tauri_specta::collect_commands![
+            commands::start_server::<Wry>,
+            commands::stop_server::<Wry>,
+            commands::is_server_running::<Wry>,
+            commands::get_connection::<Wry>,
+            commands::rotate_token::<Wry>,
+            commands::get_permissions::<Wry>,
+            commands::set_permissions::<Wry>,
+        ])
         .typ::<types::CreateIssueParams>()
         .typ::<types::CreateIssueOutput>()
         .typ::<types::AddCommentParams>()
@@ -28,6 +61,11 @@ pub fn init<R: tauri::Runtime>() -> tauri::plugin::TauriPlugin<R> {
 
     tauri::plugin::Builder::new(PLUGIN_NAME)
         .invoke_handler(specta_builder.invoke_handler())
+        .setup(|app, _api| {
+            use tauri::Manager as _;
+            app.manage(SharedState::default());
+            Ok(())
+        })
         .build()
 }
 
@@ -0,0 +1,167 @@
+use rmcp::{
+    ErrorData as McpError, RoleServer, ServerHandler,
+    handler::server::{router::tool::ToolRouter, tool::ToolCallContext, wrapper::Parameters},
+    model::*,
+    service::RequestContext,
+    tool, tool_router,
+};
+
+use crate::McpPluginExt;
+use crate::tools;
+
+pub struct VaultMcpServer<R: tauri::Runtime> {
+    app: tauri::AppHandle<R>,
+    tool_router: ToolRouter<Self>,
+}
+
+impl<R: tauri::Runtime> Clone for VaultMcpServer<R> {
+    fn clone(&self) -> Self {
+        Self {
+            app: self.app.clone(),
+            tool_router: self.tool_router.clone(),
+        }
+    }
+}
+
+impl<R: tauri::Runtime> VaultMcpServer<R> {
+    pub(crate) fn new(app: tauri::AppHandle<R>) -> Self {
+        Self {
+            app,
+            tool_router: Self::tool_router(),
+        }
+    }
+}
+
+#[tool_router]
+impl<R: tauri::Runtime> VaultMcpServer<R> {
+    #[tool(
+        description = "Full-text search over the user's meeting sessions (titles, notes and transcripts). Returns session ids with a matching snippet, best match first.",
+        annotations(
+            read_only_hint = true,
+            destructive_hint = false,
+            open_world_hint = false
+        )
+    )]
+    async fn search_sessions(
+        &self,
+        Parameters(params): Parameters<tools::SearchSessionsParams>,
+    ) -> Result<CallToolResult, McpError> {
+        tools::search_sessions(&self.app, params).await
+    }
+
+    #[tool(
+        description = "Fetch one session: title, date, linked calendar event, participants, the user's memo, AI-enhanced notes and the speaker-labelled transcript.",
+        annotations(
+            read_only_hint = true,
+            destructive_hint = false,
+            open_world_hint = false
+        )
+    )]
+    async fn get_session(
+        &self,
+        Parameters(params): Parameters<tools::GetSessionParams>,
+    ) -> Result<CallToolResult, McpError> {
+        tools::get_session(&self.app, params).await
+    }
+
+    #[tool(
+        description = "List calendar events that are in progress or start within the next few days, soonest first.",
+        annotations(
+            read_only_hint = true,
+            destructive_hint = false,
+            open_world_hint = false
+        )
+    )]
+    async fn list_upcoming_events(
+        &self,
+        Parameters(params): Parameters<tools::ListUpcomingEventsParams>,
+    ) -> Result<CallToolResult, McpError> {
+        tools::list_upcoming_events(&self.app, params).await
+    }
+
+    #[tool(
+        description = "List action items extracted from the user's meetings, with owner, due date and source session. Open items by default, those due soonest first; set assigned_to_me for the user's own.",
+        annotations(
+            read_only_hint = true,
+            destructive_hint = false,
+            open_world_hint = false
+        )
+    )]
+    async fn list_action_items(
+        &self,
+        Parameters(params): Parameters<tools::ListActionItemsParams>,
+    ) -> Result<CallToolResult, McpError> {
+        tools::list_action_items(&self.app, params).await
+    }
+
+    #[tool(
+        description = "Append markdown to the end of a session's memo, or of one of its enhanced notes. Existing content is never changed.",
+        annotations(
+            read_only_hint = false,
+            destructive_hint = false,
+            idempotent_hint = false,
+            open_world_hint = false
+        )
+    )]
+    async fn append_to_note(
+        &self,
+        Parameters(params): Parameters<tools::AppendToNoteParams>,
+    ) -> Result<CallToolResult, McpError> {
+        tools::append_to_note(&self.app, params).await
+    }
+}
+
+// Permissions are read on every request, so toggling a tool in settings
+// applies to sessions that are already connected.
+impl<R: tauri::Runtime> ServerHandler for VaultMcpServer<R> {
+    fn get_info(&self) -> ServerInfo {
+        ServerInfo {
+            protocol_version: ProtocolVersion::V_2024_11_05,
+            capabilities: ServerCapabilities::builder().enable_tools().build(),
+            server_info: Implementation {
+                name: "hyprnote-vault".to_string(),
+                title: None,
+                version: env!("CARGO_PKG_VERSION").to_string(),
+                icons: None,
+                website_url: None,
+            },
+            instructions: Some(
+                "Hyprnote vault server. Search the user's meeting notes and transcripts, read a session in full, see upcoming calendar events, and list action items across meetings."
+                    .to_string(),
+            ),
+        }
+    }
+
+    async fn list_tools(
+        &self,
+        _request: Option<PaginatedRequestParams>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListToolsResult, McpError> {
+        let permissions = self.app.mcp().permissions();
+
+        Ok(ListToolsResult::with_all_items(
+            self.tool_router
+                .list_all()
+                .into_iter()
+                .filter(|tool| permissions.allows(&tool.name))
+                .collect(),
+        ))
+    }
+
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParams,
+        context: RequestContext<RoleServer>,
+    ) -> Result<CallToolResult, McpError> {
+        if !self.app.mcp().permissions().allows(&request.name) {
+            return Err(McpError::invalid_request(
+                format!("`{}` is disabled in Hyprnote settings", request.name),
+                None,
+            ));
+        }
+
+        self.tool_router
+            .call(ToolCallContext::new(self, request, context))
+            .await
+    }
+}
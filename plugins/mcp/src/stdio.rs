@@ -0,0 +1,171 @@
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufReader};
+
+pub const URL_ENV: &str = "HYPRNOTE_MCP_URL";
+pub const TOKEN_ENV: &str = "HYPRNOTE_MCP_TOKEN";
+
+const SESSION_HEADER: &str = "mcp-session-id";
+
+/// Bridges an agent that launches MCP servers as commands to the running app.
+/// Messages are newline-delimited JSON-RPC on stdin and stdout.
+pub async fn run_stdio() -> crate::Result<()> {
+    let url = std::env::var(URL_ENV).unwrap_or_else(|_| {
+        crate::http::url((std::net::Ipv4Addr::LOCALHOST, crate::http::PORT).into())
+    });
+    let token = std::env::var(TOKEN_ENV).map_err(|_| crate::Error::MissingEnv(TOKEN_ENV))?;
+
+    proxy(&url, &token, tokio::io::stdin(), tokio::io::stdout()).await
+}
+
+pub async fn proxy(
+    url: &str,
+    token: &str,
+    input: impl AsyncRead + Unpin,
+    mut output: impl AsyncWrite + Unpin,
+) -> crate::Result<()> {
+    let http = reqwest::Client::new();
+    let mut session = None;
+
+    let mut lines = BufReader::new(input).lines();
+    while let Some(line) = lines.next_line().await? {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let replies = match forward(&http, url, token, &mut session, &line).await {
+            Ok(replies) => replies,
+            Err(e) => error_reply(&line, &e.to_string()).into_iter().collect(),
+        };
+        for reply in replies {
+            output.write_all(reply.as_bytes()).await?;
+            output.write_all(b"\n").await?;
+        }
+        output.flush().await?;
+    }
+
+    if let Some(session) = session {
+        let _ = http
+            .delete(url)
+            .bearer_auth(token)
+            .header(SESSION_HEADER, session)
+            .send()
+            .await;
+    }
+
+    Ok(())
+}
+
+async fn forward(
+    http: &reqwest::Client,
+    url: &str,
+    token: &str,
+    session: &mut Option<String>,
+    message: &str,
+) -> crate::Result<Vec<String>> {
+    let mut request = http
+        .post(url)
+        .bearer_auth(token)
+        .header(
+            reqwest::header::ACCEPT,
+            "application/json, text/event-stream",
+        )
+        .header(reqwest::header::CONTENT_TYPE, "application/json")
+        .body(message.to_string());
+    if let Some(session) = session.as_deref() {
+        request = request.header(SESSION_HEADER, session);
+    }
+
+    let response = request.send().await?.error_for_status()?;
+    if let Some(id) = response
+        .headers()
+        .get(SESSION_HEADER)
+        .and_then(|v| v.to_str().ok())
+    {
+        *session = Some(id.to_string());
+    }
+
+    let is_sse = response
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.starts_with("text/event-stream"));
+    let body = response.text().await?;
+
+    Ok(if is_sse {
+        sse_data(&body)
+    } else {
+        Some(body.trim().to_string())
+            .filter(|body| !body.is_empty())
+            .into_iter()
+            .collect()
+    })
+}
+
+/// The `data` of each event in an SSE body. Events without data, such as
+/// priming events that only carry an id, are dropped.
+fn sse_data(body: &str) -> Vec<String> {
+    body.replace("\r\n", "\n")
+        .split("\n\n")
+        .filter_map(|event| {
+            let data: Vec<&str> = event
+                .lines()
+                .filter_map(|line| line.strip_prefix("data:"))
+                .map(|data| data.strip_prefix(' ').unwrap_or(data))
+                .collect();
+            let data = data.join("\n");
+            (!data.trim().is_empty()).then_some(data)
+        })
+        .collect()
+}
+
+/// A JSON-RPC error for `message`, so the agent isn't left waiting on a request
+/// the app never saw. Notifications get no reply.
+fn error_reply(message: &str, error: &str) -> Option<String> {
+    let message: serde_json::Value = serde_json::from_str(message).ok()?;
+    let id = message.get("id").filter(|id| !id.is_null())?;
+
+    Some(
+        serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "error": {
+                "code": -32603,
+                "message": format!("request to Hyprnote failed: {}", error),
+            },
+        })
+        .to_string(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sse_data() {
+        let body = "id: 0\nretry: 3000\ndata: \n\nid: 1\ndata: {\"jsonrpc\":\"2.0\",\"id\":1,\"result\":{}}\r\n\r\n";
+        assert_eq!(
+            sse_data(body),
+            vec![r#"{"jsonrpc":"2.0","id":1,"result":{}}"#]
+        );
+    }
+
+    #[test]
+    fn test_error_reply() {
+        let reply = error_reply(
+            r#"{"jsonrpc":"2.0","id":7,"method":"tools/list"}"#,
+            "refused",
+        )
+        .unwrap();
+        let reply: serde_json::Value = serde_json::from_str(&reply).unwrap();
+        assert_eq!(reply["id"], 7);
+        assert_eq!(reply["error"]["code"], -32603);
+
+        assert!(
+            error_reply(
+                r#"{"jsonrpc":"2.0","method":"notifications/initialized"}"#,
+                "refused"
+            )
+            .is_none()
+        );
+    }
+}
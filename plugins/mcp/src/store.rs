@@ -0,0 +1,10 @@
+use tauri_plugin_store2::ScopedStoreKey;
+
+#[derive(serde::Deserialize, specta::Type, PartialEq, Eq, Hash, strum::Display)]
+pub enum StoreKey {
+    Enabled,
+    Token,
+    Permissions,
+}
+
+impl ScopedStoreKey for StoreKey {}
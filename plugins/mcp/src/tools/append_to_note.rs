@@ -0,0 +1,35 @@
+use rmcp::{ErrorData as McpError, model::*};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct AppendToNoteParams {
+    /// Session id, as returned by `search_sessions`.
+    pub session_id: String,
+    /// Id of an enhanced note from `get_session`. When omitted, appends to the user's own memo.
+    #[serde(default)]
+    pub note_id: Option<String>,
+    /// Markdown to add at the end of the note.
+    pub markdown: String,
+}
+
+pub(crate) async fn append_to_note<R: tauri::Runtime>(
+    app: &tauri::AppHandle<R>,
+    params: AppendToNoteParams,
+) -> Result<CallToolResult, McpError> {
+    if params.markdown.trim().is_empty() {
+        return Err(McpError::invalid_params("markdown is empty", None));
+    }
+
+    let base = super::vault_base(app)?;
+    let note_id = super::blocking(move || {
+        crate::vault::append_to_note(
+            &base,
+            &params.session_id,
+            params.note_id.as_deref(),
+            &params.markdown,
+        )
+    })
+    .await?;
+
+    super::json_result(&serde_json::json!({ "note_id": note_id }))
+}
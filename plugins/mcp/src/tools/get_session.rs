@@ -0,0 +1,26 @@
+use rmcp::{ErrorData as McpError, model::*};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct GetSessionParams {
+    /// Session id, as returned by `search_sessions`.
+    pub session_id: String,
+    /// Include the full transcript. Defaults to true; transcripts of long meetings can be large.
+    #[serde(default)]
+    pub include_transcript: Option<bool>,
+}
+
+pub(crate) async fn get_session<R: tauri::Runtime>(
+    app: &tauri::AppHandle<R>,
+    params: GetSessionParams,
+) -> Result<CallToolResult, McpError> {
+    let base = super::vault_base(app)?;
+    let include_transcript = params.include_transcript.unwrap_or(true);
+
+    let session = super::blocking(move || {
+        crate::vault::load_session(&base, &params.session_id, include_transcript)
+    })
+    .await?;
+
+    super::json_result(&session)
+}
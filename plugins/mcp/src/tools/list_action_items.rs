@@ -0,0 +1,42 @@
+use rmcp::{ErrorData as McpError, model::*};
+use serde::Deserialize;
+
+const DEFAULT_LIMIT: usize = 50;
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct ListActionItemsParams {
+    /// `open`, `done` or `all`. Defaults to `open`.
+    #[serde(default)]
+    pub status: Option<String>,
+    /// Only items the user owns themself.
+    #[serde(default)]
+    pub assigned_to_me: Option<bool>,
+    /// Only items from this session.
+    #[serde(default)]
+    pub session_id: Option<String>,
+    /// Maximum number of items to return. Defaults to 50.
+    #[serde(default)]
+    pub limit: Option<usize>,
+}
+
+pub(crate) async fn list_action_items<R: tauri::Runtime>(
+    app: &tauri::AppHandle<R>,
+    params: ListActionItemsParams,
+) -> Result<CallToolResult, McpError> {
+    let base = super::vault_base(app)?;
+    let status = match params.status.as_deref() {
+        None => Some("open".to_string()),
+        Some("all") => None,
+        Some(status) => Some(status.to_string()),
+    };
+    let filter = crate::vault::ActionItemFilter {
+        status,
+        assigned_to_me: params.assigned_to_me.unwrap_or(false),
+        session_id: params.session_id,
+    };
+    let limit = params.limit.unwrap_or(DEFAULT_LIMIT);
+
+    let items = super::blocking(move || crate::vault::action_items(&base, &filter, limit)).await?;
+
+    super::json_result(&items)
+}
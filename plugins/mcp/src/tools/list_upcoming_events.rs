@@ -0,0 +1,30 @@
+use rmcp::{ErrorData as McpError, model::*};
+use serde::Deserialize;
+
+const DEFAULT_DAYS: i64 = 7;
+const DEFAULT_LIMIT: usize = 20;
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct ListUpcomingEventsParams {
+    /// How many days ahead to look. Defaults to 7.
+    #[serde(default)]
+    pub days: Option<u32>,
+    /// Maximum number of events to return. Defaults to 20.
+    #[serde(default)]
+    pub limit: Option<usize>,
+}
+
+pub(crate) async fn list_upcoming_events<R: tauri::Runtime>(
+    app: &tauri::AppHandle<R>,
+    params: ListUpcomingEventsParams,
+) -> Result<CallToolResult, McpError> {
+    let base = super::vault_base(app)?;
+    let from = chrono::Utc::now();
+    let to = from + chrono::Duration::days(params.days.map(i64::from).unwrap_or(DEFAULT_DAYS));
+    let limit = params.limit.unwrap_or(DEFAULT_LIMIT);
+
+    let events =
+        super::blocking(move || crate::vault::upcoming_events(&base, from, to, limit)).await?;
+
+    super::json_result(&events)
+}
@@ -0,0 +1,46 @@
+mod append_to_note;
+mod get_session;
+mod list_action_items;
+mod list_upcoming_events;
+mod search_sessions;
+
+pub(crate) use append_to_note::{AppendToNoteParams, append_to_note};
+pub(crate) use get_session::{GetSessionParams, get_session};
+pub(crate) use list_action_items::{ListActionItemsParams, list_action_items};
+pub(crate) use list_upcoming_events::{ListUpcomingEventsParams, list_upcoming_events};
+pub(crate) use search_sessions::{SearchSessionsParams, search_sessions};
+
+use std::path::PathBuf;
+
+use rmcp::{ErrorData as McpError, model::*};
+use tauri_plugin_settings::SettingsPluginExt;
+
+fn vault_base<R: tauri::Runtime>(app: &tauri::AppHandle<R>) -> Result<PathBuf, McpError> {
+    app.settings()
+        .cached_vault_base()
+        .map_err(|e| McpError::internal_error(e.to_string(), None))
+}
+
+async fn blocking<T: Send + 'static>(
+    f: impl FnOnce() -> crate::Result<T> + Send + 'static,
+) -> Result<T, McpError> {
+    tokio::task::spawn_blocking(f)
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?
+        .map_err(to_mcp_error)
+}
+
+fn to_mcp_error(e: crate::Error) -> McpError {
+    match e {
+        crate::Error::SessionNotFound(_) | crate::Error::NoteNotFound(_) => {
+            McpError::invalid_params(e.to_string(), None)
+        }
+        e => McpError::internal_error(e.to_string(), None),
+    }
+}
+
+fn json_result<T: serde::Serialize>(value: &T) -> Result<CallToolResult, McpError> {
+    Ok(CallToolResult::success(vec![Content::text(
+        serde_json::to_string(value).map_err(|e| McpError::internal_error(e.to_string(), None))?,
+    )]))
+}
@@ -0,0 +1,92 @@
+use chrono::{DateTime, Utc};
+use rmcp::{ErrorData as McpError, model::*};
+use serde::{Deserialize, Serialize};
+use tauri_plugin_tantivy::{
+    CreatedAtFilter, SearchFilters, SearchOptions, SearchRequest, TantivyPluginExt,
+};
+
+use crate::vault::parse_time;
+
+const DEFAULT_LIMIT: usize = 10;
+const MAX_LIMIT: usize = 50;
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct SearchSessionsParams {
+    /// Words to look for in session titles, notes and transcripts. Quote a phrase to match it exactly.
+    pub query: String,
+    /// Only sessions created at or after this time (RFC 3339 or YYYY-MM-DD).
+    #[serde(default)]
+    pub since: Option<String>,
+    /// Only sessions created at or before this time (RFC 3339 or YYYY-MM-DD).
+    #[serde(default)]
+    pub until: Option<String>,
+    /// Maximum number of sessions to return. Defaults to 10, capped at 50.
+    #[serde(default)]
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug, Serialize)]
+struct SessionHit {
+    id: String,
+    title: String,
+    created_at: Option<String>,
+    score: f32,
+    snippet: Option<String>,
+}
+
+pub(crate) async fn search_sessions<R: tauri::Runtime>(
+    app: &tauri::AppHandle<R>,
+    params: SearchSessionsParams,
+) -> Result<CallToolResult, McpError> {
+    let bound = |value: Option<&str>, name: &str| {
+        value
+            .map(|v| {
+                parse_time(v)
+                    .map(|t| t.timestamp_millis())
+                    .ok_or_else(|| McpError::invalid_params(format!("invalid {name}: {v}"), None))
+            })
+            .transpose()
+    };
+    let since = bound(params.since.as_deref(), "since")?;
+    let until = bound(params.until.as_deref(), "until")?;
+
+    let result = app
+        .tantivy()
+        .search(SearchRequest {
+            query: params.query,
+            collection: None,
+            filters: SearchFilters {
+                created_at: (since.is_some() || until.is_some()).then(|| CreatedAtFilter {
+                    gte: since,
+                    lte: until,
+                    ..Default::default()
+                }),
+                doc_type: Some("session".to_string()),
+                facet: None,
+            },
+            limit: params.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT),
+            options: SearchOptions {
+                snippets: Some(true),
+                snippet_max_chars: Some(240),
+                ..Default::default()
+            },
+        })
+        .await
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+
+    let hits: Vec<SessionHit> = result
+        .hits
+        .into_iter()
+        .map(|hit| SessionHit {
+            id: hit.document.id,
+            title: hit.document.title,
+            created_at: DateTime::<Utc>::from_timestamp_millis(hit.document.created_at)
+                .filter(|_| hit.document.created_at > 0)
+                .map(|t| t.to_rfc3339()),
+            score: hit.score,
+            snippet: hit.content_snippet.map(|s| s.fragment),
+        })
+        .collect();
+
+    super::json_result(&hits)
+}
@@ -77,3 +77,46 @@ pub struct CreateBillingPortalSessionParams {
 pub struct CreateBillingPortalSessionOutput {
     pub url: String,
 }
+
+/// Which vault tools external agents may call. Writes stay off until the user opts in.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+#[serde(default)]
+pub struct ToolPermissions {
+    pub search_sessions: bool,
+    pub get_session: bool,
+    pub list_upcoming_events: bool,
+    pub list_action_items: bool,
+    pub append_to_note: bool,
+}
+
+impl Default for ToolPermissions {
+    fn default() -> Self {
+        Self {
+            search_sessions: true,
+            get_session: true,
+            list_upcoming_events: true,
+            list_action_items: true,
+            append_to_note: false,
+        }
+    }
+}
+
+impl ToolPermissions {
+    pub fn allows(&self, tool: &str) -> bool {
+        match tool {
+            "search_sessions" => self.search_sessions,
+            "get_session" => self.get_session,
+            "list_upcoming_events" => self.list_upcoming_events,
+            "list_action_items" => self.list_action_items,
+            "append_to_note" => self.append_to_note,
+            _ => false,
+        }
+    }
+}
+
+/// What an agent needs to reach the local server, over HTTP or through `--mcp-stdio`.
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+pub struct McpConnection {
+    pub url: String,
+    pub token: String,
+}
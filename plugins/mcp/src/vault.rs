@@ -0,0 +1,507 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use hypr_export::{Session, TextOptions};
+use hypr_frontmatter::{Document, Schema};
+use serde::{Deserialize, Serialize};
+
+const SESSIONS_DIR: &str = "sessions";
+const META_FILE: &str = "_meta.json";
+const MEMO_FILE: &str = "_memo.md";
+const EVENTS_FILE: &str = "events.json";
+const ACTION_ITEMS_FILE: &str = "action_items.json";
+const HUMANS_DIR: &str = "humans";
+
+type Frontmatter = serde_json::Map<String, serde_json::Value>;
+
+/// Memo and enhanced note frontmatter as fs-db versions it. Keys are kept as-is,
+/// so writes here only stamp `schema_version` and refuse files from newer builds.
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+struct NoteFrontmatter(Frontmatter);
+
+impl Schema for NoteFrontmatter {
+    const VERSION: u32 = 1;
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SessionDetail {
+    pub id: String,
+    pub title: String,
+    pub created_at: String,
+    pub event: Option<String>,
+    pub participants: Vec<String>,
+    pub memo: Option<String>,
+    pub notes: Vec<NoteDetail>,
+    /// Speaker-labelled plain text, one paragraph per segment.
+    pub transcript: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct NoteDetail {
+    pub id: String,
+    pub title: Option<String>,
+    pub content: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct UpcomingEvent {
+    pub id: String,
+    pub title: String,
+    pub started_at: String,
+    pub ended_at: String,
+    pub is_all_day: bool,
+    pub location: Option<String>,
+    pub meeting_link: Option<String>,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ActionItem {
+    pub id: String,
+    pub description: String,
+    pub status: String,
+    pub owner: Option<String>,
+    pub due_date: Option<String>,
+    pub session_id: String,
+    pub session_title: Option<String>,
+    pub created_at: String,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ActionItemFilter {
+    /// `None` matches every status.
+    pub status: Option<String>,
+    pub assigned_to_me: bool,
+    pub session_id: Option<String>,
+}
+
+/// A row of the `action_items` table, as persisted to `action_items.json`.
+#[derive(Deserialize)]
+struct StoredActionItem {
+    user_id: String,
+    created_at: String,
+    session_id: String,
+    description: String,
+    status: String,
+    #[serde(default)]
+    owner_human_id: Option<String>,
+    #[serde(default)]
+    due_date: Option<String>,
+}
+
+/// A row of the `events` table, as persisted to `events.json`.
+#[derive(Deserialize)]
+struct StoredEvent {
+    title: String,
+    started_at: String,
+    ended_at: String,
+    #[serde(default)]
+    is_all_day: Option<bool>,
+    #[serde(default)]
+    location: Option<String>,
+    #[serde(default)]
+    meeting_link: Option<String>,
+    #[serde(default)]
+    description: Option<String>,
+}
+
+pub fn session_dir(base: &Path, session_id: &str) -> crate::Result<PathBuf> {
+    // Ids come from agents, so keep them from pointing outside `sessions/`.
+    let is_plain = !session_id.is_empty()
+        && !session_id.contains(['/', '\\'])
+        && session_id != "."
+        && session_id != "..";
+    if !is_plain {
+        return Err(crate::Error::SessionNotFound(session_id.to_string()));
+    }
+
+    let dir = tauri_plugin_fs_sync::find_session_dir(&base.join(SESSIONS_DIR), session_id);
+    if !dir.join(META_FILE).is_file() {
+        return Err(crate::Error::SessionNotFound(session_id.to_string()));
+    }
+    Ok(dir)
+}
+
+pub fn load_session(
+    base: &Path,
+    session_id: &str,
+    include_transcript: bool,
+) -> crate::Result<SessionDetail> {
+    let session = Session::load(session_dir(base, session_id)?)?;
+
+    let transcript = include_transcript
+        .then(|| hypr_export::text::render(&session, &TextOptions::default()))
+        .filter(|text| !text.trim().is_empty());
+
+    Ok(SessionDetail {
+        id: session_id.to_string(),
+        title: session.title().to_string(),
+        created_at: session.meta.created_at.clone(),
+        event: session.event_title().map(str::to_string),
+        participants: session.participant_names(),
+        memo: session.memo.clone(),
+        notes: session
+            .notes
+            .iter()
+            .map(|note| NoteDetail {
+                id: note.id.clone(),
+                title: note.title.clone(),
+                content: note.content.clone(),
+            })
+            .collect(),
+        transcript,
+    })
+}
+
+/// Events that haven't ended by `from` and start before `to`, soonest first.
+pub fn upcoming_events(
+    base: &Path,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+    limit: usize,
+) -> crate::Result<Vec<UpcomingEvent>> {
+    let path = base.join(EVENTS_FILE);
+    if !path.is_file() {
+        return Ok(vec![]);
+    }
+
+    let rows: HashMap<String, serde_json::Value> =
+        serde_json::from_str(&std::fs::read_to_string(path)?)?;
+
+    // A row the app can't parse either shouldn't hide every other event.
+    let mut events: Vec<(DateTime<Utc>, UpcomingEvent)> = rows
+        .into_iter()
+        .filter_map(|(id, row)| {
+            let event: StoredEvent = serde_json::from_value(row).ok()?;
+            let start = parse_time(&event.started_at)?;
+            let end = parse_time(&event.ended_at).unwrap_or(start);
+            (end > from && start < to).then(|| {
+                let upcoming = UpcomingEvent {
+                    id,
+                    title: event.title,
+                    started_at: event.started_at,
+                    ended_at: event.ended_at,
+                    is_all_day: event.is_all_day.unwrap_or(false),
+                    location: event.location,
+                    meeting_link: event.meeting_link,
+                    description: event.description,
+                };
+                (start, upcoming)
+            })
+        })
+        .collect();
+
+    events.sort_by(|a, b| a.0.cmp(&b.0).then_with(|| a.1.id.cmp(&b.1.id)));
+    Ok(events.into_iter().take(limit).map(|(_, e)| e).collect())
+}
+
+/// Action items across all sessions, those due soonest first and undated ones
+/// last. The user's own human shares their user id, which is how
+/// `assigned_to_me` is told apart from items owned by other participants.
+pub fn action_items(
+    base: &Path,
+    filter: &ActionItemFilter,
+    limit: usize,
+) -> crate::Result<Vec<ActionItem>> {
+    let path = base.join(ACTION_ITEMS_FILE);
+    if !path.is_file() {
+        return Ok(vec![]);
+    }
+
+    let rows: HashMap<String, serde_json::Value> =
+        serde_json::from_str(&std::fs::read_to_string(path)?)?;
+
+    let mut items: Vec<(StoredActionItem, String)> = rows
+        .into_iter()
+        .filter_map(|(id, row)| {
+            let item: StoredActionItem = serde_json::from_value(row).ok()?;
+            let matches = filter.status.as_ref().is_none_or(|s| *s == item.status)
+                && filter
+                    .session_id
+                    .as_ref()
+                    .is_none_or(|s| *s == item.session_id)
+                && (!filter.assigned_to_me
+                    || item.owner_human_id.as_deref() == Some(item.user_id.as_str()));
+            matches.then_some((item, id))
+        })
+        .collect();
+
+    items.sort_by(|(a, a_id), (b, b_id)| {
+        let a_due = a.due_date.as_deref().and_then(parse_time);
+        let b_due = b.due_date.as_deref().and_then(parse_time);
+        match (a_due, b_due) {
+            (Some(a_due), Some(b_due)) => a_due.cmp(&b_due),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => std::cmp::Ordering::Equal,
+        }
+        .then_with(|| b.created_at.cmp(&a.created_at))
+        .then_with(|| a_id.cmp(b_id))
+    });
+
+    let mut human_names = HashMap::new();
+    let mut session_titles = HashMap::new();
+
+    Ok(items
+        .into_iter()
+        .take(limit)
+        .map(|(item, id)| {
+            let owner = item.owner_human_id.as_deref().and_then(|human_id| {
+                human_names
+                    .entry(human_id.to_string())
+                    .or_insert_with(|| human_name(base, human_id))
+                    .clone()
+            });
+            let session_title = session_titles
+                .entry(item.session_id.clone())
+                .or_insert_with(|| session_title(base, &item.session_id))
+                .clone();
+
+            ActionItem {
+                id,
+                description: item.description,
+                status: item.status,
+                owner,
+                due_date: item.due_date,
+                session_id: item.session_id,
+                session_title,
+                created_at: item.created_at,
+            }
+        })
+        .collect())
+}
+
+fn human_name(base: &Path, human_id: &str) -> Option<String> {
+    if human_id.contains(['/', '\\']) {
+        return None;
+    }
+
+    let raw = std::fs::read_to_string(base.join(HUMANS_DIR).join(format!("{human_id}.md"))).ok()?;
+    let doc = Document::<Frontmatter>::from_str(&raw).ok()?;
+    doc.frontmatter
+        .get("name")
+        .and_then(|v| v.as_str())
+        .filter(|name| !name.is_empty())
+        .map(str::to_string)
+}
+
+fn session_title(base: &Path, session_id: &str) -> Option<String> {
+    let dir = session_dir(base, session_id).ok()?;
+    let meta: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(dir.join(META_FILE)).ok()?).ok()?;
+    meta.get("title")
+        .and_then(|v| v.as_str())
+        .filter(|title| !title.is_empty())
+        .map(str::to_string)
+}
+
+/// Appends `markdown` to an enhanced note, or to the session's memo when
+/// `note_id` is `None`. Returns the id of the note that was written.
+pub fn append_to_note(
+    base: &Path,
+    session_id: &str,
+    note_id: Option<&str>,
+    markdown: &str,
+) -> crate::Result<String> {
+    let dir = session_dir(base, session_id)?;
+
+    let (path, mut doc) = match note_id.filter(|id| *id != session_id) {
+        Some(note_id) => find_note(&dir, note_id)?
+            .ok_or_else(|| crate::Error::NoteNotFound(note_id.to_string()))?,
+        None => {
+            let path = dir.join(MEMO_FILE);
+            let doc = match std::fs::read_to_string(&path) {
+                Ok(raw) => Document::<NoteFrontmatter>::parse_versioned(&raw)?.document,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                    let mut frontmatter = Frontmatter::new();
+                    frontmatter.insert("id".into(), session_id.into());
+                    frontmatter.insert("session_id".into(), session_id.into());
+                    Document::new(NoteFrontmatter(frontmatter), "")
+                }
+                Err(e) => return Err(e.into()),
+            };
+            (path, doc)
+        }
+    };
+
+    let existing = doc.content.trim_end();
+    doc.content = if existing.is_empty() {
+        format!("{}\n", markdown.trim())
+    } else {
+        format!("{}\n\n{}\n", existing, markdown.trim())
+    };
+    std::fs::write(&path, doc.render_versioned()?)?;
+
+    Ok(note_id.unwrap_or(session_id).to_string())
+}
+
+fn find_note(
+    dir: &Path,
+    note_id: &str,
+) -> crate::Result<Option<(PathBuf, Document<NoteFrontmatter>)>> {
+    for entry in std::fs::read_dir(dir)?.flatten() {
+        let path = entry.path();
+        let is_note = path.extension().is_some_and(|ext| ext == "md")
+            && path.file_name().is_some_and(|name| name != MEMO_FILE);
+        if !is_note {
+            continue;
+        }
+
+        let raw = std::fs::read_to_string(&path)?;
+        let Ok(doc) = Document::<Frontmatter>::from_str(&raw) else {
+            continue;
+        };
+        if doc.frontmatter.get("id").and_then(|v| v.as_str()) == Some(note_id) {
+            let doc = Document::<NoteFrontmatter>::parse_versioned(&raw)?.document;
+            return Ok(Some((path, doc)));
+        }
+    }
+    Ok(None)
+}
+
+pub(crate) fn parse_time(s: &str) -> Option<DateTime<Utc>> {
+    if let Ok(dt) = DateTime::parse_from_rfc3339(s) {
+        return Some(dt.with_timezone(&Utc));
+    }
+    chrono::NaiveDate::parse_from_str(s, "%Y-%m-%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|dt| dt.and_utc())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vault() -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        let session = dir.path().join("sessions/work/design-review");
+        std::fs::create_dir_all(&session).unwrap();
+        std::fs::create_dir_all(dir.path().join("humans")).unwrap();
+
+        std::fs::write(
+            session.join(META_FILE),
+            r#"{"id":"design-review","user_id":"me","created_at":"2024-03-01T10:00:00Z","title":"Design review","participants":[{"human_id":"jane"}]}"#,
+        )
+        .unwrap();
+        std::fs::write(
+            session.join("Summary.md"),
+            "---\nid: note-1\nsession_id: design-review\ntitle: Summary\n---\n\n- Ship the new sidebar\n",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("humans/jane.md"),
+            "---\nname: Jane Doe\n---\n",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join(EVENTS_FILE),
+            r#"{
+                "later": {"title": "Retro", "started_at": "2024-03-03T09:00:00Z", "ended_at": "2024-03-03T10:00:00Z"},
+                "soon": {"title": "Standup", "started_at": "2024-03-02T09:00:00Z", "ended_at": "2024-03-02T09:15:00Z", "meeting_link": "https://meet.example.com/x"},
+                "past": {"title": "Kickoff", "started_at": "2024-02-01T09:00:00Z", "ended_at": "2024-02-01T10:00:00Z"}
+            }"#,
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join(ACTION_ITEMS_FILE),
+            r#"{
+                "a1": {"user_id": "me", "created_at": "2024-03-01T11:00:00Z", "session_id": "design-review", "description": "Update the mockups", "status": "open", "owner_human_id": "jane", "due_date": "2024-03-08"},
+                "a2": {"user_id": "me", "created_at": "2024-03-01T11:00:00Z", "session_id": "design-review", "description": "Send the recap", "status": "open", "owner_human_id": "me", "due_date": "2024-03-04"},
+                "a3": {"user_id": "me", "created_at": "2024-03-01T11:00:00Z", "session_id": "design-review", "description": "Book the room", "status": "done", "owner_human_id": "me"},
+                "a4": {"user_id": "me", "created_at": "2024-03-01T11:00:00Z", "session_id": "design-review", "description": "Collect feedback", "status": "open"}
+            }"#,
+        )
+        .unwrap();
+
+        dir
+    }
+
+    #[test]
+    fn test_load_session() {
+        let dir = vault();
+        let session = load_session(dir.path(), "design-review", false).unwrap();
+
+        assert_eq!(session.title, "Design review");
+        assert_eq!(session.participants, vec!["Jane Doe"]);
+        assert_eq!(session.notes.len(), 1);
+        assert_eq!(session.notes[0].id, "note-1");
+        assert!(session.notes[0].content.contains("new sidebar"));
+
+        assert!(load_session(dir.path(), "../humans", false).is_err());
+        assert!(load_session(dir.path(), "missing", false).is_err());
+    }
+
+    #[test]
+    fn test_upcoming_events() {
+        let dir = vault();
+        let from = parse_time("2024-03-01T12:00:00Z").unwrap();
+        let to = parse_time("2024-03-10").unwrap();
+
+        let events = upcoming_events(dir.path(), from, to, 10).unwrap();
+        let titles: Vec<_> = events.iter().map(|e| e.title.as_str()).collect();
+        assert_eq!(titles, vec!["Standup", "Retro"]);
+
+        assert_eq!(upcoming_events(dir.path(), from, to, 1).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_action_items() {
+        let dir = vault();
+
+        let open = ActionItemFilter {
+            status: Some("open".to_string()),
+            ..Default::default()
+        };
+        let items = action_items(dir.path(), &open, 10).unwrap();
+        let descriptions: Vec<_> = items.iter().map(|i| i.description.as_str()).collect();
+        assert_eq!(
+            descriptions,
+            vec!["Send the recap", "Update the mockups", "Collect feedback"]
+        );
+        assert_eq!(items[1].owner.as_deref(), Some("Jane Doe"));
+        assert_eq!(items[1].session_title.as_deref(), Some("Design review"));
+
+        let mine = ActionItemFilter {
+            assigned_to_me: true,
+            ..open
+        };
+        let items = action_items(dir.path(), &mine, 10).unwrap();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].id, "a2");
+
+        let all = action_items(dir.path(), &ActionItemFilter::default(), 10).unwrap();
+        assert_eq!(all.len(), 4);
+    }
+
+    #[test]
+    fn test_append_to_note() {
+        let dir = vault();
+
+        append_to_note(
+            dir.path(),
+            "design-review",
+            Some("note-1"),
+            "- Follow up with Jane",
+        )
+        .unwrap();
+        append_to_note(dir.path(), "design-review", None, "Agent was here").unwrap();
+
+        let session = load_session(dir.path(), "design-review", false).unwrap();
+        assert!(
+            session.notes[0]
+                .content
+                .ends_with("- Ship the new sidebar\n\n- Follow up with Jane\n")
+        );
+        assert_eq!(session.memo.as_deref(), Some("Agent was here\n"));
+
+        let memo = std::fs::read_to_string(dir.path().join("sessions/work/design-review/_memo.md"))
+            .unwrap();
+        assert!(memo.contains("schema_version: 1\n"));
+
+        assert!(append_to_note(dir.path(), "design-review", Some("note-2"), "x").is_err());
+    }
+}
@@ -6,10 +6,8 @@ pub type Result<T> = std::result::Result<T, Error>;
 pub enum Error {
     #[error(transparent)]
     Io(#[from] std::io::Error),
-    #[error("Typst compile error: {0}")]
-    TypstCompile(String),
-    #[error("Typst PDF error: {0}")]
-    TypstPdf(String),
+    #[error(transparent)]
+    Export(#[from] hypr_export::Error),
 }
 
 impl Serialize for Error {
@@ -12,8 +12,8 @@ impl<'a, R: tauri::Runtime, M: tauri::Manager<R>> Pdf<'a, R, M> {
         input: impl Into<crate::PdfInput>,
     ) -> Result<(), crate::Error> {
         let input = input.into();
-        let typst_content = crate::typst::build_typst_content(&input);
-        let pdf_bytes = crate::typst::compile_to_pdf(&typst_content)?;
+        let typst_content = hypr_export::pdf::build_typst_content(&input);
+        let pdf_bytes = hypr_export::pdf::compile_to_pdf(&typst_content)?;
         std::fs::write(path.as_ref(), pdf_bytes)?;
         Ok(())
     }
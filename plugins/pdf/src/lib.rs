@@ -1,12 +1,10 @@
 mod commands;
 mod error;
 mod ext;
-mod types;
-mod typst;
 
 pub use error::{Error, Result};
 pub use ext::*;
-pub use types::*;
+pub use hypr_export::pdf::{PdfInput, PdfMetadata, Transcript, TranscriptItem};
 
 const PLUGIN_NAME: &str = "pdf";
 
@@ -1,8 +0,0 @@
-mod compile;
-mod content;
-mod markdown;
-mod utils;
-mod world;
-
-pub use compile::compile_to_pdf;
-pub use content::build_typst_content;
@@ -1,4 +1,12 @@
-const COMMANDS: &[&str] = &["render", "render_custom", "render_support"];
+const COMMANDS: &[&str] = &[
+    "render",
+    "render_custom",
+    "render_support",
+    "chunk_transcripts",
+    "preview_user_template",
+    "render_user_template",
+    "select_transcript_spans",
+];
 
 fn main() {
     tauri_plugin::Builder::new(COMMANDS).build();
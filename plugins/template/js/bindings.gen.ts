@@ -29,6 +29,38 @@ async renderSupport(tpl: SupportTemplate) : Promise<Result<string, string>> {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
+},
+async chunkTranscripts(transcripts: Transcript[], budget: ChunkBudget) : Promise<Result<Transcript[][], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:template|chunk_transcripts", { transcripts, budget }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+}},
+async previewUserTemplate(source: string, snippets: Partial<{ [key in string]: string }>, ctx: UserTemplateContext | null) : Promise<Result<UserTemplatePreview, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:template|preview_user_template", { source, snippets, ctx }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async renderUserTemplate(source: string, snippets: Partial<{ [key in string]: string }>, ctx: UserTemplateContext) : Promise<Result<string, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:template|render_user_template", { source, snippets, ctx }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async selectTranscriptSpans(transcript: Transcript, queries: string[], options: SpanOptions) : Promise<Result<TranscriptSpan[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:template|select_transcript_spans", { transcript, queries, options }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
 }
 }
 
@@ -43,30 +75,69 @@ async renderSupport(tpl: SupportTemplate) : Promise<Result<string, string>> {
 /** user-defined types **/
 
 export type AccountInfo = { userId: string; email: string | null; fullName: string | null; avatarUrl: string | null; stripeCustomerId: string | null }
+export type ActionItemsSystem = { language: string | null }
+export type ActionItemsUser = { session: Session; participants: Participant[]; enhancedNote: string }
 export type BugReport = { description: string; platform: string; arch: string; osVersion: string; appVersion: string; source: string }
 export type ChatSystem = { language: string | null; context: SessionContext | null }
+export type ChatVaultSystem = { language: string | null }
+/**
+ * Limits for one chunk. `max_tokens` covers the transcript only, so leave
+ * room for the rest of the prompt and the reply.
+ */
+export type ChunkBudget = { maxTokens: number; maxDurationMs: number | null }
 export type DeviceInfo = { platform: string; arch: string; osVersion: string; appVersion: string; buildHash?: string | null; locale?: string | null }
+export type EnhanceChunkUser = { session: Session; participants: Participant[]; template: EnhanceTemplate | null; transcripts: Transcript[]; chunk: number; totalChunks: number }
+export type EnhanceReduceUser = { session: Session; participants: Participant[]; template: EnhanceTemplate | null; chunkNotes: string[] }
 export type EnhanceSystem = { language: string | null }
-export type EnhanceTemplate = { title: string; description: string | null; sections: TemplateSection[] }
+export type EnhanceTemplate = { title: string; description: string | null; sections: TemplateSection[]; 
+/**
+ * Rendered user template, used in place of `sections` when set.
+ */
+body?: string | null }
 export type EnhanceUser = { session: Session; participants: Participant[]; template: EnhanceTemplate | null; transcripts: Transcript[] }
 export type Event = { name: string }
 export type FeatureRequest = { description: string; platform: string; arch: string; osVersion: string; appVersion: string; source: string }
-export type Grammar = { task: "enhance"; sections: string[] | null } | { task: "title" } | { task: "tags" } | { task: "email-to-name" }
+export type Grammar = { task: "enhance"; sections: string[] | null } | { task: "title" } | { task: "tags" } | { task: "email-to-name" } | { task: "action-items" }
 export type JsonValue = null | boolean | number | string | JsonValue[] | Partial<{ [key in string]: JsonValue }>
 export type LogAnalysis = { summarySection: string; tail: string }
 export type Participant = { name: string; jobTitle: string | null }
-export type Segment = { text: string; speaker: string }
+export type Segment = { text: string; speaker: string; startMs?: number | null; endMs?: number | null }
 export type Session = { title: string | null; startedAt: string | null; endedAt: string | null; event: Event | null }
 export type SessionContext = { title: string | null; date: string | null; rawContent: string | null; enhancedContent: string | null; transcript: Transcript | null; participants: Participant[]; event: Event | null }
+export type SpanOptions = { maxSpans: number; 
+/**
+ * Segments kept on each side of a match, so the span reads on its own.
+ */
+contextSegments: number }
 export type SupportContext = { account: AccountInfo | null; device: DeviceInfo }
 export type SupportTemplate = { supportContext: SupportContext } | { bugReport: BugReport } | { featureRequest: FeatureRequest } | { logAnalysis: LogAnalysis }
-export type Template = { enhanceSystem: EnhanceSystem } | { enhanceUser: EnhanceUser } | { titleSystem: TitleSystem } | { titleUser: TitleUser } | { chatSystem: ChatSystem } | { toolSearchSessions: ToolSearchSessions }
+export type Template = { enhanceSystem: EnhanceSystem } | { enhanceUser: EnhanceUser } | { enhanceChunkUser: EnhanceChunkUser } | { enhanceReduceUser: EnhanceReduceUser } | { titleSystem: TitleSystem } | { titleUser: TitleUser } | { chatSystem: ChatSystem } | { chatVaultSystem: ChatVaultSystem } | { toolSearchSessions: ToolSearchSessions } | { toolSearchVault: ToolSearchVault } | { actionItemsSystem: ActionItemsSystem } | { actionItemsUser: ActionItemsUser }
+/**
+ * A problem in a user template. Lines and columns start at 1.
+ */
+export type TemplateDiagnostic = { line: number; column: number; message: string; 
+/**
+ * The snippet the problem is in, or `None` for the template itself.
+ */
+snippet: string | null }
 export type TemplateSection = { title: string; description: string | null }
 export type TitleSystem = { language: string | null }
 export type TitleUser = { enhancedNote: string }
 export type ToolSearchSessionItem = { id: string; title: string | null; excerpt: string | null; score: number; createdAt: number | null; sessionContext: SessionContext | null }
 export type ToolSearchSessions = { query: string; results: ToolSearchSessionItem[] }
+export type ToolSearchVault = { queries: string[]; passages: VaultPassage[] }
 export type Transcript = { segments: Segment[]; startedAt: number | null; endedAt: number | null }
+/**
+ * A run of consecutive segments that matched a search.
+ */
+export type TranscriptSpan = { startMs: number | null; endMs: number | null; segments: Segment[]; score: number }
+export type UserTemplateContext = { session: Session; participants: Participant[]; language: string | null }
+export type UserTemplatePreview = { output: string | null; diagnostics: TemplateDiagnostic[] }
+/**
+ * One search result for `search_vault`. Transcript matches carry their
+ * segments and where they start; other matches carry an excerpt instead.
+ */
+export type VaultPassage = { sessionId: string; title: string | null; date: string | null; startMs: number | null; segments: Segment[]; excerpt: string | null }
 
 /** tauri-specta globals **/
 
@@ -27,3 +27,56 @@ pub async fn render_support<R: tauri::Runtime>(
 ) -> Result<String, String> {
     hypr_template_support::render(tpl).map_err(|e| e.to_string())
 }
+
+#[tauri::command]
+#[specta::specta]
+pub async fn chunk_transcripts<R: tauri::Runtime>(
+    _app: tauri::AppHandle<R>,
+    transcripts: Vec<hypr_template_app::Transcript>,
+    budget: hypr_template_app::ChunkBudget,
+) -> Result<Vec<Vec<hypr_template_app::Transcript>>, String> {
+    Ok(hypr_template_app::chunk_transcripts(
+        &transcripts,
+        &budget,
+        &hypr_template_app::ApproxTokenCounter,
+    ))
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn preview_user_template<R: tauri::Runtime>(
+    _app: tauri::AppHandle<R>,
+    source: String,
+    snippets: std::collections::HashMap<String, String>,
+    ctx: Option<hypr_template_app::UserTemplateContext>,
+) -> Result<hypr_template_app::UserTemplatePreview, String> {
+    Ok(hypr_template_app::preview_user_template(
+        &source, &snippets, ctx,
+    ))
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn render_user_template<R: tauri::Runtime>(
+    _app: tauri::AppHandle<R>,
+    source: String,
+    snippets: std::collections::HashMap<String, String>,
+    ctx: hypr_template_app::UserTemplateContext,
+) -> Result<String, String> {
+    hypr_template_app::render_user_template(&source, &snippets, &ctx).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn select_transcript_spans<R: tauri::Runtime>(
+    _app: tauri::AppHandle<R>,
+    transcript: hypr_template_app::Transcript,
+    queries: Vec<String>,
+    options: hypr_template_app::SpanOptions,
+) -> Result<Vec<hypr_template_app::TranscriptSpan>, String> {
+    Ok(hypr_template_app::select_transcript_spans(
+        &transcript,
+        &queries,
+        &options,
+    ))
+}
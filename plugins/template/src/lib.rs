@@ -15,6 +15,10 @@ fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
             commands::render::<Wry>,
             commands::render_custom::<Wry>,
             commands::render_support::<Wry>,
+            commands::chunk_transcripts::<Wry>,
+            commands::preview_user_template::<Wry>,
+            commands::render_user_template::<Wry>,
+            commands::select_transcript_spans::<Wry>,
         ])
         .typ::<hypr_gbnf::Grammar>()
         .error_handling(tauri_specta::ErrorHandlingMode::Result)
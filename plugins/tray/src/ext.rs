@@ -6,8 +6,8 @@ use tauri::{
 };
 
 use crate::menu_items::{
-    AppInfo, AppNew, HelpReportBug, HelpSuggestFeature, MenuItemHandler, TrayCheckUpdate, TrayOpen,
-    TrayQuit, TraySettings, TrayStart, TrayVersion,
+    AppExportVault, AppInfo, AppNew, HelpReportBug, HelpSuggestFeature, MenuItemHandler,
+    TrayCheckUpdate, TrayOpen, TrayQuit, TraySettings, TrayStart, TrayVersion,
 };
 
 const TRAY_ID: &str = "hypr-tray";
@@ -25,6 +25,7 @@ impl<'a, M: tauri::Manager<tauri::Wry>> Tray<'a, tauri::Wry, M> {
         let check_update_item = TrayCheckUpdate::build(app)?;
         let settings_item = TraySettings::build(app)?;
         let new_item = AppNew::build(app)?;
+        let export_vault_item = AppExportVault::build(app)?;
         let report_bug_item = HelpReportBug::build(app)?;
         let suggest_feature_item = HelpSuggestFeature::build(app)?;
 
@@ -65,6 +66,7 @@ impl<'a, M: tauri::Manager<tauri::Wry>> Tray<'a, tauri::Wry, M> {
                 && let MenuItemKind::Submenu(submenu) = &items[1]
             {
                 submenu.prepend(&new_item)?;
+                submenu.insert(&export_vault_item, 1)?;
             }
 
             for item in &items {
@@ -0,0 +1,37 @@
+use tauri::{
+    AppHandle, Result,
+    menu::{MenuItem, MenuItemKind},
+};
+use tauri_plugin_windows::{AppWindow, OpenTab, TabInput, WindowsPluginExt};
+use tauri_specta::Event;
+
+use super::MenuItemHandler;
+
+pub struct AppExportVault;
+
+impl MenuItemHandler for AppExportVault {
+    const ID: &'static str = "hypr_app_export_vault";
+
+    fn build(app: &AppHandle<tauri::Wry>) -> Result<MenuItemKind<tauri::Wry>> {
+        let item = MenuItem::with_id(
+            app,
+            Self::ID,
+            "Export to Obsidian or Logseq...",
+            true,
+            None::<&str>,
+        )?;
+        Ok(MenuItemKind::MenuItem(item))
+    }
+
+    // The vault export lives in the Data section of the settings tab.
+    fn handle(app: &AppHandle<tauri::Wry>) {
+        if app.windows().show(AppWindow::Main).is_ok() {
+            let event = OpenTab {
+                tab: TabInput::Settings,
+            };
+            if let Err(e) = event.emit(app) {
+                tracing::warn!("failed_emit_open_settings_tab: {e}");
+            }
+        }
+    }
+}
@@ -1,3 +1,4 @@
+mod app_export_vault;
 mod app_info;
 mod app_new;
 mod help_report_bug;
@@ -9,6 +10,7 @@ mod tray_settings;
 mod tray_start;
 mod tray_version;
 
+pub use app_export_vault::AppExportVault;
 pub use app_info::AppInfo;
 pub use app_new::AppNew;
 pub use help_report_bug::HelpReportBug;
@@ -77,6 +79,7 @@ menu_items! {
     TrayVersion => TrayVersion,
     AppInfo => AppInfo,
     AppNew => AppNew,
+    AppExportVault => AppExportVault,
     HelpReportBug => HelpReportBug,
     HelpSuggestFeature => HelpSuggestFeature,
 }
@@ -16,6 +16,9 @@ crate::common_derives! {
     pub struct SessionsState {
         pub view: Option<EditorView>,
         pub auto_start: Option<bool>,
+        /// Position in the recording to seek to once the session opens.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub seek_ms: Option<u64>,
     }
 }
 